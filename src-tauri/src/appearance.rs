@@ -0,0 +1,119 @@
+// 系统外观：读取和切换深色 / 浅色模式（macOS / GNOME / KDE / Windows）
+use crate::system_command::{powershell, run};
+use serde::{Deserialize, Serialize};
+use tauri::Emitter;
+
+// 切换后通知前端，让启动器自身的主题跟随
+const APPEARANCE_CHANGED_EVENT: &str = "system-appearance-changed";
+
+const WINDOWS_PERSONALIZE_KEY: &str = r"HKCU:\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Appearance {
+    Light,
+    Dark,
+}
+
+#[tauri::command(async)]
+pub fn get_system_appearance() -> Result<Appearance, String> {
+    let dark = match std::env::consts::OS {
+        "macos" => {
+            let output = run("osascript", &["-e", "tell application \"System Events\" to tell appearance preferences to get dark mode"])?;
+            output.trim() == "true"
+        }
+        "linux" => {
+            if is_kde() {
+                let scheme = kde_read_color_scheme()?;
+                scheme.to_lowercase().contains("dark")
+            } else {
+                let output = run("gsettings", &["get", "org.gnome.desktop.interface", "color-scheme"])?;
+                parse_gnome_color_scheme(&output)
+            }
+        }
+        "windows" => {
+            let output = powershell(&format!("(Get-ItemProperty -Path '{}').AppsUseLightTheme", WINDOWS_PERSONALIZE_KEY))?;
+            output.trim() == "0"
+        }
+        os => return Err(format!("当前系统不支持切换外观: {}", os)),
+    };
+    Ok(if dark { Appearance::Dark } else { Appearance::Light })
+}
+
+// dark 为空时切换；切换成功后广播新的外观
+#[tauri::command(async)]
+pub fn set_system_appearance(app_handle: tauri::AppHandle, dark: Option<bool>) -> Result<Appearance, String> {
+    let dark = match dark {
+        Some(dark) => dark,
+        None => get_system_appearance()? == Appearance::Light,
+    };
+
+    match std::env::consts::OS {
+        "macos" => {
+            let script = format!("tell application \"System Events\" to tell appearance preferences to set dark mode to {}", dark);
+            run("osascript", &["-e", &script])?;
+        }
+        "linux" => {
+            if is_kde() {
+                run("plasma-apply-colorscheme", &[if dark { "BreezeDark" } else { "BreezeLight" }])?;
+            } else {
+                run("gsettings", &["set", "org.gnome.desktop.interface", "color-scheme", if dark { "prefer-dark" } else { "default" }])?;
+                // 旧版 GNOME 应用只认 GTK 主题名
+                let _ = run("gsettings", &["set", "org.gnome.desktop.interface", "gtk-theme", if dark { "Adwaita-dark" } else { "Adwaita" }]);
+            }
+        }
+        "windows" => {
+            let value = if dark { 0 } else { 1 };
+            powershell(&format!(
+                "Set-ItemProperty -Path '{key}' -Name AppsUseLightTheme -Value {value} -Type Dword; Set-ItemProperty -Path '{key}' -Name SystemUsesLightTheme -Value {value} -Type Dword",
+                key = WINDOWS_PERSONALIZE_KEY,
+                value = value
+            ))?;
+        }
+        os => return Err(format!("当前系统不支持切换外观: {}", os)),
+    }
+
+    let appearance = if dark { Appearance::Dark } else { Appearance::Light };
+    let _ = app_handle.emit(APPEARANCE_CHANGED_EVENT, appearance);
+    Ok(appearance)
+}
+
+fn is_kde() -> bool {
+    std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default().to_uppercase().contains("KDE")
+}
+
+// plasma-apply-colorscheme --list-schemes 中当前方案带有 "(current color scheme)" 标记
+fn kde_read_color_scheme() -> Result<String, String> {
+    let output = run("plasma-apply-colorscheme", &["--list-schemes"])?;
+    parse_kde_current_scheme(&output).ok_or_else(|| "无法读取 KDE 当前配色方案".to_string())
+}
+
+fn parse_kde_current_scheme(output: &str) -> Option<String> {
+    output.lines()
+        .find(|line| line.contains("(current color scheme)"))
+        .map(|line| line.replace("(current color scheme)", "").trim_start_matches(|c: char| c == '*' || c.is_whitespace()).trim().to_string())
+}
+
+// gsettings 输出形如 'prefer-dark'
+fn parse_gnome_color_scheme(output: &str) -> bool {
+    output.trim().trim_matches('\'') == "prefer-dark"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gnome_color_scheme() {
+        assert!(parse_gnome_color_scheme("'prefer-dark'\n"));
+        assert!(!parse_gnome_color_scheme("'default'\n"));
+        assert!(!parse_gnome_color_scheme("'prefer-light'"));
+    }
+
+    #[test]
+    fn test_parse_kde_current_scheme() {
+        let output = "You have the following color schemes on your system:\n * BreezeClassic\n * BreezeDark (current color scheme)\n * BreezeLight\n";
+        assert_eq!(parse_kde_current_scheme(output), Some("BreezeDark".to_string()));
+        assert_eq!(parse_kde_current_scheme(" * BreezeLight\n"), None);
+    }
+}
@@ -0,0 +1,380 @@
+// 音频：切换输出/输入设备并调节默认输出的音量（pactl / osascript / SwitchAudioSource / AudioDeviceCmdlets）
+use crate::system_command::{powershell, run};
+use serde::{Deserialize, Serialize};
+
+// 音频设备类型
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioDeviceKind {
+    Output,
+    Input,
+}
+
+// 音频设备
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AudioDevice {
+    pub id: String,
+    pub name: String,
+    pub kind: AudioDeviceKind,
+    pub is_default: bool,
+}
+
+// SwitchAudioSource -f json 的每一行
+#[derive(Debug, Deserialize)]
+struct SwitchAudioSourceDevice {
+    name: String,
+}
+
+// Get-AudioDevice -List 的输出
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct WindowsAudioDevice {
+    #[serde(rename = "ID")]
+    id: String,
+    name: String,
+    #[serde(rename = "Type")]
+    device_type: String,
+    default: bool,
+}
+
+#[tauri::command(async)]
+pub fn list_audio_devices() -> Result<Vec<AudioDevice>, String> {
+    let mut devices = match std::env::consts::OS {
+        // PipeWire 通过 pipewire-pulse 兼容 pactl
+        "linux" => {
+            let info = run("pactl", &["info"])?;
+            let (default_sink, default_source) = parse_pactl_defaults(&info);
+            let mut devices = parse_pactl_devices(&run("pactl", &["list", "sinks"])?, AudioDeviceKind::Output, default_sink.as_deref());
+            devices.extend(parse_pactl_devices(&run("pactl", &["list", "sources"])?, AudioDeviceKind::Input, default_source.as_deref()));
+            devices
+        }
+        "macos" => {
+            let mut devices = Vec::new();
+            for (kind, flag) in [(AudioDeviceKind::Output, "output"), (AudioDeviceKind::Input, "input")] {
+                let list = run("SwitchAudioSource", &["-a", "-t", flag, "-f", "json"]).map_err(switch_audio_source_hint)?;
+                let current = run("SwitchAudioSource", &["-c", "-t", flag, "-f", "json"]).map_err(switch_audio_source_hint)?;
+                let current = parse_switch_audio_source(&current)?.into_iter().next();
+                devices.extend(parse_switch_audio_source(&list)?.into_iter().map(|name| AudioDevice {
+                    id: name.clone(),
+                    is_default: current.as_deref() == Some(name.as_str()),
+                    name,
+                    kind,
+                }));
+            }
+            devices
+        }
+        "windows" => {
+            let output = powershell("ConvertTo-Json -InputObject @(Get-AudioDevice -List | Select-Object ID,Name,Type,Default)").map_err(audio_device_cmdlets_hint)?;
+            parse_windows_audio_devices(&output)?
+        }
+        os => return Err(format!("当前系统不支持音频设备切换: {}", os)),
+    };
+
+    // 默认设备排在前面
+    devices.sort_by(|a, b| b.is_default.cmp(&a.is_default).then_with(|| a.name.cmp(&b.name)));
+    Ok(devices)
+}
+
+#[tauri::command(async)]
+pub fn set_default_audio_device(id: String, kind: AudioDeviceKind) -> Result<(), String> {
+    if id.is_empty() || id.starts_with('-') {
+        return Err(format!("无效的音频设备: {}", id));
+    }
+
+    match std::env::consts::OS {
+        "linux" => {
+            let command = match kind {
+                AudioDeviceKind::Output => "set-default-sink",
+                AudioDeviceKind::Input => "set-default-source",
+            };
+            run("pactl", &[command, &id])?;
+        }
+        "macos" => {
+            let flag = match kind {
+                AudioDeviceKind::Output => "output",
+                AudioDeviceKind::Input => "input",
+            };
+            run("SwitchAudioSource", &["-t", flag, "-s", &id]).map_err(switch_audio_source_hint)?;
+        }
+        "windows" => {
+            // 设备 ID 已确定输入/输出类型
+            let script = format!("Set-AudioDevice -ID '{}' | Out-Null", id.replace('\'', "''"));
+            powershell(&script).map_err(audio_device_cmdlets_hint)?;
+        }
+        os => return Err(format!("当前系统不支持音频设备切换: {}", os)),
+    }
+
+    Ok(())
+}
+
+// 默认输出设备的音量
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VolumeState {
+    pub volume: u8, // 0-100
+    pub muted: bool,
+}
+
+#[tauri::command(async)]
+pub fn get_volume() -> Result<VolumeState, String> {
+    match std::env::consts::OS {
+        "linux" => {
+            let volume = parse_percent(&run("pactl", &["get-sink-volume", "@DEFAULT_SINK@"])?)
+                .ok_or_else(|| "无法读取当前音量".to_string())?;
+            let muted = run("pactl", &["get-sink-mute", "@DEFAULT_SINK@"])?.contains("yes");
+            Ok(VolumeState { volume, muted })
+        }
+        "macos" => parse_macos_volume_settings(&run("osascript", &["-e", "get volume settings"])?),
+        "windows" => {
+            let output = powershell("'{0}|{1}' -f (Get-AudioDevice -PlaybackVolume), (Get-AudioDevice -PlaybackMute)").map_err(audio_device_cmdlets_hint)?;
+            parse_windows_volume(&output)
+        }
+        os => Err(format!("当前系统不支持音量控制: {}", os)),
+    }
+}
+
+// relative 为 true 时 value 为增量，结果限制在 0-100
+#[tauri::command(async)]
+pub fn set_volume(value: i32, relative: bool) -> Result<VolumeState, String> {
+    let current = get_volume()?;
+    let target = if relative { current.volume as i32 + value } else { value }.clamp(0, 100);
+    let percent = format!("{}%", target);
+
+    match std::env::consts::OS {
+        "linux" => {
+            run("pactl", &["set-sink-volume", "@DEFAULT_SINK@", &percent])?;
+        }
+        "macos" => {
+            run("osascript", &["-e", &format!("set volume output volume {}", target)])?;
+        }
+        "windows" => {
+            powershell(&format!("Set-AudioDevice -PlaybackVolume {} | Out-Null", target))
+                .map_err(audio_device_cmdlets_hint)?;
+        }
+        os => return Err(format!("当前系统不支持音量控制: {}", os)),
+    }
+
+    Ok(VolumeState { volume: target as u8, muted: current.muted })
+}
+
+// muted 为空时切换静音状态
+#[tauri::command(async)]
+pub fn set_muted(muted: Option<bool>) -> Result<VolumeState, String> {
+    let current = get_volume()?;
+    let muted = muted.unwrap_or(!current.muted);
+
+    match std::env::consts::OS {
+        "linux" => {
+            run("pactl", &["set-sink-mute", "@DEFAULT_SINK@", if muted { "1" } else { "0" }])?;
+        }
+        "macos" => {
+            run("osascript", &["-e", &format!("set volume output muted {}", muted)])?;
+        }
+        "windows" => {
+            let script = format!("Set-AudioDevice -PlaybackMute ${} | Out-Null", muted);
+            powershell(&script).map_err(audio_device_cmdlets_hint)?;
+        }
+        os => return Err(format!("当前系统不支持音量控制: {}", os)),
+    }
+
+    Ok(VolumeState { volume: current.volume, muted })
+}
+
+// 取第一个百分比，例如 "Volume: front-left: 32768 /  50% / -18.06 dB, ..."
+fn parse_percent(output: &str) -> Option<u8> {
+    output.split_whitespace()
+        .find_map(|token| token.trim_end_matches(',').strip_suffix('%'))
+        .and_then(|value| value.parse::<f32>().ok())
+        .map(|value| value.round().clamp(0.0, 100.0) as u8)
+}
+
+// "output volume:50, input volume:75, alert volume:100, output muted:false"
+fn parse_macos_volume_settings(output: &str) -> Result<VolumeState, String> {
+    let field = |key: &str| {
+        output.split(',')
+            .find_map(|part| part.trim().strip_prefix(key))
+            .map(str::trim)
+    };
+
+    // 部分外接设备不支持软件音量，返回 "missing value"
+    let volume = field("output volume:")
+        .and_then(|value| value.parse::<u8>().ok())
+        .ok_or_else(|| "当前输出设备不支持音量控制".to_string())?;
+    let muted = field("output muted:") == Some("true");
+    Ok(VolumeState { volume: volume.min(100), muted })
+}
+
+// "50%|False"
+fn parse_windows_volume(output: &str) -> Result<VolumeState, String> {
+    let (volume, muted) = output.trim().split_once('|')
+        .ok_or_else(|| format!("无法解析音量: {}", output.trim()))?;
+    let volume = parse_percent(volume).ok_or_else(|| format!("无法解析音量: {}", output.trim()))?;
+    Ok(VolumeState { volume, muted: muted.trim().eq_ignore_ascii_case("true") })
+}
+
+fn switch_audio_source_hint(error: String) -> String {
+    format!("{}（macOS 需要先安装 SwitchAudioSource: brew install switchaudio-osx）", error)
+}
+
+fn audio_device_cmdlets_hint(error: String) -> String {
+    format!("{}（Windows 需要先安装 AudioDeviceCmdlets: Install-Module AudioDeviceCmdlets）", error)
+}
+
+// pactl info 中的 "Default Sink:" / "Default Source:"
+fn parse_pactl_defaults(output: &str) -> (Option<String>, Option<String>) {
+    let value = |key: &str| {
+        output.lines()
+            .find_map(|line| line.trim().strip_prefix(key))
+            .map(|value| value.trim().to_string())
+    };
+    (value("Default Sink:"), value("Default Source:"))
+}
+
+// pactl list sinks/sources 的详细输出，每个设备以 "Sink #N" / "Source #N" 开头
+fn parse_pactl_devices(output: &str, kind: AudioDeviceKind, default: Option<&str>) -> Vec<AudioDevice> {
+    let mut devices = Vec::new();
+    let mut name: Option<String> = None;
+    let mut description: Option<String> = None;
+
+    let mut flush = |name: &mut Option<String>, description: &mut Option<String>| {
+        if let Some(id) = name.take() {
+            let description = description.take();
+            // 输出设备的监听源不是真正的输入设备
+            if !id.ends_with(".monitor") {
+                devices.push(AudioDevice {
+                    name: description.unwrap_or_else(|| id.clone()),
+                    is_default: default == Some(id.as_str()),
+                    id,
+                    kind,
+                });
+            }
+        }
+        *description = None;
+    };
+
+    for line in output.lines() {
+        if line.starts_with("Sink #") || line.starts_with("Source #") {
+            flush(&mut name, &mut description);
+        } else if let Some(value) = line.trim().strip_prefix("Name:") {
+            name.get_or_insert_with(|| value.trim().to_string());
+        } else if let Some(value) = line.trim().strip_prefix("Description:") {
+            description.get_or_insert_with(|| value.trim().to_string());
+        }
+    }
+    flush(&mut name, &mut description);
+
+    devices
+}
+
+// 每行一个 JSON 对象
+fn parse_switch_audio_source(output: &str) -> Result<Vec<String>, String> {
+    output.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str::<SwitchAudioSourceDevice>(line)
+                .map(|device| device.name)
+                .map_err(|e| format!("解析 SwitchAudioSource 输出失败: {}", e))
+        })
+        .collect()
+}
+
+fn parse_windows_audio_devices(output: &str) -> Result<Vec<AudioDevice>, String> {
+    let devices: Vec<WindowsAudioDevice> = serde_json::from_str(output)
+        .map_err(|e| format!("解析音频设备列表失败: {}", e))?;
+
+    Ok(devices.into_iter()
+        .filter_map(|device| {
+            let kind = match device.device_type.as_str() {
+                "Playback" => AudioDeviceKind::Output,
+                "Recording" => AudioDeviceKind::Input,
+                _ => return None,
+            };
+            Some(AudioDevice {
+                id: device.id,
+                name: device.name,
+                kind,
+                is_default: device.default,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pactl_defaults() {
+        let output = "Server Name: PulseAudio (on PipeWire 1.0.5)\nDefault Sink: alsa_output.usb-Sony.analog-stereo\nDefault Source: alsa_input.pci.analog-stereo\n";
+        assert_eq!(parse_pactl_defaults(output), (
+            Some("alsa_output.usb-Sony.analog-stereo".to_string()),
+            Some("alsa_input.pci.analog-stereo".to_string()),
+        ));
+        assert_eq!(parse_pactl_defaults(""), (None, None));
+    }
+
+    #[test]
+    fn test_parse_pactl_devices() {
+        let output = "Source #52\n\tState: SUSPENDED\n\tName: alsa_output.pci.analog-stereo.monitor\n\tDescription: Monitor of Built-in Audio\n\nSource #53\n\tState: RUNNING\n\tName: alsa_input.pci.analog-stereo\n\tDescription: Built-in Audio Analog Stereo\n\tProperties:\n\t\tdevice.description = \"Built-in Audio\"\n";
+        let devices = parse_pactl_devices(output, AudioDeviceKind::Input, Some("alsa_input.pci.analog-stereo"));
+
+        // 监听源被过滤
+        assert_eq!(devices, vec![AudioDevice {
+            id: "alsa_input.pci.analog-stereo".into(),
+            name: "Built-in Audio Analog Stereo".into(),
+            kind: AudioDeviceKind::Input,
+            is_default: true,
+        }]);
+    }
+
+    #[test]
+    fn test_parse_switch_audio_source() {
+        let output = "{\"name\": \"MacBook Pro Speakers\", \"type\": \"output\", \"id\": \"91\", \"uid\": \"BuiltInSpeakerDevice\"}\n{\"name\": \"AirPods Pro\", \"type\": \"output\", \"id\": \"64\", \"uid\": \"38-18-4C:output\"}\n";
+        assert_eq!(parse_switch_audio_source(output).unwrap(), vec!["MacBook Pro Speakers", "AirPods Pro"]);
+        assert!(parse_switch_audio_source("MacBook Pro Speakers").is_err());
+    }
+
+    #[test]
+    fn test_parse_windows_audio_devices() {
+        let output = r#"[
+            {"ID": "{0.0.0.00000000}.{a1b2}", "Name": "Speakers (Realtek)", "Type": "Playback", "Default": true},
+            {"ID": "{0.0.1.00000000}.{c3d4}", "Name": "Microphone (USB)", "Type": "Recording", "Default": false}
+        ]"#;
+        let devices = parse_windows_audio_devices(output).unwrap();
+
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].kind, AudioDeviceKind::Output);
+        assert!(devices[0].is_default);
+        assert_eq!(devices[1].name, "Microphone (USB)");
+        assert_eq!(devices[1].kind, AudioDeviceKind::Input);
+    }
+
+    #[test]
+    fn test_parse_percent() {
+        assert_eq!(parse_percent("Volume: front-left: 32768 /  50% / -18.06 dB,   front-right: 32768 /  50% / -18.06 dB"), Some(50));
+        assert_eq!(parse_percent("42%"), Some(42));
+        // pactl 允许超过 100%
+        assert_eq!(parse_percent("Volume: mono: 98304 / 150% / 10.57 dB"), Some(100));
+        assert_eq!(parse_percent("Mute: no"), None);
+    }
+
+    #[test]
+    fn test_parse_macos_volume_settings() {
+        assert_eq!(
+            parse_macos_volume_settings("output volume:38, input volume:75, alert volume:100, output muted:true").unwrap(),
+            VolumeState { volume: 38, muted: true }
+        );
+        assert!(parse_macos_volume_settings("output volume:missing value, input volume:missing value, alert volume:100, output muted:missing value").is_err());
+    }
+
+    #[test]
+    fn test_parse_windows_volume() {
+        assert_eq!(parse_windows_volume("50%|False\r\n").unwrap(), VolumeState { volume: 50, muted: false });
+        assert_eq!(parse_windows_volume("7%|True").unwrap(), VolumeState { volume: 7, muted: true });
+        assert!(parse_windows_volume("").is_err());
+    }
+
+    #[test]
+    fn test_set_default_audio_device_rejects_flags() {
+        assert!(set_default_audio_device("--help".into(), AudioDeviceKind::Output).is_err());
+    }
+}
@@ -0,0 +1,272 @@
+// 电池和运行时间：Linux 读取 /sys/class/power_supply，macOS 使用 pmset，Windows 查询 Win32_Battery
+use crate::system_command::{powershell, run};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use sysinfo::System;
+
+const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+// Win32_Battery 在接通电源或无法估计时返回的 EstimatedRunTime
+const WINDOWS_UNKNOWN_RUNTIME: u64 = 71_582_788;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChargeState {
+    Charging,
+    Discharging,
+    Full,
+    // 接通电源但未充电（例如达到充电上限）
+    Idle,
+    Unknown,
+}
+
+// time_remaining_secs 在放电时为剩余使用时间，充电时为充满所需时间
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BatteryStatus {
+    pub percentage: f32,
+    pub state: ChargeState,
+    pub time_remaining_secs: Option<u64>,
+}
+
+// 台式机等没有电池时 battery 为空
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PowerStatus {
+    pub battery: Option<BatteryStatus>,
+    pub uptime_secs: u64,
+    pub boot_time: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Win32Battery {
+    estimated_charge_remaining: Option<f32>,
+    battery_status: Option<u16>,
+    estimated_run_time: Option<u64>,
+}
+
+#[tauri::command(async)]
+pub fn get_power_status() -> Result<PowerStatus, String> {
+    let battery = match std::env::consts::OS {
+        "linux" => linux_battery(Path::new(POWER_SUPPLY_DIR)),
+        "macos" => parse_pmset(&run("pmset", &["-g", "batt"])?),
+        "windows" => parse_win32_battery(&powershell(
+            "ConvertTo-Json -InputObject @(Get-CimInstance Win32_Battery | Select-Object EstimatedChargeRemaining,BatteryStatus,EstimatedRunTime)",
+        )?)?,
+        os => return Err(format!("当前系统不支持查询电池状态: {}", os)),
+    };
+
+    Ok(PowerStatus {
+        battery,
+        uptime_secs: System::uptime(),
+        boot_time: System::boot_time(),
+    })
+}
+
+// 合并所有系统电池（scope 为 Device 的是鼠标、手柄等外设电池）
+fn linux_battery(dir: &Path) -> Option<BatteryStatus> {
+    let read = |path: &Path, name: &str| fs::read_to_string(path.join(name)).ok().map(|value| value.trim().to_string());
+    let number = |path: &Path, name: &str| read(path, name).and_then(|value| value.parse::<f64>().ok());
+
+    let mut supplies: Vec<_> = fs::read_dir(dir).ok()?.flatten().map(|entry| entry.path()).collect();
+    supplies.sort();
+
+    let (mut now, mut full, mut rate) = (0.0, 0.0, 0.0);
+    let mut capacities = Vec::new();
+    let mut states = Vec::new();
+    for supply in supplies {
+        if read(&supply, "type").as_deref() != Some("Battery") || read(&supply, "scope").as_deref() == Some("Device") {
+            continue;
+        }
+        if read(&supply, "present").as_deref() == Some("0") {
+            continue;
+        }
+
+        // 有的电池只提供 charge_*（µAh、µA），有的提供 energy_*（µWh、µW）
+        let energy = number(&supply, "energy_now").zip(number(&supply, "energy_full")).map(|pair| (pair, number(&supply, "power_now")));
+        let charge = number(&supply, "charge_now").zip(number(&supply, "charge_full")).map(|pair| (pair, number(&supply, "current_now")));
+        if let Some(((battery_now, battery_full), battery_rate)) = energy.or(charge) {
+            now += battery_now;
+            full += battery_full;
+            rate += battery_rate.unwrap_or(0.0).abs();
+        }
+        if let Some(capacity) = number(&supply, "capacity") {
+            capacities.push(capacity);
+        }
+        states.push(match read(&supply, "status").as_deref() {
+            Some("Charging") => ChargeState::Charging,
+            Some("Discharging") => ChargeState::Discharging,
+            Some("Full") => ChargeState::Full,
+            Some("Not charging") => ChargeState::Idle,
+            _ => ChargeState::Unknown,
+        });
+    }
+    if states.is_empty() {
+        return None;
+    }
+
+    let percentage = if full > 0.0 {
+        now / full * 100.0
+    } else if !capacities.is_empty() {
+        capacities.iter().sum::<f64>() / capacities.len() as f64
+    } else {
+        return None;
+    };
+    // 任一电池在充电或放电即以此为准
+    let state = [ChargeState::Charging, ChargeState::Discharging]
+        .into_iter()
+        .find(|state| states.contains(state))
+        .unwrap_or(states[0]);
+    let hours = match state {
+        ChargeState::Discharging if rate > 0.0 => Some(now / rate),
+        ChargeState::Charging if rate > 0.0 => Some((full - now).max(0.0) / rate),
+        _ => None,
+    };
+
+    Some(BatteryStatus {
+        percentage: percentage.clamp(0.0, 100.0) as f32,
+        state,
+        time_remaining_secs: hours.map(|hours| (hours * 3600.0) as u64),
+    })
+}
+
+// pmset -g batt 输出示例：
+//  -InternalBattery-0 (id=4653155)	85%; discharging; 4:32 remaining present: true
+fn parse_pmset(output: &str) -> Option<BatteryStatus> {
+    let line = output.lines().find(|line| line.contains("InternalBattery"))?;
+    let details = line.split_once('\t').map_or(line, |(_, details)| details);
+    let mut parts = details.split(';').map(str::trim);
+
+    let percentage = parts.next()?.trim_end_matches('%').parse().ok()?;
+    let state = match parts.next()? {
+        "charging" => ChargeState::Charging,
+        "discharging" => ChargeState::Discharging,
+        "charged" => ChargeState::Full,
+        "AC attached" | "finishing charge" => ChargeState::Idle,
+        _ => ChargeState::Unknown,
+    };
+    // 没有估计值时为 (no estimate)，已充满时为 0:00
+    let time_remaining_secs = parts.next()
+        .and_then(|remaining| remaining.split_whitespace().next())
+        .and_then(|time| time.split_once(':'))
+        .and_then(|(hours, minutes)| Some(hours.parse::<u64>().ok()? * 3600 + minutes.parse::<u64>().ok()? * 60))
+        .filter(|secs| *secs > 0 && matches!(state, ChargeState::Charging | ChargeState::Discharging));
+
+    Some(BatteryStatus { percentage, state, time_remaining_secs })
+}
+
+// BatteryStatus：1 放电，2 接通电源，3 充满，6-9 充电
+fn parse_win32_battery(json: &str) -> Result<Option<BatteryStatus>, String> {
+    if json.trim().is_empty() {
+        return Ok(None);
+    }
+    let batteries: Vec<Win32Battery> = serde_json::from_str(json).map_err(|e| format!("解析电池信息失败: {}", e))?;
+    let Some(battery) = batteries.into_iter().find(|battery| battery.estimated_charge_remaining.is_some()) else {
+        return Ok(None);
+    };
+
+    let state = match battery.battery_status {
+        Some(1) => ChargeState::Discharging,
+        Some(2) => ChargeState::Idle,
+        Some(3) => ChargeState::Full,
+        Some(6..=9) => ChargeState::Charging,
+        _ => ChargeState::Unknown,
+    };
+    let time_remaining_secs = battery.estimated_run_time
+        .filter(|minutes| *minutes != WINDOWS_UNKNOWN_RUNTIME && state == ChargeState::Discharging)
+        .map(|minutes| minutes * 60);
+
+    Ok(Some(BatteryStatus {
+        percentage: battery.estimated_charge_remaining.unwrap_or_default(),
+        state,
+        time_remaining_secs,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_supply(dir: &Path, name: &str, files: &[(&str, &str)]) {
+        let supply = dir.join(name);
+        fs::create_dir_all(&supply).unwrap();
+        for (file, value) in files {
+            fs::write(supply.join(file), format!("{}\n", value)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_linux_battery_energy() {
+        let dir = TempDir::new().unwrap();
+        write_supply(dir.path(), "AC", &[("type", "Mains"), ("online", "0")]);
+        write_supply(dir.path(), "BAT0", &[
+            ("type", "Battery"), ("present", "1"), ("status", "Discharging"), ("capacity", "50"),
+            ("energy_now", "25000000"), ("energy_full", "50000000"), ("power_now", "10000000"),
+        ]);
+        // 无线鼠标的电池不计入
+        write_supply(dir.path(), "hidpp_battery_0", &[("type", "Battery"), ("scope", "Device"), ("capacity", "5"), ("status", "Discharging")]);
+
+        let battery = linux_battery(dir.path()).unwrap();
+        assert_eq!(battery.percentage, 50.0);
+        assert_eq!(battery.state, ChargeState::Discharging);
+        assert_eq!(battery.time_remaining_secs, Some(9000));
+    }
+
+    #[test]
+    fn test_linux_battery_charge_and_capacity() {
+        let dir = TempDir::new().unwrap();
+        write_supply(dir.path(), "BAT1", &[
+            ("type", "Battery"), ("status", "Charging"),
+            ("charge_now", "3000000"), ("charge_full", "4000000"), ("current_now", "2000000"),
+        ]);
+        let battery = linux_battery(dir.path()).unwrap();
+        assert_eq!(battery.percentage, 75.0);
+        assert_eq!(battery.time_remaining_secs, Some(1800));
+
+        let dir = TempDir::new().unwrap();
+        write_supply(dir.path(), "BAT0", &[("type", "Battery"), ("status", "Not charging"), ("capacity", "80")]);
+        let battery = linux_battery(dir.path()).unwrap();
+        assert_eq!(battery.percentage, 80.0);
+        assert_eq!(battery.state, ChargeState::Idle);
+        assert_eq!(battery.time_remaining_secs, None);
+
+        let dir = TempDir::new().unwrap();
+        write_supply(dir.path(), "AC", &[("type", "Mains")]);
+        assert_eq!(linux_battery(dir.path()), None);
+    }
+
+    #[test]
+    fn test_parse_pmset() {
+        let output = "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=4653155)\t85%; discharging; 4:32 remaining present: true\n";
+        let battery = parse_pmset(output).unwrap();
+        assert_eq!(battery.percentage, 85.0);
+        assert_eq!(battery.state, ChargeState::Discharging);
+        assert_eq!(battery.time_remaining_secs, Some(4 * 3600 + 32 * 60));
+
+        let output = "Now drawing from 'AC Power'\n -InternalBattery-0 (id=4653155)\t100%; charged; 0:00 remaining present: true\n";
+        let battery = parse_pmset(output).unwrap();
+        assert_eq!(battery.state, ChargeState::Full);
+        assert_eq!(battery.time_remaining_secs, None);
+
+        let output = " -InternalBattery-0 (id=1)\t40%; charging; (no estimate) present: true";
+        assert_eq!(parse_pmset(output).unwrap().time_remaining_secs, None);
+        assert_eq!(parse_pmset("Now drawing from 'AC Power'\n"), None);
+    }
+
+    #[test]
+    fn test_parse_win32_battery() {
+        let json = r#"[{"EstimatedChargeRemaining": 64, "BatteryStatus": 1, "EstimatedRunTime": 95}]"#;
+        let battery = parse_win32_battery(json).unwrap().unwrap();
+        assert_eq!(battery.percentage, 64.0);
+        assert_eq!(battery.state, ChargeState::Discharging);
+        assert_eq!(battery.time_remaining_secs, Some(95 * 60));
+
+        let json = r#"[{"EstimatedChargeRemaining": 90, "BatteryStatus": 2, "EstimatedRunTime": 71582788}]"#;
+        let battery = parse_win32_battery(json).unwrap().unwrap();
+        assert_eq!(battery.state, ChargeState::Idle);
+        assert_eq!(battery.time_remaining_secs, None);
+
+        assert_eq!(parse_win32_battery("[]").unwrap(), None);
+        assert_eq!(parse_win32_battery("").unwrap(), None);
+    }
+}
@@ -0,0 +1,174 @@
+// 蓝牙设备：列出已配对设备并连接/断开（bluetoothctl / blueutil）
+use crate::system_command::run;
+use serde::{Deserialize, Serialize};
+
+// 蓝牙设备
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BluetoothDevice {
+    pub address: String,
+    pub name: String,
+    pub connected: bool,
+}
+
+// blueutil --format json 的输出
+#[derive(Debug, Deserialize)]
+struct BlueutilDevice {
+    address: String,
+    name: Option<String>,
+    #[serde(default)]
+    connected: bool,
+}
+
+#[tauri::command(async)]
+pub fn list_bluetooth_devices() -> Result<Vec<BluetoothDevice>, String> {
+    let mut devices = match std::env::consts::OS {
+        "linux" => {
+            // 旧版 bluez 没有 "devices Paired"
+            let paired = run("bluetoothctl", &["devices", "Paired"])
+                .or_else(|_| run("bluetoothctl", &["paired-devices"]))?;
+            let mut devices = parse_bluetoothctl_devices(&paired);
+
+            match run("bluetoothctl", &["devices", "Connected"]) {
+                Ok(output) => {
+                    let connected = parse_bluetoothctl_devices(&output);
+                    for device in &mut devices {
+                        device.connected = connected.iter().any(|c| c.address == device.address);
+                    }
+                }
+                Err(_) => {
+                    for device in &mut devices {
+                        device.connected = run("bluetoothctl", &["info", &device.address])
+                            .is_ok_and(|info| info.lines().any(|line| line.trim() == "Connected: yes"));
+                    }
+                }
+            }
+            devices
+        }
+        "macos" => parse_blueutil_devices(&run("blueutil", &["--paired", "--format", "json"]).map_err(blueutil_hint)?)?,
+        "windows" => return Err("Windows 暂不支持蓝牙设备管理，请在系统设置中操作".to_string()),
+        os => return Err(format!("当前系统不支持蓝牙设备管理: {}", os)),
+    };
+
+    devices.sort_by(|a, b| b.connected.cmp(&a.connected).then_with(|| a.name.cmp(&b.name)));
+    Ok(devices)
+}
+
+#[tauri::command(async)]
+pub fn connect_bluetooth(address: String) -> Result<(), String> {
+    set_connected(&address, true)
+}
+
+#[tauri::command(async)]
+pub fn disconnect_bluetooth(address: String) -> Result<(), String> {
+    set_connected(&address, false)
+}
+
+fn set_connected(address: &str, connect: bool) -> Result<(), String> {
+    // 只接受 MAC 地址，避免被当作命令参数解析
+    if !is_valid_address(address) {
+        return Err(format!("无效的蓝牙地址: {}", address));
+    }
+
+    match std::env::consts::OS {
+        "linux" => {
+            let output = run("bluetoothctl", &[if connect { "connect" } else { "disconnect" }, address])?;
+            // bluetoothctl 失败时也可能返回 0
+            if output.contains("Failed") || output.contains("not available") {
+                return Err(format!("蓝牙操作失败: {}", output.trim()));
+            }
+        }
+        "macos" => {
+            run("blueutil", &[if connect { "--connect" } else { "--disconnect" }, address]).map_err(blueutil_hint)?;
+        }
+        "windows" => return Err("Windows 暂不支持蓝牙设备管理，请在系统设置中操作".to_string()),
+        os => return Err(format!("当前系统不支持蓝牙设备管理: {}", os)),
+    }
+
+    Ok(())
+}
+
+fn blueutil_hint(error: String) -> String {
+    format!("{}（macOS 需要先安装 blueutil: brew install blueutil）", error)
+}
+
+// "AA:BB:CC:DD:EE:FF" 或 blueutil 使用的 "aa-bb-cc-dd-ee-ff"
+fn is_valid_address(address: &str) -> bool {
+    let parts: Vec<&str> = address.split([':', '-']).collect();
+    parts.len() == 6 && parts.iter().all(|part| part.len() == 2 && part.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+// 每行形如 "Device AA:BB:CC:DD:EE:FF WH-1000XM4"
+fn parse_bluetoothctl_devices(output: &str) -> Vec<BluetoothDevice> {
+    output.lines()
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix("Device ")?;
+            let (address, name) = rest.split_once(' ').unwrap_or((rest, rest));
+            is_valid_address(address).then(|| BluetoothDevice {
+                address: address.to_string(),
+                name: name.trim().to_string(),
+                connected: false,
+            })
+        })
+        .collect()
+}
+
+fn parse_blueutil_devices(output: &str) -> Result<Vec<BluetoothDevice>, String> {
+    let devices: Vec<BlueutilDevice> = serde_json::from_str(output)
+        .map_err(|e| format!("解析 blueutil 输出失败: {}", e))?;
+
+    Ok(devices.into_iter()
+        .map(|device| BluetoothDevice {
+            name: device.name.unwrap_or_else(|| device.address.clone()),
+            address: device.address,
+            connected: device.connected,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_address() {
+        assert!(is_valid_address("AA:BB:CC:DD:EE:FF"));
+        assert!(is_valid_address("a0-b1-c2-d3-e4-f5"));
+        assert!(!is_valid_address("AA:BB:CC:DD:EE"));
+        assert!(!is_valid_address("--help"));
+        assert!(!is_valid_address("GG:BB:CC:DD:EE:FF"));
+    }
+
+    #[test]
+    fn test_parse_bluetoothctl_devices() {
+        let output = "Device 38:18:4C:12:34:56 WH-1000XM4\nDevice 00:1A:7D:DA:71:13 Magic Keyboard\n[CHG] Controller 00:00:00:00:00:00 Powered: yes\n";
+        let devices = parse_bluetoothctl_devices(output);
+
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].address, "38:18:4C:12:34:56");
+        assert_eq!(devices[0].name, "WH-1000XM4");
+        assert_eq!(devices[1].name, "Magic Keyboard");
+    }
+
+    #[test]
+    fn test_parse_blueutil_devices() {
+        let output = r#"[{"address":"38-18-4c-12-34-56","name":"AirPods Pro","connected":true,"paired":true},
+            {"address":"00-1a-7d-da-71-13","name":null,"paired":true}]"#;
+        let devices = parse_blueutil_devices(output).unwrap();
+
+        assert_eq!(devices[0], BluetoothDevice {
+            address: "38-18-4c-12-34-56".into(),
+            name: "AirPods Pro".into(),
+            connected: true,
+        });
+        // 没有名称时显示地址
+        assert_eq!(devices[1].name, "00-1a-7d-da-71-13");
+        assert!(!devices[1].connected);
+
+        assert!(parse_blueutil_devices("not json").is_err());
+    }
+
+    #[test]
+    fn test_set_connected_rejects_invalid_address() {
+        assert!(connect_bluetooth("--power 0".into()).unwrap_err().contains("无效的蓝牙地址"));
+    }
+}
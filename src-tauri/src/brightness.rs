@@ -0,0 +1,88 @@
+// 屏幕亮度：读取和调节内置显示器亮度（brightnessctl / brightness / WMI）
+use crate::system_command::{powershell, run};
+
+#[tauri::command(async)]
+pub fn get_brightness() -> Result<u8, String> {
+    match std::env::consts::OS {
+        "linux" => parse_brightnessctl(&run("brightnessctl", &["-m"]).map_err(brightnessctl_hint)?),
+        "macos" => parse_macos_brightness(&run("brightness", &["-l"]).map_err(brightness_hint)?),
+        "windows" => {
+            let output = powershell("(Get-CimInstance -Namespace root/WMI -ClassName WmiMonitorBrightness | Select-Object -First 1).CurrentBrightness")?;
+            output.trim().parse::<u8>()
+                .map(|value| value.min(100))
+                .map_err(|_| "当前显示器不支持亮度调节".to_string())
+        }
+        os => Err(format!("当前系统不支持亮度调节: {}", os)),
+    }
+}
+
+// relative 为 true 时 value 为增量，结果限制在 0-100
+#[tauri::command(async)]
+pub fn set_brightness(value: i32, relative: bool) -> Result<u8, String> {
+    let target = if relative { get_brightness()? as i32 + value } else { value }.clamp(0, 100);
+
+    match std::env::consts::OS {
+        "linux" => {
+            run("brightnessctl", &["set", &format!("{}%", target)]).map_err(brightnessctl_hint)?;
+        }
+        "macos" => {
+            run("brightness", &[&format!("{:.2}", target as f32 / 100.0)]).map_err(brightness_hint)?;
+        }
+        "windows" => {
+            let script = format!(
+                "Get-CimInstance -Namespace root/WMI -ClassName WmiMonitorBrightnessMethods | Invoke-CimMethod -MethodName WmiSetBrightness -Arguments @{{Timeout=0; Brightness={}}} | Out-Null",
+                target
+            );
+            powershell(&script)?;
+        }
+        os => return Err(format!("当前系统不支持亮度调节: {}", os)),
+    }
+
+    Ok(target as u8)
+}
+
+fn brightnessctl_hint(error: String) -> String {
+    format!("{}（Linux 需要先安装 brightnessctl）", error)
+}
+
+fn brightness_hint(error: String) -> String {
+    format!("{}（macOS 需要先安装 brightness: brew install brightness）", error)
+}
+
+// brightnessctl -m 输出 "intel_backlight,backlight,19200,40%,48000"
+fn parse_brightnessctl(output: &str) -> Result<u8, String> {
+    output.lines()
+        .next()
+        .and_then(|line| line.split(',').nth(3))
+        .and_then(|value| value.trim().trim_end_matches('%').parse::<u8>().ok())
+        .map(|value| value.min(100))
+        .ok_or_else(|| format!("无法解析亮度: {}", output.trim()))
+}
+
+// brightness -l 输出 "display 0: brightness 0.750000"，取第一个内置显示器
+fn parse_macos_brightness(output: &str) -> Result<u8, String> {
+    output.lines()
+        .find_map(|line| line.split_once(": brightness "))
+        .and_then(|(_, value)| value.trim().parse::<f32>().ok())
+        .map(|value| (value * 100.0).round().clamp(0.0, 100.0) as u8)
+        .ok_or_else(|| "当前显示器不支持亮度调节".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_brightnessctl() {
+        assert_eq!(parse_brightnessctl("intel_backlight,backlight,19200,40%,48000\n").unwrap(), 40);
+        assert!(parse_brightnessctl("").is_err());
+    }
+
+    #[test]
+    fn test_parse_macos_brightness() {
+        let output = "display 0: main, active, awake, online, built-in, ID 0x1\ndisplay 0: brightness 0.753906\n";
+        assert_eq!(parse_macos_brightness(output).unwrap(), 75);
+        // 外接显示器没有亮度信息
+        assert!(parse_macos_brightness("display 0: main, active, awake, online, external, ID 0x2\n").is_err());
+    }
+}
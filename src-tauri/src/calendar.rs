@@ -0,0 +1,648 @@
+// 日历事件：读取本地 ICS 文件，回答"下一个会议是什么"并支持搜索即将到来的事件
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_DAYS_AHEAD: i64 = 14;
+const MAX_RESULTS: usize = 50;
+// 展开重复事件时的最大次数，防止无限规则
+const MAX_OCCURRENCES: usize = 5000;
+// 扫描日历目录的最大深度
+const MAX_SCAN_DEPTH: usize = 6;
+
+// 视频会议链接的域名
+const MEETING_HOSTS: &[&str] = &[
+    "zoom.us",
+    "meet.google.com",
+    "teams.microsoft.com",
+    "teams.live.com",
+    "webex.com",
+    "whereby.com",
+    "meet.jit.si",
+    "chime.aws",
+    "gotomeeting.com",
+    "meeting.tencent.com",
+    "meeting.feishu.cn",
+];
+
+// 日历事件（一次具体的发生）
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CalendarEvent {
+    pub uid: String,
+    pub title: String,
+    pub start: i64, // 秒级时间戳
+    pub end: i64,
+    pub all_day: bool,
+    pub location: Option<String>,
+    pub meeting_url: Option<String>,
+    pub calendar: String,
+}
+
+#[tauri::command(async)]
+pub fn upcoming_events(
+    query: Option<String>,
+    ics_paths: Option<Vec<String>>,
+    days_ahead: Option<i64>,
+) -> Result<Vec<CalendarEvent>, String> {
+    let now = Utc::now().timestamp();
+    let window_end = now + days_ahead.unwrap_or(DEFAULT_DAYS_AHEAD).clamp(1, 366) * 24 * 60 * 60;
+
+    let mut events = Vec::new();
+    for file in calendar_files(&ics_paths.unwrap_or_default()) {
+        let Ok(content) = fs::read_to_string(&file) else {
+            continue;
+        };
+        let calendar = file.file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        events.extend(collect_events(&content, &calendar, now, window_end));
+    }
+
+    let query = query.unwrap_or_default().trim().to_lowercase();
+    if !query.is_empty() {
+        events.retain(|event| {
+            event.title.to_lowercase().contains(&query)
+                || event.location.as_deref().is_some_and(|l| l.to_lowercase().contains(&query))
+        });
+    }
+
+    events.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.title.cmp(&b.title)));
+    // 同一事件可能同时出现在多个日历文件中
+    let mut seen = HashSet::new();
+    events.retain(|event| seen.insert((event.uid.clone(), event.start)));
+    events.truncate(MAX_RESULTS);
+
+    Ok(events)
+}
+
+// 配置的 ICS 文件/目录，以及各平台的本地日历缓存
+// Windows 的 Outlook 日历需要 COM 接口，这里只支持导出的 ICS 文件
+fn calendar_files(paths: &[String]) -> Vec<PathBuf> {
+    let mut roots: Vec<PathBuf> = paths.iter()
+        .map(|path| expand_home(path.trim()))
+        .collect();
+
+    if cfg!(target_os = "macos") {
+        if let Some(home) = dirs::home_dir() {
+            roots.push(home.join("Library").join("Calendars"));
+        }
+    }
+
+    let mut files = Vec::new();
+    for root in roots {
+        collect_ics_files(&root, 0, &mut files);
+    }
+    files
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+fn collect_ics_files(path: &Path, depth: usize, files: &mut Vec<PathBuf>) {
+    if path.is_file() {
+        if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("ics")) {
+            files.push(path.to_path_buf());
+        }
+        return;
+    }
+
+    if depth >= MAX_SCAN_DEPTH {
+        return;
+    }
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            collect_ics_files(&entry.path(), depth + 1, files);
+        }
+    }
+}
+
+// 事件时间所在的时区
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EventZone {
+    Utc,
+    Named(Tz),
+    Floating, // 未指定时区，按本地时间处理
+}
+
+impl EventZone {
+    fn timestamp(&self, datetime: NaiveDateTime) -> Option<i64> {
+        match self {
+            EventZone::Utc => Some(datetime.and_utc().timestamp()),
+            EventZone::Named(tz) => tz.from_local_datetime(&datetime).earliest().map(|dt| dt.timestamp()),
+            EventZone::Floating => Local.from_local_datetime(&datetime).earliest().map(|dt| dt.timestamp()),
+        }
+    }
+}
+
+// ICS 中的日期时间属性
+#[derive(Debug, Clone, PartialEq)]
+struct IcsTime {
+    datetime: NaiveDateTime,
+    zone: EventZone,
+    all_day: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Recurrence {
+    freq: Frequency,
+    interval: u32,
+    count: Option<usize>,
+    until: Option<i64>,
+    by_day: Vec<Weekday>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Default)]
+struct RawEvent {
+    uid: String,
+    summary: String,
+    location: Option<String>,
+    description: Option<String>,
+    url: Option<String>,
+    start: Option<IcsTime>,
+    end: Option<IcsTime>,
+    duration: Option<Duration>,
+    rrule: Option<Recurrence>,
+    exdates: Vec<i64>,
+    recurrence_id: Option<i64>,
+    cancelled: bool,
+}
+
+// 解析 ICS 内容并展开 [window_start, window_end) 内的事件（包括正在进行的事件）
+fn collect_events(content: &str, calendar: &str, window_start: i64, window_end: i64) -> Vec<CalendarEvent> {
+    let raw_events = parse_ics(content);
+
+    // 被单独修改过的重复事件实例，主事件中需要跳过
+    let overridden: HashSet<(String, i64)> = raw_events.iter()
+        .filter_map(|event| event.recurrence_id.map(|id| (event.uid.clone(), id)))
+        .collect();
+
+    let mut events = Vec::new();
+    for raw in raw_events.iter().filter(|event| !event.cancelled) {
+        let Some(start) = &raw.start else {
+            continue;
+        };
+
+        let length = match (&raw.end, raw.duration) {
+            (Some(end), _) => match (start.zone.timestamp(start.datetime), end.zone.timestamp(end.datetime)) {
+                (Some(s), Some(e)) if e >= s => Duration::seconds(e - s),
+                _ => Duration::zero(),
+            },
+            (None, Some(duration)) => duration,
+            (None, None) if start.all_day => Duration::days(1),
+            (None, None) => Duration::zero(),
+        };
+
+        let meeting_url = [&raw.url, &raw.location, &raw.description]
+            .iter()
+            .filter_map(|field| field.as_deref())
+            .find_map(find_meeting_url);
+
+        for occurrence in occurrences(start, raw.rrule.as_ref(), window_end) {
+            let Some(begin) = start.zone.timestamp(occurrence) else {
+                continue;
+            };
+            let end = begin + length.num_seconds();
+            if begin >= window_end || (begin < window_start && end <= window_start) {
+                continue;
+            }
+            if raw.rrule.is_some() && (raw.exdates.contains(&begin) || overridden.contains(&(raw.uid.clone(), begin))) {
+                continue;
+            }
+
+            events.push(CalendarEvent {
+                uid: raw.uid.clone(),
+                title: if raw.summary.is_empty() { "(无标题)".to_string() } else { raw.summary.clone() },
+                start: begin,
+                end,
+                all_day: start.all_day,
+                location: raw.location.clone(),
+                meeting_url: meeting_url.clone(),
+                calendar: calendar.to_string(),
+            });
+        }
+    }
+
+    events
+}
+
+// 按重复规则生成事件开始时间（事件时区下的本地时间），直到超过 window_end
+fn occurrences(start: &IcsTime, rule: Option<&Recurrence>, window_end: i64) -> Vec<NaiveDateTime> {
+    let Some(rule) = rule else {
+        return vec![start.datetime];
+    };
+
+    let first = start.datetime;
+    let interval = rule.interval.max(1) as i64;
+    let mut result = Vec::new();
+    let mut emitted = 0;
+
+    for step in 0.. {
+        let candidates: Vec<NaiveDateTime> = match rule.freq {
+            Frequency::Daily => vec![first + Duration::days(step * interval)],
+            Frequency::Weekly if rule.by_day.is_empty() => vec![first + Duration::weeks(step * interval)],
+            Frequency::Weekly => {
+                let week_start = first.date()
+                    - Duration::days(first.weekday().num_days_from_monday() as i64)
+                    + Duration::weeks(step * interval);
+                let mut days: Vec<NaiveDateTime> = rule.by_day.iter()
+                    .map(|day| (week_start + Duration::days(day.num_days_from_monday() as i64)).and_time(first.time()))
+                    .filter(|datetime| *datetime >= first)
+                    .collect();
+                days.sort();
+                days
+            }
+            Frequency::Monthly => add_months(first, step * interval).into_iter().collect(),
+            Frequency::Yearly => add_months(first, step * interval * 12).into_iter().collect(),
+        };
+
+        let mut past_window = false;
+        for candidate in candidates {
+            let Some(timestamp) = start.zone.timestamp(candidate) else {
+                continue;
+            };
+            if rule.until.is_some_and(|until| timestamp > until) || timestamp >= window_end {
+                past_window = true;
+                break;
+            }
+            if rule.count.is_some_and(|count| emitted >= count) {
+                return result;
+            }
+            emitted += 1;
+            result.push(candidate);
+        }
+
+        if past_window || emitted >= MAX_OCCURRENCES || step as usize >= MAX_OCCURRENCES {
+            break;
+        }
+    }
+
+    result
+}
+
+// 加月份，跳过不存在的日期（例如 2 月 30 日）
+fn add_months(datetime: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let total = datetime.year() as i64 * 12 + datetime.month0() as i64 + months;
+    let date = NaiveDate::from_ymd_opt((total / 12) as i32, (total % 12) as u32 + 1, datetime.day())?;
+    Some(date.and_time(datetime.time()))
+}
+
+// 解析 ICS 文本中的 VEVENT
+fn parse_ics(content: &str) -> Vec<RawEvent> {
+    let mut events = Vec::new();
+    let mut current: Option<RawEvent> = None;
+
+    for line in unfold_lines(content) {
+        let Some((name_and_params, value)) = line.split_once(':') else {
+            continue;
+        };
+        let mut parts = name_and_params.split(';');
+        let name = parts.next().unwrap_or_default().to_ascii_uppercase();
+        let params: Vec<(String, String)> = parts
+            .filter_map(|param| param.split_once('='))
+            .map(|(key, value)| (key.to_ascii_uppercase(), value.trim_matches('"').to_string()))
+            .collect();
+
+        match (name.as_str(), value) {
+            ("BEGIN", "VEVENT") => current = Some(RawEvent::default()),
+            ("END", "VEVENT") => events.extend(current.take()),
+            _ => {
+                let Some(event) = current.as_mut() else {
+                    continue;
+                };
+                match name.as_str() {
+                    "UID" => event.uid = value.to_string(),
+                    "SUMMARY" => event.summary = unescape(value),
+                    "LOCATION" => event.location = Some(unescape(value)).filter(|l| !l.is_empty()),
+                    "DESCRIPTION" => event.description = Some(unescape(value)),
+                    "URL" => event.url = Some(value.to_string()),
+                    "DTSTART" => event.start = parse_ics_time(value, &params),
+                    "DTEND" => event.end = parse_ics_time(value, &params),
+                    "DURATION" => event.duration = parse_ics_duration(value),
+                    "RRULE" => event.rrule = parse_rrule(value),
+                    "STATUS" => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+                    "EXDATE" => event.exdates.extend(value.split(',').filter_map(|v| {
+                        parse_ics_time(v, &params).and_then(|time| time.zone.timestamp(time.datetime))
+                    })),
+                    "RECURRENCE-ID" => event.recurrence_id = parse_ics_time(value, &params)
+                        .and_then(|time| time.zone.timestamp(time.datetime)),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    events
+}
+
+// 折行：以空格或制表符开头的行是上一行的延续
+fn unfold_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.trim_end_matches('\r').to_string()),
+        }
+    }
+    lines.iter_mut().for_each(|line| *line = line.trim_end_matches('\r').to_string());
+    lines
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => {}
+        }
+    }
+    result.trim().to_string()
+}
+
+fn parse_ics_time(value: &str, params: &[(String, String)]) -> Option<IcsTime> {
+    let value = value.trim();
+    let param = |key: &str| params.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str());
+
+    if param("VALUE") == Some("DATE") || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some(IcsTime {
+            datetime: date.and_time(NaiveTime::MIN),
+            zone: EventZone::Floating,
+            all_day: true,
+        });
+    }
+
+    let (text, zone) = match value.strip_suffix('Z') {
+        Some(text) => (text, EventZone::Utc),
+        // 无法识别的时区名（例如 Outlook 的 Windows 时区）按本地时间处理
+        None => (value, param("TZID")
+            .and_then(|tzid| tzid.parse::<Tz>().ok())
+            .map(EventZone::Named)
+            .unwrap_or(EventZone::Floating)),
+    };
+
+    let datetime = NaiveDateTime::parse_from_str(text, "%Y%m%dT%H%M%S").ok()?;
+    Some(IcsTime { datetime, zone, all_day: false })
+}
+
+// 解析 "PT1H30M"、"P1D"、"P2W" 形式的时长
+fn parse_ics_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let value = value.strip_prefix('P')?;
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    for c in value.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match unit {
+                    'W' => Duration::weeks(n),
+                    'D' => Duration::days(n),
+                    'H' => Duration::hours(n),
+                    'M' => Duration::minutes(n),
+                    'S' => Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+
+    Some(if negative { -total } else { total })
+}
+
+fn parse_rrule(value: &str) -> Option<Recurrence> {
+    let mut rule = Recurrence {
+        freq: Frequency::Daily,
+        interval: 1,
+        count: None,
+        until: None,
+        by_day: Vec::new(),
+    };
+    let mut has_freq = false;
+
+    for part in value.split(';') {
+        let (key, val) = part.split_once('=')?;
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                has_freq = true;
+                rule.freq = match val.to_ascii_uppercase().as_str() {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "YEARLY" => Frequency::Yearly,
+                    // 不支持的频率（如 HOURLY）只保留第一次
+                    _ => return None,
+                };
+            }
+            "INTERVAL" => rule.interval = val.parse().ok()?,
+            "COUNT" => rule.count = val.parse().ok(),
+            "UNTIL" => rule.until = parse_ics_time(val, &[]).and_then(|time| time.zone.timestamp(time.datetime)
+                // 只有日期的 UNTIL 包含当天
+                .map(|ts| if time.all_day { ts + 24 * 60 * 60 - 1 } else { ts })),
+            "BYDAY" => rule.by_day = val.split(',').filter_map(parse_ics_weekday).collect(),
+            _ => {}
+        }
+    }
+
+    has_freq.then_some(rule)
+}
+
+// BYDAY 中的 "MO"、"TU"（忽略 "1MO" 这样的序号）
+fn parse_ics_weekday(value: &str) -> Option<Weekday> {
+    let code = value.trim_start_matches(|c: char| c.is_ascii_digit() || c == '-' || c == '+');
+    match code.to_ascii_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+// 从文本中找出视频会议链接
+fn find_meeting_url(text: &str) -> Option<String> {
+    text.match_indices("https://")
+        .map(|(index, _)| {
+            let rest = &text[index..];
+            let end = rest.find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '\'' | ')' | ']'))
+                .unwrap_or(rest.len());
+            rest[..end].trim_end_matches(['.', ',', ';'])
+        })
+        .find(|url| {
+            let host = url["https://".len()..].split(['/', '?', '#']).next().unwrap_or_default();
+            MEETING_HOSTS.iter().any(|meeting| host == *meeting || host.ends_with(&format!(".{}", meeting)))
+        })
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    fn ts(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> i64 {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0).unwrap().timestamp()
+    }
+
+    fn calendar(body: &str) -> String {
+        format!("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n{}END:VCALENDAR\r\n", body)
+    }
+
+    #[test]
+    fn test_single_event_with_meeting_link() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Design review\r\nDTSTART:20240529T140000Z\r\nDTEND:20240529T150000Z\r\n\
+             LOCATION:Room 4\r\nDESCRIPTION:Join: https://acme.zoom.us/j/123?pwd=x\\nAgenda\r\nEND:VEVENT\r\n",
+        );
+        let events = collect_events(&ics, "work", ts(2024, 5, 29, 0, 0), ts(2024, 6, 5, 0, 0));
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].title, "Design review");
+        assert_eq!(events[0].start, ts(2024, 5, 29, 14, 0));
+        assert_eq!(events[0].end, ts(2024, 5, 29, 15, 0));
+        assert_eq!(events[0].location.as_deref(), Some("Room 4"));
+        assert_eq!(events[0].meeting_url.as_deref(), Some("https://acme.zoom.us/j/123?pwd=x"));
+        assert_eq!(events[0].calendar, "work");
+    }
+
+    #[test]
+    fn test_events_outside_window_skipped() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:past\r\nSUMMARY:Old\r\nDTSTART:20240501T100000Z\r\nDTEND:20240501T110000Z\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:ongoing\r\nSUMMARY:Now\r\nDTSTART:20240529T090000Z\r\nDTEND:20240529T110000Z\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:later\r\nSUMMARY:Far\r\nDTSTART:20240801T100000Z\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:cancel\r\nSUMMARY:X\r\nSTATUS:CANCELLED\r\nDTSTART:20240530T100000Z\r\nEND:VEVENT\r\n",
+        );
+        let events = collect_events(&ics, "work", ts(2024, 5, 29, 10, 0), ts(2024, 6, 5, 0, 0));
+
+        // 正在进行的事件保留
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].uid, "ongoing");
+    }
+
+    #[test]
+    fn test_weekly_recurrence_with_byday_and_exdate() {
+        // 2024-05-27 是周一
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:standup\r\nSUMMARY:Standup\r\nDTSTART;TZID=Europe/Berlin:20240527T093000\r\n\
+             DURATION:PT15M\r\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR\r\nEXDATE;TZID=Europe/Berlin:20240529T093000\r\nEND:VEVENT\r\n",
+        );
+        let events = collect_events(&ics, "work", ts(2024, 5, 27, 0, 0), ts(2024, 6, 4, 0, 0));
+        let starts: Vec<i64> = events.iter().map(|e| e.start).collect();
+
+        // 柏林夏令时 UTC+2
+        assert_eq!(starts, vec![
+            ts(2024, 5, 27, 7, 30),
+            ts(2024, 5, 31, 7, 30),
+            ts(2024, 6, 3, 7, 30),
+        ]);
+        assert_eq!(events[0].end - events[0].start, 15 * 60);
+    }
+
+    #[test]
+    fn test_recurrence_count_until_and_override() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:daily\r\nSUMMARY:Daily\r\nDTSTART:20240527T080000Z\r\nRRULE:FREQ=DAILY;COUNT=3\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:monthly\r\nSUMMARY:Rent\r\nDTSTART:20240131T080000Z\r\nRRULE:FREQ=MONTHLY;UNTIL=20240601T000000Z\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:daily\r\nSUMMARY:Daily moved\r\nRECURRENCE-ID:20240528T080000Z\r\nDTSTART:20240528T120000Z\r\nEND:VEVENT\r\n",
+        );
+        let events = collect_events(&ics, "home", ts(2024, 1, 1, 0, 0), ts(2024, 12, 31, 0, 0));
+        let titles: Vec<(&str, i64)> = events.iter().map(|e| (e.title.as_str(), e.start)).collect();
+
+        assert!(titles.contains(&("Daily", ts(2024, 5, 27, 8, 0))));
+        assert!(!titles.contains(&("Daily", ts(2024, 5, 28, 8, 0))));
+        assert!(titles.contains(&("Daily moved", ts(2024, 5, 28, 12, 0))));
+        assert!(titles.contains(&("Daily", ts(2024, 5, 29, 8, 0))));
+        assert!(!titles.contains(&("Daily", ts(2024, 5, 30, 8, 0))));
+
+        // 31 日只在有 31 日的月份出现
+        let rent: Vec<i64> = events.iter().filter(|e| e.uid == "monthly").map(|e| e.start).collect();
+        assert_eq!(rent, vec![ts(2024, 1, 31, 8, 0), ts(2024, 3, 31, 8, 0), ts(2024, 5, 31, 8, 0)]);
+    }
+
+    #[test]
+    fn test_unfold_and_unescape() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:fold\r\nSUMMARY:Quarterly planning\\, part\r\n  two\r\nDTSTART:20240530T100000Z\r\nEND:VEVENT\r\n",
+        );
+        let events = collect_events(&ics, "work", ts(2024, 5, 29, 0, 0), ts(2024, 6, 5, 0, 0));
+        assert_eq!(events[0].title, "Quarterly planning, part two");
+    }
+
+    #[test]
+    fn test_all_day_event() {
+        let time = parse_ics_time("20240530", &[("VALUE".to_string(), "DATE".to_string())]).unwrap();
+        assert!(time.all_day);
+        assert_eq!(time.zone, EventZone::Floating);
+        assert_eq!(time.datetime, NaiveDate::from_ymd_opt(2024, 5, 30).unwrap().and_time(NaiveTime::MIN));
+    }
+
+    #[test]
+    fn test_parse_ics_duration() {
+        assert_eq!(parse_ics_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_ics_duration("P1D"), Some(Duration::days(1)));
+        assert_eq!(parse_ics_duration("P2W"), Some(Duration::weeks(2)));
+        assert_eq!(parse_ics_duration("-PT15M"), Some(Duration::minutes(-15)));
+        assert_eq!(parse_ics_duration("1H"), None);
+    }
+
+    #[test]
+    fn test_find_meeting_url() {
+        assert_eq!(
+            find_meeting_url("Link: <https://meet.google.com/abc-defg-hij>."),
+            Some("https://meet.google.com/abc-defg-hij".to_string())
+        );
+        assert_eq!(
+            find_meeting_url("https://example.com/agenda then https://teams.microsoft.com/l/meetup-join/1"),
+            Some("https://teams.microsoft.com/l/meetup-join/1".to_string())
+        );
+        assert_eq!(find_meeting_url("https://notzoom.us.example.com/x"), None);
+        assert_eq!(find_meeting_url("no links here"), None);
+    }
+
+    #[test]
+    fn test_calendar_files_scans_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let nested = temp_dir.path().join("nested");
+        fs::create_dir(&nested).unwrap();
+        File::create(nested.join("team.ics")).unwrap().write_all(b"BEGIN:VCALENDAR\nEND:VCALENDAR\n").unwrap();
+        File::create(temp_dir.path().join("notes.txt")).unwrap();
+
+        let mut files = Vec::new();
+        collect_ics_files(temp_dir.path(), 0, &mut files);
+        assert_eq!(files, vec![nested.join("team.ics")]);
+    }
+}
@@ -0,0 +1,267 @@
+// 剪贴板写入，支持敏感内容（密码等）在若干秒后自动清除；
+// 后台定时读取剪贴板，记住上一次复制的文本，供文本对比使用；剪贴板图片可保存为 PNG/JPEG 文件
+use arboard::Clipboard;
+use chrono::Local;
+use image::codecs::jpeg::JpegEncoder;
+use image::{ImageFormat, RgbImage, RgbaImage};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use std::thread;
+use std::time::Duration;
+use tauri::AppHandle;
+
+// 自动清除的最长等待时间（秒）
+const MAX_CLEAR_AFTER_SECS: u64 = 600;
+// 超过该长度的文本不记录
+const MAX_TRACKED_LEN: usize = 1024 * 1024;
+
+const JPEG_QUALITY: u8 = 90;
+
+pub(crate) const WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+static HISTORY: LazyLock<Mutex<ClipboardHistory>> = LazyLock::new(|| Mutex::new(ClipboardHistory::default()));
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFileFormat {
+    Png,
+    Jpeg,
+}
+
+impl ImageFileFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFileFormat::Png => "png",
+            ImageFileFormat::Jpeg => "jpg",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SavedImage {
+    pub path: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+// 只保存在内存中的最近两条文本
+#[derive(Debug, Default)]
+struct ClipboardHistory {
+    previous: Option<String>,
+    current: Option<String>,
+    // 设置了自动清除的敏感内容，不进入记录
+    sensitive: Option<String>,
+}
+
+impl ClipboardHistory {
+    fn observe(&mut self, text: String) {
+        if self.current.as_ref() == Some(&text) || text.len() > MAX_TRACKED_LEN {
+            return;
+        }
+        if self.sensitive.as_ref() == Some(&text) {
+            return;
+        }
+        self.previous = self.current.replace(text);
+    }
+
+    fn mark_sensitive(&mut self, text: &str) {
+        self.sensitive = Some(text.to_string());
+        if self.current.as_deref() == Some(text) {
+            self.current = None;
+        }
+        if self.previous.as_deref() == Some(text) {
+            self.previous = None;
+        }
+    }
+}
+
+#[tauri::command]
+pub fn copy_to_clipboard(text: String, clear_after_secs: Option<u64>) -> Result<(), String> {
+    write_text(&text)?;
+
+    if let Some(delay) = clear_delay(clear_after_secs) {
+        HISTORY.lock().unwrap().mark_sensitive(&text);
+        schedule_clear(text, delay);
+    }
+
+    Ok(())
+}
+
+// 读取剪贴板中的文本，剪贴板为空或不是文本时返回空
+#[tauri::command]
+pub fn read_clipboard_text() -> Result<Option<String>, String> {
+    let mut clipboard = Clipboard::new()
+        .map_err(|e| format!("打开剪贴板失败: {}", e))?;
+    Ok(clipboard.get_text().ok().filter(|text| !text.is_empty()))
+}
+
+// 把剪贴板中的图片保存到 folder（未设置时为图片目录下的 Clipboard），文件名带时间戳
+#[tauri::command(async)]
+pub fn save_clipboard_image(folder: Option<String>, format: ImageFileFormat) -> Result<SavedImage, String> {
+    let mut clipboard = Clipboard::new()
+        .map_err(|e| format!("打开剪贴板失败: {}", e))?;
+    let data = clipboard.get_image()
+        .map_err(|_| "剪贴板中没有图片".to_string())?;
+    let image = RgbaImage::from_raw(data.width as u32, data.height as u32, data.bytes.into_owned())
+        .ok_or("剪贴板图片数据无效")?;
+
+    let folder = match folder.filter(|folder| !folder.trim().is_empty()) {
+        Some(folder) => expand_home(folder.trim()),
+        None => dirs::picture_dir().or_else(dirs::home_dir).ok_or("无法确定保存目录")?.join("Clipboard"),
+    };
+    let path = write_image(&image, &folder, format)?;
+    Ok(SavedImage {
+        path: path.to_string_lossy().to_string(),
+        width: image.width(),
+        height: image.height(),
+    })
+}
+
+fn write_image(image: &RgbaImage, folder: &Path, format: ImageFileFormat) -> Result<PathBuf, String> {
+    fs::create_dir_all(folder).map_err(|e| format!("创建目录失败: {}", e))?;
+    let stem = format!("clipboard-{}", Local::now().format("%Y%m%d-%H%M%S"));
+    let mut path = folder.join(format!("{}.{}", stem, format.extension()));
+    let mut counter = 1;
+    while path.exists() {
+        path = folder.join(format!("{}-{}.{}", stem, counter, format.extension()));
+        counter += 1;
+    }
+
+    let result = match format {
+        ImageFileFormat::Png => image.save_with_format(&path, ImageFormat::Png),
+        ImageFileFormat::Jpeg => {
+            let file = fs::File::create(&path).map_err(|e| format!("创建文件失败: {}", e))?;
+            JpegEncoder::new_with_quality(BufWriter::new(file), JPEG_QUALITY).encode_image(&flatten(image))
+        }
+    };
+    result.map_err(|e| format!("保存图片失败: {}", e))?;
+    Ok(path)
+}
+
+// JPEG 不支持透明度，透明部分按白色背景合成
+fn flatten(image: &RgbaImage) -> RgbImage {
+    RgbImage::from_fn(image.width(), image.height(), |x, y| {
+        let [r, g, b, a] = image.get_pixel(x, y).0;
+        let blend = |channel: u8| ((channel as u16 * a as u16 + 255 * (255 - a as u16)) / 255) as u8;
+        image::Rgb([blend(r), blend(g), blend(b)])
+    })
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+// 当前和上一次复制的文本，后台还没有记录到当前内容时直接读取剪贴板
+pub(crate) fn recent_texts() -> Result<(Option<String>, Option<String>), String> {
+    if let Some(text) = read_clipboard_text()? {
+        HISTORY.lock().unwrap().observe(text);
+    }
+    let history = HISTORY.lock().unwrap();
+    Ok((history.previous.clone(), history.current.clone()))
+}
+
+// 定时任务：记录剪贴板中新出现的文本
+pub(crate) fn watch(_app_handle: &AppHandle) {
+    if let Ok(Some(text)) = read_clipboard_text() {
+        HISTORY.lock().unwrap().observe(text);
+    }
+}
+
+// 写入文本到系统剪贴板
+pub(crate) fn write_text(text: &str) -> Result<(), String> {
+    let mut clipboard = Clipboard::new()
+        .map_err(|e| format!("打开剪贴板失败: {}", e))?;
+    clipboard.set_text(text.to_string())
+        .map_err(|e| format!("写入剪贴板失败: {}", e))
+}
+
+// 计算自动清除延迟，0 或未设置表示不清除
+fn clear_delay(clear_after_secs: Option<u64>) -> Option<Duration> {
+    clear_after_secs
+        .filter(|&secs| secs > 0)
+        .map(|secs| Duration::from_secs(secs.min(MAX_CLEAR_AFTER_SECS)))
+}
+
+// 延迟清除剪贴板；如果期间用户复制了其他内容则保留
+fn schedule_clear(text: String, delay: Duration) {
+    thread::spawn(move || {
+        thread::sleep(delay);
+
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let unchanged = clipboard.get_text()
+                .map(|current| current == text)
+                .unwrap_or(false);
+            if unchanged {
+                let _ = clipboard.clear();
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_clear_delay() {
+        // 未设置或为0时不清除
+        assert_eq!(clear_delay(None), None);
+        assert_eq!(clear_delay(Some(0)), None);
+
+        // 正常延迟
+        assert_eq!(clear_delay(Some(30)), Some(Duration::from_secs(30)));
+
+        // 超长延迟被限制
+        assert_eq!(clear_delay(Some(100_000)), Some(Duration::from_secs(MAX_CLEAR_AFTER_SECS)));
+    }
+
+    #[test]
+    fn test_clipboard_history() {
+        let mut history = ClipboardHistory::default();
+        history.observe("a".to_string());
+        history.observe("a".to_string());
+        assert_eq!((history.previous.as_deref(), history.current.as_deref()), (None, Some("a")));
+
+        history.observe("b".to_string());
+        assert_eq!((history.previous.as_deref(), history.current.as_deref()), (Some("a"), Some("b")));
+
+        // 敏感内容不进入记录
+        history.mark_sensitive("secret");
+        history.observe("secret".to_string());
+        assert_eq!((history.previous.as_deref(), history.current.as_deref()), (Some("a"), Some("b")));
+
+        history.observe("x".repeat(MAX_TRACKED_LEN + 1));
+        assert_eq!(history.current.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn test_write_image() {
+        let dir = TempDir::new().unwrap();
+        let folder = dir.path().join("shots");
+        let image = RgbaImage::from_fn(4, 3, |x, _| image::Rgba([255, 0, 0, if x == 0 { 0 } else { 255 }]));
+
+        let png = write_image(&image, &folder, ImageFileFormat::Png).unwrap();
+        let again = write_image(&image, &folder, ImageFileFormat::Png).unwrap();
+        assert_ne!(png, again);
+        assert_eq!(image::open(&png).unwrap().to_rgba8(), image);
+
+        let jpeg = write_image(&image, &folder, ImageFileFormat::Jpeg).unwrap();
+        assert_eq!(jpeg.extension().unwrap(), "jpg");
+        assert_eq!(image::open(&jpeg).unwrap().width(), 4);
+    }
+
+    #[test]
+    fn test_flatten() {
+        let image = RgbaImage::from_raw(2, 1, vec![0, 0, 0, 0, 10, 20, 30, 255]).unwrap();
+        let flat = flatten(&image);
+        assert_eq!(flat.get_pixel(0, 0).0, [255, 255, 255]);
+        assert_eq!(flat.get_pixel(1, 0).0, [10, 20, 30]);
+    }
+}
@@ -0,0 +1,789 @@
+// 云盘：在 Google Drive、Dropbox 和 OneDrive 中按文件名搜索，在浏览器中打开或下载。
+// 通过 OAuth（PKCE + 本机回调）登录，使用用户自己在各平台注册的应用 ID；令牌保存在系统钥匙串中并自动刷新
+use crate::http::{self, RateLimiter, TtlCache};
+use crate::keyring;
+use chrono::Local;
+use rand::distributions::Alphanumeric;
+use rand::rngs::OsRng;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{LazyLock, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// 各平台的应用需要把 http://localhost:53682/ 登记为回调地址
+const REDIRECT_PORT: u16 = 53682;
+const REDIRECT_URI: &str = "http://localhost:53682/";
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+// 访问令牌在到期前一分钟刷新
+const EXPIRY_MARGIN_SECS: i64 = 60;
+const MAX_RESULTS: usize = 20;
+
+const GOOGLE_API: &str = "https://www.googleapis.com/drive/v3";
+const DROPBOX_API: &str = "https://api.dropboxapi.com/2";
+const DROPBOX_CONTENT_API: &str = "https://content.dropboxapi.com/2";
+const GRAPH_API: &str = "https://graph.microsoft.com/v1.0";
+
+static CACHE: LazyLock<TtlCache<Vec<CloudFile>>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(2 * 60)));
+static GOOGLE_LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(60, Duration::from_secs(60)));
+static DROPBOX_LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(60, Duration::from_secs(60)));
+static ONEDRIVE_LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(60, Duration::from_secs(60)));
+// 回调端口固定，同一时间只能进行一次登录
+type PendingLogin = (CloudProvider, Receiver<Result<(), String>>);
+static PENDING_LOGIN: LazyLock<Mutex<Option<PendingLogin>>> = LazyLock::new(|| Mutex::new(None));
+// 首次读取后缓存钥匙串中的令牌（None 表示未登录），避免每次搜索都调用钥匙串命令
+static TOKENS: LazyLock<Mutex<HashMap<CloudProvider, Option<StoredToken>>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum CloudProvider {
+    Google,
+    Dropbox,
+    OneDrive,
+}
+
+// url 为网页版中的地址；downloadable 为 false 时是文件夹或 Google 在线文档
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CloudFile {
+    pub provider: CloudProvider,
+    pub id: String,
+    pub name: String,
+    pub path: Option<String>,
+    pub url: String,
+    pub modified: Option<String>,
+    pub size: Option<u64>,
+    pub is_folder: bool,
+    pub downloadable: bool,
+}
+
+// 保存在钥匙串中，刷新令牌时需要登录时使用的应用 ID
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct StoredToken {
+    client_id: String,
+    client_secret: Option<String>,
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_at: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<i64>,
+}
+
+impl CloudProvider {
+    fn name(self) -> &'static str {
+        match self {
+            CloudProvider::Google => "Google Drive",
+            CloudProvider::Dropbox => "Dropbox",
+            CloudProvider::OneDrive => "OneDrive",
+        }
+    }
+
+    fn account(self) -> &'static str {
+        match self {
+            CloudProvider::Google => "cloud-google",
+            CloudProvider::Dropbox => "cloud-dropbox",
+            CloudProvider::OneDrive => "cloud-onedrive",
+        }
+    }
+
+    fn limiter(self) -> &'static RateLimiter {
+        match self {
+            CloudProvider::Google => &GOOGLE_LIMITER,
+            CloudProvider::Dropbox => &DROPBOX_LIMITER,
+            CloudProvider::OneDrive => &ONEDRIVE_LIMITER,
+        }
+    }
+
+    fn authorize_endpoint(self) -> &'static str {
+        match self {
+            CloudProvider::Google => "https://accounts.google.com/o/oauth2/v2/auth",
+            CloudProvider::Dropbox => "https://www.dropbox.com/oauth2/authorize",
+            CloudProvider::OneDrive => "https://login.microsoftonline.com/common/oauth2/v2.0/authorize",
+        }
+    }
+
+    fn token_endpoint(self) -> &'static str {
+        match self {
+            CloudProvider::Google => "https://oauth2.googleapis.com/token",
+            CloudProvider::Dropbox => "https://api.dropboxapi.com/oauth2/token",
+            CloudProvider::OneDrive => "https://login.microsoftonline.com/common/oauth2/v2.0/token",
+        }
+    }
+
+    // 只申请只读权限；Dropbox 的权限在应用控制台中配置
+    fn extra_params(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            CloudProvider::Google => &[
+                ("scope", "https://www.googleapis.com/auth/drive.readonly"),
+                ("access_type", "offline"),
+                ("prompt", "consent"),
+            ],
+            CloudProvider::Dropbox => &[("token_access_type", "offline")],
+            CloudProvider::OneDrive => &[("scope", "Files.Read offline_access")],
+        }
+    }
+}
+
+// 返回授权页面地址，由前端在浏览器中打开，随后调用 complete_cloud_login 等待回调
+#[tauri::command]
+pub fn start_cloud_login(provider: CloudProvider, client_id: String, client_secret: Option<String>) -> Result<String, String> {
+    let client_id = client_id.trim().to_string();
+    if client_id.is_empty() {
+        return Err(format!("请先在插件设置中填写 {} 应用 ID", provider.name()));
+    }
+    let client_secret = client_secret.map(|secret| secret.trim().to_string()).filter(|secret| !secret.is_empty());
+
+    let mut pending = PENDING_LOGIN.lock().unwrap();
+    let listener = TcpListener::bind(("127.0.0.1", REDIRECT_PORT))
+        .map_err(|e| format!("无法监听登录回调端口 {}（可能有登录正在进行）: {}", REDIRECT_PORT, e))?;
+
+    let state = random_string(32);
+    let verifier = random_string(64);
+    let url = authorize_url(provider, &client_id, &state, &pkce_challenge(&verifier));
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = wait_for_code(listener, &state, Instant::now() + LOGIN_TIMEOUT).and_then(|code| {
+            let token = tauri::async_runtime::block_on(exchange_code(provider, &client_id, client_secret.as_deref(), &code, &verifier))?;
+            save_token(provider, &token)
+        });
+        let _ = sender.send(result);
+    });
+    *pending = Some((provider, receiver));
+    Ok(url)
+}
+
+// 最多等待几分钟，在阻塞线程池中等待，不占用异步工作线程
+#[tauri::command]
+pub async fn complete_cloud_login(provider: CloudProvider) -> Result<(), String> {
+    let receiver = match PENDING_LOGIN.lock().unwrap().take() {
+        Some((pending, receiver)) if pending == provider => receiver,
+        _ => return Err(format!("没有正在进行的 {} 登录", provider.name())),
+    };
+    tauri::async_runtime::spawn_blocking(move || receiver.recv_timeout(LOGIN_TIMEOUT + Duration::from_secs(30)))
+        .await
+        .map_err(|e| format!("等待 {} 登录失败: {}", provider.name(), e))?
+        .unwrap_or_else(|_| Err("登录超时".to_string()))
+}
+
+#[tauri::command(async)]
+pub fn disconnect_cloud_drive(provider: CloudProvider) -> Result<(), String> {
+    keyring::delete_secret(provider.account())?;
+    TOKENS.lock().unwrap().insert(provider, None);
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn list_cloud_drives() -> Result<Vec<CloudProvider>, String> {
+    let mut connected = Vec::new();
+    for provider in [CloudProvider::Google, CloudProvider::Dropbox, CloudProvider::OneDrive] {
+        if stored_token(provider)?.is_some() {
+            connected.push(provider);
+        }
+    }
+    Ok(connected)
+}
+
+// 只搜索已登录的服务，各服务并行请求；某个服务出错时仍返回其他服务的结果
+#[tauri::command]
+pub async fn search_cloud_drives(query: String, providers: Vec<CloudProvider>) -> Result<Vec<CloudFile>, String> {
+    let query = query.trim().to_string();
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut connected = Vec::new();
+    for provider in providers {
+        if stored_token(provider)?.is_some() {
+            connected.push(provider);
+        }
+    }
+    if connected.is_empty() {
+        return Err("请先输入 cloud login google / dropbox / onedrive 登录云盘".to_string());
+    }
+
+    let handles: Vec<_> = connected
+        .into_iter()
+        .map(|provider| {
+            let query = query.clone();
+            tauri::async_runtime::spawn(async move {
+                let key = format!("{}:{}", provider.account(), query.to_lowercase());
+                CACHE.get_or_fetch_async(&key, || search_provider(provider, &query)).await
+            })
+        })
+        .collect();
+
+    let mut files = Vec::new();
+    let mut errors = Vec::new();
+    for handle in handles {
+        match handle.await.unwrap_or_else(|_| Err("搜索云盘失败".to_string())) {
+            Ok(found) => files.extend(found),
+            Err(error) => errors.push(error),
+        }
+    }
+    if files.is_empty() && !errors.is_empty() {
+        return Err(errors.join("；"));
+    }
+    Ok(files)
+}
+
+// 下载到下载目录，同名文件已存在时加序号，返回保存的路径
+#[tauri::command]
+pub async fn download_cloud_file(provider: CloudProvider, id: String, name: String) -> Result<String, String> {
+    let token = access_token(provider).await?;
+    let client = http::client()?;
+    provider.limiter().acquire(provider.name())?;
+    let request = match provider {
+        CloudProvider::Google => client
+            .get(format!("{}/files/{}", GOOGLE_API, percent_encode(&id)))
+            .query(&[("alt", "media")]),
+        // 参数放在请求头中，只能包含 ASCII，使用文件 ID 而不是路径
+        CloudProvider::Dropbox => client
+            .post(format!("{}/files/download", DROPBOX_CONTENT_API))
+            .header("Dropbox-API-Arg", serde_json::json!({ "path": id }).to_string()),
+        CloudProvider::OneDrive => client.get(format!("{}/me/drive/items/{}/content", GRAPH_API, percent_encode(&id))),
+    };
+    let response = request
+        .bearer_auth(&token)
+        .timeout(DOWNLOAD_TIMEOUT)
+        .send()
+        .await
+        .map_err(|e| format!("下载 {} 文件失败: {}", provider.name(), e))?;
+    let mut response = http::check_status(response, provider.name()).await?;
+
+    let dir = dirs::download_dir().or_else(dirs::home_dir).ok_or("无法确定保存目录")?;
+    let name = sanitize_file_name(&name);
+    // 边下载边写入，不把整个文件读进内存；文件在阻塞线程池中写入，不占用异步工作线程
+    let (sender, receiver) = mpsc::channel();
+    let writer = tauri::async_runtime::spawn_blocking(move || write_download(&dir, &name, receiver));
+    let mut result = Ok(());
+    loop {
+        match response.chunk().await {
+            Ok(Some(chunk)) => {
+                // 发送失败说明写入已出错，错误由写入线程返回
+                if sender.send(chunk).is_err() {
+                    break;
+                }
+            }
+            Ok(None) => break,
+            Err(error) => {
+                result = Err(error.to_string());
+                break;
+            }
+        }
+    }
+    drop(sender);
+
+    let written = writer.await.map_err(|e| e.to_string()).and_then(|written| written);
+    match (result, written) {
+        (Ok(()), Ok(path)) => Ok(path.to_string_lossy().to_string()),
+        (Err(error), Ok(path)) => {
+            let _ = tauri::async_runtime::spawn_blocking(move || fs::remove_file(path)).await;
+            Err(format!("下载 {} 文件失败: {}", provider.name(), error))
+        }
+        (_, Err(error)) => Err(format!("下载 {} 文件失败: {}", provider.name(), error)),
+    }
+}
+
+// 依次写入收到的数据块，发送端关闭即写完；写入失败时删除不完整的文件
+fn write_download<T: AsRef<[u8]>>(dir: &Path, name: &str, chunks: Receiver<T>) -> Result<PathBuf, String> {
+    let path = unique_path(dir, name);
+    let mut file = File::create(&path).map_err(|e| format!("创建文件失败: {}", e))?;
+    for chunk in chunks {
+        if let Err(error) = file.write_all(chunk.as_ref()) {
+            let _ = fs::remove_file(&path);
+            return Err(error.to_string());
+        }
+    }
+    Ok(path)
+}
+
+async fn search_provider(provider: CloudProvider, query: &str) -> Result<Vec<CloudFile>, String> {
+    let token = access_token(provider).await?;
+    let client = http::client()?;
+    provider.limiter().acquire(provider.name())?;
+
+    let limit = MAX_RESULTS.to_string();
+    let request = match provider {
+        CloudProvider::Google => {
+            let q = format!("name contains '{}' and trashed = false", escape_drive_query(query));
+            client.get(format!("{}/files", GOOGLE_API)).query(&[
+                ("q", q.as_str()),
+                ("pageSize", limit.as_str()),
+                ("fields", "files(id,name,mimeType,webViewLink,modifiedTime,size)"),
+            ])
+        }
+        CloudProvider::Dropbox => client.post(format!("{}/files/search_v2", DROPBOX_API)).json(&serde_json::json!({
+            "query": query,
+            "options": { "max_results": MAX_RESULTS, "file_status": "active", "filename_only": true }
+        })),
+        CloudProvider::OneDrive => {
+            let path = format!("{}/me/drive/root/search(q='{}')", GRAPH_API, percent_encode(&query.replace('\'', "''")));
+            client.get(path).query(&[
+                ("$top", limit.as_str()),
+                ("$select", "id,name,webUrl,lastModifiedDateTime,size,folder,file,parentReference"),
+            ])
+        }
+    };
+
+    let response = request
+        .bearer_auth(&token)
+        .send()
+        .await
+        .map_err(|e| format!("请求 {} 失败: {}", provider.name(), e))?;
+    let body = http::check_status(response, provider.name()).await?
+        .text()
+        .await
+        .map_err(|e| format!("读取 {} 响应失败: {}", provider.name(), e))?;
+
+    match provider {
+        CloudProvider::Google => parse_google(&body),
+        CloudProvider::Dropbox => parse_dropbox(&body),
+        CloudProvider::OneDrive => parse_onedrive(&body),
+    }
+}
+
+// 返回有效的访问令牌，快过期时用刷新令牌换新的并写回钥匙串
+async fn access_token(provider: CloudProvider) -> Result<String, String> {
+    let token = stored_token(provider)?
+        .ok_or_else(|| format!("未登录 {}", provider.name()))?;
+    if token.expires_at - EXPIRY_MARGIN_SECS > Local::now().timestamp() {
+        return Ok(token.access_token);
+    }
+
+    let refresh_token = token.refresh_token.clone()
+        .ok_or_else(|| format!("{} 登录已过期，请重新登录", provider.name()))?;
+    let mut form = vec![
+        ("grant_type", "refresh_token"),
+        ("refresh_token", refresh_token.as_str()),
+        ("client_id", token.client_id.as_str()),
+    ];
+    if let Some(secret) = &token.client_secret {
+        form.push(("client_secret", secret.as_str()));
+    }
+    let response = request_token(provider, &form).await?;
+    let refreshed = StoredToken {
+        access_token: response.access_token,
+        // 有的服务刷新时不返回新的刷新令牌，继续使用原来的
+        refresh_token: response.refresh_token.or(token.refresh_token),
+        expires_at: expires_at(response.expires_in),
+        ..token
+    };
+    save_token(provider, &refreshed)?;
+    Ok(refreshed.access_token)
+}
+
+async fn exchange_code(provider: CloudProvider, client_id: &str, client_secret: Option<&str>, code: &str, verifier: &str) -> Result<StoredToken, String> {
+    let mut form = vec![
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("client_id", client_id),
+        ("redirect_uri", REDIRECT_URI),
+        ("code_verifier", verifier),
+    ];
+    if let Some(secret) = client_secret {
+        form.push(("client_secret", secret));
+    }
+    let response = request_token(provider, &form).await?;
+    Ok(StoredToken {
+        client_id: client_id.to_string(),
+        client_secret: client_secret.map(str::to_string),
+        access_token: response.access_token,
+        refresh_token: response.refresh_token,
+        expires_at: expires_at(response.expires_in),
+    })
+}
+
+async fn request_token(provider: CloudProvider, form: &[(&str, &str)]) -> Result<TokenResponse, String> {
+    let response = http::client()?
+        .post(provider.token_endpoint())
+        .form(form)
+        .send()
+        .await
+        .map_err(|e| format!("请求 {} 令牌失败: {}", provider.name(), e))?;
+    let body = http::check_status(response, provider.name()).await?
+        .text()
+        .await
+        .map_err(|e| format!("读取 {} 令牌失败: {}", provider.name(), e))?;
+    serde_json::from_str(&body).map_err(|e| format!("解析 {} 令牌失败: {}", provider.name(), e))
+}
+
+// 没有返回有效期时按一小时计算
+fn expires_at(expires_in: Option<i64>) -> i64 {
+    Local::now().timestamp() + expires_in.unwrap_or(3600)
+}
+
+fn stored_token(provider: CloudProvider) -> Result<Option<StoredToken>, String> {
+    if let Some(token) = TOKENS.lock().unwrap().get(&provider) {
+        return Ok(token.clone());
+    }
+    let token = match keyring::get_secret(provider.account())? {
+        Some(secret) => Some(serde_json::from_str::<StoredToken>(&secret)
+            .map_err(|_| format!("{} 登录信息已损坏，请重新登录", provider.name()))?),
+        None => None,
+    };
+    TOKENS.lock().unwrap().insert(provider, token.clone());
+    Ok(token)
+}
+
+fn save_token(provider: CloudProvider, token: &StoredToken) -> Result<(), String> {
+    let secret = serde_json::to_string(token).map_err(|e| format!("保存 {} 令牌失败: {}", provider.name(), e))?;
+    keyring::set_secret(provider.account(), &secret)?;
+    TOKENS.lock().unwrap().insert(provider, Some(token.clone()));
+    Ok(())
+}
+
+fn authorize_url(provider: CloudProvider, client_id: &str, state: &str, challenge: &str) -> String {
+    let mut params = vec![
+        ("client_id", client_id),
+        ("redirect_uri", REDIRECT_URI),
+        ("response_type", "code"),
+        ("state", state),
+        ("code_challenge", challenge),
+        ("code_challenge_method", "S256"),
+    ];
+    params.extend_from_slice(provider.extra_params());
+    let query: Vec<String> = params.iter().map(|(key, value)| format!("{}={}", key, percent_encode(value))).collect();
+    format!("{}?{}", provider.authorize_endpoint(), query.join("&"))
+}
+
+// RFC 7636：code_challenge = BASE64URL(SHA256(code_verifier))
+fn pkce_challenge(verifier: &str) -> String {
+    base64_url(&Sha256::digest(verifier.as_bytes()))
+}
+
+fn random_string(len: usize) -> String {
+    OsRng.sample_iter(&Alphanumeric).take(len).map(char::from).collect()
+}
+
+// 不带填充的 URL 安全 Base64
+fn base64_url(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, &byte)| n | (byte as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            out.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char);
+        }
+    }
+    out
+}
+
+// 等待浏览器跳转回本机，忽略 favicon 等其他请求
+fn wait_for_code(listener: TcpListener, state: &str, deadline: Instant) -> Result<String, String> {
+    listener.set_nonblocking(true).map_err(|e| format!("监听登录回调失败: {}", e))?;
+    loop {
+        match listener.accept() {
+            Ok((mut stream, _)) => {
+                let _ = stream.set_nonblocking(false);
+                let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+                let mut buffer = [0u8; 8192];
+                let read = stream.read(&mut buffer).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buffer[..read]);
+                let Some(params) = parse_redirect(&request) else {
+                    respond(&mut stream, "404 Not Found", "");
+                    continue;
+                };
+
+                let result = check_redirect(&params, state);
+                let message = match &result {
+                    Ok(_) => "登录成功，可以关闭此页面并返回 launch-rs。",
+                    Err(_) => "登录失败，请返回 launch-rs 查看原因。",
+                };
+                respond(&mut stream, "200 OK", message);
+                return result;
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                if Instant::now() >= deadline {
+                    return Err("登录超时，请重试".to_string());
+                }
+                thread::sleep(Duration::from_millis(200));
+            }
+            Err(e) => return Err(format!("监听登录回调失败: {}", e)),
+        }
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: &str, message: &str) {
+    let body = format!("<!DOCTYPE html><html><head><meta charset=\"utf-8\"></head><body><p>{}</p></body></html>", message);
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+}
+
+// 解析请求行 GET /?code=...&state=... HTTP/1.1 中的查询参数
+fn parse_redirect(request: &str) -> Option<HashMap<String, String>> {
+    let target = request.lines().next()?.split_whitespace().nth(1)?;
+    let query = target.strip_prefix("/?")?;
+    Some(
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(key, value)| (percent_decode(key), percent_decode(&value.replace('+', " "))))
+            .collect(),
+    )
+}
+
+fn check_redirect(params: &HashMap<String, String>, state: &str) -> Result<String, String> {
+    if let Some(error) = params.get("error") {
+        let description = params.get("error_description").unwrap_or(error);
+        return Err(format!("授权失败: {}", description));
+    }
+    if params.get("state").map(String::as_str) != Some(state) {
+        return Err("登录状态校验失败，请重试".to_string());
+    }
+    params.get("code").cloned().ok_or_else(|| "回调中缺少授权码".to_string())
+}
+
+// Drive 查询语法中字符串用单引号包裹
+fn escape_drive_query(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+fn parse_google(body: &str) -> Result<Vec<CloudFile>, String> {
+    let value: Value = serde_json::from_str(body).map_err(|e| format!("解析 Google Drive 响应失败: {}", e))?;
+    let files = value["files"].as_array().cloned().unwrap_or_default();
+    Ok(files
+        .iter()
+        .filter_map(|file| {
+            let id = file["id"].as_str()?.to_string();
+            let mime_type = file["mimeType"].as_str().unwrap_or_default();
+            Some(CloudFile {
+                provider: CloudProvider::Google,
+                url: file["webViewLink"].as_str().map(str::to_string)
+                    .unwrap_or_else(|| format!("https://drive.google.com/open?id={}", id)),
+                id,
+                name: file["name"].as_str()?.to_string(),
+                path: None,
+                modified: file["modifiedTime"].as_str().map(str::to_string),
+                // size 是字符串，在线文档没有大小
+                size: file["size"].as_str().and_then(|size| size.parse().ok()),
+                is_folder: mime_type == "application/vnd.google-apps.folder",
+                downloadable: !mime_type.starts_with("application/vnd.google-apps."),
+            })
+        })
+        .collect())
+}
+
+fn parse_dropbox(body: &str) -> Result<Vec<CloudFile>, String> {
+    let value: Value = serde_json::from_str(body).map_err(|e| format!("解析 Dropbox 响应失败: {}", e))?;
+    let matches = value["matches"].as_array().cloned().unwrap_or_default();
+    Ok(matches
+        .iter()
+        .filter_map(|item| {
+            let metadata = &item["metadata"]["metadata"];
+            let is_folder = metadata[".tag"].as_str()? == "folder";
+            let path = metadata["path_display"].as_str()?.to_string();
+            Some(CloudFile {
+                provider: CloudProvider::Dropbox,
+                id: metadata["id"].as_str()?.to_string(),
+                name: metadata["name"].as_str()?.to_string(),
+                url: dropbox_web_url(&path, is_folder),
+                path: Some(path),
+                modified: metadata["server_modified"].as_str().map(str::to_string),
+                size: metadata["size"].as_u64(),
+                is_folder,
+                downloadable: !is_folder,
+            })
+        })
+        .collect())
+}
+
+// 文件夹打开对应目录，文件打开所在目录并预览
+fn dropbox_web_url(path: &str, is_folder: bool) -> String {
+    let encode_path = |path: &str| -> String {
+        path.split('/').filter(|segment| !segment.is_empty()).map(|segment| format!("/{}", percent_encode(segment))).collect()
+    };
+    if is_folder {
+        return format!("https://www.dropbox.com/home{}", encode_path(path));
+    }
+    let (parent, name) = path.rsplit_once('/').unwrap_or(("", path));
+    format!("https://www.dropbox.com/home{}?preview={}", encode_path(parent), percent_encode(name))
+}
+
+fn parse_onedrive(body: &str) -> Result<Vec<CloudFile>, String> {
+    let value: Value = serde_json::from_str(body).map_err(|e| format!("解析 OneDrive 响应失败: {}", e))?;
+    let items = value["value"].as_array().cloned().unwrap_or_default();
+    Ok(items
+        .iter()
+        .filter_map(|item| {
+            let is_folder = item.get("folder").is_some();
+            // parentReference.path 形如 /drive/root:/Documents
+            let parent = item["parentReference"]["path"].as_str()
+                .and_then(|path| path.split_once(':'))
+                .map(|(_, path)| path.to_string());
+            let name = item["name"].as_str()?.to_string();
+            Some(CloudFile {
+                provider: CloudProvider::OneDrive,
+                id: item["id"].as_str()?.to_string(),
+                path: parent.map(|parent| format!("{}/{}", parent, name)),
+                name,
+                url: item["webUrl"].as_str()?.to_string(),
+                modified: item["lastModifiedDateTime"].as_str().map(str::to_string),
+                size: if is_folder { None } else { item["size"].as_u64() },
+                is_folder,
+                downloadable: item.get("file").is_some(),
+            })
+        })
+        .collect())
+}
+
+// 去掉路径分隔符等不能出现在文件名中的字符
+fn sanitize_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| if c.is_control() || "/\\:*?\"<>|".contains(c) { '_' } else { c })
+        .collect();
+    let cleaned = cleaned.trim().trim_start_matches('.');
+    if cleaned.is_empty() { "download".to_string() } else { cleaned.to_string() }
+}
+
+fn unique_path(dir: &Path, name: &str) -> PathBuf {
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, format!(".{}", extension)),
+        _ => (name, String::new()),
+    };
+    let mut path = dir.join(name);
+    let mut counter = 1;
+    while path.exists() {
+        path = dir.join(format!("{} ({}){}", stem, counter, extension));
+        counter += 1;
+    }
+    path
+}
+
+fn percent_encode(value: &str) -> String {
+    value.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Some(byte) = std::str::from_utf8(&bytes[i + 1..i + 3]).ok().and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_pkce_challenge() {
+        // RFC 7636 附录 B 的示例
+        assert_eq!(pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"), "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM");
+        assert_eq!(base64_url(b"ab"), "YWI");
+        assert_eq!(base64_url(b"abcd"), "YWJjZA");
+        assert_eq!(random_string(64).len(), 64);
+    }
+
+    #[test]
+    fn test_authorize_url() {
+        let url = authorize_url(CloudProvider::OneDrive, "client", "state", "challenge");
+        assert!(url.starts_with("https://login.microsoftonline.com/common/oauth2/v2.0/authorize?client_id=client&"));
+        assert!(url.contains("redirect_uri=http%3A%2F%2Flocalhost%3A53682%2F"));
+        assert!(url.contains("code_challenge=challenge&code_challenge_method=S256"));
+        assert!(url.contains("scope=Files.Read%20offline_access"));
+    }
+
+    #[test]
+    fn test_redirect() {
+        let request = "GET /?state=abc&code=4%2F0Ab+cd HTTP/1.1\r\nHost: localhost:53682\r\n\r\n";
+        let params = parse_redirect(request).unwrap();
+        assert_eq!(check_redirect(&params, "abc"), Ok("4/0Ab cd".to_string()));
+        assert!(check_redirect(&params, "other").unwrap_err().contains("状态校验"));
+        assert!(parse_redirect("GET /favicon.ico HTTP/1.1\r\n").is_none());
+
+        let params = parse_redirect("GET /?error=access_denied&error_description=User+cancelled HTTP/1.1").unwrap();
+        assert_eq!(check_redirect(&params, "abc"), Err("授权失败: User cancelled".to_string()));
+    }
+
+    #[test]
+    fn test_parse_providers() {
+        let body = r#"{"files": [
+            {"id": "1", "name": "Report.pdf", "mimeType": "application/pdf", "webViewLink": "https://drive.google.com/file/d/1/view", "modifiedTime": "2024-01-02T03:04:05Z", "size": "2048"},
+            {"id": "2", "name": "Plan", "mimeType": "application/vnd.google-apps.document"},
+            {"id": "3", "name": "Folder", "mimeType": "application/vnd.google-apps.folder"}
+        ]}"#;
+        let files = parse_google(body).unwrap();
+        assert_eq!((files[0].size, files[0].downloadable, files[0].is_folder), (Some(2048), true, false));
+        assert_eq!((files[1].downloadable, files[1].url.as_str()), (false, "https://drive.google.com/open?id=2"));
+        assert!(files[2].is_folder);
+
+        let body = r#"{"matches": [
+            {"metadata": {".tag": "metadata", "metadata": {".tag": "file", "id": "id:a", "name": "a b.txt", "path_display": "/Docs/a b.txt", "server_modified": "2024-01-01T00:00:00Z", "size": 10}}},
+            {"metadata": {".tag": "metadata", "metadata": {".tag": "folder", "id": "id:b", "name": "Docs", "path_display": "/Docs"}}}
+        ]}"#;
+        let files = parse_dropbox(body).unwrap();
+        assert_eq!(files[0].url, "https://www.dropbox.com/home/Docs?preview=a%20b.txt");
+        assert_eq!((files[1].url.as_str(), files[1].downloadable), ("https://www.dropbox.com/home/Docs", false));
+
+        let body = r#"{"value": [
+            {"id": "X1", "name": "notes.md", "webUrl": "https://onedrive.live.com/?id=X1", "size": 5, "file": {}, "parentReference": {"path": "/drive/root:/Documents"}},
+            {"id": "X2", "name": "Photos", "webUrl": "https://onedrive.live.com/?id=X2", "size": 900, "folder": {"childCount": 3}}
+        ]}"#;
+        let files = parse_onedrive(body).unwrap();
+        assert_eq!((files[0].path.as_deref(), files[0].downloadable), (Some("/Documents/notes.md"), true));
+        assert_eq!((files[1].size, files[1].is_folder, files[1].path.as_deref()), (None, true, None));
+    }
+
+    #[test]
+    fn test_download_file_names() {
+        assert_eq!(escape_drive_query("it's"), "it\\'s");
+        assert_eq!(sanitize_file_name("a/b:c.txt"), "a_b_c.txt");
+        assert_eq!(sanitize_file_name("../"), "_");
+        assert_eq!(sanitize_file_name(".."), "download");
+
+        let dir = TempDir::new().unwrap();
+        assert_eq!(unique_path(dir.path(), "report.pdf"), dir.path().join("report.pdf"));
+        fs::write(dir.path().join("report.pdf"), "").unwrap();
+        assert_eq!(unique_path(dir.path(), "report.pdf"), dir.path().join("report (1).pdf"));
+        fs::write(dir.path().join("README"), "").unwrap();
+        assert_eq!(unique_path(dir.path(), "README"), dir.path().join("README (1)"));
+    }
+
+    #[test]
+    fn test_write_download() {
+        let dir = TempDir::new().unwrap();
+        let (sender, receiver) = mpsc::channel();
+        sender.send(b"hello ".to_vec()).unwrap();
+        sender.send(b"world".to_vec()).unwrap();
+        drop(sender);
+        let path = write_download(dir.path(), "a.txt", receiver).unwrap();
+        assert_eq!(path, dir.path().join("a.txt"));
+        assert_eq!(fs::read_to_string(path).unwrap(), "hello world");
+    }
+}
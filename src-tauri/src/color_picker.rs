@@ -0,0 +1,168 @@
+// 屏幕取色：点击屏幕任意位置，返回该像素的颜色（hyprpicker / xcolor / choose color / WinForms）
+use crate::system_command::{powershell, run};
+use serde::{Deserialize, Serialize};
+
+// 取到的颜色
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PickedColor {
+    pub hex: String,
+    pub rgb: String,
+    pub hsl: String,
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+// 截取整个虚拟屏幕铺满一个置顶窗口，点击时读取截图中对应像素，Esc 取消
+const WINDOWS_PICK_SCRIPT: &str = r#"Add-Type -AssemblyName System.Windows.Forms, System.Drawing
+$bounds = [System.Windows.Forms.SystemInformation]::VirtualScreen
+$bitmap = New-Object System.Drawing.Bitmap $bounds.Width, $bounds.Height
+$graphics = [System.Drawing.Graphics]::FromImage($bitmap)
+$graphics.CopyFromScreen($bounds.Location, [System.Drawing.Point]::Empty, $bounds.Size)
+$form = New-Object System.Windows.Forms.Form
+$form.FormBorderStyle = 'None'
+$form.StartPosition = 'Manual'
+$form.Bounds = $bounds
+$form.TopMost = $true
+$form.ShowInTaskbar = $false
+$form.BackgroundImage = $bitmap
+$form.Cursor = [System.Windows.Forms.Cursors]::Cross
+$script:picked = ''
+$form.Add_MouseClick({ param($s, $e) $c = $bitmap.GetPixel($e.X, $e.Y); $script:picked = '#{0:X2}{1:X2}{2:X2}' -f $c.R, $c.G, $c.B; $form.Close() })
+$form.Add_KeyDown({ param($s, $e) if ($e.KeyCode -eq 'Escape') { $form.Close() } })
+[void]$form.ShowDialog()
+$script:picked"#;
+
+// 等待用户点击，取消时返回 None；会阻塞到用户完成选择，因此不在主线程执行
+#[tauri::command(async)]
+pub fn pick_color() -> Result<Option<PickedColor>, String> {
+    let output = match std::env::consts::OS {
+        "linux" => {
+            // hyprpicker 用于 wlroots 系 Wayland，xcolor 用于 X11
+            let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+            let tools: &[(&str, &[&str])] = if wayland {
+                &[("hyprpicker", &["--format=hex"]), ("xcolor", &[])]
+            } else {
+                &[("xcolor", &[]), ("hyprpicker", &["--format=hex"])]
+            };
+            let mut last_error = String::new();
+            let mut picked = None;
+            for (program, args) in tools {
+                match run(program, args) {
+                    Ok(output) => {
+                        picked = Some(output);
+                        break;
+                    }
+                    Err(e) => last_error = e,
+                }
+            }
+            picked.ok_or_else(|| format!("{}（Linux 需要先安装 hyprpicker 或 xcolor）", last_error))?
+        }
+        // 系统颜色面板自带取色器，用户取消时 osascript 返回错误 -128
+        "macos" => match run("osascript", &["-e", "choose color"]) {
+            Ok(output) => output,
+            Err(e) if e.contains("-128") => return Ok(None),
+            Err(e) => return Err(e),
+        },
+        "windows" => powershell(WINDOWS_PICK_SCRIPT)?,
+        os => return Err(format!("当前系统不支持屏幕取色: {}", os)),
+    };
+
+    if output.trim().is_empty() {
+        return Ok(None);
+    }
+    parse_color(&output)
+        .map(|(r, g, b)| Some(to_picked_color(r, g, b)))
+        .ok_or_else(|| format!("无法解析颜色: {}", output.trim()))
+}
+
+// "#RRGGBB" 或 choose color 返回的 16 位分量 "65535, 32896, 0"
+fn parse_color(output: &str) -> Option<(u8, u8, u8)> {
+    let value = output.trim();
+
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let component = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        return Some((component(0)?, component(2)?, component(4)?));
+    }
+
+    let components: Vec<u32> = value.split(',')
+        .map(|part| part.trim().parse::<u32>().ok())
+        .collect::<Option<_>>()?;
+    match components[..] {
+        [r, g, b] if r <= 65535 && g <= 65535 && b <= 65535 => {
+            let scale = |v: u32| ((v as f64) / 257.0).round() as u8;
+            Some((scale(r), scale(g), scale(b)))
+        }
+        _ => None,
+    }
+}
+
+fn to_picked_color(r: u8, g: u8, b: u8) -> PickedColor {
+    let (h, s, l) = rgb_to_hsl(r, g, b);
+    PickedColor {
+        hex: format!("#{:02X}{:02X}{:02X}", r, g, b),
+        rgb: format!("rgb({}, {}, {})", r, g, b),
+        hsl: format!("hsl({}, {}%, {}%)", h, s, l),
+        r,
+        g,
+        b,
+    }
+}
+
+// 返回取整后的 (色相 0-360, 饱和度 %, 亮度 %)
+fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (u16, u8, u8) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let d = max - min;
+
+    if d == 0.0 {
+        return (0, 0, (l * 100.0).round() as u8);
+    }
+
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        60.0 * (((g - b) / d).rem_euclid(6.0))
+    } else if max == g {
+        60.0 * ((b - r) / d + 2.0)
+    } else {
+        60.0 * ((r - g) / d + 4.0)
+    };
+
+    ((h.round() as u16) % 360, (s * 100.0).round() as u8, (l * 100.0).round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#FF8000\n"), Some((255, 128, 0)));
+        assert_eq!(parse_color("#1e90ff"), Some((30, 144, 255)));
+        assert_eq!(parse_color("65535, 32896, 0"), Some((255, 128, 0)));
+        assert_eq!(parse_color("#FFF"), None);
+        assert_eq!(parse_color("1, 2"), None);
+        assert_eq!(parse_color("not a color"), None);
+    }
+
+    #[test]
+    fn test_to_picked_color() {
+        let color = to_picked_color(30, 144, 255);
+        assert_eq!(color.hex, "#1E90FF");
+        assert_eq!(color.rgb, "rgb(30, 144, 255)");
+        assert_eq!(color.hsl, "hsl(210, 100%, 56%)");
+    }
+
+    #[test]
+    fn test_rgb_to_hsl() {
+        assert_eq!(rgb_to_hsl(255, 0, 0), (0, 100, 50));
+        assert_eq!(rgb_to_hsl(0, 255, 0), (120, 100, 50));
+        assert_eq!(rgb_to_hsl(128, 128, 128), (0, 0, 50));
+        assert_eq!(rgb_to_hsl(255, 0, 255), (300, 100, 50));
+    }
+}
@@ -0,0 +1,350 @@
+// 联系人搜索：读取 vCard 导出文件以及 macOS 通讯录数据库
+use rusqlite::{Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAX_RESULTS: usize = 20;
+const MAX_SCAN_DEPTH: usize = 4;
+
+// 联系人
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Contact {
+    pub name: String,
+    pub organization: Option<String>,
+    pub emails: Vec<String>,
+    pub phones: Vec<String>,
+    pub source: String,
+}
+
+#[tauri::command(async)]
+pub fn search_contacts(
+    query: String,
+    vcard_paths: Option<Vec<String>>,
+    include_system: Option<bool>,
+) -> Result<Vec<Contact>, String> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut contacts = Vec::new();
+    for file in vcard_files(&vcard_paths.unwrap_or_default()) {
+        if let Ok(content) = fs::read_to_string(&file) {
+            contacts.extend(parse_vcards(&content, "vCard"));
+        }
+    }
+    // Windows 人脉没有可直接读取的本地存储，可导出为 vCard 后使用
+    if include_system.unwrap_or(true) {
+        contacts.extend(system_contacts());
+    }
+
+    let mut scored: Vec<(u32, Contact)> = contacts.into_iter()
+        .filter_map(|contact| match_score(&contact, &query).map(|score| (score, contact)))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.name.cmp(&b.1.name)));
+    scored.dedup_by(|a, b| a.1.name == b.1.name && a.1.emails == b.1.emails && a.1.phones == b.1.phones);
+
+    Ok(scored.into_iter().take(MAX_RESULTS).map(|(_, contact)| contact).collect())
+}
+
+// 匹配得分：姓名前缀 > 姓名中任意词前缀 > 包含 > 公司/邮箱/号码
+fn match_score(contact: &Contact, query: &str) -> Option<u32> {
+    let name = contact.name.to_lowercase();
+    if name.starts_with(query) {
+        return Some(100);
+    }
+    if name.split_whitespace().any(|word| word.starts_with(query)) {
+        return Some(80);
+    }
+    if name.contains(query) {
+        return Some(60);
+    }
+    if contact.organization.as_deref().is_some_and(|org| org.to_lowercase().contains(query)) {
+        return Some(40);
+    }
+    if contact.emails.iter().any(|email| email.to_lowercase().contains(query)) {
+        return Some(30);
+    }
+
+    // 号码只比较数字
+    let digits: String = query.chars().filter(char::is_ascii_digit).collect();
+    if digits.len() >= 3 && contact.phones.iter().any(|phone| {
+        phone.chars().filter(char::is_ascii_digit).collect::<String>().contains(&digits)
+    }) {
+        return Some(20);
+    }
+
+    None
+}
+
+fn vcard_files(paths: &[String]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for path in paths.iter().map(|path| path.trim()).filter(|path| !path.is_empty()) {
+        let path = match (path.strip_prefix("~/"), dirs::home_dir()) {
+            (Some(rest), Some(home)) => home.join(rest),
+            _ => PathBuf::from(path),
+        };
+        collect_vcard_files(&path, 0, &mut files);
+    }
+    files
+}
+
+fn collect_vcard_files(path: &Path, depth: usize, files: &mut Vec<PathBuf>) {
+    if path.is_file() {
+        if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("vcf") || ext.eq_ignore_ascii_case("vcard")) {
+            files.push(path.to_path_buf());
+        }
+        return;
+    }
+
+    if depth >= MAX_SCAN_DEPTH {
+        return;
+    }
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            collect_vcard_files(&entry.path(), depth + 1, files);
+        }
+    }
+}
+
+// 解析 vCard（2.1/3.0/4.0）文本
+fn parse_vcards(content: &str, source: &str) -> Vec<Contact> {
+    let mut contacts = Vec::new();
+    let mut current: Option<(Contact, Option<String>)> = None; // (联系人, N 字段拼出的姓名)
+
+    for line in unfold_lines(content) {
+        let Some((name_and_params, value)) = line.split_once(':') else {
+            continue;
+        };
+        // "item1.EMAIL;type=INTERNET" 这种分组前缀需要去掉
+        let name = name_and_params.split(';').next().unwrap_or_default();
+        let name = name.rsplit('.').next().unwrap_or_default().to_ascii_uppercase();
+        let value = value.trim();
+
+        match (name.as_str(), current.as_mut()) {
+            ("BEGIN", _) if value.eq_ignore_ascii_case("VCARD") => {
+                current = Some((
+                    Contact {
+                        name: String::new(),
+                        organization: None,
+                        emails: Vec::new(),
+                        phones: Vec::new(),
+                        source: source.to_string(),
+                    },
+                    None,
+                ));
+            }
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VCARD") => {
+                let Some((mut contact, structured_name)) = current.take() else {
+                    continue;
+                };
+                if contact.name.is_empty() {
+                    contact.name = structured_name
+                        .or_else(|| contact.organization.clone())
+                        .or_else(|| contact.emails.first().cloned())
+                        .unwrap_or_default();
+                }
+                if !contact.name.is_empty() {
+                    contacts.push(contact);
+                }
+            }
+            ("FN", Some((contact, _))) => contact.name = unescape(value),
+            ("N", Some((_, structured_name))) => {
+                // N:姓;名;中间名;前缀;后缀
+                let parts: Vec<String> = value.split(';').map(unescape).collect();
+                let given = parts.get(1).cloned().unwrap_or_default();
+                let family = parts.first().cloned().unwrap_or_default();
+                let full = format!("{} {}", given, family).trim().to_string();
+                if !full.is_empty() {
+                    *structured_name = Some(full);
+                }
+            }
+            ("ORG", Some((contact, _))) => {
+                let org = unescape(value.split(';').next().unwrap_or_default());
+                contact.organization = Some(org).filter(|org| !org.is_empty());
+            }
+            ("EMAIL", Some((contact, _))) if !value.is_empty() => contact.emails.push(value.to_string()),
+            ("TEL", Some((contact, _))) if !value.is_empty() => {
+                contact.phones.push(value.trim_start_matches("tel:").to_string());
+            }
+            _ => {}
+        }
+    }
+
+    contacts
+}
+
+fn unfold_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+fn unescape(value: &str) -> String {
+    value.replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\n", " ")
+        .replace("\\\\", "\\")
+        .trim()
+        .to_string()
+}
+
+// macOS 通讯录保存在 SQLite 数据库中，每个账户（本地、iCloud 等）一个
+fn system_contacts() -> Vec<Contact> {
+    if !cfg!(target_os = "macos") {
+        return vec![];
+    }
+    let Some(home) = dirs::home_dir() else {
+        return vec![];
+    };
+
+    let root = home.join("Library").join("Application Support").join("AddressBook");
+    let mut databases = vec![root.join("AddressBook-v22.abcddb")];
+    if let Ok(sources) = fs::read_dir(root.join("Sources")) {
+        databases.extend(sources.flatten().map(|entry| entry.path().join("AddressBook-v22.abcddb")));
+    }
+
+    databases.iter()
+        .filter(|path| path.is_file())
+        .filter_map(|path| read_address_book(path).ok())
+        .flatten()
+        .collect()
+}
+
+fn read_address_book(path: &Path) -> rusqlite::Result<Vec<Contact>> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    read_address_book_tables(&conn)
+}
+
+fn read_address_book_tables(conn: &Connection) -> rusqlite::Result<Vec<Contact>> {
+    let mut stmt = conn.prepare(
+        "SELECT Z_PK, ZFIRSTNAME, ZLASTNAME, ZORGANIZATION FROM ZABCDRECORD
+         WHERE ZFIRSTNAME IS NOT NULL OR ZLASTNAME IS NOT NULL OR ZORGANIZATION IS NOT NULL",
+    )?;
+    let records = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            row.get::<_, Option<String>>(1)?,
+            row.get::<_, Option<String>>(2)?,
+            row.get::<_, Option<String>>(3)?,
+        ))
+    })?;
+
+    let mut email_stmt = conn.prepare("SELECT ZADDRESS FROM ZABCDEMAILADDRESS WHERE ZOWNER = ?1 AND ZADDRESS IS NOT NULL")?;
+    let mut phone_stmt = conn.prepare("SELECT ZFULLNUMBER FROM ZABCDPHONENUMBER WHERE ZOWNER = ?1 AND ZFULLNUMBER IS NOT NULL")?;
+
+    let mut contacts = Vec::new();
+    for record in records {
+        let (id, first, last, organization) = record?;
+        let name = [first.as_deref(), last.as_deref()]
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let emails = email_stmt.query_map([id], |row| row.get(0))?.collect::<rusqlite::Result<Vec<String>>>()?;
+        let phones = phone_stmt.query_map([id], |row| row.get(0))?.collect::<rusqlite::Result<Vec<String>>>()?;
+
+        contacts.push(Contact {
+            name: if name.is_empty() { organization.clone().unwrap_or_default() } else { name },
+            organization,
+            emails,
+            phones,
+            source: "通讯录".to_string(),
+        });
+    }
+
+    Ok(contacts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    const VCARDS: &str = "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Ada Lovelace\r\nN:Lovelace;Ada;;;\r\n\
+        ORG:Analytical Engines\\, Ltd;Research\r\nitem1.EMAIL;type=INTERNET:ada@example.com\r\n\
+        TEL;TYPE=CELL:+44 20 7946 0958\r\nEND:VCARD\r\n\
+        BEGIN:VCARD\r\nVERSION:4.0\r\nN:Hopper;Grace;;;\r\nTEL;VALUE=uri:tel:+1-555-0100\r\n\
+        EMAIL:grace@navy.example\r\n .mil\r\nEND:VCARD\r\n\
+        BEGIN:VCARD\r\nVERSION:3.0\r\nEND:VCARD\r\n";
+
+    fn contact(name: &str) -> Contact {
+        parse_vcards(VCARDS, "test").into_iter().find(|c| c.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_parse_vcards() {
+        let contacts = parse_vcards(VCARDS, "test");
+        // 空 vCard 被跳过
+        assert_eq!(contacts.len(), 2);
+
+        let ada = contact("Ada Lovelace");
+        assert_eq!(ada.organization.as_deref(), Some("Analytical Engines, Ltd"));
+        assert_eq!(ada.emails, vec!["ada@example.com"]);
+        assert_eq!(ada.phones, vec!["+44 20 7946 0958"]);
+
+        // 没有 FN 时使用 N 字段，折行内容被拼接
+        let grace = contact("Grace Hopper");
+        assert_eq!(grace.phones, vec!["+1-555-0100"]);
+        assert_eq!(grace.emails, vec!["grace@navy.example.mil"]);
+    }
+
+    #[test]
+    fn test_match_score() {
+        let ada = contact("Ada Lovelace");
+        assert_eq!(match_score(&ada, "ada"), Some(100));
+        assert_eq!(match_score(&ada, "love"), Some(80));
+        assert_eq!(match_score(&ada, "velace"), Some(60));
+        assert_eq!(match_score(&ada, "analytical"), Some(40));
+        assert_eq!(match_score(&ada, "example.com"), Some(30));
+        assert_eq!(match_score(&ada, "7946"), Some(20));
+        assert_eq!(match_score(&ada, "babbage"), None);
+        // 太短的数字不按号码匹配
+        assert_eq!(match_score(&ada, "20"), None);
+    }
+
+    #[test]
+    fn test_search_contacts_from_vcard_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        File::create(temp_dir.path().join("people.vcf")).unwrap().write_all(VCARDS.as_bytes()).unwrap();
+        File::create(temp_dir.path().join("notes.txt")).unwrap().write_all(VCARDS.as_bytes()).unwrap();
+
+        let paths = Some(vec![temp_dir.path().to_string_lossy().to_string()]);
+        let results = search_contacts("grace".to_string(), paths.clone(), Some(false)).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "Grace Hopper");
+
+        assert!(search_contacts("  ".to_string(), paths, Some(false)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_read_address_book_tables() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE ZABCDRECORD (Z_PK INTEGER PRIMARY KEY, ZFIRSTNAME TEXT, ZLASTNAME TEXT, ZORGANIZATION TEXT);
+             CREATE TABLE ZABCDEMAILADDRESS (ZOWNER INTEGER, ZADDRESS TEXT);
+             CREATE TABLE ZABCDPHONENUMBER (ZOWNER INTEGER, ZFULLNUMBER TEXT);
+             INSERT INTO ZABCDRECORD VALUES (1, 'Alan', 'Turing', NULL), (2, NULL, NULL, 'Bletchley Park'), (3, NULL, NULL, NULL);
+             INSERT INTO ZABCDEMAILADDRESS VALUES (1, 'alan@example.com');
+             INSERT INTO ZABCDPHONENUMBER VALUES (1, '555-0199'), (2, '555-0123');",
+        ).unwrap();
+
+        let contacts = read_address_book_tables(&conn).unwrap();
+        assert_eq!(contacts.len(), 2);
+        assert_eq!(contacts[0].name, "Alan Turing");
+        assert_eq!(contacts[0].emails, vec!["alan@example.com"]);
+        assert_eq!(contacts[1].name, "Bletchley Park");
+        assert_eq!(contacts[1].phones, vec!["555-0123"]);
+    }
+}
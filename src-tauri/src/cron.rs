@@ -0,0 +1,345 @@
+// cron 表达式解析：生成中文描述并计算接下来的运行时间（本地时间）
+// 支持标准五段格式（分 时 日 月 周）、名称（JAN、MON）、范围、步长、列表以及 @daily 等别名
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_RUN_COUNT: usize = 5;
+const MAX_RUN_COUNT: usize = 50;
+// 向后查找的天数上限（覆盖 2 月 29 日这类四年一次的表达式）
+const SEARCH_DAYS: i64 = 366 * 5;
+
+const MONTH_NAMES: &[&str] = &["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"];
+const WEEKDAY_NAMES: &[&str] = &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+const WEEKDAY_LABELS: &[&str] = &["周日", "周一", "周二", "周三", "周四", "周五", "周六"];
+
+const ALIASES: &[(&str, &str)] = &[
+    ("@yearly", "0 0 1 1 *"),
+    ("@annually", "0 0 1 1 *"),
+    ("@monthly", "0 0 1 * *"),
+    ("@weekly", "0 0 * * 0"),
+    ("@daily", "0 0 * * *"),
+    ("@midnight", "0 0 * * *"),
+    ("@hourly", "0 * * * *"),
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CronRun {
+    pub timestamp: i64,
+    pub local: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CronExplanation {
+    pub expression: String,
+    pub description: String,
+    pub next_runs: Vec<CronRun>,
+}
+
+// 一段中的一项：start-end/step
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Part {
+    start: u32,
+    end: u32,
+    step: u32,
+}
+
+#[derive(Debug)]
+struct Field {
+    min: u32,
+    max: u32,
+    // * 或 ?：不限制
+    any: bool,
+    parts: Vec<Part>,
+    allowed: Vec<bool>,
+}
+
+impl Field {
+    fn matches(&self, value: u32) -> bool {
+        self.allowed[value as usize]
+    }
+
+    // 只包含单个值（没有范围和步长）
+    fn single_values(&self) -> Option<Vec<u32>> {
+        self.parts.iter().map(|part| (part.start == part.end).then_some(part.start)).collect()
+    }
+
+    // 形如 */n 的整段步长
+    fn full_step(&self) -> Option<u32> {
+        match self.parts.as_slice() {
+            [part] if part.step > 1 && part.start == self.min && part.end == self.max => Some(part.step),
+            _ => None,
+        }
+    }
+
+    fn describe(&self, label: impl Fn(u32) -> String) -> String {
+        self.parts.iter()
+            .map(|part| match (part.start == part.end, part.step) {
+                (true, _) => label(part.start),
+                (false, 1) => format!("{}至{}", label(part.start), label(part.end)),
+                (false, step) => format!("{}至{}每隔 {}", label(part.start), label(part.end), step),
+            })
+            .collect::<Vec<_>>()
+            .join("、")
+    }
+}
+
+#[derive(Debug)]
+struct Schedule {
+    minute: Field,
+    hour: Field,
+    day: Field,
+    month: Field,
+    weekday: Field,
+}
+
+impl Schedule {
+    // 与 Vixie cron 一致：日和周都有限制时，满足其一即可
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !self.month.matches(date.month()) {
+            return false;
+        }
+        let day = self.day.matches(date.day());
+        let weekday = self.weekday.matches(date.weekday().num_days_from_sunday());
+        match (self.day.any, self.weekday.any) {
+            (true, true) => true,
+            (false, true) => day,
+            (true, false) => weekday,
+            (false, false) => day || weekday,
+        }
+    }
+}
+
+#[tauri::command]
+pub fn explain_cron(expression: String, count: Option<usize>) -> Result<CronExplanation, String> {
+    let schedule = parse(&expression)?;
+    let count = count.unwrap_or(DEFAULT_RUN_COUNT).clamp(1, MAX_RUN_COUNT);
+    let next_runs = next_runs(&schedule, Local::now(), count)
+        .into_iter()
+        .map(|run| CronRun {
+            timestamp: run.timestamp(),
+            local: format_run(&run),
+        })
+        .collect();
+
+    Ok(CronExplanation {
+        expression: expression.trim().to_string(),
+        description: describe(&schedule),
+        next_runs,
+    })
+}
+
+fn parse(expression: &str) -> Result<Schedule, String> {
+    let expression = expression.trim();
+    if expression.eq_ignore_ascii_case("@reboot") {
+        return Err("@reboot 在系统启动时运行，没有固定的运行时间".to_string());
+    }
+    let expression = ALIASES.iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(expression))
+        .map_or(expression, |(_, value)| value);
+
+    let fields: Vec<&str> = expression.split_whitespace().collect();
+    let [minute, hour, day, month, weekday] = fields.as_slice() else {
+        return Err(format!("cron 表达式应包含 5 段（分 时 日 月 周），实际为 {} 段", fields.len()));
+    };
+
+    let mut weekday = parse_field(weekday, "周", 0, 7, WEEKDAY_NAMES, 0)?;
+    // 0 和 7 都表示周日
+    if weekday.allowed[7] {
+        weekday.allowed[0] = true;
+    }
+
+    Ok(Schedule {
+        minute: parse_field(minute, "分钟", 0, 59, &[], 0)?,
+        hour: parse_field(hour, "小时", 0, 23, &[], 0)?,
+        day: parse_field(day, "日", 1, 31, &[], 0)?,
+        month: parse_field(month, "月", 1, 12, MONTH_NAMES, 1)?,
+        weekday,
+    })
+}
+
+fn parse_field(raw: &str, label: &str, min: u32, max: u32, names: &[&str], name_offset: u32) -> Result<Field, String> {
+    let value = |text: &str| -> Result<u32, String> {
+        let value = match names.iter().position(|name| name.eq_ignore_ascii_case(text)) {
+            Some(index) => index as u32 + name_offset,
+            None => text.parse().map_err(|_| format!("{}字段中的 \"{}\" 无效", label, text))?,
+        };
+        if value < min || value > max {
+            return Err(format!("{}字段的取值应在 {} 到 {} 之间: {}", label, min, max, text));
+        }
+        Ok(value)
+    };
+
+    let mut parts = Vec::new();
+    let mut allowed = vec![false; max as usize + 1];
+    for item in raw.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step = step.parse::<u32>().ok().filter(|step| *step > 0)
+                    .ok_or_else(|| format!("{}字段的步长无效: {}", label, item))?;
+                (range, Some(step))
+            }
+            None => (item, None),
+        };
+        let (start, end) = match range {
+            "*" | "?" => (min, max),
+            range => match range.split_once('-') {
+                Some((start, end)) => (value(start)?, value(end)?),
+                // 5/15 表示从 5 开始每隔 15
+                None if step.is_some() => (value(range)?, max),
+                None => {
+                    let single = value(range)?;
+                    (single, single)
+                }
+            },
+        };
+        if start > end {
+            return Err(format!("{}字段的范围起始值大于结束值: {}", label, item));
+        }
+
+        let step = step.unwrap_or(1);
+        for value in (start..=end).step_by(step as usize) {
+            allowed[value as usize] = true;
+        }
+        parts.push(Part { start, end, step });
+    }
+
+    Ok(Field {
+        min,
+        max,
+        any: raw == "*" || raw == "?",
+        parts,
+        allowed,
+    })
+}
+
+fn describe(schedule: &Schedule) -> String {
+    let Schedule { minute, hour, day, month, weekday } = schedule;
+    let number = |value: u32| value.to_string();
+
+    let time = match (minute.single_values(), hour.single_values()) {
+        (Some(minutes), Some(hours)) if minutes.len() * hours.len() <= 6 => hours.iter()
+            .flat_map(|hour| minutes.iter().map(move |minute| format!("{:02}:{:02}", hour, minute)))
+            .collect::<Vec<_>>()
+            .join("、"),
+        _ => {
+            let minute_text = if minute.any {
+                "每分钟".to_string()
+            } else if let Some(step) = minute.full_step() {
+                format!("每 {} 分钟", step)
+            } else {
+                format!("第 {} 分钟", minute.describe(number))
+            };
+            if hour.any {
+                if minute.any || minute.full_step().is_some() { minute_text } else { format!("每小时的{}", minute_text) }
+            } else if let Some(step) = hour.full_step() {
+                format!("每 {} 小时的{}", step, minute_text)
+            } else {
+                format!("{} 点的{}", hour.describe(number), minute_text)
+            }
+        }
+    };
+
+    let mut parts = Vec::new();
+    if !month.any {
+        parts.push(format!("每年 {} 月", month.describe(number)));
+    }
+    let day_text = (!day.any).then(|| match (day.full_step(), month.any) {
+        (Some(step), _) => format!("每 {} 天", step),
+        (None, true) => format!("每月 {} 日", day.describe(number)),
+        (None, false) => format!("{} 日", day.describe(number)),
+    });
+    let weekday_text = (!weekday.any).then(|| format!("每{}", weekday.describe(|value| WEEKDAY_LABELS[value as usize % 7].to_string())));
+    match (day_text, weekday_text) {
+        (Some(day), Some(weekday)) => parts.push(format!("{}或{}", day, weekday)),
+        (Some(text), None) | (None, Some(text)) => parts.push(text),
+        (None, None) if month.any => parts.push("每天".to_string()),
+        (None, None) => {}
+    }
+    parts.push(time);
+    parts.join(" ")
+}
+
+// 按天向后查找，只在匹配的日期里枚举小时和分钟
+fn next_runs<Tz: TimeZone>(schedule: &Schedule, after: DateTime<Tz>, count: usize) -> Vec<DateTime<Tz>> {
+    let timezone = after.timezone();
+    let start = after.naive_local();
+    let mut runs = Vec::new();
+
+    for offset in 0..SEARCH_DAYS {
+        let date = start.date() + Duration::days(offset);
+        if !schedule.matches_date(date) {
+            continue;
+        }
+        for hour in (0..24).filter(|hour| schedule.hour.matches(*hour)) {
+            for minute in (0..60).filter(|minute| schedule.minute.matches(*minute)) {
+                let Some(time) = date.and_hms_opt(hour, minute, 0) else { continue };
+                if time <= start {
+                    continue;
+                }
+                // 夏令时跳过的时间不存在，重复的时间只取第一次
+                if let Some(run) = timezone.from_local_datetime(&time).earliest() {
+                    runs.push(run);
+                    if runs.len() == count {
+                        return runs;
+                    }
+                }
+            }
+        }
+    }
+    runs
+}
+
+fn format_run<Tz: TimeZone>(run: &DateTime<Tz>) -> String {
+    format!(
+        "{} {}",
+        run.naive_local().format("%Y-%m-%d %H:%M"),
+        WEEKDAY_LABELS[run.weekday().num_days_from_sunday() as usize]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn runs(expression: &str, after: &str, count: usize) -> Vec<String> {
+        let after = Utc.from_utc_datetime(&chrono::NaiveDateTime::parse_from_str(after, "%Y-%m-%d %H:%M").unwrap());
+        next_runs(&parse(expression).unwrap(), after, count).iter().map(format_run).collect()
+    }
+
+    #[test]
+    fn test_describe() {
+        let description = |expression: &str| describe(&parse(expression).unwrap());
+        assert_eq!(description("0 5 * * 1-5"), "每周一至周五 05:00");
+        assert_eq!(description("*/15 9-17 * * *"), "每天 9至17 点的每 15 分钟");
+        assert_eq!(description("* * * * *"), "每天 每分钟");
+        assert_eq!(description("30 * * * *"), "每天 每小时的第 30 分钟");
+        assert_eq!(description("@yearly"), "每年 1 月 1 日 00:00");
+        assert_eq!(description("0 0,12 1,15 * SUN"), "每月 1、15 日或每周日 00:00、12:00");
+        assert_eq!(description("0 8 * jan-mar 7"), "每年 1至3 月 每周日 08:00");
+    }
+
+    #[test]
+    fn test_next_runs() {
+        // 2024-06-01 是周六
+        assert_eq!(
+            runs("0 5 * * 1-5", "2024-06-01 10:00", 3),
+            ["2024-06-03 05:00 周一", "2024-06-04 05:00 周二", "2024-06-05 05:00 周三"]
+        );
+        assert_eq!(runs("*/20 * * * *", "2024-06-01 10:20", 2), ["2024-06-01 10:40 周六", "2024-06-01 11:00 周六"]);
+        // 日和周都有限制时满足其一即可
+        assert_eq!(runs("0 0 13 * 5", "2024-09-01 00:00", 2), ["2024-09-06 00:00 周五", "2024-09-13 00:00 周五"]);
+        assert_eq!(runs("0 0 29 2 *", "2024-03-01 00:00", 1), ["2028-02-29 00:00 周二"]);
+        assert!(runs("0 0 31 2 *", "2024-03-01 00:00", 1).is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("0 5 * *").unwrap_err().contains("5 段"));
+        assert!(parse("60 * * * *").unwrap_err().contains("0 到 59"));
+        assert!(parse("0 17-9 * * *").unwrap_err().contains("起始值大于结束值"));
+        assert!(parse("*/0 * * * *").unwrap_err().contains("步长"));
+        assert!(parse("0 0 * * FOO").is_err());
+        assert!(parse("@reboot").is_err());
+    }
+}
@@ -0,0 +1,176 @@
+// 本地 SQLite 数据库，保存提醒、待办、快捷链接、使用记录等需要持久化的数据
+use rusqlite::Connection;
+use std::fs;
+use std::sync::{Mutex, OnceLock};
+use tauri::{AppHandle, Manager};
+
+const DB_FILE_NAME: &str = "launch-rs.db";
+
+static DB: OnceLock<Mutex<Connection>> = OnceLock::new();
+
+// 数据库迁移，按顺序执行一次，已执行的版本号记录在 user_version 中
+const MIGRATIONS: &[&str] = &[
+    // 1: 提醒
+    "CREATE TABLE reminders (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        message TEXT NOT NULL,
+        due_at INTEGER NOT NULL,
+        created_at INTEGER NOT NULL,
+        delivered INTEGER NOT NULL DEFAULT 0
+    );
+    CREATE INDEX idx_reminders_due ON reminders (delivered, due_at);",
+    // 2: 待办事项，有截止时间的待办关联一条提醒
+    "CREATE TABLE todos (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        title TEXT NOT NULL,
+        done INTEGER NOT NULL DEFAULT 0,
+        created_at INTEGER NOT NULL,
+        completed_at INTEGER,
+        due_at INTEGER,
+        reminder_id INTEGER
+    );",
+    // 3: 快捷链接，关键词不区分大小写
+    "CREATE TABLE quicklinks (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        keyword TEXT NOT NULL UNIQUE COLLATE NOCASE,
+        name TEXT NOT NULL,
+        url TEXT NOT NULL,
+        created_at INTEGER NOT NULL
+    );",
+    // 4: RSS/Atom 订阅源和条目，seen_at 为最近一次在订阅源中看到该条目的时间
+    "CREATE TABLE feeds (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        url TEXT NOT NULL UNIQUE,
+        title TEXT NOT NULL,
+        created_at INTEGER NOT NULL,
+        fetched_at INTEGER,
+        last_error TEXT
+    );
+    CREATE TABLE feed_items (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        feed_id INTEGER NOT NULL,
+        guid TEXT NOT NULL,
+        title TEXT NOT NULL,
+        link TEXT,
+        summary TEXT,
+        published_at INTEGER,
+        seen_at INTEGER NOT NULL,
+        read INTEGER NOT NULL DEFAULT 0,
+        UNIQUE (feed_id, guid)
+    );
+    CREATE INDEX idx_feed_items_unread ON feed_items (read, published_at);",
+    // 5: 使用记录：每次搜索的耗时（不保存查询内容）、各插件耗时以及用户选中的结果
+    "CREATE TABLE searches (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        latency_ms INTEGER NOT NULL,
+        result_count INTEGER NOT NULL,
+        created_at INTEGER NOT NULL
+    );
+    CREATE INDEX idx_searches_created ON searches (created_at);
+    CREATE TABLE provider_timings (
+        search_id INTEGER NOT NULL,
+        provider TEXT NOT NULL,
+        latency_ms INTEGER NOT NULL,
+        result_count INTEGER NOT NULL
+    );
+    CREATE INDEX idx_provider_timings_search ON provider_timings (search_id);
+    CREATE TABLE selections (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        query TEXT NOT NULL,
+        result_id TEXT NOT NULL,
+        title TEXT NOT NULL,
+        provider TEXT NOT NULL,
+        created_at INTEGER NOT NULL
+    );
+    CREATE INDEX idx_selections_created ON selections (created_at);",
+    // 6: 网络共享（SMB/NFS）及其文件列表缓存，checked_at 为最近一次尝试索引的时间，indexed_at 为最近一次成功的时间
+    "CREATE TABLE network_shares (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        url TEXT NOT NULL UNIQUE,
+        username TEXT,
+        created_at INTEGER NOT NULL,
+        checked_at INTEGER,
+        indexed_at INTEGER,
+        last_error TEXT
+    );
+    CREATE TABLE network_share_entries (
+        share_id INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        path TEXT NOT NULL,
+        is_dir INTEGER NOT NULL,
+        size INTEGER NOT NULL,
+        modified INTEGER NOT NULL
+    );
+    CREATE INDEX idx_network_share_entries_share ON network_share_entries (share_id);",
+];
+
+// 打开应用数据目录下的数据库并执行迁移
+pub(crate) fn init(app_handle: &AppHandle) -> Result<(), String> {
+    let data_dir = app_handle.path().app_data_dir()
+        .map_err(|e| format!("获取应用数据目录失败: {}", e))?;
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("创建应用数据目录失败: {}", e))?;
+
+    let conn = Connection::open(data_dir.join(DB_FILE_NAME))
+        .map_err(|e| format!("打开数据库失败: {}", e))?;
+    migrate(&conn).map_err(|e| format!("数据库迁移失败: {}", e))?;
+
+    DB.set(Mutex::new(conn))
+        .map_err(|_| "数据库已初始化".to_string())
+}
+
+pub(crate) fn migrate(conn: &Connection) -> rusqlite::Result<()> {
+    let version: usize = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+
+    for (index, sql) in MIGRATIONS.iter().enumerate().skip(version) {
+        conn.execute_batch(sql)?;
+        conn.pragma_update(None, "user_version", (index + 1) as i64)?;
+    }
+
+    Ok(())
+}
+
+// 在全局连接上执行数据库操作
+pub(crate) fn with_connection<T, F>(f: F) -> Result<T, String>
+where
+    F: FnOnce(&Connection) -> rusqlite::Result<T>,
+{
+    let db = DB.get().ok_or("数据库未初始化")?;
+    let conn = db.lock().unwrap();
+    f(&conn).map_err(|e| format!("数据库操作失败: {}", e))
+}
+
+// 测试用的内存数据库
+#[cfg(test)]
+pub(crate) fn open_in_memory() -> Connection {
+    let conn = Connection::open_in_memory().expect("Failed to open in-memory database");
+    migrate(&conn).expect("Failed to migrate in-memory database");
+    conn
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_sets_version() {
+        let conn = open_in_memory();
+        let version: usize = conn.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(version, MIGRATIONS.len());
+    }
+
+    #[test]
+    fn test_migrate_is_idempotent() {
+        let conn = open_in_memory();
+        // 再次迁移不应重复建表
+        migrate(&conn).expect("Second migration should be a no-op");
+    }
+
+    #[test]
+    fn test_with_connection_uninitialized() {
+        // 测试环境中未调用 init
+        if DB.get().is_none() {
+            assert!(with_connection(|_| Ok(())).is_err());
+        }
+    }
+}
@@ -0,0 +1,165 @@
+// 勿扰模式：读取和切换系统的勿扰 / 专注模式（GNOME / KDE / dunst / macOS 快捷指令 / Windows 专注助手）
+use crate::system_command::{powershell, run};
+use chrono::{Local, NaiveDate, NaiveDateTime};
+
+// KDE 把勿扰的截止时间写入 plasmanotifyrc，设为很远的将来表示一直开启
+const KDE_FOREVER: &str = "2099,12,31,0,0,0";
+
+// 读取 WNF_SHEL_QUIETHOURS_ACTIVE_PROFILE_CHANGED：0 关闭，1 仅优先通知，2 仅闹钟
+const WINDOWS_FOCUS_ASSIST_SCRIPT: &str = r#"Add-Type -Namespace LaunchRs -Name Wnf -MemberDefinition '[DllImport("ntdll.dll")] public static extern int NtQueryWnfStateData(ref ulong StateName, IntPtr TypeId, IntPtr ExplicitScope, out uint ChangeStamp, byte[] Buffer, ref uint BufferSize);'
+$name = [uint64]'0x0D83063EA3BF1C75'
+$stamp = [uint32]0
+$buffer = New-Object byte[] 4
+$size = [uint32]4
+[void][LaunchRs.Wnf]::NtQueryWnfStateData([ref]$name, [IntPtr]::Zero, [IntPtr]::Zero, [ref]$stamp, $buffer, [ref]$size)
+[BitConverter]::ToInt32($buffer, 0)"#;
+
+// 当前是否开启勿扰，无法读取时返回 None
+#[tauri::command(async)]
+pub fn get_dnd_state() -> Result<Option<bool>, String> {
+    match std::env::consts::OS {
+        "linux" => match linux_backend() {
+            LinuxBackend::Gnome => {
+                let output = run("gsettings", &["get", "org.gnome.desktop.notifications", "show-banners"])?;
+                Ok(Some(output.trim() == "false"))
+            }
+            LinuxBackend::Kde => {
+                let until = kde_config(&["kreadconfig6", "kreadconfig5"], &["--file", "plasmanotifyrc", "--group", "DoNotDisturb", "--key", "Until"])?;
+                Ok(Some(parse_kde_until(&until).is_some_and(|until| until > Local::now().naive_local())))
+            }
+            LinuxBackend::Dunst => Ok(Some(run("dunstctl", &["is-paused"])?.trim() == "true")),
+        },
+        // 专注模式的状态需要"完全磁盘访问权限"才能读取
+        "macos" => {
+            let path = dirs::home_dir()
+                .ok_or_else(|| "无法获取用户主目录".to_string())?
+                .join("Library/DoNotDisturb/DB/Assertions.json");
+            Ok(std::fs::read_to_string(path).ok().and_then(|content| parse_mac_assertions(&content)))
+        }
+        "windows" => Ok(powershell(WINDOWS_FOCUS_ASSIST_SCRIPT).ok()
+            .and_then(|output| output.trim().parse::<i32>().ok())
+            .map(|profile| profile != 0)),
+        os => Err(format!("当前系统不支持勿扰模式: {}", os)),
+    }
+}
+
+// enabled 为空时切换；macOS 通过用户创建的快捷指令切换专注模式
+#[tauri::command(async)]
+pub fn set_dnd(enabled: Option<bool>, mac_shortcut: Option<String>) -> Result<Option<bool>, String> {
+    let current = get_dnd_state()?;
+    let target = match (enabled, current) {
+        (Some(enabled), _) => enabled,
+        (None, Some(current)) => !current,
+        // 状态未知时默认开启
+        (None, None) => true,
+    };
+    if current == Some(target) {
+        return Ok(current);
+    }
+
+    match std::env::consts::OS {
+        "linux" => match linux_backend() {
+            LinuxBackend::Gnome => {
+                run("gsettings", &["set", "org.gnome.desktop.notifications", "show-banners", if target { "false" } else { "true" }])?;
+            }
+            LinuxBackend::Kde => {
+                let mut args = vec!["--file", "plasmanotifyrc", "--group", "DoNotDisturb", "--key", "Until", "--notify"];
+                if target {
+                    args.push(KDE_FOREVER);
+                } else {
+                    args.push("--delete");
+                }
+                kde_config(&["kwriteconfig6", "kwriteconfig5"], &args)?;
+            }
+            LinuxBackend::Dunst => {
+                run("dunstctl", &["set-paused", if target { "true" } else { "false" }])?;
+            }
+        },
+        "macos" => {
+            let shortcut = mac_shortcut.filter(|name| !name.trim().is_empty())
+                .ok_or_else(|| "请先在插件设置中填写用于切换专注模式的快捷指令名称".to_string())?;
+            run("shortcuts", &["run", &shortcut])?;
+        }
+        "windows" => return Err("Windows 没有切换专注助手的公开接口，请在系统设置中操作".to_string()),
+        os => return Err(format!("当前系统不支持勿扰模式: {}", os)),
+    }
+
+    // macOS 的状态可能读不到，按切换结果返回
+    Ok(get_dnd_state()?.or(Some(target)))
+}
+
+enum LinuxBackend {
+    Gnome,
+    Kde,
+    Dunst,
+}
+
+fn linux_backend() -> LinuxBackend {
+    let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default().to_uppercase();
+    if desktop.contains("KDE") {
+        LinuxBackend::Kde
+    } else if desktop.contains("GNOME") || desktop.contains("UNITY") || desktop.contains("BUDGIE") {
+        LinuxBackend::Gnome
+    } else {
+        LinuxBackend::Dunst
+    }
+}
+
+// Plasma 6 和 Plasma 5 的命令名不同
+fn kde_config(programs: &[&str], args: &[&str]) -> Result<String, String> {
+    let mut last_error = String::new();
+    for program in programs {
+        match run(program, args) {
+            Ok(output) => return Ok(output),
+            Err(e) => last_error = e,
+        }
+    }
+    Err(last_error)
+}
+
+// KConfig 中的日期时间格式为 "年,月,日,时,分,秒"
+fn parse_kde_until(value: &str) -> Option<NaiveDateTime> {
+    let parts: Vec<u32> = value.trim().split(',')
+        .map(|part| part.trim().parse().ok())
+        .collect::<Option<_>>()?;
+    match parts[..] {
+        [year, month, day, hour, minute, second] => NaiveDate::from_ymd_opt(year as i32, month, day)?
+            .and_hms_opt(hour, minute, second),
+        _ => None,
+    }
+}
+
+// 有任何专注模式的断言记录即表示已开启
+fn parse_mac_assertions(content: &str) -> Option<bool> {
+    let value: serde_json::Value = serde_json::from_str(content).ok()?;
+    let records = value.get("data")?.as_array()?;
+    Some(records.iter().any(|record| {
+        record.get("storeAssertionRecords")
+            .and_then(|records| records.as_array())
+            .is_some_and(|records| !records.is_empty())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kde_until() {
+        assert_eq!(
+            parse_kde_until(KDE_FOREVER),
+            NaiveDate::from_ymd_opt(2099, 12, 31).unwrap().and_hms_opt(0, 0, 0)
+        );
+        assert_eq!(parse_kde_until(""), None);
+        assert_eq!(parse_kde_until("2024,2,30,0,0,0"), None);
+    }
+
+    #[test]
+    fn test_parse_mac_assertions() {
+        let on = r#"{"data": [{"storeAssertionRecords": [{"assertionDetails": {"assertionDetailsModeIdentifier": "com.apple.donotdisturb.mode.default"}}]}]}"#;
+        let off = r#"{"data": [{}]}"#;
+        assert_eq!(parse_mac_assertions(on), Some(true));
+        assert_eq!(parse_mac_assertions(off), Some(false));
+        assert_eq!(parse_mac_assertions("{}"), None);
+    }
+}
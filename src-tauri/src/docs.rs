@@ -0,0 +1,596 @@
+// 离线文档：索引本机下载的 Dash / Zeal docset 和 DevDocs 文档，按 API 名称查找并以纯文本预览
+use crate::http::decode_entities;
+use rusqlite::{Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+pub(crate) const REFRESH_INTERVAL: Duration = Duration::from_secs(30 * 60);
+const DEFAULT_LIMIT: usize = 20;
+const MAX_PREVIEW_CHARS: usize = 20_000;
+const DEVDOCS_URL: &str = "https://devdocs.io";
+// 预览时转为换行的块级元素
+const BLOCK_TAGS: &[&str] = &[
+    "p", "div", "section", "article", "header", "footer", "table", "tr", "ul", "ol", "dl", "dt", "dd",
+    "h1", "h2", "h3", "h4", "h5", "h6", "blockquote", "hr", "pre",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum DocsetFormat {
+    // Contents/Resources/docSet.dsidx 索引 + Documents 目录中的 HTML
+    Dash,
+    // index.json 索引 + db.json 中按路径保存的 HTML
+    DevDocs,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct IndexEntry {
+    name: String,
+    lower_name: String,
+    kind: String,
+    path: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Docset {
+    name: String,
+    // 查询的第一个词与之相同时只在该文档中查找，例如 `rust Vec::retain`
+    keyword: String,
+    root: PathBuf,
+    format: DocsetFormat,
+    entries: Vec<IndexEntry>,
+}
+
+// docset_path 用于预览时定位文档；target 为回车打开的网址或本地文件
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DocEntry {
+    pub docset: String,
+    pub docset_path: String,
+    pub name: String,
+    pub kind: String,
+    pub path: String,
+    pub target: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DevDocsIndex {
+    entries: Vec<DevDocsEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DevDocsEntry {
+    name: String,
+    path: String,
+    #[serde(rename = "type", default)]
+    kind: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DevDocsMeta {
+    name: Option<String>,
+    release: Option<String>,
+}
+
+static ROOTS: LazyLock<Mutex<Vec<String>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+// 上次加载的文档索引，由定时任务刷新
+static DOCSETS: LazyLock<Mutex<Vec<Docset>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+// DevDocs 的 db.json 较大，保留最近一次读取的内容供连续预览
+type DevDocsDb = (PathBuf, HashMap<String, String>);
+static DEVDOCS_DB: LazyLock<Mutex<Option<DevDocsDb>>> = LazyLock::new(|| Mutex::new(None));
+
+// 更新额外的文档目录（Zeal / Dash 的默认目录总会扫描），目录有变化时立即重新加载，返回文档数量
+#[tauri::command(async)]
+pub fn set_doc_roots(app_handle: AppHandle, roots: Vec<String>) -> Result<usize, String> {
+    let roots: Vec<String> = roots.into_iter().map(|root| root.trim().to_string()).filter(|root| !root.is_empty()).collect();
+    let changed = {
+        let mut current = ROOTS.lock().unwrap();
+        let changed = *current != roots;
+        *current = roots;
+        changed
+    };
+    if changed || DOCSETS.lock().unwrap().is_empty() {
+        refresh(&app_handle);
+    }
+    Ok(DOCSETS.lock().unwrap().len())
+}
+
+// 定时任务：重新加载文档索引，下载了新文档时通知前端
+pub(crate) fn refresh(app_handle: &AppHandle) {
+    let mut dirs = Vec::new();
+    let mut roots = default_roots();
+    roots.extend(ROOTS.lock().unwrap().iter().map(|root| expand_home(root)));
+    for root in &roots {
+        scan_dir(root, 0, &mut dirs);
+    }
+    dirs.sort();
+    dirs.dedup();
+
+    let unchanged = {
+        let docsets = DOCSETS.lock().unwrap();
+        docsets.len() == dirs.len() && docsets.iter().zip(&dirs).all(|(docset, (dir, _))| docset.root == *dir)
+    };
+    if unchanged {
+        return;
+    }
+
+    let docsets: Vec<Docset> = dirs.into_iter().filter_map(|(dir, format)| load_docset(&dir, format)).collect();
+    let count = docsets.len();
+    *DOCSETS.lock().unwrap() = docsets;
+    *DEVDOCS_DB.lock().unwrap() = None;
+    let _ = app_handle.emit("docs-changed", count);
+}
+
+#[tauri::command(async)]
+pub fn search_docs(query: String, limit: Option<usize>) -> Result<Vec<DocEntry>, String> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let docsets = DOCSETS.lock().unwrap();
+    // 第一个词是文档名时只在该文档中查找
+    let (scope, term) = match query.split_once(char::is_whitespace) {
+        Some((first, rest)) if docsets.iter().any(|docset| docset.keyword == first) => (Some(first), rest.trim()),
+        _ => (None, query.as_str()),
+    };
+
+    let mut matches: Vec<(usize, &Docset, &IndexEntry)> = docsets.iter()
+        .filter(|docset| scope.is_none_or(|scope| docset.keyword == scope))
+        .flat_map(|docset| docset.entries.iter().filter_map(move |entry| match_rank(&entry.lower_name, term).map(|rank| (rank, docset, entry))))
+        .collect();
+    matches.sort_by_key(|(rank, _, entry)| (*rank, entry.name.len()));
+    matches.truncate(limit.unwrap_or(DEFAULT_LIMIT));
+
+    Ok(matches.into_iter()
+        .map(|(_, docset, entry)| DocEntry {
+            docset: docset.name.clone(),
+            docset_path: docset.root.to_string_lossy().to_string(),
+            name: entry.name.clone(),
+            kind: entry.kind.clone(),
+            path: entry.path.clone(),
+            target: entry_target(docset, &entry.path),
+        })
+        .collect())
+}
+
+// 返回文档条目的纯文本内容，从条目的锚点处开始
+#[tauri::command(async)]
+pub fn render_doc_entry(docset_path: String, path: String) -> Result<String, String> {
+    let (root, format) = {
+        let docsets = DOCSETS.lock().unwrap();
+        let docset = docsets.iter().find(|docset| docset.root == Path::new(&docset_path))
+            .ok_or_else(|| format!("未找到文档: {}", docset_path))?;
+        (docset.root.clone(), docset.format)
+    };
+    if path.starts_with("http://") || path.starts_with("https://") {
+        return Ok(format!("在线文档：{}", path));
+    }
+
+    let (file, anchor) = match path.split_once('#') {
+        Some((file, anchor)) => (file, Some(anchor)),
+        None => (path.as_str(), None),
+    };
+    let html = match format {
+        DocsetFormat::Dash => read_dash_document(&root, file)?,
+        DocsetFormat::DevDocs => read_devdocs_document(&root, file)?,
+    };
+    Ok(html_to_text(&html, anchor))
+}
+
+fn default_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    if let Some(data) = dirs::data_dir() {
+        // Zeal：Linux 为 ~/.local/share/Zeal/Zeal/docsets，Windows 为 %APPDATA%\Zeal\Zeal\docsets
+        roots.push(data.join("Zeal").join("Zeal").join("docsets"));
+        // Dash：~/Library/Application Support/Dash/DocSets/<名称>/<名称>.docset
+        roots.push(data.join("Dash").join("DocSets"));
+    }
+    if let Some(local) = dirs::data_local_dir() {
+        roots.push(local.join("Zeal").join("Zeal").join("docsets"));
+    }
+    roots
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ if path == "~" => dirs::home_dir().unwrap_or_else(|| PathBuf::from(path)),
+        _ => PathBuf::from(path),
+    }
+}
+
+// 文档目录最多嵌套两层（Dash 为 DocSets/<名称>/<名称>.docset）
+fn scan_dir(dir: &Path, depth: usize, docsets: &mut Vec<(PathBuf, DocsetFormat)>) {
+    if let Some(format) = docset_format(dir) {
+        docsets.push((dir.to_path_buf(), format));
+        return;
+    }
+    if depth >= 2 {
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) && !entry.file_name().to_string_lossy().starts_with('.') {
+            scan_dir(&entry.path(), depth + 1, docsets);
+        }
+    }
+}
+
+fn docset_format(dir: &Path) -> Option<DocsetFormat> {
+    if dir.join("Contents").join("Resources").join("docSet.dsidx").is_file() {
+        Some(DocsetFormat::Dash)
+    } else if dir.join("index.json").is_file() && dir.join("db.json").is_file() {
+        Some(DocsetFormat::DevDocs)
+    } else {
+        None
+    }
+}
+
+fn load_docset(dir: &Path, format: DocsetFormat) -> Option<Docset> {
+    let dir_name = dir.file_name()?.to_string_lossy().trim_end_matches(".docset").to_string();
+    let (name, keyword, entries) = match format {
+        DocsetFormat::Dash => {
+            let plist = fs::read_to_string(dir.join("Contents").join("Info.plist")).unwrap_or_default();
+            let name = plist_string(&plist, "CFBundleName").unwrap_or_else(|| dir_name.clone());
+            let keyword = plist_string(&plist, "DocSetPlatformFamily").unwrap_or_else(|| dir_name.clone());
+            let entries = read_dash_index(&dir.join("Contents").join("Resources").join("docSet.dsidx")).ok()?;
+            (name, keyword, entries)
+        }
+        DocsetFormat::DevDocs => {
+            // 目录名为 DevDocs 的 slug，例如 rust、python~3.12
+            let meta: Option<DevDocsMeta> = fs::read_to_string(dir.join("meta.json")).ok().and_then(|meta| serde_json::from_str(&meta).ok());
+            let name = match meta {
+                Some(DevDocsMeta { name: Some(name), release: Some(release) }) => format!("{} {}", name, release),
+                Some(DevDocsMeta { name: Some(name), .. }) => name,
+                _ => dir_name.clone(),
+            };
+            let keyword = dir_name.split('~').next().unwrap_or(&dir_name).to_string();
+            let entries = parse_devdocs_index(&fs::read_to_string(dir.join("index.json")).ok()?).ok()?;
+            (name, keyword, entries)
+        }
+    };
+
+    Some(Docset {
+        name,
+        keyword: keyword.to_lowercase(),
+        root: dir.to_path_buf(),
+        format,
+        entries,
+    })
+}
+
+fn plist_string(plist: &str, key: &str) -> Option<String> {
+    let rest = &plist[plist.find(&format!("<key>{}</key>", key))?..];
+    let start = rest.find("<string>")? + "<string>".len();
+    let end = rest[start..].find("</string>")?;
+    Some(decode_entities(rest[start..start + end].trim())).filter(|value| !value.is_empty())
+}
+
+// 新格式使用 searchIndex 表，旧格式为 Core Data 生成的 ZTOKEN 等表
+fn read_dash_index(database: &Path) -> rusqlite::Result<Vec<IndexEntry>> {
+    let conn = Connection::open_with_flags(database, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let has_search_index: bool = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = 'searchIndex'",
+        [],
+        |row| row.get(0),
+    )?;
+    let sql = if has_search_index {
+        "SELECT name, type, path FROM searchIndex"
+    } else {
+        "SELECT ztokenname, ztypename, zpath || CASE WHEN zanchor IS NULL THEN '' ELSE '#' || zanchor END
+         FROM ztoken
+         JOIN ztokenmetainformation ON ztoken.zmetainformation = ztokenmetainformation.z_pk
+         JOIN zfilepath ON ztokenmetainformation.zfile = zfilepath.z_pk
+         JOIN ztokentype ON ztoken.ztokentype = ztokentype.z_pk"
+    };
+
+    let mut stmt = conn.prepare(sql)?;
+    let entries = stmt.query_map([], |row| {
+        let name: String = row.get(0)?;
+        let kind: Option<String> = row.get(1)?;
+        let path: String = row.get(2)?;
+        Ok(IndexEntry {
+            lower_name: name.to_lowercase(),
+            name,
+            kind: kind.unwrap_or_default(),
+            path: strip_dash_entry_tags(&path),
+        })
+    })?;
+    entries.collect()
+}
+
+// 路径中可能带有 <dash_entry_name=...> 这类只给 Dash 使用的标记
+fn strip_dash_entry_tags(path: &str) -> String {
+    let mut result = String::with_capacity(path.len());
+    let mut rest = path;
+    while let Some(start) = rest.find("<dash_entry_") {
+        result.push_str(&rest[..start]);
+        match rest[start..].find('>') {
+            Some(end) => rest = &rest[start + end + 1..],
+            None => rest = "",
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn parse_devdocs_index(json: &str) -> Result<Vec<IndexEntry>, String> {
+    let index: DevDocsIndex = serde_json::from_str(json).map_err(|e| format!("解析 DevDocs 索引失败: {}", e))?;
+    Ok(index.entries.into_iter()
+        .map(|entry| IndexEntry {
+            lower_name: entry.name.to_lowercase(),
+            name: entry.name,
+            kind: entry.kind,
+            path: entry.path,
+        })
+        .collect())
+}
+
+// 完全相同最优先，其次是名称最后一段相同（std::vec::Vec::retain 匹配 retain），然后是前缀和包含
+fn match_rank(name: &str, term: &str) -> Option<usize> {
+    if term.is_empty() {
+        return None;
+    }
+    if name == term {
+        Some(0)
+    } else if name.ends_with(term) && name[..name.len() - term.len()].ends_with([':', '.', '#', '/', ' ']) {
+        Some(1)
+    } else if name.starts_with(term) {
+        Some(2)
+    } else if name.contains(term) {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+fn entry_target(docset: &Docset, path: &str) -> String {
+    if path.starts_with("http://") || path.starts_with("https://") {
+        return path.to_string();
+    }
+    match docset.format {
+        DocsetFormat::Dash => dash_documents(&docset.root)
+            .join(path.split('#').next().unwrap_or(path))
+            .to_string_lossy()
+            .to_string(),
+        DocsetFormat::DevDocs => {
+            let slug = docset.root.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            format!("{}/{}/{}", DEVDOCS_URL, slug, path)
+        }
+    }
+}
+
+fn dash_documents(root: &Path) -> PathBuf {
+    root.join("Contents").join("Resources").join("Documents")
+}
+
+// 只允许读取 Documents 目录中的文件
+fn read_dash_document(root: &Path, file: &str) -> Result<String, String> {
+    let documents = dash_documents(root).canonicalize().map_err(|e| format!("读取文档目录失败: {}", e))?;
+    let path = documents.join(file).canonicalize().map_err(|e| format!("读取文档失败: {}", e))?;
+    if !path.starts_with(&documents) {
+        return Err(format!("无效的文档路径: {}", file));
+    }
+    fs::read(&path)
+        .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+        .map_err(|e| format!("读取文档失败: {}", e))
+}
+
+fn read_devdocs_document(root: &Path, file: &str) -> Result<String, String> {
+    let mut cached = DEVDOCS_DB.lock().unwrap();
+    if cached.as_ref().is_none_or(|(path, _)| path != root) {
+        let json = fs::read_to_string(root.join("db.json")).map_err(|e| format!("读取 DevDocs 文档失败: {}", e))?;
+        let db: HashMap<String, String> = serde_json::from_str(&json).map_err(|e| format!("解析 DevDocs 文档失败: {}", e))?;
+        *cached = Some((root.to_path_buf(), db));
+    }
+    cached.as_ref()
+        .and_then(|(_, db)| db.get(file).cloned())
+        .ok_or_else(|| format!("未找到文档: {}", file))
+}
+
+// 简单地把 HTML 转为纯文本：去掉标签、脚本和样式，块级元素换行，保留 <pre> 中的空白
+fn html_to_text(html: &str, anchor: Option<&str>) -> String {
+    let html = anchor.and_then(|anchor| find_anchor(html, anchor))
+        .or_else(|| html.find("<body").map(|start| html[start..].find('>').map_or(start, |end| start + end + 1)))
+        .map_or(html, |start| &html[start..]);
+
+    let mut text = String::new();
+    let mut in_pre = false;
+    let mut rest = html;
+    while !rest.is_empty() && text.chars().count() < MAX_PREVIEW_CHARS {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut text, rest, in_pre);
+            break;
+        };
+        push_text(&mut text, &rest[..start], in_pre);
+        rest = &rest[start..];
+
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name: String = tag.trim_start_matches('/').chars().take_while(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase();
+        match name.as_str() {
+            "script" | "style" | "head" if !closing => {
+                rest = rest.find(&format!("</{}", name)).map_or("", |end| &rest[end..]);
+            }
+            "br" => text.push('\n'),
+            "li" if !closing => {
+                new_line(&mut text);
+                text.push_str("• ");
+            }
+            _ if BLOCK_TAGS.contains(&name.as_str()) => {
+                if name == "pre" {
+                    in_pre = !closing;
+                }
+                new_line(&mut text);
+            }
+            _ => {}
+        }
+    }
+
+    let mut result = String::new();
+    for line in text.lines().map(str::trim_end) {
+        if line.is_empty() && (result.is_empty() || result.ends_with("\n\n")) {
+            continue;
+        }
+        result.push_str(line);
+        result.push('\n');
+    }
+    let result = result.trim_end().to_string();
+    if result.chars().count() > MAX_PREVIEW_CHARS {
+        format!("{}…", result.chars().take(MAX_PREVIEW_CHARS).collect::<String>())
+    } else {
+        result
+    }
+}
+
+// 锚点所在元素的起始位置
+fn find_anchor(html: &str, anchor: &str) -> Option<usize> {
+    let position = [format!("id=\"{}\"", anchor), format!("name=\"{}\"", anchor), format!("id='{}'", anchor)]
+        .iter()
+        .filter_map(|pattern| html.find(pattern.as_str()))
+        .min()?;
+    html[..position].rfind('<')
+}
+
+fn push_text(text: &mut String, raw: &str, in_pre: bool) {
+    let decoded = decode_entities(raw);
+    if in_pre {
+        text.push_str(&decoded);
+        return;
+    }
+    for (index, word) in decoded.split_whitespace().enumerate() {
+        let needs_space = index > 0 || decoded.starts_with(char::is_whitespace);
+        if needs_space && !text.is_empty() && !text.ends_with(['\n', ' ']) {
+            text.push(' ');
+        }
+        text.push_str(word);
+    }
+    if decoded.ends_with(char::is_whitespace) && !decoded.trim().is_empty() {
+        text.push(' ');
+    }
+}
+
+fn new_line(text: &mut String) {
+    while text.ends_with(' ') {
+        text.pop();
+    }
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_scan_dir() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("Rust.docset/Contents/Resources")).unwrap();
+        fs::write(root.join("Rust.docset/Contents/Resources/docSet.dsidx"), "").unwrap();
+        fs::create_dir_all(root.join("Python/Python.docset/Contents/Resources")).unwrap();
+        fs::write(root.join("Python/Python.docset/Contents/Resources/docSet.dsidx"), "").unwrap();
+        fs::create_dir_all(root.join("devdocs/rust")).unwrap();
+        fs::write(root.join("devdocs/rust/index.json"), "{}").unwrap();
+        fs::write(root.join("devdocs/rust/db.json"), "{}").unwrap();
+        fs::create_dir_all(root.join("empty/dir")).unwrap();
+
+        let mut docsets = Vec::new();
+        scan_dir(root, 0, &mut docsets);
+        docsets.sort();
+        assert_eq!(docsets, vec![
+            (root.join("Python/Python.docset"), DocsetFormat::Dash),
+            (root.join("Rust.docset"), DocsetFormat::Dash),
+            (root.join("devdocs/rust"), DocsetFormat::DevDocs),
+        ]);
+    }
+
+    #[test]
+    fn test_load_dash_docset() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("Rust.docset");
+        fs::create_dir_all(dir.join("Contents/Resources/Documents")).unwrap();
+        fs::write(dir.join("Contents/Info.plist"), r#"<plist><dict>
+            <key>CFBundleName</key><string>Rust</string>
+            <key>DocSetPlatformFamily</key><string>rust</string>
+        </dict></plist>"#).unwrap();
+        let conn = Connection::open(dir.join("Contents/Resources/docSet.dsidx")).unwrap();
+        conn.execute_batch(r#"
+            CREATE TABLE searchIndex(id INTEGER PRIMARY KEY, name TEXT, type TEXT, path TEXT);
+            INSERT INTO searchIndex(name, type, path) VALUES
+                ('std::vec::Vec::retain', 'Method', '<dash_entry_name=retain>std/vec/struct.Vec.html#method.retain'),
+                ('std::vec::Vec', 'Struct', 'std/vec/struct.Vec.html');
+        "#).unwrap();
+        drop(conn);
+
+        let docset = load_docset(&dir, DocsetFormat::Dash).unwrap();
+        assert_eq!(docset.name, "Rust");
+        assert_eq!(docset.keyword, "rust");
+        assert_eq!(docset.entries[0].path, "std/vec/struct.Vec.html#method.retain");
+        assert_eq!(docset.entries[0].lower_name, "std::vec::vec::retain");
+    }
+
+    #[test]
+    fn test_parse_devdocs_index() {
+        let entries = parse_devdocs_index(r#"{"entries": [
+            {"name": "Vec::retain", "path": "std/vec/struct.vec#method.retain", "type": "std::vec"},
+            {"name": "Array", "path": "global_objects/array"}
+        ], "types": []}"#).unwrap();
+        assert_eq!(entries[0].kind, "std::vec");
+        assert_eq!(entries[1].kind, "");
+        assert!(parse_devdocs_index("[]").is_err());
+    }
+
+    #[test]
+    fn test_match_rank() {
+        assert_eq!(match_rank("vec::retain", "vec::retain"), Some(0));
+        assert_eq!(match_rank("std::vec::vec::retain", "vec::retain"), Some(1));
+        assert_eq!(match_rank("vec::retain_mut", "vec::retain"), Some(2));
+        assert_eq!(match_rank("hashset::retain", "retain"), Some(1));
+        assert_eq!(match_rank("vecdeque::retain", "deque::retain"), Some(3));
+        assert_eq!(match_rank("string", "retain"), None);
+        assert_eq!(match_rank("string", ""), None);
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        let html = r#"<html><head><title>Vec</title><style>p { color: red }</style></head><body>
+            <h1>Struct   Vec</h1><p>A contiguous&nbsp;growable <code>array</code> type.</p>
+            <div id="method.retain"><h4>pub fn retain</h4><p>Retains only the elements.</p>
+            <pre>vec.retain(|&amp;x| x % 2 == 0);
+assert_eq!(vec, [2, 4]);</pre><ul><li>one</li><li>two</li></ul><script>track()</script></div>
+            </body></html>"#;
+
+        let text = html_to_text(html, None);
+        assert!(text.starts_with("Struct Vec\nA contiguous growable array type.\n"));
+        assert!(!text.contains("color"));
+        assert!(!text.contains("track"));
+
+        let text = html_to_text(html, Some("method.retain"));
+        assert_eq!(text, "pub fn retain\nRetains only the elements.\nvec.retain(|&x| x % 2 == 0);\nassert_eq!(vec, [2, 4]);\n• one\n• two");
+    }
+
+    #[test]
+    fn test_strip_dash_entry_tags() {
+        assert_eq!(strip_dash_entry_tags("a.html#<dash_entry_name=x><dash_entry_menuDescription=y>b"), "a.html#b");
+        assert_eq!(strip_dash_entry_tags("plain.html"), "plain.html");
+    }
+}
@@ -0,0 +1,64 @@
+// 可移动磁盘：列出已挂载的 U 盘等可移动卷并安全弹出
+use crate::sysmon::{disk_usage, DiskUsage};
+use crate::system_command::{powershell, run};
+
+#[tauri::command(async)]
+pub fn list_removable_drives() -> Vec<DiskUsage> {
+    disk_usage()
+        .into_iter()
+        .filter(|disk| disk.removable || is_removable_mount(&disk.mount_point, std::env::consts::OS))
+        .collect()
+}
+
+// 只允许弹出列表中的卷，防止误卸载系统盘
+#[tauri::command(async)]
+pub fn eject_drive(mount_point: String) -> Result<(), String> {
+    if !list_removable_drives().iter().any(|disk| disk.mount_point == mount_point) {
+        return Err(format!("不是可移动磁盘: {}", mount_point));
+    }
+
+    match std::env::consts::OS {
+        // gio 会先卸载再让设备断电，和文件管理器的"安全移除"一致
+        "linux" => run("gio", &["mount", "--eject", &mount_point]).map(|_| ()),
+        "macos" => run("diskutil", &["eject", &mount_point]).map(|_| ()),
+        // 17 号命名空间即"此电脑"，调用资源管理器的"弹出"菜单
+        "windows" => {
+            let drive = mount_point.replace('\'', "''");
+            powershell(&format!(
+                "(New-Object -ComObject Shell.Application).Namespace(17).ParseName('{}').InvokeVerb('Eject')",
+                drive
+            ))
+            .map(|_| ())
+        }
+        os => Err(format!("当前系统不支持弹出磁盘: {}", os)),
+    }
+}
+
+// 有些 U 盘不报告可移动属性，按桌面环境的自动挂载目录识别
+fn is_removable_mount(mount_point: &str, os: &str) -> bool {
+    match os {
+        "linux" => mount_point.starts_with("/media/") || mount_point.starts_with("/run/media/"),
+        "macos" => mount_point.starts_with("/Volumes/"),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_removable_mount() {
+        assert!(is_removable_mount("/run/media/alice/USB", "linux"));
+        assert!(is_removable_mount("/media/alice/SANDISK", "linux"));
+        assert!(!is_removable_mount("/home", "linux"));
+        assert!(is_removable_mount("/Volumes/Untitled", "macos"));
+        assert!(!is_removable_mount("/", "macos"));
+        assert!(!is_removable_mount("E:\\", "windows"));
+    }
+
+    #[test]
+    fn test_eject_rejects_fixed_drives() {
+        assert!(eject_drive("/".to_string()).is_err());
+    }
+}
@@ -0,0 +1,95 @@
+// 环境变量：按名称搜索当前进程的环境变量，疑似密钥的值默认打码
+use serde::{Deserialize, Serialize};
+
+const MAX_RESULTS: usize = 50;
+const MASK: &str = "••••••••";
+
+// 名称按 "_" 拆分后包含这些片段时视为密钥
+const SECRET_SEGMENTS: &[&str] = &[
+    "TOKEN", "SECRET", "SECRETS", "PASSWORD", "PASSWD", "PASS", "KEY", "APIKEY", "CREDENTIAL", "CREDENTIALS",
+    "AUTH", "PRIVATE", "COOKIE", "SESSION", "DSN",
+];
+
+// masked 为 true 时 value 为打码后的值，复制时通过 get_env_var 读取原值
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct EnvVar {
+    pub name: String,
+    pub value: String,
+    pub masked: bool,
+}
+
+// secret_patterns 为用户额外指定的敏感名称片段（不区分大小写的子串）
+#[tauri::command(async)]
+pub fn search_env_vars(query: String, secret_patterns: Option<Vec<String>>) -> Vec<EnvVar> {
+    let query = query.trim().to_lowercase();
+    let patterns = secret_patterns.unwrap_or_default();
+
+    let mut vars: Vec<(String, String)> = std::env::vars_os()
+        .map(|(name, value)| (name.to_string_lossy().to_string(), value.to_string_lossy().to_string()))
+        .filter(|(name, _)| query.is_empty() || name.to_lowercase().contains(&query))
+        .collect();
+
+    // 名称以查询开头的排在前面
+    vars.sort_by(|(a, _), (b, _)| {
+        let a_prefix = a.to_lowercase().starts_with(&query);
+        let b_prefix = b.to_lowercase().starts_with(&query);
+        b_prefix.cmp(&a_prefix).then_with(|| a.cmp(b))
+    });
+
+    vars.into_iter()
+        .take(MAX_RESULTS)
+        .map(|(name, value)| {
+            let masked = is_secret_name(&name, &patterns);
+            EnvVar {
+                value: if masked { MASK.to_string() } else { value },
+                name,
+                masked,
+            }
+        })
+        .collect()
+}
+
+#[tauri::command(async)]
+pub fn get_env_var(name: String) -> Result<String, String> {
+    std::env::var_os(&name)
+        .map(|value| value.to_string_lossy().to_string())
+        .ok_or_else(|| format!("环境变量不存在: {}", name))
+}
+
+fn is_secret_name(name: &str, extra_patterns: &[String]) -> bool {
+    let upper = name.to_uppercase();
+    upper.split(['_', '-', '.']).any(|segment| SECRET_SEGMENTS.contains(&segment))
+        || extra_patterns.iter()
+            .map(|pattern| pattern.trim().to_uppercase())
+            .any(|pattern| !pattern.is_empty() && upper.contains(&pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_secret_name() {
+        assert!(is_secret_name("GITHUB_TOKEN", &[]));
+        assert!(is_secret_name("AWS_SECRET_ACCESS_KEY", &[]));
+        assert!(is_secret_name("npm_config_authToken", &["authtoken".to_string()]));
+        assert!(is_secret_name("SENTRY_DSN", &[]));
+        assert!(!is_secret_name("PATH", &[]));
+        assert!(!is_secret_name("PWD", &[]));
+        assert!(!is_secret_name("KEYBOARD_LAYOUT", &[]));
+        assert!(!is_secret_name("HOME", &[" ".to_string()]));
+    }
+
+    #[test]
+    fn test_search_env_vars_masks_secrets() {
+        std::env::set_var("LAUNCH_RS_TEST_API_KEY", "hunter2");
+        std::env::set_var("LAUNCH_RS_TEST_EDITOR", "vim");
+
+        let vars = search_env_vars("launch_rs_test".to_string(), None);
+        assert_eq!(vars.len(), 2);
+        assert_eq!(vars[0], EnvVar { name: "LAUNCH_RS_TEST_API_KEY".into(), value: MASK.into(), masked: true });
+        assert_eq!(vars[1].value, "vim");
+        assert_eq!(get_env_var("LAUNCH_RS_TEST_API_KEY".to_string()), Ok("hunter2".to_string()));
+        assert!(get_env_var("LAUNCH_RS_TEST_MISSING".to_string()).is_err());
+    }
+}
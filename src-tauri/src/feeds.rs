@@ -0,0 +1,565 @@
+// RSS/Atom 订阅：用户添加订阅源，后台定时抓取并缓存条目，搜索框中列出未读条目
+use crate::db;
+use crate::http::{self, decode_entities};
+use chrono::{DateTime, Local};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+pub(crate) const REFRESH_INTERVAL: Duration = Duration::from_secs(30 * 60);
+const DEFAULT_LIMIT: usize = 50;
+const MAX_SUMMARY_CHARS: usize = 200;
+// 已读且已不在订阅源中的条目保留 30 天，避免重新抓取时又变回未读
+const READ_RETENTION_SECS: i64 = 30 * 24 * 60 * 60;
+
+// unread_count 为未读条目数，last_error 为最近一次抓取失败的原因
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Feed {
+    pub id: i64,
+    pub url: String,
+    pub title: String,
+    pub created_at: i64,
+    pub fetched_at: Option<i64>,
+    pub last_error: Option<String>,
+    pub unread_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FeedItem {
+    pub id: i64,
+    pub feed_id: i64,
+    pub feed_title: String,
+    pub title: String,
+    pub link: Option<String>,
+    pub summary: Option<String>,
+    pub published_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ParsedFeed {
+    title: Option<String>,
+    items: Vec<ParsedItem>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ParsedItem {
+    guid: String,
+    title: String,
+    link: Option<String>,
+    summary: Option<String>,
+    published_at: Option<i64>,
+}
+
+#[tauri::command]
+pub fn list_feeds() -> Result<Vec<Feed>, String> {
+    db::with_connection(list)
+}
+
+// 添加时先抓取一次，确认地址是有效的订阅源并取得标题
+#[tauri::command]
+pub async fn add_feed(app_handle: AppHandle, url: String) -> Result<Feed, String> {
+    let url = url.trim();
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(format!("无效的订阅源地址: {}", url));
+    }
+    if db::with_connection(|conn| find_by_url(conn, url))?.is_some() {
+        return Err(format!("订阅源已存在: {}", url));
+    }
+
+    let parsed = fetch_feed(url).await?;
+    let now = Local::now().timestamp();
+    let feed = db::with_connection(|conn| {
+        let title = parsed.title.as_deref().unwrap_or(url);
+        let id = insert(conn, url, title, now)?;
+        store_items(conn, id, &parsed.items, now)?;
+        get(conn, id)
+    })?
+    .ok_or("添加订阅源失败")?;
+
+    let _ = app_handle.emit("feeds-changed", ());
+    Ok(feed)
+}
+
+#[tauri::command]
+pub fn delete_feed(app_handle: AppHandle, id: i64) -> Result<(), String> {
+    if !db::with_connection(|conn| remove(conn, id))? {
+        return Err(format!("订阅源不存在: {}", id));
+    }
+
+    let _ = app_handle.emit("feeds-changed", ());
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_unread_feed_items(limit: Option<usize>) -> Result<Vec<FeedItem>, String> {
+    db::with_connection(|conn| list_unread(conn, limit.unwrap_or(DEFAULT_LIMIT)))
+}
+
+#[tauri::command]
+pub fn mark_feed_items_read(app_handle: AppHandle, ids: Vec<i64>) -> Result<(), String> {
+    db::with_connection(|conn| mark_read(conn, &ids))?;
+
+    let _ = app_handle.emit("feeds-changed", ());
+    Ok(())
+}
+
+// 立即抓取所有订阅源，返回新增的条目数
+#[tauri::command]
+pub async fn refresh_feeds(app_handle: AppHandle) -> Result<usize, String> {
+    let feeds = db::with_connection(list)?;
+    let added = fetch_all(&feeds).await;
+
+    let _ = app_handle.emit("feeds-changed", ());
+    Ok(added)
+}
+
+// 定时任务：抓取所有订阅源，有新条目时通知前端（在定时任务线程中等待请求完成）
+pub(crate) fn refresh(app_handle: &AppHandle) {
+    let Ok(feeds) = db::with_connection(list) else {
+        return;
+    };
+    if tauri::async_runtime::block_on(fetch_all(&feeds)) > 0 {
+        let _ = app_handle.emit("feeds-changed", ());
+    }
+}
+
+// 单个订阅源失败时记录原因，不影响其他订阅源
+async fn fetch_all(feeds: &[Feed]) -> usize {
+    let mut added = 0;
+    for feed in feeds {
+        let now = Local::now().timestamp();
+        let result = match fetch_feed(&feed.url).await {
+            Ok(parsed) => db::with_connection(|conn| {
+                let count = store_items(conn, feed.id, &parsed.items, now)?;
+                record_fetch(conn, feed.id, now, None)?;
+                Ok(count)
+            }),
+            Err(e) => db::with_connection(|conn| record_fetch(conn, feed.id, now, Some(&e)).map(|_| 0)),
+        };
+        match result {
+            Ok(count) => added += count,
+            Err(e) => log::warn!("保存订阅源失败: {}: {}", feed.url, e),
+        }
+    }
+    added
+}
+
+async fn fetch_feed(url: &str) -> Result<ParsedFeed, String> {
+    let response = http::client()?
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("抓取订阅源失败: {}", e))?;
+    let body = http::check_status(response, "订阅源").await?
+        .text()
+        .await
+        .map_err(|e| format!("读取订阅源失败: {}", e))?;
+    parse_feed(&body)
+}
+
+fn list(conn: &Connection) -> rusqlite::Result<Vec<Feed>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, url, title, created_at, fetched_at, last_error,
+                (SELECT COUNT(*) FROM feed_items WHERE feed_id = feeds.id AND read = 0)
+         FROM feeds ORDER BY title COLLATE NOCASE",
+    )?;
+    let rows = stmt.query_map([], row_to_feed)?;
+    rows.collect()
+}
+
+fn get(conn: &Connection, id: i64) -> rusqlite::Result<Option<Feed>> {
+    conn.query_row(
+        "SELECT id, url, title, created_at, fetched_at, last_error,
+                (SELECT COUNT(*) FROM feed_items WHERE feed_id = feeds.id AND read = 0)
+         FROM feeds WHERE id = ?1",
+        params![id],
+        row_to_feed,
+    )
+    .optional()
+}
+
+fn find_by_url(conn: &Connection, url: &str) -> rusqlite::Result<Option<i64>> {
+    conn.query_row("SELECT id FROM feeds WHERE url = ?1", params![url], |row| row.get(0)).optional()
+}
+
+fn insert(conn: &Connection, url: &str, title: &str, now: i64) -> rusqlite::Result<i64> {
+    conn.execute(
+        "INSERT INTO feeds (url, title, created_at) VALUES (?1, ?2, ?3)",
+        params![url, title, now],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+fn remove(conn: &Connection, id: i64) -> rusqlite::Result<bool> {
+    conn.execute("DELETE FROM feed_items WHERE feed_id = ?1", params![id])?;
+    Ok(conn.execute("DELETE FROM feeds WHERE id = ?1", params![id])? > 0)
+}
+
+fn record_fetch(conn: &Connection, id: i64, now: i64, error: Option<&str>) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE feeds SET fetched_at = ?1, last_error = ?2 WHERE id = ?3",
+        params![now, error, id],
+    )?;
+    Ok(())
+}
+
+// 保存新条目并刷新已有条目的 seen_at，返回新增的条目数
+fn store_items(conn: &Connection, feed_id: i64, items: &[ParsedItem], now: i64) -> rusqlite::Result<usize> {
+    let mut added = 0;
+    for item in items {
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO feed_items (feed_id, guid, title, link, summary, published_at, seen_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![feed_id, item.guid, item.title, item.link, item.summary, item.published_at, now],
+        )?;
+        if inserted > 0 {
+            added += 1;
+        } else {
+            conn.execute(
+                "UPDATE feed_items SET seen_at = ?1 WHERE feed_id = ?2 AND guid = ?3",
+                params![now, feed_id, item.guid],
+            )?;
+        }
+    }
+
+    conn.execute(
+        "DELETE FROM feed_items WHERE feed_id = ?1 AND read = 1 AND seen_at < ?2",
+        params![feed_id, now - READ_RETENTION_SECS],
+    )?;
+    Ok(added)
+}
+
+fn list_unread(conn: &Connection, limit: usize) -> rusqlite::Result<Vec<FeedItem>> {
+    let mut stmt = conn.prepare(
+        "SELECT feed_items.id, feed_id, feeds.title, feed_items.title, link, summary, published_at
+         FROM feed_items JOIN feeds ON feeds.id = feed_items.feed_id
+         WHERE read = 0
+         ORDER BY COALESCE(published_at, seen_at) DESC, feed_items.id DESC
+         LIMIT ?1",
+    )?;
+    let rows = stmt.query_map(params![limit as i64], |row| {
+        Ok(FeedItem {
+            id: row.get(0)?,
+            feed_id: row.get(1)?,
+            feed_title: row.get(2)?,
+            title: row.get(3)?,
+            link: row.get(4)?,
+            summary: row.get(5)?,
+            published_at: row.get(6)?,
+        })
+    })?;
+    rows.collect()
+}
+
+fn mark_read(conn: &Connection, ids: &[i64]) -> rusqlite::Result<()> {
+    for id in ids {
+        conn.execute("UPDATE feed_items SET read = 1 WHERE id = ?1", params![id])?;
+    }
+    Ok(())
+}
+
+fn row_to_feed(row: &rusqlite::Row) -> rusqlite::Result<Feed> {
+    Ok(Feed {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        title: row.get(2)?,
+        created_at: row.get(3)?,
+        fetched_at: row.get(4)?,
+        last_error: row.get(5)?,
+        unread_count: row.get(6)?,
+    })
+}
+
+// RSS 2.0 和 RSS 1.0 (RDF) 的条目为 <item>，Atom 为 <entry>
+fn parse_feed(xml: &str) -> Result<ParsedFeed, String> {
+    let atom = if xml.contains("<feed") {
+        true
+    } else if xml.contains("<rss") || xml.contains("<rdf:RDF") {
+        false
+    } else {
+        return Err("不是有效的 RSS/Atom 订阅源".to_string());
+    };
+
+    let item_tag = if atom { "entry" } else { "item" };
+    let header = xml.find(&format!("<{}", item_tag)).map_or(xml, |start| &xml[..start]);
+    let title = elements(header, "title").first().map(|(_, content)| collapse_whitespace(&text(content))).filter(|title| !title.is_empty());
+
+    let items = elements(xml, item_tag)
+        .into_iter()
+        .filter_map(|(_, content)| if atom { parse_atom_entry(content) } else { parse_rss_item(content) })
+        .collect();
+    Ok(ParsedFeed { title, items })
+}
+
+fn parse_rss_item(xml: &str) -> Option<ParsedItem> {
+    let link = child_text(xml, "link");
+    let title = child_text(xml, "title");
+    let guid = child_text(xml, "guid").or_else(|| link.clone()).or_else(|| title.clone())?;
+    let summary = ["description", "content:encoded"].iter().find_map(|tag| child_summary(xml, tag));
+    let published_at = ["pubDate", "dc:date"].iter().find_map(|tag| child_text(xml, tag).and_then(|date| parse_date(&date)));
+    Some(ParsedItem {
+        guid,
+        title: title.unwrap_or_else(|| "(无标题)".to_string()),
+        link,
+        summary,
+        published_at,
+    })
+}
+
+// Atom 的链接在 href 属性中，优先取 rel="alternate"（省略 rel 时即为 alternate）
+fn parse_atom_entry(xml: &str) -> Option<ParsedItem> {
+    let links = elements(xml, "link");
+    let link = links.iter()
+        .find(|(attrs, _)| attribute(attrs, "rel").is_none_or(|rel| rel == "alternate"))
+        .or_else(|| links.first())
+        .and_then(|(attrs, _)| attribute(attrs, "href"));
+    let title = child_text(xml, "title");
+    let guid = child_text(xml, "id").or_else(|| link.clone()).or_else(|| title.clone())?;
+    let summary = ["summary", "content"].iter().find_map(|tag| child_summary(xml, tag));
+    let published_at = ["published", "updated"].iter().find_map(|tag| child_text(xml, tag).and_then(|date| parse_date(&date)));
+    Some(ParsedItem {
+        guid,
+        title: title.unwrap_or_else(|| "(无标题)".to_string()),
+        link,
+        summary,
+        published_at,
+    })
+}
+
+// 返回所有同名元素的 (属性, 内容)；自闭合元素的内容为空
+fn elements<'a>(xml: &'a str, tag: &str) -> Vec<(&'a str, &'a str)> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut found = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find(&open) {
+        let after = &rest[start + open.len()..];
+        // 确认是完整的标签名，避免 <link> 匹配到 <linkedin>
+        if !after.starts_with(|c: char| c == '>' || c == '/' || c.is_whitespace()) {
+            rest = after;
+            continue;
+        }
+        let Some(tag_end) = after.find('>') else {
+            break;
+        };
+        let attrs = &after[..tag_end];
+        let body = &after[tag_end + 1..];
+        if let Some(attrs) = attrs.strip_suffix('/') {
+            found.push((attrs, ""));
+            rest = body;
+            continue;
+        }
+        let Some(end) = body.find(&close) else {
+            break;
+        };
+        found.push((attrs, &body[..end]));
+        rest = &body[end + close.len()..];
+    }
+    found
+}
+
+fn attribute(attrs: &str, name: &str) -> Option<String> {
+    for quote in ['"', '\''] {
+        let pattern = format!("{}={}", name, quote);
+        for (index, _) in attrs.match_indices(&pattern) {
+            if !attrs[..index].ends_with(char::is_whitespace) {
+                continue;
+            }
+            let value = &attrs[index + pattern.len()..];
+            return value.find(quote).map(|end| decode_entities(&value[..end]));
+        }
+    }
+    None
+}
+
+fn child_text(xml: &str, tag: &str) -> Option<String> {
+    elements(xml, tag).first().map(|(_, content)| collapse_whitespace(&text(content))).filter(|text| !text.is_empty())
+}
+
+// 摘要通常是转义后的 HTML，解码后去掉标签再截断
+fn child_summary(xml: &str, tag: &str) -> Option<String> {
+    let html = text(elements(xml, tag).first()?.1);
+    let summary = collapse_whitespace(&decode_entities(&strip_tags(&html)));
+    if summary.is_empty() {
+        return None;
+    }
+    if summary.chars().count() > MAX_SUMMARY_CHARS {
+        return Some(format!("{}…", summary.chars().take(MAX_SUMMARY_CHARS).collect::<String>().trim_end()));
+    }
+    Some(summary)
+}
+
+// CDATA 中的内容不需要解码
+fn text(content: &str) -> String {
+    let content = content.trim();
+    match content.strip_prefix("<![CDATA[").and_then(|rest| rest.strip_suffix("]]>")) {
+        Some(data) => data.to_string(),
+        None => decode_entities(content),
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// RSS 使用 RFC 2822 日期，Atom 和 dc:date 使用 RFC 3339
+fn parse_date(date: &str) -> Option<i64> {
+    DateTime::parse_from_rfc2822(date)
+        .or_else(|_| DateTime::parse_from_rfc3339(date))
+        .ok()
+        .map(|date| date.timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(guid: &str) -> ParsedItem {
+        ParsedItem {
+            guid: guid.to_string(),
+            title: format!("Post {}", guid),
+            link: Some(format!("https://example.com/{}", guid)),
+            summary: None,
+            published_at: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_rss() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+  <channel>
+    <title>Rust Blog</title>
+    <link>https://blog.rust-lang.org/</link>
+    <image><title>Rust logo</title></image>
+    <item>
+      <title>Announcing Rust 1.80</title>
+      <link>https://blog.rust-lang.org/2024/07/25/Rust-1.80.0.html</link>
+      <guid isPermaLink="false">rust-1.80</guid>
+      <pubDate>Thu, 25 Jul 2024 00:00:00 GMT</pubDate>
+      <description>&lt;p&gt;The Rust team is happy to announce &lt;b&gt;1.80&lt;/b&gt; &amp;amp; more.&lt;/p&gt;</description>
+    </item>
+    <item>
+      <title><![CDATA[Vec<T> & friends]]></title>
+      <link>https://example.com/vec</link>
+    </item>
+  </channel>
+</rss>"#;
+        let feed = parse_feed(xml).unwrap();
+        assert_eq!(feed.title.as_deref(), Some("Rust Blog"));
+        assert_eq!(feed.items.len(), 2);
+        assert_eq!(feed.items[0].guid, "rust-1.80");
+        assert_eq!(feed.items[0].published_at, Some(1721865600));
+        assert_eq!(feed.items[0].summary.as_deref(), Some("The Rust team is happy to announce 1.80 & more."));
+        assert_eq!(feed.items[1].title, "Vec<T> & friends");
+        // 没有 guid 时使用链接
+        assert_eq!(feed.items[1].guid, "https://example.com/vec");
+    }
+
+    #[test]
+    fn test_parse_atom() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title type="text">Example Feed</title>
+  <link href="https://example.org/" />
+  <entry>
+    <title>Atom-Powered Robots Run Amok</title>
+    <link rel="self" href="https://example.org/feed/1" />
+    <link href="https://example.org/2003/12/13/atom03" />
+    <id>urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a</id>
+    <updated>2003-12-13T18:30:02Z</updated>
+    <summary>Some text.</summary>
+  </entry>
+</feed>"#;
+        let feed = parse_feed(xml).unwrap();
+        assert_eq!(feed.title.as_deref(), Some("Example Feed"));
+        assert_eq!(feed.items.len(), 1);
+        let entry = &feed.items[0];
+        assert_eq!(entry.guid, "urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a");
+        assert_eq!(entry.link.as_deref(), Some("https://example.org/2003/12/13/atom03"));
+        assert_eq!(entry.published_at, Some(1071340202));
+        assert_eq!(entry.summary.as_deref(), Some("Some text."));
+    }
+
+    #[test]
+    fn test_parse_invalid_feed() {
+        assert!(parse_feed("<!DOCTYPE html><html><body>Not a feed</body></html>").is_err());
+    }
+
+    #[test]
+    fn test_elements_matches_whole_tag_name() {
+        let found = elements("<linkedin>x</linkedin><link>a</link><link href='b'/>", "link");
+        assert_eq!(found, vec![("", "a"), (" href='b'", "")]);
+        assert_eq!(attribute(" rel=\"alternate\" href='b'", "href").as_deref(), Some("b"));
+        assert_eq!(attribute(" data-href=\"x\"", "href"), None);
+    }
+
+    #[test]
+    fn test_summary_is_truncated() {
+        let long = "字".repeat(MAX_SUMMARY_CHARS + 10);
+        let summary = child_summary(&format!("<description>{}</description>", long), "description").unwrap();
+        assert_eq!(summary.chars().count(), MAX_SUMMARY_CHARS + 1);
+        assert!(summary.ends_with('…'));
+    }
+
+    #[test]
+    fn test_store_items_and_mark_read() {
+        let conn = db::open_in_memory();
+        let feed_id = insert(&conn, "https://example.com/feed.xml", "Example", 100).unwrap();
+        assert_eq!(store_items(&conn, feed_id, &[item("a"), item("b")], 100).unwrap(), 2);
+        // 重复抓取不会新增条目
+        assert_eq!(store_items(&conn, feed_id, &[item("a"), item("b"), item("c")], 200).unwrap(), 1);
+
+        let unread = list_unread(&conn, 10).unwrap();
+        assert_eq!(unread.len(), 3);
+        assert_eq!(unread[0].feed_title, "Example");
+
+        mark_read(&conn, &[unread[0].id]).unwrap();
+        assert_eq!(list_unread(&conn, 10).unwrap().len(), 2);
+        assert_eq!(get(&conn, feed_id).unwrap().unwrap().unread_count, 2);
+    }
+
+    #[test]
+    fn test_read_items_are_pruned_after_retention() {
+        let conn = db::open_in_memory();
+        let feed_id = insert(&conn, "https://example.com/feed.xml", "Example", 0).unwrap();
+        store_items(&conn, feed_id, &[item("old"), item("kept")], 0).unwrap();
+        let ids: Vec<i64> = list_unread(&conn, 10).unwrap().iter().map(|item| item.id).collect();
+        mark_read(&conn, &ids).unwrap();
+
+        // "old" 不再出现在订阅源中，超过保留期后删除；"kept" 仍在订阅源中，保持已读
+        store_items(&conn, feed_id, &[item("kept")], READ_RETENTION_SECS + 1).unwrap();
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM feed_items", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, 1);
+        assert!(list_unread(&conn, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_remove_feed_deletes_items() {
+        let conn = db::open_in_memory();
+        let feed_id = insert(&conn, "https://example.com/feed.xml", "Example", 0).unwrap();
+        store_items(&conn, feed_id, &[item("a")], 0).unwrap();
+        assert!(remove(&conn, feed_id).unwrap());
+        assert!(!remove(&conn, feed_id).unwrap());
+        assert!(list(&conn).unwrap().is_empty());
+        assert!(list_unread(&conn, 10).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,245 @@
+// 系统字体：按字族名搜索，在后端渲染示例文字预览图
+use crate::http::TtlCache;
+use ab_glyph::{point, Font, FontRef, PxScale, ScaleFont};
+use fontdb::{Database, Style};
+use image::{ImageFormat, Rgba, RgbaImage};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Cursor;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+// 扫描系统字体目录较慢，安装新字体后最多五分钟可搜索到
+static FONTS: LazyLock<TtlCache<Arc<Vec<FontFamily>>>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(5 * 60)));
+
+const DEFAULT_SAMPLE: &str = "The quick brown fox jumps over the lazy dog\n0123456789 天地玄黄 宇宙洪荒";
+const PREVIEW_FONT_SIZE: f32 = 36.0;
+const MAX_PREVIEW_WIDTH: u32 = 1600;
+const MAX_SAMPLE_LINES: usize = 6;
+const INK: [u8; 3] = [0x1f, 0x29, 0x37];
+
+// path / index 指向最接近常规字重的字形，用于渲染预览
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FontFamily {
+    pub family: String,
+    pub styles: Vec<String>,
+    pub monospaced: bool,
+    pub path: String,
+    pub index: u32,
+}
+
+// 完全匹配优先，其次为前缀匹配，最后为包含关键词的字族
+#[tauri::command(async)]
+pub fn search_fonts(query: String, limit: Option<usize>) -> Result<Vec<FontFamily>, String> {
+    let families = FONTS.get_or_fetch("system", || Ok(Arc::new(load_families())))?;
+    let needle = query.trim().to_lowercase();
+
+    let mut matches: Vec<(u8, &FontFamily)> = families
+        .iter()
+        .filter_map(|font| {
+            let name = font.family.to_lowercase();
+            let rank = if name == needle {
+                0
+            } else if name.starts_with(&needle) {
+                1
+            } else if name.contains(&needle) {
+                2
+            } else {
+                return None;
+            };
+            Some((rank, font))
+        })
+        .collect();
+    matches.sort_by_key(|(rank, _)| *rank);
+
+    Ok(matches
+        .into_iter()
+        .take(limit.unwrap_or(20))
+        .map(|(_, font)| font.clone())
+        .collect())
+}
+
+// 返回 PNG 的 data URL，text 为空时使用默认示例文字，字体中没有的字符显示为方框
+#[tauri::command(async)]
+pub fn render_font_preview(family: String, text: Option<String>) -> Result<String, String> {
+    let families = FONTS.get_or_fetch("system", || Ok(Arc::new(load_families())))?;
+    let font = families
+        .iter()
+        .find(|font| font.family == family)
+        .ok_or_else(|| format!("未找到字体: {}", family))?;
+    let data = fs::read(&font.path).map_err(|e| format!("读取字体文件 {} 失败: {}", font.path, e))?;
+
+    let sample = text.filter(|text| !text.trim().is_empty()).unwrap_or_else(|| DEFAULT_SAMPLE.to_string());
+    let image = render_preview(&data, font.index, &sample, PREVIEW_FONT_SIZE)?;
+
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| format!("生成预览图失败: {}", e))?;
+    Ok(format!("data:image/png;base64,{}", base64_encode(&png)))
+}
+
+fn load_families() -> Vec<FontFamily> {
+    let mut database = Database::new();
+    database.load_system_fonts();
+
+    // 字族名 -> (常规字形的字重偏差, 字族信息)
+    let mut families: BTreeMap<String, (u16, FontFamily)> = BTreeMap::new();
+    for face in database.faces() {
+        let Some((family, _)) = face.families.first() else {
+            continue;
+        };
+        let fontdb::Source::File(path) = &face.source else {
+            continue;
+        };
+        let style = style_name(face.weight.0, face.style);
+        // 斜体不作为预览字形
+        let distance = face.weight.0.abs_diff(400) + if face.style == Style::Normal { 0 } else { 1000 };
+
+        let entry = families.entry(family.to_lowercase()).or_insert_with(|| {
+            (u16::MAX, FontFamily {
+                family: family.clone(),
+                styles: Vec::new(),
+                monospaced: face.monospaced,
+                path: String::new(),
+                index: 0,
+            })
+        });
+        if !entry.1.styles.contains(&style) {
+            entry.1.styles.push(style);
+        }
+        if distance < entry.0 {
+            entry.0 = distance;
+            entry.1.path = path.to_string_lossy().to_string();
+            entry.1.index = face.index;
+        }
+    }
+
+    families.into_values().map(|(_, family)| family).collect()
+}
+
+fn style_name(weight: u16, style: Style) -> String {
+    let weight_name = match weight {
+        0..=149 => "Thin",
+        150..=249 => "ExtraLight",
+        250..=349 => "Light",
+        350..=449 => "Regular",
+        450..=549 => "Medium",
+        550..=649 => "SemiBold",
+        650..=749 => "Bold",
+        750..=849 => "ExtraBold",
+        _ => "Black",
+    };
+    match (weight_name, style) {
+        (name, Style::Normal) => name.to_string(),
+        ("Regular", Style::Italic) => "Italic".to_string(),
+        ("Regular", Style::Oblique) => "Oblique".to_string(),
+        (name, Style::Italic) => format!("{} Italic", name),
+        (name, Style::Oblique) => format!("{} Oblique", name),
+    }
+}
+
+// 逐行排版，透明背景上用深灰色绘制，超出最大宽度的部分被裁掉
+fn render_preview(data: &[u8], index: u32, text: &str, size: f32) -> Result<RgbaImage, String> {
+    let font = FontRef::try_from_slice_and_index(data, index).map_err(|_| "无法解析字体文件".to_string())?;
+    let scaled = font.as_scaled(PxScale::from(size));
+    let padding = (size / 3.0).ceil();
+    let line_height = scaled.height() + scaled.line_gap();
+
+    let mut glyphs = Vec::new();
+    let mut width: f32 = 0.0;
+    let lines: Vec<&str> = text.lines().take(MAX_SAMPLE_LINES).collect();
+    for (row, line) in lines.iter().enumerate() {
+        let baseline = padding + scaled.ascent() + row as f32 * line_height;
+        let mut x = padding;
+        let mut previous = None;
+        for c in line.chars().filter(|c| !c.is_control()) {
+            let id = scaled.glyph_id(c);
+            if let Some(previous) = previous {
+                x += scaled.kern(previous, id);
+            }
+            glyphs.push(id.with_scale_and_position(size, point(x, baseline)));
+            x += scaled.h_advance(id);
+            previous = Some(id);
+        }
+        width = width.max(x + padding);
+    }
+
+    let width = (width.ceil() as u32).clamp(1, MAX_PREVIEW_WIDTH);
+    let height = (padding * 2.0 + lines.len().max(1) as f32 * line_height).ceil() as u32;
+    let mut image = RgbaImage::new(width, height);
+    for glyph in glyphs {
+        let Some(outlined) = scaled.outline_glyph(glyph) else {
+            continue;
+        };
+        let bounds = outlined.px_bounds();
+        outlined.draw(|x, y, coverage| {
+            let x = bounds.min.x as i64 + x as i64;
+            let y = bounds.min.y as i64 + y as i64;
+            if x < 0 || y < 0 || x >= width as i64 || y >= height as i64 {
+                return;
+            }
+            let pixel = image.get_pixel_mut(x as u32, y as u32);
+            let alpha = pixel.0[3].max((coverage.clamp(0.0, 1.0) * 255.0).round() as u8);
+            *pixel = Rgba([INK[0], INK[1], INK[2], alpha]);
+        });
+    }
+    Ok(image)
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let value = chunk.iter().enumerate().fold(0u32, |value, (i, &byte)| value | (byte as u32) << (16 - i * 8));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(value >> (18 - i * 6)) as usize & 0x3f] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_name() {
+        assert_eq!(style_name(400, Style::Normal), "Regular");
+        assert_eq!(style_name(400, Style::Italic), "Italic");
+        assert_eq!(style_name(700, Style::Italic), "Bold Italic");
+        assert_eq!(style_name(300, Style::Oblique), "Light Oblique");
+        assert_eq!(style_name(900, Style::Normal), "Black");
+    }
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_render_preview_rejects_invalid_font() {
+        assert!(render_preview(b"not a font", 0, "abc", 24.0).is_err());
+    }
+
+    #[test]
+    fn test_render_preview_with_system_font() {
+        // 没有安装字体的环境（如 CI 容器）跳过
+        let Some(font) = load_families().into_iter().next() else {
+            return;
+        };
+        let data = fs::read(&font.path).unwrap();
+        let image = render_preview(&data, font.index, "Ag\nAg", 24.0).unwrap();
+        assert!(image.width() > 16 && image.height() > 48);
+        assert!(image.pixels().any(|pixel| pixel.0[3] > 0));
+    }
+}
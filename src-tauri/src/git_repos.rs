@@ -0,0 +1,279 @@
+// Git 仓库：扫描配置的目录查找仓库，显示当前分支和未提交改动，在编辑器或终端中打开
+use crate::system_command::{command, run};
+use crate::terminal::{open_terminal_in, split_command_line};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+pub(crate) const REFRESH_INTERVAL: Duration = Duration::from_secs(10 * 60);
+const DEFAULT_MAX_DEPTH: usize = 4;
+const DEFAULT_LIMIT: usize = 20;
+// 依赖和构建产物目录中不会有需要打开的仓库
+const SKIPPED_DIRS: &[&str] = &["node_modules", "target", "vendor", "build", "dist", "Library", "AppData"];
+
+// dirty 为 None 表示无法获取状态（例如未安装 git）
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GitRepo {
+    pub name: String,
+    pub path: String,
+    pub branch: Option<String>,
+    pub dirty: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ScanConfig {
+    roots: Vec<String>,
+    max_depth: usize,
+}
+
+static CONFIG: LazyLock<Mutex<ScanConfig>> = LazyLock::new(|| Mutex::new(ScanConfig {
+    roots: Vec::new(),
+    max_depth: DEFAULT_MAX_DEPTH,
+}));
+
+// 上次扫描到的仓库目录，由定时任务刷新
+static REPOS: LazyLock<Mutex<Vec<PathBuf>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+// 更新扫描目录，目录有变化时立即重新扫描，返回仓库数量
+#[tauri::command(async)]
+pub fn set_git_repo_roots(app_handle: AppHandle, roots: Vec<String>, max_depth: Option<usize>) -> Result<usize, String> {
+    let config = ScanConfig {
+        roots: roots.into_iter().map(|root| root.trim().to_string()).filter(|root| !root.is_empty()).collect(),
+        max_depth: max_depth.unwrap_or(DEFAULT_MAX_DEPTH),
+    };
+
+    let changed = {
+        let mut current = CONFIG.lock().unwrap();
+        let changed = *current != config;
+        *current = config;
+        changed
+    };
+    if changed {
+        refresh(&app_handle);
+    }
+    Ok(REPOS.lock().unwrap().len())
+}
+
+// 定时任务：重新扫描仓库，列表有变化时通知前端
+pub(crate) fn refresh(app_handle: &AppHandle) {
+    let config = CONFIG.lock().unwrap().clone();
+    let mut repos = Vec::new();
+    for root in &config.roots {
+        scan_dir(&expand_home(root), 0, config.max_depth, &mut repos);
+    }
+    repos.sort();
+    repos.dedup();
+
+    let mut cached = REPOS.lock().unwrap();
+    if *cached != repos {
+        *cached = repos;
+        let _ = app_handle.emit("git-repos-changed", cached.len());
+    }
+}
+
+// 按仓库名匹配，只为返回的结果读取分支和改动状态
+#[tauri::command(async)]
+pub fn search_git_repos(query: String, limit: Option<usize>) -> Result<Vec<GitRepo>, String> {
+    let query = query.trim().to_lowercase();
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+
+    let mut matches: Vec<(PathBuf, String)> = REPOS.lock().unwrap()
+        .iter()
+        .map(|path| (path.clone(), repo_name(path)))
+        .filter(|(path, name)| name.to_lowercase().contains(&query) || path.to_string_lossy().to_lowercase().contains(&query))
+        .collect();
+    matches.sort_by_key(|(_, name)| {
+        let name = name.to_lowercase();
+        (!name.starts_with(&query), !name.contains(&query), name.len(), name)
+    });
+    matches.truncate(limit);
+
+    Ok(matches.into_iter()
+        .map(|(path, name)| GitRepo {
+            name,
+            branch: read_branch(&path),
+            dirty: is_dirty(&path),
+            path: path.to_string_lossy().to_string(),
+        })
+        .collect())
+}
+
+// editor_command 中的 {path} 会被替换为仓库路径，没有占位符时追加到末尾
+#[tauri::command]
+pub fn open_git_repo_in_editor(path: String, editor_command: Option<String>) -> Result<(), String> {
+    let path = known_repo(&path)?;
+    let template = editor_command.filter(|command| !command.trim().is_empty()).unwrap_or_else(|| "code".to_string());
+    let argv = editor_args(&template, &path.to_string_lossy())?;
+
+    command(&argv[0])
+        .args(&argv[1..])
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("启动编辑器 {} 失败: {}", argv[0], e))
+}
+
+#[tauri::command]
+pub fn open_git_repo_in_terminal(path: String, terminal_command: Option<String>) -> Result<(), String> {
+    let path = known_repo(&path)?;
+    open_terminal_in(&path, terminal_command.as_deref())
+}
+
+// 优先返回 origin，没有时返回第一个远程仓库
+#[tauri::command(async)]
+pub fn get_git_remote_url(path: String) -> Result<String, String> {
+    let path = known_repo(&path)?;
+    let dir = path.to_string_lossy();
+    if let Ok(url) = run("git", &["-C", &dir, "remote", "get-url", "origin"]) {
+        return Ok(url.trim().to_string());
+    }
+
+    let remotes = run("git", &["-C", &dir, "remote"])?;
+    let remote = remotes.lines().map(str::trim).find(|remote| !remote.is_empty())
+        .ok_or_else(|| "该仓库没有配置远程仓库".to_string())?;
+    run("git", &["-C", &dir, "remote", "get-url", remote]).map(|url| url.trim().to_string())
+}
+
+// 只允许操作扫描到的仓库
+fn known_repo(path: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(path);
+    if REPOS.lock().unwrap().contains(&path) {
+        Ok(path)
+    } else {
+        Err(format!("未找到仓库: {}", path.display()))
+    }
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ if path == "~" => dirs::home_dir().unwrap_or_else(|| PathBuf::from(path)),
+        _ => PathBuf::from(path),
+    }
+}
+
+// 找到仓库后不再进入其子目录，也不跟随符号链接
+fn scan_dir(dir: &Path, depth: usize, max_depth: usize, repos: &mut Vec<PathBuf>) {
+    if dir.join(".git").exists() {
+        repos.push(dir.to_path_buf());
+        return;
+    }
+    if depth >= max_depth {
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if !entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            continue;
+        }
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.') || SKIPPED_DIRS.contains(&name.as_ref()) {
+            continue;
+        }
+        scan_dir(&entry.path(), depth + 1, max_depth, repos);
+    }
+}
+
+fn repo_name(path: &Path) -> String {
+    path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_else(|| path.to_string_lossy().to_string())
+}
+
+// 直接读取 HEAD 文件，不需要启动 git 进程；工作树和子模块的 .git 是指向实际目录的文件
+fn read_branch(repo: &Path) -> Option<String> {
+    let dot_git = repo.join(".git");
+    let git_dir = if dot_git.is_file() {
+        let content = fs::read_to_string(&dot_git).ok()?;
+        let target = PathBuf::from(content.trim().strip_prefix("gitdir:")?.trim());
+        if target.is_absolute() { target } else { repo.join(target) }
+    } else {
+        dot_git
+    };
+    parse_head(&fs::read_to_string(git_dir.join("HEAD")).ok()?)
+}
+
+// 分支名，分离头指针时为提交哈希的前 7 位
+fn parse_head(head: &str) -> Option<String> {
+    let head = head.trim();
+    if let Some(reference) = head.strip_prefix("ref:") {
+        let reference = reference.trim();
+        return Some(reference.strip_prefix("refs/heads/").unwrap_or(reference).to_string());
+    }
+    (head.len() >= 7 && head.chars().all(|c| c.is_ascii_hexdigit())).then(|| head[..7].to_string())
+}
+
+fn is_dirty(repo: &Path) -> Option<bool> {
+    run("git", &["-C", &repo.to_string_lossy(), "status", "--porcelain"])
+        .ok()
+        .map(|output| !output.trim().is_empty())
+}
+
+fn editor_args(template: &str, path: &str) -> Result<Vec<String>, String> {
+    let mut argv = split_command_line(template)?;
+    if argv.is_empty() {
+        return Err("编辑器命令为空".to_string());
+    }
+    if argv.iter().any(|arg| arg.contains("{path}")) {
+        for arg in argv.iter_mut() {
+            *arg = arg.replace("{path}", path);
+        }
+    } else {
+        argv.push(path.to_string());
+    }
+    Ok(argv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_scan_dir() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        for dir in ["app/.git", "app/sub/.git", "work/api/.git", "work/node_modules/pkg/.git", ".hidden/repo/.git", "deep/a/b/c/.git"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+
+        let mut repos = Vec::new();
+        scan_dir(root, 0, 3, &mut repos);
+        repos.sort();
+        assert_eq!(repos, vec![root.join("app"), root.join("work/api")]);
+    }
+
+    #[test]
+    fn test_read_branch() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        fs::create_dir_all(repo.join(".git")).unwrap();
+        fs::write(repo.join(".git/HEAD"), "ref: refs/heads/feature/login\n").unwrap();
+        assert_eq!(read_branch(&repo), Some("feature/login".to_string()));
+
+        // 工作树的 .git 是文件
+        let worktree = temp.path().join("worktree");
+        fs::create_dir_all(temp.path().join("repo/.git/worktrees/wt")).unwrap();
+        fs::create_dir_all(&worktree).unwrap();
+        fs::write(worktree.join(".git"), format!("gitdir: {}\n", repo.join(".git/worktrees/wt").display())).unwrap();
+        fs::write(repo.join(".git/worktrees/wt/HEAD"), "3f2a9c1d0e8b7a6f5e4d3c2b1a0f9e8d7c6b5a49\n").unwrap();
+        assert_eq!(read_branch(&worktree), Some("3f2a9c1".to_string()));
+    }
+
+    #[test]
+    fn test_parse_head() {
+        assert_eq!(parse_head("ref: refs/heads/main\n"), Some("main".to_string()));
+        assert_eq!(parse_head("garbage"), None);
+    }
+
+    #[test]
+    fn test_editor_args() {
+        assert_eq!(editor_args("code", "/src/app").unwrap(), vec!["code", "/src/app"]);
+        assert_eq!(editor_args("idea --wait {path}", "/src/my app").unwrap(), vec!["idea", "--wait", "/src/my app"]);
+        assert!(editor_args("  ", "/src/app").is_err());
+    }
+}
@@ -0,0 +1,230 @@
+// GitHub：按关键词搜索自己的仓库、Issue 和 Pull Request（令牌保存在系统钥匙串中）
+use crate::http::{self, RateLimiter, TtlCache};
+use crate::keyring;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::LazyLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const API_BASE: &str = "https://api.github.com";
+const KEYRING_ACCOUNT: &str = "github";
+const MAX_REPOS: usize = 10;
+const MAX_ISSUES: usize = 20;
+
+// 仓库列表变化不频繁，缓存时间长一些
+static REPOS: LazyLock<TtlCache<Vec<GitHubItem>>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(10 * 60)));
+static ISSUES: LazyLock<TtlCache<Vec<GitHubItem>>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(2 * 60)));
+// 搜索 API 认证后每分钟 30 次，留出余量
+static LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(20, Duration::from_secs(60)));
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum GitHubItemKind {
+    Repo,
+    Issue,
+    Pull,
+}
+
+// repo 为 owner/name，number 和 state 只有 Issue / PR 有，clone_url 和 ssh_url 只有仓库有
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GitHubItem {
+    pub kind: GitHubItemKind,
+    pub title: String,
+    pub repo: String,
+    pub number: Option<u64>,
+    pub url: String,
+    pub state: Option<String>,
+    pub description: Option<String>,
+    pub clone_url: Option<String>,
+    pub ssh_url: Option<String>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Repo {
+    full_name: String,
+    html_url: String,
+    description: Option<String>,
+    clone_url: String,
+    ssh_url: String,
+    updated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueSearch {
+    items: Vec<Issue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Issue {
+    number: u64,
+    title: String,
+    html_url: String,
+    state: String,
+    repository_url: String,
+    updated_at: String,
+    pull_request: Option<serde_json::Value>,
+}
+
+// 先列出名称匹配的仓库，再列出与自己相关的 Issue 和 PR
+#[tauri::command]
+pub async fn search_github(query: String) -> Result<Vec<GitHubItem>, String> {
+    let token = keyring::get_secret(KEYRING_ACCOUNT)?
+        .ok_or_else(|| "请先输入 gh login 保存 GitHub 访问令牌".to_string())?;
+    let query = query.trim().to_string();
+    // 换令牌后不使用旧账号的缓存
+    let account = token_key(&token);
+    let client = http::client()?;
+
+    let words: Vec<String> = query.to_lowercase().split_whitespace().map(str::to_string).collect();
+    let mut items: Vec<GitHubItem> = REPOS.get_or_fetch_async(&account, || fetch_repos(&client, &token)).await?
+        .into_iter()
+        .filter(|repo| {
+            let text = format!("{} {}", repo.repo, repo.description.as_deref().unwrap_or_default()).to_lowercase();
+            words.iter().all(|word| text.contains(word.as_str()))
+        })
+        .take(MAX_REPOS)
+        .collect();
+
+    if query.chars().count() >= 2 {
+        let key = format!("{}:{}", account, query.to_lowercase());
+        items.extend(ISSUES.get_or_fetch_async(&key, || fetch_issues(&client, &token, &query)).await?);
+    }
+    Ok(items)
+}
+
+// 最近更新的 100 个仓库（包括参与的组织仓库）
+async fn fetch_repos(client: &reqwest::Client, token: &str) -> Result<Vec<GitHubItem>, String> {
+    let body = get(client, token, "/user/repos", &[
+        ("per_page", "100"),
+        ("sort", "updated"),
+        ("affiliation", "owner,collaborator,organization_member"),
+    ]).await?;
+    parse_repos(&body)
+}
+
+async fn fetch_issues(client: &reqwest::Client, token: &str, query: &str) -> Result<Vec<GitHubItem>, String> {
+    let q = format!("{} involves:@me", query);
+    let per_page = MAX_ISSUES.to_string();
+    let body = get(client, token, "/search/issues", &[("q", q.as_str()), ("sort", "updated"), ("per_page", per_page.as_str())]).await?;
+    parse_issues(&body)
+}
+
+async fn get(client: &reqwest::Client, token: &str, path: &str, query: &[(&str, &str)]) -> Result<String, String> {
+    LIMITER.acquire("GitHub")?;
+    let response = client.get(format!("{}{}", API_BASE, path))
+        .bearer_auth(token)
+        .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+        .query(query)
+        .send()
+        .await
+        .map_err(|e| format!("请求 GitHub 失败: {}", e))?;
+
+    // 配额用完时在重置之前不再请求
+    let header = |name: &str| response.headers().get(name).and_then(|value| value.to_str().ok()).map(str::to_string);
+    if header("x-ratelimit-remaining").as_deref() == Some("0") {
+        if let Some(reset) = header("x-ratelimit-reset").and_then(|reset| reset.parse::<u64>().ok()) {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|now| now.as_secs()).unwrap_or(0);
+            LIMITER.block_until(Instant::now() + Duration::from_secs(reset.saturating_sub(now)));
+        }
+    }
+
+    http::check_status(response, "GitHub").await?
+        .text()
+        .await
+        .map_err(|e| format!("读取 GitHub 响应失败: {}", e))
+}
+
+fn token_key(token: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    token.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+fn parse_repos(body: &str) -> Result<Vec<GitHubItem>, String> {
+    let repos: Vec<Repo> = serde_json::from_str(body).map_err(|e| format!("解析 GitHub 仓库列表失败: {}", e))?;
+    Ok(repos.into_iter()
+        .map(|repo| GitHubItem {
+            kind: GitHubItemKind::Repo,
+            title: repo.full_name.clone(),
+            repo: repo.full_name,
+            number: None,
+            url: repo.html_url,
+            state: None,
+            description: repo.description.filter(|description| !description.is_empty()),
+            clone_url: Some(repo.clone_url),
+            ssh_url: Some(repo.ssh_url),
+            updated_at: repo.updated_at,
+        })
+        .collect())
+}
+
+fn parse_issues(body: &str) -> Result<Vec<GitHubItem>, String> {
+    let search: IssueSearch = serde_json::from_str(body).map_err(|e| format!("解析 GitHub 搜索结果失败: {}", e))?;
+    Ok(search.items.into_iter()
+        .map(|issue| GitHubItem {
+            kind: if issue.pull_request.is_some() { GitHubItemKind::Pull } else { GitHubItemKind::Issue },
+            title: issue.title,
+            // https://api.github.com/repos/owner/name
+            repo: issue.repository_url.split("/repos/").nth(1).unwrap_or(&issue.repository_url).to_string(),
+            number: Some(issue.number),
+            url: issue.html_url,
+            state: Some(issue.state),
+            description: None,
+            clone_url: None,
+            ssh_url: None,
+            updated_at: issue.updated_at,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_repos() {
+        let body = r#"[{
+            "id": 1, "full_name": "yeheng/launch-rs", "private": false,
+            "html_url": "https://github.com/yeheng/launch-rs",
+            "description": "A launcher", "clone_url": "https://github.com/yeheng/launch-rs.git",
+            "ssh_url": "git@github.com:yeheng/launch-rs.git", "updated_at": "2024-06-01T12:00:00Z"
+        }, {
+            "id": 2, "full_name": "yeheng/dotfiles", "html_url": "https://github.com/yeheng/dotfiles",
+            "description": "", "clone_url": "https://github.com/yeheng/dotfiles.git",
+            "ssh_url": "git@github.com:yeheng/dotfiles.git", "updated_at": "2024-05-01T12:00:00Z"
+        }]"#;
+        let repos = parse_repos(body).unwrap();
+        assert_eq!(repos.len(), 2);
+        assert_eq!(repos[0].kind, GitHubItemKind::Repo);
+        assert_eq!(repos[0].repo, "yeheng/launch-rs");
+        assert_eq!(repos[0].ssh_url.as_deref(), Some("git@github.com:yeheng/launch-rs.git"));
+        assert_eq!(repos[1].description, None);
+        assert!(parse_repos(r#"{"message": "Bad credentials"}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_issues() {
+        let body = r#"{"total_count": 2, "incomplete_results": false, "items": [
+            {"number": 42, "title": "Crash on startup", "html_url": "https://github.com/yeheng/launch-rs/issues/42",
+             "state": "open", "repository_url": "https://api.github.com/repos/yeheng/launch-rs", "updated_at": "2024-06-02T08:00:00Z"},
+            {"number": 43, "title": "Fix crash", "html_url": "https://github.com/yeheng/launch-rs/pull/43",
+             "state": "closed", "repository_url": "https://api.github.com/repos/yeheng/launch-rs", "updated_at": "2024-06-03T08:00:00Z",
+             "pull_request": {"url": "https://api.github.com/repos/yeheng/launch-rs/pulls/43"}}
+        ]}"#;
+        let items = parse_issues(body).unwrap();
+        assert_eq!(items[0].kind, GitHubItemKind::Issue);
+        assert_eq!(items[0].repo, "yeheng/launch-rs");
+        assert_eq!(items[0].number, Some(42));
+        assert_eq!(items[1].kind, GitHubItemKind::Pull);
+        assert_eq!(items[1].state.as_deref(), Some("closed"));
+    }
+
+    #[test]
+    fn test_token_key() {
+        assert_eq!(token_key("ghp_a"), token_key("ghp_a"));
+        assert_ne!(token_key("ghp_a"), token_key("ghp_b"));
+        assert!(!token_key("ghp_secret").contains("secret"));
+    }
+}
@@ -0,0 +1,250 @@
+// 访问第三方 Web API 的公共部分：HTTP 客户端、响应缓存和客户端限流
+// 使用异步客户端：命令运行在 tokio 工作线程上，reqwest::blocking 在其中会 panic
+use reqwest::{Client, Response};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+const USER_AGENT: &str = concat!("launch-rs/", env!("CARGO_PKG_VERSION"));
+// 缓存条目过多时先清理过期的
+const MAX_CACHE_ENTRIES: usize = 200;
+
+// GitHub 等 API 要求请求带 User-Agent
+pub(crate) fn client() -> Result<Client, String> {
+    Client::builder()
+        .timeout(TIMEOUT)
+        .user_agent(USER_AGENT)
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))
+}
+
+// service 为错误提示中显示的服务名
+pub(crate) async fn check_status(response: Response, service: &str) -> Result<Response, String> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().await.unwrap_or_default();
+    Err(match status.as_u16() {
+        401 | 403 => format!("{} 授权失败，请检查令牌和权限: {}", service, body),
+        429 => format!("{} 请求过于频繁，请稍后再试", service),
+        code => format!("{} 返回错误 {}: {}", service, code, body),
+    })
+}
+
+// 按键缓存一段时间内的结果，相同的搜索不重复请求
+pub(crate) struct TtlCache<T> {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, T)>>,
+}
+
+impl<T: Clone> TtlCache<T> {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        TtlCache { ttl, entries: Mutex::new(HashMap::new()) }
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<T> {
+        self.get_at(key, Instant::now())
+    }
+
+    pub(crate) fn insert(&self, key: &str, value: T) {
+        self.insert_at(key, value, Instant::now());
+    }
+
+    // 出错时不缓存
+    pub(crate) fn get_or_fetch<F>(&self, key: &str, fetch: F) -> Result<T, String>
+    where
+        F: FnOnce() -> Result<T, String>,
+    {
+        if let Some(value) = self.get(key) {
+            return Ok(value);
+        }
+        let value = fetch()?;
+        self.insert(key, value.clone());
+        Ok(value)
+    }
+
+    // get_or_fetch 的异步版本，用于网络请求；请求期间不持有锁
+    pub(crate) async fn get_or_fetch_async<F, Fut>(&self, key: &str, fetch: F) -> Result<T, String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, String>>,
+    {
+        if let Some(value) = self.get(key) {
+            return Ok(value);
+        }
+        let value = fetch().await?;
+        self.insert(key, value.clone());
+        Ok(value)
+    }
+
+    fn get_at(&self, key: &str, now: Instant) -> Option<T> {
+        let entries = self.entries.lock().unwrap();
+        entries.get(key)
+            .filter(|(stored_at, _)| now.duration_since(*stored_at) < self.ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    fn insert_at(&self, key: &str, value: T, now: Instant) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= MAX_CACHE_ENTRIES {
+            entries.retain(|_, (stored_at, _)| now.duration_since(*stored_at) < self.ttl);
+        }
+        if entries.len() >= MAX_CACHE_ENTRIES {
+            entries.clear();
+        }
+        entries.insert(key.to_string(), (now, value));
+    }
+}
+
+// 滑动窗口限流：window 内最多 max 次请求；服务端返回限流信息时暂停到指定时间
+pub(crate) struct RateLimiter {
+    max: usize,
+    window: Duration,
+    state: Mutex<RateState>,
+}
+
+struct RateState {
+    calls: VecDeque<Instant>,
+    blocked_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(max: usize, window: Duration) -> Self {
+        RateLimiter {
+            max,
+            window,
+            state: Mutex::new(RateState { calls: VecDeque::new(), blocked_until: None }),
+        }
+    }
+
+    pub(crate) fn acquire(&self, service: &str) -> Result<(), String> {
+        self.acquire_at(service, Instant::now())
+    }
+
+    pub(crate) fn block_until(&self, until: Instant) {
+        self.state.lock().unwrap().blocked_until = Some(until);
+    }
+
+    fn acquire_at(&self, service: &str, now: Instant) -> Result<(), String> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(until) = state.blocked_until.filter(|until| *until > now) {
+            return Err(too_many_requests(service, until - now));
+        }
+        while state.calls.front().is_some_and(|call| now.duration_since(*call) >= self.window) {
+            state.calls.pop_front();
+        }
+        if state.calls.len() >= self.max {
+            let oldest = state.calls[0];
+            return Err(too_many_requests(service, self.window - now.duration_since(oldest)));
+        }
+        state.calls.push_back(now);
+        Ok(())
+    }
+}
+
+fn too_many_requests(service: &str, wait: Duration) -> String {
+    format!("{} 请求过于频繁，请 {} 秒后再试", service, wait.as_secs().max(1))
+}
+
+// 还原 HTML 转义字符，无法识别的原样保留
+pub(crate) fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|end| *end <= 10) else {
+            decoded.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let character = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|decimal| decimal.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match character {
+            Some(character) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ttl_cache_expires() {
+        let cache = TtlCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+        cache.insert_at("rust", vec![1, 2], now);
+        assert_eq!(cache.get_at("rust", now + Duration::from_secs(30)), Some(vec![1, 2]));
+        assert_eq!(cache.get_at("rust", now + Duration::from_secs(61)), None);
+        assert_eq!(cache.get_at("go", now), None);
+    }
+
+    #[test]
+    fn test_get_or_fetch_skips_errors() {
+        let cache: TtlCache<String> = TtlCache::new(Duration::from_secs(60));
+        assert!(cache.get_or_fetch("q", || Err("offline".to_string())).is_err());
+        assert_eq!(cache.get_or_fetch("q", || Ok("first".to_string())).unwrap(), "first");
+        assert_eq!(cache.get_or_fetch("q", || Ok("second".to_string())).unwrap(), "first");
+    }
+
+    #[test]
+    fn test_get_or_fetch_async_skips_errors() {
+        let cache: TtlCache<String> = TtlCache::new(Duration::from_secs(60));
+        assert!(tokio_test::block_on(cache.get_or_fetch_async("q", || async { Err("offline".to_string()) })).is_err());
+        assert_eq!(tokio_test::block_on(cache.get_or_fetch_async("q", || async { Ok("first".to_string()) })).unwrap(), "first");
+        assert_eq!(tokio_test::block_on(cache.get_or_fetch_async("q", || async { Ok("second".to_string()) })).unwrap(), "first");
+    }
+
+    #[test]
+    fn test_rate_limiter_window() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+        let now = Instant::now();
+        assert!(limiter.acquire_at("GitHub", now).is_ok());
+        assert!(limiter.acquire_at("GitHub", now + Duration::from_secs(10)).is_ok());
+        let error = limiter.acquire_at("GitHub", now + Duration::from_secs(20)).unwrap_err();
+        assert!(error.contains("40 秒"));
+        assert!(limiter.acquire_at("GitHub", now + Duration::from_secs(61)).is_ok());
+    }
+
+    #[test]
+    fn test_rate_limiter_blocked() {
+        let limiter = RateLimiter::new(10, Duration::from_secs(60));
+        let now = Instant::now();
+        limiter.block_until(now + Duration::from_secs(120));
+        assert!(limiter.acquire_at("GitHub", now).is_err());
+        assert!(limiter.acquire_at("GitHub", now + Duration::from_secs(121)).is_ok());
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("a &amp;&amp; b &lt; c"), "a && b < c");
+        assert_eq!(decode_entities("&#x27;x&#39;"), "'x'");
+        assert_eq!(decode_entities("a&nbsp;b"), "a b");
+        assert_eq!(decode_entities("AT&T & co; &unknown;"), "AT&T & co; &unknown;");
+        assert_eq!(decode_entities("中文&hellip"), "中文&hellip");
+    }
+}
@@ -0,0 +1,277 @@
+// 问题跟踪：在 Jira 和 Linear 中按编号或关键词查找 Issue（令牌保存在系统钥匙串中）
+use crate::http::{self, RateLimiter, TtlCache};
+use crate::keyring;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+const JIRA_ACCOUNT: &str = "jira";
+const LINEAR_ACCOUNT: &str = "linear";
+const LINEAR_API: &str = "https://api.linear.app/graphql";
+const MAX_RESULTS: usize = 20;
+
+static CACHE: LazyLock<TtlCache<Vec<TrackerIssue>>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(2 * 60)));
+static JIRA_LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(30, Duration::from_secs(60)));
+static LINEAR_LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(30, Duration::from_secs(60)));
+
+const LINEAR_ISSUE_QUERY: &str = "query($id: String!) { issue(id: $id) { identifier title url state { name } } }";
+const LINEAR_SEARCH_QUERY: &str = "query($term: String!) { searchIssues(term: $term, first: 20) { nodes { identifier title url state { name } } } }";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Tracker {
+    Jira,
+    Linear,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TrackerIssue {
+    pub tracker: Tracker,
+    pub key: String,
+    pub title: String,
+    pub status: Option<String>,
+    pub url: String,
+}
+
+// base_url 为 Jira 站点地址；email 为空时按 Jira Server / Data Center 的个人访问令牌认证
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct JiraConfig {
+    pub base_url: String,
+    pub email: Option<String>,
+}
+
+// 查询形如 PROJ-1234 时按编号查找，否则按关键词搜索；某个服务出错时仍返回其他服务的结果
+#[tauri::command]
+pub async fn search_issue_tracker(query: String, jira: Option<JiraConfig>, linear: bool) -> Result<Vec<TrackerIssue>, String> {
+    let query = query.trim().to_string();
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+    let key = parse_issue_key(&query);
+    let mut issues = Vec::new();
+    let mut errors = Vec::new();
+
+    if let Some(config) = jira.filter(|config| !config.base_url.trim().is_empty()) {
+        let base_url = config.base_url.trim().trim_end_matches('/').to_string();
+        let cache_key = format!("jira:{}:{}", base_url, query.to_lowercase());
+        match CACHE.get_or_fetch_async(&cache_key, || search_jira(&base_url, config.email.as_deref(), &query, key.as_deref())).await {
+            Ok(found) => issues.extend(found),
+            Err(error) => {
+                // 按编号查找时即使请求失败也可以直接打开
+                if let Some(key) = &key {
+                    issues.push(TrackerIssue {
+                        tracker: Tracker::Jira,
+                        key: key.clone(),
+                        title: key.clone(),
+                        status: None,
+                        url: format!("{}/browse/{}", base_url, key),
+                    });
+                }
+                errors.push(error);
+            }
+        }
+    }
+
+    if linear {
+        let cache_key = format!("linear:{}", query.to_lowercase());
+        match CACHE.get_or_fetch_async(&cache_key, || search_linear(&query, key.as_deref())).await {
+            Ok(found) => issues.extend(found),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if issues.is_empty() && !errors.is_empty() {
+        return Err(errors.join("；"));
+    }
+    Ok(issues)
+}
+
+// PROJ-1234，不区分大小写
+fn parse_issue_key(query: &str) -> Option<String> {
+    let (project, number) = query.split_once('-')?;
+    let valid = project.len() >= 2
+        && project.starts_with(|c: char| c.is_ascii_alphabetic())
+        && project.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit());
+    valid.then(|| query.to_ascii_uppercase())
+}
+
+async fn search_jira(base_url: &str, email: Option<&str>, query: &str, key: Option<&str>) -> Result<Vec<TrackerIssue>, String> {
+    let token = keyring::get_secret(JIRA_ACCOUNT)?
+        .ok_or_else(|| "请先输入 issue login jira 保存 Jira 令牌".to_string())?;
+    let email = email.map(str::trim).filter(|email| !email.is_empty());
+    JIRA_LIMITER.acquire("Jira")?;
+
+    let client = http::client()?;
+    let request = match key {
+        Some(key) => client.get(format!("{}/rest/api/2/issue/{}", base_url, key))
+            .query(&[("fields", "summary,status")]),
+        None => {
+            let jql = format!("text ~ \"{}\" ORDER BY updated DESC", escape_jql(query));
+            let max_results = MAX_RESULTS.to_string();
+            // Jira Cloud 使用新的搜索接口，Server / Data Center 只有旧接口
+            let path = if email.is_some() { "/rest/api/3/search/jql" } else { "/rest/api/2/search" };
+            client.get(format!("{}{}", base_url, path))
+                .query(&[("jql", jql.as_str()), ("maxResults", max_results.as_str()), ("fields", "summary,status")])
+        }
+    };
+    let request = match email {
+        Some(email) => request.basic_auth(email, Some(&token)),
+        None => request.bearer_auth(&token),
+    };
+
+    let response = request.send().await.map_err(|e| format!("请求 Jira 失败: {}", e))?;
+    if key.is_some() && response.status().as_u16() == 404 {
+        return Ok(vec![]);
+    }
+    let body = http::check_status(response, "Jira").await?
+        .text()
+        .await
+        .map_err(|e| format!("读取 Jira 响应失败: {}", e))?;
+    parse_jira(&body, base_url)
+}
+
+async fn search_linear(query: &str, key: Option<&str>) -> Result<Vec<TrackerIssue>, String> {
+    let token = keyring::get_secret(LINEAR_ACCOUNT)?
+        .ok_or_else(|| "请先输入 issue login linear 保存 Linear API 密钥".to_string())?;
+    LINEAR_LIMITER.acquire("Linear")?;
+
+    let body = match key {
+        Some(key) => serde_json::json!({ "query": LINEAR_ISSUE_QUERY, "variables": { "id": key } }),
+        None => serde_json::json!({ "query": LINEAR_SEARCH_QUERY, "variables": { "term": query } }),
+    };
+    // 个人 API 密钥直接放在 Authorization 中，不加 Bearer
+    let response = http::client()?
+        .post(LINEAR_API)
+        .header(reqwest::header::AUTHORIZATION, token)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("请求 Linear 失败: {}", e))?;
+    let body = http::check_status(response, "Linear").await?
+        .text()
+        .await
+        .map_err(|e| format!("读取 Linear 响应失败: {}", e))?;
+    parse_linear(&body)
+}
+
+fn escape_jql(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// 单个 Issue 的响应和搜索响应中的 issues 元素格式相同
+fn parse_jira(body: &str, base_url: &str) -> Result<Vec<TrackerIssue>, String> {
+    let value: serde_json::Value = serde_json::from_str(body).map_err(|e| format!("解析 Jira 响应失败: {}", e))?;
+    let items = match value.get("issues").and_then(|issues| issues.as_array()) {
+        Some(issues) => issues.clone(),
+        None => vec![value],
+    };
+
+    Ok(items.iter()
+        .filter_map(|item| {
+            let key = item.get("key")?.as_str()?;
+            let fields = item.get("fields");
+            Some(TrackerIssue {
+                tracker: Tracker::Jira,
+                key: key.to_string(),
+                title: fields.and_then(|fields| fields.get("summary")).and_then(|summary| summary.as_str()).unwrap_or(key).to_string(),
+                status: fields
+                    .and_then(|fields| fields.pointer("/status/name"))
+                    .and_then(|status| status.as_str())
+                    .map(str::to_string),
+                url: format!("{}/browse/{}", base_url, key),
+            })
+        })
+        .collect())
+}
+
+// 按编号查找不到时 Linear 返回 Entity not found 错误，视为没有结果
+fn parse_linear(body: &str) -> Result<Vec<TrackerIssue>, String> {
+    let value: serde_json::Value = serde_json::from_str(body).map_err(|e| format!("解析 Linear 响应失败: {}", e))?;
+    let data = value.get("data").filter(|data| !data.is_null());
+    let Some(data) = data else {
+        let message = value.pointer("/errors/0/message").and_then(|message| message.as_str()).unwrap_or("未知错误");
+        if message.contains("not found") {
+            return Ok(vec![]);
+        }
+        return Err(format!("Linear 返回错误: {}", message));
+    };
+
+    let nodes = match data.pointer("/searchIssues/nodes").and_then(|nodes| nodes.as_array()) {
+        Some(nodes) => nodes.clone(),
+        None => data.get("issue").filter(|issue| !issue.is_null()).cloned().into_iter().collect(),
+    };
+
+    Ok(nodes.iter()
+        .filter_map(|node| {
+            Some(TrackerIssue {
+                tracker: Tracker::Linear,
+                key: node.get("identifier")?.as_str()?.to_string(),
+                title: node.get("title")?.as_str()?.to_string(),
+                status: node.pointer("/state/name").and_then(|state| state.as_str()).map(str::to_string),
+                url: node.get("url")?.as_str()?.to_string(),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_issue_key() {
+        assert_eq!(parse_issue_key("PROJ-1234"), Some("PROJ-1234".to_string()));
+        assert_eq!(parse_issue_key("eng-42"), Some("ENG-42".to_string()));
+        assert_eq!(parse_issue_key("A2_B-7"), Some("A2_B-7".to_string()));
+        assert_eq!(parse_issue_key("fix login bug"), None);
+        assert_eq!(parse_issue_key("x-1"), None);
+        assert_eq!(parse_issue_key("PROJ-"), None);
+        assert_eq!(parse_issue_key("2024-06"), None);
+    }
+
+    #[test]
+    fn test_parse_jira() {
+        let search = r#"{"issues": [
+            {"id": "10001", "key": "PROJ-1", "fields": {"summary": "Login fails", "status": {"name": "In Progress"}}},
+            {"id": "10002", "key": "PROJ-2", "fields": {"summary": "Crash"}}
+        ]}"#;
+        let issues = parse_jira(search, "https://acme.atlassian.net").unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].status.as_deref(), Some("In Progress"));
+        assert_eq!(issues[0].url, "https://acme.atlassian.net/browse/PROJ-1");
+        assert_eq!(issues[1].status, None);
+
+        let single = r#"{"key": "PROJ-1234", "fields": {"summary": "Dark mode", "status": {"name": "Done"}}}"#;
+        let issues = parse_jira(single, "https://jira.example.com").unwrap();
+        assert_eq!(issues[0].key, "PROJ-1234");
+        assert_eq!(issues[0].title, "Dark mode");
+    }
+
+    #[test]
+    fn test_parse_linear() {
+        let search = r#"{"data": {"searchIssues": {"nodes": [
+            {"identifier": "ENG-42", "title": "Slow search", "url": "https://linear.app/acme/issue/ENG-42", "state": {"name": "Todo"}}
+        ]}}}"#;
+        let issues = parse_linear(search).unwrap();
+        assert_eq!(issues[0].tracker, Tracker::Linear);
+        assert_eq!(issues[0].status.as_deref(), Some("Todo"));
+
+        let single = r#"{"data": {"issue": {"identifier": "ENG-7", "title": "Typo", "url": "https://linear.app/acme/issue/ENG-7", "state": null}}}"#;
+        assert_eq!(parse_linear(single).unwrap()[0].key, "ENG-7");
+
+        let missing = r#"{"data": {"issue": null}, "errors": [{"message": "Entity not found"}]}"#;
+        assert!(parse_linear(missing).unwrap().is_empty());
+        let missing = r#"{"data": null, "errors": [{"message": "Entity not found: Issue"}]}"#;
+        assert!(parse_linear(missing).unwrap().is_empty());
+
+        let unauthorized = r#"{"errors": [{"message": "Authentication required"}]}"#;
+        assert!(parse_linear(unauthorized).unwrap_err().contains("Authentication required"));
+    }
+
+    #[test]
+    fn test_escape_jql() {
+        assert_eq!(escape_jql(r#"say "hi" \ bye"#), r#"say \"hi\" \\ bye"#);
+    }
+}
@@ -0,0 +1,346 @@
+// JetBrains 最近项目：读取各 IDE 的 recentProjects.xml，用对应的 IDE 打开项目（支持 Toolbox 安装）
+use crate::system_command::command;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+struct Product {
+    // 配置目录名前缀，后面紧跟版本号，例如 CLion2024.1
+    prefix: &'static str,
+    name: &'static str,
+    launcher: &'static str,
+    mac_app: &'static str,
+}
+
+// PyCharmCE 需要排在 PyCharm 之前
+const PRODUCTS: &[Product] = &[
+    Product { prefix: "IntelliJIdea", name: "IntelliJ IDEA", launcher: "idea", mac_app: "IntelliJ IDEA" },
+    Product { prefix: "IdeaIC", name: "IntelliJ IDEA CE", launcher: "idea", mac_app: "IntelliJ IDEA CE" },
+    Product { prefix: "CLion", name: "CLion", launcher: "clion", mac_app: "CLion" },
+    Product { prefix: "PyCharmCE", name: "PyCharm CE", launcher: "pycharm", mac_app: "PyCharm CE" },
+    Product { prefix: "PyCharm", name: "PyCharm", launcher: "pycharm", mac_app: "PyCharm" },
+    Product { prefix: "WebStorm", name: "WebStorm", launcher: "webstorm", mac_app: "WebStorm" },
+    Product { prefix: "GoLand", name: "GoLand", launcher: "goland", mac_app: "GoLand" },
+    Product { prefix: "PhpStorm", name: "PhpStorm", launcher: "phpstorm", mac_app: "PhpStorm" },
+    Product { prefix: "RubyMine", name: "RubyMine", launcher: "rubymine", mac_app: "RubyMine" },
+    Product { prefix: "Rider", name: "Rider", launcher: "rider", mac_app: "Rider" },
+    Product { prefix: "RustRover", name: "RustRover", launcher: "rustrover", mac_app: "RustRover" },
+    Product { prefix: "DataGrip", name: "DataGrip", launcher: "datagrip", mac_app: "DataGrip" },
+    Product { prefix: "DataSpell", name: "DataSpell", launcher: "dataspell", mac_app: "DataSpell" },
+];
+
+// product 为配置目录前缀，last_opened 为毫秒时间戳
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct JetBrainsProject {
+    pub name: String,
+    pub path: String,
+    pub product: String,
+    pub product_name: String,
+    pub last_opened: Option<i64>,
+}
+
+// 每个 IDE 只读取最新版本的配置，结果按最近打开时间排序
+#[tauri::command(async)]
+pub fn list_jetbrains_projects() -> Result<Vec<JetBrainsProject>, String> {
+    let Some(config_dir) = dirs::config_dir() else {
+        return Ok(vec![]);
+    };
+    let home = dirs::home_dir().unwrap_or_default();
+
+    let mut projects = Vec::new();
+    for (product, options_dir) in latest_config_dirs(&config_dir.join("JetBrains")) {
+        let xml = ["recentProjects.xml", "recentProjectDirectories.xml", "recentSolutions.xml"]
+            .iter()
+            .find_map(|file| fs::read_to_string(options_dir.join(file)).ok());
+        let Some(xml) = xml else {
+            continue;
+        };
+
+        for (path, last_opened) in parse_recent_projects(&xml, &home) {
+            if !path.exists() {
+                continue;
+            }
+            projects.push(JetBrainsProject {
+                name: project_name(&path),
+                path: path.to_string_lossy().to_string(),
+                product: product.prefix.to_string(),
+                product_name: product.name.to_string(),
+                last_opened,
+            });
+        }
+    }
+
+    projects.sort_by_key(|project| std::cmp::Reverse(project.last_opened));
+    Ok(projects)
+}
+
+#[tauri::command]
+pub fn open_jetbrains_project(project: JetBrainsProject) -> Result<(), String> {
+    let product = PRODUCTS.iter()
+        .find(|product| product.prefix == project.product)
+        .ok_or_else(|| format!("不支持的 IDE: {}", project.product))?;
+    let mut argv = find_launcher(product)
+        .ok_or_else(|| format!("未找到 {} 的启动程序，请通过 Toolbox 启用 Shell 脚本或将其加入 PATH", product.name))?;
+    argv.push(project.path);
+
+    command(&argv[0])
+        .args(&argv[1..])
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("启动 {} 失败: {}", product.name, e))
+}
+
+// 同一 IDE 有多个版本的配置目录时取版本号最大的
+fn latest_config_dirs(root: &Path) -> Vec<(&'static Product, PathBuf)> {
+    let mut latest: HashMap<&'static str, (Vec<u32>, PathBuf)> = HashMap::new();
+    let Ok(entries) = fs::read_dir(root) else {
+        return vec![];
+    };
+
+    for entry in entries.flatten() {
+        let dir_name = entry.file_name().to_string_lossy().to_string();
+        let Some((product, version)) = match_product(&dir_name) else {
+            continue;
+        };
+        if latest.get(product.prefix).is_none_or(|(current, _)| *current < version) {
+            latest.insert(product.prefix, (version, entry.path().join("options")));
+        }
+    }
+
+    PRODUCTS.iter()
+        .filter_map(|product| latest.remove(product.prefix).map(|(_, dir)| (product, dir)))
+        .collect()
+}
+
+fn match_product(dir_name: &str) -> Option<(&'static Product, Vec<u32>)> {
+    PRODUCTS.iter().find_map(|product| {
+        let version = dir_name.strip_prefix(product.prefix)?;
+        if !version.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+        Some((product, version.split('.').map(|part| part.parse().unwrap_or(0)).collect()))
+    })
+}
+
+// 新格式为 additionalInfo 中的 entry，旧格式为 recentPaths 列表
+fn parse_recent_projects(xml: &str, home: &Path) -> Vec<(PathBuf, Option<i64>)> {
+    let mut projects = Vec::new();
+
+    for block in xml.split("<entry key=\"").skip(1) {
+        let Some((key, rest)) = block.split_once('"') else {
+            continue;
+        };
+        let body = rest.split("</entry>").next().unwrap_or(rest);
+        let last_opened = ["activationTimestamp", "projectOpenTimestamp"]
+            .iter()
+            .filter_map(|name| option_value(body, name)?.parse::<i64>().ok())
+            .max();
+        projects.push((expand_macros(&unescape_xml(key), home), last_opened));
+    }
+
+    if projects.is_empty() {
+        if let Some(list) = xml.split("<option name=\"recentPaths\">").nth(1).and_then(|rest| rest.split("</list>").next()) {
+            for item in list.split("<option value=\"").skip(1) {
+                if let Some((value, _)) = item.split_once('"') {
+                    projects.push((expand_macros(&unescape_xml(value), home), None));
+                }
+            }
+        }
+    }
+    projects
+}
+
+fn option_value(body: &str, name: &str) -> Option<String> {
+    let rest = body.split(&format!("<option name=\"{}\" value=\"", name)).nth(1)?;
+    rest.split_once('"').map(|(value, _)| value.to_string())
+}
+
+fn expand_macros(path: &str, home: &Path) -> PathBuf {
+    match path.strip_prefix("$USER_HOME$") {
+        Some(rest) => home.join(rest.trim_start_matches(['/', '\\'])),
+        None => PathBuf::from(path),
+    }
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+// 项目改过名时 .idea/.name 中保存的是显示名称
+fn project_name(path: &Path) -> String {
+    fs::read_to_string(path.join(".idea").join(".name"))
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| path.file_name().map(|name| name.to_string_lossy().to_string()))
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+}
+
+// 依次查找 Toolbox 生成的脚本、PATH 中的命令和各系统的默认安装位置
+fn find_launcher(product: &Product) -> Option<Vec<String>> {
+    let home = dirs::home_dir().unwrap_or_default();
+    let data_dir = dirs::data_local_dir().unwrap_or_default();
+    let script = if cfg!(windows) { format!("{}.cmd", product.launcher) } else { product.launcher.to_string() };
+
+    let toolbox_scripts = data_dir.join("JetBrains").join("Toolbox").join("scripts").join(&script);
+    if toolbox_scripts.is_file() {
+        return Some(vec![toolbox_scripts.to_string_lossy().to_string()]);
+    }
+    if let Some(path) = find_in_path(&script) {
+        return Some(vec![path.to_string_lossy().to_string()]);
+    }
+
+    match std::env::consts::OS {
+        "macos" => {
+            let app = format!("{}.app", product.mac_app);
+            [PathBuf::from("/Applications"), home.join("Applications"), home.join("Applications").join("JetBrains Toolbox")]
+                .iter()
+                .map(|dir| dir.join(&app))
+                .find(|app| app.is_dir())
+                .map(|app| vec!["open".to_string(), "-na".to_string(), app.to_string_lossy().to_string(), "--args".to_string()])
+        }
+        "windows" => {
+            let exe = format!("{}64.exe", product.launcher);
+            let program_files = std::env::var_os("ProgramFiles").map(PathBuf::from).unwrap_or_default();
+            // Toolbox 2.x 安装在 %LOCALAPPDATA%\Programs 下
+            [program_files.join("JetBrains"), data_dir.join("Programs")]
+                .iter()
+                .find_map(|root| find_bin(root, product, &exe))
+                .map(|path| vec![path.to_string_lossy().to_string()])
+        }
+        _ => {
+            let exe = format!("{}.sh", product.launcher);
+            [data_dir.join("JetBrains"), data_dir.join("JetBrains").join("Toolbox").join("apps"), PathBuf::from("/opt")]
+                .iter()
+                .find_map(|root| find_bin(root, product, &exe))
+                .map(|path| vec![path.to_string_lossy().to_string()])
+        }
+    }
+}
+
+// 在安装根目录下查找名称包含 IDE 名称的目录中的 bin/<exe>，Toolbox 1.x 的目录多一层渠道和版本
+fn find_bin(root: &Path, product: &Product, exe: &str) -> Option<PathBuf> {
+    let keyword = product.launcher.to_lowercase();
+    let mut candidates: Vec<PathBuf> = fs::read_dir(root).ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.file_name().is_some_and(|name| {
+            let name = name.to_string_lossy().to_lowercase().replace(['-', ' '], "");
+            name.contains(&keyword) || name.contains(&product.name.to_lowercase().replace(' ', ""))
+        }))
+        .collect();
+    candidates.sort();
+
+    candidates.iter().rev().find_map(|dir| {
+        let direct = dir.join("bin").join(exe);
+        if direct.is_file() {
+            return Some(direct);
+        }
+        // Toolbox 1.x: apps/<产品>/ch-0/<版本>/bin
+        let mut nested: Vec<PathBuf> = fs::read_dir(dir).ok()?
+            .flatten()
+            .flat_map(|channel| fs::read_dir(channel.path()).into_iter().flatten().flatten())
+            .map(|version| version.path().join("bin").join(exe))
+            .filter(|path| path.is_file())
+            .collect();
+        nested.sort();
+        nested.pop()
+    })
+}
+
+fn find_in_path(program: &str) -> Option<PathBuf> {
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths).map(|dir| dir.join(program)).find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_recent_projects() {
+        let xml = r#"<application>
+  <component name="RecentProjectsManager">
+    <option name="additionalInfo">
+      <map>
+        <entry key="$USER_HOME$/IdeaProjects/launch-rs">
+          <value>
+            <RecentProjectMetaInfo frameTitle="launch-rs" opened="true">
+              <option name="build" value="CL-241.14494.288" />
+              <option name="projectOpenTimestamp" value="1718000000000" />
+              <option name="activationTimestamp" value="1718500000000" />
+            </RecentProjectMetaInfo>
+          </value>
+        </entry>
+        <entry key="/srv/R&amp;D tools">
+          <value>
+            <RecentProjectMetaInfo />
+          </value>
+        </entry>
+      </map>
+    </option>
+  </component>
+</application>"#;
+        let home = Path::new("/home/me");
+        let projects = parse_recent_projects(xml, home);
+        assert_eq!(projects, vec![
+            (PathBuf::from("/home/me/IdeaProjects/launch-rs"), Some(1718500000000)),
+            (PathBuf::from("/srv/R&D tools"), None),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_legacy_recent_paths() {
+        let xml = r#"<application>
+  <component name="RecentDirectoryProjectsManager">
+    <option name="recentPaths">
+      <list>
+        <option value="$USER_HOME$/PycharmProjects/scraper" />
+        <option value="/tmp/demo" />
+      </list>
+    </option>
+  </component>
+</application>"#;
+        let projects = parse_recent_projects(xml, Path::new("/home/me"));
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects[0].0, PathBuf::from("/home/me/PycharmProjects/scraper"));
+    }
+
+    #[test]
+    fn test_latest_config_dirs() {
+        let temp = TempDir::new().unwrap();
+        for dir in ["CLion2023.3", "CLion2024.1", "PyCharmCE2023.2", "PyCharm2024.1", "Toolbox", "consentOptions"] {
+            fs::create_dir_all(temp.path().join(dir)).unwrap();
+        }
+
+        let dirs = latest_config_dirs(temp.path());
+        let found: Vec<(&str, PathBuf)> = dirs.iter().map(|(product, dir)| (product.prefix, dir.clone())).collect();
+        assert_eq!(found, vec![
+            ("CLion", temp.path().join("CLion2024.1").join("options")),
+            ("PyCharmCE", temp.path().join("PyCharmCE2023.2").join("options")),
+            ("PyCharm", temp.path().join("PyCharm2024.1").join("options")),
+        ]);
+    }
+
+    #[test]
+    fn test_find_bin_toolbox_layout() {
+        let temp = TempDir::new().unwrap();
+        let product = &PRODUCTS[2];
+        fs::create_dir_all(temp.path().join("CLion/ch-0/241.1/bin")).unwrap();
+        fs::create_dir_all(temp.path().join("CLion/ch-0/241.2/bin")).unwrap();
+        fs::write(temp.path().join("CLion/ch-0/241.1/bin/clion.sh"), "").unwrap();
+        fs::write(temp.path().join("CLion/ch-0/241.2/bin/clion.sh"), "").unwrap();
+        assert_eq!(find_bin(temp.path(), product, "clion.sh"), Some(temp.path().join("CLion/ch-0/241.2/bin/clion.sh")));
+
+        fs::create_dir_all(temp.path().join("clion/bin")).unwrap();
+        fs::write(temp.path().join("clion/bin/clion.sh"), "").unwrap();
+        assert!(find_bin(temp.path(), product, "clion.sh").is_some());
+        assert_eq!(find_bin(temp.path(), &PRODUCTS[5], "webstorm.sh"), None);
+    }
+}
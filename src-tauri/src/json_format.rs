@@ -0,0 +1,194 @@
+// JSON 格式化、压缩与校验
+// 只用 serde_json 校验语法，格式化时逐字符重排空白，保留原有的键顺序和数字写法（大整数、1.0 等）
+use chrono::Local;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+const INDENT: &str = "  ";
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct JsonSyntaxError {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+// 合法时 pretty、minified、summary 有值，否则只有 error
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct JsonFormatResult {
+    pub pretty: Option<String>,
+    pub minified: Option<String>,
+    pub summary: Option<String>,
+    pub error: Option<JsonSyntaxError>,
+}
+
+#[tauri::command]
+pub fn format_json(text: String) -> JsonFormatResult {
+    let text = text.trim_start_matches('\u{feff}');
+    if let Err(e) = serde_json::from_str::<IgnoredAny>(text) {
+        // serde_json 的错误信息末尾带有 "at line X column Y"，位置单独返回
+        let message = e.to_string();
+        let message = message.rsplit_once(" at line ").map_or(message.as_str(), |(message, _)| message);
+        return JsonFormatResult {
+            pretty: None,
+            minified: None,
+            summary: None,
+            error: Some(JsonSyntaxError {
+                message: format!("JSON 语法错误: {}", message),
+                line: e.line(),
+                column: e.column(),
+            }),
+        };
+    }
+
+    JsonFormatResult {
+        pretty: Some(reformat(text, Some(INDENT))),
+        minified: Some(reformat(text, None)),
+        summary: Some(summarize(text)),
+        error: None,
+    }
+}
+
+// 保存到下载目录（没有时使用主目录），返回文件路径
+#[tauri::command]
+pub fn save_json_file(content: String) -> Result<String, String> {
+    let dir = dirs::download_dir().or_else(dirs::home_dir).ok_or("无法确定保存目录")?;
+    save_to(&dir, &content)
+}
+
+fn save_to(dir: &Path, content: &str) -> Result<String, String> {
+    let stem = format!("json-{}", Local::now().format("%Y%m%d-%H%M%S"));
+    let mut path = dir.join(format!("{}.json", stem));
+    let mut counter = 1;
+    while path.exists() {
+        path = dir.join(format!("{}-{}.json", stem, counter));
+        counter += 1;
+    }
+    fs::write(&path, content).map_err(|e| format!("保存 JSON 文件失败: {}", e))?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+// 重排已校验过的 JSON：indent 为 None 时压缩成一行
+fn reformat(text: &str, indent: Option<&str>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut depth = 0;
+    let mut chars = text.chars().peekable();
+    let newline = |out: &mut String, depth: usize| {
+        if let Some(indent) = indent {
+            out.push('\n');
+            out.push_str(&indent.repeat(depth));
+        }
+    };
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                out.push(c);
+                while let Some(c) = chars.next() {
+                    out.push(c);
+                    match c {
+                        '\\' => out.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '{' | '[' => {
+                out.push(c);
+                while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                    chars.next();
+                }
+                // 空对象和空数组保持在一行
+                if matches!(chars.peek(), Some('}' | ']')) {
+                    out.extend(chars.next());
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            }
+            '}' | ']' => {
+                depth -= 1;
+                newline(&mut out, depth);
+                out.push(c);
+            }
+            ',' => {
+                out.push(c);
+                newline(&mut out, depth);
+            }
+            ':' => {
+                out.push(c);
+                if indent.is_some() {
+                    out.push(' ');
+                }
+            }
+            c if c.is_whitespace() => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn summarize(text: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(text) {
+        Ok(serde_json::Value::Object(map)) => format!("对象，{} 个键", map.len()),
+        Ok(serde_json::Value::Array(items)) => format!("数组，{} 个元素", items.len()),
+        Ok(serde_json::Value::String(_)) => "字符串".to_string(),
+        Ok(serde_json::Value::Number(_)) => "数字".to_string(),
+        Ok(serde_json::Value::Bool(_)) => "布尔值".to_string(),
+        Ok(serde_json::Value::Null) | Err(_) => "null".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_pretty_and_minified() {
+        let result = format_json(r#" { "b": [1, 2, {}], "a": {"s": "x, y: [z]"}, "e": [ ] } "#.to_string());
+        assert_eq!(result.error, None);
+        assert_eq!(
+            result.pretty.as_deref(),
+            Some("{\n  \"b\": [\n    1,\n    2,\n    {}\n  ],\n  \"a\": {\n    \"s\": \"x, y: [z]\"\n  },\n  \"e\": []\n}")
+        );
+        assert_eq!(result.minified.as_deref(), Some(r#"{"b":[1,2,{}],"a":{"s":"x, y: [z]"},"e":[]}"#));
+        assert_eq!(result.summary.as_deref(), Some("对象，3 个键"));
+    }
+
+    #[test]
+    fn test_preserves_strings_and_numbers() {
+        let text = r#"[12345678901234567890123, 1.0, "quote \" and \\", "a  b"]"#;
+        let result = format_json(text.to_string());
+        assert_eq!(
+            result.minified.as_deref(),
+            Some(r#"[12345678901234567890123,1.0,"quote \" and \\","a  b"]"#)
+        );
+        assert_eq!(format_json("\u{feff}true".to_string()).minified.as_deref(), Some("true"));
+    }
+
+    #[test]
+    fn test_syntax_error_position() {
+        let result = format_json("{\n  \"a\": 1,\n  \"b\" 2\n}".to_string());
+        let error = result.error.unwrap();
+        assert_eq!((error.line, error.column), (3, 7));
+        assert!(error.message.starts_with("JSON 语法错误: expected `:`"));
+        assert!(!error.message.contains("at line"));
+        assert_eq!(result.pretty, None);
+
+        assert!(format_json("".to_string()).error.is_some());
+        assert!(format_json("[1,]".to_string()).error.is_some());
+    }
+
+    #[test]
+    fn test_save_to() {
+        let dir = TempDir::new().unwrap();
+        let first = save_to(dir.path(), "{}").unwrap();
+        let second = save_to(dir.path(), "[]").unwrap();
+        assert_ne!(first, second);
+        assert_eq!(fs::read_to_string(&first).unwrap(), "{}");
+        assert!(second.ends_with(".json"));
+    }
+}
@@ -0,0 +1,115 @@
+// 系统钥匙串：保存 GitHub、Jira 等服务的访问令牌（secret-tool / security / Windows 凭据保管库）
+// 令牌只在后端读取，前端只能保存、删除和查询是否已保存
+use crate::system_command::{command, powershell, powershell_with_input, run, run_with_input};
+
+const SERVICE: &str = "launch-rs";
+
+const WINDOWS_VAULT: &str = "[Windows.Security.Credentials.PasswordVault, Windows.Security.Credentials, ContentType = WindowsRuntime] | Out-Null
+$vault = New-Object Windows.Security.Credentials.PasswordVault";
+
+#[tauri::command(async)]
+pub fn save_credential(account: String, secret: String) -> Result<(), String> {
+    let secret = secret.trim();
+    if secret.is_empty() {
+        return Err("令牌不能为空".to_string());
+    }
+    set_secret(&account, secret)
+}
+
+#[tauri::command(async)]
+pub fn has_credential(account: String) -> Result<bool, String> {
+    get_secret(&account).map(|secret| secret.is_some())
+}
+
+#[tauri::command(async)]
+pub fn delete_credential(account: String) -> Result<(), String> {
+    delete_secret(&account)
+}
+
+pub(crate) fn get_secret(account: &str) -> Result<Option<String>, String> {
+    validate_account(account)?;
+    let secret = match std::env::consts::OS {
+        "macos" => {
+            // 条目不存在时 security 以非零状态退出
+            let output = command("security")
+                .args(["find-generic-password", "-s", SERVICE, "-a", account, "-w"])
+                .output()
+                .map_err(|e| format!("执行 security 失败: {}", e))?;
+            output.status.success().then(|| String::from_utf8_lossy(&output.stdout).to_string())
+        }
+        "windows" => {
+            let script = format!(
+                "{}\ntry {{ $credential = $vault.Retrieve('{}', '{}'); $credential.RetrievePassword(); $credential.Password }} catch {{ }}",
+                WINDOWS_VAULT, SERVICE, account
+            );
+            Some(powershell(&script)?)
+        }
+        _ => {
+            let output = command("secret-tool")
+                .args(["lookup", "service", SERVICE, "account", account])
+                .output()
+                .map_err(|e| format!("执行 secret-tool 失败: {}（请安装 libsecret-tools）", e))?;
+            output.status.success().then(|| String::from_utf8_lossy(&output.stdout).to_string())
+        }
+    };
+    Ok(secret.map(|secret| secret.trim().to_string()).filter(|secret| !secret.is_empty()))
+}
+
+pub(crate) fn set_secret(account: &str, secret: &str) -> Result<(), String> {
+    validate_account(account)?;
+    let label = format!("{} ({})", SERVICE, account);
+    match std::env::consts::OS {
+        // -w 放在最后且不带值时 security 从标准输入读取密码（需输入两次确认），-U 会覆盖已有条目
+        "macos" => run_with_input(
+            "security",
+            &["add-generic-password", "-U", "-s", SERVICE, "-a", account, "-l", &label, "-w"],
+            &format!("{}\n{}\n", secret, secret),
+        ),
+        // 同一资源和用户名的凭据会被覆盖
+        "windows" => powershell_with_input(
+            &format!(
+                "{}\n$secret = [Console]::In.ReadToEnd()\n$vault.Add((New-Object Windows.Security.Credentials.PasswordCredential('{}', '{}', $secret)))",
+                WINDOWS_VAULT, SERVICE, account
+            ),
+            secret,
+        ),
+        _ => run_with_input("secret-tool", &["store", "--label", &label, "service", SERVICE, "account", account], secret),
+    }
+    .map(|_| ())
+}
+
+pub(crate) fn delete_secret(account: &str) -> Result<(), String> {
+    validate_account(account)?;
+    if get_secret(account)?.is_none() {
+        return Ok(());
+    }
+    match std::env::consts::OS {
+        "macos" => run("security", &["delete-generic-password", "-s", SERVICE, "-a", account]),
+        "windows" => powershell(&format!("{}\n$vault.Remove($vault.Retrieve('{}', '{}'))", WINDOWS_VAULT, SERVICE, account)),
+        _ => run("secret-tool", &["clear", "service", SERVICE, "account", account]),
+    }
+    .map(|_| ())
+}
+
+// 账户名会拼进 PowerShell 脚本，只允许简单字符
+fn validate_account(account: &str) -> Result<(), String> {
+    let valid = !account.is_empty() && account.chars().all(|c| c.is_ascii_alphanumeric() || "-_.@".contains(c));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("无效的账户名: {}", account))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_account() {
+        assert!(validate_account("github").is_ok());
+        assert!(validate_account("jira.example.com").is_ok());
+        assert!(validate_account("").is_err());
+        assert!(validate_account("x'); Remove-Item").is_err());
+    }
+}
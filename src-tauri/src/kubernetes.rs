@@ -0,0 +1,182 @@
+// Kubernetes：列出 kubeconfig 中的上下文并切换，搜索当前上下文的 Pod 和 Deployment
+use crate::system_command::run;
+use crate::terminal::open_in_terminal;
+use serde::{Deserialize, Serialize};
+
+const MAX_RESULTS: usize = 50;
+// 集群不可达时不要让搜索一直等待
+const REQUEST_TIMEOUT: &str = "--request-timeout=5s";
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct KubeContext {
+    pub name: String,
+    pub cluster: String,
+    pub namespace: Option<String>,
+    pub current: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum KubeResourceKind {
+    Pod,
+    Deployment,
+}
+
+// status 为 Pod 的阶段或 Deployment 的 "就绪数/副本数"
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct KubeResource {
+    pub kind: KubeResourceKind,
+    pub name: String,
+    pub namespace: String,
+    pub status: String,
+}
+
+// kubectl config view 默认会隐藏证书和令牌，这里只读取上下文信息
+#[tauri::command(async)]
+pub fn list_kube_contexts() -> Result<Vec<KubeContext>, String> {
+    let output = run("kubectl", &["config", "view", "-o", "json"]).map_err(kubectl_hint)?;
+    parse_contexts(&output)
+}
+
+#[tauri::command(async)]
+pub fn switch_kube_context(name: String) -> Result<(), String> {
+    run("kubectl", &["config", "use-context", &name]).map(|_| ()).map_err(kubectl_hint)
+}
+
+// 在当前上下文的默认命名空间中搜索
+#[tauri::command(async)]
+pub fn search_kube_resources(query: String) -> Result<Vec<KubeResource>, String> {
+    let output = run("kubectl", &["get", "pods,deployments", "-o", "json", REQUEST_TIMEOUT]).map_err(kubectl_hint)?;
+    let query = query.trim().to_lowercase();
+    let mut resources: Vec<KubeResource> = parse_resources(&output)?
+        .into_iter()
+        .filter(|resource| resource.name.to_lowercase().contains(&query))
+        .collect();
+    resources.sort_by_key(|resource| (!resource.name.to_lowercase().starts_with(&query), resource.name.clone()));
+    resources.truncate(MAX_RESULTS);
+    Ok(resources)
+}
+
+// 在终端中持续查看日志，Deployment 会选择其中一个 Pod
+#[tauri::command]
+pub fn open_kube_logs(resource: KubeResource, terminal_command: Option<String>) -> Result<(), String> {
+    let target = format!("{}/{}", kind_name(resource.kind), resource.name);
+    let args = ["kubectl", "logs", "-f", "--tail=200", "-n", &resource.namespace, &target];
+    open_in_terminal(&args, terminal_command.as_deref())
+}
+
+fn kind_name(kind: KubeResourceKind) -> &'static str {
+    match kind {
+        KubeResourceKind::Pod => "pod",
+        KubeResourceKind::Deployment => "deployment",
+    }
+}
+
+fn kubectl_hint(error: String) -> String {
+    if error.contains("执行 kubectl 失败") {
+        format!("{}（请先安装 kubectl 并配置 kubeconfig）", error)
+    } else {
+        error
+    }
+}
+
+fn parse_contexts(json: &str) -> Result<Vec<KubeContext>, String> {
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| format!("解析 kubeconfig 失败: {}", e))?;
+    let current = value.get("current-context").and_then(|current| current.as_str()).unwrap_or_default();
+
+    Ok(value.get("contexts")
+        .and_then(|contexts| contexts.as_array())
+        .map(|contexts| {
+            contexts.iter()
+                .filter_map(|context| {
+                    let name = context.get("name")?.as_str()?;
+                    let details = context.get("context");
+                    let field = |key: &str| details.and_then(|details| details.get(key)).and_then(|value| value.as_str()).map(str::to_string);
+                    Some(KubeContext {
+                        name: name.to_string(),
+                        cluster: field("cluster").unwrap_or_default(),
+                        namespace: field("namespace"),
+                        current: name == current,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+fn parse_resources(json: &str) -> Result<Vec<KubeResource>, String> {
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| format!("解析 kubectl 输出失败: {}", e))?;
+    let items = value.get("items").and_then(|items| items.as_array()).cloned().unwrap_or_default();
+
+    Ok(items.iter()
+        .filter_map(|item| {
+            let kind = match item.get("kind")?.as_str()? {
+                "Pod" => KubeResourceKind::Pod,
+                "Deployment" => KubeResourceKind::Deployment,
+                _ => return None,
+            };
+            let metadata = item.get("metadata")?;
+            let status = item.get("status");
+            let number = |key: &str| status.and_then(|status| status.get(key)).and_then(|value| value.as_u64()).unwrap_or(0);
+
+            Some(KubeResource {
+                kind,
+                name: metadata.get("name")?.as_str()?.to_string(),
+                namespace: metadata.get("namespace").and_then(|namespace| namespace.as_str()).unwrap_or("default").to_string(),
+                status: match kind {
+                    KubeResourceKind::Pod => status
+                        .and_then(|status| status.get("phase"))
+                        .and_then(|phase| phase.as_str())
+                        .unwrap_or("Unknown")
+                        .to_string(),
+                    KubeResourceKind::Deployment => format!("{}/{}", number("readyReplicas"), number("replicas")),
+                },
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_contexts() {
+        let json = r#"{
+            "current-context": "prod",
+            "contexts": [
+                {"name": "prod", "context": {"cluster": "prod-cluster", "user": "admin", "namespace": "web"}},
+                {"name": "kind-dev", "context": {"cluster": "kind-dev", "user": "kind-dev"}}
+            ],
+            "users": [{"name": "admin", "user": {"token": "REDACTED"}}]
+        }"#;
+        let contexts = parse_contexts(json).unwrap();
+        assert_eq!(contexts.len(), 2);
+        assert_eq!(contexts[0], KubeContext {
+            name: "prod".into(),
+            cluster: "prod-cluster".into(),
+            namespace: Some("web".into()),
+            current: true,
+        });
+        assert!(!contexts[1].current);
+        assert_eq!(contexts[1].namespace, None);
+        assert!(parse_contexts("not json").is_err());
+    }
+
+    #[test]
+    fn test_parse_resources() {
+        let json = r#"{"items": [
+            {"kind": "Pod", "metadata": {"name": "web-7d9f-abcde", "namespace": "web"}, "status": {"phase": "Running"}},
+            {"kind": "Deployment", "metadata": {"name": "web", "namespace": "web"}, "status": {"replicas": 3, "readyReplicas": 2}},
+            {"kind": "Deployment", "metadata": {"name": "idle"}, "status": {}},
+            {"kind": "Service", "metadata": {"name": "web"}}
+        ]}"#;
+        let resources = parse_resources(json).unwrap();
+        assert_eq!(resources.len(), 3);
+        assert_eq!(resources[0].status, "Running");
+        assert_eq!(resources[1].kind, KubeResourceKind::Deployment);
+        assert_eq!(resources[1].status, "2/3");
+        assert_eq!(resources[2].namespace, "default");
+        assert_eq!(resources[2].status, "0/0");
+    }
+}
@@ -1,12 +1,81 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 use tauri::Manager;
-use tauri_plugin_global_shortcut::{Shortcut, GlobalShortcutExt};
+use tauri_plugin_global_shortcut::{Shortcut, GlobalShortcutExt, ShortcutState};
 use std::collections::HashMap;
 use std::sync::{Mutex, LazyLock};
 use std::fs;
 use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 
+mod clipboard;
+mod password;
+mod lorem;
+mod timestamp;
+mod world_clock;
+mod notifications;
+mod timers;
+mod db;
+mod scheduler;
+mod reminders;
+mod calendar;
+mod contacts;
+mod notes;
+mod todos;
+mod power;
+mod processes;
+mod sysmon;
+mod system_command;
+mod wifi;
+mod bluetooth;
+mod audio;
+mod brightness;
+mod media;
+mod spotify;
+mod color_picker;
+mod ocr;
+mod workspaces;
+mod dnd;
+mod appearance;
+mod trash;
+mod drives;
+mod terminal;
+mod ssh;
+mod shell;
+mod shell_history;
+mod env_vars;
+mod man_pages;
+mod packages;
+mod kubernetes;
+mod git_repos;
+mod vscode;
+mod jetbrains;
+mod http;
+mod keyring;
+mod github;
+mod issue_tracker;
+mod registry;
+mod stackoverflow;
+mod docs;
+mod quicklinks;
+mod open_target;
+mod network_tools;
+mod ports;
+mod weather;
+mod feeds;
+mod mail;
+mod vault;
+mod battery;
+mod text_transform;
+mod json_format;
+mod regex_tester;
+mod cron;
+mod number_base;
+mod text_diff;
+mod usage;
+mod network_shares;
+mod cloud_drives;
+mod fonts;
+
 // 用于存储已注册的快捷键
 static REGISTERED_SHORTCUTS: LazyLock<Mutex<HashMap<String, Shortcut>>> = LazyLock::new(|| {
     Mutex::new(HashMap::new())
@@ -71,7 +140,21 @@ fn register_global_shortcut(
     
     // 设置快捷键监听器
     let app_handle_clone = app_handle.clone();
-    app_handle.global_shortcut().on_shortcut(shortcut.clone(), move |_app, _shortcut, _event| {
+    let media_action = media::MediaAction::from_shortcut_id(&shortcut_id);
+    app_handle.global_shortcut().on_shortcut(shortcut.clone(), move |_app, _shortcut, event| {
+        // 媒体控制快捷键，只在按下时触发一次
+        if let Some(action) = media_action {
+            if event.state() == ShortcutState::Pressed {
+                // 发送按键会调用外部命令，不能阻塞主线程
+                std::thread::spawn(move || {
+                    if let Err(e) = media::send(action) {
+                        log::error!("媒体控制失败: {}", e);
+                    }
+                });
+            }
+            return;
+        }
+
         let window = app_handle_clone.get_webview_window("main");
         if let Some(window) = window {
             // 检查窗口是否可见
@@ -347,12 +430,167 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_log::Builder::new().build())
         .invoke_handler(tauri::generate_handler![
             greet, 
             toggle_headless,
             register_global_shortcut,
             unregister_global_shortcut,
-            search_files
+            search_files,
+            clipboard::copy_to_clipboard,
+            clipboard::read_clipboard_text,
+            clipboard::save_clipboard_image,
+            password::generate_password,
+            password::generate_passphrase,
+            lorem::generate_lorem,
+            timestamp::convert_timestamp,
+            world_clock::lookup_world_time,
+            timers::start_timer,
+            timers::start_pomodoro,
+            timers::list_timers,
+            timers::cancel_timer,
+            reminders::preview_reminder,
+            reminders::add_reminder,
+            reminders::list_reminders,
+            reminders::delete_reminder,
+            reminders::snooze_reminder,
+            calendar::upcoming_events,
+            contacts::search_contacts,
+            notes::search_notes,
+            todos::preview_todo,
+            todos::add_todo,
+            todos::list_todos,
+            todos::complete_todo,
+            todos::delete_todo,
+            power::list_power_actions,
+            power::run_power_action,
+            processes::search_processes,
+            processes::kill_process,
+            sysmon::get_system_snapshot,
+            sysmon::start_system_monitor,
+            sysmon::stop_system_monitor,
+            wifi::list_wifi_networks,
+            wifi::connect_wifi,
+            wifi::set_wifi_enabled,
+            bluetooth::list_bluetooth_devices,
+            bluetooth::connect_bluetooth,
+            bluetooth::disconnect_bluetooth,
+            audio::list_audio_devices,
+            audio::set_default_audio_device,
+            audio::get_volume,
+            audio::set_volume,
+            audio::set_muted,
+            brightness::get_brightness,
+            brightness::set_brightness,
+            media::media_control,
+            media::now_playing,
+            spotify::spotify_now_playing,
+            spotify::spotify_save_track,
+            color_picker::pick_color,
+            ocr::ocr_screen_region,
+            workspaces::list_workspaces,
+            workspaces::switch_workspace,
+            workspaces::move_window_to_workspace,
+            dnd::get_dnd_state,
+            dnd::set_dnd,
+            appearance::get_system_appearance,
+            appearance::set_system_appearance,
+            trash::get_trash_summary,
+            trash::empty_trash,
+            drives::list_removable_drives,
+            drives::eject_drive,
+            ssh::list_ssh_hosts,
+            ssh::open_ssh,
+            shell::run_shell_command,
+            shell::kill_shell_command,
+            shell::run_shell_in_terminal,
+            shell_history::search_shell_history,
+            env_vars::search_env_vars,
+            env_vars::get_env_var,
+            man_pages::search_man_pages,
+            man_pages::render_man_page,
+            man_pages::render_tldr_page,
+            man_pages::open_man_page,
+            packages::search_packages,
+            packages::run_package_action,
+            kubernetes::list_kube_contexts,
+            kubernetes::switch_kube_context,
+            kubernetes::search_kube_resources,
+            kubernetes::open_kube_logs,
+            git_repos::set_git_repo_roots,
+            git_repos::search_git_repos,
+            git_repos::open_git_repo_in_editor,
+            git_repos::open_git_repo_in_terminal,
+            git_repos::get_git_remote_url,
+            vscode::list_vscode_recents,
+            vscode::open_vscode_recent,
+            jetbrains::list_jetbrains_projects,
+            jetbrains::open_jetbrains_project,
+            keyring::save_credential,
+            keyring::has_credential,
+            keyring::delete_credential,
+            github::search_github,
+            issue_tracker::search_issue_tracker,
+            registry::search_package_registry,
+            stackoverflow::search_stackoverflow,
+            docs::set_doc_roots,
+            docs::search_docs,
+            docs::render_doc_entry,
+            quicklinks::list_quicklinks,
+            quicklinks::add_quicklink,
+            quicklinks::update_quicklink,
+            quicklinks::delete_quicklink,
+            quicklinks::match_quicklinks,
+            open_target::detect_open_target,
+            network_tools::dns_lookup,
+            network_tools::whois_lookup,
+            network_tools::ip_info,
+            ports::find_port_listeners,
+            weather::get_weather,
+            feeds::list_feeds,
+            feeds::add_feed,
+            feeds::delete_feed,
+            feeds::list_unread_feed_items,
+            feeds::mark_feed_items_read,
+            feeds::refresh_feeds,
+            mail::search_mail,
+            mail::export_mail_message,
+            vault::vault_status,
+            vault::unlock_vault,
+            vault::lock_vault,
+            vault::search_vault,
+            vault::copy_vault_field,
+            battery::get_power_status,
+            text_transform::transform_text,
+            json_format::format_json,
+            json_format::save_json_file,
+            regex_tester::test_regex,
+            cron::explain_cron,
+            number_base::convert_number_base,
+            text_diff::diff_clipboard,
+            text_diff::diff_files,
+            usage::record_search,
+            usage::record_selection,
+            usage::get_usage_stats,
+            usage::get_selection_boosts,
+            usage::reset_learning,
+            network_shares::list_network_shares,
+            network_shares::add_network_share,
+            network_shares::remove_network_share,
+            network_shares::refresh_network_share,
+            network_shares::search_network_shares,
+            cloud_drives::start_cloud_login,
+            cloud_drives::complete_cloud_login,
+            cloud_drives::disconnect_cloud_drive,
+            cloud_drives::list_cloud_drives,
+            cloud_drives::search_cloud_drives,
+            cloud_drives::download_cloud_file,
+            trash::search_trash,
+            trash::restore_trash_item,
+            trash::delete_trash_item,
+            fonts::search_fonts,
+            fonts::render_font_preview
         ])
         .setup(|app| {
             // 从环境变量获取是否启用无头模式
@@ -365,6 +603,15 @@ pub fn run() {
                 }
             }
 
+            // 初始化数据库并启动后台定时任务
+            db::init(app.handle())?;
+            scheduler::every("reminders", std::time::Duration::from_secs(15), reminders::deliver_due);
+            scheduler::every("git-repos", git_repos::REFRESH_INTERVAL, git_repos::refresh);
+            scheduler::every("docs", docs::REFRESH_INTERVAL, docs::refresh);
+            scheduler::every("feeds", feeds::REFRESH_INTERVAL, feeds::refresh);
+            scheduler::every("clipboard", clipboard::WATCH_INTERVAL, clipboard::watch);
+            scheduler::start(app.handle().clone());
+
             Ok(())
         })
         .run(tauri::generate_context!())
@@ -0,0 +1,188 @@
+// 占位文本（Lorem ipsum）生成
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+const WORDS: &[&str] = &[
+    "lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit",
+    "sed", "do", "eiusmod", "tempor", "incididunt", "ut", "labore", "et", "dolore",
+    "magna", "aliqua", "enim", "ad", "minim", "veniam", "quis", "nostrud",
+    "exercitation", "ullamco", "laboris", "nisi", "aliquip", "ex", "ea", "commodo",
+    "consequat", "duis", "aute", "irure", "in", "reprehenderit", "voluptate",
+    "velit", "esse", "cillum", "fugiat", "nulla", "pariatur", "excepteur", "sint",
+    "occaecat", "cupidatat", "non", "proident", "sunt", "culpa", "qui", "officia",
+    "deserunt", "mollit", "anim", "id", "est", "laborum", "perspiciatis", "unde",
+    "omnis", "iste", "natus", "error", "voluptatem", "accusantium", "doloremque",
+    "laudantium", "totam", "rem", "aperiam", "eaque", "ipsa", "quae", "ab", "illo",
+    "inventore", "veritatis", "quasi", "architecto", "beatae", "vitae", "dicta",
+];
+
+// 经典开头
+const OPENING: &[&str] = &["lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit"];
+
+const MAX_COUNT: usize = 100;
+
+// 生成单位
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoremUnit {
+    Words,
+    Sentences,
+    Paragraphs,
+}
+
+impl LoremUnit {
+    pub fn parse(unit: &str) -> Option<Self> {
+        match unit.trim().to_lowercase().as_str() {
+            "w" | "word" | "words" => Some(Self::Words),
+            "s" | "sentence" | "sentences" => Some(Self::Sentences),
+            "p" | "paragraph" | "paragraphs" => Some(Self::Paragraphs),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Words => "words",
+            Self::Sentences => "sentences",
+            Self::Paragraphs => "paragraphs",
+        }
+    }
+}
+
+// 生成结果
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LoremText {
+    pub text: String,
+    pub unit: String,
+    pub count: usize,
+    pub word_count: usize,
+}
+
+#[tauri::command]
+pub fn generate_lorem(
+    count: usize,
+    unit: String,
+    start_with_lorem: Option<bool>,
+) -> Result<LoremText, String> {
+    let unit = LoremUnit::parse(&unit)
+        .ok_or_else(|| format!("不支持的单位: {}", unit))?;
+
+    if count == 0 || count > MAX_COUNT {
+        return Err(format!("数量必须在 1 到 {} 之间", MAX_COUNT));
+    }
+
+    let text = build_text(&mut rand::thread_rng(), count, unit, start_with_lorem.unwrap_or(true));
+    let word_count = text.split_whitespace().count();
+
+    Ok(LoremText {
+        text,
+        unit: unit.as_str().to_string(),
+        count,
+        word_count,
+    })
+}
+
+fn build_text<R: Rng>(rng: &mut R, count: usize, unit: LoremUnit, start_with_lorem: bool) -> String {
+    match unit {
+        LoremUnit::Words => {
+            let mut words = random_words(rng, count);
+            if start_with_lorem {
+                for (word, opening) in words.iter_mut().zip(OPENING) {
+                    *word = opening;
+                }
+            }
+            words.join(" ")
+        }
+        LoremUnit::Sentences => sentences(rng, count, start_with_lorem).join(" "),
+        LoremUnit::Paragraphs => (0..count)
+            .map(|i| {
+                let sentence_count = rng.gen_range(3..=6);
+                sentences(rng, sentence_count, start_with_lorem && i == 0).join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    }
+}
+
+fn random_words<R: Rng>(rng: &mut R, count: usize) -> Vec<&'static str> {
+    (0..count)
+        .map(|_| *WORDS.choose(rng).unwrap_or(&"lorem"))
+        .collect()
+}
+
+fn sentences<R: Rng>(rng: &mut R, count: usize, start_with_lorem: bool) -> Vec<String> {
+    (0..count)
+        .map(|i| {
+            if start_with_lorem && i == 0 {
+                return format!("{}.", capitalize(&OPENING.join(" ")).replacen("amet", "amet,", 1));
+            }
+
+            let length = rng.gen_range(6..=14);
+            let mut words = random_words(rng, length).join(" ");
+
+            // 较长的句子中间加一个逗号
+            if length > 8 {
+                if let Some(pos) = words.match_indices(' ').nth(length / 2 - 1).map(|(pos, _)| pos) {
+                    words.insert(pos, ',');
+                }
+            }
+
+            format!("{}.", capitalize(&words))
+        })
+        .collect()
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lorem_unit_parse() {
+        assert_eq!(LoremUnit::parse("p"), Some(LoremUnit::Paragraphs));
+        assert_eq!(LoremUnit::parse("Sentences"), Some(LoremUnit::Sentences));
+        assert_eq!(LoremUnit::parse(" words "), Some(LoremUnit::Words));
+        assert_eq!(LoremUnit::parse("lines"), None);
+    }
+
+    #[test]
+    fn test_generate_words() {
+        let result = generate_lorem(5, "w".to_string(), None).expect("Should generate words");
+        assert_eq!(result.word_count, 5);
+        assert_eq!(result.unit, "words");
+        assert_eq!(result.text, "lorem ipsum dolor sit amet");
+
+        // 不以 lorem ipsum 开头时单词数依然正确
+        let result = generate_lorem(12, "words".to_string(), Some(false)).unwrap();
+        assert_eq!(result.word_count, 12);
+    }
+
+    #[test]
+    fn test_generate_sentences() {
+        let result = generate_lorem(4, "s".to_string(), Some(true)).unwrap();
+        assert!(result.text.starts_with("Lorem ipsum dolor sit amet, consectetur adipiscing elit."));
+        assert_eq!(result.text.matches('.').count(), 4);
+    }
+
+    #[test]
+    fn test_generate_paragraphs() {
+        let result = generate_lorem(3, "p".to_string(), None).unwrap();
+        assert_eq!(result.text.split("\n\n").count(), 3);
+        assert!(result.text.split("\n\n").all(|p| p.ends_with('.')));
+        assert!(result.word_count > 3 * 3 * 6 - 1);
+    }
+
+    #[test]
+    fn test_generate_lorem_invalid() {
+        assert!(generate_lorem(0, "p".to_string(), None).is_err());
+        assert!(generate_lorem(MAX_COUNT + 1, "w".to_string(), None).is_err());
+        assert!(generate_lorem(3, "lines".to_string(), None).is_err());
+    }
+}
@@ -0,0 +1,542 @@
+// 本地邮件搜索：索引 Maildir、mbox（Thunderbird 等）和 Apple Mail 的 .emlx，按发件人和主题查找
+use crate::http::TtlCache;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+const MAX_RESULTS: usize = 30;
+// Apple Mail 的 .emlx 位于 V10/<账户>/<邮箱>.mbox/<ID>/Data/x/y/Messages 下
+const MAX_SCAN_DEPTH: usize = 10;
+const MAX_HEADER_BYTES: usize = 64 * 1024;
+// Thunderbird 已删除但尚未压缩的邮件
+const MOZILLA_DELETED: u32 = 0x0008;
+
+// 扫描邮件目录较慢，索引保留 5 分钟
+static INDEX: LazyLock<TtlCache<Arc<Vec<MailMessage>>>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(5 * 60)));
+
+// offset 和 length 为邮件原文在 path 中的位置，打开时据此导出 .eml
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MailMessage {
+    pub subject: String,
+    pub from: String,
+    pub date: Option<i64>,
+    pub path: String,
+    pub offset: u64,
+    pub length: u64,
+    pub source: String,
+}
+
+#[tauri::command(async)]
+pub fn search_mail(
+    query: String,
+    mail_paths: Option<Vec<String>>,
+    include_default: Option<bool>,
+) -> Result<Vec<MailMessage>, String> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if words.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut roots: Vec<PathBuf> = mail_paths.unwrap_or_default().iter()
+        .map(|path| path.trim())
+        .filter(|path| !path.is_empty())
+        .map(expand_home)
+        .collect();
+    if include_default.unwrap_or(true) {
+        roots.extend(default_roots());
+    }
+    roots.sort();
+    roots.dedup();
+
+    let key = roots.iter().map(|root| root.to_string_lossy()).collect::<Vec<_>>().join("|");
+    let index = INDEX.get_or_fetch(&key, || {
+        let mut messages = Vec::new();
+        for root in &roots {
+            collect_messages(root, 0, &mut messages);
+        }
+        Ok(Arc::new(messages))
+    })?;
+
+    let mut matches: Vec<MailMessage> = index.iter().filter(|message| matches_words(message, &words)).cloned().collect();
+    matches.sort_by_key(|message| std::cmp::Reverse(message.date));
+    matches.truncate(MAX_RESULTS);
+    Ok(matches)
+}
+
+// 把邮件原文导出为临时 .eml 文件并返回路径，由前端交给默认邮件客户端打开
+#[tauri::command(async)]
+pub fn export_mail_message(path: String, offset: u64, length: u64) -> Result<String, String> {
+    let source = PathBuf::from(&path);
+    if !is_mail_file(&source) {
+        return Err(format!("不是邮件文件: {}", path));
+    }
+
+    let mut file = File::open(&source).map_err(|e| format!("打开邮件失败: {}", e))?;
+    file.seek(SeekFrom::Start(offset)).map_err(|e| format!("读取邮件失败: {}", e))?;
+    let mut content = Vec::new();
+    file.take(length).read_to_end(&mut content).map_err(|e| format!("读取邮件失败: {}", e))?;
+
+    let dir = std::env::temp_dir().join("launch-rs-mail");
+    fs::create_dir_all(&dir).map_err(|e| format!("创建临时目录失败: {}", e))?;
+    let mut hasher = DefaultHasher::new();
+    (&path, offset).hash(&mut hasher);
+    let target = dir.join(format!("message-{:016x}.eml", hasher.finish()));
+    fs::write(&target, content).map_err(|e| format!("导出邮件失败: {}", e))?;
+    Ok(target.to_string_lossy().to_string())
+}
+
+// 所有词都要出现在主题或发件人中
+fn matches_words(message: &MailMessage, words: &[String]) -> bool {
+    let text = format!("{} {}", message.subject, message.from).to_lowercase();
+    words.iter().all(|word| text.contains(word.as_str()))
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+// Outlook 的 .pst/.ost 是私有格式，无法直接读取，可导出为 mbox 后添加目录
+fn default_roots() -> Vec<PathBuf> {
+    let Some(home) = dirs::home_dir() else {
+        return vec![];
+    };
+    let mut roots = vec![home.join("Maildir"), home.join(".mail"), home.join("Mail")];
+    match std::env::consts::OS {
+        "macos" => {
+            // Apple Mail 需要为应用授予“完全磁盘访问权限”
+            roots.push(home.join("Library/Mail"));
+            roots.push(home.join("Library/Thunderbird/Profiles"));
+        }
+        "windows" => {
+            if let Some(data_dir) = dirs::data_dir() {
+                roots.push(data_dir.join("Thunderbird").join("Profiles"));
+            }
+        }
+        _ => {
+            roots.push(home.join(".thunderbird"));
+            roots.push(home.join(".local/share/evolution/mail/local"));
+        }
+    }
+    roots
+}
+
+fn collect_messages(path: &Path, depth: usize, messages: &mut Vec<MailMessage>) {
+    if path.is_file() {
+        messages.extend(read_mail_file(path));
+        return;
+    }
+    if depth >= MAX_SCAN_DEPTH {
+        return;
+    }
+
+    // Maildir 的邮件在 cur 和 new 中，tmp 为正在投递的邮件
+    let maildir = path.join("cur").is_dir() && path.join("new").is_dir();
+    if maildir {
+        for dir in ["cur", "new"] {
+            let Ok(entries) = fs::read_dir(path.join(dir)) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let file = entry.path();
+                if entry.file_name().to_string_lossy().starts_with('.') {
+                    continue;
+                }
+                if let Some(message) = read_message(&file, 0, None, "Maildir") {
+                    messages.push(message);
+                }
+            }
+        }
+    }
+
+    let Ok(entries) = fs::read_dir(path) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        // 不跟随符号链接，避免循环
+        if entry.file_type().is_ok_and(|file_type| file_type.is_symlink()) {
+            continue;
+        }
+        let name = entry.file_name();
+        if maildir && matches!(name.to_str(), Some("cur" | "new" | "tmp")) {
+            continue;
+        }
+        collect_messages(&entry.path(), depth + 1, messages);
+    }
+}
+
+fn read_mail_file(path: &Path) -> Vec<MailMessage> {
+    let extension = path.extension().map(|ext| ext.to_string_lossy().to_lowercase());
+    match extension.as_deref() {
+        Some("emlx") => read_emlx(path).into_iter().collect(),
+        Some("eml") => read_message(path, 0, None, "eml").into_iter().collect(),
+        None | Some("mbox") | Some("mbx") if starts_with_from_line(path) => match File::open(path) {
+            Ok(file) => parse_mbox(BufReader::new(file), &path.to_string_lossy()),
+            Err(_) => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+// 只有 mbox、Maildir、.eml 和 .emlx 文件可以导出
+fn is_mail_file(path: &Path) -> bool {
+    let extension = path.extension().map(|ext| ext.to_string_lossy().to_lowercase());
+    let in_maildir = path.parent().is_some_and(|dir| dir.ends_with("cur") || dir.ends_with("new"));
+    matches!(extension.as_deref(), Some("eml" | "emlx")) || in_maildir || starts_with_from_line(path)
+}
+
+fn starts_with_from_line(path: &Path) -> bool {
+    let mut prefix = [0u8; 5];
+    File::open(path).and_then(|mut file| file.read_exact(&mut prefix)).is_ok() && &prefix == b"From "
+}
+
+// .emlx 第一行是邮件原文的字节数，原文之后是 Apple Mail 的 plist
+fn read_emlx(path: &Path) -> Option<MailMessage> {
+    let mut reader = BufReader::new(File::open(path).ok()?);
+    let mut first_line = String::new();
+    reader.read_line(&mut first_line).ok()?;
+    let length: u64 = first_line.trim().parse().ok()?;
+    read_message(path, first_line.len() as u64, Some(length), "Apple Mail")
+}
+
+fn read_message(path: &Path, offset: u64, length: Option<u64>, source: &str) -> Option<MailMessage> {
+    let mut file = File::open(path).ok()?;
+    let length = match length {
+        Some(length) => length,
+        None => file.metadata().ok()?.len(),
+    };
+    file.seek(SeekFrom::Start(offset)).ok()?;
+
+    let mut head = Vec::new();
+    file.take(MAX_HEADER_BYTES.min(length as usize) as u64).read_to_end(&mut head).ok()?;
+    let header_end = find_header_end(&head).unwrap_or(head.len());
+    let headers = parse_headers(&head[..header_end])?;
+    Some(MailMessage {
+        subject: headers.subject,
+        from: headers.from,
+        date: headers.date,
+        path: path.to_string_lossy().to_string(),
+        offset,
+        length,
+        source: source.to_string(),
+    })
+}
+
+fn find_header_end(bytes: &[u8]) -> Option<usize> {
+    bytes.windows(2).position(|window| window == b"\n\n")
+        .or_else(|| bytes.windows(4).position(|window| window == b"\r\n\r\n"))
+}
+
+// 每封邮件以空行后的 "From " 行开始，记录不含该行的原文范围
+fn parse_mbox<R: BufRead>(mut reader: R, path: &str) -> Vec<MailMessage> {
+    struct Pending {
+        offset: u64,
+        headers: Vec<u8>,
+        in_headers: bool,
+    }
+
+    fn finish(pending: Option<Pending>, end: u64, path: &str, messages: &mut Vec<MailMessage>) {
+        let Some(pending) = pending else {
+            return;
+        };
+        let Some(headers) = parse_headers(&pending.headers) else {
+            return;
+        };
+        if headers.mozilla_status.is_some_and(|status| status & MOZILLA_DELETED != 0) {
+            return;
+        }
+        messages.push(MailMessage {
+            subject: headers.subject,
+            from: headers.from,
+            date: headers.date,
+            path: path.to_string(),
+            offset: pending.offset,
+            length: end.saturating_sub(pending.offset),
+            source: "mbox".to_string(),
+        });
+    }
+
+    let mut messages = Vec::new();
+    let mut pending: Option<Pending> = None;
+    let mut position = 0u64;
+    let mut previous_blank = true;
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        match reader.read_until(b'\n', &mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        let blank = line.iter().all(|byte| byte.is_ascii_whitespace());
+
+        if previous_blank && line.starts_with(b"From ") {
+            finish(pending.take(), position, path, &mut messages);
+            pending = Some(Pending { offset: position + line.len() as u64, headers: Vec::new(), in_headers: true });
+        } else if let Some(current) = pending.as_mut().filter(|current| current.in_headers) {
+            if blank {
+                current.in_headers = false;
+            } else if current.headers.len() < MAX_HEADER_BYTES {
+                current.headers.extend_from_slice(&line);
+            }
+        }
+
+        previous_blank = blank;
+        position += line.len() as u64;
+    }
+    finish(pending, position, path, &mut messages);
+    messages
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Headers {
+    subject: String,
+    from: String,
+    date: Option<i64>,
+    mozilla_status: Option<u32>,
+}
+
+// 没有发件人和主题时不视为邮件
+fn parse_headers(bytes: &[u8]) -> Option<Headers> {
+    let text = String::from_utf8_lossy(bytes);
+    let mut fields: Vec<(String, String)> = Vec::new();
+    for line in text.lines() {
+        if line.starts_with([' ', '\t']) {
+            // 折叠的续行
+            if let Some((_, value)) = fields.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((name, value)) = line.split_once(':') {
+            fields.push((name.trim().to_lowercase(), value.trim().to_string()));
+        }
+    }
+
+    let field = |name: &str| fields.iter().find(|(field, _)| field == name).map(|(_, value)| value.as_str());
+    let subject = field("subject").map(decode_header);
+    let from = field("from").map(|from| decode_header(from).replace('"', ""));
+    if subject.is_none() && from.is_none() {
+        return None;
+    }
+
+    Some(Headers {
+        subject: subject.filter(|subject| !subject.is_empty()).unwrap_or_else(|| "(无主题)".to_string()),
+        from: from.unwrap_or_default(),
+        date: field("date").and_then(|date| chrono::DateTime::parse_from_rfc2822(date).ok()).map(|date| date.timestamp()),
+        mozilla_status: field("x-mozilla-status").and_then(|status| u32::from_str_radix(status, 16).ok()),
+    })
+}
+
+// 解码 RFC 2047 编码字（=?UTF-8?B?...?=），相邻编码字之间的空白会被忽略
+fn decode_header(value: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = value;
+    let mut previous_encoded = false;
+    while let Some(start) = rest.find("=?") {
+        let (before, candidate) = rest.split_at(start);
+        match decode_encoded_word(candidate) {
+            Some((word, used)) => {
+                if !(previous_encoded && before.trim().is_empty()) {
+                    decoded.push_str(before);
+                }
+                decoded.push_str(&word);
+                rest = &candidate[used..];
+                previous_encoded = true;
+            }
+            None => {
+                decoded.push_str(before);
+                decoded.push_str("=?");
+                rest = &candidate[2..];
+                previous_encoded = false;
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// 返回解码结果和编码字占用的字节数
+fn decode_encoded_word(word: &str) -> Option<(String, usize)> {
+    let inner = word.strip_prefix("=?")?;
+    let (charset, rest) = inner.split_once('?')?;
+    let (encoding, rest) = rest.split_once('?')?;
+    let end = rest.find("?=")?;
+    let text = &rest[..end];
+    if charset.contains(char::is_whitespace) || text.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let bytes = match encoding {
+        "B" | "b" => decode_base64(text)?,
+        "Q" | "q" => decode_quoted_printable(text),
+        _ => return None,
+    };
+    let used = 2 + charset.len() + 1 + encoding.len() + 1 + end + 2;
+    // 字符集后可能带语言，如 UTF-8*en
+    let decoded = match charset.split('*').next().unwrap_or(charset).to_lowercase().as_str() {
+        "iso-8859-1" | "latin1" | "windows-1252" | "us-ascii" => bytes.iter().map(|&byte| byte as char).collect(),
+        _ => String::from_utf8_lossy(&bytes).to_string(),
+    };
+    Some((decoded, used))
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    fn value(byte: u8) -> Option<u32> {
+        match byte {
+            b'A'..=b'Z' => Some((byte - b'A') as u32),
+            b'a'..=b'z' => Some((byte - b'a' + 26) as u32),
+            b'0'..=b'9' => Some((byte - b'0' + 52) as u32),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for byte in text.bytes().filter(|&byte| byte != b'=') {
+        buffer = (buffer << 6) | value(byte)?;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+// 编码字中的 Q 编码：下划线表示空格，=XX 为十六进制字节
+fn decode_quoted_printable(text: &str) -> Vec<u8> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'_' => decoded.push(b' '),
+            b'=' if i + 2 < bytes.len() => {
+                match std::str::from_utf8(&bytes[i + 1..i + 3]).ok().and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 3;
+                        continue;
+                    }
+                    None => decoded.push(b'='),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use tempfile::TempDir;
+
+    const MBOX: &str = "From alice@example.com Mon Jun  3 09:00:00 2024
+From: \"Alice\" <alice@example.com>
+Subject: Quarterly report
+Date: Mon, 03 Jun 2024 09:00:00 +0000
+
+Body line
+From the desk of Alice
+
+From bob@example.com Tue Jun  4 10:00:00 2024
+From: =?UTF-8?B?5byg5LiJ?= <zhangsan@example.com>
+Subject: =?UTF-8?Q?Caf=C3=A9?= =?UTF-8?Q?_menu?=
+X-Mozilla-Status: 0001
+
+Hi
+";
+
+    #[test]
+    fn test_parse_mbox() {
+        let messages = parse_mbox(Cursor::new(MBOX), "/mail/Inbox");
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].from, "Alice <alice@example.com>");
+        assert_eq!(messages[0].subject, "Quarterly report");
+        assert_eq!(messages[0].date, Some(1717405200));
+        // 正文中的 "From the desk" 前面不是空行，不会被当作新邮件
+        let first = &MBOX[messages[0].offset as usize..(messages[0].offset + messages[0].length) as usize];
+        assert!(first.starts_with("From: \"Alice\""));
+        assert!(first.contains("From the desk of Alice"));
+        assert_eq!(messages[1].from, "张三 <zhangsan@example.com>");
+        assert_eq!(messages[1].subject, "Café menu");
+        assert!(MBOX[messages[1].offset as usize..].starts_with("From: =?UTF-8?B?"));
+    }
+
+    #[test]
+    fn test_parse_mbox_skips_deleted_thunderbird_messages() {
+        let mbox = "From - Mon Jun  3 09:00:00 2024\nX-Mozilla-Status: 0009\nSubject: deleted\n\nbody\n";
+        assert!(parse_mbox(Cursor::new(mbox), "Inbox").is_empty());
+    }
+
+    #[test]
+    fn test_decode_header() {
+        assert_eq!(decode_header("=?utf-8?b?5L2g5aW9?= world"), "你好 world");
+        assert_eq!(decode_header("=?ISO-8859-1?Q?Andr=E9?= Pirard"), "André Pirard");
+        assert_eq!(decode_header("=?UTF-8?Q?a?=  =?UTF-8?Q?b?="), "ab");
+        assert_eq!(decode_header("2 =? 3"), "2 =? 3");
+        assert_eq!(decode_base64("aGVsbG8="), Some(b"hello".to_vec()));
+        assert_eq!(decode_base64("!!"), None);
+    }
+
+    #[test]
+    fn test_parse_headers_unfolds_lines() {
+        let headers = parse_headers(b"Subject: a very\r\n long subject\r\nFrom: carol@example.com\r\n").unwrap();
+        assert_eq!(headers.subject, "a very long subject");
+        assert_eq!(headers.from, "carol@example.com");
+        assert_eq!(parse_headers(b"not a mail header"), None);
+    }
+
+    #[test]
+    fn test_collect_maildir_and_emlx() {
+        let dir = TempDir::new().unwrap();
+        let maildir = dir.path().join("Maildir");
+        for sub in ["cur", "new", "tmp"] {
+            fs::create_dir_all(maildir.join(sub)).unwrap();
+        }
+        fs::write(maildir.join("cur/1717405200.M1P1.host:2,S"), "From: dave@example.com\nSubject: Invoice\n\nbody").unwrap();
+        fs::write(maildir.join("tmp/1717405201.M2P2.host"), "From: x@example.com\nSubject: Partial\n\n").unwrap();
+
+        let message = "From: erin@example.com\nSubject: Travel plans\n\nSee you";
+        let emlx = format!("{}\n{}<?xml version=\"1.0\"?><plist/>", message.len(), message);
+        fs::write(dir.path().join("42.emlx"), emlx).unwrap();
+
+        let mut messages = Vec::new();
+        collect_messages(dir.path(), 0, &mut messages);
+        messages.sort_by(|a, b| a.subject.cmp(&b.subject));
+        assert_eq!(messages.iter().map(|message| message.subject.as_str()).collect::<Vec<_>>(), ["Invoice", "Travel plans"]);
+        assert_eq!(messages[0].source, "Maildir");
+        assert_eq!(messages[1].source, "Apple Mail");
+        assert_eq!(messages[1].length, message.len() as u64);
+
+        let exported = export_mail_message(messages[1].path.clone(), messages[1].offset, messages[1].length).unwrap();
+        assert_eq!(fs::read_to_string(exported).unwrap(), message);
+    }
+
+    #[test]
+    fn test_export_rejects_other_files() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("notes.txt");
+        fs::write(&file, "secret").unwrap();
+        assert!(export_mail_message(file.to_string_lossy().to_string(), 0, 6).is_err());
+    }
+
+    #[test]
+    fn test_matches_words() {
+        let message = parse_mbox(Cursor::new(MBOX), "Inbox").remove(0);
+        assert!(matches_words(&message, &["alice".to_string(), "report".to_string()]));
+        assert!(!matches_words(&message, &["alice".to_string(), "invoice".to_string()]));
+    }
+}
@@ -0,0 +1,295 @@
+// 命令手册：搜索本机的 man 手册和 tldr 页面，渲染为纯文本供预览，或在终端中打开
+use crate::http;
+use crate::system_command::command;
+use crate::terminal::open_in_terminal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+const MAX_RESULTS: usize = 20;
+const TLDR_PAGES_URL: &str = "https://raw.githubusercontent.com/tldr-pages/tldr/main/pages";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ManPageSource {
+    Man,
+    Tldr,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ManPageEntry {
+    pub name: String,
+    pub section: Option<String>,
+    pub summary: String,
+    pub source: ManPageSource,
+}
+
+// man 手册通过 man -k 搜索，tldr 页面在本机已下载的缓存中查找
+#[tauri::command(async)]
+pub fn search_man_pages(query: String) -> Vec<ManPageEntry> {
+    let query = query.trim().to_lowercase();
+    if !is_valid_page_name(&query) {
+        return vec![];
+    }
+
+    let mut entries = search_tldr(&query);
+    // Windows 没有 man
+    if let Ok(output) = command("man").args(["-k", &query]).output() {
+        let mut pages = parse_apropos(&String::from_utf8_lossy(&output.stdout));
+        pages.retain(|page| page.name.to_lowercase().contains(&query));
+        entries.extend(pages);
+    }
+
+    // 名称完全匹配的排在最前，其次是以查询开头的
+    entries.sort_by_key(|entry| {
+        let name = entry.name.to_lowercase();
+        (name != query, !name.starts_with(&query), name.len())
+    });
+    entries.truncate(MAX_RESULTS);
+    entries
+}
+
+#[tauri::command(async)]
+pub fn render_man_page(name: String, section: Option<String>) -> Result<String, String> {
+    let args = man_args(&name, section.as_deref())?;
+    let output = command("man")
+        .args(&args)
+        .env("MANPAGER", "cat")
+        .env("PAGER", "cat")
+        .env("MANWIDTH", "80")
+        .output()
+        .map_err(|e| format!("执行 man 失败: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("没有 {} 的手册页", name));
+    }
+    Ok(strip_overstrike(&String::from_utf8_lossy(&output.stdout)))
+}
+
+// 本机没有缓存时从 tldr-pages 仓库下载并缓存
+#[tauri::command]
+pub async fn render_tldr_page(name: String) -> Result<String, String> {
+    if !is_valid_page_name(&name) {
+        return Err(format!("无效的命令名: {}", name));
+    }
+
+    let markdown = match find_local_tldr(&name) {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("读取 tldr 页面失败: {}", e))?,
+        None => download_tldr(&name).await?,
+    };
+    Ok(render_tldr_markdown(&markdown))
+}
+
+#[tauri::command]
+pub fn open_man_page(name: String, section: Option<String>, terminal_command: Option<String>) -> Result<(), String> {
+    let mut args = vec!["man".to_string()];
+    args.extend(man_args(&name, section.as_deref())?);
+    open_in_terminal(&args.iter().map(String::as_str).collect::<Vec<_>>(), terminal_command.as_deref())
+}
+
+fn man_args(name: &str, section: Option<&str>) -> Result<Vec<String>, String> {
+    if !is_valid_page_name(name) {
+        return Err(format!("无效的手册页名称: {}", name));
+    }
+    let mut args = Vec::new();
+    if let Some(section) = section.filter(|section| !section.is_empty()) {
+        if !section.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(format!("无效的手册页章节: {}", section));
+        }
+        args.push(section.to_string());
+    }
+    args.push(name.to_string());
+    Ok(args)
+}
+
+// 命令名只允许常见字符，防止被当作 man 的选项或路径
+fn is_valid_page_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(['-', '.'])
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || "._+-:".contains(c))
+}
+
+// 兼容 man-db（"ls (1) - ..."）、macOS 和 mandoc（"ls(1), dir(1) - ..."）的输出
+fn parse_apropos(output: &str) -> Vec<ManPageEntry> {
+    output.lines()
+        .filter_map(|line| {
+            let (names, summary) = line.split_once(" - ")?;
+            let first = names.split(',').next()?.trim();
+            let (name, section) = first.split_once('(')?;
+            let section = section.trim_end_matches(')').trim();
+            Some(ManPageEntry {
+                name: name.trim().to_string(),
+                section: (!section.is_empty()).then(|| section.to_string()),
+                summary: summary.trim().to_string(),
+                source: ManPageSource::Man,
+            })
+        })
+        .collect()
+}
+
+// 去掉 nroff 用退格实现的粗体（X\bX）和下划线（_\bX）
+fn strip_overstrike(text: &str) -> String {
+    let mut result: Vec<char> = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        if c == '\u{8}' {
+            result.pop();
+        } else {
+            result.push(c);
+        }
+    }
+    result.into_iter().collect()
+}
+
+fn tldr_platforms() -> [&'static str; 2] {
+    let platform = match std::env::consts::OS {
+        "macos" => "osx",
+        "windows" => "windows",
+        _ => "linux",
+    };
+    [platform, "common"]
+}
+
+// 常见 tldr 客户端的缓存目录，以及本应用自己的下载目录
+fn tldr_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    if let Some(cache) = dirs::cache_dir() {
+        roots.push(cache.join("launch-rs/tldr/pages"));
+        roots.push(cache.join("tealdeer/tldr-pages/pages"));
+        roots.push(cache.join("tlrc/pages.en"));
+    }
+    if let Some(home) = dirs::home_dir() {
+        roots.push(home.join(".tldr/cache/pages"));
+    }
+    roots
+}
+
+fn find_local_tldr(name: &str) -> Option<PathBuf> {
+    let file = format!("{}.md", name);
+    tldr_roots().into_iter()
+        .flat_map(|root| tldr_platforms().map(|platform| root.join(platform).join(&file)))
+        .find(|path| path.is_file())
+}
+
+fn search_tldr(query: &str) -> Vec<ManPageEntry> {
+    let mut seen = HashSet::new();
+    let mut entries = Vec::new();
+    for root in tldr_roots() {
+        for platform in tldr_platforms() {
+            let Ok(dir) = fs::read_dir(root.join(platform)) else {
+                continue;
+            };
+            for entry in dir.flatten() {
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                let Some(name) = file_name.strip_suffix(".md") else {
+                    continue;
+                };
+                if name.contains(query) && seen.insert(name.to_string()) {
+                    entries.push(ManPageEntry {
+                        name: name.to_string(),
+                        section: None,
+                        summary: format!("tldr · {}", platform),
+                        source: ManPageSource::Tldr,
+                    });
+                }
+            }
+        }
+    }
+    entries
+}
+
+async fn download_tldr(name: &str) -> Result<String, String> {
+    let client = http::client()?;
+
+    for platform in tldr_platforms() {
+        let url = format!("{}/{}/{}.md", TLDR_PAGES_URL, platform, name);
+        let response = client.get(&url).send().await.map_err(|e| format!("下载 tldr 页面失败: {}", e))?;
+        if !response.status().is_success() {
+            continue;
+        }
+        let markdown = response.text().await.map_err(|e| format!("读取 tldr 页面失败: {}", e))?;
+
+        // 缓存失败不影响显示
+        if let Some(cache) = dirs::cache_dir() {
+            let dir = cache.join("launch-rs/tldr/pages").join(platform);
+            if fs::create_dir_all(&dir).is_ok() {
+                let _ = fs::write(dir.join(format!("{}.md", name)), &markdown);
+            }
+        }
+        return Ok(markdown);
+    }
+    Err(format!("tldr 中没有 {} 的页面", name))
+}
+
+// 把 tldr 的 Markdown 转为纯文本：示例命令缩进显示，去掉 {{占位符}} 的括号
+fn render_tldr_markdown(markdown: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for line in markdown.lines() {
+        let line = line.trim_end();
+        let rendered = if let Some(title) = line.strip_prefix("# ") {
+            title.to_string()
+        } else if let Some(description) = line.strip_prefix("> ") {
+            description.replace(['<', '>'], "")
+        } else if let Some(code) = line.strip_prefix('`').and_then(|line| line.strip_suffix('`')) {
+            format!("    {}", code.replace("{{", "").replace("}}", ""))
+        } else {
+            line.to_string()
+        };
+
+        // 合并连续的空行
+        if rendered.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        // 标题和描述之间、每条示例之前空一行
+        if (line.starts_with("> ") && lines.len() == 1) || (line.starts_with("- ") && lines.last().is_some_and(|last| !last.is_empty())) {
+            lines.push(String::new());
+        }
+        lines.push(rendered);
+    }
+    lines.join("\n").trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_apropos() {
+        let output = "ls (1)               - list directory contents\n\
+                      git-add(1), git add(1) - Add file contents to the index\n\
+                      printf(3) - formatted output conversion\n\
+                      garbage line\n";
+        let pages = parse_apropos(output);
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[0], ManPageEntry {
+            name: "ls".into(),
+            section: Some("1".into()),
+            summary: "list directory contents".into(),
+            source: ManPageSource::Man,
+        });
+        assert_eq!(pages[1].name, "git-add");
+        assert_eq!(pages[2].section.as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn test_strip_overstrike() {
+        assert_eq!(strip_overstrike("N\u{8}NA\u{8}AM\u{8}ME\u{8}E _\u{8}l_\u{8}s"), "NAME ls");
+    }
+
+    #[test]
+    fn test_render_tldr_markdown() {
+        let markdown = "# tar\n\n> Archiving utility.\n> More information: <https://www.gnu.org/software/tar>.\n\n- Create an archive:\n\n`tar cf {{path/to/target.tar}} {{file1}}`\n\n- Extract an archive:\n\n`tar xf {{source.tar}}`\n";
+        assert_eq!(
+            render_tldr_markdown(markdown),
+            "tar\n\nArchiving utility.\nMore information: https://www.gnu.org/software/tar.\n\n- Create an archive:\n\n    tar cf path/to/target.tar file1\n\n- Extract an archive:\n\n    tar xf source.tar"
+        );
+    }
+
+    #[test]
+    fn test_man_args() {
+        assert_eq!(man_args("printf", Some("3")).unwrap(), vec!["3", "printf"]);
+        assert_eq!(man_args("git-add", None).unwrap(), vec!["git-add"]);
+        assert!(man_args("-P", None).is_err());
+        assert!(man_args("ls", Some("1;rm")).is_err());
+        assert!(!is_valid_page_name("../etc/passwd"));
+    }
+}
@@ -0,0 +1,254 @@
+// 媒体播放控制：播放/暂停、下一首、上一首，以及当前播放的曲目（MPRIS / 媒体键 / SMTC）
+use crate::system_command::{powershell, run, WINRT_AWAIT};
+use serde::{Deserialize, Serialize};
+
+// 媒体操作
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaAction {
+    PlayPause,
+    Next,
+    Previous,
+}
+
+impl MediaAction {
+    // 可绑定为全局快捷键的媒体操作，ID 与前端 shortcuts.ts 中一致
+    pub(crate) fn from_shortcut_id(shortcut_id: &str) -> Option<MediaAction> {
+        match shortcut_id {
+            "mediaPlayPause" => Some(MediaAction::PlayPause),
+            "mediaNext" => Some(MediaAction::Next),
+            "mediaPrevious" => Some(MediaAction::Previous),
+            _ => None,
+        }
+    }
+
+    fn playerctl_command(self) -> &'static str {
+        match self {
+            MediaAction::PlayPause => "play-pause",
+            MediaAction::Next => "next",
+            MediaAction::Previous => "previous",
+        }
+    }
+
+    // macOS NX_KEYTYPE_PLAY / NX_KEYTYPE_NEXT / NX_KEYTYPE_PREVIOUS
+    fn mac_key_type(self) -> u32 {
+        match self {
+            MediaAction::PlayPause => 16,
+            MediaAction::Next => 17,
+            MediaAction::Previous => 18,
+        }
+    }
+
+    // Windows VK_MEDIA_PLAY_PAUSE / VK_MEDIA_NEXT_TRACK / VK_MEDIA_PREV_TRACK
+    fn windows_virtual_key(self) -> u32 {
+        match self {
+            MediaAction::PlayPause => 179,
+            MediaAction::Next => 176,
+            MediaAction::Previous => 177,
+        }
+    }
+}
+
+#[tauri::command(async)]
+pub fn media_control(action: MediaAction) -> Result<(), String> {
+    send(action)
+}
+
+pub(crate) fn send(action: MediaAction) -> Result<(), String> {
+    match std::env::consts::OS {
+        // playerctl 通过 MPRIS 控制最近活动的播放器
+        "linux" => {
+            run("playerctl", &[action.playerctl_command()])
+                .map_err(|e| format!("{}（Linux 需要先安装 playerctl）", e))?;
+        }
+        // 模拟系统媒体键，由系统分发给当前的播放会话，需要辅助功能权限
+        "macos" => {
+            run("osascript", &["-l", "JavaScript", "-e", &mac_media_key_script(action.mac_key_type())])?;
+        }
+        // 媒体键由系统媒体传输控制 (SMTC) 分发给当前的播放会话
+        "windows" => {
+            let script = format!("(New-Object -ComObject WScript.Shell).SendKeys([char]{})", action.windows_virtual_key());
+            powershell(&script)?;
+        }
+        os => return Err(format!("当前系统不支持媒体控制: {}", os)),
+    }
+
+    Ok(())
+}
+
+// 当前播放的曲目
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NowPlaying {
+    pub title: String,
+    pub artist: String,
+    pub album: String,
+    pub artwork_url: Option<String>,
+    pub player: String,
+    pub playing: bool,
+    pub spotify_track_id: Option<String>,
+}
+
+// 各平台脚本统一输出一行：播放器\t状态\t标题\t艺术家\t专辑\t封面\t曲目 ID
+const PLAYERCTL_FORMAT: &str = "{{playerName}}\t{{status}}\t{{title}}\t{{artist}}\t{{album}}\t{{mpris:artUrl}}\t{{mpris:trackid}}";
+
+const MAC_SPOTIFY_SCRIPT: &str = r#"if application "Spotify" is running then
+    tell application "Spotify"
+        if player state is not stopped then
+            set t to current track
+            return "Spotify" & tab & (player state as text) & tab & (name of t) & tab & (artist of t) & tab & (album of t) & tab & (artwork url of t) & tab & (spotify url of t)
+        end if
+    end tell
+end if
+return """#;
+
+const MAC_MUSIC_SCRIPT: &str = r#"if application "Music" is running then
+    tell application "Music"
+        if player state is not stopped then
+            set t to current track
+            return "Music" & tab & (player state as text) & tab & (name of t) & tab & (artist of t) & tab & (album of t) & tab & "" & tab & ""
+        end if
+    end tell
+end if
+return """#;
+
+// 通过 WinRT 读取系统媒体传输控制 (SMTC) 的当前会话
+const WINDOWS_SMTC_SCRIPT: &str = r#"[Windows.Media.Control.GlobalSystemMediaTransportControlsSessionManager, Windows.Media.Control, ContentType = WindowsRuntime] | Out-Null
+$manager = Await ([Windows.Media.Control.GlobalSystemMediaTransportControlsSessionManager]::RequestAsync()) ([Windows.Media.Control.GlobalSystemMediaTransportControlsSessionManager])
+$session = $manager.GetCurrentSession()
+if ($session) {
+    $props = Await ($session.TryGetMediaPropertiesAsync()) ([Windows.Media.Control.GlobalSystemMediaTransportControlsSessionMediaProperties])
+    $status = $session.GetPlaybackInfo().PlaybackStatus
+    "$($session.SourceAppUserModelId)`t$status`t$($props.Title)`t$($props.Artist)`t$($props.AlbumTitle)`t`t"
+}"#;
+
+#[tauri::command(async)]
+pub fn now_playing() -> Result<Option<NowPlaying>, String> {
+    match std::env::consts::OS {
+        "linux" => match run("playerctl", &["metadata", "--format", PLAYERCTL_FORMAT]) {
+            Ok(output) => Ok(parse_now_playing(&output)),
+            // 没有播放器时 playerctl 返回错误
+            Err(e) if e.contains("No players found") => Ok(None),
+            Err(e) => Err(format!("{}（Linux 需要先安装 playerctl）", e)),
+        },
+        "macos" => {
+            // 分开执行，未安装 Spotify 时脚本无法编译
+            for script in [MAC_SPOTIFY_SCRIPT, MAC_MUSIC_SCRIPT] {
+                if let Some(track) = run("osascript", &["-e", script]).ok().and_then(|output| parse_now_playing(&output)) {
+                    return Ok(Some(track));
+                }
+            }
+            Ok(None)
+        }
+        "windows" => Ok(parse_now_playing(&powershell(&format!("{}\n{}", WINRT_AWAIT, WINDOWS_SMTC_SCRIPT))?)),
+        os => Err(format!("当前系统不支持媒体控制: {}", os)),
+    }
+}
+
+fn parse_now_playing(output: &str) -> Option<NowPlaying> {
+    let line = output.lines().find(|line| !line.trim().is_empty())?;
+    let mut fields = line.split('\t').map(str::trim);
+    let mut next = || fields.next().unwrap_or_default().to_string();
+
+    let player = next();
+    let status = next();
+    let title = next();
+    let artist = next();
+    let album = next();
+    let artwork_url = next();
+    let track_id = next();
+
+    if title.is_empty() {
+        return None;
+    }
+
+    Some(NowPlaying {
+        title,
+        artist,
+        album,
+        // 界面只能加载 https 图片，本地封面 (file://) 忽略
+        artwork_url: artwork_url.starts_with("https://").then_some(artwork_url),
+        player,
+        playing: status.eq_ignore_ascii_case("playing"),
+        spotify_track_id: parse_spotify_track_id(&track_id),
+    })
+}
+
+// "/com/spotify/track/<id>" (MPRIS) 或 "spotify:track:<id>" (AppleScript)
+pub(crate) fn parse_spotify_track_id(value: &str) -> Option<String> {
+    let id = value.strip_prefix("/com/spotify/track/")
+        .or_else(|| value.strip_prefix("spotify:track:"))?;
+    (id.len() == 22 && id.chars().all(|c| c.is_ascii_alphanumeric())).then(|| id.to_string())
+}
+
+// 通过 JXA 发送 NSSystemDefined 媒体键按下和抬起事件
+fn mac_media_key_script(key_type: u32) -> String {
+    format!(
+        "ObjC.import('Cocoa');\n\
+         function post(down) {{\n\
+         \x20 var flags = down ? 0xa00 : 0xb00;\n\
+         \x20 var event = $.NSEvent.otherEventWithTypeLocationModifierFlagsTimestampWindowNumberContextSubtypeData1Data2(14, $.NSMakePoint(0, 0), flags, 0, 0, null, 8, ({} << 16) | flags, -1);\n\
+         \x20 $.CGEventPost(0, event.CGEvent);\n\
+         }}\n\
+         post(true);\n\
+         post(false);",
+        key_type
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_shortcut_id() {
+        assert_eq!(MediaAction::from_shortcut_id("mediaPlayPause"), Some(MediaAction::PlayPause));
+        assert_eq!(MediaAction::from_shortcut_id("mediaPrevious"), Some(MediaAction::Previous));
+        assert_eq!(MediaAction::from_shortcut_id("toggleSearch"), None);
+    }
+
+    #[test]
+    fn test_action_deserialize() {
+        assert_eq!(serde_json::from_str::<MediaAction>("\"play_pause\"").unwrap(), MediaAction::PlayPause);
+        assert!(serde_json::from_str::<MediaAction>("\"stop\"").is_err());
+    }
+
+    #[test]
+    fn test_parse_now_playing() {
+        let output = "spotify\tPlaying\tBohemian Rhapsody\tQueen\tA Night at the Opera\thttps://i.scdn.co/image/ab67616d0000b273\t/com/spotify/track/4u7EnebtmKWzUH433cf5Qv\n";
+        assert_eq!(parse_now_playing(output), Some(NowPlaying {
+            title: "Bohemian Rhapsody".into(),
+            artist: "Queen".into(),
+            album: "A Night at the Opera".into(),
+            artwork_url: Some("https://i.scdn.co/image/ab67616d0000b273".into()),
+            player: "spotify".into(),
+            playing: true,
+            spotify_track_id: Some("4u7EnebtmKWzUH433cf5Qv".into()),
+        }));
+
+        // 本地播放器：file:// 封面被忽略，没有 Spotify ID
+        let track = parse_now_playing("vlc\tPaused\tdemo.mp3\t\t\tfile:///tmp/cover.png\t/org/videolan/vlc/playlist/3").unwrap();
+        assert!(!track.playing);
+        assert_eq!(track.artwork_url, None);
+        assert_eq!(track.spotify_track_id, None);
+
+        // SMTC 只输出前几列
+        assert_eq!(parse_now_playing("Spotify.exe\tPlaying\tSong\tArtist\tAlbum\t\t").unwrap().title, "Song");
+
+        assert_eq!(parse_now_playing(""), None);
+        assert_eq!(parse_now_playing("Music\tpaused\t\t\t\t\t"), None);
+    }
+
+    #[test]
+    fn test_parse_spotify_track_id() {
+        assert_eq!(parse_spotify_track_id("spotify:track:4u7EnebtmKWzUH433cf5Qv").as_deref(), Some("4u7EnebtmKWzUH433cf5Qv"));
+        assert_eq!(parse_spotify_track_id("spotify:episode:4u7EnebtmKWzUH433cf5Qv"), None);
+        assert_eq!(parse_spotify_track_id("/com/spotify/track/../../me"), None);
+    }
+
+    #[test]
+    fn test_mac_media_key_script() {
+        let script = mac_media_key_script(MediaAction::Next.mac_key_type());
+        assert!(script.contains("(17 << 16) | flags"));
+        assert!(script.contains("post(true);\npost(false);"));
+    }
+}
@@ -0,0 +1,703 @@
+// 网络共享：把 SMB/NFS 共享添加为搜索位置。共享在后台线程中挂载并索引，文件列表缓存在数据库中，
+// 搜索只查缓存，共享离线时仍能搜到上次的结果而不会卡住搜索框。密码保存在系统钥匙串中
+use crate::db;
+use crate::keyring;
+use crate::system_command::{powershell, powershell_with_input, run, run_with_input};
+use chrono::Local;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, LazyLock, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, UNIX_EPOCH};
+
+// 探测主机端口、挂载共享的超时时间，离线的共享应尽快失败
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+const MOUNT_TIMEOUT: Duration = Duration::from_secs(20);
+// 单次索引的时间和数量上限，超过后只保存已经遍历到的部分
+const INDEX_DEADLINE: Duration = Duration::from_secs(120);
+const MAX_ENTRIES: usize = 20_000;
+const MAX_DEPTH: usize = 4;
+// 成功索引后的刷新间隔，以及失败（离线）后的重试间隔
+const REFRESH_SECS: i64 = 15 * 60;
+const RETRY_SECS: i64 = 2 * 60;
+const DEFAULT_LIMIT: usize = 30;
+const MAX_LIMIT: usize = 100;
+
+// 正在索引的共享及开始时间；超过两倍索引时限仍未结束（例如读目录卡住）时允许重新开始
+static INDEXING: LazyLock<Mutex<HashMap<i64, Instant>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ShareProtocol {
+    Smb,
+    Nfs,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ShareStatus {
+    Pending,
+    Indexing,
+    Online,
+    Offline,
+}
+
+// entry_count 为缓存的文件数，last_error 为最近一次索引失败的原因
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NetworkShare {
+    pub id: i64,
+    pub url: String,
+    pub protocol: ShareProtocol,
+    pub username: Option<String>,
+    pub created_at: i64,
+    pub indexed_at: Option<i64>,
+    pub last_error: Option<String>,
+    pub entry_count: i64,
+    pub status: ShareStatus,
+}
+
+// path 为本机可以打开的路径（gvfs 挂载点、/Volumes 下的目录或 UNC 路径）
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ShareEntry {
+    pub share_id: i64,
+    pub share_url: String,
+    pub name: String,
+    pub path: String,
+    pub is_dir: bool,
+    pub size: u64,
+    pub modified: u64,
+}
+
+// 共享地址：SMB 的 share 为共享名，path 为共享内的子目录；NFS 的 share 为完整导出路径
+#[derive(Debug, Clone, PartialEq)]
+struct ShareUrl {
+    protocol: ShareProtocol,
+    host: String,
+    share: String,
+    path: String,
+    username: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct StoredShare {
+    id: i64,
+    url: String,
+    username: Option<String>,
+    created_at: i64,
+    checked_at: Option<i64>,
+    indexed_at: Option<i64>,
+    last_error: Option<String>,
+    entry_count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct IndexedEntry {
+    name: String,
+    path: String,
+    is_dir: bool,
+    size: u64,
+    modified: u64,
+}
+
+impl ShareUrl {
+    fn port(&self) -> u16 {
+        match self.protocol {
+            ShareProtocol::Smb => 445,
+            ShareProtocol::Nfs => 2049,
+        }
+    }
+
+    // 不含用户名，作为共享的唯一标识保存
+    fn uri(&self) -> String {
+        let scheme = match self.protocol {
+            ShareProtocol::Smb => "smb",
+            ShareProtocol::Nfs => "nfs",
+        };
+        let mut uri = format!("{}://{}/{}", scheme, self.host, self.share.trim_start_matches('/'));
+        if !self.path.is_empty() {
+            uri.push('/');
+            uri.push_str(&self.path);
+        }
+        uri
+    }
+
+    fn unc_root(&self) -> String {
+        format!("\\\\{}\\{}", self.host, self.share.trim_start_matches('/').replace('/', "\\"))
+    }
+}
+
+#[tauri::command]
+pub fn list_network_shares() -> Result<Vec<NetworkShare>, String> {
+    let shares = db::with_connection(list)?;
+    Ok(shares.into_iter().filter_map(|share| describe(share).ok()).collect())
+}
+
+// 添加后立即在后台索引；password 只写入钥匙串
+#[tauri::command(async)]
+pub fn add_network_share(url: String, username: Option<String>, password: Option<String>) -> Result<NetworkShare, String> {
+    let parsed = parse_share_url(&url)?;
+    let username = username
+        .map(|username| username.trim().to_string())
+        .filter(|username| !username.is_empty())
+        .or(parsed.username.clone());
+    if let Some(username) = &username {
+        validate_username(username)?;
+    }
+
+    let uri = parsed.uri();
+    if db::with_connection(|conn| find_by_url(conn, &uri))?.is_some() {
+        return Err(format!("共享已存在: {}", uri));
+    }
+    let id = db::with_connection(|conn| insert(conn, &uri, username.as_deref(), Local::now().timestamp()))?;
+
+    if let Some(password) = password.filter(|password| !password.is_empty()) {
+        if let Err(error) = keyring::set_secret(&credential_account(id), &password) {
+            db::with_connection(|conn| delete(conn, id))?;
+            return Err(error);
+        }
+    }
+
+    spawn_index(id);
+    let share = db::with_connection(|conn| get(conn, id))?.ok_or("添加共享失败")?;
+    describe(share)
+}
+
+#[tauri::command(async)]
+pub fn remove_network_share(id: i64) -> Result<(), String> {
+    db::with_connection(|conn| delete(conn, id))?;
+    // 没有保存密码或钥匙串工具不可用时忽略
+    let _ = keyring::delete_secret(&credential_account(id));
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn refresh_network_share(id: i64) -> Result<(), String> {
+    db::with_connection(|conn| get(conn, id))?.ok_or_else(|| format!("共享不存在: {}", id))?;
+    spawn_index(id);
+    Ok(())
+}
+
+// 只查询缓存，过期或从未索引的共享在后台刷新，结果在下次搜索时生效
+#[tauri::command(async)]
+pub fn search_network_shares(query: String, limit: Option<usize>) -> Result<Vec<ShareEntry>, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let now = Local::now().timestamp();
+    for share in db::with_connection(list)? {
+        if needs_index(&share, now) {
+            spawn_index(share.id);
+        }
+    }
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    db::with_connection(|conn| search(conn, query, limit))
+}
+
+fn describe(share: StoredShare) -> Result<NetworkShare, String> {
+    let protocol = parse_share_url(&share.url)?.protocol;
+    let status = if is_indexing(share.id) {
+        ShareStatus::Indexing
+    } else if share.last_error.is_some() {
+        ShareStatus::Offline
+    } else if share.indexed_at.is_some() {
+        ShareStatus::Online
+    } else {
+        ShareStatus::Pending
+    };
+
+    Ok(NetworkShare {
+        id: share.id,
+        url: share.url,
+        protocol,
+        username: share.username,
+        created_at: share.created_at,
+        indexed_at: share.indexed_at,
+        last_error: share.last_error,
+        entry_count: share.entry_count,
+        status,
+    })
+}
+
+fn credential_account(id: i64) -> String {
+    format!("share-{}", id)
+}
+
+// 支持 smb://[用户@]主机/共享[/子目录]、nfs://主机/导出路径，以及 \\主机\共享 形式的 UNC 路径
+fn parse_share_url(input: &str) -> Result<ShareUrl, String> {
+    let input = input.trim();
+    let invalid = || format!("无效的共享地址: {}（示例: smb://server/share）", input);
+    let lower = input.to_lowercase();
+
+    let (protocol, rest) = if lower.starts_with("smb://") || lower.starts_with("nfs://") {
+        let protocol = if lower.starts_with("smb") { ShareProtocol::Smb } else { ShareProtocol::Nfs };
+        (protocol, input[6..].to_string())
+    } else if input.starts_with("\\\\") || input.starts_with("//") {
+        (ShareProtocol::Smb, input[2..].replace('\\', "/"))
+    } else {
+        return Err(invalid());
+    };
+
+    let (authority, path) = rest.split_once('/').ok_or_else(invalid)?;
+    let (username, host) = match authority.rsplit_once('@') {
+        Some((username, host)) => (Some(username.to_string()), host),
+        None => (None, authority),
+    };
+    let valid_host = !host.is_empty() && host.chars().all(|c| c.is_ascii_alphanumeric() || "-._".contains(c));
+    if !valid_host {
+        return Err(invalid());
+    }
+    if let Some(username) = &username {
+        validate_username(username)?;
+    }
+
+    // 路径会拼进 AppleScript / PowerShell 脚本，拒绝引号、控制字符和上级目录
+    let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+    let valid_segments = segments.iter().all(|segment| {
+        *segment != ".." && !segment.chars().any(|c| c.is_control() || "\"'`\\".contains(c))
+    });
+    if segments.is_empty() || !valid_segments {
+        return Err(invalid());
+    }
+
+    let (share, path) = match protocol {
+        ShareProtocol::Smb => (segments[0].to_string(), segments[1..].join("/")),
+        ShareProtocol::Nfs => (format!("/{}", segments.join("/")), String::new()),
+    };
+    Ok(ShareUrl { protocol, host: host.to_lowercase(), share, path, username })
+}
+
+fn validate_username(username: &str) -> Result<(), String> {
+    let valid = !username.is_empty() && username.chars().all(|c| c.is_alphanumeric() || "-_.@\\ ".contains(c));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("无效的用户名: {}", username))
+    }
+}
+
+fn needs_index(share: &StoredShare, now: i64) -> bool {
+    match share.checked_at {
+        None => true,
+        Some(checked_at) if share.last_error.is_some() => now - checked_at >= RETRY_SECS,
+        Some(checked_at) => now - checked_at >= REFRESH_SECS,
+    }
+}
+
+fn is_indexing(id: i64) -> bool {
+    INDEXING.lock().unwrap().get(&id).is_some_and(|started| started.elapsed() < INDEX_DEADLINE * 2)
+}
+
+fn spawn_index(id: i64) {
+    {
+        let mut indexing = INDEXING.lock().unwrap();
+        if indexing.get(&id).is_some_and(|started| started.elapsed() < INDEX_DEADLINE * 2) {
+            return;
+        }
+        indexing.insert(id, Instant::now());
+    }
+
+    thread::spawn(move || {
+        let result = index_share(id);
+        let now = Local::now().timestamp();
+        let stored = db::with_connection(|conn| match &result {
+            Ok(entries) => store_entries(conn, id, entries, now),
+            Err(error) => record_failure(conn, id, error, now),
+        });
+        if let Err(error) = stored {
+            log::warn!("保存共享索引失败: {}", error);
+        }
+        INDEXING.lock().unwrap().remove(&id);
+    });
+}
+
+fn index_share(id: i64) -> Result<Vec<IndexedEntry>, String> {
+    let share = db::with_connection(|conn| get(conn, id))?.ok_or_else(|| format!("共享不存在: {}", id))?;
+    let url = parse_share_url(&share.url)?;
+    let password = match share.username {
+        Some(_) => keyring::get_secret(&credential_account(id))?,
+        None => None,
+    };
+
+    // 先探测端口，离线的主机在几秒内失败，不必等待挂载超时
+    let (host, port) = (url.host.clone(), url.port());
+    with_timeout(PROBE_TIMEOUT * 2, move || probe(&host, port))?;
+
+    let username = share.username.clone();
+    let root = with_timeout(MOUNT_TIMEOUT, move || mount(&url, username.as_deref(), password.as_deref()))?;
+    walk(&root, Instant::now() + INDEX_DEADLINE)
+}
+
+// 在独立线程中执行，超时后放弃等待（线程本身可能仍卡在系统调用中）
+fn with_timeout<T, F>(timeout: Duration, f: F) -> Result<T, String>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, String> + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(f());
+    });
+    receiver
+        .recv_timeout(timeout)
+        .unwrap_or_else(|_| Err(format!("操作超时（{} 秒）", timeout.as_secs())))
+}
+
+fn probe(host: &str, port: u16) -> Result<(), String> {
+    let addresses = (host, port).to_socket_addrs().map_err(|e| format!("无法解析主机 {}: {}", host, e))?;
+    for address in addresses {
+        if TcpStream::connect_timeout(&address, PROBE_TIMEOUT).is_ok() {
+            return Ok(());
+        }
+    }
+    Err(format!("无法连接到 {}:{}", host, port))
+}
+
+// 挂载共享并返回本机路径，已挂载时直接返回
+fn mount(url: &ShareUrl, username: Option<&str>, password: Option<&str>) -> Result<PathBuf, String> {
+    match std::env::consts::OS {
+        "linux" => {
+            let uri = url.uri();
+            if let Some(path) = gio_local_path(&uri) {
+                return Ok(path);
+            }
+            // gio mount 依次询问用户名、域和密码，空行表示使用默认值
+            let input = format!("{}\n\n{}\n", username.unwrap_or(""), password.unwrap_or(""));
+            run_with_input("gio", &["mount", &uri], &input)?;
+            gio_local_path(&uri).ok_or_else(|| format!("挂载后找不到共享的本地路径: {}", uri))
+        }
+        "macos" => {
+            let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"");
+            let mut uri = url.clone();
+            uri.path = String::new();
+            let credentials = match (username, password) {
+                (Some(username), Some(password)) => {
+                    format!(" as user name \"{}\" with password \"{}\"", escape(username), escape(password))
+                }
+                (Some(username), None) => format!(" as user name \"{}\"", escape(username)),
+                _ => String::new(),
+            };
+            // 脚本通过标准输入传给 osascript，密码不会出现在进程参数中
+            let script = format!("POSIX path of (mount volume \"{}\"{})", escape(&uri.uri()), credentials);
+            let root = run_with_input("osascript", &[], &script)?;
+            Ok(PathBuf::from(root.trim()).join(&url.path))
+        }
+        "windows" => {
+            let unc = url.unc_root();
+            if let Some(username) = username {
+                let script = format!(
+                    "$password = [Console]::In.ReadToEnd()\nnet use '{}' $password /user:'{}' /persistent:no | Out-Null\nif ($LASTEXITCODE -ne 0) {{ exit $LASTEXITCODE }}",
+                    unc.replace('\'', "''"),
+                    username.replace('\'', "''")
+                );
+                // 已经以相同身份连接时 net use 会报错，能访问即可
+                if let Err(error) = powershell_with_input(&script, password.unwrap_or("")) {
+                    if !Path::new(&unc).is_dir() {
+                        return Err(error);
+                    }
+                }
+            } else if !Path::new(&unc).is_dir() {
+                powershell(&format!("net use '{}' /persistent:no | Out-Null", unc.replace('\'', "''")))?;
+            }
+            Ok(PathBuf::from(unc).join(url.path.replace('/', "\\")))
+        }
+        os => Err(format!("当前系统不支持网络共享: {}", os)),
+    }
+}
+
+fn gio_local_path(uri: &str) -> Option<PathBuf> {
+    let output = run("gio", &["info", uri]).ok()?;
+    parse_gio_local_path(&output).filter(|path| path.is_dir())
+}
+
+// gio info 的输出中 "local path: ..." 一行为 gvfs 的 FUSE 挂载路径
+fn parse_gio_local_path(output: &str) -> Option<PathBuf> {
+    output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("local path:"))
+        .map(|path| PathBuf::from(path.trim()))
+}
+
+// 按层遍历，先收录浅层的文件；根目录无法读取时视为共享不可用
+fn walk(root: &Path, deadline: Instant) -> Result<Vec<IndexedEntry>, String> {
+    fs::read_dir(root).map_err(|e| format!("无法读取共享 {}: {}", root.display(), e))?;
+
+    let mut entries = Vec::new();
+    let mut queue = VecDeque::from([(root.to_path_buf(), 0)]);
+    while let Some((dir, depth)) = queue.pop_front() {
+        if entries.len() >= MAX_ENTRIES || Instant::now() >= deadline {
+            break;
+        }
+        let Ok(read_dir) = fs::read_dir(&dir) else { continue };
+
+        for entry in read_dir.flatten() {
+            if entries.len() >= MAX_ENTRIES {
+                break;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            // 跳过隐藏文件和系统文件
+            if name.starts_with('.') || name.starts_with('~') {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata() else { continue };
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs())
+                .unwrap_or(0);
+
+            let path = entry.path();
+            if metadata.is_dir() && depth < MAX_DEPTH {
+                queue.push_back((path.clone(), depth + 1));
+            }
+            entries.push(IndexedEntry {
+                name,
+                path: path.to_string_lossy().to_string(),
+                is_dir: metadata.is_dir(),
+                size: metadata.len(),
+                modified,
+            });
+        }
+    }
+    Ok(entries)
+}
+
+fn from_row(row: &Row) -> rusqlite::Result<StoredShare> {
+    Ok(StoredShare {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        username: row.get(2)?,
+        created_at: row.get(3)?,
+        checked_at: row.get(4)?,
+        indexed_at: row.get(5)?,
+        last_error: row.get(6)?,
+        entry_count: row.get(7)?,
+    })
+}
+
+const SELECT_SHARES: &str = "SELECT id, url, username, created_at, checked_at, indexed_at, last_error,
+    (SELECT COUNT(*) FROM network_share_entries WHERE share_id = network_shares.id)
+    FROM network_shares";
+
+fn list(conn: &Connection) -> rusqlite::Result<Vec<StoredShare>> {
+    let mut stmt = conn.prepare(&format!("{} ORDER BY url", SELECT_SHARES))?;
+    let shares = stmt.query_map([], from_row)?.collect();
+    shares
+}
+
+fn get(conn: &Connection, id: i64) -> rusqlite::Result<Option<StoredShare>> {
+    conn.query_row(&format!("{} WHERE id = ?1", SELECT_SHARES), params![id], from_row).optional()
+}
+
+fn find_by_url(conn: &Connection, url: &str) -> rusqlite::Result<Option<StoredShare>> {
+    conn.query_row(&format!("{} WHERE url = ?1", SELECT_SHARES), params![url], from_row).optional()
+}
+
+fn insert(conn: &Connection, url: &str, username: Option<&str>, now: i64) -> rusqlite::Result<i64> {
+    conn.execute(
+        "INSERT INTO network_shares (url, username, created_at) VALUES (?1, ?2, ?3)",
+        params![url, username, now],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+fn delete(conn: &Connection, id: i64) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM network_share_entries WHERE share_id = ?1", params![id])?;
+    conn.execute("DELETE FROM network_shares WHERE id = ?1", params![id])?;
+    Ok(())
+}
+
+// 整体替换该共享的缓存；共享在索引期间被删除时不再写入
+fn store_entries(conn: &Connection, id: i64, entries: &[IndexedEntry], now: i64) -> rusqlite::Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    let updated = tx.execute(
+        "UPDATE network_shares SET checked_at = ?2, indexed_at = ?2, last_error = NULL WHERE id = ?1",
+        params![id, now],
+    )?;
+    if updated == 0 {
+        return Ok(());
+    }
+
+    tx.execute("DELETE FROM network_share_entries WHERE share_id = ?1", params![id])?;
+    {
+        let mut stmt = tx.prepare(
+            "INSERT INTO network_share_entries (share_id, name, path, is_dir, size, modified) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )?;
+        for entry in entries {
+            stmt.execute(params![id, entry.name, entry.path, entry.is_dir, entry.size as i64, entry.modified as i64])?;
+        }
+    }
+    tx.commit()
+}
+
+// 失败时保留之前缓存的文件列表
+fn record_failure(conn: &Connection, id: i64, error: &str, now: i64) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE network_shares SET checked_at = ?2, last_error = ?3 WHERE id = ?1",
+        params![id, now, error],
+    )?;
+    Ok(())
+}
+
+// 完全匹配优先，其次是前缀匹配，再按名称长度排序
+fn search(conn: &Connection, query: &str, limit: usize) -> rusqlite::Result<Vec<ShareEntry>> {
+    let escaped = query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    let mut stmt = conn.prepare(
+        "SELECT e.share_id, s.url, e.name, e.path, e.is_dir, e.size, e.modified
+         FROM network_share_entries e JOIN network_shares s ON s.id = e.share_id
+         WHERE e.name LIKE ?1 ESCAPE '\\'
+         ORDER BY lower(e.name) = lower(?2) DESC, e.name LIKE ?3 ESCAPE '\\' DESC, length(e.name), e.name
+         LIMIT ?4",
+    )?;
+    let entries = stmt
+        .query_map(
+            params![format!("%{}%", escaped), query, format!("{}%", escaped), limit as i64],
+            |row| {
+                Ok(ShareEntry {
+                    share_id: row.get(0)?,
+                    share_url: row.get(1)?,
+                    name: row.get(2)?,
+                    path: row.get(3)?,
+                    is_dir: row.get(4)?,
+                    size: row.get::<_, i64>(5)? as u64,
+                    modified: row.get::<_, i64>(6)? as u64,
+                })
+            },
+        )?
+        .collect();
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn entry(name: &str, path: &str) -> IndexedEntry {
+        IndexedEntry { name: name.to_string(), path: path.to_string(), is_dir: false, size: 1, modified: 0 }
+    }
+
+    #[test]
+    fn test_parse_share_url() {
+        let url = parse_share_url("smb://alice@NAS.local/Media/Movies").unwrap();
+        assert_eq!(url.protocol, ShareProtocol::Smb);
+        assert_eq!((url.host.as_str(), url.share.as_str(), url.path.as_str()), ("nas.local", "Media", "Movies"));
+        assert_eq!(url.username.as_deref(), Some("alice"));
+        assert_eq!(url.uri(), "smb://nas.local/Media/Movies");
+        assert_eq!(url.unc_root(), "\\\\nas.local\\Media");
+
+        let url = parse_share_url("\\\\server\\public\\docs").unwrap();
+        assert_eq!(url.uri(), "smb://server/public/docs");
+
+        let url = parse_share_url("nfs://server/export/home").unwrap();
+        assert_eq!((url.share.as_str(), url.path.as_str()), ("/export/home", ""));
+        assert_eq!(url.uri(), "nfs://server/export/home");
+        assert_eq!(url.port(), 2049);
+
+        assert!(parse_share_url("server/share").is_err());
+        assert!(parse_share_url("smb://server").is_err());
+        assert!(parse_share_url("smb://server/").is_err());
+        assert!(parse_share_url("smb://server/share/../etc").is_err());
+        assert!(parse_share_url("smb://server/share\"; rm").is_err());
+        assert!(parse_share_url("smb://ser ver/share").is_err());
+    }
+
+    #[test]
+    fn test_needs_index() {
+        let mut share = StoredShare {
+            id: 1,
+            url: "smb://server/share".to_string(),
+            username: None,
+            created_at: NOW,
+            checked_at: None,
+            indexed_at: None,
+            last_error: None,
+            entry_count: 0,
+        };
+        assert!(needs_index(&share, NOW));
+
+        share.checked_at = Some(NOW);
+        assert!(!needs_index(&share, NOW + REFRESH_SECS - 1));
+        assert!(needs_index(&share, NOW + REFRESH_SECS));
+
+        // 离线的共享按较短的间隔重试
+        share.last_error = Some("无法连接".to_string());
+        assert!(!needs_index(&share, NOW + RETRY_SECS - 1));
+        assert!(needs_index(&share, NOW + RETRY_SECS));
+    }
+
+    #[test]
+    fn test_store_and_search_entries() {
+        let conn = db::open_in_memory();
+        let id = insert(&conn, "smb://server/share", None, NOW).unwrap();
+        store_entries(&conn, id, &[entry("report.pdf", "/a/report.pdf"), entry("old report.pdf", "/a/old report.pdf"), entry("report", "/a/report"), entry("50%_off.txt", "/a/50%_off.txt")], NOW).unwrap();
+
+        let names: Vec<String> = search(&conn, "REPORT", 10).unwrap().into_iter().map(|entry| entry.name).collect();
+        assert_eq!(names, ["report", "report.pdf", "old report.pdf"]);
+        assert_eq!(search(&conn, "%_", 10).unwrap().len(), 1);
+
+        // 索引失败时保留缓存，状态变为离线
+        record_failure(&conn, id, "无法连接到 server:445", NOW + 60).unwrap();
+        let share = describe(get(&conn, id).unwrap().unwrap()).unwrap();
+        assert_eq!((share.status, share.entry_count), (ShareStatus::Offline, 4));
+        assert_eq!(search(&conn, "report", 10).unwrap().len(), 3);
+
+        // 再次成功后替换缓存
+        store_entries(&conn, id, &[entry("new.txt", "/a/new.txt")], NOW + 120).unwrap();
+        let share = describe(get(&conn, id).unwrap().unwrap()).unwrap();
+        assert_eq!((share.status, share.entry_count, share.indexed_at), (ShareStatus::Online, 1, Some(NOW + 120)));
+
+        delete(&conn, id).unwrap();
+        assert!(search(&conn, "new", 10).unwrap().is_empty());
+        // 删除后完成的索引不再写入
+        store_entries(&conn, id, &[entry("late.txt", "/a/late.txt")], NOW).unwrap();
+        assert!(search(&conn, "late", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_walk() {
+        let dir = TempDir::new().unwrap();
+        let mut nested = dir.path().to_path_buf();
+        for level in 0..=MAX_DEPTH + 1 {
+            nested = nested.join(format!("level{}", level));
+        }
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.path().join("notes.txt"), "hello").unwrap();
+        fs::write(dir.path().join(".hidden"), "").unwrap();
+
+        let entries = walk(dir.path(), Instant::now() + INDEX_DEADLINE).unwrap();
+        let names: Vec<&str> = entries.iter().map(|entry| entry.name.as_str()).collect();
+        assert!(names.contains(&"notes.txt"));
+        assert!(!names.contains(&".hidden"));
+        assert!(names.contains(&format!("level{}", MAX_DEPTH).as_str()));
+        assert!(!names.contains(&format!("level{}", MAX_DEPTH + 1).as_str()));
+
+        assert!(walk(&dir.path().join("missing"), Instant::now() + INDEX_DEADLINE).is_err());
+    }
+
+    #[test]
+    fn test_parse_gio_local_path() {
+        let output = "display name: share on nas\nlocal path: /run/user/1000/gvfs/smb-share:server=nas,share=share\nuri: smb://nas/share/\n";
+        assert_eq!(parse_gio_local_path(output), Some(PathBuf::from("/run/user/1000/gvfs/smb-share:server=nas,share=share")));
+        assert_eq!(parse_gio_local_path("uri: smb://nas/share/\n"), None);
+    }
+
+    #[test]
+    fn test_with_timeout() {
+        assert_eq!(with_timeout(Duration::from_secs(1), || Ok(1)), Ok(1));
+        let result: Result<(), String> = with_timeout(Duration::from_millis(10), || {
+            thread::sleep(Duration::from_millis(200));
+            Ok(())
+        });
+        assert!(result.unwrap_err().contains("超时"));
+    }
+}
@@ -0,0 +1,355 @@
+// 网络诊断：DNS 记录查询（DNS over HTTPS）、RDAP 注册信息（whois）和 IP 地址归属查询
+use crate::http::{self, RateLimiter, TtlCache};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::net::IpAddr;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+const DOH_API: &str = "https://cloudflare-dns.com/dns-query";
+// rdap.org 会重定向到负责该域名或 IP 段的注册局
+const RDAP_API: &str = "https://rdap.org";
+const IPINFO_API: &str = "https://ipinfo.io";
+const DNS_RECORD_TYPES: &[(&str, u16)] = &[("A", 1), ("AAAA", 28), ("CNAME", 5), ("MX", 15), ("TXT", 16)];
+
+static CACHE: LazyLock<TtlCache<Vec<InfoRow>>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(5 * 60)));
+static LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(30, Duration::from_secs(60)));
+
+// 结果中的一行，value 可以直接复制
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InfoRow {
+    pub label: String,
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DohResponse {
+    #[serde(rename = "Status")]
+    status: u32,
+    #[serde(rename = "Answer", default)]
+    answer: Vec<DohAnswer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DohAnswer {
+    #[serde(rename = "type")]
+    record_type: u16,
+    #[serde(rename = "TTL")]
+    ttl: u64,
+    data: String,
+}
+
+// 依次查询 A、AAAA、CNAME、MX、TXT 记录，label 为记录类型和 TTL
+#[tauri::command]
+pub async fn dns_lookup(domain: String) -> Result<Vec<InfoRow>, String> {
+    let domain = normalize_domain(&domain)?;
+    CACHE.get_or_fetch_async(&format!("dns:{}", domain), || async {
+        let client = http::client()?;
+        let mut rows = Vec::new();
+        for (name, record_type) in DNS_RECORD_TYPES {
+            LIMITER.acquire("DNS")?;
+            let response = client.get(DOH_API)
+                .header(reqwest::header::ACCEPT, "application/dns-json")
+                .query(&[("name", domain.as_str()), ("type", name)])
+                .send()
+                .await
+                .map_err(|e| format!("DNS 查询失败: {}", e))?;
+            let body = http::check_status(response, "DNS").await?
+                .text()
+                .await
+                .map_err(|e| format!("读取 DNS 响应失败: {}", e))?;
+            rows.extend(parse_dns(&body, name, *record_type)?);
+        }
+        Ok(rows)
+    }).await
+}
+
+#[tauri::command]
+pub async fn whois_lookup(query: String) -> Result<Vec<InfoRow>, String> {
+    let query = query.trim();
+    let path = match query.parse::<IpAddr>() {
+        Ok(ip) => format!("ip/{}", ip),
+        Err(_) => format!("domain/{}", normalize_domain(query)?),
+    };
+    CACHE.get_or_fetch_async(&format!("rdap:{}", path), || async {
+        let body = get_json(&format!("{}/{}", RDAP_API, path), "RDAP").await?;
+        parse_rdap(&body)
+    }).await
+}
+
+// ip 为空时查询本机的公网 IP
+#[tauri::command]
+pub async fn ip_info(ip: Option<String>) -> Result<Vec<InfoRow>, String> {
+    let ip = ip.map(|ip| ip.trim().to_string()).filter(|ip| !ip.is_empty());
+    let url = match &ip {
+        Some(ip) => {
+            let ip: IpAddr = ip.parse().map_err(|_| format!("无效的 IP 地址: {}", ip))?;
+            format!("{}/{}/json", IPINFO_API, ip)
+        }
+        None => format!("{}/json", IPINFO_API),
+    };
+    // 本机公网 IP 可能变化，不缓存
+    let fetch = || async { parse_ipinfo(&get_json(&url, "ipinfo").await?) };
+    match ip {
+        Some(ip) => CACHE.get_or_fetch_async(&format!("ipinfo:{}", ip), fetch).await,
+        None => fetch().await,
+    }
+}
+
+async fn get_json(url: &str, service: &str) -> Result<String, String> {
+    LIMITER.acquire(service)?;
+    let response = http::client()?
+        .get(url)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .send()
+        .await
+        .map_err(|e| format!("请求 {} 失败: {}", service, e))?;
+    if response.status().as_u16() == 404 {
+        return Err(format!("{} 没有该记录", service));
+    }
+    http::check_status(response, service).await?
+        .text()
+        .await
+        .map_err(|e| format!("读取 {} 响应失败: {}", service, e))
+}
+
+// 去掉协议、路径和末尾的点，只接受合法的域名
+fn normalize_domain(input: &str) -> Result<String, String> {
+    let input = input.trim();
+    let without_scheme = input.split_once("://").map_or(input, |(_, rest)| rest);
+    let host = without_scheme.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+    let host = host.split(':').next().unwrap_or_default().trim_end_matches('.').to_lowercase();
+
+    let valid = host.contains('.')
+        && host.len() <= 253
+        && host.split('.').all(|label| {
+            !label.is_empty() && label.len() <= 63 && !label.starts_with('-') && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        });
+    if valid {
+        Ok(host)
+    } else {
+        Err(format!("无效的域名: {}", input))
+    }
+}
+
+// 只保留请求的记录类型（A 查询时也会返回 CNAME 链）
+fn parse_dns(body: &str, name: &str, record_type: u16) -> Result<Vec<InfoRow>, String> {
+    let response: DohResponse = serde_json::from_str(body).map_err(|e| format!("解析 DNS 响应失败: {}", e))?;
+    match response.status {
+        0 => {}
+        3 => return Err("域名不存在".to_string()),
+        status => return Err(format!("DNS 查询失败，错误码 {}", status)),
+    }
+
+    Ok(response.answer.into_iter()
+        .filter(|answer| answer.record_type == record_type)
+        .map(|answer| InfoRow {
+            label: format!("{} · TTL {}", name, answer.ttl),
+            value: if record_type == 16 { join_txt(&answer.data) } else { answer.data.trim_end_matches('.').to_string() },
+        })
+        .collect())
+}
+
+// TXT 记录由多个带引号的字符串组成，例如 "v=spf1 " "-all"
+fn join_txt(data: &str) -> String {
+    let data = data.trim();
+    if !data.starts_with('"') {
+        return data.to_string();
+    }
+    let mut value = String::new();
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for c in data.chars() {
+        match c {
+            _ if escaped => {
+                value.push(c);
+                escaped = false;
+            }
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            _ if in_quotes => value.push(c),
+            _ => {}
+        }
+    }
+    value
+}
+
+fn parse_rdap(body: &str) -> Result<Vec<InfoRow>, String> {
+    let value: Value = serde_json::from_str(body).map_err(|e| format!("解析 RDAP 响应失败: {}", e))?;
+    let text = |key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+    let mut rows = Vec::new();
+    let mut push = |label: &str, value: Option<String>| {
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            rows.push(InfoRow { label: label.to_string(), value });
+        }
+    };
+
+    push("域名", text("ldhName").map(|name| name.to_lowercase()));
+    // IP 段
+    push("网段名称", text("name"));
+    if let (Some(start), Some(end)) = (text("startAddress"), text("endAddress")) {
+        push("地址范围", Some(format!("{} - {}", start, end)));
+    }
+    push("国家", text("country"));
+
+    for entity in value.get("entities").and_then(Value::as_array).into_iter().flatten() {
+        let roles: Vec<&str> = entity.get("roles").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str).collect();
+        let label = if roles.contains(&"registrar") {
+            "注册商"
+        } else if roles.contains(&"registrant") {
+            "注册人"
+        } else {
+            continue;
+        };
+        push(label, vcard_name(entity));
+    }
+
+    for event in value.get("events").and_then(Value::as_array).into_iter().flatten() {
+        let date = event.get("eventDate").and_then(Value::as_str).map(|date| date.chars().take(10).collect());
+        match event.get("eventAction").and_then(Value::as_str) {
+            Some("registration") => push("注册时间", date),
+            Some("expiration") => push("到期时间", date),
+            Some("last changed") => push("更新时间", date),
+            _ => {}
+        }
+    }
+
+    let nameservers: Vec<String> = value.get("nameservers").and_then(Value::as_array).into_iter().flatten()
+        .filter_map(|server| server.get("ldhName").and_then(Value::as_str))
+        .map(str::to_lowercase)
+        .collect();
+    push("域名服务器", Some(nameservers.join(", ")));
+    let status: Vec<&str> = value.get("status").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str).collect();
+    push("状态", Some(status.join(", ")));
+
+    if rows.is_empty() {
+        return Err("RDAP 响应中没有注册信息".to_string());
+    }
+    Ok(rows)
+}
+
+// vcardArray: ["vcard", [["fn", {}, "text", "名称"], ...]]
+fn vcard_name(entity: &Value) -> Option<String> {
+    entity.get("vcardArray")?.get(1)?.as_array()?
+        .iter()
+        .find(|property| property.get(0).and_then(Value::as_str) == Some("fn"))?
+        .get(3)?
+        .as_str()
+        .map(str::to_string)
+}
+
+fn parse_ipinfo(body: &str) -> Result<Vec<InfoRow>, String> {
+    let value: Value = serde_json::from_str(body).map_err(|e| format!("解析 ipinfo 响应失败: {}", e))?;
+    let text = |key: &str| value.get(key).and_then(Value::as_str).map(str::to_string).filter(|value| !value.is_empty());
+    let ip = text("ip").ok_or_else(|| "ipinfo 响应中没有 IP 地址".to_string())?;
+
+    let mut rows = vec![InfoRow { label: "IP 地址".to_string(), value: ip }];
+    if value.get("bogon").and_then(Value::as_bool) == Some(true) {
+        rows.push(InfoRow { label: "类型".to_string(), value: "私有或保留地址".to_string() });
+        return Ok(rows);
+    }
+
+    let location: Vec<String> = ["city", "region", "country"].iter().filter_map(|key| text(key)).collect();
+    for (label, value) in [
+        ("主机名", text("hostname")),
+        ("位置", Some(location.join(", "))),
+        ("坐标", text("loc")),
+        ("ASN / 组织", text("org")),
+        ("时区", text("timezone")),
+    ] {
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            rows.push(InfoRow { label: label.to_string(), value });
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_domain() {
+        assert_eq!(normalize_domain(" Example.COM. ").unwrap(), "example.com");
+        assert_eq!(normalize_domain("https://user@www.example.com:8080/path?q=1").unwrap(), "www.example.com");
+        assert_eq!(normalize_domain("_dmarc.example.com").unwrap(), "_dmarc.example.com");
+        assert!(normalize_domain("localhost").is_err());
+        assert!(normalize_domain("exa mple.com").is_err());
+        assert!(normalize_domain("-bad.com").is_err());
+    }
+
+    #[test]
+    fn test_parse_dns() {
+        let body = r#"{"Status": 0, "Answer": [
+            {"name": "www.example.com.", "type": 5, "TTL": 300, "data": "example.com."},
+            {"name": "example.com.", "type": 1, "TTL": 120, "data": "93.184.216.34"}
+        ]}"#;
+        assert_eq!(parse_dns(body, "A", 1).unwrap(), vec![InfoRow { label: "A · TTL 120".to_string(), value: "93.184.216.34".to_string() }]);
+        assert_eq!(parse_dns(body, "CNAME", 5).unwrap()[0].value, "example.com");
+
+        let body = r#"{"Status": 0, "Answer": [{"name": "example.com.", "type": 16, "TTL": 60, "data": "\"v=spf1 \" \"-all\""}]}"#;
+        assert_eq!(parse_dns(body, "TXT", 16).unwrap()[0].value, "v=spf1 -all");
+
+        assert!(parse_dns(r#"{"Status": 3}"#, "A", 1).unwrap_err().contains("不存在"));
+        assert!(parse_dns(r#"{"Status": 0}"#, "MX", 15).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_join_txt() {
+        assert_eq!(join_txt(r#""a \"quoted\" value""#), r#"a "quoted" value"#);
+        assert_eq!(join_txt("unquoted"), "unquoted");
+    }
+
+    #[test]
+    fn test_parse_rdap_domain() {
+        let body = r#"{
+            "objectClassName": "domain", "ldhName": "EXAMPLE.COM",
+            "status": ["client delete prohibited", "client transfer prohibited"],
+            "events": [
+                {"eventAction": "registration", "eventDate": "1995-08-14T04:00:00Z"},
+                {"eventAction": "expiration", "eventDate": "2025-08-13T04:00:00Z"},
+                {"eventAction": "last update of RDAP database", "eventDate": "2024-06-01T00:00:00Z"}
+            ],
+            "entities": [
+                {"roles": ["registrar"], "vcardArray": ["vcard", [["version", {}, "text", "4.0"], ["fn", {}, "text", "RESERVED-IANA"]]]},
+                {"roles": ["abuse"], "vcardArray": ["vcard", [["fn", {}, "text", "Abuse"]]]}
+            ],
+            "nameservers": [{"ldhName": "A.IANA-SERVERS.NET"}, {"ldhName": "B.IANA-SERVERS.NET"}]
+        }"#;
+        let rows = parse_rdap(body).unwrap();
+        let get = |label: &str| rows.iter().find(|row| row.label == label).map(|row| row.value.as_str());
+        assert_eq!(get("域名"), Some("example.com"));
+        assert_eq!(get("注册商"), Some("RESERVED-IANA"));
+        assert_eq!(get("注册时间"), Some("1995-08-14"));
+        assert_eq!(get("到期时间"), Some("2025-08-13"));
+        assert_eq!(get("更新时间"), None);
+        assert_eq!(get("域名服务器"), Some("a.iana-servers.net, b.iana-servers.net"));
+        assert_eq!(get("注册人"), None);
+    }
+
+    #[test]
+    fn test_parse_rdap_ip() {
+        let body = r#"{"objectClassName": "ip network", "handle": "NET-8-8-8-0-2", "name": "GOGL",
+            "startAddress": "8.8.8.0", "endAddress": "8.8.8.255", "country": "US"}"#;
+        let rows = parse_rdap(body).unwrap();
+        assert_eq!(rows[0], InfoRow { label: "网段名称".to_string(), value: "GOGL".to_string() });
+        assert_eq!(rows[1].value, "8.8.8.0 - 8.8.8.255");
+        assert!(parse_rdap("{}").is_err());
+    }
+
+    #[test]
+    fn test_parse_ipinfo() {
+        let body = r#"{"ip": "8.8.8.8", "hostname": "dns.google", "city": "Mountain View", "region": "California",
+            "country": "US", "loc": "37.4056,-122.0775", "org": "AS15169 Google LLC", "timezone": "America/Los_Angeles", "anycast": true}"#;
+        let rows = parse_ipinfo(body).unwrap();
+        assert_eq!(rows.len(), 6);
+        assert_eq!(rows[2].value, "Mountain View, California, US");
+        assert_eq!(rows[4].value, "AS15169 Google LLC");
+
+        let rows = parse_ipinfo(r#"{"ip": "192.168.1.1", "bogon": true}"#).unwrap();
+        assert_eq!(rows[1].value, "私有或保留地址");
+        assert!(parse_ipinfo(r#"{"error": {"title": "Wrong ip"}}"#).is_err());
+    }
+}
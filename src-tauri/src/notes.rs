@@ -0,0 +1,343 @@
+// 笔记搜索：索引 Markdown 文件夹（Obsidian 仓库）的标题、小标题和正文
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+// 索引的有效期，过期后重新扫描
+const INDEX_TTL: Duration = Duration::from_secs(60);
+const MAX_NOTES_PER_VAULT: usize = 20_000;
+// 单个文件只索引前 256KB
+const MAX_NOTE_BYTES: usize = 256 * 1024;
+const MAX_RESULTS: usize = 30;
+const SNIPPET_CONTEXT: usize = 40;
+
+// 跳过的目录
+const IGNORED_DIRS: &[&str] = &[".obsidian", ".git", ".trash", "node_modules"];
+
+// 搜索结果
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NoteMatch {
+    pub title: String,
+    pub path: String,
+    pub vault: String,
+    pub heading: Option<String>,
+    pub snippet: Option<String>,
+    pub obsidian_uri: Option<String>,
+    pub score: u32,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedNote {
+    title: String,
+    path: PathBuf,
+    relative_path: String,
+    headings: Vec<String>,
+    content: String,
+}
+
+struct VaultIndex {
+    name: String,
+    is_obsidian: bool,
+    notes: Vec<IndexedNote>,
+    built_at: Instant,
+}
+
+static INDEXES: LazyLock<Mutex<HashMap<PathBuf, VaultIndex>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[tauri::command(async)]
+pub fn search_notes(query: String, vaults: Vec<String>) -> Result<Vec<NoteMatch>, String> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut indexes = INDEXES.lock().unwrap();
+    let mut matches = Vec::new();
+
+    for vault in vaults.iter().map(|vault| vault.trim()).filter(|vault| !vault.is_empty()) {
+        let root = match (vault.strip_prefix("~/"), dirs::home_dir()) {
+            (Some(rest), Some(home)) => home.join(rest),
+            _ => PathBuf::from(vault),
+        };
+        if !root.is_dir() {
+            return Err(format!("笔记目录不存在: {}", vault));
+        }
+
+        let stale = indexes.get(&root).is_none_or(|index| index.built_at.elapsed() > INDEX_TTL);
+        if stale {
+            indexes.insert(root.clone(), build_index(&root));
+        }
+        if let Some(index) = indexes.get(&root) {
+            matches.extend(search_index(index, &query));
+        }
+    }
+
+    matches.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.title.cmp(&b.title)));
+    matches.truncate(MAX_RESULTS);
+    Ok(matches)
+}
+
+fn build_index(root: &Path) -> VaultIndex {
+    let mut files = Vec::new();
+    collect_markdown_files(root, &mut files);
+
+    let notes = files.into_iter()
+        .filter_map(|path| {
+            let bytes = fs::read(&path).ok()?;
+            let text = String::from_utf8_lossy(&bytes[..bytes.len().min(MAX_NOTE_BYTES)]).to_string();
+            let relative_path = path.strip_prefix(root).ok()?.to_string_lossy().replace('\\', "/");
+            Some(index_note(path, relative_path, &text))
+        })
+        .collect();
+
+    VaultIndex {
+        name: root.file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        is_obsidian: root.join(".obsidian").is_dir(),
+        notes,
+        built_at: Instant::now(),
+    }
+}
+
+fn collect_markdown_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        if files.len() >= MAX_NOTES_PER_VAULT {
+            return;
+        }
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if path.is_dir() {
+            if !IGNORED_DIRS.contains(&name.as_str()) && !name.starts_with('.') {
+                collect_markdown_files(&path, files);
+            }
+        } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")) {
+            files.push(path);
+        }
+    }
+}
+
+// 提取标题（frontmatter 的 title、第一个一级标题或文件名）和小标题
+fn index_note(path: PathBuf, relative_path: String, text: &str) -> IndexedNote {
+    let mut body = text;
+    let mut title = None;
+
+    if let Some(rest) = text.strip_prefix("---\n").or_else(|| text.strip_prefix("---\r\n")) {
+        if let Some(end) = rest.find("\n---") {
+            title = rest[..end].lines()
+                .find_map(|line| line.strip_prefix("title:"))
+                .map(|value| value.trim().trim_matches(['"', '\'']).to_string())
+                .filter(|value| !value.is_empty());
+            body = rest[end + 4..].trim_start_matches(['-', '\r', '\n']);
+        }
+    }
+
+    let mut headings = Vec::new();
+    let mut in_code_block = false;
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        let hashes = line.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&hashes) && line[hashes..].starts_with(' ') {
+            let heading = line[hashes..].trim().to_string();
+            if hashes == 1 && title.is_none() {
+                title = Some(heading.clone());
+            }
+            headings.push(heading);
+        }
+    }
+
+    let title = title.unwrap_or_else(|| {
+        path.file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default()
+    });
+
+    IndexedNote {
+        title,
+        path,
+        relative_path,
+        headings,
+        content: body.to_string(),
+    }
+}
+
+fn search_index(index: &VaultIndex, query: &str) -> Vec<NoteMatch> {
+    index.notes.iter()
+        .filter_map(|note| {
+            let title = note.title.to_lowercase();
+            let (score, heading, snippet) = if title == query {
+                (100, None, None)
+            } else if title.starts_with(query) {
+                (90, None, None)
+            } else if title.contains(query) {
+                (80, None, None)
+            } else if let Some(heading) = note.headings.iter().find(|h| h.to_lowercase().contains(query)) {
+                (60, Some(heading.clone()), None)
+            } else if let Some(snippet) = find_snippet(&note.content, query) {
+                (30, None, Some(snippet))
+            } else {
+                return None;
+            };
+
+            Some(NoteMatch {
+                title: note.title.clone(),
+                path: note.path.to_string_lossy().to_string(),
+                vault: index.name.clone(),
+                heading,
+                snippet,
+                obsidian_uri: index.is_obsidian.then(|| obsidian_uri(&index.name, &note.relative_path)),
+                score,
+            })
+        })
+        .collect()
+}
+
+// 匹配位置附近的一段正文
+fn find_snippet(content: &str, query: &str) -> Option<String> {
+    let chars: Vec<char> = content.chars().collect();
+    // 小写转换可能把一个字符变成多个（如 'İ'），记录每个小写字符对应的原文位置
+    let (lower, origin): (Vec<char>, Vec<usize>) = chars
+        .iter()
+        .enumerate()
+        .flat_map(|(index, c)| c.to_lowercase().map(move |lower| (lower, index)))
+        .unzip();
+    let needle: Vec<char> = query.chars().collect();
+    if needle.is_empty() {
+        return None;
+    }
+    let position = lower.windows(needle.len()).position(|window| window == needle.as_slice())?;
+    let match_start = origin[position];
+    let match_end = origin[position + needle.len() - 1] + 1;
+
+    let start = match_start.saturating_sub(SNIPPET_CONTEXT);
+    let end = (match_end + SNIPPET_CONTEXT).min(chars.len());
+    let snippet: String = chars[start..end].iter().collect();
+    let snippet = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    Some(format!(
+        "{}{}{}",
+        if start > 0 { "…" } else { "" },
+        snippet,
+        if end < chars.len() { "…" } else { "" }
+    ))
+}
+
+// obsidian://open?vault=<仓库名>&file=<不含扩展名的相对路径>
+fn obsidian_uri(vault: &str, relative_path: &str) -> String {
+    let file = relative_path.strip_suffix(".md").unwrap_or(relative_path);
+    format!("obsidian://open?vault={}&file={}", percent_encode(vault), percent_encode(file))
+}
+
+fn percent_encode(value: &str) -> String {
+    value.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    fn write_note(dir: &Path, name: &str, content: &str) {
+        if let Some(parent) = dir.join(name).parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        File::create(dir.join(name)).unwrap().write_all(content.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn test_index_note_title_sources() {
+        let note = index_note(PathBuf::from("a.md"), "a.md".into(), "---\ntitle: \"Weekly Plan\"\ntags: [x]\n---\n# Heading\n## Sub\n");
+        assert_eq!(note.title, "Weekly Plan");
+        assert_eq!(note.headings, vec!["Heading", "Sub"]);
+
+        let note = index_note(PathBuf::from("b.md"), "b.md".into(), "intro\n# First Heading\n```\n# not a heading\n```\n");
+        assert_eq!(note.title, "First Heading");
+        assert_eq!(note.headings, vec!["First Heading"]);
+
+        let note = index_note(PathBuf::from("dir/Inbox.md"), "dir/Inbox.md".into(), "#tag only\nplain text");
+        assert_eq!(note.title, "Inbox");
+        assert!(note.headings.is_empty());
+    }
+
+    #[test]
+    fn test_search_ranks_title_heading_content() {
+        let temp_dir = TempDir::new().unwrap();
+        let vault = temp_dir.path().join("Brain");
+        fs::create_dir_all(vault.join(".obsidian")).unwrap();
+        write_note(&vault, "Rust Tips.md", "# Rust Tips\nuse clippy");
+        write_note(&vault, "Projects/Launcher.md", "# Launcher\n## Rust backend\nnotes");
+        write_note(&vault, "Daily/2024-05-29.md", "Today I wrote some rust code for the launcher.");
+        write_note(&vault, ".obsidian/workspace.md", "# Rust cache");
+        write_note(&vault, "image.png", "rust");
+
+        let index = build_index(&vault);
+        assert_eq!(index.notes.len(), 3);
+        assert!(index.is_obsidian);
+
+        let mut results = search_index(&index, "rust");
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
+        let titles: Vec<&str> = results.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, vec!["Rust Tips", "Launcher", "2024-05-29"]);
+        assert_eq!(results[1].heading.as_deref(), Some("Rust backend"));
+        assert_eq!(results[2].snippet.as_deref(), Some("Today I wrote some rust code for the launcher."));
+        assert_eq!(results[1].obsidian_uri.as_deref(), Some("obsidian://open?vault=Brain&file=Projects%2FLauncher"));
+    }
+
+    #[test]
+    fn test_plain_folder_has_no_obsidian_uri() {
+        let temp_dir = TempDir::new().unwrap();
+        write_note(temp_dir.path(), "todo.md", "# Todo");
+
+        let results = search_notes("todo".into(), vec![temp_dir.path().to_string_lossy().to_string()]).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].score, 100);
+        assert!(results[0].obsidian_uri.is_none());
+
+        assert!(search_notes("todo".into(), vec!["/nonexistent/vault".into()]).is_err());
+    }
+
+    #[test]
+    fn test_find_snippet() {
+        let content = format!("{}needle{}", "a ".repeat(50), " b".repeat(50));
+        let snippet = find_snippet(&content, "needle").unwrap();
+        assert!(snippet.starts_with('…') && snippet.ends_with('…'));
+        assert!(snippet.contains("needle"));
+
+        // 中文内容按字符截取
+        assert_eq!(find_snippet("会议记录：讨论发布计划", "发布").unwrap(), "会议记录：讨论发布计划");
+        assert!(find_snippet("nothing", "needle").is_none());
+
+        // 小写后变长的字符不会导致越界
+        let content = format!("{}x", "İ".repeat(100));
+        let snippet = find_snippet(&content, "x").unwrap();
+        assert!(snippet.starts_with('…') && snippet.ends_with('x'));
+        assert_eq!(find_snippet("İstanbul", "i̇st").unwrap(), "İstanbul");
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("My Vault"), "My%20Vault");
+        assert_eq!(percent_encode("笔记"), "%E7%AC%94%E8%AE%B0");
+    }
+}
@@ -0,0 +1,19 @@
+// 系统原生通知
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+
+// 发送系统通知，可选播放默认提示音
+pub(crate) fn notify(
+    app_handle: &AppHandle,
+    title: &str,
+    body: &str,
+    play_sound: bool,
+) -> Result<(), String> {
+    let mut builder = app_handle.notification().builder().title(title).body(body);
+
+    if play_sound {
+        builder = builder.sound("default");
+    }
+
+    builder.show().map_err(|e| format!("发送通知失败: {}", e))
+}
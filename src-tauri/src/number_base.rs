@@ -0,0 +1,206 @@
+// 进制转换：十进制、十六进制、二进制、八进制互转，负数额外给出补码表示
+use serde::{Deserialize, Serialize};
+
+// 补码显示时依次尝试的位宽，都放不下时使用 128 位
+const TWOS_COMPLEMENT_WIDTHS: &[u32] = &[8, 16, 32, 64];
+
+const BASES: &[(&str, u32, &str, &str)] = &[
+    // (类型, 基数, 前缀, 名称)
+    ("dec", 10, "", "十进制"),
+    ("hex", 16, "0x", "十六进制"),
+    ("bin", 2, "0b", "二进制"),
+    ("oct", 8, "0o", "八进制"),
+];
+
+// value 为可直接复制的形式，display 为分组后便于阅读的形式
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BaseValue {
+    pub kind: String,
+    pub name: String,
+    pub value: String,
+    pub display: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BaseConversion {
+    pub input_base: String,
+    pub values: Vec<BaseValue>,
+}
+
+// 输入示例：0xff、-0b1010 to dec、255 to hex、1_000_000 in bin
+#[tauri::command]
+pub fn convert_number_base(input: String) -> Result<BaseConversion, String> {
+    let input = input.trim().to_lowercase();
+    let (number, target) = match input.rsplit_once(" to ").or_else(|| input.rsplit_once(" in ")) {
+        Some((number, target)) => {
+            let target = target.trim();
+            let kind = BASES.iter()
+                .find(|(kind, ..)| *kind == target)
+                .map(|(kind, ..)| *kind)
+                .ok_or_else(|| format!("不支持的目标进制: {}", target))?;
+            (number.trim(), Some(kind))
+        }
+        None => (input.as_str(), None),
+    };
+
+    let (input_base, value) = parse_number(number)?;
+    let mut values: Vec<BaseValue> = BASES.iter()
+        .map(|(kind, radix, prefix, name)| {
+            let sign = if value < 0 { "-" } else { "" };
+            let digits = to_radix(value.unsigned_abs(), *radix);
+            BaseValue {
+                kind: kind.to_string(),
+                name: name.to_string(),
+                value: format!("{}{}{}", sign, prefix, digits),
+                display: format!("{}{}", sign, group_digits(&digits, *radix)),
+            }
+        })
+        .collect();
+
+    if value < 0 {
+        values.extend(twos_complement(value));
+    }
+    // 指定了目标进制时放在最前面
+    if let Some(target) = target {
+        if let Some(index) = values.iter().position(|value| value.kind == target) {
+            let value = values.remove(index);
+            values.insert(0, value);
+        }
+    }
+
+    Ok(BaseConversion { input_base: input_base.to_string(), values })
+}
+
+fn parse_number(text: &str) -> Result<(&'static str, i128), String> {
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (kind, radix, digits) = BASES.iter()
+        .filter(|(_, _, prefix, _)| !prefix.is_empty())
+        .find_map(|(kind, radix, prefix, _)| unsigned.strip_prefix(prefix).map(|digits| (*kind, *radix, digits)))
+        .unwrap_or(("dec", 10, unsigned));
+
+    // 允许用 _ 或空格分隔数字
+    let digits: String = digits.chars().filter(|c| *c != '_' && *c != ' ').collect();
+    if digits.is_empty() {
+        return Err(format!("无法识别的数字: {}", text));
+    }
+    let magnitude = u128::from_str_radix(&digits, radix).map_err(|e| match e.kind() {
+        std::num::IntErrorKind::PosOverflow => "数字超出 128 位范围".to_string(),
+        _ => format!("无法识别的数字: {}", text),
+    })?;
+
+    let value = if negative {
+        0i128.checked_sub_unsigned(magnitude)
+    } else {
+        i128::try_from(magnitude).ok()
+    };
+    value.map(|value| (kind, value)).ok_or_else(|| "数字超出 128 位范围".to_string())
+}
+
+fn to_radix(mut value: u128, radix: u32) -> String {
+    if value == 0 {
+        return "0".to_string();
+    }
+    let mut digits = Vec::new();
+    while value > 0 {
+        digits.push(std::char::from_digit((value % radix as u128) as u32, radix).unwrap_or('?'));
+        value /= radix as u128;
+    }
+    digits.iter().rev().collect()
+}
+
+// 二进制和十六进制每 4 位一组（二进制补齐前导零），十进制每 3 位加逗号
+fn group_digits(digits: &str, radix: u32) -> String {
+    let (size, separator, pad) = match radix {
+        2 => (4, " ", true),
+        16 => (4, " ", false),
+        10 => (3, ",", false),
+        _ => return digits.to_string(),
+    };
+    let mut digits = digits.to_string();
+    if pad && !digits.len().is_multiple_of(size) {
+        digits = format!("{}{}", "0".repeat(size - digits.len() % size), digits);
+    }
+
+    let first = digits.len() % size;
+    let mut groups: Vec<&str> = Vec::new();
+    if first > 0 {
+        groups.push(&digits[..first]);
+    }
+    groups.extend(digits.as_bytes()[first..].chunks(size).map(|chunk| std::str::from_utf8(chunk).unwrap_or_default()));
+    groups.join(separator)
+}
+
+// 在能容纳该负数的最小位宽下显示补码
+fn twos_complement(value: i128) -> Vec<BaseValue> {
+    let bits = TWOS_COMPLEMENT_WIDTHS.iter().copied().find(|bits| *bits < 128 && value >= -(1i128 << (bits - 1))).unwrap_or(128);
+    let mask = if bits == 128 { u128::MAX } else { (1u128 << bits) - 1 };
+    let raw = value as u128 & mask;
+
+    let hex = format!("{:0width$x}", raw, width = bits as usize / 4);
+    let bin = format!("{:0width$b}", raw, width = bits as usize);
+    vec![
+        BaseValue {
+            kind: "twos_hex".to_string(),
+            name: format!("{} 位补码（十六进制）", bits),
+            value: format!("0x{}", hex),
+            display: group_digits(&hex, 16),
+        },
+        BaseValue {
+            kind: "twos_bin".to_string(),
+            name: format!("{} 位补码（二进制）", bits),
+            value: format!("0b{}", bin),
+            display: group_digits(&bin, 2),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(conversion: &BaseConversion, kind: &str) -> (String, String) {
+        let value = conversion.values.iter().find(|value| value.kind == kind).unwrap();
+        (value.value.clone(), value.display.clone())
+    }
+
+    #[test]
+    fn test_convert_positive() {
+        let conversion = convert_number_base("0xFF".to_string()).unwrap();
+        assert_eq!(conversion.input_base, "hex");
+        assert_eq!(value(&conversion, "dec"), ("255".to_string(), "255".to_string()));
+        assert_eq!(value(&conversion, "bin"), ("0b11111111".to_string(), "1111 1111".to_string()));
+        assert_eq!(value(&conversion, "oct").0, "0o377");
+        assert!(conversion.values.iter().all(|value| !value.kind.starts_with("twos")));
+
+        let conversion = convert_number_base("1_234_567 to hex".to_string()).unwrap();
+        assert_eq!(conversion.values[0].kind, "hex");
+        assert_eq!(conversion.values[0].value, "0x12d687");
+        assert_eq!(conversion.values[0].display, "12 d687");
+        assert_eq!(value(&conversion, "dec").1, "1,234,567");
+        assert_eq!(value(&conversion, "bin").1, "0001 0010 1101 0110 1000 0111");
+    }
+
+    #[test]
+    fn test_convert_negative() {
+        let conversion = convert_number_base("-0b1010 to dec".to_string()).unwrap();
+        assert_eq!(conversion.values[0].value, "-10");
+        assert_eq!(value(&conversion, "twos_hex"), ("0xf6".to_string(), "f6".to_string()));
+        assert_eq!(value(&conversion, "twos_bin"), ("0b11110110".to_string(), "1111 0110".to_string()));
+
+        let conversion = convert_number_base("-129".to_string()).unwrap();
+        assert_eq!(value(&conversion, "twos_hex").0, "0xff7f");
+        assert_eq!(conversion.values.iter().find(|value| value.kind == "twos_hex").unwrap().name, "16 位补码（十六进制）");
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(convert_number_base("0xzz".to_string()).is_err());
+        assert!(convert_number_base("0b".to_string()).is_err());
+        assert!(convert_number_base("10 to base64".to_string()).unwrap_err().contains("目标进制"));
+        assert!(convert_number_base(format!("0x1{}", "0".repeat(32))).unwrap_err().contains("128 位"));
+        assert_eq!(convert_number_base("0".to_string()).unwrap().values[2].display, "0000");
+    }
+}
@@ -0,0 +1,166 @@
+// 屏幕文字识别：框选屏幕区域并识别其中的文字（tesseract / Vision / Windows.Media.Ocr）
+use crate::system_command::{powershell, run, WINRT_AWAIT};
+use std::path::Path;
+
+// 通过 Vision 框架识别图片中的文字，路径作为参数传入
+const MAC_VISION_SCRIPT: &str = r#"ObjC.import('Vision');
+function run(argv) {
+    var handler = $.VNImageRequestHandler.alloc.initWithURLOptions($.NSURL.fileURLWithPath(argv[0]), $.NSDictionary.dictionary);
+    var request = $.VNRecognizeTextRequest.alloc.init;
+    request.setUsesLanguageCorrection(true);
+    handler.performRequestsError($.NSArray.arrayWithObject(request), null);
+    var results = request.results;
+    var lines = [];
+    for (var i = 0; i < results.count; i++) {
+        lines.push(results.objectAtIndex(i).topCandidates(1).objectAtIndex(0).string.js);
+    }
+    return lines.join('\n');
+}"#;
+
+// 截取整个虚拟屏幕铺满一个置顶窗口，拖动框选区域后保存为 PNG，Esc 取消
+const WINDOWS_CAPTURE_SCRIPT: &str = r#"Add-Type -AssemblyName System.Windows.Forms, System.Drawing
+$bounds = [System.Windows.Forms.SystemInformation]::VirtualScreen
+$bitmap = New-Object System.Drawing.Bitmap $bounds.Width, $bounds.Height
+$graphics = [System.Drawing.Graphics]::FromImage($bitmap)
+$graphics.CopyFromScreen($bounds.Location, [System.Drawing.Point]::Empty, $bounds.Size)
+$form = New-Object System.Windows.Forms.Form
+$form.FormBorderStyle = 'None'
+$form.StartPosition = 'Manual'
+$form.Bounds = $bounds
+$form.TopMost = $true
+$form.ShowInTaskbar = $false
+$form.BackgroundImage = $bitmap
+$form.Cursor = [System.Windows.Forms.Cursors]::Cross
+$script:start = $null
+$script:region = $null
+$form.Add_MouseDown({ param($s, $e) $script:start = $e.Location })
+$form.Add_MouseUp({ param($s, $e)
+    if ($script:start) {
+        $x = [Math]::Min($script:start.X, $e.X); $y = [Math]::Min($script:start.Y, $e.Y)
+        $script:region = New-Object System.Drawing.Rectangle $x, $y, ([Math]::Abs($e.X - $script:start.X)), ([Math]::Abs($e.Y - $script:start.Y))
+    }
+    $form.Close()
+})
+$form.Add_KeyDown({ param($s, $e) if ($e.KeyCode -eq 'Escape') { $form.Close() } })
+[void]$form.ShowDialog()
+if ($script:region -and $script:region.Width -gt 2 -and $script:region.Height -gt 2) {
+    $bitmap.Clone($script:region, $bitmap.PixelFormat).Save($path, [System.Drawing.Imaging.ImageFormat]::Png)
+}"#;
+
+// 使用用户语言设置中的 OCR 引擎识别 $path 指向的图片
+const WINDOWS_OCR_SCRIPT: &str = r#"[Windows.Storage.StorageFile, Windows.Storage, ContentType = WindowsRuntime] | Out-Null
+[Windows.Media.Ocr.OcrEngine, Windows.Foundation, ContentType = WindowsRuntime] | Out-Null
+[Windows.Graphics.Imaging.BitmapDecoder, Windows.Graphics, ContentType = WindowsRuntime] | Out-Null
+$file = Await ([Windows.Storage.StorageFile]::GetFileFromPathAsync($path)) ([Windows.Storage.StorageFile])
+$stream = Await ($file.OpenAsync([Windows.Storage.FileAccessMode]::Read)) ([Windows.Storage.Streams.IRandomAccessStream])
+$decoder = Await ([Windows.Graphics.Imaging.BitmapDecoder]::CreateAsync($stream)) ([Windows.Graphics.Imaging.BitmapDecoder])
+$image = Await ($decoder.GetSoftwareBitmapAsync()) ([Windows.Graphics.Imaging.SoftwareBitmap])
+$engine = [Windows.Media.Ocr.OcrEngine]::TryCreateFromUserProfileLanguages()
+$result = Await ($engine.RecognizeAsync($image)) ([Windows.Media.Ocr.OcrResult])
+$stream.Dispose()
+$result.Lines | ForEach-Object { $_.Text }"#;
+
+// 框选区域并识别文字，用户取消时返回 None；会阻塞到用户完成框选，因此不在主线程执行
+// language 为 tesseract 的语言代码（如 "chi_sim+eng"），仅在 Linux 上使用
+#[tauri::command(async)]
+pub fn ocr_screen_region(language: Option<String>) -> Result<Option<String>, String> {
+    let path = std::env::temp_dir().join(format!("launch-rs-ocr-{}.png", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let result = capture_region(&path).and_then(|captured| {
+        if !captured || !path.exists() {
+            return Ok(None);
+        }
+        recognize(&path, language.as_deref()).map(|text| Some(clean_text(&text)))
+    });
+
+    let _ = std::fs::remove_file(&path);
+    result
+}
+
+// 返回 false 表示用户取消了框选
+fn capture_region(path: &Path) -> Result<bool, String> {
+    let path_str = path.to_string_lossy();
+
+    let result = match std::env::consts::OS {
+        "linux" => {
+            if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+                run("slurp", &[])
+                    .and_then(|geometry| run("grim", &["-g", geometry.trim(), &path_str]))
+                    .map_err(|e| format!("{}（Wayland 需要先安装 grim 和 slurp）", e))
+            } else {
+                run("maim", &["-s", &path_str])
+                    .map_err(|e| format!("{}（X11 需要先安装 maim）", e))
+            }
+        }
+        // 用户按 Esc 时 screencapture 正常退出但不生成文件
+        "macos" => run("screencapture", &["-i", "-x", &path_str]),
+        "windows" => powershell(&format!("$path = '{}'\n{}", path_str.replace('\'', "''"), WINDOWS_CAPTURE_SCRIPT)),
+        os => return Err(format!("当前系统不支持屏幕文字识别: {}", os)),
+    };
+
+    match result {
+        Ok(_) => Ok(true),
+        // slurp / maim 在取消框选时返回错误
+        Err(e) if e.to_lowercase().contains("cancel") => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+fn recognize(path: &Path, language: Option<&str>) -> Result<String, String> {
+    let path_str = path.to_string_lossy();
+
+    match std::env::consts::OS {
+        "linux" => {
+            let mut args = vec![path_str.as_ref(), "stdout"];
+            if let Some(language) = language.filter(|language| is_valid_language(language)) {
+                args.extend(["-l", language]);
+            }
+            run("tesseract", &args).map_err(|e| format!("{}（Linux 需要先安装 tesseract）", e))
+        }
+        "macos" => run("osascript", &["-l", "JavaScript", "-e", MAC_VISION_SCRIPT, &path_str]),
+        "windows" => powershell(&format!("$path = '{}'\n{}\n{}", path_str.replace('\'', "''"), WINRT_AWAIT, WINDOWS_OCR_SCRIPT)),
+        os => Err(format!("当前系统不支持屏幕文字识别: {}", os)),
+    }
+}
+
+// tesseract 语言代码，例如 "eng"、"chi_sim+eng"
+fn is_valid_language(language: &str) -> bool {
+    !language.is_empty()
+        && language.split('+').all(|code| !code.is_empty() && code.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+}
+
+// 去掉每行首尾空白和多余的空行，tesseract 会在末尾输出换页符
+fn clean_text(text: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    for line in text.lines().map(|line| line.trim().trim_matches('\u{c}')) {
+        if line.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|last| last.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_language() {
+        assert!(is_valid_language("eng"));
+        assert!(is_valid_language("chi_sim+eng"));
+        assert!(!is_valid_language(""));
+        assert!(!is_valid_language("eng+"));
+        assert!(!is_valid_language("--psm 0"));
+    }
+
+    #[test]
+    fn test_clean_text() {
+        assert_eq!(clean_text("  Hello world  \n\n\n第二行\n\n\u{c}"), "Hello world\n\n第二行");
+        assert_eq!(clean_text("\n\n"), "");
+    }
+}
@@ -0,0 +1,202 @@
+// 识别搜索框中输入的网址、域名和文件路径，直接提供打开操作
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+// 不带协议的域名只识别这些常见后缀，避免把 readme.md、main.rs 之类的文件名当成网址
+const COMMON_TLDS: &[&str] = &[
+    "com", "net", "org", "io", "dev", "app", "ai", "co", "edu", "gov", "info", "me", "xyz", "tech", "site",
+    "online", "cloud", "page", "gg", "tv", "cn", "uk", "de", "jp", "fr", "ru", "us", "ca", "au", "nl", "eu",
+    "in", "br", "it", "es", "ch", "se", "kr", "tw", "hk",
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OpenTargetKind {
+    Url,
+    File,
+    Folder,
+}
+
+// target 为补全协议后的网址或展开后的绝对路径
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OpenTarget {
+    pub kind: OpenTargetKind,
+    pub target: String,
+}
+
+// 路径只在存在时返回
+#[tauri::command]
+pub fn detect_open_target(query: String) -> Option<OpenTarget> {
+    let query = query.trim();
+    if query.is_empty() || query.contains('\n') {
+        return None;
+    }
+
+    if let Some(path) = parse_path(query) {
+        let metadata = path.metadata().ok()?;
+        return Some(OpenTarget {
+            kind: if metadata.is_dir() { OpenTargetKind::Folder } else { OpenTargetKind::File },
+            target: path.to_string_lossy().to_string(),
+        });
+    }
+    parse_url(query).map(|url| OpenTarget { kind: OpenTargetKind::Url, target: url })
+}
+
+// 绝对路径、~ 开头的路径、Windows 盘符路径、UNC 路径和 file:// 网址
+fn parse_path(query: &str) -> Option<PathBuf> {
+    if let Some(rest) = query.strip_prefix("file://") {
+        let path = percent_decode(rest);
+        // file:///C:/Windows 去掉盘符前的斜杠
+        let path = match path.strip_prefix('/') {
+            Some(windows) if is_drive_path(windows) => windows.to_string(),
+            _ => path,
+        };
+        return Some(PathBuf::from(path));
+    }
+    if query == "~" {
+        return dirs::home_dir();
+    }
+    if let Some(rest) = query.strip_prefix("~/").or_else(|| query.strip_prefix("~\\")) {
+        return dirs::home_dir().map(|home| home.join(rest));
+    }
+    if query.starts_with('/') || query.starts_with("\\\\") || is_drive_path(query) {
+        return Some(PathBuf::from(query));
+    }
+    None
+}
+
+fn is_drive_path(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    bytes.len() >= 3 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' && matches!(bytes[2], b'\\' | b'/')
+}
+
+// 带协议的网址原样返回；不带协议的域名、localhost 和 IP 地址补全协议
+fn parse_url(query: &str) -> Option<String> {
+    if query.contains(char::is_whitespace) {
+        return None;
+    }
+    if let Some((scheme, rest)) = query.split_once("://") {
+        let lower = scheme.to_lowercase();
+        return (matches!(lower.as_str(), "http" | "https" | "ftp") && !rest.is_empty()).then(|| query.to_string());
+    }
+
+    let end = query.find(['/', '?', '#']).unwrap_or(query.len());
+    let (authority, rest) = query.split_at(end);
+    if authority.contains('@') {
+        return None;
+    }
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (authority, None),
+    };
+    if port.is_some_and(|port| port.is_empty() || !port.chars().all(|c| c.is_ascii_digit()) || port.parse::<u16>().is_err()) {
+        return None;
+    }
+
+    let host = host.to_lowercase();
+    if host == "localhost" || is_ipv4(&host) {
+        return Some(format!("http://{}", query));
+    }
+
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.len() < 2 || labels.iter().any(|label| !is_domain_label(label)) {
+        return None;
+    }
+    let tld = labels[labels.len() - 1];
+    if !tld.chars().all(|c| c.is_ascii_alphabetic()) || tld.len() < 2 {
+        return None;
+    }
+    // 有路径、端口或 www 前缀时任意字母后缀都可以
+    if COMMON_TLDS.contains(&tld) || labels[0] == "www" || port.is_some() || rest.starts_with('/') {
+        Some(format!("https://{}", query))
+    } else {
+        None
+    }
+}
+
+fn is_domain_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.len() <= 63
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+fn is_ipv4(host: &str) -> bool {
+    let parts: Vec<&str> = host.split('.').collect();
+    parts.len() == 4 && parts.iter().all(|part| !part.is_empty() && part.len() <= 3 && part.parse::<u8>().is_ok())
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Some(byte) = std::str::from_utf8(&bytes[i + 1..i + 3]).ok().and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(parse_url("https://example.com/a b"), None);
+        assert_eq!(parse_url("HTTPS://Example.com").as_deref(), Some("HTTPS://Example.com"));
+        assert_eq!(parse_url("example.com/docs").as_deref(), Some("https://example.com/docs"));
+        assert_eq!(parse_url("docs.rs").as_deref(), None);
+        assert_eq!(parse_url("docs.rs/serde").as_deref(), Some("https://docs.rs/serde"));
+        assert_eq!(parse_url("www.example.museum").as_deref(), Some("https://www.example.museum"));
+        assert_eq!(parse_url("github.com").as_deref(), Some("https://github.com"));
+        assert_eq!(parse_url("localhost:3000/api").as_deref(), Some("http://localhost:3000/api"));
+        assert_eq!(parse_url("192.168.1.1").as_deref(), Some("http://192.168.1.1"));
+        assert_eq!(parse_url("10.0.0.5:8080").as_deref(), Some("http://10.0.0.5:8080"));
+
+        assert_eq!(parse_url("readme.md"), None);
+        assert_eq!(parse_url("main.rs"), None);
+        assert_eq!(parse_url("1.5"), None);
+        assert_eq!(parse_url("3.14.15"), None);
+        assert_eq!(parse_url("user@example.com"), None);
+        assert_eq!(parse_url("example.com:99999"), None);
+        assert_eq!(parse_url("javascript://alert"), None);
+        assert_eq!(parse_url("hello world.com"), None);
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(parse_path("/var/log/syslog"), Some(PathBuf::from("/var/log/syslog")));
+        assert_eq!(parse_path("C:\\logs\\app.log"), Some(PathBuf::from("C:\\logs\\app.log")));
+        assert_eq!(parse_path("\\\\server\\share"), Some(PathBuf::from("\\\\server\\share")));
+        assert_eq!(parse_path("file:///tmp/a%20b.txt"), Some(PathBuf::from("/tmp/a b.txt")));
+        assert_eq!(parse_path("file:///C:/Windows"), Some(PathBuf::from("C:/Windows")));
+        assert_eq!(parse_path("~/Documents"), dirs::home_dir().map(|home| home.join("Documents")));
+        assert_eq!(parse_path("src/main.rs"), None);
+        assert_eq!(parse_path("c:"), None);
+    }
+
+    #[test]
+    fn test_detect_open_target() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("app.log");
+        std::fs::write(&file, "log").unwrap();
+
+        let target = detect_open_target(file.to_string_lossy().to_string()).unwrap();
+        assert_eq!(target.kind, OpenTargetKind::File);
+        let target = detect_open_target(format!("  {}  ", temp.path().display())).unwrap();
+        assert_eq!(target.kind, OpenTargetKind::Folder);
+        assert_eq!(detect_open_target(temp.path().join("missing.log").to_string_lossy().to_string()), None);
+        assert_eq!(detect_open_target("example.com".to_string()).unwrap().kind, OpenTargetKind::Url);
+        assert_eq!(detect_open_target("hello".to_string()), None);
+    }
+}
@@ -0,0 +1,393 @@
+// 软件包：通过系统包管理器（winget / brew / apt / pacman）搜索、安装和升级软件包
+use crate::system_command::{command, run};
+use crate::terminal::open_in_terminal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+const MAX_RESULTS: usize = 30;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageManager {
+    Winget,
+    Brew,
+    Apt,
+    Pacman,
+}
+
+// id 为安装时使用的标识（winget 的包 ID，其他包管理器为包名）
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Package {
+    pub name: String,
+    pub id: String,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub installed: bool,
+    pub upgradable: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PackageSearchResult {
+    pub manager: PackageManager,
+    pub packages: Vec<Package>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageAction {
+    Install,
+    Upgrade,
+}
+
+#[tauri::command(async)]
+pub fn search_packages(query: String) -> Result<PackageSearchResult, String> {
+    let query = query.trim().to_string();
+    if !is_valid_package_id(&query) {
+        return Err(format!("无效的软件包名称: {}", query));
+    }
+
+    let manager = detect_manager()?;
+    let mut packages = match manager {
+        PackageManager::Winget => {
+            let output = run("winget", &["search", &query, "--accept-source-agreements", "--disable-interactivity"])?;
+            let mut packages = parse_winget_table(&output, false);
+            // winget list 的 Available 列表示有可用更新
+            let installed = command("winget").args(["list", &query, "--accept-source-agreements", "--disable-interactivity"]).output()
+                .map(|output| parse_winget_table(&String::from_utf8_lossy(&output.stdout), true))
+                .unwrap_or_default();
+            for package in &mut packages {
+                if let Some(local) = installed.iter().find(|local| local.id == package.id) {
+                    package.installed = true;
+                    package.upgradable = local.upgradable;
+                    package.version = local.version.clone();
+                }
+            }
+            packages
+        }
+        PackageManager::Brew => {
+            let mut packages = parse_brew_search(&run("brew", &["search", &query])?);
+            let mut installed = parse_brew_versions(&run("brew", &["list", "--versions"]).unwrap_or_default());
+            installed.extend(parse_brew_versions(&run("brew", &["list", "--cask", "--versions"]).unwrap_or_default()));
+            let outdated: HashSet<String> = run("brew", &["outdated", "--quiet"]).unwrap_or_default()
+                .lines().map(|line| line.trim().to_string()).collect();
+            for package in &mut packages {
+                if let Some(version) = installed.get(&package.name) {
+                    package.installed = true;
+                    package.version = Some(version.clone());
+                    package.upgradable = outdated.contains(&package.name);
+                }
+            }
+            packages
+        }
+        PackageManager::Apt => {
+            let mut packages = parse_apt_search(&run("apt-cache", &["search", "--names-only", &query])?);
+            sort_by_relevance(&mut packages, &query);
+            packages.truncate(MAX_RESULTS);
+
+            // 包不存在时 dpkg-query 返回非零，但已安装的包仍会输出
+            let names: Vec<&str> = packages.iter().map(|package| package.name.as_str()).collect();
+            let installed = command("dpkg-query")
+                .args(["-W", "-f=${Package}\t${Version}\t${db:Status-Abbrev}\n"])
+                .args(&names)
+                .output()
+                .map(|output| parse_dpkg_query(&String::from_utf8_lossy(&output.stdout)))
+                .unwrap_or_default();
+            let upgradable = command("apt").args(["list", "--upgradable"]).output()
+                .map(|output| parse_apt_upgradable(&String::from_utf8_lossy(&output.stdout)))
+                .unwrap_or_default();
+            for package in &mut packages {
+                if let Some(version) = installed.get(&package.name) {
+                    package.installed = true;
+                    package.version = Some(version.clone());
+                    package.upgradable = upgradable.contains(&package.name);
+                }
+            }
+            packages
+        }
+        PackageManager::Pacman => parse_pacman_search(&run("pacman", &["-Ss", &query])?),
+    };
+
+    sort_by_relevance(&mut packages, &query);
+    packages.truncate(MAX_RESULTS);
+    Ok(PackageSearchResult { manager, packages })
+}
+
+// 安装和升级需要管理员权限或会输出进度，放到终端中执行；必须经过前端确认
+#[tauri::command(async)]
+pub fn run_package_action(id: String, action: PackageAction, confirmed: Option<bool>, terminal_command: Option<String>) -> Result<(), String> {
+    if !confirmed.unwrap_or(false) {
+        return Err("该操作需要确认".to_string());
+    }
+    if !is_valid_package_id(&id) {
+        return Err(format!("无效的软件包名称: {}", id));
+    }
+
+    let args = package_command(detect_manager()?, action, &id);
+    open_in_terminal(&args, terminal_command.as_deref())
+}
+
+fn detect_manager() -> Result<PackageManager, String> {
+    let available = |program: &str| command(program).arg("--version").output().is_ok_and(|output| output.status.success());
+    match std::env::consts::OS {
+        "windows" if available("winget") => Ok(PackageManager::Winget),
+        "macos" if available("brew") => Ok(PackageManager::Brew),
+        "linux" if available("pacman") => Ok(PackageManager::Pacman),
+        "linux" if available("apt-cache") => Ok(PackageManager::Apt),
+        _ => Err("未找到支持的包管理器（winget、brew、apt、pacman）".to_string()),
+    }
+}
+
+fn package_command(manager: PackageManager, action: PackageAction, id: &str) -> Vec<&str> {
+    match (manager, action) {
+        (PackageManager::Winget, PackageAction::Install) => vec!["winget", "install", "--exact", "--id", id],
+        (PackageManager::Winget, PackageAction::Upgrade) => vec!["winget", "upgrade", "--exact", "--id", id],
+        (PackageManager::Brew, PackageAction::Install) => vec!["brew", "install", id],
+        (PackageManager::Brew, PackageAction::Upgrade) => vec!["brew", "upgrade", id],
+        (PackageManager::Apt, PackageAction::Install) => vec!["sudo", "apt", "install", id],
+        (PackageManager::Apt, PackageAction::Upgrade) => vec!["sudo", "apt", "install", "--only-upgrade", id],
+        // pacman 不支持只升级单个包，-S 会安装或升级到最新版本
+        (PackageManager::Pacman, _) => vec!["sudo", "pacman", "-S", id],
+    }
+}
+
+// 防止包名被当作命令行选项
+fn is_valid_package_id(id: &str) -> bool {
+    !id.is_empty()
+        && !id.starts_with('-')
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || "._+-@/".contains(c))
+}
+
+// 名称完全匹配的排在最前，其次是以查询开头的，已安装的优先
+fn sort_by_relevance(packages: &mut [Package], query: &str) {
+    let query = query.to_lowercase();
+    packages.sort_by_key(|package| {
+        let name = package.name.to_lowercase();
+        (name != query, !name.starts_with(&query), !package.installed, name.len())
+    });
+}
+
+fn package(name: &str, id: &str) -> Package {
+    Package {
+        name: name.to_string(),
+        id: id.to_string(),
+        version: None,
+        description: None,
+        installed: false,
+        upgradable: false,
+    }
+}
+
+// "名称 - 描述"
+fn parse_apt_search(output: &str) -> Vec<Package> {
+    output.lines()
+        .filter_map(|line| {
+            let (name, description) = line.split_once(" - ")?;
+            let mut package = package(name.trim(), name.trim());
+            package.description = Some(description.trim().to_string());
+            Some(package)
+        })
+        .collect()
+}
+
+// "名称\t版本\t状态"，状态 ii 表示已安装
+fn parse_dpkg_query(output: &str) -> HashMap<String, String> {
+    output.lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\t');
+            let (name, version, status) = (parts.next()?, parts.next()?, parts.next()?);
+            status.starts_with("ii").then(|| (name.to_string(), version.to_string()))
+        })
+        .collect()
+}
+
+// "名称/发行版 新版本 架构 [upgradable from: 旧版本]"
+fn parse_apt_upgradable(output: &str) -> HashSet<String> {
+    output.lines()
+        .filter(|line| line.contains("[upgradable from:"))
+        .filter_map(|line| line.split('/').next().map(str::to_string))
+        .collect()
+}
+
+// 每个包两行：第一行 "仓库/名称 版本 [installed]"，第二行缩进的描述
+fn parse_pacman_search(output: &str) -> Vec<Package> {
+    let mut packages: Vec<Package> = Vec::new();
+    for line in output.lines() {
+        if line.starts_with(char::is_whitespace) {
+            if let Some(package) = packages.last_mut() {
+                package.description = Some(line.trim().to_string());
+            }
+            continue;
+        }
+
+        let mut parts = line.split_whitespace();
+        let (Some(full_name), Some(version)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let name = full_name.rsplit('/').next().unwrap_or(full_name);
+        let mut package = package(name, name);
+        package.version = Some(version.to_string());
+
+        // "[installed]" 为最新版本，"[installed: 旧版本]" 表示有更新
+        if let Some(index) = line.find("[installed") {
+            package.installed = true;
+            let status = &line[index..];
+            if let Some(local) = status.strip_prefix("[installed: ").and_then(|rest| rest.split(']').next()) {
+                package.upgradable = true;
+                package.version = Some(local.to_string());
+            }
+        }
+        packages.push(package);
+    }
+    packages
+}
+
+// 有多种类型时以 "==> Formulae" / "==> Casks" 分组
+fn parse_brew_search(output: &str) -> Vec<Package> {
+    output.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("==>"))
+        .flat_map(|line| line.split_whitespace())
+        .map(|name| package(name, name))
+        .collect()
+}
+
+// "名称 版本 [版本...]"，取最后一个版本
+fn parse_brew_versions(output: &str) -> HashMap<String, String> {
+    output.lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let name = parts.next()?;
+            Some((name.to_string(), parts.last()?.to_string()))
+        })
+        .collect()
+}
+
+// winget 输出固定宽度的表格，按表头中各列的位置切分；list 的表格多一列可用更新
+fn parse_winget_table(output: &str, list: bool) -> Vec<Package> {
+    // 表头之前可能有进度动画，用 \r 分隔
+    let lines: Vec<&str> = output.lines()
+        .map(|line| line.rsplit('\r').next().unwrap_or(line))
+        .collect();
+    let Some(separator) = lines.iter().position(|line| !line.is_empty() && line.trim().chars().all(|c| c == '-')) else {
+        return vec![];
+    };
+    if separator == 0 {
+        return vec![];
+    }
+
+    let header: Vec<char> = lines[separator - 1].chars().collect();
+    let mut columns: Vec<(String, usize)> = Vec::new();
+    for (index, c) in header.iter().enumerate() {
+        if !c.is_whitespace() && (index == 0 || header[index - 1].is_whitespace()) {
+            let name: String = header[index..].iter().take_while(|c| !c.is_whitespace()).collect();
+            columns.push((name, index));
+        }
+    }
+    // 表头会被本地化，按位置取：名称、ID、版本，list 的第四列是可用更新
+    if columns.len() < 3 {
+        return vec![];
+    }
+    let has_available = list && columns.len() >= 5;
+
+    lines[separator + 1..].iter()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            let chars: Vec<char> = line.chars().collect();
+            let cell = |column: usize| -> String {
+                let start = columns[column].1.min(chars.len());
+                let end = columns.get(column + 1).map_or(chars.len(), |next| next.1.min(chars.len()));
+                chars[start..end].iter().collect::<String>().trim().to_string()
+            };
+            let id = cell(1);
+            if id.is_empty() {
+                return None;
+            }
+            let mut package = package(&cell(0), &id);
+            package.version = Some(cell(2)).filter(|version| !version.is_empty());
+            package.upgradable = has_available && !cell(3).is_empty();
+            Some(package)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_apt() {
+        let packages = parse_apt_search("ripgrep - Recursively searches directories for a regex pattern\nrust-ripgrep - dummy\n");
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].name, "ripgrep");
+        assert_eq!(packages[0].description.as_deref(), Some("Recursively searches directories for a regex pattern"));
+
+        let installed = parse_dpkg_query("ripgrep\t13.0.0-2\tii \nrust-ripgrep\t\tun \n");
+        assert_eq!(installed.get("ripgrep").map(String::as_str), Some("13.0.0-2"));
+        assert!(!installed.contains_key("rust-ripgrep"));
+
+        let upgradable = parse_apt_upgradable("Listing...\nripgrep/jammy-updates 13.0.0-2ubuntu0.1 amd64 [upgradable from: 13.0.0-2]\n");
+        assert!(upgradable.contains("ripgrep"));
+    }
+
+    #[test]
+    fn test_parse_pacman_search() {
+        let output = "extra/ripgrep 14.1.0-1 [installed]\n    A search tool\nextra/firefox 125.0-1 [installed: 124.0-1]\n    Web browser\nextra/ripgrep-all 0.10.6-1\n    rga\n";
+        let packages = parse_pacman_search(output);
+        assert_eq!(packages.len(), 3);
+        assert!(packages[0].installed && !packages[0].upgradable);
+        assert_eq!(packages[0].description.as_deref(), Some("A search tool"));
+        assert!(packages[1].installed && packages[1].upgradable);
+        assert_eq!(packages[1].version.as_deref(), Some("124.0-1"));
+        assert!(!packages[2].installed);
+    }
+
+    #[test]
+    fn test_parse_brew() {
+        let packages = parse_brew_search("==> Formulae\nwget\nwget2\n\n==> Casks\nwgetx\n");
+        let names: Vec<&str> = packages.iter().map(|package| package.name.as_str()).collect();
+        assert_eq!(names, vec!["wget", "wget2", "wgetx"]);
+
+        let versions = parse_brew_versions("wget 1.21.3 1.21.4\ngit 2.44.0\n");
+        assert_eq!(versions.get("wget").map(String::as_str), Some("1.21.4"));
+    }
+
+    #[test]
+    fn test_parse_winget_table() {
+        let search = "\r   - \r   \\ \rName               Id                         Version Match           Source\n\
+                      ------------------------------------------------------------------------------\n\
+                      Visual Studio Code Microsoft.VisualStudioCode 1.88.1  Moniker: vscode winget\n\
+                      Git                Git.Git                    2.44.0                  winget\n";
+        let packages = parse_winget_table(search, false);
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].name, "Visual Studio Code");
+        assert_eq!(packages[0].id, "Microsoft.VisualStudioCode");
+        assert!(!packages[0].upgradable);
+        assert_eq!(packages[1].id, "Git.Git");
+        assert_eq!(packages[1].version.as_deref(), Some("2.44.0"));
+        assert!(!packages[1].upgradable);
+
+        let list = "Name Id      Version Available Source\n\
+                    --------------------------------------\n\
+                    Git  Git.Git 2.43.0  2.44.0    winget\n";
+        let installed = parse_winget_table(list, true);
+        assert!(installed[0].upgradable);
+        assert_eq!(installed[0].version.as_deref(), Some("2.43.0"));
+    }
+
+    #[test]
+    fn test_package_command() {
+        assert_eq!(package_command(PackageManager::Apt, PackageAction::Install, "ripgrep"), vec!["sudo", "apt", "install", "ripgrep"]);
+        assert_eq!(
+            package_command(PackageManager::Winget, PackageAction::Upgrade, "Git.Git"),
+            vec!["winget", "upgrade", "--exact", "--id", "Git.Git"]
+        );
+        assert!(!is_valid_package_id("--purge"));
+        assert!(!is_valid_package_id("vim; rm -rf /"));
+        assert!(is_valid_package_id("homebrew/cask/firefox"));
+    }
+
+    #[test]
+    fn test_run_package_action_requires_confirmation() {
+        assert!(run_package_action("ripgrep".into(), PackageAction::Install, None, None).is_err());
+    }
+}
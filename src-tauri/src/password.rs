@@ -0,0 +1,328 @@
+// 密码与口令短语（diceware）生成，随机数全部来自操作系统 CSPRNG
+use rand::rngs::OsRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!@#$%^&*()-_=+[]{};:,.<>?/";
+// 容易混淆的字符
+const AMBIGUOUS: &str = "Il1O0o";
+
+const DEFAULT_LENGTH: usize = 20;
+const MIN_LENGTH: usize = 4;
+const MAX_LENGTH: usize = 256;
+
+const DEFAULT_WORDS: usize = 6;
+const MAX_WORDS: usize = 32;
+// 自定义词表的最少单词数，过小的词表无法提供足够的熵
+const MIN_WORDLIST_SIZE: usize = 16;
+
+// 生成结果
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GeneratedSecret {
+    pub value: String,
+    pub kind: String, // "password" | "passphrase"
+    pub entropy_bits: f64,
+}
+
+// 密码生成选项
+#[derive(Debug, Deserialize, Default)]
+pub struct PasswordOptions {
+    pub length: Option<usize>,
+    pub lowercase: Option<bool>,
+    pub uppercase: Option<bool>,
+    pub digits: Option<bool>,
+    pub symbols: Option<bool>,
+    pub exclude_ambiguous: Option<bool>,
+}
+
+// 口令短语生成选项
+#[derive(Debug, Deserialize, Default)]
+pub struct PassphraseOptions {
+    pub words: Option<usize>,
+    pub separator: Option<String>,
+    pub capitalize: Option<bool>,
+    pub include_number: Option<bool>,
+    // "eff-large"（默认）、"eff-short" 或自定义词表文件路径
+    pub wordlist: Option<String>,
+}
+
+#[tauri::command]
+pub fn generate_password(options: Option<PasswordOptions>) -> Result<GeneratedSecret, String> {
+    let options = options.unwrap_or_default();
+    let length = options.length.unwrap_or(DEFAULT_LENGTH);
+    if !(MIN_LENGTH..=MAX_LENGTH).contains(&length) {
+        return Err(format!("密码长度必须在 {} 到 {} 之间", MIN_LENGTH, MAX_LENGTH));
+    }
+
+    let exclude_ambiguous = options.exclude_ambiguous.unwrap_or(false);
+    let classes: Vec<Vec<char>> = [
+        (options.lowercase.unwrap_or(true), LOWERCASE),
+        (options.uppercase.unwrap_or(true), UPPERCASE),
+        (options.digits.unwrap_or(true), DIGITS),
+        (options.symbols.unwrap_or(true), SYMBOLS),
+    ]
+    .iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, chars)| {
+        chars
+            .chars()
+            .filter(|c| !exclude_ambiguous || !AMBIGUOUS.contains(*c))
+            .collect()
+    })
+    .collect();
+
+    if classes.is_empty() {
+        return Err("至少需要启用一种字符类型".to_string());
+    }
+
+    let pool: Vec<char> = classes.iter().flatten().copied().collect();
+    let mut rng = OsRng;
+
+    // 每种启用的字符类型至少出现一次，其余从全部字符中随机选取
+    let mut chars: Vec<char> = classes
+        .iter()
+        .map(|class| class[rng.gen_range(0..class.len())])
+        .collect();
+    while chars.len() < length {
+        chars.push(pool[rng.gen_range(0..pool.len())]);
+    }
+    chars.shuffle(&mut rng);
+
+    Ok(GeneratedSecret {
+        value: chars.into_iter().collect(),
+        kind: "password".to_string(),
+        entropy_bits: length as f64 * (pool.len() as f64).log2(),
+    })
+}
+
+#[tauri::command]
+pub fn generate_passphrase(options: Option<PassphraseOptions>) -> Result<GeneratedSecret, String> {
+    let options = options.unwrap_or_default();
+    let word_count = options.words.unwrap_or(DEFAULT_WORDS);
+    if word_count == 0 || word_count > MAX_WORDS {
+        return Err(format!("单词数量必须在 1 到 {} 之间", MAX_WORDS));
+    }
+
+    let wordlist = load_wordlist(options.wordlist.as_deref())?;
+    let separator = options.separator.unwrap_or_else(|| "-".to_string());
+    let capitalize = options.capitalize.unwrap_or(false);
+    let mut rng = OsRng;
+
+    let mut words: Vec<String> = (0..word_count)
+        .map(|_| {
+            let word = wordlist[rng.gen_range(0..wordlist.len())].clone();
+            if capitalize { capitalize_word(&word) } else { word }
+        })
+        .collect();
+
+    let mut entropy_bits = word_count as f64 * (wordlist.len() as f64).log2();
+
+    // 在随机位置的单词后追加一位数字
+    if options.include_number.unwrap_or(false) {
+        let index = rng.gen_range(0..words.len());
+        let digit = rng.gen_range(0..10);
+        words[index].push_str(&digit.to_string());
+        entropy_bits += (10.0 * word_count as f64).log2();
+    }
+
+    Ok(GeneratedSecret {
+        value: words.join(&separator),
+        kind: "passphrase".to_string(),
+        entropy_bits,
+    })
+}
+
+// 加载词表：内置 EFF 词表或用户指定的文件
+fn load_wordlist(wordlist: Option<&str>) -> Result<Vec<String>, String> {
+    let words: Vec<String> = match wordlist.unwrap_or("eff-large") {
+        "" | "eff-large" => eff_wordlist::large::LIST.iter().map(|(_, w)| w.to_string()).collect(),
+        "eff-short" => eff_wordlist::short::LIST.iter().map(|(_, w)| w.to_string()).collect(),
+        path => {
+            let content = fs::read_to_string(Path::new(path))
+                .map_err(|e| format!("读取词表失败 {}: {}", path, e))?;
+            parse_wordlist(&content)
+        }
+    };
+
+    if words.len() < MIN_WORDLIST_SIZE {
+        return Err(format!("词表单词过少（至少需要 {} 个）", MIN_WORDLIST_SIZE));
+    }
+
+    Ok(words)
+}
+
+// 解析词表文件，兼容 diceware 格式（"11111\tword"）和每行一个单词的格式
+fn parse_wordlist(content: &str) -> Vec<String> {
+    let mut words: Vec<String> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let first = parts.next()?;
+            match parts.next() {
+                Some(word) if first.chars().all(|c| c.is_ascii_digit()) => Some(word.to_string()),
+                _ => Some(first.to_string()),
+            }
+        })
+        .collect();
+
+    words.sort();
+    words.dedup();
+    words
+}
+
+fn capitalize_word(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_generate_password_default() {
+        let secret = generate_password(None).expect("Should generate password");
+
+        assert_eq!(secret.kind, "password");
+        assert_eq!(secret.value.chars().count(), DEFAULT_LENGTH);
+        assert!(secret.entropy_bits > 100.0);
+
+        // 默认包含所有字符类型
+        assert!(secret.value.chars().any(|c| c.is_ascii_lowercase()));
+        assert!(secret.value.chars().any(|c| c.is_ascii_uppercase()));
+        assert!(secret.value.chars().any(|c| c.is_ascii_digit()));
+        assert!(secret.value.chars().any(|c| SYMBOLS.contains(c)));
+    }
+
+    #[test]
+    fn test_generate_password_options() {
+        let options = PasswordOptions {
+            length: Some(64),
+            symbols: Some(false),
+            exclude_ambiguous: Some(true),
+            ..Default::default()
+        };
+        let secret = generate_password(Some(options)).expect("Should generate password");
+
+        assert_eq!(secret.value.len(), 64);
+        assert!(secret.value.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert!(!secret.value.chars().any(|c| AMBIGUOUS.contains(c)));
+    }
+
+    #[test]
+    fn test_generate_password_invalid_options() {
+        // 长度越界
+        let too_short = PasswordOptions { length: Some(2), ..Default::default() };
+        assert!(generate_password(Some(too_short)).is_err());
+
+        let too_long = PasswordOptions { length: Some(MAX_LENGTH + 1), ..Default::default() };
+        assert!(generate_password(Some(too_long)).is_err());
+
+        // 没有任何字符类型
+        let no_classes = PasswordOptions {
+            lowercase: Some(false),
+            uppercase: Some(false),
+            digits: Some(false),
+            symbols: Some(false),
+            ..Default::default()
+        };
+        assert!(generate_password(Some(no_classes)).is_err());
+    }
+
+    #[test]
+    fn test_generate_password_uniqueness() {
+        let a = generate_password(None).unwrap();
+        let b = generate_password(None).unwrap();
+        assert_ne!(a.value, b.value);
+    }
+
+    #[test]
+    fn test_generate_passphrase() {
+        let options = PassphraseOptions {
+            words: Some(5),
+            separator: Some(" ".to_string()),
+            capitalize: Some(true),
+            ..Default::default()
+        };
+        let secret = generate_passphrase(Some(options)).expect("Should generate passphrase");
+
+        let words: Vec<&str> = secret.value.split(' ').collect();
+        assert_eq!(secret.kind, "passphrase");
+        assert_eq!(words.len(), 5);
+        assert!(words.iter().all(|w| w.chars().next().unwrap().is_uppercase()));
+
+        // EFF 大词表每个单词约 12.9 位熵
+        assert!(secret.entropy_bits > 64.0);
+    }
+
+    #[test]
+    fn test_generate_passphrase_with_number() {
+        let options = PassphraseOptions {
+            words: Some(4),
+            include_number: Some(true),
+            wordlist: Some("eff-short".to_string()),
+            ..Default::default()
+        };
+        let secret = generate_passphrase(Some(options)).unwrap();
+        assert_eq!(secret.value.split('-').count(), 4);
+        assert_eq!(secret.value.chars().filter(|c| c.is_ascii_digit()).count(), 1);
+    }
+
+    #[test]
+    fn test_generate_passphrase_invalid_word_count() {
+        let zero = PassphraseOptions { words: Some(0), ..Default::default() };
+        assert!(generate_passphrase(Some(zero)).is_err());
+
+        let too_many = PassphraseOptions { words: Some(MAX_WORDS + 1), ..Default::default() };
+        assert!(generate_passphrase(Some(too_many)).is_err());
+    }
+
+    #[test]
+    fn test_parse_wordlist() {
+        let content = "# comment\n11111\tabacus\n11112\tabdomen\n\nplain\nplain\n";
+        let words = parse_wordlist(content);
+        assert_eq!(words, vec!["abacus", "abdomen", "plain"]);
+    }
+
+    #[test]
+    fn test_custom_wordlist() {
+        let mut file = NamedTempFile::new().expect("Failed to create temp file");
+        for i in 0..MIN_WORDLIST_SIZE {
+            writeln!(file, "word{}", i).unwrap();
+        }
+
+        let path = file.path().to_string_lossy().to_string();
+        let options = PassphraseOptions { wordlist: Some(path), ..Default::default() };
+        let secret = generate_passphrase(Some(options)).expect("Should use custom wordlist");
+        assert!(secret.value.split('-').all(|w| w.starts_with("word")));
+
+        // 过小的词表被拒绝
+        let mut small = NamedTempFile::new().unwrap();
+        writeln!(small, "only\nthree\nwords").unwrap();
+        let options = PassphraseOptions {
+            wordlist: Some(small.path().to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        assert!(generate_passphrase(Some(options)).is_err());
+
+        // 不存在的词表文件
+        let missing = PassphraseOptions {
+            wordlist: Some("/nonexistent/wordlist.txt".to_string()),
+            ..Default::default()
+        };
+        assert!(generate_passphrase(Some(missing)).is_err());
+    }
+}
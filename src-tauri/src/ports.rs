@@ -0,0 +1,287 @@
+// 端口占用：查找监听本机某个端口的进程（Linux 读取 /proc，macOS 使用 lsof，Windows 使用 netstat）
+use crate::system_command::run;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use sysinfo::{Pid, ProcessesToUpdate, System};
+
+// /proc/net/tcp 中的 LISTEN 状态；UDP 没有连接状态，未连接的套接字为 07
+const TCP_LISTEN: &str = "0A";
+const UDP_UNCONNECTED: &str = "07";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Protocol {
+    Tcp,
+    Udp,
+}
+
+// pid 为空表示没有权限查看该套接字所属的进程
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PortListener {
+    pub port: u16,
+    pub protocol: Protocol,
+    pub address: String,
+    pub pid: Option<u32>,
+    pub process: Option<String>,
+    pub exe: Option<String>,
+}
+
+#[tauri::command(async)]
+pub fn find_port_listeners(port: u16) -> Result<Vec<PortListener>, String> {
+    let mut listeners = match std::env::consts::OS {
+        "linux" => linux_listeners(port),
+        "macos" => {
+            // 没有进程占用端口时 lsof 返回 1
+            let output = run("lsof", &["-nP", &format!("-iTCP:{}", port), "-sTCP:LISTEN", "-FpcPn"]).unwrap_or_default();
+            let mut listeners = parse_lsof(&output, port);
+            let output = run("lsof", &["-nP", &format!("-iUDP:{}", port), "-FpcPn"]).unwrap_or_default();
+            listeners.extend(parse_lsof(&output, port));
+            listeners
+        }
+        "windows" => parse_netstat(&run("netstat", &["-ano"])?, port),
+        os => return Err(format!("当前系统不支持查询端口占用: {}", os)),
+    };
+
+    fill_process_info(&mut listeners);
+    listeners.sort_by_key(|listener| (listener.protocol == Protocol::Udp, listener.pid.is_none(), listener.pid));
+    listeners.dedup_by(|a, b| a.protocol == b.protocol && a.pid == b.pid && a.address == b.address);
+    Ok(listeners)
+}
+
+fn linux_listeners(port: u16) -> Vec<PortListener> {
+    let mut sockets = Vec::new();
+    for (file, protocol, state) in [
+        ("tcp", Protocol::Tcp, TCP_LISTEN),
+        ("tcp6", Protocol::Tcp, TCP_LISTEN),
+        ("udp", Protocol::Udp, UDP_UNCONNECTED),
+        ("udp6", Protocol::Udp, UDP_UNCONNECTED),
+    ] {
+        if let Ok(table) = fs::read_to_string(format!("/proc/net/{}", file)) {
+            sockets.extend(parse_proc_net(&table, protocol, state, port));
+        }
+    }
+    if sockets.is_empty() {
+        return vec![];
+    }
+
+    let owners = socket_owners(sockets.iter().map(|(_, inode)| *inode).collect());
+    sockets.into_iter()
+        .map(|(mut listener, inode)| {
+            listener.pid = owners.get(&inode).copied();
+            listener
+        })
+        .collect()
+}
+
+// 返回监听该端口的套接字和 inode
+fn parse_proc_net(table: &str, protocol: Protocol, state: &str, port: u16) -> Vec<(PortListener, u64)> {
+    table.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 || fields[3] != state {
+                return None;
+            }
+            let (address, local_port) = fields[1].split_once(':')?;
+            if u16::from_str_radix(local_port, 16).ok()? != port {
+                return None;
+            }
+            let listener = PortListener {
+                port,
+                protocol,
+                address: decode_proc_address(address)?,
+                pid: None,
+                process: None,
+                exe: None,
+            };
+            Some((listener, fields[9].parse().ok()?))
+        })
+        .collect()
+}
+
+// 地址按 32 位字以本机字节序（小端）存放
+fn decode_proc_address(hex: &str) -> Option<String> {
+    let words: Vec<u32> = (0..hex.len() / 8)
+        .map(|i| u32::from_str_radix(&hex[i * 8..i * 8 + 8], 16).map(u32::swap_bytes))
+        .collect::<Result<_, _>>()
+        .ok()?;
+    match words.as_slice() {
+        [v4] => Some(Ipv4Addr::from(*v4).to_string()),
+        [a, b, c, d] => {
+            let bytes: Vec<u8> = [a, b, c, d].iter().flat_map(|word| word.to_be_bytes()).collect();
+            let bytes: [u8; 16] = bytes.try_into().ok()?;
+            Some(Ipv6Addr::from(bytes).to_string())
+        }
+        _ => None,
+    }
+}
+
+// 通过 /proc/<pid>/fd 中指向 socket:[inode] 的链接找到进程，其他用户的进程需要 root 权限
+fn socket_owners(mut inodes: Vec<u64>) -> HashMap<u64, u32> {
+    inodes.sort_unstable();
+    let mut owners = HashMap::new();
+    let Ok(entries) = fs::read_dir("/proc") else {
+        return owners;
+    };
+    for entry in entries.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+            continue;
+        };
+        let Ok(fds) = fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        for fd in fds.flatten() {
+            let inode = fs::read_link(fd.path()).ok()
+                .and_then(|target| target.to_str()?.strip_prefix("socket:[")?.strip_suffix(']')?.parse::<u64>().ok());
+            if let Some(inode) = inode.filter(|inode| inodes.binary_search(inode).is_ok()) {
+                owners.insert(inode, pid);
+            }
+        }
+        if owners.len() == inodes.len() {
+            break;
+        }
+    }
+    owners
+}
+
+// lsof -F 输出：每个进程以 p<pid> 开始，之后是 c<命令>、每个文件的 P<协议> 和 n<地址:端口>
+fn parse_lsof(output: &str, port: u16) -> Vec<PortListener> {
+    let mut listeners = Vec::new();
+    let mut pid = None;
+    let mut command = None;
+    let mut protocol = Protocol::Tcp;
+    for line in output.lines() {
+        let (tag, value) = line.split_at(line.len().min(1));
+        match tag {
+            "p" => {
+                pid = value.parse().ok();
+                command = None;
+            }
+            "c" => command = Some(value.to_string()),
+            "P" => protocol = if value.eq_ignore_ascii_case("udp") { Protocol::Udp } else { Protocol::Tcp },
+            "n" => {
+                // 已建立的连接为 本地->远程
+                let local = value.split("->").next().unwrap_or(value);
+                let Some((address, local_port)) = local.rsplit_once(':') else {
+                    continue;
+                };
+                if local_port.parse::<u16>().ok() == Some(port) {
+                    listeners.push(PortListener {
+                        port,
+                        protocol,
+                        address: address.trim_start_matches('[').trim_end_matches(']').to_string(),
+                        pid,
+                        process: command.clone(),
+                        exe: None,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    listeners
+}
+
+// netstat -ano：TCP 行为 协议 本地地址 远程地址 状态 PID，UDP 行没有状态
+fn parse_netstat(output: &str, port: u16) -> Vec<PortListener> {
+    output.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (protocol, pid) = match fields.as_slice() {
+                [proto, _, _, state, pid] if proto.eq_ignore_ascii_case("tcp") && state.eq_ignore_ascii_case("listening") => (Protocol::Tcp, pid),
+                [proto, _, _, pid] if proto.eq_ignore_ascii_case("udp") => (Protocol::Udp, pid),
+                _ => return None,
+            };
+            let (address, local_port) = fields[1].rsplit_once(':')?;
+            if local_port.parse::<u16>().ok()? != port {
+                return None;
+            }
+            Some(PortListener {
+                port,
+                protocol,
+                address: address.trim_start_matches('[').trim_end_matches(']').to_string(),
+                pid: pid.parse().ok(),
+                process: None,
+                exe: None,
+            })
+        })
+        .collect()
+}
+
+fn fill_process_info(listeners: &mut [PortListener]) {
+    let pids: Vec<Pid> = listeners.iter().filter_map(|listener| listener.pid).map(Pid::from_u32).collect();
+    if pids.is_empty() {
+        return;
+    }
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&pids), true);
+    for listener in listeners.iter_mut() {
+        let Some(process) = listener.pid.and_then(|pid| system.process(Pid::from_u32(pid))) else {
+            continue;
+        };
+        if listener.process.is_none() {
+            listener.process = Some(process.name().to_string_lossy().to_string());
+        }
+        listener.exe = process.exe().map(|exe| exe.to_string_lossy().to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_net() {
+        let table = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 12345 1 0000000000000000 100 0 0 10 0
+   1: 00000000:1F90 0100007F:D431 01 00000000:00000000 00:00000000 00000000  1000        0 0 1 0000000000000000 20 4 30 10 -1
+   2: 00000000:0016 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 999 1 0000000000000000 100 0 0 10 0";
+        let sockets = parse_proc_net(table, Protocol::Tcp, TCP_LISTEN, 8080);
+        assert_eq!(sockets.len(), 1);
+        assert_eq!(sockets[0].0.address, "127.0.0.1");
+        assert_eq!(sockets[0].1, 12345);
+        assert_eq!(parse_proc_net(table, Protocol::Tcp, TCP_LISTEN, 22)[0].0.address, "0.0.0.0");
+    }
+
+    #[test]
+    fn test_decode_proc_address() {
+        assert_eq!(decode_proc_address("0100007F").as_deref(), Some("127.0.0.1"));
+        assert_eq!(decode_proc_address("00000000000000000000000001000000").as_deref(), Some("::1"));
+        assert_eq!(decode_proc_address("00000000000000000000000000000000").as_deref(), Some("::"));
+        assert_eq!(decode_proc_address("zz"), None);
+    }
+
+    #[test]
+    fn test_parse_lsof() {
+        let output = "p4321\ncnode\nf23\nPTCP\nn*:3000\nf24\nPTCP\nn[::1]:3000\np99\ncpython3\nf5\nPUDP\nn127.0.0.1:3000\n";
+        let listeners = parse_lsof(output, 3000);
+        assert_eq!(listeners.len(), 3);
+        assert_eq!(listeners[0].pid, Some(4321));
+        assert_eq!(listeners[0].process.as_deref(), Some("node"));
+        assert_eq!(listeners[0].address, "*");
+        assert_eq!(listeners[1].address, "::1");
+        assert_eq!(listeners[2].protocol, Protocol::Udp);
+        assert!(parse_lsof(output, 8080).is_empty());
+    }
+
+    #[test]
+    fn test_parse_netstat() {
+        let output = "
+Active Connections
+
+  Proto  Local Address          Foreign Address        State           PID
+  TCP    0.0.0.0:8080           0.0.0.0:0              LISTENING       4120
+  TCP    127.0.0.1:8080         127.0.0.1:52000        ESTABLISHED     4120
+  TCP    [::]:8080              [::]:0                 LISTENING       4120
+  UDP    0.0.0.0:8080           *:*                                    880
+  TCP    0.0.0.0:80801          0.0.0.0:0              LISTENING       1";
+        let listeners = parse_netstat(output, 8080);
+        assert_eq!(listeners.len(), 3);
+        assert_eq!(listeners[0].pid, Some(4120));
+        assert_eq!(listeners[1].address, "::");
+        assert_eq!(listeners[2].protocol, Protocol::Udp);
+        assert_eq!(listeners[2].pid, Some(880));
+    }
+}
@@ -0,0 +1,140 @@
+// 系统电源操作：锁屏、睡眠、重启、关机、注销、休眠
+use crate::system_command::command;
+use serde::{Deserialize, Serialize};
+
+// 电源操作
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PowerAction {
+    Lock,
+    Sleep,
+    Restart,
+    Shutdown,
+    Logout,
+    Hibernate,
+}
+
+impl PowerAction {
+    const ALL: [PowerAction; 6] = [
+        PowerAction::Lock,
+        PowerAction::Sleep,
+        PowerAction::Restart,
+        PowerAction::Shutdown,
+        PowerAction::Logout,
+        PowerAction::Hibernate,
+    ];
+
+    // 会丢失未保存工作的操作需要确认
+    fn requires_confirmation(self) -> bool {
+        matches!(self, PowerAction::Restart | PowerAction::Shutdown | PowerAction::Logout | PowerAction::Hibernate)
+    }
+}
+
+// 当前平台支持的操作
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PowerActionInfo {
+    pub action: PowerAction,
+    pub requires_confirmation: bool,
+}
+
+#[tauri::command]
+pub fn list_power_actions() -> Vec<PowerActionInfo> {
+    PowerAction::ALL.iter()
+        .filter(|action| command_for(**action, std::env::consts::OS).is_some())
+        .map(|action| PowerActionInfo {
+            action: *action,
+            requires_confirmation: action.requires_confirmation(),
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub fn run_power_action(action: PowerAction, confirmed: Option<bool>) -> Result<(), String> {
+    if action.requires_confirmation() && !confirmed.unwrap_or(false) {
+        return Err("该操作需要确认".to_string());
+    }
+
+    let args = command_for(action, std::env::consts::OS)
+        .ok_or_else(|| "当前系统不支持该操作".to_string())?;
+
+    let status = command(args[0])
+        .args(&args[1..])
+        .status()
+        .map_err(|e| format!("执行电源命令失败: {}", e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("电源命令执行失败: {}", status))
+    }
+}
+
+// 各平台对应的命令
+fn command_for(action: PowerAction, os: &str) -> Option<Vec<&'static str>> {
+    let command = match (os, action) {
+        // 登录窗口的锁屏快捷键 Control-Command-Q，关闭显示器在未设置立即要求密码时不会锁屏
+        ("macos", PowerAction::Lock) => vec!["osascript", "-e", "tell application \"System Events\" to keystroke \"q\" using {control down, command down}"],
+        ("macos", PowerAction::Sleep) => vec!["pmset", "sleepnow"],
+        ("macos", PowerAction::Restart) => vec!["osascript", "-e", "tell application \"System Events\" to restart"],
+        ("macos", PowerAction::Shutdown) => vec!["osascript", "-e", "tell application \"System Events\" to shut down"],
+        ("macos", PowerAction::Logout) => vec!["osascript", "-e", "tell application \"System Events\" to log out"],
+
+        ("windows", PowerAction::Lock) => vec!["rundll32.exe", "user32.dll,LockWorkStation"],
+        ("windows", PowerAction::Sleep) => vec!["rundll32.exe", "powrprof.dll,SetSuspendState", "0,1,0"],
+        ("windows", PowerAction::Restart) => vec!["shutdown", "/r", "/t", "0"],
+        ("windows", PowerAction::Shutdown) => vec!["shutdown", "/s", "/t", "0"],
+        ("windows", PowerAction::Logout) => vec!["shutdown", "/l"],
+        ("windows", PowerAction::Hibernate) => vec!["shutdown", "/h"],
+
+        ("linux", PowerAction::Lock) => vec!["loginctl", "lock-session"],
+        ("linux", PowerAction::Sleep) => vec!["systemctl", "suspend"],
+        ("linux", PowerAction::Restart) => vec!["systemctl", "reboot"],
+        ("linux", PowerAction::Shutdown) => vec!["systemctl", "poweroff"],
+        ("linux", PowerAction::Logout) => vec!["loginctl", "terminate-session", "self"],
+        ("linux", PowerAction::Hibernate) => vec!["systemctl", "hibernate"],
+
+        // macOS 没有单独的休眠命令
+        _ => return None,
+    };
+
+    Some(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_for_platforms() {
+        assert_eq!(command_for(PowerAction::Lock, "windows"), Some(vec!["rundll32.exe", "user32.dll,LockWorkStation"]));
+        assert_eq!(command_for(PowerAction::Sleep, "linux"), Some(vec!["systemctl", "suspend"]));
+        assert_eq!(command_for(PowerAction::Sleep, "macos"), Some(vec!["pmset", "sleepnow"]));
+        assert_eq!(command_for(PowerAction::Lock, "macos").unwrap()[0], "osascript");
+        assert_eq!(command_for(PowerAction::Hibernate, "macos"), None);
+        assert_eq!(command_for(PowerAction::Lock, "freebsd"), None);
+
+        // 所有操作在三个主要平台上都有实现（macOS 休眠除外）
+        for action in PowerAction::ALL {
+            assert!(command_for(action, "windows").is_some());
+            assert!(command_for(action, "linux").is_some());
+        }
+    }
+
+    #[test]
+    fn test_destructive_actions_require_confirmation() {
+        assert!(!PowerAction::Lock.requires_confirmation());
+        assert!(!PowerAction::Sleep.requires_confirmation());
+        assert!(PowerAction::Shutdown.requires_confirmation());
+
+        // 未确认时直接拒绝，不会执行命令
+        assert!(run_power_action(PowerAction::Shutdown, None).is_err());
+        assert!(run_power_action(PowerAction::Restart, Some(false)).is_err());
+    }
+
+    #[test]
+    fn test_power_action_serialization() {
+        assert_eq!(serde_json::to_string(&PowerAction::Shutdown).unwrap(), "\"shutdown\"");
+        let action: PowerAction = serde_json::from_str("\"lock\"").unwrap();
+        assert_eq!(action, PowerAction::Lock);
+    }
+}
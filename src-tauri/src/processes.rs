@@ -0,0 +1,174 @@
+// 进程搜索与结束：列出匹配的进程并提供结束/强制结束操作，保护关键系统进程
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, Mutex};
+use sysinfo::{Pid, ProcessesToUpdate, Signal, System};
+
+const MAX_RESULTS: usize = 30;
+
+// 结束后会导致系统崩溃或会话退出的进程（小写）
+const PROTECTED_PROCESSES: &[&str] = &[
+    // Linux
+    "init", "systemd", "kthreadd", "dbus-daemon", "xorg", "gnome-shell", "kwin_x11", "kwin_wayland", "plasmashell",
+    // macOS
+    "kernel_task", "launchd", "windowserver", "loginwindow", "logd", "opendirectoryd", "securityd", "dock", "finder",
+    // Windows
+    "system", "registry", "smss.exe", "csrss.exe", "wininit.exe", "winlogon.exe", "services.exe", "lsass.exe",
+    "svchost.exe", "dwm.exe", "explorer.exe", "fontdrvhost.exe", "memory compression",
+];
+
+// 复用同一个 System 实例，两次刷新之间才能计算出 CPU 占用
+static SYSTEM: LazyLock<Mutex<System>> = LazyLock::new(|| Mutex::new(System::new()));
+
+// 进程信息
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub name: String,
+    pub cpu_usage: f32, // 百分比
+    pub memory: u64,    // 字节
+    pub exe: Option<String>,
+    pub protected: bool,
+}
+
+#[tauri::command(async)]
+pub fn search_processes(query: String) -> Result<Vec<ProcessInfo>, String> {
+    let query = query.trim().to_lowercase();
+    let own_pid = std::process::id();
+
+    let mut system = SYSTEM.lock().unwrap();
+    system.refresh_processes(ProcessesToUpdate::All, true);
+
+    let mut processes: Vec<ProcessInfo> = system.processes()
+        .iter()
+        .map(|(pid, process)| {
+            let name = process.name().to_string_lossy().to_string();
+            ProcessInfo {
+                pid: pid.as_u32(),
+                protected: is_protected(&name, pid.as_u32(), own_pid),
+                name,
+                cpu_usage: process.cpu_usage(),
+                memory: process.memory(),
+                exe: process.exe().map(|exe| exe.to_string_lossy().to_string()),
+            }
+        })
+        .filter(|process| matches_query(process, &query))
+        .collect();
+
+    // 同名进程按资源占用排序，占用高的更可能是要结束的
+    processes.sort_by(|a, b| {
+        name_rank(&a.name, &query).cmp(&name_rank(&b.name, &query))
+            .then_with(|| b.cpu_usage.total_cmp(&a.cpu_usage))
+            .then_with(|| b.memory.cmp(&a.memory))
+    });
+    processes.truncate(MAX_RESULTS);
+
+    Ok(processes)
+}
+
+#[tauri::command(async)]
+pub fn kill_process(pid: u32, force: Option<bool>) -> Result<(), String> {
+    let mut system = SYSTEM.lock().unwrap();
+    system.refresh_processes(ProcessesToUpdate::Some(&[Pid::from_u32(pid)]), true);
+
+    let process = system.process(Pid::from_u32(pid))
+        .ok_or_else(|| format!("进程不存在: {}", pid))?;
+    let name = process.name().to_string_lossy().to_string();
+
+    if is_protected(&name, pid, std::process::id()) {
+        return Err(format!("不能结束受保护的进程: {} ({})", name, pid));
+    }
+
+    let sent = if force.unwrap_or(false) {
+        process.kill_with(Signal::Kill).unwrap_or_else(|| process.kill())
+    } else {
+        // Windows 不支持 SIGTERM，退回到默认的结束方式
+        process.kill_with(Signal::Term).unwrap_or_else(|| process.kill())
+    };
+
+    if sent {
+        Ok(())
+    } else {
+        Err(format!("结束进程失败: {} ({})", name, pid))
+    }
+}
+
+fn is_protected(name: &str, pid: u32, own_pid: u32) -> bool {
+    pid <= 4 || pid == own_pid || PROTECTED_PROCESSES.contains(&name.to_lowercase().as_str())
+}
+
+// 按名称或 PID 匹配
+fn matches_query(process: &ProcessInfo, query: &str) -> bool {
+    if query.is_empty() {
+        return true;
+    }
+    if query.chars().all(|c| c.is_ascii_digit()) {
+        return process.pid.to_string() == query;
+    }
+    process.name.to_lowercase().contains(query)
+}
+
+// 名称完全匹配 < 前缀匹配 < 包含
+fn name_rank(name: &str, query: &str) -> u8 {
+    let name = name.to_lowercase();
+    let stem = name.strip_suffix(".exe").unwrap_or(&name);
+    if stem == query {
+        0
+    } else if name.starts_with(query) {
+        1
+    } else {
+        2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, name: &str) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: name.to_string(),
+            cpu_usage: 0.0,
+            memory: 0,
+            exe: None,
+            protected: false,
+        }
+    }
+
+    #[test]
+    fn test_is_protected() {
+        assert!(is_protected("systemd", 1, 999));
+        assert!(is_protected("WindowServer", 300, 999));
+        assert!(is_protected("csrss.exe", 600, 999));
+        assert!(is_protected("whatever", 0, 999));
+        // 不能结束启动器自身
+        assert!(is_protected("launch-rs", 999, 999));
+        assert!(!is_protected("firefox", 1234, 999));
+    }
+
+    #[test]
+    fn test_matches_query() {
+        assert!(matches_query(&process(42, "Slack Helper"), "slack"));
+        assert!(matches_query(&process(42, "node"), ""));
+        assert!(matches_query(&process(4242, "node"), "4242"));
+        assert!(!matches_query(&process(4242, "node"), "424"));
+        assert!(!matches_query(&process(42, "node"), "python"));
+    }
+
+    #[test]
+    fn test_name_rank() {
+        assert_eq!(name_rank("chrome.exe", "chrome"), 0);
+        assert_eq!(name_rank("chrome_crashpad", "chrome"), 1);
+        assert_eq!(name_rank("Google Chrome", "chrome"), 2);
+    }
+
+    #[test]
+    fn test_search_finds_own_process_as_protected() {
+        let own_pid = std::process::id();
+        let results = search_processes(own_pid.to_string()).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].protected);
+        assert!(kill_process(own_pid, None).is_err());
+    }
+}
@@ -0,0 +1,272 @@
+// 快捷链接：用户按关键词保存网址模板，{query} 会被替换为关键词后面输入的内容
+use crate::db;
+use chrono::Local;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+const PLACEHOLDER: &str = "{query}";
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Quicklink {
+    pub id: i64,
+    pub keyword: String,
+    pub name: String,
+    pub url: String,
+    pub created_at: i64,
+}
+
+// url 为替换占位符后的网址；需要参数但还没有输入时为空
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct QuicklinkMatch {
+    pub link: Quicklink,
+    pub url: Option<String>,
+}
+
+#[tauri::command]
+pub fn list_quicklinks() -> Result<Vec<Quicklink>, String> {
+    db::with_connection(list)
+}
+
+#[tauri::command]
+pub fn add_quicklink(app_handle: AppHandle, keyword: String, name: String, url: String) -> Result<Quicklink, String> {
+    let (keyword, name, url) = validate(&keyword, &name, &url)?;
+    let link = db::with_connection(|conn| {
+        if find_by_keyword(conn, &keyword)?.is_some() {
+            return Ok(None);
+        }
+        insert(conn, &keyword, &name, &url, Local::now().timestamp()).map(Some)
+    })?
+    .ok_or_else(|| format!("关键词已存在: {}", keyword))?;
+
+    let _ = app_handle.emit("quicklinks-changed", ());
+    Ok(link)
+}
+
+#[tauri::command]
+pub fn update_quicklink(app_handle: AppHandle, id: i64, keyword: String, name: String, url: String) -> Result<Quicklink, String> {
+    let (keyword, name, url) = validate(&keyword, &name, &url)?;
+    let link = db::with_connection(|conn| {
+        if find_by_keyword(conn, &keyword)?.is_some_and(|existing| existing.id != id) {
+            return Ok(Err(format!("关键词已存在: {}", keyword)));
+        }
+        update(conn, id, &keyword, &name, &url).map(|link| link.ok_or_else(|| format!("快捷链接不存在: {}", id)))
+    })??;
+
+    let _ = app_handle.emit("quicklinks-changed", ());
+    Ok(link)
+}
+
+#[tauri::command]
+pub fn delete_quicklink(app_handle: AppHandle, id: i64) -> Result<(), String> {
+    if !db::with_connection(|conn| remove(conn, id))? {
+        return Err(format!("快捷链接不存在: {}", id));
+    }
+
+    let _ = app_handle.emit("quicklinks-changed", ());
+    Ok(())
+}
+
+// 第一个词与关键词相同时展开网址；只输入了关键词的一部分时列出候选
+#[tauri::command]
+pub fn match_quicklinks(query: String) -> Result<Vec<QuicklinkMatch>, String> {
+    let links = db::with_connection(list)?;
+    Ok(match_links(&links, &query))
+}
+
+fn validate(keyword: &str, name: &str, url: &str) -> Result<(String, String, String), String> {
+    let keyword = keyword.trim();
+    if keyword.is_empty() || keyword.contains(char::is_whitespace) {
+        return Err("关键词不能为空，也不能包含空格".to_string());
+    }
+
+    let url = url.trim();
+    let scheme = url.split_once(':').map(|(scheme, _)| scheme.to_lowercase()).unwrap_or_default();
+    let valid_scheme = !scheme.is_empty()
+        && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !matches!(scheme.as_str(), "javascript" | "data" | "file");
+    if !valid_scheme {
+        return Err(format!("无效的网址: {}", url));
+    }
+
+    let name = match name.trim() {
+        "" => keyword,
+        name => name,
+    };
+    Ok((keyword.to_string(), name.to_string(), url.to_string()))
+}
+
+fn match_links(links: &[Quicklink], query: &str) -> Vec<QuicklinkMatch> {
+    let query = query.trim();
+    let (word, argument) = match query.split_once(char::is_whitespace) {
+        Some((word, argument)) => (word, argument.trim()),
+        None => (query, ""),
+    };
+    if word.is_empty() {
+        return vec![];
+    }
+
+    let word = word.to_lowercase();
+    links.iter()
+        .filter_map(|link| {
+            let keyword = link.keyword.to_lowercase();
+            if keyword == word {
+                Some(QuicklinkMatch { link: link.clone(), url: expand(&link.url, argument) })
+            } else if argument.is_empty() && keyword.starts_with(&word) {
+                Some(QuicklinkMatch { link: link.clone(), url: expand(&link.url, "") })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// 有占位符的链接必须输入参数；没有占位符时忽略参数
+fn expand(template: &str, argument: &str) -> Option<String> {
+    if !template.contains(PLACEHOLDER) {
+        return Some(template.to_string());
+    }
+    if argument.is_empty() {
+        return None;
+    }
+    Some(template.replace(PLACEHOLDER, &percent_encode(argument)))
+}
+
+fn percent_encode(value: &str) -> String {
+    value.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn list(conn: &Connection) -> rusqlite::Result<Vec<Quicklink>> {
+    let mut stmt = conn.prepare("SELECT id, keyword, name, url, created_at FROM quicklinks ORDER BY keyword COLLATE NOCASE")?;
+    let rows = stmt.query_map([], row_to_quicklink)?;
+    rows.collect()
+}
+
+fn insert(conn: &Connection, keyword: &str, name: &str, url: &str, now: i64) -> rusqlite::Result<Quicklink> {
+    conn.execute(
+        "INSERT INTO quicklinks (keyword, name, url, created_at) VALUES (?1, ?2, ?3, ?4)",
+        params![keyword, name, url, now],
+    )?;
+    Ok(Quicklink {
+        id: conn.last_insert_rowid(),
+        keyword: keyword.to_string(),
+        name: name.to_string(),
+        url: url.to_string(),
+        created_at: now,
+    })
+}
+
+fn update(conn: &Connection, id: i64, keyword: &str, name: &str, url: &str) -> rusqlite::Result<Option<Quicklink>> {
+    conn.execute(
+        "UPDATE quicklinks SET keyword = ?1, name = ?2, url = ?3 WHERE id = ?4",
+        params![keyword, name, url, id],
+    )?;
+    get(conn, id)
+}
+
+fn remove(conn: &Connection, id: i64) -> rusqlite::Result<bool> {
+    Ok(conn.execute("DELETE FROM quicklinks WHERE id = ?1", params![id])? > 0)
+}
+
+fn get(conn: &Connection, id: i64) -> rusqlite::Result<Option<Quicklink>> {
+    conn.query_row(
+        "SELECT id, keyword, name, url, created_at FROM quicklinks WHERE id = ?1",
+        params![id],
+        row_to_quicklink,
+    )
+    .optional()
+}
+
+fn find_by_keyword(conn: &Connection, keyword: &str) -> rusqlite::Result<Option<Quicklink>> {
+    conn.query_row(
+        "SELECT id, keyword, name, url, created_at FROM quicklinks WHERE keyword = ?1",
+        params![keyword],
+        row_to_quicklink,
+    )
+    .optional()
+}
+
+fn row_to_quicklink(row: &rusqlite::Row) -> rusqlite::Result<Quicklink> {
+    Ok(Quicklink {
+        id: row.get(0)?,
+        keyword: row.get(1)?,
+        name: row.get(2)?,
+        url: row.get(3)?,
+        created_at: row.get(4)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            validate(" jira ", "", "https://jira.example.com/browse/{query}").unwrap(),
+            ("jira".to_string(), "jira".to_string(), "https://jira.example.com/browse/{query}".to_string())
+        );
+        assert_eq!(validate("slack", "Slack", "slack://open").unwrap().1, "Slack");
+        assert!(validate("my link", "", "https://example.com").is_err());
+        assert!(validate("", "", "https://example.com").is_err());
+        assert!(validate("x", "", "example.com").is_err());
+        assert!(validate("x", "", "javascript:alert(1)").is_err());
+        assert!(validate("x", "", "file:///etc/passwd").is_err());
+    }
+
+    #[test]
+    fn test_crud() {
+        let conn = db::open_in_memory();
+        let jira = insert(&conn, "jira", "Jira", "https://jira.example.com/browse/{query}", 100).unwrap();
+        let wiki = insert(&conn, "Wiki", "Wiki", "https://wiki.example.com", 100).unwrap();
+
+        // 关键词不区分大小写且唯一
+        assert!(insert(&conn, "JIRA", "Jira", "https://example.com", 100).is_err());
+        assert_eq!(find_by_keyword(&conn, "wiki").unwrap().map(|link| link.id), Some(wiki.id));
+
+        let updated = update(&conn, jira.id, "j", "Jira", "https://jira.example.com/browse/{query}").unwrap().unwrap();
+        assert_eq!(updated.keyword, "j");
+        assert!(update(&conn, 9999, "x", "x", "https://example.com").unwrap().is_none());
+
+        assert!(remove(&conn, wiki.id).unwrap());
+        assert!(!remove(&conn, wiki.id).unwrap());
+        assert_eq!(list(&conn).unwrap(), vec![updated]);
+    }
+
+    #[test]
+    fn test_match_links() {
+        let link = |id, keyword: &str, url: &str| Quicklink {
+            id,
+            keyword: keyword.to_string(),
+            name: keyword.to_string(),
+            url: url.to_string(),
+            created_at: 0,
+        };
+        let links = vec![
+            link(1, "jira", "https://jira.example.com/browse/{query}"),
+            link(2, "jenkins", "https://ci.example.com"),
+            link(3, "g", "https://www.google.com/search?q={query}"),
+        ];
+
+        let matches = match_links(&links, "jira ABC-123");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].url.as_deref(), Some("https://jira.example.com/browse/ABC-123"));
+
+        let matches = match_links(&links, "g rust vec&co");
+        assert_eq!(matches[0].url.as_deref(), Some("https://www.google.com/search?q=rust%20vec%26co"));
+
+        // 只输入关键词的一部分时列出候选，需要参数的链接没有网址
+        let matches = match_links(&links, "J");
+        assert_eq!(matches.iter().map(|m| m.link.id).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(matches[0].url, None);
+        assert_eq!(matches[1].url.as_deref(), Some("https://ci.example.com"));
+
+        assert!(match_links(&links, "je build").is_empty());
+        assert!(match_links(&links, "  ").is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+// 正则表达式测试：编译模式并返回在样本文本中的匹配和捕获组
+use regex::RegexBuilder;
+use serde::{Deserialize, Serialize};
+
+// 最多返回的匹配数，超出时只计数
+const MAX_MATCHES: usize = 100;
+// 限制编译后的大小，避免超大的重复次数拖慢界面
+const SIZE_LIMIT: usize = 1 << 20;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RegexGroup {
+    pub index: usize,
+    pub name: Option<String>,
+    // 未参与匹配的可选组为空
+    pub text: Option<String>,
+}
+
+// start 和 end 为字符位置（不是字节位置）
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RegexMatch {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+    pub line: usize,
+    pub groups: Vec<RegexGroup>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RegexTestResult {
+    pub total: usize,
+    pub matches: Vec<RegexMatch>,
+}
+
+#[tauri::command]
+pub fn test_regex(pattern: String, sample: String, case_insensitive: bool) -> Result<RegexTestResult, String> {
+    if pattern.is_empty() {
+        return Err("正则表达式不能为空".to_string());
+    }
+    let regex = RegexBuilder::new(&pattern)
+        .case_insensitive(case_insensitive)
+        .multi_line(true)
+        .size_limit(SIZE_LIMIT)
+        .build()
+        .map_err(|e| format!("正则表达式无效: {}", e))?;
+
+    let names: Vec<Option<&str>> = regex.capture_names().collect();
+    let char_index = |byte: usize| sample[..byte].chars().count();
+
+    let mut total = 0;
+    let mut matches = Vec::new();
+    for captures in regex.captures_iter(&sample) {
+        total += 1;
+        if matches.len() >= MAX_MATCHES {
+            continue;
+        }
+        let whole = captures.get(0).expect("第 0 组总是存在");
+        let groups = (1..captures.len())
+            .map(|index| RegexGroup {
+                index,
+                name: names[index].map(str::to_string),
+                text: captures.get(index).map(|group| group.as_str().to_string()),
+            })
+            .collect();
+        matches.push(RegexMatch {
+            start: char_index(whole.start()),
+            end: char_index(whole.end()),
+            text: whole.as_str().to_string(),
+            line: sample[..whole.start()].matches('\n').count() + 1,
+            groups,
+        });
+    }
+
+    Ok(RegexTestResult { total, matches })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_and_groups() {
+        let result = test_regex(r"(?P<key>\w+)=(\d+)?".to_string(), "a=1 b= é=3".to_string(), false).unwrap();
+        assert_eq!(result.total, 3);
+        assert_eq!(result.matches[0].text, "a=1");
+        assert_eq!(result.matches[0].groups[0], RegexGroup { index: 1, name: Some("key".to_string()), text: Some("a".to_string()) });
+        assert_eq!(result.matches[1].groups[1], RegexGroup { index: 2, name: None, text: None });
+        // 字符位置，é 只算一个字符
+        assert_eq!((result.matches[2].start, result.matches[2].end), (7, 10));
+    }
+
+    #[test]
+    fn test_flags_and_lines() {
+        let result = test_regex("^foo".to_string(), "Foo\nbar\nfoo".to_string(), true).unwrap();
+        assert_eq!(result.total, 2);
+        assert_eq!(result.matches[1].line, 3);
+        assert_eq!(test_regex("^foo".to_string(), "Foo".to_string(), false).unwrap().total, 0);
+    }
+
+    #[test]
+    fn test_invalid_pattern_and_limit() {
+        assert!(test_regex("(abc".to_string(), "abc".to_string(), false).unwrap_err().starts_with("正则表达式无效"));
+        assert!(test_regex(String::new(), "abc".to_string(), false).is_err());
+
+        let result = test_regex("a".to_string(), "a".repeat(150), false).unwrap();
+        assert_eq!(result.total, 150);
+        assert_eq!(result.matches.len(), MAX_MATCHES);
+    }
+}
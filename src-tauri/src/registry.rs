@@ -0,0 +1,189 @@
+// 包仓库：在 crates.io 和 npm 中按名称查找包，显示最新版本和简介
+use crate::http::{self, RateLimiter, TtlCache};
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+const CRATES_API: &str = "https://crates.io/api/v1/crates";
+const NPM_SEARCH_API: &str = "https://registry.npmjs.org/-/v1/search";
+const PER_PAGE: &str = "10";
+
+static CACHE: LazyLock<TtlCache<Vec<RegistryPackage>>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(5 * 60)));
+// crates.io 要求每秒最多一次请求
+static CRATES_LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(1, Duration::from_secs(1)));
+static NPM_LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(10, Duration::from_secs(10)));
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Registry {
+    Crates,
+    Npm,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RegistryPackage {
+    pub registry: Registry,
+    pub name: String,
+    pub version: String,
+    pub description: Option<String>,
+    pub url: String,
+    pub downloads: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CratesResponse {
+    crates: Vec<Crate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Crate {
+    name: String,
+    max_stable_version: Option<String>,
+    max_version: String,
+    description: Option<String>,
+    downloads: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmResponse {
+    objects: Vec<NpmObject>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmObject {
+    package: NpmPackage,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmPackage {
+    name: String,
+    version: String,
+    description: Option<String>,
+}
+
+// registry 为空时同时查找两个仓库；某个仓库出错时仍返回另一个的结果
+#[tauri::command]
+pub async fn search_package_registry(query: String, registry: Option<Registry>) -> Result<Vec<RegistryPackage>, String> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let registries = match registry {
+        Some(registry) => vec![registry],
+        None => vec![Registry::Crates, Registry::Npm],
+    };
+    let mut packages = Vec::new();
+    let mut errors = Vec::new();
+    for registry in registries {
+        let key = format!("{:?}:{}", registry, query);
+        let result = match registry {
+            Registry::Crates => CACHE.get_or_fetch_async(&key, || search_crates(&query)).await,
+            Registry::Npm => CACHE.get_or_fetch_async(&key, || search_npm(&query)).await,
+        };
+        match result {
+            Ok(found) => packages.extend(found),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if packages.is_empty() && !errors.is_empty() {
+        return Err(errors.join("；"));
+    }
+    // 名称完全匹配的排在最前面
+    packages.sort_by_key(|package| package.name.to_lowercase() != query);
+    Ok(packages)
+}
+
+async fn search_crates(query: &str) -> Result<Vec<RegistryPackage>, String> {
+    CRATES_LIMITER.acquire("crates.io")?;
+    let response = http::client()?
+        .get(CRATES_API)
+        .query(&[("q", query), ("per_page", PER_PAGE)])
+        .send()
+        .await
+        .map_err(|e| format!("请求 crates.io 失败: {}", e))?;
+    let body = http::check_status(response, "crates.io").await?
+        .text()
+        .await
+        .map_err(|e| format!("读取 crates.io 响应失败: {}", e))?;
+    parse_crates(&body)
+}
+
+async fn search_npm(query: &str) -> Result<Vec<RegistryPackage>, String> {
+    NPM_LIMITER.acquire("npm")?;
+    let response = http::client()?
+        .get(NPM_SEARCH_API)
+        .query(&[("text", query), ("size", PER_PAGE)])
+        .send()
+        .await
+        .map_err(|e| format!("请求 npm 失败: {}", e))?;
+    let body = http::check_status(response, "npm").await?
+        .text()
+        .await
+        .map_err(|e| format!("读取 npm 响应失败: {}", e))?;
+    parse_npm(&body)
+}
+
+// 只有预发布版本时使用 max_version
+fn parse_crates(body: &str) -> Result<Vec<RegistryPackage>, String> {
+    let response: CratesResponse = serde_json::from_str(body).map_err(|e| format!("解析 crates.io 响应失败: {}", e))?;
+    Ok(response.crates.into_iter()
+        .map(|krate| RegistryPackage {
+            registry: Registry::Crates,
+            url: format!("https://crates.io/crates/{}", krate.name),
+            version: krate.max_stable_version.unwrap_or(krate.max_version),
+            description: krate.description.map(|description| description.trim().to_string()).filter(|description| !description.is_empty()),
+            downloads: krate.downloads,
+            name: krate.name,
+        })
+        .collect())
+}
+
+fn parse_npm(body: &str) -> Result<Vec<RegistryPackage>, String> {
+    let response: NpmResponse = serde_json::from_str(body).map_err(|e| format!("解析 npm 响应失败: {}", e))?;
+    Ok(response.objects.into_iter()
+        .map(|object| RegistryPackage {
+            registry: Registry::Npm,
+            url: format!("https://www.npmjs.com/package/{}", object.package.name),
+            version: object.package.version,
+            description: object.package.description.filter(|description| !description.is_empty()),
+            downloads: None,
+            name: object.package.name,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_crates() {
+        let body = r#"{"crates": [
+            {"id": "serde", "name": "serde", "max_version": "1.0.203", "max_stable_version": "1.0.203",
+             "description": "A generic serialization/deserialization framework\n", "downloads": 300000000},
+            {"id": "serde-next", "name": "serde-next", "max_version": "0.2.0-alpha.1", "max_stable_version": null,
+             "description": null, "downloads": 12}
+        ], "meta": {"total": 2}}"#;
+        let packages = parse_crates(body).unwrap();
+        assert_eq!(packages[0].version, "1.0.203");
+        assert_eq!(packages[0].description.as_deref(), Some("A generic serialization/deserialization framework"));
+        assert_eq!(packages[0].url, "https://crates.io/crates/serde");
+        assert_eq!(packages[1].version, "0.2.0-alpha.1");
+        assert_eq!(packages[1].description, None);
+    }
+
+    #[test]
+    fn test_parse_npm() {
+        let body = r#"{"objects": [
+            {"package": {"name": "@vue/reactivity", "version": "3.4.27", "description": "reactivity", "links": {"npm": "https://www.npmjs.com/package/@vue/reactivity"}}, "score": {"final": 0.9}},
+            {"package": {"name": "vue", "version": "3.4.27"}}
+        ], "total": 2}"#;
+        let packages = parse_npm(body).unwrap();
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].url, "https://www.npmjs.com/package/@vue/reactivity");
+        assert_eq!(packages[1].description, None);
+        assert!(parse_npm("<html>").is_err());
+    }
+}
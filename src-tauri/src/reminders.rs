@@ -0,0 +1,506 @@
+// 提醒：解析自然语言时间，保存到数据库，到期后通过系统通知送达（应用重启后依然有效）
+use crate::{db, notifications, timers};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Weekday};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+// 只写日期时的默认提醒时间
+const DEFAULT_HOUR: u32 = 9;
+// "tonight" 对应的时间
+const TONIGHT_HOUR: u32 = 20;
+// 列表中保留已送达提醒的时间（秒），便于稍后提醒
+const DELIVERED_RETENTION_SECS: i64 = 24 * 60 * 60;
+
+const REMINDER_PREFIXES: &[&str] = &["remind me", "remind", "提醒我"];
+// 只写了时间没有内容时的提醒内容
+pub(crate) const DEFAULT_MESSAGE: &str = "提醒";
+
+// 提醒
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Reminder {
+    pub id: i64,
+    pub message: String,
+    pub due_at: i64, // 秒级时间戳
+    pub created_at: i64,
+    pub delivered: bool,
+}
+
+// 解析预览（尚未保存）
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ParsedReminder {
+    pub message: String,
+    pub due_at: i64,
+    pub due_text: String,
+}
+
+#[tauri::command]
+pub fn preview_reminder(text: String) -> Result<ParsedReminder, String> {
+    let (due, message) = parse_reminder(&text, Local::now().naive_local())?;
+    let due_at = to_timestamp(due)?;
+
+    Ok(ParsedReminder {
+        message,
+        due_at,
+        due_text: due.format("%Y-%m-%d %H:%M (%a)").to_string(),
+    })
+}
+
+#[tauri::command]
+pub fn add_reminder(app_handle: AppHandle, text: String) -> Result<Reminder, String> {
+    let parsed = preview_reminder(text)?;
+    let reminder = db::with_connection(|conn| {
+        insert_reminder(conn, &parsed.message, parsed.due_at, Local::now().timestamp())
+    })?;
+
+    let _ = app_handle.emit("reminders-changed", ());
+    Ok(reminder)
+}
+
+#[tauri::command]
+pub fn list_reminders() -> Result<Vec<Reminder>, String> {
+    db::with_connection(|conn| list_active(conn, Local::now().timestamp()))
+}
+
+#[tauri::command]
+pub fn delete_reminder(app_handle: AppHandle, id: i64) -> Result<(), String> {
+    let deleted = db::with_connection(|conn| {
+        conn.execute("DELETE FROM reminders WHERE id = ?1", params![id])
+    })?;
+    if deleted == 0 {
+        return Err(format!("提醒不存在: {}", id));
+    }
+
+    let _ = app_handle.emit("reminders-changed", ());
+    Ok(())
+}
+
+#[tauri::command]
+pub fn snooze_reminder(app_handle: AppHandle, id: i64, minutes: Option<i64>) -> Result<Reminder, String> {
+    let minutes = minutes.unwrap_or(10).clamp(1, 24 * 60);
+    let due_at = Local::now().timestamp() + minutes * 60;
+
+    let reminder = db::with_connection(|conn| snooze(conn, id, due_at))?
+        .ok_or_else(|| format!("提醒不存在: {}", id))?;
+
+    let _ = app_handle.emit("reminders-changed", ());
+    Ok(reminder)
+}
+
+// 由调度器定期调用：送达所有到期提醒（包括应用关闭期间到期的）
+pub(crate) fn deliver_due(app_handle: &AppHandle) {
+    let due = db::with_connection(|conn| take_due(conn, Local::now().timestamp()));
+
+    match due {
+        Ok(reminders) if !reminders.is_empty() => {
+            for reminder in &reminders {
+                let _ = notifications::notify(app_handle, "🔔 提醒", &reminder.message, true);
+                let _ = app_handle.emit("reminder-due", reminder);
+            }
+            let _ = app_handle.emit("reminders-changed", ());
+        }
+        Ok(_) => {}
+        Err(e) => log::error!("检查到期提醒失败: {}", e),
+    }
+}
+
+pub(crate) fn insert_reminder(conn: &Connection, message: &str, due_at: i64, now: i64) -> rusqlite::Result<Reminder> {
+    conn.execute(
+        "INSERT INTO reminders (message, due_at, created_at) VALUES (?1, ?2, ?3)",
+        params![message, due_at, now],
+    )?;
+
+    Ok(Reminder {
+        id: conn.last_insert_rowid(),
+        message: message.to_string(),
+        due_at,
+        created_at: now,
+        delivered: false,
+    })
+}
+
+// 未送达的提醒以及最近送达的提醒
+fn list_active(conn: &Connection, now: i64) -> rusqlite::Result<Vec<Reminder>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, message, due_at, created_at, delivered FROM reminders
+         WHERE delivered = 0 OR due_at >= ?1
+         ORDER BY delivered ASC, due_at ASC",
+    )?;
+    let rows = stmt.query_map(params![now - DELIVERED_RETENTION_SECS], row_to_reminder)?;
+    rows.collect()
+}
+
+// 取出到期未送达的提醒并标记为已送达
+fn take_due(conn: &Connection, now: i64) -> rusqlite::Result<Vec<Reminder>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, message, due_at, created_at, delivered FROM reminders
+         WHERE delivered = 0 AND due_at <= ?1 ORDER BY due_at ASC",
+    )?;
+    let due: Vec<Reminder> = stmt.query_map(params![now], row_to_reminder)?
+        .collect::<rusqlite::Result<_>>()?;
+
+    for reminder in &due {
+        conn.execute("UPDATE reminders SET delivered = 1 WHERE id = ?1", params![reminder.id])?;
+    }
+
+    Ok(due)
+}
+
+fn snooze(conn: &Connection, id: i64, due_at: i64) -> rusqlite::Result<Option<Reminder>> {
+    conn.execute(
+        "UPDATE reminders SET due_at = ?1, delivered = 0 WHERE id = ?2",
+        params![due_at, id],
+    )?;
+    conn.query_row(
+        "SELECT id, message, due_at, created_at, delivered FROM reminders WHERE id = ?1",
+        params![id],
+        row_to_reminder,
+    )
+    .optional()
+}
+
+fn row_to_reminder(row: &rusqlite::Row) -> rusqlite::Result<Reminder> {
+    Ok(Reminder {
+        id: row.get(0)?,
+        message: row.get(1)?,
+        due_at: row.get(2)?,
+        created_at: row.get(3)?,
+        delivered: row.get::<_, i64>(4)? != 0,
+    })
+}
+
+pub(crate) fn to_timestamp(datetime: NaiveDateTime) -> Result<i64, String> {
+    Local.from_local_datetime(&datetime)
+        .earliest()
+        .map(|local| local.timestamp())
+        .ok_or_else(|| "提醒时间在本地时区中不存在".to_string())
+}
+
+// 解析 "remind me friday 9am standup"、"in 10 minutes to stretch"、"call mom tomorrow at 6pm"
+pub(crate) fn parse_reminder(text: &str, now: NaiveDateTime) -> Result<(NaiveDateTime, String), String> {
+    let mut text = text.trim();
+    for prefix in REMINDER_PREFIXES {
+        if text.to_lowercase().starts_with(prefix) {
+            text = text[prefix.len()..].trim_start();
+            break;
+        }
+    }
+
+    let original: Vec<&str> = text.split_whitespace().collect();
+    let lower: Vec<String> = original.iter().map(|t| t.to_lowercase()).collect();
+    let lower: Vec<&str> = lower.iter().map(String::as_str).collect();
+
+    // 时间在开头：取最长的可解析前缀；否则时间在末尾：取最长的可解析后缀
+    let parsed = (1..=lower.len())
+        .rev()
+        .find_map(|k| parse_when(&lower[..k], now).map(|due| (due, original[k..].join(" "))))
+        .or_else(|| {
+            (1..lower.len())
+                .find_map(|k| parse_when(&lower[k..], now).map(|due| (due, original[..k].join(" "))))
+        });
+
+    let (due, message) = parsed.ok_or_else(|| format!("无法识别提醒时间: {}", text))?;
+    if due <= now {
+        return Err("提醒时间已经过去".to_string());
+    }
+
+    let message = message.strip_prefix("to ").unwrap_or(&message).trim().to_string();
+    let message = if message.is_empty() || message == "to" { DEFAULT_MESSAGE.to_string() } else { message };
+    Ok((due, message))
+}
+
+// 所有词都必须被识别为时间表达式
+fn parse_when(tokens: &[&str], now: NaiveDateTime) -> Option<NaiveDateTime> {
+    if tokens.is_empty() {
+        return None;
+    }
+
+    // 相对时间："in 10 minutes"、"in 1h30m"
+    if tokens[0] == "in" {
+        let duration = timers::parse_duration_token(&tokens[1..].concat())?;
+        if duration.is_zero() {
+            return None;
+        }
+        // 超出可表示的日期范围时视为无法识别
+        return now.checked_add_signed(Duration::from_std(duration).ok()?);
+    }
+
+    let mut date: Option<(NaiveDate, i64)> = None;
+    let mut time: Option<NaiveTime> = None;
+    let mut default_time = NaiveTime::from_hms_opt(DEFAULT_HOUR, 0, 0)?;
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match tokens[i] {
+            "on" => {
+                i += 1;
+                continue;
+            }
+            // "at 9" 中的裸数字视为小时
+            "at" => {
+                let (parsed, consumed) = parse_time(&tokens[i + 1..], true)?;
+                if time.replace(parsed).is_some() {
+                    return None;
+                }
+                i += 1 + consumed;
+                continue;
+            }
+            _ => {}
+        }
+
+        if date.is_none() {
+            if let Some((day, consumed, day_time, rollover)) = parse_day(&tokens[i..], now.date()) {
+                date = Some((day, rollover));
+                if let Some(day_time) = day_time {
+                    default_time = day_time;
+                }
+                i += consumed;
+                continue;
+            }
+        }
+
+        if time.is_none() {
+            if let Some((parsed, consumed)) = parse_time(&tokens[i..], false) {
+                time = Some(parsed);
+                i += consumed;
+                continue;
+            }
+        }
+
+        return None;
+    }
+
+    match (date, time) {
+        (Some((day, rollover_days)), time) => {
+            let due = day.and_time(time.unwrap_or(default_time));
+            // 例如周五上午说 "friday 9am"，时间已过则顺延到下周
+            if due <= now && rollover_days > 0 {
+                Some(due + Duration::days(rollover_days))
+            } else {
+                Some(due)
+            }
+        }
+        (None, Some(time)) => {
+            let today = now.date().and_time(time);
+            Some(if today > now { today } else { today + Duration::days(1) })
+        }
+        (None, None) => None,
+    }
+}
+
+// 解析日期，返回 (日期, 消耗的词数, 默认时间, 已过期时顺延的天数)
+fn parse_day(tokens: &[&str], today: NaiveDate) -> Option<(NaiveDate, usize, Option<NaiveTime>, i64)> {
+    let first = *tokens.first()?;
+
+    match first {
+        "today" | "今天" => return Some((today, 1, None, 0)),
+        "tonight" | "今晚" => return Some((today, 1, NaiveTime::from_hms_opt(TONIGHT_HOUR, 0, 0), 0)),
+        "tomorrow" | "tmr" | "明天" => return Some((today + Duration::days(1), 1, None, 0)),
+        "后天" => return Some((today + Duration::days(2), 1, None, 0)),
+        _ => {}
+    }
+
+    // "next friday" 跳过今天
+    let (weekday_token, consumed, skip_today) = if first == "next" {
+        (*tokens.get(1)?, 2, true)
+    } else {
+        (first, 1, false)
+    };
+    if let Some(weekday) = parse_weekday(weekday_token) {
+        let mut days_ahead = (weekday.num_days_from_monday() as i64 + 7
+            - today.weekday().num_days_from_monday() as i64) % 7;
+        if days_ahead == 0 && skip_today {
+            days_ahead = 7;
+        }
+        return Some((today + Duration::days(days_ahead), consumed, None, 7));
+    }
+
+    ["%Y-%m-%d", "%Y/%m/%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(first, format).ok())
+        .map(|date| (date, 1, None, 0))
+}
+
+fn parse_weekday(token: &str) -> Option<Weekday> {
+    match token {
+        "monday" | "mon" => Some(Weekday::Mon),
+        "tuesday" | "tue" | "tues" => Some(Weekday::Tue),
+        "wednesday" | "wed" => Some(Weekday::Wed),
+        "thursday" | "thu" | "thur" | "thurs" => Some(Weekday::Thu),
+        "friday" | "fri" => Some(Weekday::Fri),
+        "saturday" | "sat" => Some(Weekday::Sat),
+        "sunday" | "sun" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+// 解析时间，返回 (时间, 消耗的词数)；allow_bare_hour 为真时接受 "9" 这样的裸数字
+fn parse_time(tokens: &[&str], allow_bare_hour: bool) -> Option<(NaiveTime, usize)> {
+    let first = *tokens.first()?;
+
+    match first {
+        "noon" | "中午" => return Some((NaiveTime::from_hms_opt(12, 0, 0)?, 1)),
+        "midnight" => return Some((NaiveTime::from_hms_opt(0, 0, 0)?, 1)),
+        _ => {}
+    }
+
+    // "9 am" 这种分开写的情况
+    let (text, consumed) = match tokens.get(1) {
+        Some(&suffix) if suffix == "am" || suffix == "pm" => (format!("{}{}", first, suffix), 2),
+        _ => (first.to_string(), 1),
+    };
+
+    let (clock, meridiem) = if let Some(clock) = text.strip_suffix("am") {
+        (clock, Some(false))
+    } else if let Some(clock) = text.strip_suffix("pm") {
+        (clock, Some(true))
+    } else {
+        (text.as_str(), None)
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((h, m)) => (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?),
+        // 没有冒号也没有 am/pm 的数字需要明确允许
+        None if meridiem.is_some() || allow_bare_hour => (clock.parse::<u32>().ok()?, 0),
+        None => return None,
+    };
+
+    let hour = match meridiem {
+        Some(pm) if (1..=12).contains(&hour) => (hour % 12) + if pm { 12 } else { 0 },
+        Some(_) => return None,
+        None => hour,
+    };
+
+    NaiveTime::from_hms_opt(hour, minute, 0).map(|time| (time, consumed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-05-29 周三 10:00
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 5, 29).unwrap().and_hms_opt(10, 0, 0).unwrap()
+    }
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap().and_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_reminder_weekday() {
+        let (due, message) = parse_reminder("remind me friday 9am standup", now()).unwrap();
+        assert_eq!(due, at(31, 9, 0));
+        assert_eq!(message, "standup");
+
+        // 今天是周三，上午已过 9 点，顺延到下周三
+        let (due, _) = parse_reminder("wednesday 9am review", now()).unwrap();
+        assert_eq!(due, NaiveDate::from_ymd_opt(2024, 6, 5).unwrap().and_hms_opt(9, 0, 0).unwrap());
+
+        // next 跳过今天
+        let (due, _) = parse_reminder("next wed 3pm sync", now()).unwrap();
+        assert_eq!(due.date(), NaiveDate::from_ymd_opt(2024, 6, 5).unwrap());
+    }
+
+    #[test]
+    fn test_parse_reminder_relative() {
+        let (due, message) = parse_reminder("in 10 minutes to stretch", now()).unwrap();
+        assert_eq!(due, at(29, 10, 10));
+        assert_eq!(message, "stretch");
+
+        let (due, _) = parse_reminder("remind me in 1h30m", now()).unwrap();
+        assert_eq!(due, at(29, 11, 30));
+
+        let (due, _) = parse_reminder("in 2 days pay rent", now()).unwrap();
+        assert_eq!(due, at(31, 10, 0));
+    }
+
+    #[test]
+    fn test_parse_reminder_time_at_end() {
+        let (due, message) = parse_reminder("call mom tomorrow at 6pm", now()).unwrap();
+        assert_eq!(due, at(30, 18, 0));
+        assert_eq!(message, "call mom");
+
+        let (due, message) = parse_reminder("Buy Milk at 9", now()).unwrap();
+        assert_eq!(due, at(30, 9, 0));
+        assert_eq!(message, "Buy Milk");
+    }
+
+    #[test]
+    fn test_parse_reminder_day_and_time_forms() {
+        // 只有日期使用默认时间
+        assert_eq!(parse_reminder("tomorrow dentist", now()).unwrap().0, at(30, DEFAULT_HOUR, 0));
+        assert_eq!(parse_reminder("tonight gym", now()).unwrap().0, at(29, TONIGHT_HOUR, 0));
+
+        // 24 小时制与分开写的 am/pm
+        assert_eq!(parse_reminder("14:30 meeting", now()).unwrap().0, at(29, 14, 30));
+        assert_eq!(parse_reminder("today 5 pm leave", now()).unwrap().0, at(29, 17, 0));
+        assert_eq!(parse_reminder("12am backup", now()).unwrap().0, at(30, 0, 0));
+
+        // 只有时间且已过去，顺延到明天
+        assert_eq!(parse_reminder("8am run", now()).unwrap().0, at(30, 8, 0));
+
+        // 绝对日期
+        let (due, message) = parse_reminder("2024-06-15 noon lunch", now()).unwrap();
+        assert_eq!(due, NaiveDate::from_ymd_opt(2024, 6, 15).unwrap().and_hms_opt(12, 0, 0).unwrap());
+        assert_eq!(message, "lunch");
+
+        // 中文
+        assert_eq!(parse_reminder("提醒我 明天 开会", now()).unwrap().1, "开会");
+    }
+
+    #[test]
+    fn test_parse_reminder_invalid() {
+        assert!(parse_reminder("remind me something", now()).is_err());
+        assert!(parse_reminder("", now()).is_err());
+        assert!(parse_reminder("today 8am", now()).is_err()); // 已经过去
+        assert!(parse_reminder("25:00 nothing", now()).is_err());
+        assert!(parse_reminder("13pm nothing", now()).is_err());
+        assert!(parse_reminder("in 99999999w", now()).is_err());
+    }
+
+    #[test]
+    fn test_parse_reminder_default_message() {
+        let (_, message) = parse_reminder("remind me in 5m", now()).unwrap();
+        assert_eq!(message, DEFAULT_MESSAGE);
+    }
+
+    #[test]
+    fn test_reminder_storage() {
+        let conn = db::open_in_memory();
+        let now = 1_000_000;
+
+        let first = insert_reminder(&conn, "standup", now + 60, now).unwrap();
+        let second = insert_reminder(&conn, "lunch", now + 3600, now).unwrap();
+        assert_ne!(first.id, second.id);
+
+        let active = list_active(&conn, now).unwrap();
+        assert_eq!(active.len(), 2);
+        assert_eq!(active[0].message, "standup");
+
+        // 到期后只送达一次
+        let due = take_due(&conn, now + 120).unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, first.id);
+        assert!(take_due(&conn, now + 120).unwrap().is_empty());
+
+        // 已送达的提醒仍在列表中，可以稍后提醒
+        let active = list_active(&conn, now + 120).unwrap();
+        assert!(active.iter().any(|r| r.id == first.id && r.delivered));
+
+        let snoozed = snooze(&conn, first.id, now + 600).unwrap().expect("Reminder should exist");
+        assert!(!snoozed.delivered);
+        assert_eq!(snoozed.due_at, now + 600);
+        assert!(snooze(&conn, 9999, now).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_overdue_reminders_delivered_after_restart() {
+        let conn = db::open_in_memory();
+        // 应用关闭期间到期的提醒在下次检查时送达
+        insert_reminder(&conn, "missed", 100, 0).unwrap();
+        let due = take_due(&conn, 10_000).unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].message, "missed");
+    }
+}
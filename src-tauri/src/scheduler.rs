@@ -0,0 +1,43 @@
+// 后台定时任务：每个任务在独立线程中按固定间隔执行
+use std::sync::{LazyLock, Mutex};
+use std::thread;
+use std::time::Duration;
+use tauri::AppHandle;
+
+type JobFn = Box<dyn Fn(&AppHandle) + Send + Sync>;
+
+struct Job {
+    name: &'static str,
+    interval: Duration,
+    run: JobFn,
+}
+
+static JOBS: LazyLock<Mutex<Vec<Job>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+// 注册周期任务，启动后立即执行一次
+pub(crate) fn every<F>(name: &'static str, interval: Duration, run: F)
+where
+    F: Fn(&AppHandle) + Send + Sync + 'static,
+{
+    JOBS.lock().unwrap().push(Job { name, interval, run: Box::new(run) });
+}
+
+// 启动所有已注册的任务，耗时的任务（如抓取订阅源）不会推迟其他任务
+pub(crate) fn start(app_handle: AppHandle) {
+    let jobs = std::mem::take(&mut *JOBS.lock().unwrap());
+    for job in jobs {
+        let app_handle = app_handle.clone();
+        thread::spawn(move || loop {
+            run_job(&job, &app_handle);
+            thread::sleep(job.interval);
+        });
+    }
+}
+
+fn run_job(job: &Job, app_handle: &AppHandle) {
+    // 单个任务 panic 不应终止任务线程
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (job.run)(app_handle)));
+    if result.is_err() {
+        log::error!("定时任务执行失败: {}", job.name);
+    }
+}
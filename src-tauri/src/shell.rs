@@ -0,0 +1,219 @@
+// 运行 shell 命令：逐行通过事件推送标准输出和标准错误，或者在终端中运行
+use crate::system_command::{command, run};
+use crate::terminal::open_in_terminal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Child, Stdio};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+const OUTPUT_EVENT: &str = "shell-output";
+const EXIT_EVENT: &str = "shell-exit";
+
+// 正在运行的命令，按前端生成的 id 索引，用于中止
+static RUNNING: LazyLock<Mutex<HashMap<String, Arc<Mutex<Child>>>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ShellStream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ShellOutput {
+    pub id: String,
+    pub stream: ShellStream,
+    pub line: String,
+}
+
+// code 为空表示被信号终止
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ShellExit {
+    pub id: String,
+    pub code: Option<i32>,
+}
+
+// 立即返回，输出通过 "shell-output" 事件推送，结束时发送 "shell-exit"
+#[tauri::command]
+pub fn run_shell_command(app_handle: AppHandle, id: String, command_line: String, shell: Option<String>) -> Result<(), String> {
+    if command_line.trim().is_empty() {
+        return Err("命令不能为空".to_string());
+    }
+    let shell = resolve_shell(shell);
+
+    let mut shell_command = command(&shell);
+    shell_command
+        .args(shell_args(&shell, &command_line))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    // 放入新的进程组，中止时连同后台子进程一起结束
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        shell_command.process_group(0);
+    }
+    let mut child = shell_command.spawn().map_err(|e| format!("启动 {} 失败: {}", shell, e))?;
+
+    let readers = [
+        child.stdout.take().map(|stdout| spawn_reader(app_handle.clone(), id.clone(), ShellStream::Stdout, stdout)),
+        child.stderr.take().map(|stderr| spawn_reader(app_handle.clone(), id.clone(), ShellStream::Stderr, stderr)),
+    ];
+
+    let child = Arc::new(Mutex::new(child));
+    RUNNING.lock().unwrap().insert(id.clone(), child.clone());
+
+    thread::spawn(move || {
+        let code = loop {
+            match child.lock().unwrap().try_wait() {
+                Ok(Some(status)) => break status.code(),
+                Ok(None) => {}
+                Err(_) => break None,
+            }
+            thread::sleep(Duration::from_millis(50));
+        };
+        // 等输出读完再发送结束事件
+        for reader in readers.into_iter().flatten() {
+            let _ = reader.join();
+        }
+        RUNNING.lock().unwrap().remove(&id);
+        let _ = app_handle.emit(EXIT_EVENT, ShellExit { id, code });
+    });
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn kill_shell_command(id: String) -> Result<(), String> {
+    let child = RUNNING.lock().unwrap().get(&id).cloned()
+        .ok_or_else(|| "命令已结束".to_string())?;
+    let pid = child.lock().unwrap().id();
+    kill_process_tree(pid, std::env::consts::OS)
+}
+
+// 只结束 shell 本身时，孙进程仍占用输出管道，结束事件不会到达
+fn kill_process_tree(pid: u32, os: &str) -> Result<(), String> {
+    let result = match os {
+        "windows" => run("taskkill", &["/T", "/F", "/PID", &pid.to_string()]),
+        // 进程组号与 shell 的 PID 相同
+        _ => run("kill", &["-KILL", "--", &format!("-{}", pid)]),
+    };
+    result.map(|_| ()).map_err(|e| format!("中止命令失败: {}", e))
+}
+
+// 在终端中运行，命令结束后保留终端以便查看输出
+#[tauri::command]
+pub fn run_shell_in_terminal(command_line: String, shell: Option<String>, terminal_command: Option<String>) -> Result<(), String> {
+    if command_line.trim().is_empty() {
+        return Err("命令不能为空".to_string());
+    }
+    let shell = resolve_shell(shell);
+    let args = terminal_args(&shell, &command_line, std::env::consts::OS);
+    open_in_terminal(&args.iter().map(String::as_str).collect::<Vec<_>>(), terminal_command.as_deref())
+}
+
+fn spawn_reader(app_handle: AppHandle, id: String, stream: ShellStream, source: impl Read + Send + 'static) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut reader = BufReader::new(source);
+        let mut buffer = Vec::new();
+        // 按字节读取行，非 UTF-8 输出也不会中断
+        while reader.read_until(b'\n', &mut buffer).unwrap_or(0) > 0 {
+            let line = String::from_utf8_lossy(&buffer).trim_end_matches(['\r', '\n']).to_string();
+            let _ = app_handle.emit(OUTPUT_EVENT, ShellOutput { id: id.clone(), stream, line });
+            buffer.clear();
+        }
+    })
+}
+
+// 未指定时使用 $SHELL，Windows 使用 cmd
+fn resolve_shell(shell: Option<String>) -> String {
+    shell.map(|shell| shell.trim().to_string())
+        .filter(|shell| !shell.is_empty())
+        .or_else(|| if cfg!(windows) { None } else { std::env::var("SHELL").ok().filter(|shell| !shell.is_empty()) })
+        .unwrap_or_else(|| if cfg!(windows) { "cmd".to_string() } else { "/bin/sh".to_string() })
+}
+
+fn shell_name(shell: &str) -> String {
+    let name = shell.rsplit(['/', '\\']).next().unwrap_or(shell).to_lowercase();
+    name.trim_end_matches(".exe").to_string()
+}
+
+fn shell_args(shell: &str, command_line: &str) -> Vec<String> {
+    let flag = match shell_name(shell).as_str() {
+        "cmd" => "/C",
+        "powershell" | "pwsh" => "-Command",
+        _ => "-c",
+    };
+    vec![flag.to_string(), command_line.to_string()]
+}
+
+fn terminal_args(shell: &str, command_line: &str, os: &str) -> Vec<String> {
+    match shell_name(shell).as_str() {
+        "cmd" => vec!["cmd".to_string(), "/K".to_string(), command_line.to_string()],
+        "powershell" | "pwsh" => vec![shell.to_string(), "-NoExit".to_string(), "-Command".to_string(), command_line.to_string()],
+        // Windows 终端本身会保留窗口
+        _ if os == "windows" => vec![shell.to_string(), "-c".to_string(), command_line.to_string()],
+        _ => vec![shell.to_string(), "-c".to_string(), format!("{}; exec {}", command_line, shell)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_args() {
+        assert_eq!(shell_args("/bin/zsh", "ls -la"), vec!["-c", "ls -la"]);
+        assert_eq!(shell_args("cmd", "dir"), vec!["/C", "dir"]);
+        assert_eq!(shell_args(r"C:\Program Files\PowerShell\7\pwsh.exe", "Get-Date"), vec!["-Command", "Get-Date"]);
+    }
+
+    #[test]
+    fn test_terminal_args() {
+        assert_eq!(
+            terminal_args("/bin/bash", "make test", "linux"),
+            vec!["/bin/bash", "-c", "make test; exec /bin/bash"]
+        );
+        assert_eq!(terminal_args("cmd", "dir", "windows"), vec!["cmd", "/K", "dir"]);
+        assert_eq!(
+            terminal_args("powershell", "Get-Date", "windows"),
+            vec!["powershell", "-NoExit", "-Command", "Get-Date"]
+        );
+    }
+
+    #[test]
+    fn test_resolve_shell() {
+        assert_eq!(resolve_shell(Some(" /bin/zsh ".to_string())), "/bin/zsh");
+        assert!(!resolve_shell(Some(String::new())).is_empty());
+        assert!(!resolve_shell(None).is_empty());
+    }
+
+    #[test]
+    fn test_kill_unknown_command() {
+        assert!(kill_shell_command("missing".to_string()).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_process_tree() {
+        use std::os::unix::process::CommandExt;
+        let mut child = command("sh")
+            .args(["-c", "sleep 30 & sleep 30"])
+            .stdout(Stdio::piped())
+            .process_group(0)
+            .spawn()
+            .unwrap();
+        let mut stdout = child.stdout.take().unwrap();
+        thread::sleep(Duration::from_millis(100));
+
+        kill_process_tree(child.id(), "linux").unwrap();
+        // 后台的 sleep 也已结束，管道关闭后读取立即返回
+        let mut output = Vec::new();
+        stdout.read_to_end(&mut output).unwrap();
+        assert!(child.wait().unwrap().code().is_none());
+    }
+}
@@ -0,0 +1,243 @@
+// Shell 历史：索引 bash / zsh / fish 的历史文件，按关键词搜索执行过的命令
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+use std::time::SystemTime;
+
+const DEFAULT_LIMIT: usize = 50;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum HistoryShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+// timestamp 为 Unix 秒，bash 未开启 HISTTIMEFORMAT 时为空
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub command: String,
+    pub shell: HistoryShell,
+    pub timestamp: Option<i64>,
+}
+
+struct HistoryFile {
+    modified: SystemTime,
+    entries: Vec<HistoryEntry>,
+}
+
+// 历史文件没有变化时复用上次解析的结果
+static INDEX: LazyLock<Mutex<HashMap<PathBuf, HistoryFile>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// 结果按时间从新到旧排列，相同的命令只保留最近一次
+#[tauri::command(async)]
+pub fn search_shell_history(query: String, limit: Option<usize>) -> Result<Vec<HistoryEntry>, String> {
+    let words: Vec<String> = query.to_lowercase().split_whitespace().map(str::to_string).collect();
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+
+    let mut index = INDEX.lock().unwrap();
+    let mut entries: Vec<(usize, &HistoryEntry)> = Vec::new();
+    let files = history_files()?;
+    for (path, shell) in &files {
+        let Some(modified) = fs::metadata(path).and_then(|metadata| metadata.modified()).ok() else {
+            continue;
+        };
+        if index.get(path).is_none_or(|file| file.modified != modified) {
+            let bytes = fs::read(path).unwrap_or_default();
+            let entries = match shell {
+                HistoryShell::Bash => parse_bash_history(&String::from_utf8_lossy(&bytes)),
+                HistoryShell::Zsh => parse_zsh_history(&unmetafy(&bytes)),
+                HistoryShell::Fish => parse_fish_history(&String::from_utf8_lossy(&bytes)),
+            };
+            index.insert(path.clone(), HistoryFile { modified, entries });
+        }
+    }
+
+    for (path, _) in &files {
+        if let Some(file) = index.get(path) {
+            // 文件内越靠后越新
+            entries.extend(file.entries.iter().enumerate());
+        }
+    }
+
+    // 有时间戳的按时间排序，没有时间戳的按在文件中的位置排在后面
+    entries.sort_by(|(a_pos, a), (b_pos, b)| b.timestamp.cmp(&a.timestamp).then(b_pos.cmp(a_pos)));
+
+    let mut seen = HashSet::new();
+    Ok(entries.into_iter()
+        .map(|(_, entry)| entry)
+        .filter(|entry| {
+            let command = entry.command.to_lowercase();
+            words.iter().all(|word| command.contains(word.as_str()))
+        })
+        .filter(|entry| seen.insert(entry.command.clone()))
+        .take(limit)
+        .cloned()
+        .collect())
+}
+
+fn history_files() -> Result<Vec<(PathBuf, HistoryShell)>, String> {
+    let home = dirs::home_dir().ok_or_else(|| "无法获取用户主目录".to_string())?;
+    let fish_dir = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home.join(".local/share"))
+        .join("fish");
+
+    let mut files = vec![
+        (home.join(".bash_history"), HistoryShell::Bash),
+        (home.join(".zsh_history"), HistoryShell::Zsh),
+        (home.join(".histfile"), HistoryShell::Zsh),
+        (fish_dir.join("fish_history"), HistoryShell::Fish),
+    ];
+    // 当前 shell 的 HISTFILE 可能指向其他位置
+    if let Some(histfile) = std::env::var_os("HISTFILE").map(PathBuf::from) {
+        let shell = if std::env::var("SHELL").unwrap_or_default().ends_with("zsh") { HistoryShell::Zsh } else { HistoryShell::Bash };
+        if !files.iter().any(|(path, _)| *path == histfile) {
+            files.push((histfile, shell));
+        }
+    }
+    Ok(files.into_iter().filter(|(path, _)| path.is_file()).collect())
+}
+
+// HISTTIMEFORMAT 开启时，每条命令前有一行 "#时间戳"
+fn parse_bash_history(content: &str) -> Vec<HistoryEntry> {
+    let mut entries = Vec::new();
+    let mut timestamp = None;
+    for line in content.lines() {
+        if let Some(value) = line.strip_prefix('#').and_then(|value| value.trim().parse::<i64>().ok()) {
+            timestamp = Some(value);
+            continue;
+        }
+        if !line.trim().is_empty() {
+            entries.push(HistoryEntry { command: line.to_string(), shell: HistoryShell::Bash, timestamp: timestamp.take() });
+        }
+    }
+    entries
+}
+
+// 扩展格式为 ": 开始时间:耗时;命令"，多行命令以反斜杠续行
+fn parse_zsh_history(content: &str) -> Vec<HistoryEntry> {
+    let mut entries: Vec<HistoryEntry> = Vec::new();
+    let mut continuing = false;
+
+    for line in content.lines() {
+        if continuing {
+            if let Some(entry) = entries.last_mut() {
+                entry.command.push('\n');
+                entry.command.push_str(line.strip_suffix('\\').unwrap_or(line));
+            }
+            continuing = line.ends_with('\\');
+            continue;
+        }
+
+        let (timestamp, command) = match line.strip_prefix(": ").and_then(|rest| rest.split_once(';')) {
+            Some((meta, command)) => (meta.split(':').next().and_then(|value| value.trim().parse().ok()), command),
+            None => (None, line),
+        };
+        continuing = command.ends_with('\\');
+        let command = command.strip_suffix('\\').unwrap_or(command);
+        if !command.trim().is_empty() || continuing {
+            entries.push(HistoryEntry { command: command.to_string(), shell: HistoryShell::Zsh, timestamp });
+        }
+    }
+    entries
+}
+
+// zsh 写入历史时会把 0x83 之后的字节异或 32
+fn unmetafy(bytes: &[u8]) -> String {
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut iter = bytes.iter();
+    while let Some(&byte) = iter.next() {
+        if byte == 0x83 {
+            if let Some(&next) = iter.next() {
+                result.push(next ^ 32);
+            }
+        } else {
+            result.push(byte);
+        }
+    }
+    String::from_utf8_lossy(&result).to_string()
+}
+
+// fish 使用类 YAML 的格式：- cmd: 命令 / when: 时间戳
+fn parse_fish_history(content: &str) -> Vec<HistoryEntry> {
+    let mut entries: Vec<HistoryEntry> = Vec::new();
+    for line in content.lines() {
+        if let Some(command) = line.strip_prefix("- cmd: ") {
+            entries.push(HistoryEntry { command: unescape_fish(command), shell: HistoryShell::Fish, timestamp: None });
+        } else if let Some(when) = line.trim_start().strip_prefix("when: ") {
+            if let Some(entry) = entries.last_mut() {
+                entry.timestamp = when.trim().parse().ok();
+            }
+        }
+    }
+    entries
+}
+
+fn unescape_fish(command: &str) -> String {
+    let mut result = String::with_capacity(command.len());
+    let mut chars = command.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('\\') => result.push('\\'),
+            Some(other) => {
+                result.push('\\');
+                result.push(other);
+            }
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bash_history() {
+        let entries = parse_bash_history("ls -la\n#1700000000\ngit status\n\ncargo test\n");
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].timestamp, None);
+        assert_eq!(entries[1].command, "git status");
+        assert_eq!(entries[1].timestamp, Some(1700000000));
+        assert_eq!(entries[2].timestamp, None);
+    }
+
+    #[test]
+    fn test_parse_zsh_history() {
+        let content = ": 1700000000:0;git push\n: 1700000100:3;for f in *; do\\\necho $f\\\ndone\nplain command\n";
+        let entries = parse_zsh_history(content);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].command, "git push");
+        assert_eq!(entries[0].timestamp, Some(1700000000));
+        assert_eq!(entries[1].command, "for f in *; do\necho $f\ndone");
+        assert_eq!(entries[2].command, "plain command");
+        assert_eq!(entries[2].timestamp, None);
+    }
+
+    #[test]
+    fn test_unmetafy() {
+        // "€" 的 UTF-8 编码 0xE2 0x82 0xAC 中，0x82 被写成 0x83 0xA2
+        assert_eq!(unmetafy(&[b'e', b'c', b'h', b'o', b' ', 0xC3, 0xA9]), "echo é");
+        assert_eq!(unmetafy(&[0xE2, 0x83, 0xA2, 0xAC]), "€");
+    }
+
+    #[test]
+    fn test_parse_fish_history() {
+        let content = "- cmd: echo hello\\nworld\n  when: 1700000000\n- cmd: cd C:\\\\Users\n  when: 1700000050\n  paths:\n    - C:\\\\Users\n";
+        let entries = parse_fish_history(content);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].command, "echo hello\nworld");
+        assert_eq!(entries[0].timestamp, Some(1700000000));
+        assert_eq!(entries[1].command, "cd C:\\Users");
+    }
+}
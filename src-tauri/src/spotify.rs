@@ -0,0 +1,195 @@
+// Spotify Web API：读取当前播放的曲目并收藏（需要用户在插件设置中提供应用凭据和 refresh token）
+use crate::http;
+use crate::media::{parse_spotify_track_id, NowPlaying};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const TOKEN_URL: &str = "https://accounts.spotify.com/api/token";
+const API_BASE: &str = "https://api.spotify.com/v1";
+
+// Spotify 应用凭据，refresh token 需要 user-read-currently-playing 和 user-library-modify 权限
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SpotifyCredentials {
+    pub client_id: String,
+    pub client_secret: String,
+    pub refresh_token: String,
+}
+
+// 缓存的 access token，按 refresh token 区分
+struct AccessToken {
+    refresh_token: String,
+    token: String,
+    expires_at: Instant,
+}
+
+static ACCESS_TOKEN: Mutex<Option<AccessToken>> = Mutex::new(None);
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct CurrentlyPlaying {
+    is_playing: bool,
+    item: Option<Track>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Track {
+    id: Option<String>,
+    name: String,
+    artists: Vec<Artist>,
+    album: Album,
+}
+
+#[derive(Debug, Deserialize)]
+struct Artist {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Album {
+    name: String,
+    images: Vec<Image>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Image {
+    url: String,
+}
+
+#[tauri::command]
+pub async fn spotify_now_playing(credentials: SpotifyCredentials) -> Result<Option<NowPlaying>, String> {
+    let client = http::client()?;
+    let token = access_token(&client, &credentials).await?;
+
+    let response = client.get(format!("{}/me/player/currently-playing", API_BASE))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .map_err(|e| format!("请求 Spotify 失败: {}", e))?;
+
+    // 没有播放内容时返回 204
+    if response.status().as_u16() == 204 {
+        return Ok(None);
+    }
+    let body = http::check_status(response, "Spotify").await?
+        .text()
+        .await
+        .map_err(|e| format!("读取 Spotify 响应失败: {}", e))?;
+    parse_currently_playing(&body)
+}
+
+// track_id 为空时收藏 Spotify 上正在播放的曲目
+#[tauri::command]
+pub async fn spotify_save_track(credentials: SpotifyCredentials, track_id: Option<String>) -> Result<(), String> {
+    let track_id = match track_id {
+        Some(id) => id,
+        None => spotify_now_playing(credentials.clone()).await?
+            .and_then(|track| track.spotify_track_id)
+            .ok_or_else(|| "Spotify 上没有正在播放的曲目".to_string())?,
+    };
+    if parse_spotify_track_id(&format!("spotify:track:{}", track_id)).is_none() {
+        return Err(format!("无效的 Spotify 曲目 ID: {}", track_id));
+    }
+
+    let client = http::client()?;
+    let token = access_token(&client, &credentials).await?;
+    let response = client.put(format!("{}/me/tracks", API_BASE))
+        .bearer_auth(&token)
+        .json(&serde_json::json!({ "ids": [track_id] }))
+        .send()
+        .await
+        .map_err(|e| format!("请求 Spotify 失败: {}", e))?;
+    http::check_status(response, "Spotify").await?;
+
+    Ok(())
+}
+
+async fn access_token(client: &reqwest::Client, credentials: &SpotifyCredentials) -> Result<String, String> {
+    if credentials.client_id.is_empty() || credentials.client_secret.is_empty() || credentials.refresh_token.is_empty() {
+        return Err("请先在插件设置中填写 Spotify 凭据".to_string());
+    }
+
+    // 请求期间不持有锁
+    if let Some(token) = ACCESS_TOKEN.lock().map_err(|e| e.to_string())?.as_ref() {
+        if token.refresh_token == credentials.refresh_token && token.expires_at > Instant::now() {
+            return Ok(token.token.clone());
+        }
+    }
+
+    let response = client.post(TOKEN_URL)
+        .basic_auth(&credentials.client_id, Some(&credentials.client_secret))
+        .form(&[("grant_type", "refresh_token"), ("refresh_token", credentials.refresh_token.as_str())])
+        .send()
+        .await
+        .map_err(|e| format!("请求 Spotify 失败: {}", e))?;
+    let token: TokenResponse = http::check_status(response, "Spotify").await?
+        .json()
+        .await
+        .map_err(|e| format!("解析 Spotify 授权响应失败: {}", e))?;
+
+    // 提前一分钟过期，避免请求途中失效
+    *ACCESS_TOKEN.lock().map_err(|e| e.to_string())? = Some(AccessToken {
+        refresh_token: credentials.refresh_token.clone(),
+        token: token.access_token.clone(),
+        expires_at: Instant::now() + Duration::from_secs(token.expires_in.saturating_sub(60)),
+    });
+    Ok(token.access_token)
+}
+
+fn parse_currently_playing(body: &str) -> Result<Option<NowPlaying>, String> {
+    let playing: CurrentlyPlaying = serde_json::from_str(body)
+        .map_err(|e| format!("解析 Spotify 响应失败: {}", e))?;
+
+    // 播客等非曲目内容没有 item
+    Ok(playing.item.map(|track| NowPlaying {
+        title: track.name,
+        artist: track.artists.into_iter().map(|artist| artist.name).collect::<Vec<_>>().join(", "),
+        album: track.album.name,
+        artwork_url: track.album.images.into_iter().next().map(|image| image.url),
+        player: "Spotify".to_string(),
+        playing: playing.is_playing,
+        spotify_track_id: track.id,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_currently_playing() {
+        let body = r#"{
+            "is_playing": true,
+            "item": {
+                "id": "4u7EnebtmKWzUH433cf5Qv",
+                "name": "Bohemian Rhapsody",
+                "artists": [{"name": "Queen"}, {"name": "Freddie Mercury"}],
+                "album": {"name": "A Night at the Opera", "images": [{"url": "https://i.scdn.co/image/640"}, {"url": "https://i.scdn.co/image/300"}]}
+            }
+        }"#;
+        let track = parse_currently_playing(body).unwrap().unwrap();
+
+        assert_eq!(track.artist, "Queen, Freddie Mercury");
+        assert_eq!(track.artwork_url.as_deref(), Some("https://i.scdn.co/image/640"));
+        assert_eq!(track.spotify_track_id.as_deref(), Some("4u7EnebtmKWzUH433cf5Qv"));
+        assert!(track.playing);
+
+        assert_eq!(parse_currently_playing(r#"{"is_playing": false, "item": null}"#).unwrap(), None);
+        assert!(parse_currently_playing("<html>").is_err());
+    }
+
+    #[test]
+    fn test_missing_credentials() {
+        let credentials = SpotifyCredentials {
+            client_id: String::new(),
+            client_secret: String::new(),
+            refresh_token: String::new(),
+        };
+        assert!(tokio_test::block_on(spotify_now_playing(credentials)).unwrap_err().contains("Spotify 凭据"));
+    }
+}
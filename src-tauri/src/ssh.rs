@@ -0,0 +1,327 @@
+// SSH 主机：解析 ~/.ssh/config 和 known_hosts，在终端中连接选中的主机
+use crate::terminal::open_in_terminal;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Include 嵌套层数上限，和 OpenSSH 一致
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SshHostSource {
+    Config,
+    KnownHosts,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SshHost {
+    pub alias: String,
+    pub hostname: Option<String>,
+    pub user: Option<String>,
+    pub port: Option<u16>,
+    pub source: SshHostSource,
+}
+
+#[tauri::command]
+pub fn list_ssh_hosts() -> Result<Vec<SshHost>, String> {
+    let ssh_dir = ssh_dir()?;
+    let mut hosts = Vec::new();
+    parse_config_file(&ssh_dir.join("config"), &ssh_dir, 0, &mut hosts);
+
+    if let Ok(content) = fs::read_to_string(ssh_dir.join("known_hosts")) {
+        for host in parse_known_hosts(&content) {
+            // 已在 config 中出现的主机不重复列出
+            let known = hosts.iter().any(|existing| {
+                existing.alias == host.alias || existing.hostname.as_deref() == Some(host.alias.as_str())
+            });
+            if !known {
+                hosts.push(host);
+            }
+        }
+    }
+
+    Ok(hosts)
+}
+
+// terminal_command 为该主机使用的终端命令，{cmd} 会被替换为 ssh 命令
+#[tauri::command]
+pub fn open_ssh(host: SshHost, terminal_command: Option<String>) -> Result<(), String> {
+    if !is_valid_destination(&host.alias) {
+        return Err(format!("无效的主机名: {}", host.alias));
+    }
+
+    // config 中的端口由 ssh 自己读取，known_hosts 中的非默认端口需要显式传入
+    let port = host.port.filter(|_| host.source == SshHostSource::KnownHosts).map(|port| port.to_string());
+    let mut args = vec!["ssh"];
+    if let Some(port) = &port {
+        args.extend(["-p", port]);
+    }
+    args.push(&host.alias);
+
+    open_in_terminal(&args, terminal_command.as_deref())
+}
+
+fn ssh_dir() -> Result<PathBuf, String> {
+    dirs::home_dir()
+        .map(|home| home.join(".ssh"))
+        .ok_or_else(|| "无法获取用户主目录".to_string())
+}
+
+// 防止主机名被当作 ssh 的选项
+fn is_valid_destination(host: &str) -> bool {
+    !host.is_empty()
+        && !host.starts_with('-')
+        && host.chars().all(|c| c.is_ascii_alphanumeric() || "._-@:".contains(c))
+}
+
+fn parse_config_file(path: &Path, ssh_dir: &Path, depth: usize, hosts: &mut Vec<SshHost>) {
+    if depth > MAX_INCLUDE_DEPTH {
+        return;
+    }
+    if let Ok(content) = fs::read_to_string(path) {
+        parse_config(&content, ssh_dir, depth, hosts);
+    }
+}
+
+fn parse_config(content: &str, ssh_dir: &Path, depth: usize, hosts: &mut Vec<SshHost>) {
+    // 当前 Host 块对应的主机在 hosts 中的下标
+    let mut current: Vec<usize> = Vec::new();
+
+    for line in content.lines() {
+        let Some((keyword, value)) = split_config_line(line) else {
+            continue;
+        };
+
+        match keyword.to_lowercase().as_str() {
+            "host" => {
+                current.clear();
+                for alias in value.split_whitespace() {
+                    // 通配符和否定模式不是可连接的主机
+                    if alias.contains(['*', '?', '!']) {
+                        continue;
+                    }
+                    if let Some(index) = hosts.iter().position(|host| host.alias == alias) {
+                        current.push(index);
+                    } else {
+                        hosts.push(SshHost {
+                            alias: alias.to_string(),
+                            hostname: None,
+                            user: None,
+                            port: None,
+                            source: SshHostSource::Config,
+                        });
+                        current.push(hosts.len() - 1);
+                    }
+                }
+            }
+            "match" => current.clear(),
+            "include" => {
+                for pattern in value.split_whitespace() {
+                    for path in resolve_include(pattern, ssh_dir) {
+                        parse_config_file(&path, ssh_dir, depth + 1, hosts);
+                    }
+                }
+            }
+            // 和 ssh 一样，同一选项以第一次出现的值为准
+            "hostname" => {
+                for &index in &current {
+                    hosts[index].hostname.get_or_insert_with(|| value.to_string());
+                }
+            }
+            "user" => {
+                for &index in &current {
+                    hosts[index].user.get_or_insert_with(|| value.to_string());
+                }
+            }
+            "port" => {
+                if let Ok(port) = value.parse::<u16>() {
+                    for &index in &current {
+                        hosts[index].port.get_or_insert(port);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// 选项和值之间可以用空白或 "=" 分隔
+fn split_config_line(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let index = line.find(|c: char| c.is_whitespace() || c == '=')?;
+    let keyword = &line[..index];
+    let value = line[index..].trim_start_matches(|c: char| c.is_whitespace() || c == '=').trim();
+    let value = value.strip_prefix('"').and_then(|value| value.strip_suffix('"')).unwrap_or(value);
+    Some((keyword, value))
+}
+
+// 相对路径相对于 ~/.ssh，只支持最后一级中的 * 通配符
+fn resolve_include(pattern: &str, ssh_dir: &Path) -> Vec<PathBuf> {
+    let path = match pattern.strip_prefix("~/") {
+        Some(rest) => ssh_dir.parent().map(|home| home.join(rest)).unwrap_or_else(|| ssh_dir.join(rest)),
+        None => ssh_dir.join(pattern),
+    };
+
+    let Some(file_name) = path.file_name().map(|name| name.to_string_lossy().to_string()) else {
+        return Vec::new();
+    };
+    if !file_name.contains('*') {
+        return vec![path];
+    }
+
+    let Some(dir) = path.parent() else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries.flatten()
+                .filter(|entry| wildcard_match(&file_name, &entry.file_name().to_string_lossy()))
+                .map(|entry| entry.path())
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    paths
+}
+
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if !name.starts_with(first) || !name[first.len()..].ends_with(last) {
+        return false;
+    }
+    let mut rest = &name[first.len()..name.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+fn parse_known_hosts(content: &str) -> Vec<SshHost> {
+    let mut hosts: Vec<SshHost> = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        // 跳过注释和 @cert-authority / @revoked 记录
+        if line.is_empty() || line.starts_with('#') || line.starts_with('@') {
+            continue;
+        }
+        let Some(names) = line.split_whitespace().next() else {
+            continue;
+        };
+
+        for name in names.split(',') {
+            // 哈希过的主机名无法还原
+            if name.starts_with("|1|") || name.contains(['*', '?', '!']) {
+                continue;
+            }
+            let (alias, port) = match name.strip_prefix('[').and_then(|rest| rest.split_once("]:")) {
+                Some((host, port)) => (host, port.parse::<u16>().ok()),
+                None => (name, None),
+            };
+            if alias.is_empty() || hosts.iter().any(|host| host.alias == alias && host.port == port) {
+                continue;
+            }
+            hosts.push(SshHost {
+                alias: alias.to_string(),
+                hostname: None,
+                user: None,
+                port,
+                source: SshHostSource::KnownHosts,
+            });
+        }
+    }
+
+    hosts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_config() {
+        let config = "\
+# 工作机器
+Host prod-web-1 prod-web-2
+    HostName 10.0.0.5
+    User deploy
+    Port=2222
+
+Host *.internal !bastion
+    User admin
+
+Host bastion
+    Hostname = \"bastion.example.com\"
+    User ops
+    User ignored
+
+Match host *.example.com
+    User nobody
+";
+        let mut hosts = Vec::new();
+        parse_config(config, Path::new("/nonexistent"), 0, &mut hosts);
+
+        assert_eq!(hosts.len(), 3);
+        assert_eq!(hosts[0].alias, "prod-web-1");
+        assert_eq!(hosts[1].hostname.as_deref(), Some("10.0.0.5"));
+        assert_eq!(hosts[1].port, Some(2222));
+        assert_eq!(hosts[2].alias, "bastion");
+        assert_eq!(hosts[2].hostname.as_deref(), Some("bastion.example.com"));
+        assert_eq!(hosts[2].user.as_deref(), Some("ops"));
+    }
+
+    #[test]
+    fn test_parse_config_include() {
+        let temp_dir = TempDir::new().unwrap();
+        let ssh_dir = temp_dir.path().join(".ssh");
+        fs::create_dir_all(ssh_dir.join("config.d")).unwrap();
+        fs::write(ssh_dir.join("config"), "Include config.d/*.conf\nHost home\n  HostName 192.168.1.2\n").unwrap();
+        fs::write(ssh_dir.join("config.d/work.conf"), "Host work\n  User me\n").unwrap();
+        fs::write(ssh_dir.join("config.d/notes.txt"), "Host ignored\n").unwrap();
+
+        let mut hosts = Vec::new();
+        parse_config_file(&ssh_dir.join("config"), &ssh_dir, 0, &mut hosts);
+        let aliases: Vec<&str> = hosts.iter().map(|host| host.alias.as_str()).collect();
+        assert_eq!(aliases, vec!["work", "home"]);
+    }
+
+    #[test]
+    fn test_parse_known_hosts() {
+        let content = "\
+github.com,140.82.112.3 ssh-ed25519 AAAA
+[git.example.com]:2222 ssh-rsa AAAA
+|1|abc=|def= ssh-ed25519 AAAA
+@cert-authority *.example.com ssh-rsa AAAA
+github.com ssh-rsa AAAA
+";
+        let hosts = parse_known_hosts(content);
+        let summary: Vec<(&str, Option<u16>)> = hosts.iter().map(|host| (host.alias.as_str(), host.port)).collect();
+        assert_eq!(summary, vec![("github.com", None), ("140.82.112.3", None), ("git.example.com", Some(2222))]);
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.conf", "work.conf"));
+        assert!(wildcard_match("a*b*c", "aXXbYYc"));
+        assert!(!wildcard_match("*.conf", "notes.txt"));
+        assert!(!wildcard_match("ab*ba", "aba"));
+    }
+
+    #[test]
+    fn test_is_valid_destination() {
+        assert!(is_valid_destination("prod-web-1"));
+        assert!(is_valid_destination("deploy@10.0.0.5"));
+        assert!(!is_valid_destination("-oProxyCommand=evil"));
+        assert!(!is_valid_destination("host; rm -rf ~"));
+        assert!(!is_valid_destination(""));
+    }
+}
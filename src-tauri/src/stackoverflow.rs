@@ -0,0 +1,119 @@
+// Stack Overflow：通过 Stack Exchange API 按关键词搜索问题
+use crate::http::{self, decode_entities, RateLimiter, TtlCache};
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+const SEARCH_API: &str = "https://api.stackexchange.com/2.3/search/advanced";
+const PAGE_SIZE: &str = "10";
+
+// 未注册应用每个 IP 每天只有 300 次配额，问题列表变化不快，缓存时间长一些
+static CACHE: LazyLock<TtlCache<Vec<StackOverflowQuestion>>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(30 * 60)));
+static LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(10, Duration::from_secs(60)));
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StackOverflowQuestion {
+    pub id: u64,
+    pub title: String,
+    pub url: String,
+    pub score: i64,
+    pub answer_count: u64,
+    pub is_answered: bool,
+    pub accepted: bool,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    items: Vec<Question>,
+    // 服务端要求的等待秒数，期间同类请求会被拒绝
+    backoff: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Question {
+    question_id: u64,
+    title: String,
+    link: String,
+    score: i64,
+    answer_count: u64,
+    is_answered: bool,
+    accepted_answer_id: Option<u64>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[tauri::command]
+pub async fn search_stackoverflow(query: String) -> Result<Vec<StackOverflowQuestion>, String> {
+    let query = query.trim().to_lowercase();
+    if query.chars().count() < 2 {
+        return Ok(vec![]);
+    }
+    CACHE.get_or_fetch_async(&query, || fetch_questions(&query)).await
+}
+
+async fn fetch_questions(query: &str) -> Result<Vec<StackOverflowQuestion>, String> {
+    LIMITER.acquire("Stack Overflow")?;
+    let response = http::client()?
+        .get(SEARCH_API)
+        .query(&[
+            ("q", query),
+            ("site", "stackoverflow"),
+            ("order", "desc"),
+            ("sort", "relevance"),
+            ("pagesize", PAGE_SIZE),
+        ])
+        .send()
+        .await
+        .map_err(|e| format!("请求 Stack Overflow 失败: {}", e))?;
+    let body = http::check_status(response, "Stack Overflow").await?
+        .text()
+        .await
+        .map_err(|e| format!("读取 Stack Overflow 响应失败: {}", e))?;
+
+    let (questions, backoff) = parse_questions(&body)?;
+    if let Some(seconds) = backoff {
+        LIMITER.block_until(Instant::now() + Duration::from_secs(seconds));
+    }
+    Ok(questions)
+}
+
+fn parse_questions(body: &str) -> Result<(Vec<StackOverflowQuestion>, Option<u64>), String> {
+    let response: SearchResponse = serde_json::from_str(body).map_err(|e| format!("解析 Stack Overflow 响应失败: {}", e))?;
+    let questions = response.items.into_iter()
+        .map(|question| StackOverflowQuestion {
+            id: question.question_id,
+            title: decode_entities(&question.title),
+            url: question.link,
+            score: question.score,
+            answer_count: question.answer_count,
+            is_answered: question.is_answered,
+            accepted: question.accepted_answer_id.is_some(),
+            tags: question.tags,
+        })
+        .collect();
+    Ok((questions, response.backoff))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_questions() {
+        let body = r#"{"items": [
+            {"tags": ["rust", "borrow-checker"], "is_answered": true, "answer_count": 3, "accepted_answer_id": 101,
+             "score": 42, "question_id": 100, "link": "https://stackoverflow.com/questions/100/why-cant-i",
+             "title": "Why can&#39;t I borrow &quot;self&quot; as mutable?"},
+            {"tags": [], "is_answered": false, "answer_count": 0, "score": -1, "question_id": 200,
+             "link": "https://stackoverflow.com/questions/200/x", "title": "x"}
+        ], "has_more": true, "quota_max": 300, "quota_remaining": 280, "backoff": 10}"#;
+        let (questions, backoff) = parse_questions(body).unwrap();
+        assert_eq!(backoff, Some(10));
+        assert_eq!(questions[0].title, "Why can't I borrow \"self\" as mutable?");
+        assert!(questions[0].accepted);
+        assert_eq!(questions[0].tags, vec!["rust", "borrow-checker"]);
+        assert!(!questions[1].accepted);
+        assert_eq!(questions[1].score, -1);
+    }
+}
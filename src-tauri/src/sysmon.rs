@@ -0,0 +1,149 @@
+// 系统资源监控：CPU、内存、磁盘和每个核心的负载，界面可见时通过事件持续推送
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use sysinfo::{Disks, System};
+use tauri::{AppHandle, Emitter};
+
+const DEFAULT_INTERVAL_MS: u64 = 1000;
+// 前端每次搜索都会续期，超过租期没有续期则停止推送
+const MONITOR_LEASE: Duration = Duration::from_secs(5);
+// 不显示的虚拟文件系统
+const IGNORED_FILE_SYSTEMS: &[&str] = &["squashfs", "overlay", "tmpfs", "devtmpfs", "autofs"];
+
+static SYSTEM: LazyLock<Mutex<System>> = LazyLock::new(|| Mutex::new(System::new()));
+
+struct MonitorState {
+    running: bool,
+    lease_until: Instant,
+}
+
+static MONITOR: LazyLock<Mutex<MonitorState>> = LazyLock::new(|| {
+    Mutex::new(MonitorState { running: false, lease_until: Instant::now() })
+});
+
+// 系统资源快照
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SystemSnapshot {
+    pub cpu_usage: f32, // 百分比
+    pub per_core: Vec<f32>,
+    pub memory_used: u64, // 字节
+    pub memory_total: u64,
+    pub swap_used: u64,
+    pub swap_total: u64,
+    pub disks: Vec<DiskUsage>,
+    pub uptime_secs: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DiskUsage {
+    pub name: String,
+    pub mount_point: String,
+    pub used: u64,
+    pub total: u64,
+    pub removable: bool,
+}
+
+#[tauri::command]
+pub fn get_system_snapshot() -> SystemSnapshot {
+    take_snapshot()
+}
+
+// 开始（或续期）推送 "system-snapshot" 事件
+#[tauri::command]
+pub fn start_system_monitor(app_handle: AppHandle, interval_ms: Option<u64>) {
+    let interval = Duration::from_millis(interval_ms.unwrap_or(DEFAULT_INTERVAL_MS).clamp(250, 10_000));
+
+    let mut monitor = MONITOR.lock().unwrap();
+    monitor.lease_until = Instant::now() + MONITOR_LEASE;
+    if monitor.running {
+        return;
+    }
+    monitor.running = true;
+
+    thread::spawn(move || loop {
+        {
+            let mut monitor = MONITOR.lock().unwrap();
+            if Instant::now() > monitor.lease_until {
+                monitor.running = false;
+                break;
+            }
+        }
+
+        let _ = app_handle.emit("system-snapshot", take_snapshot());
+        thread::sleep(interval);
+    });
+}
+
+#[tauri::command]
+pub fn stop_system_monitor() {
+    MONITOR.lock().unwrap().lease_until = Instant::now();
+}
+
+fn take_snapshot() -> SystemSnapshot {
+    let mut system = SYSTEM.lock().unwrap();
+    system.refresh_cpu_usage();
+    system.refresh_memory();
+
+    SystemSnapshot {
+        cpu_usage: system.global_cpu_usage(),
+        per_core: system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect(),
+        memory_used: system.used_memory(),
+        memory_total: system.total_memory(),
+        swap_used: system.used_swap(),
+        swap_total: system.total_swap(),
+        disks: disk_usage(),
+        uptime_secs: System::uptime(),
+    }
+}
+
+pub(crate) fn disk_usage() -> Vec<DiskUsage> {
+    let disks = Disks::new_with_refreshed_list();
+    let mut result: Vec<DiskUsage> = Vec::new();
+
+    for disk in disks.list() {
+        let file_system = disk.file_system().to_string_lossy().to_lowercase();
+        let mount_point = disk.mount_point().to_string_lossy().to_string();
+        if disk.total_space() == 0
+            || IGNORED_FILE_SYSTEMS.contains(&file_system.as_str())
+            || result.iter().any(|d| d.mount_point == mount_point)
+        {
+            continue;
+        }
+
+        result.push(DiskUsage {
+            name: disk.name().to_string_lossy().to_string(),
+            mount_point,
+            used: disk.total_space().saturating_sub(disk.available_space()),
+            total: disk.total_space(),
+            removable: disk.is_removable(),
+        });
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_snapshot() {
+        let snapshot = take_snapshot();
+
+        assert!(snapshot.memory_total > 0);
+        assert!(snapshot.memory_used <= snapshot.memory_total);
+        assert!(!snapshot.per_core.is_empty());
+        assert!(snapshot.disks.iter().all(|disk| disk.used <= disk.total && disk.total > 0));
+    }
+
+    #[test]
+    fn test_disk_usage_has_unique_mount_points() {
+        let disks = disk_usage();
+        let mut mount_points: Vec<&str> = disks.iter().map(|d| d.mount_point.as_str()).collect();
+        mount_points.sort();
+        mount_points.dedup();
+        assert_eq!(mount_points.len(), disks.len());
+    }
+}
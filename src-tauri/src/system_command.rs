@@ -0,0 +1,116 @@
+// 调用系统命令行工具（nmcli、networksetup、netsh、PowerShell 等）并获取输出
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+// Windows 下不弹出控制台窗口
+#[cfg(windows)]
+const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+pub(crate) fn command(program: &str) -> Command {
+    #[allow(unused_mut)]
+    let mut command = Command::new(program);
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    command
+}
+
+// 执行命令并返回标准输出，失败时返回标准错误的内容
+pub(crate) fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = command(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("执行 {} 失败: {}", program, e))?;
+    into_result(program, output)
+}
+
+// 通过标准输入传入数据（例如令牌），避免出现在命令行参数中
+pub(crate) fn run_with_input(program: &str, args: &[&str], input: &str) -> Result<String, String> {
+    let mut child = command(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("执行 {} 失败: {}", program, e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes()).map_err(|e| format!("写入 {} 输入失败: {}", program, e))?;
+    }
+    let output = child.wait_with_output().map_err(|e| format!("执行 {} 失败: {}", program, e))?;
+    into_result(program, output)
+}
+
+// 通过环境变量传入会话密钥等敏感数据，避免出现在命令行参数中
+pub(crate) fn run_with_env(program: &str, args: &[&str], envs: &[(&str, &str)]) -> Result<String, String> {
+    let output = command(program)
+        .args(args)
+        .envs(envs.iter().copied())
+        .output()
+        .map_err(|e| format!("执行 {} 失败: {}", program, e))?;
+    into_result(program, output)
+}
+
+fn into_result(program: &str, output: Output) -> Result<String, String> {
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let message = if stderr.trim().is_empty() { stdout.trim() } else { stderr.trim() };
+        Err(format!("{} 执行失败: {}", program, message))
+    }
+}
+
+// 执行 PowerShell 脚本，输出统一为 UTF-8，避免设备名、曲目名等非 ASCII 文本乱码
+pub(crate) fn powershell(script: &str) -> Result<String, String> {
+    // WinForms 窗口需要 STA 线程
+    run("powershell", &["-NoProfile", "-STA", "-Command", &utf8_script(script)])
+}
+
+// 脚本中通过 [Console]::In.ReadToEnd() 读取 input
+pub(crate) fn powershell_with_input(script: &str, input: &str) -> Result<String, String> {
+    run_with_input("powershell", &["-NoProfile", "-Command", &utf8_script(script)], input)
+}
+
+fn utf8_script(script: &str) -> String {
+    format!("[Console]::OutputEncoding = [System.Text.Encoding]::UTF8\n{}", script)
+}
+
+// PowerShell 中等待 WinRT 异步操作的辅助函数，用法：Await $operation ([ResultType])
+pub(crate) const WINRT_AWAIT: &str = r#"Add-Type -AssemblyName System.Runtime.WindowsRuntime
+$asTask = ([System.WindowsRuntimeSystemExtensions].GetMethods() | Where-Object { $_.Name -eq 'AsTask' -and $_.GetParameters().Count -eq 1 -and $_.GetParameters()[0].ParameterType.Name -eq 'IAsyncOperation`1' })[0]
+function Await($op, $type) { $task = $asTask.MakeGenericMethod($type).Invoke($null, @($op)); $task.Wait(-1) | Out-Null; $task.Result }"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_missing_program() {
+        let result = run("launch-rs-definitely-missing-program", &[]);
+        assert!(result.unwrap_err().contains("执行 launch-rs-definitely-missing-program 失败"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_captures_output_and_errors() {
+        assert_eq!(run("sh", &["-c", "echo hello"]).unwrap(), "hello\n");
+        let error = run("sh", &["-c", "echo oops >&2; exit 3"]).unwrap_err();
+        assert!(error.ends_with("oops"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_with_input() {
+        assert_eq!(run_with_input("sh", &["-c", "tr a-z A-Z"], "token").unwrap(), "TOKEN");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_with_env() {
+        assert_eq!(run_with_env("sh", &["-c", "printf %s \"$LAUNCH_RS_TEST\""], &[("LAUNCH_RS_TEST", "session")]).unwrap(), "session");
+    }
+}
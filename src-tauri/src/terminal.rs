@@ -0,0 +1,221 @@
+// 在新的终端窗口中运行命令（SSH 连接、man 手册等）
+use crate::system_command::{command, run};
+use std::io::ErrorKind;
+use std::path::Path;
+
+// Linux 下依次尝试的终端及其"执行命令"参数
+const LINUX_TERMINALS: &[(&str, &[&str])] = &[
+    ("x-terminal-emulator", &["-e"]),
+    ("gnome-terminal", &["--"]),
+    ("konsole", &["-e"]),
+    ("xfce4-terminal", &["-x"]),
+    ("kitty", &[]),
+    ("alacritty", &["-e"]),
+    ("wezterm", &["start", "--"]),
+    ("foot", &[]),
+    ("xterm", &["-e"]),
+];
+
+// template 为用户自定义的终端命令，其中 {cmd} 会被替换为要执行的命令，为空时使用系统默认终端
+pub(crate) fn open_in_terminal(args: &[&str], template: Option<&str>) -> Result<(), String> {
+    launch(args, template, None)
+}
+
+// 在指定目录中打开终端，运行用户的默认 shell
+pub(crate) fn open_terminal_in(dir: &Path, template: Option<&str>) -> Result<(), String> {
+    let has_template = template.is_some_and(|template| !template.trim().is_empty());
+    match std::env::consts::OS {
+        "macos" if !has_template => run("open", &["-a", "Terminal", &dir.to_string_lossy()]).map(|_| ()),
+        "windows" if !has_template => spawn("cmd", &["/c", "start", "", "cmd"], Some(dir)),
+        _ => {
+            let shell = default_shell();
+            launch(&[&shell], template, Some(dir))
+        }
+    }
+}
+
+fn default_shell() -> String {
+    if cfg!(windows) {
+        "cmd".to_string()
+    } else {
+        std::env::var("SHELL").ok().filter(|shell| !shell.trim().is_empty()).unwrap_or_else(|| "/bin/sh".to_string())
+    }
+}
+
+fn launch(args: &[&str], template: Option<&str>, dir: Option<&Path>) -> Result<(), String> {
+    if args.is_empty() {
+        return Err("没有要执行的命令".to_string());
+    }
+
+    if let Some(template) = template.map(str::trim).filter(|template| !template.is_empty()) {
+        let argv = expand_template(template, args)?;
+        return spawn(&argv[0], &argv[1..].iter().map(String::as_str).collect::<Vec<_>>(), dir);
+    }
+
+    match std::env::consts::OS {
+        "macos" => {
+            let command_line = match dir {
+                Some(dir) => format!("cd {} && {}", shell_quote(&dir.to_string_lossy()), shell_join(args)),
+                None => shell_join(args),
+            };
+            let script = format!(
+                "tell application \"Terminal\"\nactivate\ndo script \"{}\"\nend tell",
+                escape_applescript(&command_line)
+            );
+            run("osascript", &["-e", &script]).map(|_| ())
+        }
+        "linux" => {
+            // 优先使用 $TERMINAL 指定的终端
+            let preferred = std::env::var("TERMINAL").ok().filter(|terminal| !terminal.trim().is_empty());
+            let mut last_error = "未找到可用的终端程序，请在插件设置中填写终端命令".to_string();
+            for (program, prefix) in preferred.iter().map(|terminal| (terminal.as_str(), &["-e"][..])).chain(LINUX_TERMINALS.iter().copied()) {
+                let mut argv: Vec<&str> = prefix.to_vec();
+                argv.extend_from_slice(args);
+                let mut terminal = command(program);
+                terminal.args(&argv);
+                if let Some(dir) = dir {
+                    terminal.current_dir(dir);
+                }
+                match terminal.spawn() {
+                    Ok(_) => return Ok(()),
+                    Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                    Err(e) => last_error = format!("启动 {} 失败: {}", program, e),
+                }
+            }
+            Err(last_error)
+        }
+        // start 会为命令打开新的控制台窗口
+        "windows" => {
+            let mut argv = vec!["/c", "start", "", "cmd", "/k"];
+            argv.extend_from_slice(args);
+            spawn("cmd", &argv, dir)
+        }
+        os => Err(format!("当前系统不支持打开终端: {}", os)),
+    }
+}
+
+fn spawn(program: &str, args: &[&str], dir: Option<&Path>) -> Result<(), String> {
+    let mut child = command(program);
+    child.args(args);
+    if let Some(dir) = dir {
+        child.current_dir(dir);
+    }
+    child
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("启动 {} 失败: {}", program, e))
+}
+
+// 单独的 {cmd} 参数展开为多个参数，嵌在其他文本中时替换为整条命令
+fn expand_template(template: &str, args: &[&str]) -> Result<Vec<String>, String> {
+    let tokens = split_command_line(template)?;
+    let mut argv = Vec::new();
+    let mut replaced = false;
+    for token in tokens {
+        if token == "{cmd}" {
+            argv.extend(args.iter().map(|arg| arg.to_string()));
+            replaced = true;
+        } else if token.contains("{cmd}") {
+            argv.push(token.replace("{cmd}", &shell_join(args)));
+            replaced = true;
+        } else {
+            argv.push(token);
+        }
+    }
+    // 没有占位符时把命令追加到末尾
+    if !replaced {
+        argv.extend(args.iter().map(|arg| arg.to_string()));
+    }
+    if argv.is_empty() {
+        return Err("终端命令为空".to_string());
+    }
+    Ok(argv)
+}
+
+// 按空白拆分命令行，支持单引号和双引号
+pub(crate) fn split_command_line(line: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut quote: Option<char> = None;
+
+    for c in line.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '\'' || c == '"' => {
+                quote = Some(c);
+                in_token = true;
+            }
+            None if c.is_whitespace() => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_token = true;
+            }
+        }
+    }
+    if quote.is_some() {
+        return Err(format!("终端命令中的引号不匹配: {}", line));
+    }
+    if in_token {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
+
+// 拼成 POSIX shell 可以直接执行的命令
+pub(crate) fn shell_join(args: &[&str]) -> String {
+    args.iter().map(|arg| shell_quote(arg)).collect::<Vec<_>>().join(" ")
+}
+
+fn shell_quote(arg: &str) -> String {
+    let safe = !arg.is_empty() && arg.chars().all(|c| c.is_ascii_alphanumeric() || "@%_+=:,./-".contains(c));
+    if safe {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+fn escape_applescript(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_command_line() {
+        assert_eq!(split_command_line("kitty -e {cmd}").unwrap(), vec!["kitty", "-e", "{cmd}"]);
+        assert_eq!(
+            split_command_line(r#"bash -c "{cmd}; exec bash" ''"#).unwrap(),
+            vec!["bash", "-c", "{cmd}; exec bash", ""]
+        );
+        assert!(split_command_line("kitty 'oops").is_err());
+    }
+
+    #[test]
+    fn test_expand_template() {
+        assert_eq!(
+            expand_template("wezterm start -- {cmd}", &["ssh", "prod-web-1"]).unwrap(),
+            vec!["wezterm", "start", "--", "ssh", "prod-web-1"]
+        );
+        assert_eq!(
+            expand_template("bash -c '{cmd}; read'", &["man", "ls"]).unwrap(),
+            vec!["bash", "-c", "man ls; read"]
+        );
+        assert_eq!(expand_template("alacritty -e", &["ssh", "db"]).unwrap(), vec!["alacritty", "-e", "ssh", "db"]);
+    }
+
+    #[test]
+    fn test_shell_join() {
+        assert_eq!(shell_join(&["ssh", "user@host", "-p", "2222"]), "ssh user@host -p 2222");
+        assert_eq!(shell_join(&["echo", "it's here", ""]), "echo 'it'\\''s here' ''");
+    }
+}
@@ -0,0 +1,239 @@
+// 文本对比：剪贴板与上一次复制的内容，或两个文件，生成统一格式（unified）的差异
+use crate::clipboard;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CONTEXT_LINES: usize = 3;
+// 去掉公共首尾后的行数乘积上限，避免 LCS 表占用过多内存
+const MAX_DIFF_CELLS: usize = 16_000_000;
+const MAX_FILE_SIZE: u64 = 5 * 1024 * 1024;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TextDiff {
+    pub left_label: String,
+    pub right_label: String,
+    pub unified: String,
+    pub added: usize,
+    pub removed: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+// 左侧为上一次复制的文本，右侧为当前剪贴板
+#[tauri::command(async)]
+pub fn diff_clipboard() -> Result<TextDiff, String> {
+    let (previous, current) = clipboard::recent_texts()?;
+    let current = current.ok_or("剪贴板中没有文本")?;
+    let previous = previous.ok_or("没有上一次复制的文本，请先复制要对比的两段内容")?;
+    diff("上一次复制", "当前剪贴板", &previous, &current)
+}
+
+#[tauri::command(async)]
+pub fn diff_files(left: String, right: String) -> Result<TextDiff, String> {
+    let left_text = read_text_file(&expand_home(&left))?;
+    let right_text = read_text_file(&expand_home(&right))?;
+    diff(&left, &right, &left_text, &right_text)
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+fn read_text_file(path: &Path) -> Result<String, String> {
+    let metadata = fs::metadata(path).map_err(|e| format!("无法读取文件 {}: {}", path.display(), e))?;
+    if !metadata.is_file() {
+        return Err(format!("不是文件: {}", path.display()));
+    }
+    if metadata.len() > MAX_FILE_SIZE {
+        return Err(format!("文件太大，无法对比: {}", path.display()));
+    }
+    let bytes = fs::read(path).map_err(|e| format!("无法读取文件 {}: {}", path.display(), e))?;
+    if bytes.contains(&0) {
+        return Err(format!("不支持对比二进制文件: {}", path.display()));
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn diff(left_label: &str, right_label: &str, left: &str, right: &str) -> Result<TextDiff, String> {
+    let left_lines: Vec<&str> = left.lines().collect();
+    let right_lines: Vec<&str> = right.lines().collect();
+    let ops = diff_lines(&left_lines, &right_lines)?;
+
+    let added = ops.iter().filter(|op| matches!(op, Op::Insert(_))).count();
+    let removed = ops.iter().filter(|op| matches!(op, Op::Delete(_))).count();
+    let unified = if added + removed == 0 {
+        String::new()
+    } else {
+        format!("--- {}\n+++ {}\n{}", left_label, right_label, format_hunks(&ops, &left_lines, &right_lines))
+    };
+
+    Ok(TextDiff {
+        left_label: left_label.to_string(),
+        right_label: right_label.to_string(),
+        unified,
+        added,
+        removed,
+    })
+}
+
+// 先去掉公共的开头和结尾，剩余部分用最长公共子序列对齐
+fn diff_lines(left: &[&str], right: &[&str]) -> Result<Vec<Op>, String> {
+    let prefix = left.iter().zip(right).take_while(|(a, b)| a == b).count();
+    let suffix = left[prefix..].iter().rev().zip(right[prefix..].iter().rev()).take_while(|(a, b)| a == b).count();
+    let (a, b) = (&left[prefix..left.len() - suffix], &right[prefix..right.len() - suffix]);
+    if a.len().saturating_mul(b.len()) > MAX_DIFF_CELLS {
+        return Err("差异过大，无法对比".to_string());
+    }
+
+    // lcs[i][j]：a[i..] 与 b[j..] 的最长公共子序列长度
+    let width = b.len() + 1;
+    let mut lcs = vec![0u32; (a.len() + 1) * width];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i * width + j] = if a[i] == b[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut ops: Vec<Op> = (0..prefix).map(|i| Op::Equal(i, i)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            ops.push(Op::Equal(prefix + i, prefix + j));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[(i + 1) * width + j] >= lcs[i * width + j + 1]) {
+            // 删除优先于插入，与 diff -u 的输出顺序一致
+            ops.push(Op::Delete(prefix + i));
+            i += 1;
+        } else {
+            ops.push(Op::Insert(prefix + j));
+            j += 1;
+        }
+    }
+    ops.extend((0..suffix).map(|k| Op::Equal(left.len() - suffix + k, right.len() - suffix + k)));
+    Ok(ops)
+}
+
+// 把变更及前后 CONTEXT_LINES 行上下文合并为若干块
+fn format_hunks(ops: &[Op], left: &[&str], right: &[&str]) -> String {
+    let changes: Vec<usize> = ops.iter().enumerate().filter(|(_, op)| !matches!(op, Op::Equal(..))).map(|(index, _)| index).collect();
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for index in changes {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + CONTEXT_LINES + 1).min(ops.len());
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    let mut out = String::new();
+    for (start, end) in ranges {
+        let hunk = &ops[start..end];
+        // 块起始行号：块内第一个左/右行，没有时取之前的位置
+        let left_start = position(ops, start, |op| match op {
+            Op::Equal(i, _) | Op::Delete(i) => Some(i),
+            Op::Insert(_) => None,
+        });
+        let right_start = position(ops, start, |op| match op {
+            Op::Equal(_, j) | Op::Insert(j) => Some(j),
+            Op::Delete(_) => None,
+        });
+        let left_count = hunk.iter().filter(|op| !matches!(op, Op::Insert(_))).count();
+        let right_count = hunk.iter().filter(|op| !matches!(op, Op::Delete(_))).count();
+
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(left_start, left_count),
+            hunk_range(right_start, right_count)
+        ));
+        for op in hunk {
+            match *op {
+                Op::Equal(i, _) => out.push_str(&format!(" {}\n", left[i])),
+                Op::Delete(i) => out.push_str(&format!("-{}\n", left[i])),
+                Op::Insert(j) => out.push_str(&format!("+{}\n", right[j])),
+            }
+        }
+    }
+    out
+}
+
+// 返回从 start 开始第一个有对应行的行号（从 0 开始），都没有时为之前最后一行的下一行
+fn position(ops: &[Op], start: usize, line: impl Fn(Op) -> Option<usize>) -> usize {
+    ops[start..].iter().find_map(|op| line(*op))
+        .or_else(|| ops[..start].iter().rev().find_map(|op| line(*op)).map(|index| index + 1))
+        .unwrap_or(0)
+}
+
+// 与 diff -u 一致：行号从 1 开始，空范围时行号为前一行
+fn hunk_range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, count),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_unified_diff() {
+        let left = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\n";
+        let right = "a\nb\nc\nD\ne\nf\ng\nh\ni\nj\nk\nl\nm\nn\n";
+        let result = diff("old", "new", left, right).unwrap();
+        assert_eq!((result.added, result.removed), (2, 1));
+        assert_eq!(
+            result.unified,
+            "--- old\n+++ new\n@@ -1,7 +1,7 @@\n a\n b\n c\n-d\n+D\n e\n f\n g\n@@ -11,3 +11,4 @@\n k\n l\n m\n+n\n"
+        );
+
+        // 间隔不超过两倍上下文时合并为一块
+        let result = diff("old", "new", "a\nb\nc\nd\ne\nf\ng\nh", "A\nb\nc\nd\ne\nf\ng\nH").unwrap();
+        assert_eq!(result.unified.matches("@@ -").count(), 1);
+    }
+
+    #[test]
+    fn test_diff_edge_cases() {
+        let result = diff("old", "new", "same\n", "same").unwrap();
+        assert_eq!((result.added, result.removed, result.unified.as_str()), (0, 0, ""));
+
+        let result = diff("old", "new", "", "x\ny").unwrap();
+        assert_eq!(result.unified, "--- old\n+++ new\n@@ -0,0 +1,2 @@\n+x\n+y\n");
+
+        let result = diff("old", "new", "x\ny", "y").unwrap();
+        assert_eq!(result.unified, "--- old\n+++ new\n@@ -1,2 +1 @@\n-x\n y\n");
+    }
+
+    #[test]
+    fn test_diff_files() {
+        let dir = TempDir::new().unwrap();
+        let left = dir.path().join("left.txt");
+        let right = dir.path().join("right.txt");
+        let binary = dir.path().join("image.bin");
+        fs::write(&left, "one\ntwo\n").unwrap();
+        fs::write(&right, "one\nthree\n").unwrap();
+        fs::write(&binary, [0u8, 1, 2]).unwrap();
+
+        let path = |path: &Path| path.to_string_lossy().to_string();
+        let result = diff_files(path(&left), path(&right)).unwrap();
+        assert!(result.unified.contains("-two\n+three\n"));
+        assert!(diff_files(path(&left), path(&binary)).unwrap_err().contains("二进制"));
+        assert!(diff_files(path(&left), path(dir.path())).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+// 文本转换：大小写、命名风格（camelCase、snake_case 等）、slug 以及按行整理
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+type Transform = fn(&str) -> String;
+
+// (类型, 名称, 转换函数)
+const TRANSFORMS: &[(&str, &str, Transform)] = &[
+    ("upper", "大写", |text| text.to_uppercase()),
+    ("lower", "小写", |text| text.to_lowercase()),
+    ("title", "标题格式", title_case),
+    ("camel", "camelCase", |text| per_line(text, camel_case)),
+    ("pascal", "PascalCase", |text| per_line(text, pascal_case)),
+    ("snake", "snake_case", |text| per_line(text, |line| join_words(line, "_", str::to_lowercase))),
+    ("constant", "CONSTANT_CASE", |text| per_line(text, |line| join_words(line, "_", str::to_uppercase))),
+    ("kebab", "kebab-case", |text| per_line(text, |line| join_words(line, "-", str::to_lowercase))),
+    ("slug", "slug", |text| per_line(text, slugify)),
+    ("trim", "去除首尾空白", trim_lines),
+    ("dedupe", "删除重复行", dedupe_lines),
+    ("sort", "按行排序", sort_lines),
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TextTransform {
+    pub kind: String,
+    pub name: String,
+    pub value: String,
+}
+
+// 与原文相同或结果为空的转换不返回
+#[tauri::command]
+pub fn transform_text(text: String) -> Vec<TextTransform> {
+    TRANSFORMS.iter()
+        .map(|(kind, name, transform)| TextTransform {
+            kind: kind.to_string(),
+            name: name.to_string(),
+            value: transform(&text),
+        })
+        .filter(|transform| !transform.value.trim().is_empty() && transform.value != text)
+        .collect()
+}
+
+// 多行文本逐行转换，便于批量处理标识符列表
+fn per_line(text: &str, transform: impl Fn(&str) -> String) -> String {
+    text.lines().map(transform).collect::<Vec<_>>().join("\n")
+}
+
+// 按非字母数字字符和大小写边界拆分单词：parseHTTPResponse2 -> parse, HTTP, Response2
+fn split_words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in text.split(|c: char| !c.is_alphanumeric()).filter(|part| !part.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        for (i, &c) in chars.iter().enumerate() {
+            let boundary = i > 0 && c.is_uppercase() && {
+                let previous = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+                previous.is_lowercase() || previous.is_numeric() || (previous.is_uppercase() && next_is_lower)
+            };
+            if boundary && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            word.push(c);
+        }
+        words.push(word);
+    }
+    words
+}
+
+fn join_words(text: &str, separator: &str, case: fn(&str) -> String) -> String {
+    split_words(text).iter().map(|word| case(word)).collect::<Vec<_>>().join(separator)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+        None => String::new(),
+    }
+}
+
+fn camel_case(text: &str) -> String {
+    split_words(text).iter()
+        .enumerate()
+        .map(|(i, word)| if i == 0 { word.to_lowercase() } else { capitalize(word) })
+        .collect()
+}
+
+fn pascal_case(text: &str) -> String {
+    split_words(text).iter().map(|word| capitalize(word)).collect()
+}
+
+// 保留原有的空白和标点，只调整每个词的大小写
+fn title_case(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut at_word_start = true;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            at_word_start = true;
+            result.push(c);
+        } else if at_word_start {
+            result.extend(c.to_uppercase());
+            at_word_start = false;
+        } else {
+            result.extend(c.to_lowercase());
+        }
+    }
+    result
+}
+
+// 不拆分大小写边界，撇号直接删除（don't -> dont）
+fn slugify(text: &str) -> String {
+    text.to_lowercase()
+        .replace(['\'', '’'], "")
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+// 去掉每行首尾空白以及开头和结尾的空行
+fn trim_lines(text: &str) -> String {
+    text.lines().map(str::trim).collect::<Vec<_>>().join("\n").trim_matches('\n').to_string()
+}
+
+// 保留每行第一次出现的位置
+fn dedupe_lines(text: &str) -> String {
+    let mut seen = HashSet::new();
+    text.lines().filter(|line| seen.insert(*line)).collect::<Vec<_>>().join("\n")
+}
+
+fn sort_lines(text: &str) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    lines.sort_by_key(|line| line.to_lowercase());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(text: &str, kind: &str) -> Option<String> {
+        transform_text(text.to_string()).into_iter().find(|transform| transform.kind == kind).map(|transform| transform.value)
+    }
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(split_words("parseHTTPResponse2"), ["parse", "HTTP", "Response2"]);
+        assert_eq!(split_words("user_id-value  x"), ["user", "id", "value", "x"]);
+        assert_eq!(split_words("IOError"), ["IO", "Error"]);
+        assert_eq!(split_words("version2Beta"), ["version2", "Beta"]);
+    }
+
+    #[test]
+    fn test_naming_conventions() {
+        let text = "Hello world-fooBar";
+        assert_eq!(value(text, "camel").as_deref(), Some("helloWorldFooBar"));
+        assert_eq!(value(text, "pascal").as_deref(), Some("HelloWorldFooBar"));
+        assert_eq!(value(text, "snake").as_deref(), Some("hello_world_foo_bar"));
+        assert_eq!(value(text, "constant").as_deref(), Some("HELLO_WORLD_FOO_BAR"));
+        assert_eq!(value(text, "kebab").as_deref(), Some("hello-world-foo-bar"));
+        // 多行逐行转换
+        assert_eq!(value("user id\norder total", "camel").as_deref(), Some("userId\norderTotal"));
+    }
+
+    #[test]
+    fn test_case_and_slug() {
+        assert_eq!(value("the QUICK brown fox", "title").as_deref(), Some("The Quick Brown Fox"));
+        assert_eq!(value("Don't Stop: Believin' 2024!", "slug").as_deref(), Some("dont-stop-believin-2024"));
+        assert_eq!(value("héllo", "upper").as_deref(), Some("HÉLLO"));
+    }
+
+    #[test]
+    fn test_line_transforms() {
+        assert_eq!(value("\n  a  \n b\n\n", "trim").as_deref(), Some("a\nb"));
+        assert_eq!(value("b\na\nb\nc\na", "dedupe").as_deref(), Some("b\na\nc"));
+        assert_eq!(value("b\nA\nc", "sort").as_deref(), Some("A\nb\nc"));
+    }
+
+    #[test]
+    fn test_unchanged_transforms_are_skipped() {
+        let kinds: Vec<String> = transform_text("hello".to_string()).into_iter().map(|transform| transform.kind).collect();
+        assert!(!kinds.contains(&"lower".to_string()));
+        assert!(!kinds.contains(&"snake".to_string()));
+        assert!(kinds.contains(&"upper".to_string()));
+        assert!(transform_text("  ".to_string()).iter().all(|transform| !transform.value.trim().is_empty()));
+    }
+}
@@ -0,0 +1,409 @@
+// 倒计时与番茄钟：由后端线程计时，窗口隐藏后依然有效，结束时发送系统通知
+use crate::notifications;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+// 单个计时器的最长时间
+const MAX_DURATION: Duration = Duration::from_secs(24 * 60 * 60);
+// 计时线程检查取消状态的间隔
+const TICK: Duration = Duration::from_millis(250);
+
+const DEFAULT_WORK_MINUTES: u64 = 25;
+const DEFAULT_BREAK_MINUTES: u64 = 5;
+const DEFAULT_ROUNDS: u32 = 4;
+
+// 正在运行的计时器
+static TIMERS: LazyLock<Mutex<HashMap<u64, TimerEntry>>> = LazyLock::new(|| {
+    Mutex::new(HashMap::new())
+});
+static NEXT_TIMER_ID: AtomicU64 = AtomicU64::new(1);
+
+// 计时器阶段（普通计时器只有一个阶段，番茄钟为工作/休息交替）
+#[derive(Debug, Clone, PartialEq)]
+struct Phase {
+    label: String,
+    duration: Duration,
+}
+
+struct TimerEntry {
+    label: String,
+    kind: String,
+    play_sound: bool,
+    phases: Vec<Phase>,
+    current_phase: usize,
+    phase_deadline: Instant,
+    cancelled: Arc<AtomicBool>,
+}
+
+// 返回给前端的计时器信息
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TimerInfo {
+    pub id: u64,
+    pub label: String,
+    pub kind: String, // "timer" | "pomodoro"
+    pub phase: String,
+    pub phase_index: usize,
+    pub phase_count: usize,
+    pub duration_secs: u64,
+    pub remaining_secs: u64,
+    pub ends_at: u64, // 当前阶段结束时间（毫秒时间戳）
+    pub play_sound: bool,
+}
+
+#[tauri::command]
+pub fn start_timer(
+    app_handle: AppHandle,
+    spec: String,
+    play_sound: Option<bool>,
+) -> Result<TimerInfo, String> {
+    let (duration, label) = parse_timer_spec(&spec)?;
+    let label = label.unwrap_or_else(|| "计时器".to_string());
+    let phases = vec![Phase { label: label.clone(), duration }];
+
+    let info = register_timer(label, "timer", phases, play_sound.unwrap_or(true));
+    spawn_timer(app_handle, info.id);
+    Ok(info)
+}
+
+#[tauri::command]
+pub fn start_pomodoro(
+    app_handle: AppHandle,
+    work_minutes: Option<u64>,
+    break_minutes: Option<u64>,
+    rounds: Option<u32>,
+    play_sound: Option<bool>,
+) -> Result<TimerInfo, String> {
+    let phases = pomodoro_phases(
+        work_minutes.unwrap_or(DEFAULT_WORK_MINUTES),
+        break_minutes.unwrap_or(DEFAULT_BREAK_MINUTES),
+        rounds.unwrap_or(DEFAULT_ROUNDS),
+    )?;
+
+    let info = register_timer("番茄钟".to_string(), "pomodoro", phases, play_sound.unwrap_or(true));
+    spawn_timer(app_handle, info.id);
+    Ok(info)
+}
+
+#[tauri::command]
+pub fn list_timers() -> Vec<TimerInfo> {
+    let timers = TIMERS.lock().unwrap();
+    let mut list: Vec<TimerInfo> = timers.iter().map(|(id, entry)| timer_info(*id, entry)).collect();
+    list.sort_by_key(|info| info.remaining_secs);
+    list
+}
+
+#[tauri::command]
+pub fn cancel_timer(app_handle: AppHandle, id: u64) -> Result<(), String> {
+    remove_timer(id)?;
+    let _ = app_handle.emit("timers-changed", list_timers());
+    Ok(())
+}
+
+// 解析 "10m tea"、"1h 30m"、"90s 泡面"，纯数字按分钟计算
+fn parse_timer_spec(spec: &str) -> Result<(Duration, Option<String>), String> {
+    let mut total = Duration::ZERO;
+    let mut tokens = spec.split_whitespace().peekable();
+
+    while let Some(token) = tokens.peek() {
+        match parse_duration_token(token) {
+            Some(duration) => {
+                total = total.checked_add(duration).ok_or("计时时长不能超过 24 小时")?;
+                tokens.next();
+            }
+            None => break,
+        }
+    }
+
+    if total.is_zero() {
+        return Err(format!("无法解析计时时长: {}", spec.trim()));
+    }
+    if total > MAX_DURATION {
+        return Err("计时时长不能超过 24 小时".to_string());
+    }
+
+    let label = tokens.collect::<Vec<_>>().join(" ");
+    Ok((total, if label.is_empty() { None } else { Some(label) }))
+}
+
+// 解析单个时长片段，支持组合写法如 "1h30m"
+pub(crate) fn parse_duration_token(token: &str) -> Option<Duration> {
+    let token = token.to_lowercase();
+    if token.chars().all(|c| c.is_ascii_digit()) {
+        return token.parse::<u64>().ok()?.checked_mul(60).map(Duration::from_secs);
+    }
+
+    let mut total = 0u64;
+    let mut rest = token.as_str();
+    while !rest.is_empty() {
+        let digits_end = rest.find(|c: char| !c.is_ascii_digit())?;
+        if digits_end == 0 {
+            return None;
+        }
+        let value: u64 = rest[..digits_end].parse().ok()?;
+        rest = &rest[digits_end..];
+
+        let unit_end = rest.find(|c: char| c.is_ascii_digit()).unwrap_or(rest.len());
+        let multiplier = match &rest[..unit_end] {
+            "w" | "week" | "weeks" => 7 * 86_400,
+            "d" | "day" | "days" => 86_400,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60,
+            "s" | "sec" | "secs" | "second" | "seconds" => 1,
+            _ => return None,
+        };
+        total = total.checked_add(value.checked_mul(multiplier)?)?;
+        rest = &rest[unit_end..];
+    }
+
+    Some(Duration::from_secs(total))
+}
+
+fn pomodoro_phases(work_minutes: u64, break_minutes: u64, rounds: u32) -> Result<Vec<Phase>, String> {
+    if work_minutes == 0 || work_minutes > 180 || break_minutes > 60 {
+        return Err("番茄钟时长设置无效".to_string());
+    }
+    if rounds == 0 || rounds > 12 {
+        return Err("番茄钟轮数必须在 1 到 12 之间".to_string());
+    }
+
+    let mut phases = Vec::new();
+    for round in 1..=rounds {
+        phases.push(Phase {
+            label: format!("专注 {}/{}", round, rounds),
+            duration: Duration::from_secs(work_minutes * 60),
+        });
+        // 最后一轮之后不需要休息
+        if round < rounds && break_minutes > 0 {
+            phases.push(Phase {
+                label: format!("休息 {}/{}", round, rounds - 1),
+                duration: Duration::from_secs(break_minutes * 60),
+            });
+        }
+    }
+
+    Ok(phases)
+}
+
+fn register_timer(label: String, kind: &str, phases: Vec<Phase>, play_sound: bool) -> TimerInfo {
+    let id = NEXT_TIMER_ID.fetch_add(1, Ordering::SeqCst);
+    let entry = TimerEntry {
+        label,
+        kind: kind.to_string(),
+        play_sound,
+        phase_deadline: Instant::now() + phases[0].duration,
+        phases,
+        current_phase: 0,
+        cancelled: Arc::new(AtomicBool::new(false)),
+    };
+
+    let info = timer_info(id, &entry);
+    TIMERS.lock().unwrap().insert(id, entry);
+    info
+}
+
+fn remove_timer(id: u64) -> Result<(), String> {
+    let entry = TIMERS.lock().unwrap().remove(&id)
+        .ok_or_else(|| format!("计时器不存在: {}", id))?;
+    entry.cancelled.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+// 启动计时线程，每个阶段结束时发送通知
+fn spawn_timer(app_handle: AppHandle, id: u64) {
+    let _ = app_handle.emit("timers-changed", list_timers());
+
+    run_timer(id, move |info, finished| {
+        let body = if finished {
+            format!("{} 已结束", info.label)
+        } else {
+            format!("{} 已结束，下一阶段：{}", info.label, info.phase)
+        };
+        let _ = notifications::notify(&app_handle, "⏰ 时间到", &body, info.play_sound);
+        let _ = app_handle.emit("timer-finished", info);
+        let _ = app_handle.emit("timers-changed", list_timers());
+    });
+}
+
+// 计时循环；on_phase_end 的第二个参数表示整个计时器是否已结束
+fn run_timer<F>(id: u64, on_phase_end: F)
+where
+    F: Fn(&TimerInfo, bool) + Send + 'static,
+{
+    let cancelled = match TIMERS.lock().unwrap().get(&id) {
+        Some(entry) => Arc::clone(&entry.cancelled),
+        None => return,
+    };
+
+    thread::spawn(move || loop {
+        if cancelled.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let deadline = match TIMERS.lock().unwrap().get(&id) {
+            Some(entry) => entry.phase_deadline,
+            None => return,
+        };
+
+        let now = Instant::now();
+        if now < deadline {
+            thread::sleep(TICK.min(deadline - now));
+            continue;
+        }
+
+        // 当前阶段结束，进入下一阶段或移除计时器
+        let (info, finished) = {
+            let mut timers = TIMERS.lock().unwrap();
+            let Some(entry) = timers.get_mut(&id) else { return };
+            let finished_label = entry.phases[entry.current_phase].label.clone();
+
+            if entry.current_phase + 1 < entry.phases.len() {
+                entry.current_phase += 1;
+                entry.phase_deadline = Instant::now() + entry.phases[entry.current_phase].duration;
+                let mut info = timer_info(id, entry);
+                info.label = finished_label;
+                (info, false)
+            } else {
+                let entry = timers.remove(&id).expect("timer exists");
+                (timer_info(id, &entry), true)
+            }
+        };
+
+        on_phase_end(&info, finished);
+        if finished {
+            return;
+        }
+    });
+}
+
+fn timer_info(id: u64, entry: &TimerEntry) -> TimerInfo {
+    let remaining = entry.phase_deadline.saturating_duration_since(Instant::now());
+    let ends_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .saturating_add(remaining)
+        .as_millis() as u64;
+    let phase = &entry.phases[entry.current_phase];
+
+    TimerInfo {
+        id,
+        label: entry.label.clone(),
+        kind: entry.kind.clone(),
+        phase: phase.label.clone(),
+        phase_index: entry.current_phase,
+        phase_count: entry.phases.len(),
+        duration_secs: phase.duration.as_secs(),
+        remaining_secs: remaining.as_secs_f64().ceil() as u64,
+        ends_at,
+        play_sound: entry.play_sound,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_parse_timer_spec() {
+        let (duration, label) = parse_timer_spec("10m tea").unwrap();
+        assert_eq!(duration, Duration::from_secs(600));
+        assert_eq!(label.as_deref(), Some("tea"));
+
+        // 多个时长片段与组合写法
+        let (duration, label) = parse_timer_spec("1h 30m").unwrap();
+        assert_eq!(duration, Duration::from_secs(5400));
+        assert_eq!(label, None);
+        assert_eq!(parse_timer_spec("1h30m").unwrap().0, Duration::from_secs(5400));
+
+        // 纯数字按分钟
+        let (duration, label) = parse_timer_spec("5 泡面 时间").unwrap();
+        assert_eq!(duration, Duration::from_secs(300));
+        assert_eq!(label.as_deref(), Some("泡面 时间"));
+
+        assert_eq!(parse_timer_spec("90sec").unwrap().0, Duration::from_secs(90));
+        assert_eq!(parse_duration_token("2days"), Some(Duration::from_secs(2 * 86_400)));
+    }
+
+    #[test]
+    fn test_parse_timer_spec_invalid() {
+        assert!(parse_timer_spec("").is_err());
+        assert!(parse_timer_spec("tea 10m").is_err());
+        assert!(parse_timer_spec("0m").is_err());
+        assert!(parse_timer_spec("25h").is_err());
+        assert!(parse_timer_spec("10x").is_err());
+
+        // 溢出时返回错误而不是 panic
+        assert!(parse_timer_spec("99999999999999999999").is_err());
+        assert!(parse_timer_spec("999999999999999999").is_err());
+        assert!(parse_timer_spec("307445734561825860m 307445734561825860m").is_err());
+    }
+
+    #[test]
+    fn test_pomodoro_phases() {
+        let phases = pomodoro_phases(25, 5, 4).unwrap();
+        assert_eq!(phases.len(), 7);
+        assert_eq!(phases[0].duration, Duration::from_secs(25 * 60));
+        assert_eq!(phases[1].duration, Duration::from_secs(5 * 60));
+        assert_eq!(phases[6].label, "专注 4/4");
+
+        // 无休息
+        assert_eq!(pomodoro_phases(50, 0, 2).unwrap().len(), 2);
+
+        assert!(pomodoro_phases(0, 5, 4).is_err());
+        assert!(pomodoro_phases(25, 5, 0).is_err());
+    }
+
+    #[test]
+    fn test_register_and_cancel_timer() {
+        let phases = vec![Phase { label: "test".to_string(), duration: Duration::from_secs(60) }];
+        let info = register_timer("test".to_string(), "timer", phases, false);
+
+        assert_eq!(info.duration_secs, 60);
+        assert!(info.remaining_secs <= 60 && info.remaining_secs >= 59);
+        assert!(list_timers().iter().any(|t| t.id == info.id));
+
+        remove_timer(info.id).expect("Should cancel timer");
+        assert!(!list_timers().iter().any(|t| t.id == info.id));
+        assert!(remove_timer(info.id).is_err());
+    }
+
+    #[test]
+    fn test_run_timer_phases() {
+        let phases = vec![
+            Phase { label: "work".to_string(), duration: Duration::from_millis(50) },
+            Phase { label: "rest".to_string(), duration: Duration::from_millis(50) },
+        ];
+        let info = register_timer("pomodoro".to_string(), "pomodoro", phases, false);
+
+        let (tx, rx) = mpsc::channel();
+        run_timer(info.id, move |info, finished| {
+            tx.send((info.label.clone(), info.phase.clone(), finished)).unwrap();
+        });
+
+        let first = rx.recv_timeout(Duration::from_secs(2)).expect("First phase should end");
+        assert_eq!(first, ("work".to_string(), "rest".to_string(), false));
+
+        let second = rx.recv_timeout(Duration::from_secs(2)).expect("Timer should finish");
+        assert!(second.2);
+        assert!(!list_timers().iter().any(|t| t.id == info.id));
+    }
+
+    #[test]
+    fn test_cancelled_timer_does_not_fire() {
+        let phases = vec![Phase { label: "x".to_string(), duration: Duration::from_millis(100) }];
+        let info = register_timer("x".to_string(), "timer", phases, false);
+
+        let (tx, rx) = mpsc::channel();
+        run_timer(info.id, move |info, _| {
+            tx.send(info.id).unwrap();
+        });
+        remove_timer(info.id).unwrap();
+
+        assert!(rx.recv_timeout(Duration::from_millis(400)).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+// Unix 时间戳与日期时间互相转换
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+// 不带时区的日期时间格式（按本地时间解释）
+const DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%Y/%m/%d %H:%M:%S",
+    "%Y/%m/%d %H:%M",
+];
+
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d"];
+
+// 输入中可以携带的方向提示，如 "2024-06-01 14:00 to unix"
+const DIRECTION_SUFFIXES: &[&str] = &[" to unix", " to timestamp", " to date", " to utc"];
+
+// 转换结果
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TimestampInfo {
+    pub input_kind: String, // "timestamp" | "date"
+    pub unix_seconds: i64,
+    pub unix_millis: i64,
+    pub local: String,
+    pub utc: String,
+    pub iso8601: String,
+    pub relative: String,
+}
+
+#[tauri::command]
+pub fn convert_timestamp(input: String) -> Result<TimestampInfo, String> {
+    convert(&input, Utc::now(), &Local)
+}
+
+// 在指定时区下解析输入并生成各种表示
+fn convert<Tz>(input: &str, now: DateTime<Utc>, tz: &Tz) -> Result<TimestampInfo, String>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    let (input_kind, datetime) = parse_input(input, now, tz)?;
+    let local = datetime.with_timezone(tz);
+
+    Ok(TimestampInfo {
+        input_kind: input_kind.to_string(),
+        unix_seconds: datetime.timestamp(),
+        unix_millis: datetime.timestamp_millis(),
+        local: local.format("%Y-%m-%d %H:%M:%S %:z").to_string(),
+        utc: datetime.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+        iso8601: local.to_rfc3339(),
+        relative: format_relative(datetime.timestamp() - now.timestamp()),
+    })
+}
+
+fn parse_input<Tz: TimeZone>(
+    input: &str,
+    now: DateTime<Utc>,
+    tz: &Tz,
+) -> Result<(&'static str, DateTime<Utc>), String> {
+    let mut text = input.trim().to_lowercase();
+    for suffix in DIRECTION_SUFFIXES {
+        if let Some(stripped) = text.strip_suffix(suffix) {
+            text = stripped.trim().to_string();
+            break;
+        }
+    }
+
+    if text.is_empty() {
+        return Err("输入不能为空".to_string());
+    }
+
+    if text == "now" {
+        return Ok(("date", now));
+    }
+
+    if let Some(datetime) = parse_unix(&text) {
+        return Ok(("timestamp", datetime));
+    }
+
+    parse_date(&text, tz)
+        .map(|datetime| ("date", datetime))
+        .ok_or_else(|| format!("无法识别的时间格式: {}", input.trim()))
+}
+
+// 按位数判断时间戳精度：秒、毫秒、微秒、纳秒
+fn parse_unix(text: &str) -> Option<DateTime<Utc>> {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let value: i64 = text.parse().ok()?;
+    match digits.len() {
+        0..=11 => DateTime::from_timestamp(value, 0),
+        12..=14 => DateTime::from_timestamp_millis(value),
+        15..=17 => DateTime::from_timestamp_micros(value),
+        _ => Some(DateTime::from_timestamp_nanos(value)),
+    }
+}
+
+fn parse_date<Tz: TimeZone>(text: &str, tz: &Tz) -> Option<DateTime<Utc>> {
+    // 带时区信息的标准格式
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(&text.to_uppercase()) {
+        return Some(datetime.with_timezone(&Utc));
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc2822(text) {
+        return Some(datetime.with_timezone(&Utc));
+    }
+
+    // 以 "utc" 结尾时按 UTC 解释，否则按本地时区解释
+    let (text, is_utc) = match text.strip_suffix("utc").or_else(|| text.strip_suffix('z')) {
+        Some(stripped) => (stripped.trim(), true),
+        None => (text, false),
+    };
+
+    let naive = DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(text, format).ok())
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })?;
+
+    if is_utc {
+        Some(naive.and_utc())
+    } else {
+        tz.from_local_datetime(&naive)
+            .earliest()
+            .map(|datetime| datetime.with_timezone(&Utc))
+    }
+}
+
+// 相对时间描述
+fn format_relative(diff_seconds: i64) -> String {
+    let abs = diff_seconds.abs();
+    if abs < 60 {
+        return "刚刚".to_string();
+    }
+
+    let amount = match abs {
+        0..=3599 => format!("{} 分钟", abs / 60),
+        3600..=86_399 => format!("{} 小时", abs / 3600),
+        86_400..=31_535_999 => format!("{} 天", abs / 86_400),
+        _ => format!("{} 年", abs / 31_536_000),
+    };
+
+    if diff_seconds < 0 {
+        format!("{}前", amount)
+    } else {
+        format!("{}后", amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    fn fixed_now() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_717_171_717, 0).unwrap()
+    }
+
+    #[test]
+    fn test_convert_unix_seconds() {
+        let info = convert("1717171717", fixed_now(), &Utc).expect("Should convert");
+        assert_eq!(info.input_kind, "timestamp");
+        assert_eq!(info.unix_seconds, 1_717_171_717);
+        assert_eq!(info.unix_millis, 1_717_171_717_000);
+        assert_eq!(info.utc, "2024-05-31 16:08:37 UTC");
+        assert_eq!(info.relative, "刚刚");
+    }
+
+    #[test]
+    fn test_convert_unix_precision() {
+        // 毫秒
+        let info = convert("1717171717123", fixed_now(), &Utc).unwrap();
+        assert_eq!(info.unix_seconds, 1_717_171_717);
+        assert_eq!(info.unix_millis, 1_717_171_717_123);
+
+        // 微秒与纳秒
+        let info = convert("1717171717123456", fixed_now(), &Utc).unwrap();
+        assert_eq!(info.unix_millis, 1_717_171_717_123);
+        let info = convert("1717171717123456789", fixed_now(), &Utc).unwrap();
+        assert_eq!(info.unix_millis, 1_717_171_717_123);
+
+        // 负数时间戳
+        let info = convert("-86400", fixed_now(), &Utc).unwrap();
+        assert_eq!(info.utc, "1969-12-31 00:00:00 UTC");
+    }
+
+    #[test]
+    fn test_convert_date_to_unix() {
+        let info = convert("2024-06-01 14:00 to unix", fixed_now(), &Utc).unwrap();
+        assert_eq!(info.input_kind, "date");
+        assert_eq!(info.unix_seconds, 1_717_250_400);
+
+        // 仅日期
+        let info = convert("2024/06/01", fixed_now(), &Utc).unwrap();
+        assert_eq!(info.unix_seconds, 1_717_200_000);
+    }
+
+    #[test]
+    fn test_convert_with_local_offset() {
+        let tz = FixedOffset::east_opt(8 * 3600).unwrap();
+
+        // 无时区的输入按本地时区解释
+        let info = convert("2024-06-01 08:00:00", fixed_now(), &tz).unwrap();
+        assert_eq!(info.utc, "2024-06-01 00:00:00 UTC");
+        assert_eq!(info.local, "2024-06-01 08:00:00 +08:00");
+        assert_eq!(info.iso8601, "2024-06-01T08:00:00+08:00");
+
+        // 显式 UTC
+        let info = convert("2024-06-01 08:00 utc", fixed_now(), &tz).unwrap();
+        assert_eq!(info.utc, "2024-06-01 08:00:00 UTC");
+
+        // RFC 3339 自带时区
+        let info = convert("2024-06-01T08:00:00-05:00", fixed_now(), &tz).unwrap();
+        assert_eq!(info.utc, "2024-06-01 13:00:00 UTC");
+    }
+
+    #[test]
+    fn test_convert_now_and_invalid() {
+        let info = convert("now", fixed_now(), &Utc).unwrap();
+        assert_eq!(info.unix_seconds, fixed_now().timestamp());
+
+        assert!(convert("", fixed_now(), &Utc).is_err());
+        assert!(convert("yesterday-ish", fixed_now(), &Utc).is_err());
+        assert!(convert("2024-13-45", fixed_now(), &Utc).is_err());
+    }
+
+    #[test]
+    fn test_format_relative() {
+        assert_eq!(format_relative(30), "刚刚");
+        assert_eq!(format_relative(-120), "2 分钟前");
+        assert_eq!(format_relative(7200), "2 小时后");
+        assert_eq!(format_relative(-3 * 86_400), "3 天前");
+        assert_eq!(format_relative(2 * 31_536_000), "2 年后");
+    }
+}
@@ -0,0 +1,285 @@
+// 待办事项：保存在本地数据库，带截止时间的待办通过提醒系统按时通知
+use crate::{db, reminders};
+use chrono::{Local, NaiveDateTime};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+// 已完成的待办在列表中保留的时间（秒）
+const DONE_RETENTION_SECS: i64 = 24 * 60 * 60;
+// 待办提醒内容的前缀
+const REMINDER_PREFIX: &str = "待办：";
+
+// 待办事项
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Todo {
+    pub id: i64,
+    pub title: String,
+    pub done: bool,
+    pub created_at: i64,
+    pub completed_at: Option<i64>,
+    pub due_at: Option<i64>,
+    pub reminder_id: Option<i64>,
+}
+
+// 解析预览（尚未保存）
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ParsedTodo {
+    pub title: String,
+    pub due_at: Option<i64>,
+    pub due_text: Option<String>,
+}
+
+#[tauri::command]
+pub fn preview_todo(text: String) -> Result<ParsedTodo, String> {
+    let (title, due) = parse_todo(&text, Local::now().naive_local())?;
+    let due_at = due.map(reminders::to_timestamp).transpose()?;
+
+    Ok(ParsedTodo {
+        title,
+        due_at,
+        due_text: due.map(|due| due.format("%Y-%m-%d %H:%M (%a)").to_string()),
+    })
+}
+
+#[tauri::command]
+pub fn add_todo(app_handle: AppHandle, text: String) -> Result<Todo, String> {
+    let parsed = preview_todo(text)?;
+    let todo = db::with_connection(|conn| {
+        insert_todo(conn, &parsed.title, parsed.due_at, Local::now().timestamp())
+    })?;
+
+    let _ = app_handle.emit("todos-changed", ());
+    if todo.reminder_id.is_some() {
+        let _ = app_handle.emit("reminders-changed", ());
+    }
+    Ok(todo)
+}
+
+#[tauri::command]
+pub fn list_todos(include_done: Option<bool>) -> Result<Vec<Todo>, String> {
+    db::with_connection(|conn| list(conn, include_done.unwrap_or(true), Local::now().timestamp()))
+}
+
+#[tauri::command]
+pub fn complete_todo(app_handle: AppHandle, id: i64, done: Option<bool>) -> Result<Todo, String> {
+    let todo = db::with_connection(|conn| set_done(conn, id, done.unwrap_or(true), Local::now().timestamp()))?
+        .ok_or_else(|| format!("待办不存在: {}", id))?;
+
+    let _ = app_handle.emit("todos-changed", ());
+    let _ = app_handle.emit("reminders-changed", ());
+    Ok(todo)
+}
+
+#[tauri::command]
+pub fn delete_todo(app_handle: AppHandle, id: i64) -> Result<(), String> {
+    if !db::with_connection(|conn| remove(conn, id))? {
+        return Err(format!("待办不存在: {}", id));
+    }
+
+    let _ = app_handle.emit("todos-changed", ());
+    let _ = app_handle.emit("reminders-changed", ());
+    Ok(())
+}
+
+// 从 "buy milk tomorrow 5pm" 中分离待办内容和截止时间，识别不出时间时整句作为内容
+fn parse_todo(text: &str, now: NaiveDateTime) -> Result<(String, Option<NaiveDateTime>), String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("待办内容不能为空".to_string());
+    }
+
+    match reminders::parse_reminder(text, now) {
+        Ok((due, title)) if title != reminders::DEFAULT_MESSAGE => Ok((title, Some(due))),
+        _ => Ok((text.to_string(), None)),
+    }
+}
+
+// 有截止时间时同时创建一条提醒
+fn insert_todo(conn: &Connection, title: &str, due_at: Option<i64>, now: i64) -> rusqlite::Result<Todo> {
+    let tx = conn.unchecked_transaction()?;
+
+    let reminder_id = match due_at {
+        Some(due_at) => Some(reminders::insert_reminder(&tx, &format!("{}{}", REMINDER_PREFIX, title), due_at, now)?.id),
+        None => None,
+    };
+    tx.execute(
+        "INSERT INTO todos (title, created_at, due_at, reminder_id) VALUES (?1, ?2, ?3, ?4)",
+        params![title, now, due_at, reminder_id],
+    )?;
+    let id = tx.last_insert_rowid();
+    tx.commit()?;
+
+    Ok(Todo {
+        id,
+        title: title.to_string(),
+        done: false,
+        created_at: now,
+        completed_at: None,
+        due_at,
+        reminder_id,
+    })
+}
+
+// 未完成的待办按截止时间排在前面，已完成的只保留最近的
+fn list(conn: &Connection, include_done: bool, now: i64) -> rusqlite::Result<Vec<Todo>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, title, done, created_at, completed_at, due_at, reminder_id FROM todos
+         WHERE done = 0 OR (?1 AND completed_at >= ?2)
+         ORDER BY done ASC, due_at IS NULL, due_at ASC, created_at DESC",
+    )?;
+    let rows = stmt.query_map(params![include_done, now - DONE_RETENTION_SECS], row_to_todo)?;
+    rows.collect()
+}
+
+// 完成时删除尚未送达的提醒；重新打开时如果截止时间未到则恢复提醒
+fn set_done(conn: &Connection, id: i64, done: bool, now: i64) -> rusqlite::Result<Option<Todo>> {
+    let Some(todo) = get(conn, id)? else {
+        return Ok(None);
+    };
+    let tx = conn.unchecked_transaction()?;
+
+    let reminder_id = if done {
+        if let Some(reminder_id) = todo.reminder_id {
+            tx.execute("DELETE FROM reminders WHERE id = ?1 AND delivered = 0", params![reminder_id])?;
+        }
+        None
+    } else {
+        match todo.due_at {
+            Some(due_at) if due_at > now && todo.reminder_id.is_none() => {
+                Some(reminders::insert_reminder(&tx, &format!("{}{}", REMINDER_PREFIX, todo.title), due_at, now)?.id)
+            }
+            _ => todo.reminder_id,
+        }
+    };
+
+    tx.execute(
+        "UPDATE todos SET done = ?1, completed_at = ?2, reminder_id = ?3 WHERE id = ?4",
+        params![done, done.then_some(now), reminder_id, id],
+    )?;
+    tx.commit()?;
+
+    get(conn, id)
+}
+
+fn remove(conn: &Connection, id: i64) -> rusqlite::Result<bool> {
+    let Some(todo) = get(conn, id)? else {
+        return Ok(false);
+    };
+    let tx = conn.unchecked_transaction()?;
+
+    if let Some(reminder_id) = todo.reminder_id {
+        tx.execute("DELETE FROM reminders WHERE id = ?1 AND delivered = 0", params![reminder_id])?;
+    }
+    tx.execute("DELETE FROM todos WHERE id = ?1", params![id])?;
+    tx.commit()?;
+
+    Ok(true)
+}
+
+fn get(conn: &Connection, id: i64) -> rusqlite::Result<Option<Todo>> {
+    conn.query_row(
+        "SELECT id, title, done, created_at, completed_at, due_at, reminder_id FROM todos WHERE id = ?1",
+        params![id],
+        row_to_todo,
+    )
+    .optional()
+}
+
+fn row_to_todo(row: &rusqlite::Row) -> rusqlite::Result<Todo> {
+    Ok(Todo {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        done: row.get::<_, i64>(2)? != 0,
+        created_at: row.get(3)?,
+        completed_at: row.get(4)?,
+        due_at: row.get(5)?,
+        reminder_id: row.get(6)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 5, 29).unwrap().and_hms_opt(10, 0, 0).unwrap()
+    }
+
+    fn reminder_count(conn: &Connection) -> i64 {
+        conn.query_row("SELECT COUNT(*) FROM reminders", [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_todo() {
+        assert_eq!(parse_todo("buy milk", now()).unwrap(), ("buy milk".to_string(), None));
+
+        let (title, due) = parse_todo("buy milk tomorrow 5pm", now()).unwrap();
+        assert_eq!(title, "buy milk");
+        assert_eq!(due, NaiveDate::from_ymd_opt(2024, 5, 30).unwrap().and_hms_opt(17, 0, 0));
+
+        // 只有时间没有内容时整句作为待办内容
+        assert_eq!(parse_todo("tomorrow", now()).unwrap(), ("tomorrow".to_string(), None));
+        // 时间已经过去时不设置截止时间
+        assert_eq!(parse_todo("report today 8am", now()).unwrap().1, None);
+        assert!(parse_todo("   ", now()).is_err());
+    }
+
+    #[test]
+    fn test_todo_with_due_date_creates_reminder() {
+        let conn = db::open_in_memory();
+
+        let plain = insert_todo(&conn, "buy milk", None, 100).unwrap();
+        assert_eq!(plain.reminder_id, None);
+        assert_eq!(reminder_count(&conn), 0);
+
+        let due = insert_todo(&conn, "pay rent", Some(5000), 100).unwrap();
+        let reminder_id = due.reminder_id.expect("Due todo should have a reminder");
+        let message: String = conn
+            .query_row("SELECT message FROM reminders WHERE id = ?1", [reminder_id], |row| row.get(0))
+            .unwrap();
+        assert_eq!(message, "待办：pay rent");
+
+        // 有截止时间的排在前面
+        let todos = list(&conn, true, 200).unwrap();
+        assert_eq!(todos.iter().map(|t| t.id).collect::<Vec<_>>(), vec![due.id, plain.id]);
+    }
+
+    #[test]
+    fn test_complete_and_reopen_todo() {
+        let conn = db::open_in_memory();
+        let todo = insert_todo(&conn, "pay rent", Some(5000), 100).unwrap();
+
+        // 完成后删除提醒
+        let done = set_done(&conn, todo.id, true, 200).unwrap().unwrap();
+        assert!(done.done);
+        assert_eq!(done.completed_at, Some(200));
+        assert_eq!(done.reminder_id, None);
+        assert_eq!(reminder_count(&conn), 0);
+
+        // 已完成的待办可以隐藏，超过保留时间后也不再显示
+        assert!(list(&conn, false, 300).unwrap().is_empty());
+        assert_eq!(list(&conn, true, 300).unwrap().len(), 1);
+        assert!(list(&conn, true, 200 + DONE_RETENTION_SECS + 1).unwrap().is_empty());
+
+        // 重新打开时恢复提醒
+        let reopened = set_done(&conn, todo.id, false, 300).unwrap().unwrap();
+        assert!(!reopened.done);
+        assert!(reopened.reminder_id.is_some());
+        assert_eq!(reminder_count(&conn), 1);
+
+        assert!(set_done(&conn, 9999, true, 300).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_delete_todo_removes_pending_reminder() {
+        let conn = db::open_in_memory();
+        let todo = insert_todo(&conn, "call mom", Some(5000), 100).unwrap();
+
+        assert!(remove(&conn, todo.id).unwrap());
+        assert_eq!(reminder_count(&conn), 0);
+        assert!(get(&conn, todo.id).unwrap().is_none());
+        assert!(!remove(&conn, todo.id).unwrap());
+    }
+}
@@ -0,0 +1,456 @@
+// 废纸篓 / 回收站：统计内容并通过系统接口清空，按名称搜索已删除的项目并恢复或永久删除
+use crate::system_command::{powershell, run};
+use chrono::{Local, NaiveDateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAX_SEARCH_RESULTS: usize = 50;
+
+// 回收站中的项目数（顶层）和占用空间，无法统计大小时 size 为空
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TrashSummary {
+    pub count: u64,
+    pub size: Option<u64>,
+}
+
+// id 为回收站中的文件名（Linux / macOS）或 $R 文件的完整路径（Windows）；
+// original_path 为删除前的位置，macOS 无法读取；restorable 为 false 时只能永久删除
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TrashItem {
+    pub id: String,
+    pub name: String,
+    pub original_path: Option<String>,
+    pub deleted_at: Option<i64>,
+    pub size: Option<u64>,
+    pub is_dir: bool,
+    pub restorable: bool,
+}
+
+// Shell.Application 的 10 号命名空间即回收站
+const WINDOWS_SUMMARY_SCRIPT: &str = r#"$items = (New-Object -ComObject Shell.Application).Namespace(10).Items()
+$size = ($items | Measure-Object -Property Size -Sum).Sum
+"$($items.Count) $([int64]$size)""#;
+
+#[tauri::command(async)]
+pub fn get_trash_summary() -> Result<TrashSummary, String> {
+    match std::env::consts::OS {
+        "linux" => Ok(summarize_dir(&linux_trash_dir()?.join("files"))),
+        "macos" => {
+            let count = run("osascript", &["-e", "tell application \"Finder\" to count items of trash"])?;
+            let count = count.trim().parse().map_err(|_| format!("无法解析废纸篓项目数: {}", count.trim()))?;
+            // ~/.Trash 需要"完全磁盘访问权限"才能读取，读不到时只显示数量
+            let size = dirs::home_dir()
+                .map(|home| summarize_dir(&home.join(".Trash")))
+                .filter(|summary| summary.count == count)
+                .and_then(|summary| summary.size);
+            Ok(TrashSummary { count, size })
+        }
+        "windows" => parse_windows_summary(&powershell(WINDOWS_SUMMARY_SCRIPT)?)
+            .ok_or_else(|| "无法读取回收站信息".to_string()),
+        os => Err(format!("当前系统不支持回收站操作: {}", os)),
+    }
+}
+
+// 清空前必须经过前端确认；交给系统处理，不直接删除文件
+#[tauri::command(async)]
+pub fn empty_trash(confirmed: Option<bool>) -> Result<(), String> {
+    if !confirmed.unwrap_or(false) {
+        return Err("该操作需要确认".to_string());
+    }
+
+    match std::env::consts::OS {
+        "linux" => run("gio", &["trash", "--empty"]).map(|_| ()),
+        "macos" => run("osascript", &["-e", "tell application \"Finder\" to empty trash"]).map(|_| ()),
+        // Clear-RecycleBin 内部调用 SHEmptyRecycleBin
+        "windows" => powershell("Clear-RecycleBin -Force -ErrorAction Stop").map(|_| ()),
+        os => Err(format!("当前系统不支持回收站操作: {}", os)),
+    }
+}
+
+// Path 为回收站中的 $R 文件，对应的 $I 文件保存原位置和删除时间
+const WINDOWS_LIST_SCRIPT: &str = r#"$items = (New-Object -ComObject Shell.Application).Namespace(10).Items() | ForEach-Object {
+    $deleted = $null
+    try { $deleted = ([DateTimeOffset]$_.ExtendedProperty('System.Recycle.DateDeleted')).ToUnixTimeSeconds() } catch { }
+    [PSCustomObject]@{ id = $_.Path; name = $_.Name; folder = $_.ExtendedProperty('System.Recycle.DeletedFrom'); deleted_at = $deleted; size = [int64]$_.Size; is_dir = $_.IsFolder }
+}
+ConvertTo-Json -Compress -InputObject @($items)"#;
+
+#[derive(Debug, Deserialize)]
+struct WindowsTrashItem {
+    id: String,
+    name: String,
+    folder: Option<String>,
+    deleted_at: Option<i64>,
+    size: Option<i64>,
+    is_dir: bool,
+}
+
+// 名称包含关键词（不区分大小写）的项目，最近删除的在前
+#[tauri::command(async)]
+pub fn search_trash(query: String) -> Result<Vec<TrashItem>, String> {
+    let needle = query.trim().to_lowercase();
+    let mut items: Vec<TrashItem> = list_trash()?
+        .into_iter()
+        .filter(|item| item.name.to_lowercase().contains(&needle))
+        .collect();
+    items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at).then_with(|| a.name.cmp(&b.name)));
+    items.truncate(MAX_SEARCH_RESULTS);
+    Ok(items)
+}
+
+// 原位置已有同名文件时不覆盖，返回恢复后的路径
+#[tauri::command(async)]
+pub fn restore_trash_item(id: String) -> Result<String, String> {
+    match std::env::consts::OS {
+        "linux" => restore_linux(&linux_trash_dir()?, &id),
+        "macos" => Err("macOS 不支持从命令恢复，请在废纸篓中使用“放回原处”".to_string()),
+        "windows" => {
+            let (recycled, info) = windows_recycled_paths(&id)?;
+            let script = format!(
+                "$item = (New-Object -ComObject Shell.Application).Namespace(10).Items() | Where-Object {{ $_.Path -eq '{0}' }} | Select-Object -First 1
+if (-not $item) {{ throw '回收站中没有该项目' }}
+$target = Join-Path $item.ExtendedProperty('System.Recycle.DeletedFrom') $item.Name
+if (Test-Path -LiteralPath $target) {{ throw \"原位置已存在同名文件: $target\" }}
+New-Item -ItemType Directory -Force -Path (Split-Path $target) | Out-Null
+Move-Item -LiteralPath '{0}' -Destination $target -ErrorAction Stop
+Remove-Item -LiteralPath '{1}' -Force -ErrorAction SilentlyContinue
+$target",
+                recycled.replace('\'', "''"),
+                info.replace('\'', "''")
+            );
+            powershell(&script).map(|target| target.trim().to_string())
+        }
+        os => Err(format!("当前系统不支持回收站操作: {}", os)),
+    }
+}
+
+// 永久删除单个项目，与清空回收站一样需要前端确认
+#[tauri::command(async)]
+pub fn delete_trash_item(id: String, confirmed: Option<bool>) -> Result<(), String> {
+    if !confirmed.unwrap_or(false) {
+        return Err("该操作需要确认".to_string());
+    }
+
+    match std::env::consts::OS {
+        "linux" => delete_linux(&linux_trash_dir()?, &id),
+        "macos" => {
+            validate_trash_name(&id)?;
+            let home = dirs::home_dir().ok_or("无法获取主目录")?;
+            remove_path(&home.join(".Trash").join(&id))
+                .map_err(|e| format!("删除失败（可能需要在系统设置中授予“完全磁盘访问权限”）: {}", e))
+        }
+        "windows" => {
+            let (recycled, info) = windows_recycled_paths(&id)?;
+            remove_path(Path::new(&recycled))?;
+            let _ = fs::remove_file(info);
+            Ok(())
+        }
+        os => Err(format!("当前系统不支持回收站操作: {}", os)),
+    }
+}
+
+fn list_trash() -> Result<Vec<TrashItem>, String> {
+    match std::env::consts::OS {
+        "linux" => Ok(list_linux(&linux_trash_dir()?)),
+        // Finder 不需要"完全磁盘访问权限"即可列出名称，但不提供原位置
+        "macos" => {
+            let names = run("osascript", &[
+                "-e", "tell application \"Finder\" to set names to name of every item of trash",
+                "-e", "set AppleScript's text item delimiters to linefeed",
+                "-e", "return names as text",
+            ])?;
+            Ok(names
+                .lines()
+                .filter(|name| !name.is_empty())
+                .map(|name| TrashItem {
+                    id: name.to_string(),
+                    name: name.to_string(),
+                    original_path: None,
+                    deleted_at: None,
+                    size: None,
+                    is_dir: false,
+                    restorable: false,
+                })
+                .collect())
+        }
+        "windows" => parse_windows_items(&powershell(WINDOWS_LIST_SCRIPT)?),
+        os => Err(format!("当前系统不支持回收站操作: {}", os)),
+    }
+}
+
+fn parse_windows_items(output: &str) -> Result<Vec<TrashItem>, String> {
+    let output = output.trim();
+    if output.is_empty() {
+        return Ok(vec![]);
+    }
+    let items: Vec<WindowsTrashItem> = serde_json::from_str(output).map_err(|e| format!("解析回收站内容失败: {}", e))?;
+    Ok(items
+        .into_iter()
+        .map(|item| TrashItem {
+            original_path: item.folder.map(|folder| format!("{}\\{}", folder.trim_end_matches('\\'), item.name)),
+            id: item.id,
+            name: item.name,
+            deleted_at: item.deleted_at,
+            size: item.size.map(|size| size.max(0) as u64),
+            is_dir: item.is_dir,
+            restorable: true,
+        })
+        .collect())
+}
+
+// 只接受回收站中的 $R 文件，返回它和对应的 $I 文件
+fn windows_recycled_paths(id: &str) -> Result<(String, String), String> {
+    let invalid = || format!("不是回收站中的项目: {}", id);
+    let (dir, name) = id.rsplit_once('\\').ok_or_else(invalid)?;
+    if !dir.to_lowercase().contains("\\$recycle.bin\\") || !name.starts_with("$R") {
+        return Err(invalid());
+    }
+    Ok((id.to_string(), format!("{}\\$I{}", dir, &name[2..])))
+}
+
+// 回收站中的名称不能包含路径分隔符，防止访问回收站以外的文件
+fn validate_trash_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name == "." || name == ".." || name.contains('/') || name.contains('\\') {
+        return Err(format!("无效的回收站项目: {}", name));
+    }
+    Ok(())
+}
+
+// 每个 files/<名称> 对应 info/<名称>.trashinfo，没有 info 文件的项目无法恢复
+fn list_linux(trash_dir: &Path) -> Vec<TrashItem> {
+    let Ok(entries) = fs::read_dir(trash_dir.join("files")) else {
+        return vec![];
+    };
+
+    entries
+        .flatten()
+        .map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let info = fs::read_to_string(trash_dir.join("info").join(format!("{}.trashinfo", name)))
+                .ok()
+                .and_then(|content| parse_trash_info(&content));
+            let (original_path, deleted_at) = info.unzip();
+            let is_dir = fs::symlink_metadata(entry.path()).map(|metadata| metadata.is_dir()).unwrap_or(false);
+            // 原文件名为删除前路径的最后一段，回收站中的名称可能带有去重后缀
+            let display_name = original_path
+                .as_deref()
+                .and_then(|path| Path::new(path).file_name())
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| name.clone());
+            TrashItem {
+                restorable: original_path.is_some(),
+                size: Some(path_size(&entry.path())),
+                id: name,
+                name: display_name,
+                original_path,
+                deleted_at: deleted_at.flatten(),
+                is_dir,
+            }
+        })
+        .collect()
+}
+
+// [Trash Info] 中的 Path 经过百分号编码，DeletionDate 为本地时间（YYYY-MM-DDThh:mm:ss）
+fn parse_trash_info(content: &str) -> Option<(String, Option<i64>)> {
+    let mut path = None;
+    let mut deleted_at = None;
+    for line in content.lines() {
+        if let Some(value) = line.strip_prefix("Path=") {
+            path = Some(percent_decode(value.trim()));
+        } else if let Some(value) = line.strip_prefix("DeletionDate=") {
+            deleted_at = NaiveDateTime::parse_from_str(value.trim(), "%Y-%m-%dT%H:%M:%S")
+                .ok()
+                .and_then(|date| Local.from_local_datetime(&date).earliest())
+                .map(|date| date.timestamp());
+        }
+    }
+    // 主目录回收站中的路径应为绝对路径
+    path.filter(|path: &String| path.starts_with('/')).map(|path| (path, deleted_at))
+}
+
+fn restore_linux(trash_dir: &Path, id: &str) -> Result<String, String> {
+    validate_trash_name(id)?;
+    let file = trash_dir.join("files").join(id);
+    let info = trash_dir.join("info").join(format!("{}.trashinfo", id));
+    let content = fs::read_to_string(&info).map_err(|_| format!("找不到 {} 的原位置信息", id))?;
+    let (original, _) = parse_trash_info(&content).ok_or_else(|| format!("无法解析 {} 的原位置信息", id))?;
+
+    let target = PathBuf::from(&original);
+    if fs::symlink_metadata(&target).is_ok() {
+        return Err(format!("原位置已存在同名文件: {}", original));
+    }
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建目录 {} 失败: {}", parent.display(), e))?;
+    }
+    fs::rename(&file, &target).map_err(|e| format!("恢复 {} 失败: {}", id, e))?;
+    let _ = fs::remove_file(&info);
+    Ok(original)
+}
+
+fn delete_linux(trash_dir: &Path, id: &str) -> Result<(), String> {
+    validate_trash_name(id)?;
+    remove_path(&trash_dir.join("files").join(id))?;
+    let _ = fs::remove_file(trash_dir.join("info").join(format!("{}.trashinfo", id)));
+    Ok(())
+}
+
+// 目录递归删除，符号链接只删除链接本身
+fn remove_path(path: &Path) -> Result<(), String> {
+    let metadata = fs::symlink_metadata(path).map_err(|e| format!("找不到 {}: {}", path.display(), e))?;
+    let result = if metadata.is_dir() { fs::remove_dir_all(path) } else { fs::remove_file(path) };
+    result.map_err(|e| format!("删除 {} 失败: {}", path.display(), e))
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Some(byte) = std::str::from_utf8(&bytes[i + 1..i + 3]).ok().and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+// 遵循 freedesktop 回收站规范：$XDG_DATA_HOME/Trash
+fn linux_trash_dir() -> Result<PathBuf, String> {
+    dirs::data_dir()
+        .map(|dir| dir.join("Trash"))
+        .ok_or_else(|| "无法获取回收站目录".to_string())
+}
+
+fn summarize_dir(dir: &Path) -> TrashSummary {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return TrashSummary { count: 0, size: None };
+    };
+
+    let mut count = 0;
+    let mut size = 0;
+    for entry in entries.flatten() {
+        // macOS 的 .DS_Store 不算回收站项目
+        if entry.file_name() == ".DS_Store" {
+            continue;
+        }
+        count += 1;
+        size += path_size(&entry.path());
+    }
+    TrashSummary { count, size: Some(size) }
+}
+
+// 不跟随符号链接，避免把链接目标算进去
+fn path_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|entry| path_size(&entry.path())).sum())
+        .unwrap_or(0)
+}
+
+// 输出形如 "3 1048576"
+fn parse_windows_summary(output: &str) -> Option<TrashSummary> {
+    let mut parts = output.split_whitespace();
+    let count = parts.next()?.parse().ok()?;
+    let size = parts.next().and_then(|size| size.parse().ok());
+    Some(TrashSummary { count, size })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_summarize_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let files = temp_dir.path().join("files");
+        fs::create_dir_all(files.join("folder/nested")).unwrap();
+        fs::write(files.join("a.txt"), "hello").unwrap();
+        fs::write(files.join("folder/b.txt"), "12345678").unwrap();
+        fs::write(files.join("folder/nested/c.txt"), "xy").unwrap();
+        fs::write(files.join(".DS_Store"), "ignored").unwrap();
+
+        assert_eq!(summarize_dir(&files), TrashSummary { count: 2, size: Some(15) });
+        assert_eq!(summarize_dir(&temp_dir.path().join("missing")), TrashSummary { count: 0, size: None });
+    }
+
+    #[test]
+    fn test_parse_windows_summary() {
+        assert_eq!(parse_windows_summary("3 1048576\r\n"), Some(TrashSummary { count: 3, size: Some(1048576) }));
+        assert_eq!(parse_windows_summary("0 0"), Some(TrashSummary { count: 0, size: Some(0) }));
+        assert_eq!(parse_windows_summary(""), None);
+    }
+
+    #[test]
+    fn test_empty_trash_requires_confirmation() {
+        assert!(empty_trash(None).is_err());
+        assert!(empty_trash(Some(false)).is_err());
+        assert!(delete_trash_item("a.txt".to_string(), None).is_err());
+    }
+
+    fn trash_file(trash: &Path, id: &str, original: &str) {
+        fs::write(trash.join("files").join(id), "content").unwrap();
+        let info = format!("[Trash Info]\nPath={}\nDeletionDate=2024-03-01T10:20:30\n", original);
+        fs::write(trash.join("info").join(format!("{}.trashinfo", id)), info).unwrap();
+    }
+
+    #[test]
+    fn test_linux_trash_items() {
+        let temp_dir = TempDir::new().unwrap();
+        let trash = temp_dir.path().join("Trash");
+        fs::create_dir_all(trash.join("files")).unwrap();
+        fs::create_dir_all(trash.join("info")).unwrap();
+        let original = temp_dir.path().join("docs/my report.txt");
+        let encoded = original.to_string_lossy().replace(' ', "%20");
+        trash_file(&trash, "my report.2.txt", &encoded);
+        fs::write(trash.join("files/orphan"), "x").unwrap();
+
+        let mut items = list_linux(&trash);
+        items.sort_by(|a, b| a.id.cmp(&b.id));
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].name, "my report.txt");
+        assert_eq!(items[0].original_path.as_deref(), Some(original.to_string_lossy().as_ref()));
+        assert_eq!((items[0].size, items[0].restorable), (Some(7), true));
+        let expected = Local.with_ymd_and_hms(2024, 3, 1, 10, 20, 30).unwrap().timestamp();
+        assert_eq!(items[0].deleted_at, Some(expected));
+        assert_eq!((items[1].name.as_str(), items[1].restorable), ("orphan", false));
+
+        // 恢复到原位置并创建缺少的目录，已存在同名文件时不覆盖
+        assert_eq!(restore_linux(&trash, "my report.2.txt").unwrap(), original.to_string_lossy());
+        assert_eq!(fs::read_to_string(&original).unwrap(), "content");
+        assert!(!trash.join("info/my report.2.txt.trashinfo").exists());
+        trash_file(&trash, "again", &encoded);
+        assert!(restore_linux(&trash, "again").unwrap_err().contains("已存在"));
+
+        delete_linux(&trash, "again").unwrap();
+        assert!(!trash.join("files/again").exists());
+        assert!(!trash.join("info/again.trashinfo").exists());
+        assert!(delete_linux(&trash, "../files").is_err());
+        assert!(restore_linux(&trash, "missing").is_err());
+    }
+
+    #[test]
+    fn test_parse_windows_items() {
+        let output = r#"[{"id":"C:\\$Recycle.Bin\\S-1-5-21\\$RAB12CD.txt","name":"notes.txt","folder":"C:\\Users\\alice\\Desktop","deleted_at":1700000000,"size":12,"is_dir":false}]"#;
+        let items = parse_windows_items(output).unwrap();
+        assert_eq!(items[0].original_path.as_deref(), Some("C:\\Users\\alice\\Desktop\\notes.txt"));
+        assert_eq!((items[0].deleted_at, items[0].size), (Some(1700000000), Some(12)));
+        assert!(parse_windows_items("").unwrap().is_empty());
+
+        let (recycled, info) = windows_recycled_paths(&items[0].id).unwrap();
+        assert_eq!(info, "C:\\$Recycle.Bin\\S-1-5-21\\$IAB12CD.txt");
+        assert_eq!(recycled, items[0].id);
+        assert!(windows_recycled_paths("C:\\Users\\alice\\notes.txt").is_err());
+    }
+}
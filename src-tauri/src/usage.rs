@@ -0,0 +1,353 @@
+// 使用统计：记录搜索耗时和选中的结果，汇总常用应用、常用查询、插件使用情况和平均搜索耗时；
+// 选中记录同时用于排序学习，同一查询反复选中的结果排在前面。
+// 数据只保存在本地数据库中，超过保留期的记录会被删除
+use crate::db;
+use chrono::Local;
+use rusqlite::{params, Connection, ToSql};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+const RETENTION_DAYS: i64 = 365;
+const TOP_LIMIT: usize = 10;
+const APPS_PROVIDER: &str = "apps";
+// 排序学习只参考最近的选择，权重每隔半衰期减半
+const LEARNING_WINDOW_DAYS: i64 = 90;
+const LEARNING_HALF_LIFE_DAYS: f64 = 30.0;
+// 输入是之前查询的前缀（例如 "fi" 与 "firefox"）时的权重
+const PREFIX_MATCH_WEIGHT: f64 = 0.5;
+const MAX_BOOSTS: usize = 20;
+// 每记录这么多次搜索清理一次过期记录，启动后的第一次记录也会清理
+const PRUNE_INTERVAL: u32 = 100;
+
+static SEARCHES_SINCE_PRUNE: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageRange {
+    Day,
+    Week,
+    Month,
+    All,
+}
+
+impl UsageRange {
+    // 统计范围的起始时间，All 为空
+    fn since(self, now: i64) -> Option<i64> {
+        let days = match self {
+            UsageRange::Day => 1,
+            UsageRange::Week => 7,
+            UsageRange::Month => 30,
+            UsageRange::All => return None,
+        };
+        Some(now - days * 86400)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProviderTiming {
+    pub provider: String,
+    pub latency_ms: u64,
+    pub result_count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageCount {
+    pub name: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProviderUsage {
+    pub provider: String,
+    pub searches: usize,
+    pub selections: usize,
+    pub average_latency_ms: Option<f64>,
+}
+
+// score 约等于该查询下选中该结果的次数（按时间衰减）
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SelectionBoost {
+    pub result_id: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageStats {
+    pub range: UsageRange,
+    pub total_searches: usize,
+    pub total_selections: usize,
+    pub average_latency_ms: Option<f64>,
+    pub top_apps: Vec<UsageCount>,
+    pub top_queries: Vec<UsageCount>,
+    // 按选中次数、搜索次数排序
+    pub providers: Vec<ProviderUsage>,
+}
+
+#[tauri::command(async)]
+pub fn record_search(latency_ms: u64, result_count: usize, providers: Vec<ProviderTiming>) -> Result<(), String> {
+    let now = Local::now().timestamp();
+    db::with_connection(|conn| {
+        insert_search(conn, latency_ms, result_count, &providers, now)?;
+        if should_prune(SEARCHES_SINCE_PRUNE.fetch_add(1, Ordering::Relaxed)) {
+            prune(conn, now)?;
+        }
+        Ok(())
+    })
+}
+
+#[tauri::command]
+pub fn record_selection(query: String, result_id: String, title: String, provider: String) -> Result<(), String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(());
+    }
+    db::with_connection(|conn| insert_selection(conn, query, &result_id, &title, &provider, Local::now().timestamp()))
+}
+
+#[tauri::command]
+pub fn get_usage_stats(range: UsageRange) -> Result<UsageStats, String> {
+    db::with_connection(|conn| usage_stats(conn, range, Local::now().timestamp()))
+}
+
+#[tauri::command]
+pub fn get_selection_boosts(query: String) -> Result<Vec<SelectionBoost>, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    db::with_connection(|conn| selection_boosts(conn, query, Local::now().timestamp()))
+}
+
+// 清除全部选中记录，排序恢复默认；使用统计中的常用应用和常用查询也会清空
+#[tauri::command]
+pub fn reset_learning() -> Result<(), String> {
+    db::with_connection(|conn| conn.execute("DELETE FROM selections", []).map(|_| ()))
+}
+
+fn selection_boosts(conn: &Connection, query: &str, now: i64) -> rusqlite::Result<Vec<SelectionBoost>> {
+    let since = now - LEARNING_WINDOW_DAYS * 86400;
+    let pattern = format!("{}%", query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"));
+    let mut stmt = conn.prepare(
+        "SELECT query, result_id, created_at FROM selections WHERE created_at >= ?1 AND query LIKE ?2 ESCAPE '\\'",
+    )?;
+    let rows = stmt.query_map(params![since, pattern], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, i64>(2)?))
+    })?;
+
+    let mut scores: HashMap<String, f64> = HashMap::new();
+    for row in rows {
+        let (selected_query, result_id, created_at) = row?;
+        let weight = if selected_query.eq_ignore_ascii_case(query) { 1.0 } else { PREFIX_MATCH_WEIGHT };
+        let age_days = (now - created_at).max(0) as f64 / 86400.0;
+        *scores.entry(result_id).or_default() += weight * 0.5f64.powf(age_days / LEARNING_HALF_LIFE_DAYS);
+    }
+
+    let mut boosts: Vec<SelectionBoost> = scores.into_iter()
+        .map(|(result_id, score)| SelectionBoost { result_id, score })
+        .collect();
+    boosts.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.result_id.cmp(&b.result_id)));
+    boosts.truncate(MAX_BOOSTS);
+    Ok(boosts)
+}
+
+fn insert_search(conn: &Connection, latency_ms: u64, result_count: usize, providers: &[ProviderTiming], now: i64) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO searches (latency_ms, result_count, created_at) VALUES (?1, ?2, ?3)",
+        params![latency_ms as i64, result_count as i64, now],
+    )?;
+    let search_id = conn.last_insert_rowid();
+    let mut stmt = conn.prepare(
+        "INSERT INTO provider_timings (search_id, provider, latency_ms, result_count) VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for timing in providers {
+        stmt.execute(params![search_id, timing.provider, timing.latency_ms as i64, timing.result_count as i64])?;
+    }
+    Ok(())
+}
+
+fn insert_selection(conn: &Connection, query: &str, result_id: &str, title: &str, provider: &str, now: i64) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO selections (query, result_id, title, provider, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![query, result_id, title, provider, now],
+    )?;
+    Ok(())
+}
+
+fn should_prune(recorded: u32) -> bool {
+    recorded.is_multiple_of(PRUNE_INTERVAL)
+}
+
+fn prune(conn: &Connection, now: i64) -> rusqlite::Result<()> {
+    let cutoff = now - RETENTION_DAYS * 86400;
+    conn.execute(
+        "DELETE FROM provider_timings WHERE search_id IN (SELECT id FROM searches WHERE created_at < ?1)",
+        [cutoff],
+    )?;
+    conn.execute("DELETE FROM searches WHERE created_at < ?1", [cutoff])?;
+    conn.execute("DELETE FROM selections WHERE created_at < ?1", [cutoff])?;
+    Ok(())
+}
+
+fn usage_stats(conn: &Connection, range: UsageRange, now: i64) -> rusqlite::Result<UsageStats> {
+    // 不限范围时从 0 开始
+    let since = range.since(now).unwrap_or(0);
+
+    let (total_searches, average_latency_ms) = conn.query_row(
+        "SELECT COUNT(*), AVG(latency_ms) FROM searches WHERE created_at >= ?1",
+        [since],
+        |row| Ok((row.get::<_, i64>(0)? as usize, row.get::<_, Option<f64>>(1)?)),
+    )?;
+    let total_selections = conn.query_row(
+        "SELECT COUNT(*) FROM selections WHERE created_at >= ?1",
+        [since],
+        |row| row.get::<_, i64>(0),
+    )? as usize;
+
+    let counts = |sql: &str, params: &[&dyn ToSql]| -> rusqlite::Result<Vec<UsageCount>> {
+        let mut stmt = conn.prepare(sql)?;
+        let rows = stmt.query_map(params, |row| {
+            Ok(UsageCount { name: row.get(0)?, count: row.get::<_, i64>(1)? as usize })
+        })?;
+        rows.collect()
+    };
+    // 与 MAX() 一起查询时，SQLite 的非聚合列取自 MAX 所在的行，即最近一次的标题或写法
+    let top_apps = counts(
+        "SELECT title, COUNT(*) AS count, MAX(created_at) AS last_at FROM selections
+         WHERE created_at >= ?1 AND provider = ?2 GROUP BY result_id ORDER BY count DESC, last_at DESC LIMIT ?3",
+        &[&since, &APPS_PROVIDER, &(TOP_LIMIT as i64)],
+    )?;
+    let top_queries = counts(
+        "SELECT query, COUNT(*) AS count, MAX(created_at) AS last_at FROM selections
+         WHERE created_at >= ?1 GROUP BY query COLLATE NOCASE ORDER BY count DESC, last_at DESC LIMIT ?2",
+        &[&since, &(TOP_LIMIT as i64)],
+    )?;
+
+    let mut providers: HashMap<String, ProviderUsage> = HashMap::new();
+    let mut stmt = conn.prepare(
+        "SELECT t.provider, COUNT(*), AVG(t.latency_ms) FROM provider_timings t JOIN searches s ON s.id = t.search_id
+         WHERE s.created_at >= ?1 GROUP BY t.provider",
+    )?;
+    let timings = stmt.query_map([since], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize, row.get::<_, Option<f64>>(2)?))
+    })?;
+    for timing in timings {
+        let (provider, searches, average_latency_ms) = timing?;
+        providers.insert(provider.clone(), ProviderUsage { provider, searches, selections: 0, average_latency_ms });
+    }
+    let mut stmt = conn.prepare("SELECT provider, COUNT(*) FROM selections WHERE created_at >= ?1 GROUP BY provider")?;
+    let selections = stmt.query_map([since], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize)))?;
+    for selection in selections {
+        let (provider, count) = selection?;
+        providers.entry(provider.clone())
+            .or_insert(ProviderUsage { provider, searches: 0, selections: 0, average_latency_ms: None })
+            .selections = count;
+    }
+    let mut providers: Vec<ProviderUsage> = providers.into_values().collect();
+    providers.sort_by(|a, b| {
+        b.selections.cmp(&a.selections)
+            .then(b.searches.cmp(&a.searches))
+            .then(a.provider.cmp(&b.provider))
+    });
+
+    Ok(UsageStats {
+        range,
+        total_searches,
+        total_selections,
+        average_latency_ms,
+        top_apps,
+        top_queries,
+        providers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+    const DAY: i64 = 86400;
+
+    fn timing(provider: &str, latency_ms: u64) -> ProviderTiming {
+        ProviderTiming { provider: provider.to_string(), latency_ms, result_count: 1 }
+    }
+
+    fn count(name: &str, count: usize) -> UsageCount {
+        UsageCount { name: name.to_string(), count }
+    }
+
+    #[test]
+    fn test_usage_stats() {
+        let conn = db::open_in_memory();
+        insert_search(&conn, 40, 5, &[timing("apps", 10), timing("files", 30)], NOW).unwrap();
+        insert_search(&conn, 80, 2, &[timing("apps", 20), timing("files", 70)], NOW - 2 * DAY).unwrap();
+        insert_selection(&conn, "fire", "app-1", "Firefox", "apps", NOW).unwrap();
+        insert_selection(&conn, "Fire", "app-1", "Firefox", "apps", NOW - 3 * DAY).unwrap();
+        insert_selection(&conn, "term", "app-2", "Terminal", "apps", NOW - 3 * DAY).unwrap();
+        insert_selection(&conn, "1+1", "calc-1", "2", "calculator", NOW).unwrap();
+
+        let stats = usage_stats(&conn, UsageRange::Week, NOW).unwrap();
+        assert_eq!((stats.total_searches, stats.total_selections), (2, 4));
+        assert_eq!(stats.average_latency_ms, Some(60.0));
+        assert_eq!(stats.top_apps, [count("Firefox", 2), count("Terminal", 1)]);
+        assert_eq!(stats.top_queries[0], count("fire", 2));
+        assert_eq!(stats.providers[0].provider, "apps");
+        assert_eq!(stats.providers[0].average_latency_ms, Some(15.0));
+        assert_eq!(stats.providers[0].selections, 3);
+        let calculator = stats.providers.iter().find(|provider| provider.provider == "calculator").unwrap();
+        assert_eq!((calculator.searches, calculator.selections, calculator.average_latency_ms), (0, 1, None));
+
+        let stats = usage_stats(&conn, UsageRange::Day, NOW).unwrap();
+        assert_eq!((stats.total_searches, stats.total_selections), (1, 2));
+        assert_eq!(stats.top_apps, [count("Firefox", 1)]);
+    }
+
+    #[test]
+    fn test_empty_stats_and_prune() {
+        let conn = db::open_in_memory();
+        let stats = usage_stats(&conn, UsageRange::All, NOW).unwrap();
+        assert_eq!((stats.total_searches, stats.average_latency_ms), (0, None));
+        assert!(stats.providers.is_empty());
+
+        insert_search(&conn, 10, 1, &[timing("apps", 10)], NOW - (RETENTION_DAYS + 1) * DAY).unwrap();
+        insert_selection(&conn, "old", "x", "Old", "apps", NOW - (RETENTION_DAYS + 1) * DAY).unwrap();
+        insert_search(&conn, 10, 1, &[timing("apps", 10)], NOW).unwrap();
+        prune(&conn, NOW).unwrap();
+
+        let stats = usage_stats(&conn, UsageRange::All, NOW).unwrap();
+        assert_eq!((stats.total_searches, stats.total_selections), (1, 0));
+        let timings: i64 = conn.query_row("SELECT COUNT(*) FROM provider_timings", [], |row| row.get(0)).unwrap();
+        assert_eq!(timings, 1);
+    }
+
+    #[test]
+    fn test_should_prune() {
+        assert!(should_prune(0));
+        assert!(!should_prune(1));
+        assert!(!should_prune(PRUNE_INTERVAL - 1));
+        assert!(should_prune(PRUNE_INTERVAL));
+    }
+
+    #[test]
+    fn test_selection_boosts() {
+        let conn = db::open_in_memory();
+        insert_selection(&conn, "fire", "app-firefox", "Firefox", "apps", NOW).unwrap();
+        insert_selection(&conn, "Fire", "app-firefox", "Firefox", "apps", NOW - 30 * DAY).unwrap();
+        insert_selection(&conn, "firewall", "file-firewall", "firewall.conf", "files", NOW).unwrap();
+        insert_selection(&conn, "fi", "app-files", "Files", "apps", NOW).unwrap();
+        insert_selection(&conn, "fire", "app-old", "Old", "apps", NOW - (LEARNING_WINDOW_DAYS + 1) * DAY).unwrap();
+        insert_selection(&conn, "f_re", "app-literal", "Literal", "apps", NOW).unwrap();
+
+        let boosts = selection_boosts(&conn, "fire", NOW).unwrap();
+        let ids: Vec<&str> = boosts.iter().map(|boost| boost.result_id.as_str()).collect();
+        assert_eq!(ids, ["app-firefox", "file-firewall"]);
+        // 当前 1 次加 30 天前的半次，不区分大小写
+        assert!((boosts[0].score - 1.5).abs() < 1e-9);
+        assert!((boosts[1].score - PREFIX_MATCH_WEIGHT).abs() < 1e-9);
+
+        // _ 和 % 按字面匹配
+        let boosts = selection_boosts(&conn, "f_", NOW).unwrap();
+        assert_eq!(boosts.len(), 1);
+        assert_eq!(boosts[0].result_id, "app-literal");
+    }
+}
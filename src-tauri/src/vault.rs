@@ -0,0 +1,445 @@
+// 密码管理器：调用 Bitwarden CLI（bw）或 1Password CLI（op）搜索条目，密码和验证码只在后端读取并直接写入剪贴板
+// 会话密钥只保存在内存中，空闲超时后丢弃
+use crate::clipboard;
+use crate::system_command::{run_with_env, run_with_input};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+// 条目列表不含密码，短时间缓存避免每次输入都调用 CLI
+const ITEMS_TTL: Duration = Duration::from_secs(60);
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+const MAX_RESULTS: usize = 20;
+// Bitwarden 条目类型 1 为登录
+const BITWARDEN_LOGIN: u8 = 1;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum VaultProvider {
+    Bitwarden,
+    OnePassword,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VaultField {
+    Username,
+    Password,
+    Totp,
+}
+
+// 1Password 的条目列表不含一次性密码信息，登录条目都视为可能有验证码
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VaultItem {
+    pub id: String,
+    pub name: String,
+    pub username: Option<String>,
+    pub url: Option<String>,
+    pub vault: Option<String>,
+    pub has_totp: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VaultStatus {
+    pub installed: bool,
+    pub unlocked: bool,
+    pub message: Option<String>,
+}
+
+struct Session {
+    key: Option<String>,
+    last_used: Instant,
+}
+
+// key 为空表示通过 1Password 桌面应用集成授权，不需要会话密钥
+static SESSIONS: LazyLock<Mutex<HashMap<VaultProvider, Session>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+type CachedItems = (Instant, Vec<VaultItem>);
+static ITEMS: LazyLock<Mutex<HashMap<VaultProvider, CachedItems>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Deserialize)]
+struct BitwardenStatus {
+    status: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitwardenItem {
+    id: String,
+    name: String,
+    #[serde(rename = "type")]
+    kind: u8,
+    login: Option<BitwardenLogin>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitwardenLogin {
+    username: Option<String>,
+    totp: Option<String>,
+    #[serde(default)]
+    uris: Option<Vec<BitwardenUri>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitwardenUri {
+    uri: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OnePasswordItem {
+    id: String,
+    title: String,
+    category: String,
+    vault: Option<OnePasswordVault>,
+    additional_information: Option<String>,
+    #[serde(default)]
+    urls: Vec<OnePasswordUrl>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OnePasswordVault {
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OnePasswordUrl {
+    href: String,
+    #[serde(default)]
+    primary: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct OnePasswordAccount {
+    user_uuid: String,
+}
+
+#[tauri::command(async)]
+pub fn vault_status(provider: VaultProvider) -> Result<VaultStatus, String> {
+    let program = program(provider);
+    match provider {
+        VaultProvider::Bitwarden => {
+            let output = match run_bw(&["status"], session_key(provider).as_deref()) {
+                Ok(output) => output,
+                Err(e) => return Ok(not_installed(program, e)),
+            };
+            let status: BitwardenStatus = serde_json::from_str(output.trim()).map_err(|e| format!("解析 bw 状态失败: {}", e))?;
+            let unlocked = status.status == "unlocked";
+            if unlocked && session_key(provider).is_none() {
+                // 继承了外部设置的 BW_SESSION 环境变量
+                touch_session(provider, None);
+            }
+            let message = (status.status == "unauthenticated").then(|| "请先在终端中执行 bw login 登录".to_string());
+            Ok(VaultStatus { installed: true, unlocked, message })
+        }
+        VaultProvider::OnePassword => {
+            if let Err(e) = run_with_env(program, &["--version"], &[]) {
+                return Ok(not_installed(program, e));
+            }
+            let key = session_key(provider);
+            let unlocked = run_op(&["whoami"], key.as_deref()).is_ok();
+            if unlocked && key.is_none() {
+                // 通过桌面应用集成授权
+                touch_session(provider, None);
+            }
+            Ok(VaultStatus { installed: true, unlocked, message: None })
+        }
+    }
+}
+
+// 主密码只用于换取会话密钥，不会保存
+#[tauri::command(async)]
+pub fn unlock_vault(provider: VaultProvider, password: String) -> Result<(), String> {
+    if password.is_empty() {
+        return Err("请输入主密码".to_string());
+    }
+    let key = match provider {
+        VaultProvider::Bitwarden => run_with_env("bw", &["unlock", "--raw", "--passwordenv", "BW_PASSWORD"], &[("BW_PASSWORD", &password)])?,
+        VaultProvider::OnePassword => run_with_input("op", &["signin", "--raw"], &format!("{}\n", password))?,
+    };
+    let key = key.trim();
+    if key.is_empty() && provider == VaultProvider::Bitwarden {
+        return Err("解锁失败：bw 没有返回会话密钥".to_string());
+    }
+
+    touch_session(provider, Some(key.to_string()).filter(|key| !key.is_empty()));
+    ITEMS.lock().unwrap().remove(&provider);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn lock_vault(provider: VaultProvider) -> Result<(), String> {
+    SESSIONS.lock().unwrap().remove(&provider);
+    ITEMS.lock().unwrap().remove(&provider);
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn search_vault(provider: VaultProvider, query: String) -> Result<Vec<VaultItem>, String> {
+    let key = active_session(provider)?;
+    let items = match cached_items(provider) {
+        Some(items) => items,
+        None => {
+            let items = match provider {
+                VaultProvider::Bitwarden => parse_bitwarden_items(&run_bw(&["list", "items"], key.as_deref())?)?,
+                VaultProvider::OnePassword => parse_onepassword_items(&run_op(&["item", "list", "--format", "json"], key.as_deref())?)?,
+            };
+            ITEMS.lock().unwrap().insert(provider, (Instant::now(), items.clone()));
+            items
+        }
+    };
+    Ok(filter_items(items, &query))
+}
+
+// 读取的内容直接写入剪贴板并按设置自动清除，不返回给前端
+#[tauri::command(async)]
+pub fn copy_vault_field(provider: VaultProvider, id: String, field: VaultField, clear_after_secs: Option<u64>) -> Result<(), String> {
+    if id.is_empty() || id.starts_with('-') {
+        return Err(format!("无效的条目: {}", id));
+    }
+    let key = active_session(provider)?;
+    let value = match (provider, field) {
+        (VaultProvider::Bitwarden, VaultField::Username) => run_bw(&["get", "username", &id], key.as_deref())?,
+        (VaultProvider::Bitwarden, VaultField::Password) => run_bw(&["get", "password", &id], key.as_deref())?,
+        (VaultProvider::Bitwarden, VaultField::Totp) => run_bw(&["get", "totp", &id], key.as_deref())?,
+        (VaultProvider::OnePassword, VaultField::Username) => run_op(&["item", "get", &id, "--fields", "label=username"], key.as_deref())?,
+        (VaultProvider::OnePassword, VaultField::Password) => run_op(&["item", "get", &id, "--fields", "label=password", "--reveal"], key.as_deref())?,
+        (VaultProvider::OnePassword, VaultField::Totp) => run_op(&["item", "get", &id, "--otp"], key.as_deref())?,
+    };
+    let value = value.trim_end_matches(['\r', '\n']).to_string();
+    if value.is_empty() {
+        return Err("该条目没有此字段".to_string());
+    }
+
+    // 用户名不是敏感信息，不自动清除
+    let clear_after_secs = if field == VaultField::Username { None } else { clear_after_secs };
+    clipboard::copy_to_clipboard(value, clear_after_secs)
+}
+
+fn program(provider: VaultProvider) -> &'static str {
+    match provider {
+        VaultProvider::Bitwarden => "bw",
+        VaultProvider::OnePassword => "op",
+    }
+}
+
+fn not_installed(program: &str, error: String) -> VaultStatus {
+    VaultStatus {
+        installed: false,
+        unlocked: false,
+        message: Some(format!("未找到 {} 命令行工具: {}", program, error)),
+    }
+}
+
+// Bitwarden 的会话密钥通过 BW_SESSION 环境变量传入
+fn run_bw(args: &[&str], key: Option<&str>) -> Result<String, String> {
+    let mut full_args = args.to_vec();
+    full_args.push("--nointeraction");
+    match key {
+        Some(key) => run_with_env("bw", &full_args, &[("BW_SESSION", key)]),
+        None => run_with_env("bw", &full_args, &[]),
+    }
+}
+
+// 1Password 的会话密钥通过 OP_SESSION_<账户> 环境变量传入，避免出现在进程参数里
+fn run_op(args: &[&str], key: Option<&str>) -> Result<String, String> {
+    match key {
+        Some(key) => {
+            let accounts = run_with_env("op", &["account", "list", "--format", "json"], &[]).unwrap_or_default();
+            let variable = onepassword_session_variable(&accounts);
+            run_with_env("op", args, &[(&variable, key)])
+        }
+        None => run_with_env("op", args, &[]),
+    }
+}
+
+// signin 未指定账户时登录的是第一个账户
+fn onepassword_session_variable(accounts_json: &str) -> String {
+    serde_json::from_str::<Vec<OnePasswordAccount>>(accounts_json.trim())
+        .ok()
+        .and_then(|accounts| accounts.into_iter().next())
+        .map(|account| format!("OP_SESSION_{}", account.user_uuid))
+        .unwrap_or_else(|| "OP_SESSION".to_string())
+}
+
+fn touch_session(provider: VaultProvider, key: Option<String>) {
+    SESSIONS.lock().unwrap().insert(provider, Session { key, last_used: Instant::now() });
+}
+
+fn session_key(provider: VaultProvider) -> Option<String> {
+    SESSIONS.lock().unwrap().get(&provider)
+        .filter(|session| is_active(session.last_used, Instant::now()))
+        .and_then(|session| session.key.clone())
+}
+
+// 返回当前会话的密钥并刷新空闲时间，已超时则要求重新解锁
+fn active_session(provider: VaultProvider) -> Result<Option<String>, String> {
+    let mut sessions = SESSIONS.lock().unwrap();
+    let now = Instant::now();
+    match sessions.get_mut(&provider) {
+        Some(session) if is_active(session.last_used, now) => {
+            session.last_used = now;
+            Ok(session.key.clone())
+        }
+        _ => {
+            sessions.remove(&provider);
+            ITEMS.lock().unwrap().remove(&provider);
+            Err("密码库已锁定，请先解锁".to_string())
+        }
+    }
+}
+
+fn is_active(last_used: Instant, now: Instant) -> bool {
+    now.duration_since(last_used) < SESSION_IDLE_TIMEOUT
+}
+
+fn cached_items(provider: VaultProvider) -> Option<Vec<VaultItem>> {
+    ITEMS.lock().unwrap().get(&provider)
+        .filter(|(fetched_at, _)| fetched_at.elapsed() < ITEMS_TTL)
+        .map(|(_, items)| items.clone())
+}
+
+// 只保留登录条目；反序列化时忽略密码等字段
+fn parse_bitwarden_items(json: &str) -> Result<Vec<VaultItem>, String> {
+    let items: Vec<BitwardenItem> = serde_json::from_str(json).map_err(|e| format!("解析 Bitwarden 条目失败: {}", e))?;
+    Ok(items.into_iter()
+        .filter(|item| item.kind == BITWARDEN_LOGIN)
+        .map(|item| {
+            let login = item.login;
+            VaultItem {
+                id: item.id,
+                name: item.name,
+                username: login.as_ref().and_then(|login| login.username.clone()).filter(|name| !name.is_empty()),
+                url: login.as_ref()
+                    .and_then(|login| login.uris.as_ref())
+                    .and_then(|uris| uris.iter().find_map(|uri| uri.uri.clone())),
+                vault: None,
+                has_totp: login.as_ref().is_some_and(|login| login.totp.as_deref().is_some_and(|totp| !totp.is_empty())),
+            }
+        })
+        .collect())
+}
+
+fn parse_onepassword_items(json: &str) -> Result<Vec<VaultItem>, String> {
+    let items: Vec<OnePasswordItem> = serde_json::from_str(json).map_err(|e| format!("解析 1Password 条目失败: {}", e))?;
+    Ok(items.into_iter()
+        .filter(|item| item.category == "LOGIN")
+        .map(|item| VaultItem {
+            url: item.urls.iter().find(|url| url.primary).or_else(|| item.urls.first()).map(|url| url.href.clone()),
+            id: item.id,
+            name: item.title,
+            username: item.additional_information.filter(|info| !info.is_empty()),
+            vault: item.vault.and_then(|vault| vault.name),
+            has_totp: true,
+        })
+        .collect())
+}
+
+// 名称前缀优先，其次名称、用户名或网址包含查询
+fn filter_items(items: Vec<VaultItem>, query: &str) -> Vec<VaultItem> {
+    let query = query.trim().to_lowercase();
+    let mut scored: Vec<(u32, VaultItem)> = items.into_iter()
+        .filter_map(|item| {
+            let name = item.name.to_lowercase();
+            let score = if query.is_empty() || name.starts_with(&query) {
+                3
+            } else if name.contains(&query) {
+                2
+            } else if [&item.username, &item.url].iter().any(|field| field.as_deref().is_some_and(|field| field.to_lowercase().contains(&query))) {
+                1
+            } else {
+                return None;
+            };
+            Some((score, item))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.name.to_lowercase().cmp(&b.1.name.to_lowercase())));
+    scored.into_iter().take(MAX_RESULTS).map(|(_, item)| item).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(name: &str, username: Option<&str>, url: Option<&str>) -> VaultItem {
+        VaultItem {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            username: username.map(str::to_string),
+            url: url.map(str::to_string),
+            vault: None,
+            has_totp: false,
+        }
+    }
+
+    #[test]
+    fn test_parse_bitwarden_items() {
+        let json = r#"[
+            {"object": "item", "id": "a1", "type": 1, "name": "GitHub", "folderId": null,
+             "login": {"username": "octocat", "password": "hunter2", "totp": "otpauth://totp/GitHub?secret=ABC",
+                       "uris": [{"match": null, "uri": "https://github.com/login"}]}},
+            {"object": "item", "id": "b2", "type": 2, "name": "Recovery codes", "notes": "secret", "secureNote": {"type": 0}},
+            {"object": "item", "id": "c3", "type": 1, "name": "Router", "login": {"username": "", "password": "admin", "totp": null, "uris": null}}
+        ]"#;
+        let items = parse_bitwarden_items(json).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].username.as_deref(), Some("octocat"));
+        assert_eq!(items[0].url.as_deref(), Some("https://github.com/login"));
+        assert!(items[0].has_totp);
+        assert_eq!(items[1].username, None);
+        assert!(!items[1].has_totp);
+    }
+
+    #[test]
+    fn test_parse_onepassword_items() {
+        let json = r#"[
+            {"id": "x1", "title": "Slack", "version": 3, "vault": {"id": "v", "name": "Work"}, "category": "LOGIN",
+             "additional_information": "me@example.com",
+             "urls": [{"label": "website", "href": "https://old.slack.com"}, {"primary": true, "href": "https://slack.com"}]},
+            {"id": "x2", "title": "Passport", "category": "PASSPORT", "vault": {"id": "v", "name": "Private"}}
+        ]"#;
+        let items = parse_onepassword_items(json).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].vault.as_deref(), Some("Work"));
+        assert_eq!(items[0].url.as_deref(), Some("https://slack.com"));
+        assert_eq!(items[0].username.as_deref(), Some("me@example.com"));
+    }
+
+    #[test]
+    fn test_onepassword_session_variable() {
+        let json = r#"[{"url":"my.1password.com","email":"a@example.com","user_uuid":"ABC123","account_uuid":"XYZ"}]"#;
+        assert_eq!(onepassword_session_variable(json), "OP_SESSION_ABC123");
+        assert_eq!(onepassword_session_variable("[]"), "OP_SESSION");
+        assert_eq!(onepassword_session_variable(""), "OP_SESSION");
+    }
+
+    #[test]
+    fn test_filter_items() {
+        let items = vec![
+            item("My GitHub", None, None),
+            item("GitHub", Some("octocat"), None),
+            item("Mail", Some("github-bot"), None),
+            item("Bank", None, Some("https://bank.example.com")),
+        ];
+        let names: Vec<String> = filter_items(items.clone(), "git").into_iter().map(|item| item.name).collect();
+        assert_eq!(names, ["GitHub", "My GitHub", "Mail"]);
+        assert_eq!(filter_items(items.clone(), "bank.example")[0].name, "Bank");
+        assert_eq!(filter_items(items, "").len(), 4);
+    }
+
+    #[test]
+    fn test_session_idle_timeout() {
+        let now = Instant::now();
+        assert!(is_active(now, now + Duration::from_secs(60)));
+        assert!(!is_active(now, now + SESSION_IDLE_TIMEOUT));
+    }
+
+    #[test]
+    fn test_copy_rejects_option_like_ids() {
+        assert!(copy_vault_field(VaultProvider::Bitwarden, "--help".to_string(), VaultField::Password, None).is_err());
+    }
+
+    #[test]
+    fn test_provider_serialization() {
+        assert_eq!(serde_json::to_string(&VaultProvider::OnePassword).unwrap(), "\"onepassword\"");
+        assert_eq!(serde_json::from_str::<VaultField>("\"totp\"").unwrap(), VaultField::Totp);
+    }
+}
@@ -0,0 +1,211 @@
+// VS Code 最近打开：读取 VS Code（及 Insiders、VSCodium）记录的最近工作区和文件夹
+use crate::system_command::command;
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// (配置目录名, 命令行程序)
+const EDITIONS: &[(&str, &str)] = &[
+    ("Code", "code"),
+    ("Code - Insiders", "code-insiders"),
+    ("VSCodium", "codium"),
+];
+
+const RECENTS_KEY: &str = "history.recentlyOpenedPathsList";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RecentKind {
+    Folder,
+    Workspace,
+    File,
+}
+
+// path 为本地路径，远程（SSH、WSL、容器）条目为空，remote 为远程名称
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VsCodeRecent {
+    pub label: String,
+    pub uri: String,
+    pub kind: RecentKind,
+    pub path: Option<String>,
+    pub remote: Option<String>,
+    pub program: String,
+}
+
+// 按最近使用的顺序返回，先列出 VS Code，再列出 Insiders 和 VSCodium
+#[tauri::command(async)]
+pub fn list_vscode_recents() -> Result<Vec<VsCodeRecent>, String> {
+    let Some(config_dir) = dirs::config_dir() else {
+        return Ok(vec![]);
+    };
+
+    let mut recents = Vec::new();
+    for (dir, program) in EDITIONS {
+        let user_dir = config_dir.join(dir).join("User");
+        if let Some(json) = read_recents_json(&user_dir) {
+            recents.extend(parse_recents(&json, program)?);
+        }
+    }
+    Ok(recents)
+}
+
+// 使用 URI 打开，远程工作区也能直接打开
+#[tauri::command]
+pub fn open_vscode_recent(recent: VsCodeRecent, code_command: Option<String>) -> Result<(), String> {
+    let program = code_command.filter(|program| !program.trim().is_empty()).unwrap_or(recent.program);
+    let flag = match recent.kind {
+        RecentKind::Folder => "--folder-uri",
+        RecentKind::Workspace | RecentKind::File => "--file-uri",
+    };
+
+    command(program.trim())
+        .args([flag, &recent.uri])
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("启动 {} 失败: {}（请确认已将 VS Code 命令加入 PATH）", program, e))
+}
+
+// 新版本保存在 state.vscdb 中，旧版本保存在 storage.json 中
+fn read_recents_json(user_dir: &Path) -> Option<String> {
+    let database = user_dir.join("globalStorage").join("state.vscdb");
+    if database.is_file() {
+        if let Ok(Some(json)) = read_state_value(&database, RECENTS_KEY) {
+            return Some(json);
+        }
+    }
+
+    let storage = fs::read_to_string(user_dir.join("globalStorage").join("storage.json")).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&storage).ok()?;
+    value.get("openedPathsList").map(|list| list.to_string())
+}
+
+fn read_state_value(database: &Path, key: &str) -> rusqlite::Result<Option<String>> {
+    let conn = Connection::open_with_flags(database, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    conn.query_row("SELECT value FROM ItemTable WHERE key = ?1", [key], |row| {
+        Ok(match row.get_ref(0)? {
+            ValueRef::Text(bytes) | ValueRef::Blob(bytes) => String::from_utf8_lossy(bytes).to_string(),
+            _ => String::new(),
+        })
+    })
+    .optional()
+}
+
+fn parse_recents(json: &str, program: &str) -> Result<Vec<VsCodeRecent>, String> {
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| format!("解析 VS Code 最近记录失败: {}", e))?;
+    let entries = value.get("entries").and_then(|entries| entries.as_array()).cloned().unwrap_or_default();
+
+    Ok(entries.iter()
+        .filter_map(|entry| {
+            let text = |key: &str| entry.get(key).and_then(|value| value.as_str()).map(str::to_string);
+            let (kind, uri) = if let Some(uri) = text("folderUri") {
+                (RecentKind::Folder, uri)
+            } else if let Some(uri) = entry.get("workspace").and_then(|workspace| workspace.get("configPath")).and_then(|uri| uri.as_str()) {
+                (RecentKind::Workspace, uri.to_string())
+            } else {
+                (RecentKind::File, text("fileUri")?)
+            };
+
+            let path = file_uri_to_path(&uri);
+            let remote = text("remoteAuthority");
+            let label = text("label")
+                .map(|label| label.split(" [").next().unwrap_or(&label).to_string())
+                .or_else(|| path.as_ref().and_then(|path| path.file_name()).map(|name| name.to_string_lossy().to_string()))
+                .unwrap_or_else(|| uri.trim_end_matches('/').rsplit('/').next().map(percent_decode).unwrap_or_default());
+
+            Some(VsCodeRecent {
+                label: label.trim_end_matches(".code-workspace").to_string(),
+                kind,
+                path: path.map(|path| path.to_string_lossy().to_string()),
+                remote,
+                program: program.to_string(),
+                uri,
+            })
+        })
+        .collect())
+}
+
+// file:///home/me/app -> /home/me/app，file:///c%3A/src -> c:/src
+fn file_uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = percent_decode(uri.strip_prefix("file://")?);
+    let bytes = path.as_bytes();
+    let windows_drive = bytes.len() >= 3 && bytes[0] == b'/' && bytes[1].is_ascii_alphabetic() && bytes[2] == b':';
+    Some(PathBuf::from(if windows_drive { &path[1..] } else { &path }))
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|hex| std::str::from_utf8(hex).ok()).and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const RECENTS: &str = r#"{"entries": [
+        {"folderUri": "file:///home/me/code/launch-rs"},
+        {"workspace": {"id": "1a2b", "configPath": "file:///home/me/work/platform.code-workspace"}},
+        {"folderUri": "vscode-remote://ssh-remote%2Bprod/srv/app", "label": "/srv/app [SSH: prod]", "remoteAuthority": "ssh-remote+prod"},
+        {"fileUri": "file:///c%3A/Users/me/notes%20today.md"},
+        {"unknown": true}
+    ]}"#;
+
+    #[test]
+    fn test_parse_recents() {
+        let recents = parse_recents(RECENTS, "code").unwrap();
+        assert_eq!(recents.len(), 4);
+        assert_eq!(recents[0].label, "launch-rs");
+        assert_eq!(recents[0].kind, RecentKind::Folder);
+        assert_eq!(recents[0].path.as_deref(), Some("/home/me/code/launch-rs"));
+        assert_eq!(recents[1].label, "platform");
+        assert_eq!(recents[1].kind, RecentKind::Workspace);
+        assert_eq!(recents[2].label, "/srv/app");
+        assert_eq!(recents[2].path, None);
+        assert_eq!(recents[2].remote.as_deref(), Some("ssh-remote+prod"));
+        assert_eq!(recents[3].kind, RecentKind::File);
+        assert_eq!(recents[3].path.as_deref(), Some("c:/Users/me/notes today.md"));
+        assert!(parse_recents("not json", "code").is_err());
+    }
+
+    #[test]
+    fn test_read_recents_json() {
+        let temp = TempDir::new().unwrap();
+        let storage_dir = temp.path().join("globalStorage");
+        fs::create_dir_all(&storage_dir).unwrap();
+
+        // 旧版本的 storage.json
+        fs::write(storage_dir.join("storage.json"), r#"{"openedPathsList": {"entries": [{"folderUri": "file:///old"}]}}"#).unwrap();
+        let json = read_recents_json(temp.path()).unwrap();
+        assert_eq!(parse_recents(&json, "code").unwrap()[0].path.as_deref(), Some("/old"));
+
+        let conn = Connection::open(storage_dir.join("state.vscdb")).unwrap();
+        conn.execute_batch("CREATE TABLE ItemTable (key TEXT UNIQUE ON CONFLICT REPLACE, value BLOB)").unwrap();
+        conn.execute("INSERT INTO ItemTable VALUES (?1, ?2)", [RECENTS_KEY, r#"{"entries": [{"folderUri": "file:///new"}]}"#]).unwrap();
+        drop(conn);
+        let json = read_recents_json(temp.path()).unwrap();
+        assert_eq!(parse_recents(&json, "code").unwrap()[0].path.as_deref(), Some("/new"));
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("a%20b%E4%B8%AD"), "a b中");
+        assert_eq!(percent_decode("100%"), "100%");
+    }
+}
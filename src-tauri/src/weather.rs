@@ -0,0 +1,320 @@
+// 天气：通过 Open-Meteo（无需密钥，也可以换成自建的兼容服务）查询当前天气和未来几天的预报
+use crate::http::{self, RateLimiter, TtlCache};
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+const DEFAULT_FORECAST_API: &str = "https://api.open-meteo.com/v1/forecast";
+const DEFAULT_GEOCODING_API: &str = "https://geocoding-api.open-meteo.com/v1/search";
+const FORECAST_DAYS: &str = "3";
+
+// 城市坐标基本不变，缓存一天
+static LOCATIONS: LazyLock<TtlCache<WeatherLocation>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(24 * 60 * 60)));
+static FORECASTS: LazyLock<TtlCache<Weather>> = LazyLock::new(|| TtlCache::new(Duration::from_secs(15 * 60)));
+static LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(30, Duration::from_secs(60)));
+
+// 接口地址为空时使用 Open-Meteo 官方服务
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WeatherConfig {
+    pub forecast_url: Option<String>,
+    pub geocoding_url: Option<String>,
+    pub fahrenheit: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WeatherLocation {
+    pub name: String,
+    pub region: Option<String>,
+    pub country: Option<String>,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CurrentWeather {
+    pub time: String,
+    pub temperature: f64,
+    pub apparent_temperature: f64,
+    pub humidity: f64,
+    pub wind_speed: f64,
+    pub weather_code: u32,
+    pub description: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DailyForecast {
+    pub date: String,
+    pub weather_code: u32,
+    pub description: String,
+    pub temperature_max: f64,
+    pub temperature_min: f64,
+    pub precipitation_probability: Option<f64>,
+}
+
+// temperature_unit 为 °C 或 °F，wind_speed_unit 为 km/h 或 mp/h
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Weather {
+    pub location: WeatherLocation,
+    pub current: CurrentWeather,
+    pub daily: Vec<DailyForecast>,
+    pub temperature_unit: String,
+    pub wind_speed_unit: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeocodingResponse {
+    #[serde(default)]
+    results: Vec<GeocodingResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeocodingResult {
+    name: String,
+    latitude: f64,
+    longitude: f64,
+    country: Option<String>,
+    admin1: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForecastResponse {
+    current: ForecastCurrent,
+    current_units: ForecastUnits,
+    daily: ForecastDaily,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForecastCurrent {
+    time: String,
+    temperature_2m: f64,
+    apparent_temperature: f64,
+    relative_humidity_2m: f64,
+    wind_speed_10m: f64,
+    weather_code: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForecastUnits {
+    temperature_2m: String,
+    wind_speed_10m: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForecastDaily {
+    time: Vec<String>,
+    weather_code: Vec<u32>,
+    temperature_2m_max: Vec<f64>,
+    temperature_2m_min: Vec<f64>,
+    #[serde(default)]
+    precipitation_probability_max: Vec<Option<f64>>,
+}
+
+// location 为城市名或 "纬度,经度"
+#[tauri::command]
+pub async fn get_weather(location: String, config: WeatherConfig) -> Result<Weather, String> {
+    let location = location.trim();
+    if location.is_empty() {
+        return Err("请输入城市名，或在插件设置中填写默认位置".to_string());
+    }
+
+    let place = match parse_coordinates(location) {
+        Some((latitude, longitude)) => WeatherLocation {
+            name: location.to_string(),
+            region: None,
+            country: None,
+            latitude,
+            longitude,
+        },
+        None => {
+            let geocoding_url = api_url(config.geocoding_url.as_deref(), DEFAULT_GEOCODING_API);
+            let key = format!("{}|{}", geocoding_url, location.to_lowercase());
+            LOCATIONS.get_or_fetch_async(&key, || geocode(&geocoding_url, location)).await?
+        }
+    };
+
+    let forecast_url = api_url(config.forecast_url.as_deref(), DEFAULT_FORECAST_API);
+    let key = format!("{}|{:.3},{:.3}|{}", forecast_url, place.latitude, place.longitude, config.fahrenheit);
+    let mut weather = FORECASTS.get_or_fetch_async(&key, || fetch_forecast(&forecast_url, &place, config.fahrenheit)).await?;
+    weather.location = place;
+    Ok(weather)
+}
+
+fn api_url(configured: Option<&str>, default: &str) -> String {
+    configured.map(str::trim).filter(|url| !url.is_empty()).unwrap_or(default).to_string()
+}
+
+fn parse_coordinates(text: &str) -> Option<(f64, f64)> {
+    let (latitude, longitude) = text.split_once(',')?;
+    let latitude: f64 = latitude.trim().parse().ok()?;
+    let longitude: f64 = longitude.trim().parse().ok()?;
+    ((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude)).then_some((latitude, longitude))
+}
+
+async fn geocode(url: &str, name: &str) -> Result<WeatherLocation, String> {
+    LIMITER.acquire("Open-Meteo")?;
+    let response = http::client()?
+        .get(url)
+        .query(&[("name", name), ("count", "1"), ("language", "zh"), ("format", "json")])
+        .send()
+        .await
+        .map_err(|e| format!("查询城市失败: {}", e))?;
+    let body = http::check_status(response, "Open-Meteo").await?
+        .text()
+        .await
+        .map_err(|e| format!("读取城市查询结果失败: {}", e))?;
+    parse_geocoding(&body)?.ok_or_else(|| format!("未找到城市: {}", name))
+}
+
+async fn fetch_forecast(url: &str, place: &WeatherLocation, fahrenheit: bool) -> Result<Weather, String> {
+    LIMITER.acquire("Open-Meteo")?;
+    let latitude = place.latitude.to_string();
+    let longitude = place.longitude.to_string();
+    let mut query = vec![
+        ("latitude", latitude.as_str()),
+        ("longitude", longitude.as_str()),
+        ("current", "temperature_2m,relative_humidity_2m,apparent_temperature,weather_code,wind_speed_10m"),
+        ("daily", "weather_code,temperature_2m_max,temperature_2m_min,precipitation_probability_max"),
+        ("timezone", "auto"),
+        ("forecast_days", FORECAST_DAYS),
+    ];
+    if fahrenheit {
+        query.extend([("temperature_unit", "fahrenheit"), ("wind_speed_unit", "mph")]);
+    }
+
+    let response = http::client()?
+        .get(url)
+        .query(&query)
+        .send()
+        .await
+        .map_err(|e| format!("查询天气失败: {}", e))?;
+    let body = http::check_status(response, "Open-Meteo").await?
+        .text()
+        .await
+        .map_err(|e| format!("读取天气数据失败: {}", e))?;
+    parse_forecast(&body, place.clone())
+}
+
+fn parse_geocoding(body: &str) -> Result<Option<WeatherLocation>, String> {
+    let response: GeocodingResponse = serde_json::from_str(body).map_err(|e| format!("解析城市查询结果失败: {}", e))?;
+    Ok(response.results.into_iter().next().map(|result| WeatherLocation {
+        name: result.name,
+        region: result.admin1,
+        country: result.country,
+        latitude: result.latitude,
+        longitude: result.longitude,
+    }))
+}
+
+fn parse_forecast(body: &str, location: WeatherLocation) -> Result<Weather, String> {
+    let response: ForecastResponse = serde_json::from_str(body).map_err(|e| format!("解析天气数据失败: {}", e))?;
+    let daily = &response.daily;
+    let days = daily.time.len().min(daily.weather_code.len()).min(daily.temperature_2m_max.len()).min(daily.temperature_2m_min.len());
+
+    Ok(Weather {
+        location,
+        current: CurrentWeather {
+            time: response.current.time,
+            temperature: response.current.temperature_2m,
+            apparent_temperature: response.current.apparent_temperature,
+            humidity: response.current.relative_humidity_2m,
+            wind_speed: response.current.wind_speed_10m,
+            weather_code: response.current.weather_code,
+            description: describe_weather_code(response.current.weather_code).to_string(),
+        },
+        daily: (0..days)
+            .map(|i| DailyForecast {
+                date: daily.time[i].clone(),
+                weather_code: daily.weather_code[i],
+                description: describe_weather_code(daily.weather_code[i]).to_string(),
+                temperature_max: daily.temperature_2m_max[i],
+                temperature_min: daily.temperature_2m_min[i],
+                precipitation_probability: daily.precipitation_probability_max.get(i).copied().flatten(),
+            })
+            .collect(),
+        temperature_unit: response.current_units.temperature_2m,
+        wind_speed_unit: response.current_units.wind_speed_10m,
+    })
+}
+
+// WMO 天气代码
+fn describe_weather_code(code: u32) -> &'static str {
+    match code {
+        0 => "晴",
+        1 => "大致晴朗",
+        2 => "多云",
+        3 => "阴",
+        45 | 48 => "雾",
+        51 | 53 | 55 => "毛毛雨",
+        56 | 57 => "冻毛毛雨",
+        61 => "小雨",
+        63 => "中雨",
+        65 => "大雨",
+        66 | 67 => "冻雨",
+        71 => "小雪",
+        73 => "中雪",
+        75 => "大雪",
+        77 => "雪粒",
+        80..=82 => "阵雨",
+        85 | 86 => "阵雪",
+        95 => "雷暴",
+        96 | 99 => "雷暴伴冰雹",
+        _ => "未知",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_coordinates() {
+        assert_eq!(parse_coordinates("39.9, 116.4"), Some((39.9, 116.4)));
+        assert_eq!(parse_coordinates("-33.87,151.21"), Some((-33.87, 151.21)));
+        assert_eq!(parse_coordinates("91,0"), None);
+        assert_eq!(parse_coordinates("Beijing"), None);
+        assert_eq!(parse_coordinates("New York, NY"), None);
+    }
+
+    #[test]
+    fn test_parse_geocoding() {
+        let body = r#"{"results": [{"id": 1816670, "name": "北京", "latitude": 39.9075, "longitude": 116.39723,
+            "country": "中国", "admin1": "北京市", "timezone": "Asia/Shanghai"}], "generationtime_ms": 0.5}"#;
+        let location = parse_geocoding(body).unwrap().unwrap();
+        assert_eq!(location.name, "北京");
+        assert_eq!(location.country.as_deref(), Some("中国"));
+        assert_eq!(location.latitude, 39.9075);
+
+        // 没有结果时不返回 results 字段
+        assert_eq!(parse_geocoding(r#"{"generationtime_ms": 0.3}"#).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_forecast() {
+        let body = r#"{
+            "latitude": 39.9, "longitude": 116.4, "timezone": "Asia/Shanghai",
+            "current_units": {"time": "iso8601", "temperature_2m": "°C", "relative_humidity_2m": "%", "apparent_temperature": "°C", "weather_code": "wmo code", "wind_speed_10m": "km/h"},
+            "current": {"time": "2024-06-01T14:00", "interval": 900, "temperature_2m": 28.4, "relative_humidity_2m": 35,
+                        "apparent_temperature": 27.9, "weather_code": 2, "wind_speed_10m": 9.7},
+            "daily": {"time": ["2024-06-01", "2024-06-02"], "weather_code": [2, 61],
+                      "temperature_2m_max": [30.1, 25.0], "temperature_2m_min": [18.2, 17.5],
+                      "precipitation_probability_max": [5, null]}
+        }"#;
+        let location = WeatherLocation { name: "北京".to_string(), region: None, country: None, latitude: 39.9, longitude: 116.4 };
+        let weather = parse_forecast(body, location).unwrap();
+        assert_eq!(weather.current.temperature, 28.4);
+        assert_eq!(weather.current.description, "多云");
+        assert_eq!(weather.temperature_unit, "°C");
+        assert_eq!(weather.daily.len(), 2);
+        assert_eq!(weather.daily[1].description, "小雨");
+        assert_eq!(weather.daily[0].precipitation_probability, Some(5.0));
+        assert_eq!(weather.daily[1].precipitation_probability, None);
+        assert!(parse_forecast(r#"{"error": true, "reason": "Latitude must be in range"}"#, weather.location).is_err());
+    }
+
+    #[test]
+    fn test_describe_weather_code() {
+        assert_eq!(describe_weather_code(0), "晴");
+        assert_eq!(describe_weather_code(81), "阵雨");
+        assert_eq!(describe_weather_code(42), "未知");
+    }
+}
@@ -0,0 +1,364 @@
+// Wi-Fi 切换：列出已知/可见的网络并连接，开关 Wi-Fi（nmcli / networksetup / netsh）
+use crate::system_command::run;
+use serde::{Deserialize, Serialize};
+
+// Wi-Fi 网络
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WifiNetwork {
+    pub ssid: String,
+    pub signal: Option<u8>, // 0-100
+    pub secure: bool,
+    pub known: bool,
+    pub connected: bool,
+}
+
+#[tauri::command(async)]
+pub fn list_wifi_networks() -> Result<Vec<WifiNetwork>, String> {
+    let mut networks = match std::env::consts::OS {
+        "linux" => {
+            let visible = parse_nmcli_wifi_list(&run("nmcli", &["-t", "-f", "IN-USE,SSID,SIGNAL,SECURITY", "device", "wifi", "list"])?);
+            let known = parse_nmcli_known(&run("nmcli", &["-t", "-f", "NAME,TYPE", "connection", "show"])?);
+            merge_known(visible, &known)
+        }
+        "macos" => {
+            // 新版 macOS 移除了 airport 命令，只列出已保存的网络
+            let device = macos_wifi_device()?;
+            let known = parse_macos_preferred(&run("networksetup", &["-listpreferredwirelessnetworks", &device])?);
+            let current = run("networksetup", &["-getairportnetwork", &device])
+                .ok()
+                .and_then(|output| parse_macos_current(&output));
+            known.into_iter()
+                .map(|ssid| WifiNetwork {
+                    connected: current.as_deref() == Some(ssid.as_str()),
+                    ssid,
+                    signal: None,
+                    secure: true,
+                    known: true,
+                })
+                .collect()
+        }
+        "windows" => {
+            let mut visible = parse_netsh_networks(&run("netsh", &["wlan", "show", "networks", "mode=bssid"])?);
+            let current = parse_netsh_current(&run("netsh", &["wlan", "show", "interfaces"])?);
+            for network in &mut visible {
+                network.connected = current.as_deref() == Some(network.ssid.as_str());
+            }
+            let known = parse_netsh_profiles(&run("netsh", &["wlan", "show", "profiles"])?);
+            merge_known(visible, &known)
+        }
+        os => return Err(format!("当前系统不支持 Wi-Fi 管理: {}", os)),
+    };
+
+    // 已连接 > 已知 > 信号强度
+    networks.sort_by(|a, b| {
+        b.connected.cmp(&a.connected)
+            .then_with(|| b.known.cmp(&a.known))
+            .then_with(|| b.signal.cmp(&a.signal))
+            .then_with(|| a.ssid.cmp(&b.ssid))
+    });
+    Ok(networks)
+}
+
+#[tauri::command(async)]
+pub fn connect_wifi(ssid: String, password: Option<String>) -> Result<(), String> {
+    let password = password.filter(|password| !password.is_empty());
+
+    match std::env::consts::OS {
+        "linux" => {
+            let mut args = vec!["device", "wifi", "connect", ssid.as_str()];
+            if let Some(password) = &password {
+                args.extend(["password", password.as_str()]);
+            }
+            run("nmcli", &args)?;
+        }
+        "macos" => {
+            let device = macos_wifi_device()?;
+            let mut args = vec!["-setairportnetwork", device.as_str(), ssid.as_str()];
+            if let Some(password) = &password {
+                args.push(password.as_str());
+            }
+            let output = run("networksetup", &args)?;
+            // networksetup 连接失败时也返回 0
+            if output.contains("Could not find network") || output.contains("Failed") {
+                return Err(format!("连接 Wi-Fi 失败: {}", output.trim()));
+            }
+        }
+        "windows" => {
+            // netsh 只能连接已保存配置文件的网络
+            let known = parse_netsh_profiles(&run("netsh", &["wlan", "show", "profiles"])?);
+            if !known.contains(&ssid) {
+                return Err(format!("请先在系统设置中连接一次该网络: {}", ssid));
+            }
+            run("netsh", &["wlan", "connect", &format!("name={}", ssid)])?;
+        }
+        os => return Err(format!("当前系统不支持 Wi-Fi 管理: {}", os)),
+    }
+
+    Ok(())
+}
+
+// 打开/关闭 Wi-Fi，不指定时切换当前状态，返回新的状态
+#[tauri::command(async)]
+pub fn set_wifi_enabled(enabled: Option<bool>) -> Result<bool, String> {
+    match std::env::consts::OS {
+        "linux" => {
+            let enabled = enabled.unwrap_or(run("nmcli", &["radio", "wifi"])?.trim() != "enabled");
+            run("nmcli", &["radio", "wifi", if enabled { "on" } else { "off" }])?;
+            Ok(enabled)
+        }
+        "macos" => {
+            let device = macos_wifi_device()?;
+            let enabled = match enabled {
+                Some(enabled) => enabled,
+                None => !run("networksetup", &["-getairportpower", &device])?.trim_end().ends_with("On"),
+            };
+            run("networksetup", &["-setairportpower", &device, if enabled { "on" } else { "off" }])?;
+            Ok(enabled)
+        }
+        "windows" => {
+            // 需要管理员权限
+            let enabled = match enabled {
+                Some(enabled) => enabled,
+                None => parse_netsh_radio_off(&run("netsh", &["wlan", "show", "interfaces"])?),
+            };
+            run("netsh", &[
+                "interface", "set", "interface", "name=Wi-Fi",
+                if enabled { "admin=enabled" } else { "admin=disabled" },
+            ])?;
+            Ok(enabled)
+        }
+        os => Err(format!("当前系统不支持 Wi-Fi 管理: {}", os)),
+    }
+}
+
+fn merge_known(mut visible: Vec<WifiNetwork>, known: &[String]) -> Vec<WifiNetwork> {
+    for network in &mut visible {
+        network.known = known.contains(&network.ssid);
+    }
+    // 不在附近的已知网络也列出，方便查看
+    for ssid in known {
+        if !visible.iter().any(|network| &network.ssid == ssid) {
+            visible.push(WifiNetwork {
+                ssid: ssid.clone(),
+                signal: None,
+                secure: true,
+                known: true,
+                connected: false,
+            });
+        }
+    }
+    visible
+}
+
+// nmcli -t 输出以 ':' 分隔，字段中的 ':' 转义为 '\:'
+fn split_nmcli_fields(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => fields.last_mut().unwrap().extend(chars.next()),
+            ':' => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+fn parse_nmcli_wifi_list(output: &str) -> Vec<WifiNetwork> {
+    let mut networks: Vec<WifiNetwork> = Vec::new();
+    for line in output.lines() {
+        let fields = split_nmcli_fields(line);
+        let [in_use, ssid, signal, security] = fields.as_slice() else {
+            continue;
+        };
+        // 隐藏网络没有 SSID
+        if ssid.is_empty() {
+            continue;
+        }
+
+        let network = WifiNetwork {
+            ssid: ssid.clone(),
+            signal: signal.parse().ok(),
+            secure: !security.is_empty() && security != "--",
+            known: false,
+            connected: in_use == "*",
+        };
+        // 同一网络的多个接入点只保留信号最强的
+        match networks.iter_mut().find(|n| n.ssid == network.ssid) {
+            Some(existing) => {
+                existing.connected |= network.connected;
+                existing.signal = existing.signal.max(network.signal);
+            }
+            None => networks.push(network),
+        }
+    }
+    networks
+}
+
+fn parse_nmcli_known(output: &str) -> Vec<String> {
+    output.lines()
+        .map(split_nmcli_fields)
+        .filter(|fields| fields.len() == 2 && fields[1] == "802-11-wireless")
+        .map(|fields| fields[0].clone())
+        .collect()
+}
+
+fn macos_wifi_device() -> Result<String, String> {
+    parse_macos_wifi_device(&run("networksetup", &["-listallhardwareports"])?)
+        .ok_or_else(|| "未找到 Wi-Fi 网卡".to_string())
+}
+
+// "Hardware Port: Wi-Fi" 的下一行是 "Device: en0"
+fn parse_macos_wifi_device(output: &str) -> Option<String> {
+    let mut lines = output.lines();
+    while let Some(line) = lines.next() {
+        if matches!(line.trim(), "Hardware Port: Wi-Fi" | "Hardware Port: AirPort") {
+            return lines.next()?.trim().strip_prefix("Device:").map(|device| device.trim().to_string());
+        }
+    }
+    None
+}
+
+fn parse_macos_preferred(output: &str) -> Vec<String> {
+    // 第一行是 "Preferred networks on en0:"
+    output.lines()
+        .skip(1)
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn parse_macos_current(output: &str) -> Option<String> {
+    output.trim().strip_prefix("Current Wi-Fi Network:").map(|ssid| ssid.trim().to_string())
+}
+
+// netsh 输出形如 "    SSID                   : Name"
+fn netsh_value<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let (name, value) = line.split_once(':')?;
+    (name.trim() == key).then(|| value.trim())
+}
+
+fn parse_netsh_networks(output: &str) -> Vec<WifiNetwork> {
+    let mut networks: Vec<WifiNetwork> = Vec::new();
+    for line in output.lines() {
+        let trimmed = line.trim();
+        // "SSID 1 : Name"（注意不要匹配 "BSSID 1 : ..."）
+        if trimmed.starts_with("SSID ") {
+            if let Some((_, ssid)) = trimmed.split_once(':') {
+                let ssid = ssid.trim();
+                if !ssid.is_empty() {
+                    networks.push(WifiNetwork {
+                        ssid: ssid.to_string(),
+                        signal: None,
+                        secure: true,
+                        known: false,
+                        connected: false,
+                    });
+                }
+            }
+            continue;
+        }
+
+        let Some(network) = networks.last_mut() else {
+            continue;
+        };
+        if let Some(auth) = netsh_value(line, "Authentication") {
+            network.secure = auth != "Open";
+        } else if let Some(signal) = netsh_value(line, "Signal") {
+            let signal = signal.trim_end_matches('%').parse().ok();
+            network.signal = network.signal.max(signal);
+        }
+    }
+    networks
+}
+
+fn parse_netsh_profiles(output: &str) -> Vec<String> {
+    output.lines()
+        .filter_map(|line| netsh_value(line, "All User Profile").or_else(|| netsh_value(line, "Current User Profile")))
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn parse_netsh_current(output: &str) -> Option<String> {
+    let connected = output.lines().any(|line| netsh_value(line, "State") == Some("connected"));
+    if !connected {
+        return None;
+    }
+    output.lines().find_map(|line| netsh_value(line, "SSID")).map(str::to_string)
+}
+
+// 无线电显示 "Software Off" 或网卡被禁用时认为 Wi-Fi 已关闭
+fn parse_netsh_radio_off(output: &str) -> bool {
+    output.contains("Software Off") || output.contains("There is no wireless interface")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_nmcli_fields() {
+        assert_eq!(split_nmcli_fields("*:Cafe\\:Guest:72:WPA2"), vec!["*", "Cafe:Guest", "72", "WPA2"]);
+        assert_eq!(split_nmcli_fields(" ::40:"), vec![" ", "", "40", ""]);
+    }
+
+    #[test]
+    fn test_parse_nmcli_wifi_list() {
+        let output = "*:Home:80:WPA2\n :Home:60:WPA2\n :Cafe\\:Guest:45:\n ::30:WPA2\n :Office:55:WPA1 WPA2\n";
+        let networks = parse_nmcli_wifi_list(output);
+
+        assert_eq!(networks.len(), 3);
+        assert_eq!(networks[0], WifiNetwork {
+            ssid: "Home".into(),
+            signal: Some(80),
+            secure: true,
+            known: false,
+            connected: true,
+        });
+        assert_eq!(networks[1].ssid, "Cafe:Guest");
+        assert!(!networks[1].secure);
+    }
+
+    #[test]
+    fn test_merge_known() {
+        let known = parse_nmcli_known("Home:802-11-wireless\nWired connection 1:802-3-ethernet\nAway:802-11-wireless\n");
+        assert_eq!(known, vec!["Home", "Away"]);
+
+        let networks = merge_known(parse_nmcli_wifi_list("*:Home:80:WPA2\n :Cafe:45:\n"), &known);
+        let summary: Vec<(&str, bool)> = networks.iter().map(|n| (n.ssid.as_str(), n.known)).collect();
+        assert_eq!(summary, vec![("Home", true), ("Cafe", false), ("Away", true)]);
+    }
+
+    #[test]
+    fn test_parse_macos_output() {
+        let ports = "Hardware Port: Ethernet\nDevice: en1\n\nHardware Port: Wi-Fi\nDevice: en0\nEthernet Address: aa:bb\n";
+        assert_eq!(parse_macos_wifi_device(ports), Some("en0".to_string()));
+        assert_eq!(parse_macos_wifi_device("Hardware Port: Ethernet\nDevice: en1\n"), None);
+
+        assert_eq!(parse_macos_preferred("Preferred networks on en0:\n\tHome\n\tOffice 5G\n"), vec!["Home", "Office 5G"]);
+        assert_eq!(parse_macos_current("Current Wi-Fi Network: Home\n"), Some("Home".to_string()));
+        assert_eq!(parse_macos_current("You are not associated with an AirPort network.\n"), None);
+    }
+
+    #[test]
+    fn test_parse_netsh_output() {
+        let networks = "Interface name : Wi-Fi\nThere are 2 networks currently visible.\n\n\
+            SSID 1 : Home\n    Network type            : Infrastructure\n    Authentication          : WPA2-Personal\n\
+            \x20   BSSID 1                 : aa:bb:cc:dd:ee:ff\n         Signal             : 82%\n\
+            \x20   BSSID 2                 : aa:bb:cc:dd:ee:00\n         Signal             : 40%\n\n\
+            SSID 2 : Airport Free\n    Authentication          : Open\n    BSSID 1 : 11:22:33:44:55:66\n         Signal : 30%\n";
+        let parsed = parse_netsh_networks(networks);
+        assert_eq!(parsed.len(), 2);
+        assert_eq!((parsed[0].ssid.as_str(), parsed[0].signal, parsed[0].secure), ("Home", Some(82), true));
+        assert_eq!((parsed[1].ssid.as_str(), parsed[1].signal, parsed[1].secure), ("Airport Free", Some(30), false));
+
+        let profiles = "User profiles\n-------------\n    All User Profile     : Home\n    All User Profile     : Office\n";
+        assert_eq!(parse_netsh_profiles(profiles), vec!["Home", "Office"]);
+
+        let interfaces = "    Name                   : Wi-Fi\n    State                  : connected\n    SSID                   : Home\n    BSSID                  : aa:bb\n";
+        assert_eq!(parse_netsh_current(interfaces), Some("Home".to_string()));
+        assert_eq!(parse_netsh_current("    State                  : disconnected\n"), None);
+    }
+}
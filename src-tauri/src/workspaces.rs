@@ -0,0 +1,269 @@
+// 虚拟桌面：列出工作区、切换工作区、把当前窗口移到指定工作区（wmctrl / hyprctl / Spaces / Windows 虚拟桌面）
+use crate::system_command::{powershell, run};
+use serde::{Deserialize, Serialize};
+
+// 虚拟桌面 / 工作区
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Workspace {
+    pub id: i64,
+    pub name: String,
+    pub current: bool,
+}
+
+// hyprctl -j 的输出
+#[derive(Debug, Deserialize)]
+struct HyprWorkspace {
+    id: i64,
+    name: String,
+}
+
+// macOS Spaces 配置中的桌面
+#[derive(Debug, Deserialize)]
+struct MacSpace {
+    #[serde(rename = "ManagedSpaceID")]
+    managed_space_id: i64,
+    #[serde(rename = "type", default)]
+    space_type: i64,
+}
+
+const MAC_SPACES_PLIST: &str = "Library/Preferences/com.apple.spaces.plist";
+const MAC_SPACES_KEY: &str = "SpacesDisplayConfiguration.Management Data.Monitors.0";
+
+// Control+1 ~ Control+9 的按键码（需要在"键盘快捷键 > 调度中心"中启用）
+const MAC_DIGIT_KEY_CODES: [u8; 9] = [18, 19, 20, 21, 23, 22, 26, 28, 25];
+
+// 从注册表读取虚拟桌面列表，每行输出 "序号\t是否当前\t名称"
+const WINDOWS_LIST_SCRIPT: &str = r#"$key = 'HKCU:\Software\Microsoft\Windows\CurrentVersion\Explorer\VirtualDesktops'
+$ids = (Get-ItemProperty $key -ErrorAction SilentlyContinue).VirtualDesktopIDs
+$current = (Get-ItemProperty $key -ErrorAction SilentlyContinue).CurrentVirtualDesktop
+if (-not $current) {
+    $session = (Get-Process -Id $PID).SessionId
+    $current = (Get-ItemProperty "HKCU:\Software\Microsoft\Windows\CurrentVersion\Explorer\SessionInfo\$session\VirtualDesktops" -ErrorAction SilentlyContinue).CurrentVirtualDesktop
+}
+if ($ids) {
+    for ($i = 0; $i -lt $ids.Length / 16; $i++) {
+        $guid = [Guid]::new([byte[]]$ids[($i * 16)..($i * 16 + 15)])
+        $name = (Get-ItemProperty "$key\Desktops\{$guid}" -ErrorAction SilentlyContinue).Name
+        $isCurrent = [bool]($current -and ([Guid]::new([byte[]]$current) -eq $guid))
+        "$i`t$isCurrent`t$name"
+    }
+}"#;
+
+// 模拟 Ctrl+Win+方向键逐个切换桌面，$count 为次数，$vk 为方向键
+const WINDOWS_SWITCH_SCRIPT: &str = r#"Add-Type -Namespace LaunchRs -Name Keys -MemberDefinition '[DllImport("user32.dll")] public static extern void keybd_event(byte bVk, byte bScan, uint dwFlags, UIntPtr dwExtraInfo);'
+for ($i = 0; $i -lt $count; $i++) {
+    foreach ($k in 0x11, 0x5B, $vk) { [LaunchRs.Keys]::keybd_event($k, 0, 0, [UIntPtr]::Zero) }
+    foreach ($k in $vk, 0x5B, 0x11) { [LaunchRs.Keys]::keybd_event($k, 0, 2, [UIntPtr]::Zero) }
+    Start-Sleep -Milliseconds 150
+}"#;
+
+#[tauri::command(async)]
+pub fn list_workspaces() -> Result<Vec<Workspace>, String> {
+    match std::env::consts::OS {
+        "linux" => {
+            if is_hyprland() {
+                let current = serde_json::from_str::<HyprWorkspace>(&run("hyprctl", &["activeworkspace", "-j"])?)
+                    .map_err(|e| format!("解析 hyprctl 输出失败: {}", e))?;
+                parse_hyprland_workspaces(&run("hyprctl", &["workspaces", "-j"])?, current.id)
+            } else {
+                Ok(parse_wmctrl_desktops(&run("wmctrl", &["-d"]).map_err(wmctrl_hint)?))
+            }
+        }
+        "macos" => {
+            let plist = dirs::home_dir()
+                .ok_or_else(|| "无法获取用户主目录".to_string())?
+                .join(MAC_SPACES_PLIST);
+            let plist = plist.to_string_lossy();
+            let spaces = run("plutil", &["-extract", &format!("{}.Spaces", MAC_SPACES_KEY), "json", "-o", "-", &plist])?;
+            let current = run("plutil", &["-extract", &format!("{}.Current Space", MAC_SPACES_KEY), "json", "-o", "-", &plist])?;
+            parse_mac_spaces(&spaces, &current)
+        }
+        "windows" => Ok(parse_windows_desktops(&powershell(WINDOWS_LIST_SCRIPT)?)),
+        os => Err(format!("当前系统不支持虚拟桌面: {}", os)),
+    }
+}
+
+#[tauri::command(async)]
+pub fn switch_workspace(id: i64) -> Result<(), String> {
+    match std::env::consts::OS {
+        "linux" => {
+            if is_hyprland() {
+                run("hyprctl", &["dispatch", "workspace", &id.to_string()])?;
+            } else {
+                run("wmctrl", &["-s", &id.to_string()]).map_err(wmctrl_hint)?;
+            }
+        }
+        // Spaces 没有公开 API，通过调度中心的 Control+数字 快捷键切换
+        "macos" => {
+            let key_code = usize::try_from(id - 1).ok()
+                .and_then(|index| MAC_DIGIT_KEY_CODES.get(index))
+                .ok_or_else(|| "macOS 只支持切换到前 9 个桌面".to_string())?;
+            let script = format!("tell application \"System Events\" to key code {} using control down", key_code);
+            run("osascript", &["-e", &script])?;
+        }
+        // 没有公开的切换 API，按当前桌面与目标桌面的距离发送 Ctrl+Win+方向键
+        "windows" => {
+            let current = list_workspaces()?
+                .into_iter()
+                .find(|workspace| workspace.current)
+                .ok_or_else(|| "无法获取当前桌面".to_string())?;
+            let distance = id - current.id;
+            if distance != 0 {
+                let vk = if distance > 0 { "0x27" } else { "0x25" };
+                powershell(&format!("$count = {}\n$vk = {}\n{}", distance.abs(), vk, WINDOWS_SWITCH_SCRIPT))?;
+            }
+        }
+        os => return Err(format!("当前系统不支持虚拟桌面: {}", os)),
+    }
+
+    Ok(())
+}
+
+// 把当前获得焦点的窗口移到指定工作区，调用前需要先隐藏启动器窗口
+#[tauri::command(async)]
+pub fn move_window_to_workspace(id: i64) -> Result<(), String> {
+    match std::env::consts::OS {
+        "linux" => {
+            if is_hyprland() {
+                run("hyprctl", &["dispatch", "movetoworkspacesilent", &id.to_string()])?;
+            } else {
+                run("wmctrl", &["-r", ":ACTIVE:", "-t", &id.to_string()]).map_err(wmctrl_hint)?;
+            }
+            Ok(())
+        }
+        "macos" | "windows" => Err("当前系统没有移动窗口到其他桌面的公开接口".to_string()),
+        os => Err(format!("当前系统不支持虚拟桌面: {}", os)),
+    }
+}
+
+fn is_hyprland() -> bool {
+    std::env::var_os("HYPRLAND_INSTANCE_SIGNATURE").is_some()
+}
+
+fn wmctrl_hint(error: String) -> String {
+    format!("{}（Linux 需要先安装 wmctrl，Wayland 下仅支持 Hyprland）", error)
+}
+
+// 每行形如 "0  * DG: 1920x1080  VP: 0,0  WA: 0,0 1920x1052  Workspace 1"，当前桌面标记为 *
+fn parse_wmctrl_desktops(output: &str) -> Vec<Workspace> {
+    output.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let id = fields.first()?.parse::<i64>().ok()?;
+            let current = fields.get(1) == Some(&"*");
+
+            // 名称在 "WA: x,y WxH" 之后，没有工作区信息时为 "WA: N/A"
+            let name = fields.iter().position(|field| *field == "WA:")
+                .map(|wa| if fields.get(wa + 1) == Some(&"N/A") { wa + 2 } else { wa + 3 })
+                .and_then(|start| fields.get(start..))
+                .map(|name| name.join(" "))
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| format!("桌面 {}", id + 1));
+
+            Some(Workspace { id, name, current })
+        })
+        .collect()
+}
+
+fn parse_hyprland_workspaces(output: &str, current_id: i64) -> Result<Vec<Workspace>, String> {
+    let mut workspaces: Vec<HyprWorkspace> = serde_json::from_str(output)
+        .map_err(|e| format!("解析 hyprctl 输出失败: {}", e))?;
+    workspaces.sort_by_key(|workspace| workspace.id);
+
+    Ok(workspaces.into_iter()
+        // 负数 ID 是特殊工作区（scratchpad 等）
+        .filter(|workspace| workspace.id > 0)
+        .map(|workspace| Workspace {
+            current: workspace.id == current_id,
+            id: workspace.id,
+            name: workspace.name,
+        })
+        .collect())
+}
+
+// 只保留普通桌面（type 0），全屏应用占用的空间不算
+fn parse_mac_spaces(spaces: &str, current: &str) -> Result<Vec<Workspace>, String> {
+    let spaces: Vec<MacSpace> = serde_json::from_str(spaces)
+        .map_err(|e| format!("解析 Spaces 配置失败: {}", e))?;
+    let current: MacSpace = serde_json::from_str(current)
+        .map_err(|e| format!("解析 Spaces 配置失败: {}", e))?;
+
+    Ok(spaces.into_iter()
+        .filter(|space| space.space_type == 0)
+        .enumerate()
+        .map(|(index, space)| Workspace {
+            id: index as i64 + 1,
+            name: format!("桌面 {}", index + 1),
+            current: space.managed_space_id == current.managed_space_id,
+        })
+        .collect())
+}
+
+// "序号\t是否当前\t名称"，未命名的桌面名称为空
+fn parse_windows_desktops(output: &str) -> Vec<Workspace> {
+    output.lines()
+        .filter_map(|line| {
+            let mut fields = line.trim_end_matches('\r').splitn(3, '\t');
+            let id = fields.next()?.trim().parse::<i64>().ok()?;
+            let current = fields.next()?.trim().eq_ignore_ascii_case("true");
+            let name = fields.next().map(str::trim).filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("桌面 {}", id + 1));
+            Some(Workspace { id, name, current })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wmctrl_desktops() {
+        let output = "0  * DG: 1920x1080  VP: 0,0  WA: 0,0 1920x1052  Workspace 1\n1  - DG: 1920x1080  VP: N/A  WA: N/A  Mail\n2  - DG: 1920x1080  VP: N/A  WA: N/A  \n";
+        assert_eq!(parse_wmctrl_desktops(output), vec![
+            Workspace { id: 0, name: "Workspace 1".into(), current: true },
+            Workspace { id: 1, name: "Mail".into(), current: false },
+            Workspace { id: 2, name: "桌面 3".into(), current: false },
+        ]);
+    }
+
+    #[test]
+    fn test_parse_hyprland_workspaces() {
+        let output = r#"[
+            {"id": 3, "name": "web", "monitor": "DP-1", "windows": 2},
+            {"id": 1, "name": "1", "monitor": "DP-1", "windows": 4},
+            {"id": -98, "name": "special:scratchpad", "monitor": "DP-1", "windows": 1}
+        ]"#;
+        let workspaces = parse_hyprland_workspaces(output, 3).unwrap();
+
+        assert_eq!(workspaces.len(), 2);
+        assert_eq!(workspaces[0], Workspace { id: 1, name: "1".into(), current: false });
+        assert!(workspaces[1].current);
+    }
+
+    #[test]
+    fn test_parse_mac_spaces() {
+        let spaces = r#"[
+            {"ManagedSpaceID": 1, "id64": 1, "type": 0, "uuid": ""},
+            {"ManagedSpaceID": 5, "id64": 5, "type": 4, "uuid": "FULLSCREEN"},
+            {"ManagedSpaceID": 7, "id64": 7, "type": 0, "uuid": "A1B2"}
+        ]"#;
+        let current = r#"{"ManagedSpaceID": 7, "id64": 7, "type": 0, "uuid": "A1B2"}"#;
+        let workspaces = parse_mac_spaces(spaces, current).unwrap();
+
+        assert_eq!(workspaces, vec![
+            Workspace { id: 1, name: "桌面 1".into(), current: false },
+            Workspace { id: 2, name: "桌面 2".into(), current: true },
+        ]);
+    }
+
+    #[test]
+    fn test_parse_windows_desktops() {
+        let output = "0\tFalse\t\r\n1\tTrue\tWork\r\n";
+        assert_eq!(parse_windows_desktops(output), vec![
+            Workspace { id: 0, name: "桌面 1".into(), current: false },
+            Workspace { id: 1, name: "Work".into(), current: true },
+        ]);
+    }
+}
@@ -0,0 +1,270 @@
+// 世界时钟：基于 IANA 时区数据库查询城市当前时间
+use chrono::{DateTime, Datelike, Local, Offset, Utc};
+use chrono_tz::{Tz, TZ_VARIANTS};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+// 不是时区标识符的常用城市名（含中文名）
+const CITY_ALIASES: &[(&str, &str)] = &[
+    ("san francisco", "America/Los_Angeles"),
+    ("seattle", "America/Los_Angeles"),
+    ("silicon valley", "America/Los_Angeles"),
+    ("boston", "America/New_York"),
+    ("washington", "America/New_York"),
+    ("miami", "America/New_York"),
+    ("atlanta", "America/New_York"),
+    ("dallas", "America/Chicago"),
+    ("houston", "America/Chicago"),
+    ("austin", "America/Chicago"),
+    ("montreal", "America/Toronto"),
+    ("munich", "Europe/Berlin"),
+    ("frankfurt", "Europe/Berlin"),
+    ("barcelona", "Europe/Madrid"),
+    ("milan", "Europe/Rome"),
+    ("geneva", "Europe/Zurich"),
+    ("beijing", "Asia/Shanghai"),
+    ("shenzhen", "Asia/Shanghai"),
+    ("guangzhou", "Asia/Shanghai"),
+    ("hangzhou", "Asia/Shanghai"),
+    ("mumbai", "Asia/Kolkata"),
+    ("delhi", "Asia/Kolkata"),
+    ("new delhi", "Asia/Kolkata"),
+    ("bangalore", "Asia/Kolkata"),
+    ("osaka", "Asia/Tokyo"),
+    ("hanoi", "Asia/Bangkok"),
+    ("abu dhabi", "Asia/Dubai"),
+    ("canberra", "Australia/Sydney"),
+    ("wellington", "Pacific/Auckland"),
+    ("北京", "Asia/Shanghai"),
+    ("上海", "Asia/Shanghai"),
+    ("深圳", "Asia/Shanghai"),
+    ("香港", "Asia/Hong_Kong"),
+    ("台北", "Asia/Taipei"),
+    ("东京", "Asia/Tokyo"),
+    ("首尔", "Asia/Seoul"),
+    ("新加坡", "Asia/Singapore"),
+    ("伦敦", "Europe/London"),
+    ("巴黎", "Europe/Paris"),
+    ("柏林", "Europe/Berlin"),
+    ("纽约", "America/New_York"),
+    ("洛杉矶", "America/Los_Angeles"),
+    ("旧金山", "America/Los_Angeles"),
+    ("悉尼", "Australia/Sydney"),
+];
+
+const MAX_RESULTS: usize = 10;
+
+// 城市时间
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WorldTime {
+    pub city: String,
+    pub timezone: String,
+    pub time: String,
+    pub date: String,
+    pub weekday: String,
+    pub utc_offset: String,
+    pub diff_from_local: String,
+    pub diff_minutes: i32,
+}
+
+#[tauri::command]
+pub fn lookup_world_time(
+    query: Option<String>,
+    favorites: Option<Vec<String>>,
+) -> Result<Vec<WorldTime>, String> {
+    let now = Utc::now();
+    let local_offset = Local::now().offset().local_minus_utc();
+    let query = query.unwrap_or_default();
+
+    // 空查询时显示收藏的城市
+    if query.trim().is_empty() {
+        return Ok(favorites
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|city| find_zones(city).into_iter().next())
+            .map(|(city, tz)| world_time(&city, tz, now, local_offset))
+            .collect());
+    }
+
+    let zones = find_zones(&query);
+    if zones.is_empty() {
+        return Err(format!("未找到城市或时区: {}", query.trim()));
+    }
+
+    Ok(zones
+        .into_iter()
+        .take(MAX_RESULTS)
+        .map(|(city, tz)| world_time(&city, tz, now, local_offset))
+        .collect())
+}
+
+// 按城市名、别名或时区标识符查找时区，精确匹配优先
+fn find_zones(query: &str) -> Vec<(String, Tz)> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return vec![];
+    }
+    let needle_underscored = needle.replace(' ', "_");
+
+    let mut exact = Vec::new();
+    let mut partial = Vec::new();
+
+    for (alias, zone) in CITY_ALIASES {
+        if let Ok(tz) = zone.parse::<Tz>() {
+            if *alias == needle {
+                exact.push((title_case(alias), tz));
+            } else if alias.starts_with(&needle) {
+                partial.push((title_case(alias), tz));
+            }
+        }
+    }
+
+    for tz in TZ_VARIANTS.iter() {
+        let name = tz.name();
+        let name_lower = name.to_lowercase();
+        let city = name.rsplit('/').next().unwrap_or(name);
+        let city_lower = city.to_lowercase();
+        let display = city.replace('_', " ");
+
+        if city_lower == needle_underscored || name_lower == needle_underscored {
+            exact.push((display, *tz));
+        } else if city_lower.starts_with(&needle_underscored) || name_lower.contains(&needle_underscored) {
+            partial.push((display, *tz));
+        }
+    }
+
+    let mut zones = exact;
+    zones.extend(partial);
+    // 同一时区只保留第一次出现
+    let mut seen = HashSet::new();
+    zones.retain(|(_, tz)| seen.insert(tz.name()));
+    zones
+}
+
+fn world_time(city: &str, tz: Tz, now: DateTime<Utc>, local_offset_seconds: i32) -> WorldTime {
+    let zoned = now.with_timezone(&tz);
+    let offset_seconds = zoned.offset().fix().local_minus_utc();
+    let diff_minutes = (offset_seconds - local_offset_seconds) / 60;
+
+    WorldTime {
+        city: city.to_string(),
+        timezone: tz.name().to_string(),
+        time: zoned.format("%H:%M").to_string(),
+        date: zoned.format("%Y-%m-%d").to_string(),
+        weekday: zoned.weekday().to_string(),
+        utc_offset: zoned.format("%:z").to_string(),
+        diff_from_local: format_diff(diff_minutes),
+        diff_minutes,
+    }
+}
+
+// 与本地时间的差值，如 "+9h"、"-5h30m"、"same"
+fn format_diff(diff_minutes: i32) -> String {
+    if diff_minutes == 0 {
+        return "same".to_string();
+    }
+
+    let sign = if diff_minutes > 0 { '+' } else { '-' };
+    let hours = diff_minutes.abs() / 60;
+    let minutes = diff_minutes.abs() % 60;
+
+    if minutes == 0 {
+        format!("{}{}h", sign, hours)
+    } else {
+        format!("{}{}h{}m", sign, hours, minutes)
+    }
+}
+
+fn title_case(text: &str) -> String {
+    text.split(' ')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_zones_by_tz_city() {
+        let zones = find_zones("tokyo");
+        assert_eq!(zones[0].0, "Tokyo");
+        assert_eq!(zones[0].1.name(), "Asia/Tokyo");
+
+        // 含空格的城市名
+        let zones = find_zones("New York");
+        assert_eq!(zones[0].1.name(), "America/New_York");
+
+        // 完整时区标识符
+        let zones = find_zones("europe/paris");
+        assert_eq!(zones[0].1.name(), "Europe/Paris");
+    }
+
+    #[test]
+    fn test_find_zones_by_alias() {
+        assert_eq!(find_zones("san francisco")[0].1.name(), "America/Los_Angeles");
+        assert_eq!(find_zones("Beijing")[0].1.name(), "Asia/Shanghai");
+        assert_eq!(find_zones("东京")[0].1.name(), "Asia/Tokyo");
+    }
+
+    #[test]
+    fn test_find_zones_no_duplicates() {
+        let zones = find_zones("a");
+        let mut names: Vec<&str> = zones.iter().map(|(_, tz)| tz.name()).collect();
+        let total = names.len();
+        names.dedup();
+        assert_eq!(names.len(), total);
+
+        assert!(find_zones("").is_empty());
+        assert!(find_zones("atlantis-city").is_empty());
+    }
+
+    #[test]
+    fn test_world_time() {
+        // 2024-06-01 00:00:00 UTC
+        let now = DateTime::from_timestamp(1_717_200_000, 0).unwrap();
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+
+        // 本地为 UTC+8
+        let time = world_time("Tokyo", tokyo, now, 8 * 3600);
+        assert_eq!(time.time, "09:00");
+        assert_eq!(time.date, "2024-06-01");
+        assert_eq!(time.weekday, "Sat");
+        assert_eq!(time.utc_offset, "+09:00");
+        assert_eq!(time.diff_minutes, 60);
+        assert_eq!(time.diff_from_local, "+1h");
+
+        // 夏令时
+        let new_york: Tz = "America/New_York".parse().unwrap();
+        let time = world_time("New York", new_york, now, 0);
+        assert_eq!(time.utc_offset, "-04:00");
+        assert_eq!(time.diff_from_local, "-4h");
+    }
+
+    #[test]
+    fn test_format_diff() {
+        assert_eq!(format_diff(0), "same");
+        assert_eq!(format_diff(540), "+9h");
+        assert_eq!(format_diff(-330), "-5h30m");
+    }
+
+    #[test]
+    fn test_lookup_world_time() {
+        let results = lookup_world_time(Some("london".to_string()), None).expect("Should find London");
+        assert_eq!(results[0].timezone, "Europe/London");
+
+        // 空查询返回收藏城市
+        let favorites = vec!["Tokyo".to_string(), "Paris".to_string(), "nowhere-land".to_string()];
+        let results = lookup_world_time(None, Some(favorites)).unwrap();
+        assert_eq!(results.len(), 2);
+
+        assert!(lookup_world_time(Some("atlantis-city".to_string()), None).is_err());
+    }
+}
@@ -230,6 +230,18 @@ describe('性能和缓存系统测试', () => {
       expect(duration).toBeLessThan(1000) // 1000次读写应该在1000ms内完成
     })
 
+    it('应该只删除指定插件的缓存', async () => {
+      await searchCache.set('plugin-a', 'query', [{ id: 'a', title: 'A', description: 'A' }], 10)
+      await searchCache.set('plugin-a', 'other', [{ id: 'a2', title: 'A2', description: 'A2' }], 10)
+      await searchCache.set('plugin-b', 'query', [{ id: 'b', title: 'B', description: 'B' }], 10)
+
+      searchCache.invalidatePlugin('plugin-a')
+
+      expect(await searchCache.get('plugin-a', 'query')).toBeNull()
+      expect(await searchCache.get('plugin-a', 'other')).toBeNull()
+      expect(await searchCache.get('plugin-b', 'query')).toHaveLength(1)
+    })
+
     it('应该处理大量缓存数据', async () => {
       const largeDataSet = []
       const dataSize = 100 // 减少数据量
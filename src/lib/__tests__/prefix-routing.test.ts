@@ -0,0 +1,106 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest'
+import { invoke } from '@tauri-apps/api/core'
+import { useSearchPluginManager } from '@/lib/search-plugin-manager'
+import type { SearchContext } from '@/lib/search-plugins'
+import { WorldClockPlugin } from '@/lib/plugins/builtin/world-clock-plugin'
+import { PackagesPlugin } from '@/lib/plugins/builtin/packages-plugin'
+import { RegistryPlugin } from '@/lib/plugins/builtin/registry-plugin'
+
+/**
+ * 前缀路由测试
+ * 验证内置插件的前缀不会拦截其他插件的搜索，且同一前缀只交给一个插件
+ */
+
+describe('前缀路由', () => {
+  let pluginManager: ReturnType<typeof useSearchPluginManager>
+  let calls: { command: string; args: any }[]
+
+  beforeEach(async () => {
+    pluginManager = useSearchPluginManager()
+    calls = []
+    vi.mocked(invoke).mockImplementation(async (command: string, args?: any) => {
+      calls.push({ command, args })
+      switch (command) {
+        case 'lookup_world_time':
+          return [{
+            city: 'Tokyo',
+            timezone: 'Asia/Tokyo',
+            time: '09:00',
+            date: '2026-10-15',
+            weekday: '周四',
+            utc_offset: '+09:00',
+            diff_minutes: 60,
+            diff_from_local: '快 1 小时'
+          }]
+        case 'search_packages':
+          return {
+            manager: 'brew',
+            packages: [{ id: 'ripgrep', name: 'ripgrep', version: '14.1.0', description: '', installed: false, upgradable: false }]
+          }
+        case 'search_package_registry':
+          return [{ name: 'serde', version: '1.0.0', description: '', url: 'https://crates.io/crates/serde', registry: 'crates' }]
+        default:
+          return []
+      }
+    })
+
+    // 模拟应用搜索：不带前缀，按名称匹配
+    await pluginManager.register({
+      id: 'test-apps',
+      name: 'Test Apps',
+      enabled: true,
+      priority: 100,
+      search: async (context: SearchContext) => context.query.toLowerCase().startsWith('clock')
+        ? [{ id: 'app-clock', title: 'Clock', description: 'App', priority: 100, source: 'test-apps' }]
+        : []
+    })
+    await pluginManager.register(new WorldClockPlugin())
+    await pluginManager.register(new PackagesPlugin())
+    await pluginManager.register(new RegistryPlugin())
+  })
+
+  afterEach(async () => {
+    for (const id of ['test-apps', 'world-clock', 'packages', 'registry']) {
+      await pluginManager.unregister(id)
+    }
+    vi.mocked(invoke).mockResolvedValue([])
+  })
+
+  const called = (command: string) => calls.filter(call => call.command === command)
+
+  it('单独输入 clock 时仍然返回应用结果，同时显示收藏城市', async () => {
+    const results = await pluginManager.search('clock')
+
+    expect(results.some(result => result.id === 'app-clock')).toBe(true)
+    expect(results.some(result => result.source === 'world-clock')).toBe(true)
+    expect(called('lookup_world_time').map(call => call.args.query)).toEqual([''])
+  })
+
+  it('以 clock 开头的应用名不应该触发世界时钟', async () => {
+    const results = await pluginManager.search('clockify')
+
+    expect(results.some(result => result.id === 'app-clock')).toBe(true)
+    expect(called('lookup_world_time')).toHaveLength(0)
+  })
+
+  it('clock 加空格后应该按城市查询', async () => {
+    await pluginManager.search('clock tokyo')
+
+    expect(called('lookup_world_time').map(call => call.args.query)).toEqual(['tokyo'])
+  })
+
+  it('pkg 前缀应该只交给软件包插件', async () => {
+    const results = await pluginManager.search('pkg ripgrep')
+
+    expect(called('search_packages')).toHaveLength(1)
+    expect(called('search_package_registry')).toHaveLength(0)
+    expect(new Set(results.map(result => result.source))).toEqual(new Set(['packages']))
+  })
+
+  it('crate 前缀应该只交给包仓库插件', async () => {
+    await pluginManager.search('crate serde')
+
+    expect(called('search_package_registry').map(call => call.args)).toEqual([{ query: 'serde', registry: 'crates' }])
+    expect(called('search_packages')).toHaveLength(0)
+  })
+})
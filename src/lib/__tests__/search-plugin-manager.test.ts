@@ -1,5 +1,8 @@
-import { describe, it, expect, beforeEach } from 'vitest'
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest'
+import { invoke } from '@tauri-apps/api/core'
 import { useSearchPluginManager } from '@/lib/search-plugin-manager'
+import type { SearchContext } from '@/lib/search-plugins'
+import { RegexPlugin } from '@/lib/plugins/builtin/regex-plugin'
 
 /**
  * 简化的插件管理器测试
@@ -126,6 +129,84 @@ describe('SearchPluginManager', () => {
       expect(results.length).toBeGreaterThan(0)
     })
 
+    it('没有输入前缀时应该跳过 prefixOnly 插件', async () => {
+      let calls = 0
+      const testPlugin = {
+        id: 'test-plugin-prefix-only',
+        name: 'Test Plugin Prefix Only',
+        enabled: true,
+        priority: 50,
+        searchPrefixes: ['po '],
+        prefixOnly: true,
+        search: async () => {
+          calls++
+          return [{ id: 'prefix-only-result', title: 'Prefix Only', description: 'Description' }]
+        }
+      }
+
+      await pluginManager.register(testPlugin)
+      await pluginManager.search('prefix only query')
+      expect(calls).toBe(0)
+
+      const results = await pluginManager.search('po query')
+      expect(calls).toBe(1)
+      expect(results.some(result => result.id === 'prefix-only-result')).toBe(true)
+    })
+
+    it('rawInput 插件应该原样收到前缀后的输入，其他插件不参与搜索', async () => {
+      const received: string[] = []
+      let otherCalls = 0
+      await pluginManager.register({
+        id: 'test-plugin-raw-input',
+        name: 'Test Plugin Raw Input',
+        enabled: true,
+        priority: 50,
+        searchPrefixes: ['raw '],
+        prefixOnly: true,
+        rawInput: true,
+        search: async (context: SearchContext) => {
+          received.push(context.query)
+          return []
+        }
+      })
+      await pluginManager.register({
+        id: 'test-plugin-raw-other',
+        name: 'Test Plugin Raw Other',
+        enabled: true,
+        priority: 50,
+        search: async () => {
+          otherCalls++
+          return []
+        }
+      })
+
+      await pluginManager.search('raw ls -la | grep "$HOME" > out.txt')
+      expect(received).toEqual(['ls -la | grep "$HOME" > out.txt'])
+      expect(otherCalls).toBe(0)
+
+      // 没有前缀时仍然拒绝危险字符
+      await pluginManager.search('ls | grep x')
+      expect(received).toHaveLength(1)
+      expect(otherCalls).toBe(0)
+    })
+
+    it('正则插件应该收到包含特殊字符的正则', async () => {
+      const calls: unknown[] = []
+      vi.mocked(invoke).mockImplementation(async (command: string, args?: unknown) => {
+        if (command === 'test_regex') {
+          calls.push(args)
+          return { total: 0, matches: [] }
+        }
+        return []
+      })
+
+      await pluginManager.register(new RegexPlugin())
+      await pluginManager.search('re (\\d+)-(\\w+) :: 12-ab')
+
+      expect(calls).toEqual([{ pattern: '(\\d+)-(\\w+)', sample: '12-ab', caseInsensitive: false }])
+      vi.mocked(invoke).mockResolvedValue([])
+    })
+
     it('应该搜索空字符串时返回空结果', async () => {
       const results = await pluginManager.search('')
       expect(Array.isArray(results)).toBe(true)
@@ -133,6 +214,129 @@ describe('SearchPluginManager', () => {
     })
   })
 
+  describe('实时刷新', () => {
+    it('通知结果变化后再次搜索应该得到新结果', async () => {
+      let value = 1
+      await pluginManager.register({
+        id: 'test-plugin-live',
+        name: 'Test Plugin Live',
+        enabled: true,
+        priority: 50,
+        searchPrefixes: ['live '],
+        search: async () => [{ id: 'live-value', title: `value ${value}`, description: 'Live', priority: 100, source: 'test-plugin-live' }]
+      })
+
+      let results = await pluginManager.search('live value')
+      expect(results.find(result => result.id === 'live-value')?.title).toBe('value 1')
+
+      value = 2
+      pluginManager.notifyResultsChanged('test-plugin-live')
+      results = await pluginManager.search('live value')
+      expect(results.find(result => result.id === 'live-value')?.title).toBe('value 2')
+    })
+
+    it('刷新单个插件时只替换该插件的结果，不记录为搜索', async () => {
+      let value = 1
+      let otherCalls = 0
+      await pluginManager.register({
+        id: 'test-plugin-refresh',
+        name: 'Test Plugin Refresh',
+        enabled: true,
+        priority: 50,
+        searchPrefixes: ['refresh '],
+        search: async () => [{ id: 'refresh-value', title: `value ${value}`, description: 'Live', priority: 100, source: 'test-plugin-refresh' }]
+      })
+      await pluginManager.register({
+        id: 'test-plugin-refresh-other',
+        name: 'Test Plugin Refresh Other',
+        enabled: true,
+        priority: 50,
+        searchPrefixes: ['refresh '],
+        search: async () => {
+          otherCalls++
+          return [{ id: 'refresh-other', title: 'other', description: 'Other', priority: 90, source: 'test-plugin-refresh-other' }]
+        }
+      })
+
+      const current = await pluginManager.search('refresh value')
+      expect(otherCalls).toBe(1)
+      vi.mocked(invoke).mockClear()
+
+      value = 2
+      pluginManager.notifyResultsChanged('test-plugin-refresh')
+      const refreshed = await pluginManager.refreshPluginResults('test-plugin-refresh', 'refresh value', current)
+
+      expect(otherCalls).toBe(1)
+      expect(refreshed.find(result => result.id === 'refresh-value')?.title).toBe('value 2')
+      expect(refreshed.some(result => result.id === 'refresh-other')).toBe(true)
+      expect(invoke).not.toHaveBeenCalledWith('record_search', expect.anything())
+    })
+  })
+
+  describe('排序学习', () => {
+    let boosts: { result_id: string; score: number }[]
+
+    beforeEach(async () => {
+      boosts = []
+      vi.mocked(invoke).mockImplementation(async (command: string) => {
+        if (command === 'get_selection_boosts') {
+          return boosts
+        }
+        if (command === 'reset_learning') {
+          boosts = []
+        }
+        return []
+      })
+
+      await pluginManager.register({
+        id: 'test-plugin-boost',
+        name: 'Test Plugin Boost',
+        enabled: true,
+        priority: 50,
+        searchPrefixes: ['boost '],
+        prefixOnly: true,
+        search: async () => [
+          { id: 'boost-a', title: 'Boost A', description: 'Description A', priority: 100 },
+          { id: 'boost-b', title: 'Boost B', description: 'Description B', priority: 90 }
+        ]
+      })
+    })
+
+    afterEach(() => {
+      vi.mocked(invoke).mockResolvedValue([])
+    })
+
+    it('之前选中过的结果应该提升优先级', async () => {
+      boosts = [{ result_id: 'boost-b', score: 1 }]
+
+      const results = await pluginManager.search('boost applied')
+      const ids = results.map(result => result.id)
+
+      expect(results.find(result => result.id === 'boost-b')?.priority).toBe(105)
+      expect(ids.indexOf('boost-b')).toBeLessThan(ids.indexOf('boost-a'))
+    })
+
+    it('提升幅度不应该超过上限', async () => {
+      boosts = [{ result_id: 'boost-b', score: 10 }]
+
+      const results = await pluginManager.search('boost capped')
+
+      expect(results.find(result => result.id === 'boost-b')?.priority).toBe(150)
+    })
+
+    it('清除学习数据后不再提升', async () => {
+      boosts = [{ result_id: 'boost-b', score: 1 }]
+      let results = await pluginManager.search('boost cleared')
+      expect(results.find(result => result.id === 'boost-b')?.priority).toBe(105)
+
+      await pluginManager.clearLearningData()
+      expect(invoke).toHaveBeenCalledWith('reset_learning')
+
+      results = await pluginManager.search('boost cleared')
+      expect(results.find(result => result.id === 'boost-b')?.priority).toBe(90)
+    })
+  })
+
   describe('事件系统', () => {
     it('应该能够监听插件注册事件', async () => {
       const mockListener = vi.fn()
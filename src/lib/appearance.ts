@@ -0,0 +1,25 @@
+// lib/appearance.ts
+// 让启动器主题跟随系统外观
+
+import { useUserStore } from '@/store/modules/user'
+
+export type SystemAppearance = 'light' | 'dark'
+
+// 切换系统外观后由后端发出
+export const APPEARANCE_CHANGED_EVENT = 'system-appearance-changed'
+
+export function applyAppearance(appearance: SystemAppearance) {
+    document.documentElement.classList.toggle('dark', appearance === 'dark')
+}
+
+// 主题设为"跟随系统"时，系统外观变化后同步切换启动器的深色样式
+export async function initializeAppearanceSync() {
+    const { listen } = await import('@tauri-apps/api/event')
+    const userStore = useUserStore()
+
+    await listen<SystemAppearance>(APPEARANCE_CHANGED_EVENT, event => {
+        if (userStore.preferences.theme === 'system') {
+            applyAppearance(event.payload)
+        }
+    })
+}
@@ -100,6 +100,8 @@ export interface CacheKey {
  * 缓存值结构
  */
 export interface CacheValue {
+  /** 所属插件（缓存键是哈希，按插件失效时靠它查找） */
+  pluginId: string
   /** 搜索结果 */
   results: SearchResult[]
   /** 缓存时间戳 */
@@ -283,6 +285,7 @@ export class SearchCache {
 
     const now = Date.now()
     const cacheValue: CacheValue = {
+      pluginId,
       results: processedResults,
       timestamp: now,
       count: results.length,
@@ -1063,18 +1066,14 @@ export class SearchCache {
   invalidatePlugin(pluginId: string): void {
     const keysToDelete: string[] = []
     
-    for (const key of this.cache.keys()) {
-      try {
-        const keyData = JSON.parse(key)
-        if (keyData.pluginId === pluginId) {
-          keysToDelete.push(key)
-        }
-      } catch {
-        // 忽略解析错误
+    for (const [key, value] of this.cache.entries()) {
+      if (value.pluginId === pluginId) {
+        keysToDelete.push(key)
       }
     }
     
     keysToDelete.forEach(key => this.cache.delete(key))
+    this.updateStatistics()
     
     logger.info('Plugin cache invalidated', { 
       pluginId, 
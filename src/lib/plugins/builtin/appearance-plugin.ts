@@ -0,0 +1,83 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import type { SystemAppearance } from '../../appearance'
+
+/**
+ * 深色模式插件
+ *
+ * 输入 `dark mode` / `theme` 切换系统深浅色外观，`dark` / `light` 直接切换到对应外观
+ */
+export class AppearancePlugin implements SearchPlugin {
+  id = 'appearance'
+  name = '深色模式'
+  description = '在深色和浅色外观之间切换系统主题'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+  // 不使用前缀，避免 "dark" 开头的其他查询只交给本插件处理
+  private readonly togglePattern = /^(dark mode|light mode|theme|toggle theme|appearance|深色模式|浅色模式|外观)$/i
+  private readonly darkPattern = /^(dark|深色)$/i
+  private readonly lightPattern = /^(light|浅色)$/i
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.SunMoon)
+      logger.info('深色模式插件初始化完成')
+    } catch (error) {
+      logger.warn('深色模式图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    let dark: boolean | null
+    if (this.darkPattern.test(query)) {
+      dark = true
+    } else if (this.lightPattern.test(query)) {
+      dark = false
+    } else if (this.togglePattern.test(query)) {
+      dark = null
+    } else {
+      return []
+    }
+
+    let current: SystemAppearance | null = null
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      current = await invoke<SystemAppearance>('get_system_appearance')
+    } catch (error) {
+      logger.warn('读取系统外观失败', error)
+    }
+
+    const target = dark ?? (current === null ? null : current === 'light')
+    const title = target === null ? '切换深色 / 浅色外观' : target ? '切换到深色外观' : '切换到浅色外观'
+
+    return [{
+      id: 'appearance-toggle',
+      title,
+      description: `当前：${current === null ? '未知' : current === 'dark' ? '深色' : '浅色'}`,
+      icon: this.icon,
+      priority: this.priority + 50,
+      action: () => this.setAppearance(dark),
+      source: this.id,
+      metadata: {
+        type: 'appearance'
+      }
+    }]
+  }
+
+  private async setAppearance(dark: boolean | null): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const appearance = await invoke<SystemAppearance>('set_system_appearance', { dark })
+      logger.info(`已切换到${appearance === 'dark' ? '深色' : '浅色'}外观`)
+    } catch (error) {
+      const appError = handlePluginError('切换系统外观', error)
+      logger.error('切换系统外观失败', appError)
+    }
+  }
+}
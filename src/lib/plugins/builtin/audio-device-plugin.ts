@@ -0,0 +1,100 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface AudioDevice {
+  id: string
+  name: string
+  kind: 'output' | 'input'
+  is_default: boolean
+}
+
+/**
+ * 音频设备插件
+ *
+ * 用法：`audio` 列出输出和输入设备，回车设为默认；`audio out` / `audio in` 只看一类，`audio <名称>` 过滤
+ */
+export class AudioDevicePlugin implements SearchPlugin {
+  id = 'audio-device'
+  name = '音频设备'
+  description = '切换默认的音频输出和输入设备'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['audio ', 'sound ']
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Speaker)
+      logger.info('音频设备插件初始化完成')
+    } catch (error) {
+      logger.warn('音频设备图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 audio / sound
+    if (!context.prefix && !/^(audio|sound)$/i.test(context.query.trim())) {
+      return []
+    }
+    let query = context.prefix ? context.query.trim().toLowerCase() : ''
+    let kind: AudioDevice['kind'] | null = null
+
+    const match = query.match(/^(out|output|in|input)\b\s*(.*)$/)
+    if (match) {
+      kind = match[1].startsWith('out') ? 'output' : 'input'
+      query = match[2]
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const devices = await invoke<AudioDevice[]>('list_audio_devices')
+
+      return devices
+        .filter(device => !kind || device.kind === kind)
+        .filter(device => !query || device.name.toLowerCase().includes(query))
+        .slice(0, context.maxResults)
+        .map((device, index) => ({
+          id: `audio-device-${device.kind}-${device.id}`,
+          title: device.name,
+          description: this.formatDescription(device),
+          icon: this.icon,
+          // 输出设备更常切换
+          priority: this.priority + (device.kind === 'output' ? 50 : 30) - index,
+          action: () => this.setDefault(device),
+          source: this.id,
+          metadata: {
+            type: 'audio-device',
+            kind: device.kind
+          }
+        }))
+    } catch (error) {
+      const appError = handlePluginError('获取音频设备', error)
+      logger.error('获取音频设备失败', appError)
+      return []
+    }
+  }
+
+  private formatDescription(device: AudioDevice): string {
+    const kind = device.kind === 'output' ? '输出' : '输入'
+    return device.is_default ? `${kind} • 当前默认` : `${kind} • 回车设为默认`
+  }
+
+  private async setDefault(device: AudioDevice): Promise<void> {
+    if (device.is_default) {
+      return
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('set_default_audio_device', { id: device.id, kind: device.kind })
+      logger.success(`默认${device.kind === 'output' ? '输出' : '输入'}设备已切换为: ${device.name}`)
+    } catch (error) {
+      const appError = handlePluginError('切换音频设备', error)
+      logger.error('切换音频设备失败', appError)
+    }
+  }
+}
@@ -0,0 +1,138 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+type ChargeState = 'charging' | 'discharging' | 'full' | 'idle' | 'unknown'
+
+interface BatteryStatus {
+  percentage: number
+  state: ChargeState
+  time_remaining_secs: number | null
+}
+
+interface PowerStatus {
+  battery: BatteryStatus | null
+  uptime_secs: number
+  boot_time: number
+}
+
+const STATE_NAMES: Record<ChargeState, string> = {
+  charging: '正在充电',
+  discharging: '使用电池',
+  full: '已充满',
+  idle: '已接通电源，未充电',
+  unknown: '状态未知'
+}
+
+/**
+ * 电池插件
+ *
+ * 输入 `battery` 或 `uptime` 查看电量、充电状态、剩余时间和系统运行时间，回车复制对应的一行
+ */
+export class BatteryPlugin implements SearchPlugin {
+  id = 'battery'
+  name = '电池与运行时间'
+  description = '查看电量、充电状态和系统运行时间'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+
+  private readonly triggerPattern = /^(battery|bat|uptime|电池|电量|运行时间)$/i
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.BatteryMedium)
+      logger.info('电池插件初始化完成')
+    } catch (error) {
+      logger.warn('电池图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    if (!this.triggerPattern.test(context.query.trim())) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const status = await invoke<PowerStatus>('get_power_status')
+      const rows: Array<[string, string, string]> = []
+
+      const battery = status.battery
+      if (battery) {
+        rows.push(['battery-level', `电量 ${Math.round(battery.percentage)}%`, STATE_NAMES[battery.state]])
+        if (battery.time_remaining_secs !== null) {
+          const label = battery.state === 'charging' ? '充满还需' : '剩余使用时间'
+          rows.push(['battery-remaining', `${label} ${this.formatDuration(battery.time_remaining_secs)}`, '根据当前功耗估算'])
+        }
+      } else {
+        rows.push(['battery-none', '没有检测到电池', '台式机或电池信息不可用'])
+      }
+
+      rows.push([
+        'battery-uptime',
+        `已运行 ${this.formatDuration(status.uptime_secs)}`,
+        `开机时间 ${new Date(status.boot_time * 1000).toLocaleString()}`
+      ])
+
+      return rows.map(([id, title, description], index) => this.createResult(
+        id,
+        title,
+        `${description} • 回车复制`,
+        this.priority + 20 - index,
+        () => this.copy(title)
+      ))
+    } catch (error) {
+      const appError = handlePluginError('获取电池状态', error)
+      logger.error('获取电池状态失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'battery'
+      }
+    }
+  }
+
+  private formatDuration(seconds: number): string {
+    const days = Math.floor(seconds / 86400)
+    const hours = Math.floor((seconds % 86400) / 3600)
+    const minutes = Math.floor((seconds % 3600) / 60)
+    if (days > 0) {
+      return `${days} 天 ${hours} 小时`
+    }
+    return hours > 0 ? `${hours} 小时 ${minutes} 分钟` : `${minutes} 分钟`
+  }
+
+  private async copy(text: string): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('battery-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.success(`已复制: ${text}`)
+    } catch (error) {
+      const appError = handlePluginError('复制电池信息', error)
+      logger.error('复制电池信息失败', appError)
+    }
+  }
+}
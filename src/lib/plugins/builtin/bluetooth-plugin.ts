@@ -0,0 +1,81 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface BluetoothDevice {
+  address: string
+  name: string
+  connected: boolean
+}
+
+/**
+ * 蓝牙插件
+ *
+ * 用法：`bt` 或 `bluetooth` 列出已配对设备，回车连接或断开，`bt <名称>` 过滤
+ */
+export class BluetoothPlugin implements SearchPlugin {
+  id = 'bluetooth'
+  name = '蓝牙'
+  description = '连接或断开已配对的蓝牙设备'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['bluetooth ', 'bt ']
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Bluetooth)
+      logger.info('蓝牙插件初始化完成')
+    } catch (error) {
+      logger.warn('蓝牙图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 bt / bluetooth
+    if (!context.prefix && !/^(bt|bluetooth)$/i.test(context.query.trim())) {
+      return []
+    }
+    const query = context.prefix ? context.query.trim().toLowerCase() : ''
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const devices = await invoke<BluetoothDevice[]>('list_bluetooth_devices')
+
+      return devices
+        .filter(device => !query || device.name.toLowerCase().includes(query))
+        .slice(0, context.maxResults)
+        .map((device, index) => ({
+          id: `bluetooth-${device.address}`,
+          title: device.connected ? `断开 ${device.name}` : `连接 ${device.name}`,
+          description: `${device.connected ? '已连接' : '未连接'} • ${device.address}`,
+          icon: this.icon,
+          priority: this.priority + 50 - index,
+          action: () => this.toggle(device),
+          source: this.id,
+          metadata: {
+            type: 'bluetooth',
+            address: device.address
+          }
+        }))
+    } catch (error) {
+      const appError = handlePluginError('获取蓝牙设备', error)
+      logger.error('获取蓝牙设备失败', appError)
+      return []
+    }
+  }
+
+  private async toggle(device: BluetoothDevice): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke(device.connected ? 'disconnect_bluetooth' : 'connect_bluetooth', { address: device.address })
+      logger.success(`${device.connected ? '已断开' : '已连接'}蓝牙设备: ${device.name}`)
+    } catch (error) {
+      const appError = handlePluginError('切换蓝牙连接', error)
+      logger.error('切换蓝牙连接失败', appError)
+    }
+  }
+}
@@ -0,0 +1,148 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface CalendarEvent {
+  uid: string
+  title: string
+  start: number
+  end: number
+  all_day: boolean
+  location: string | null
+  meeting_url: string | null
+  calendar: string
+}
+
+/**
+ * 日历插件
+ *
+ * 用法：`next meeting` 或 `calendar` 查看接下来的事件，`cal <关键词>` 搜索事件，
+ * 有视频会议链接的事件回车直接加入会议
+ */
+export class CalendarPlugin implements SearchPlugin {
+  id = 'calendar'
+  name = '日历'
+  description = '查看和搜索即将到来的日历事件，一键加入视频会议'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 86
+  searchPrefixes = ['calendar ', 'cal ', 'next meeting', "what's next"]
+
+  settings = {
+    schema: [
+      {
+        key: 'icsPaths',
+        label: 'ICS 日历路径',
+        description: 'ICS 文件或包含 ICS 文件的目录，以逗号分隔（macOS 会自动读取本地日历）',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'daysAhead',
+        label: '显示天数',
+        description: '显示未来多少天内的事件',
+        type: 'number' as const,
+        defaultValue: 14
+      }
+    ],
+    values: {
+      icsPaths: '',
+      daysAhead: 14
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.CalendarDays)
+      logger.info('日历插件初始化完成')
+    } catch (error) {
+      logger.warn('日历图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 calendar / cal
+    if (!context.prefix && !/^(calendar|cal)$/i.test(context.query.trim())) {
+      return []
+    }
+    const query = context.prefix ? context.query.trim() : ''
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const events = await invoke<CalendarEvent[]>('upcoming_events', {
+        query: query || null,
+        icsPaths: this.settings.values.icsPaths
+          .split(',')
+          .map(path => path.trim())
+          .filter(Boolean),
+        daysAhead: this.settings.values.daysAhead
+      })
+
+      return events.slice(0, context.maxResults).map((event, index) => ({
+        id: `calendar-${event.uid}-${event.start}`,
+        title: event.title,
+        description: this.formatDescription(event),
+        icon: this.icon,
+        // 越早的事件排得越前
+        priority: this.priority + 50 - index,
+        action: () => this.openEvent(event),
+        source: this.id,
+        metadata: {
+          type: 'calendar-event',
+          start: new Date(event.start * 1000).toISOString(),
+          end: new Date(event.end * 1000).toISOString(),
+          calendar: event.calendar,
+          meetingUrl: event.meeting_url
+        }
+      }))
+    } catch (error) {
+      const appError = handlePluginError('读取日历', error)
+      logger.error('读取日历失败', appError)
+      return []
+    }
+  }
+
+  private formatDescription(event: CalendarEvent): string {
+    const parts = [this.formatTime(event)]
+    if (event.location) {
+      parts.push(event.location)
+    }
+    parts.push(event.meeting_url ? '回车加入会议' : event.calendar)
+    return parts.join(' • ')
+  }
+
+  private formatTime(event: CalendarEvent): string {
+    const start = new Date(event.start * 1000)
+    const now = Date.now()
+
+    if (event.start * 1000 <= now && now < event.end * 1000) {
+      return '进行中'
+    }
+
+    const day = start.toLocaleDateString('zh-CN', { month: 'numeric', day: 'numeric', weekday: 'short' })
+    if (event.all_day) {
+      return `${day} 全天`
+    }
+    const time = start.toLocaleTimeString('zh-CN', { hour: '2-digit', minute: '2-digit' })
+    return `${day} ${time}`
+  }
+
+  private async openEvent(event: CalendarEvent): Promise<void> {
+    if (!event.meeting_url) {
+      logger.info(`日历事件: ${event.title}`)
+      return
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_url', { url: event.meeting_url })
+      logger.info(`加入会议: ${event.title}`)
+    } catch (error) {
+      const appError = handlePluginError('加入会议', error)
+      logger.error('加入会议失败', appError)
+    }
+  }
+}
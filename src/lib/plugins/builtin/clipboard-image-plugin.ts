@@ -0,0 +1,136 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+type ImageFileFormat = 'png' | 'jpeg'
+
+interface SavedImage {
+  path: string
+  width: number
+  height: number
+}
+
+/**
+ * 剪贴板图片插件
+ *
+ * 输入 `save image`、`保存图片` 或 `保存截图`，把剪贴板中的图片以带时间戳的文件名保存为 PNG 或 JPEG；
+ * 保存后再次输入会显示最近保存的文件，回车打开
+ */
+export class ClipboardImagePlugin implements SearchPlugin {
+  id = 'clipboard-image'
+  name = '保存剪贴板图片'
+  description = '把剪贴板中的图片保存为 PNG / JPEG 文件'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+
+  private readonly triggerPattern = /^(save (clipboard|image|screenshot)|保存(剪贴板)?(图片|截图))$/i
+  private lastSaved: SavedImage | null = null
+
+  settings = {
+    schema: [
+      {
+        key: 'folder',
+        label: '保存目录',
+        description: '留空时保存到图片目录下的 Clipboard 文件夹',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'format',
+        label: '默认格式',
+        description: '排在第一位的保存格式',
+        type: 'select' as const,
+        defaultValue: 'png',
+        options: [
+          { label: 'PNG（无损）', value: 'png' },
+          { label: 'JPEG（体积更小）', value: 'jpeg' }
+        ]
+      }
+    ],
+    values: {
+      folder: '',
+      format: 'png'
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.ImageDown)
+      logger.info('剪贴板图片插件初始化完成')
+    } catch (error) {
+      logger.warn('剪贴板图片图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    if (!this.triggerPattern.test(context.query.trim())) {
+      return []
+    }
+
+    const preferred = this.settings.values.format as ImageFileFormat
+    const formats: ImageFileFormat[] = preferred === 'jpeg' ? ['jpeg', 'png'] : ['png', 'jpeg']
+    const folder = this.settings.values.folder || '图片/Clipboard'
+    const results = formats.map((format, index) => this.createResult(
+      `clipboard-image-save-${format}`,
+      `保存剪贴板图片为 ${format.toUpperCase()}`,
+      `保存到 ${folder}`,
+      this.priority + 20 - index,
+      () => this.save(format)
+    ))
+
+    const saved = this.lastSaved
+    if (saved) {
+      results.push(this.createResult(
+        'clipboard-image-last',
+        saved.path,
+        `最近保存 · ${saved.width}×${saved.height} · 回车打开`,
+        this.priority + 10,
+        () => this.open(saved.path)
+      ))
+    }
+    return results
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'clipboard-image'
+      }
+    }
+  }
+
+  private async save(format: ImageFileFormat): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      this.lastSaved = await invoke<SavedImage>('save_clipboard_image', {
+        folder: this.settings.values.folder || null,
+        format
+      })
+      logger.success(`图片已保存到 ${this.lastSaved.path}`)
+    } catch (error) {
+      const appError = handlePluginError('保存剪贴板图片', error)
+      logger.error('保存剪贴板图片失败', appError)
+    }
+  }
+
+  private async open(path: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_path', { path })
+    } catch (error) {
+      const appError = handlePluginError('打开图片', error)
+      logger.error('打开图片失败', appError)
+    }
+  }
+}
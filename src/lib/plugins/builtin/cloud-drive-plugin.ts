@@ -0,0 +1,258 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+type CloudProvider = 'google' | 'dropbox' | 'onedrive'
+
+interface CloudFile {
+  provider: CloudProvider
+  id: string
+  name: string
+  path: string | null
+  url: string
+  modified: string | null
+  size: number | null
+  is_folder: boolean
+  downloadable: boolean
+}
+
+const PROVIDER_NAMES: Record<CloudProvider, string> = {
+  google: 'Google Drive',
+  dropbox: 'Dropbox',
+  onedrive: 'OneDrive'
+}
+
+// 只搜索一个服务时使用的前缀
+const PREFIX_PROVIDERS: Record<string, CloudProvider> = {
+  gdrive: 'google',
+  dropbox: 'dropbox',
+  onedrive: 'onedrive'
+}
+
+const ALL_PROVIDERS: CloudProvider[] = ['google', 'dropbox', 'onedrive']
+
+/**
+ * 云盘插件
+ *
+ * 用法：`cloud <关键词>` 在已登录的 Google Drive、Dropbox 和 OneDrive 中按文件名搜索，回车在浏览器中打开；
+ * `gdrive` / `dropbox` / `onedrive <关键词>` 只搜索一个服务，`cloud dl <关键词>` 回车下载到下载目录。
+ * 先在设置中填写在各平台注册的应用 ID（回调地址 http://localhost:53682/），再用 `cloud login google` 等登录
+ */
+export class CloudDrivePlugin implements SearchPlugin {
+  id = 'cloud-drive'
+  name = '云盘搜索'
+  description = '搜索 Google Drive、Dropbox 和 OneDrive 中的文件'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 70
+  searchPrefixes = ['cloud ', 'gdrive ', 'dropbox ', 'onedrive ']
+  prefixOnly = true
+
+  private folderIcon: any = null
+
+  settings = {
+    schema: [
+      {
+        key: 'googleClientId',
+        label: 'Google 应用 ID',
+        description: 'Google Cloud 控制台中“桌面应用”类型的 OAuth 客户端 ID',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'googleClientSecret',
+        label: 'Google 客户端密钥',
+        description: 'Google 桌面应用换取令牌时需要',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'dropboxAppKey',
+        label: 'Dropbox App Key',
+        description: '应用需要 files.metadata.read 和 files.content.read 权限',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'onedriveClientId',
+        label: 'OneDrive 应用 ID',
+        description: 'Azure 门户中注册的应用（客户端）ID，平台选择“移动和桌面应用程序”',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      googleClientId: '',
+      googleClientSecret: '',
+      dropboxAppKey: '',
+      onedriveClientId: ''
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Cloud)
+      this.folderIcon = await getIcon(ICON_MAP.Folder)
+      logger.info('云盘插件初始化完成')
+    } catch (error) {
+      logger.warn('云盘图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    const prefix = context.prefix?.trim().toLowerCase() ?? ''
+    const [command, ...rest] = query.split(/\s+/)
+
+    if (/^(login|logout)$/i.test(command)) {
+      const provider = (PREFIX_PROVIDERS[prefix] ?? rest[0]?.toLowerCase()) as CloudProvider
+      if (!PROVIDER_NAMES[provider]) {
+        return ALL_PROVIDERS.map(provider => this.createCredentialResult(provider, command.toLowerCase() === 'login'))
+      }
+      return [this.createCredentialResult(provider, command.toLowerCase() === 'login')]
+    }
+
+    const download = /^(dl|download)$/i.test(command)
+    const keyword = download ? rest.join(' ') : query
+    if (!keyword) {
+      return []
+    }
+
+    const providers = PREFIX_PROVIDERS[prefix] ? [PREFIX_PROVIDERS[prefix]] : ALL_PROVIDERS
+    let files: CloudFile[]
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      files = await invoke<CloudFile[]>('search_cloud_drives', { query: keyword, providers })
+    } catch (error) {
+      // 未登录、令牌过期等情况直接显示原因
+      logger.debug('搜索云盘失败', error)
+      return [this.createResult('cloud-error', String(error), '无法搜索云盘', this.priority, async () => {})]
+    }
+
+    return files
+      .filter(file => !download || file.downloadable)
+      .slice(0, context.maxResults)
+      .map((file, index) => {
+        const result = this.createResult(
+          `cloud-${file.provider}-${file.id}`,
+          file.name,
+          this.describe(file, download),
+          this.priority + 40 - index,
+          () => download ? this.download(file) : this.openUrl(file.url)
+        )
+        if (file.is_folder && this.folderIcon) {
+          result.icon = this.folderIcon
+        }
+        return result
+      })
+  }
+
+  private describe(file: CloudFile, download: boolean): string {
+    const modified = file.modified ? new Date(file.modified).toLocaleDateString() : ''
+    const size = file.size !== null ? this.formatSize(file.size) : ''
+    const hint = download ? '回车下载' : '回车在浏览器中打开'
+    return [PROVIDER_NAMES[file.provider], file.path, modified, size, hint].filter(Boolean).join(' · ')
+  }
+
+  private clientFor(provider: CloudProvider): { clientId: string, clientSecret: string | null } {
+    const values = this.settings.values
+    switch (provider) {
+      case 'google':
+        return { clientId: values.googleClientId, clientSecret: values.googleClientSecret || null }
+      case 'dropbox':
+        return { clientId: values.dropboxAppKey, clientSecret: null }
+      case 'onedrive':
+        return { clientId: values.onedriveClientId, clientSecret: null }
+    }
+  }
+
+  private createCredentialResult(provider: CloudProvider, login: boolean): SearchResultItem {
+    const name = PROVIDER_NAMES[provider]
+    return this.createResult(
+      `cloud-${login ? 'login' : 'logout'}-${provider}`,
+      login ? `登录 ${name}` : `退出 ${name}`,
+      login ? '回车在浏览器中授权，令牌保存到系统钥匙串' : '从系统钥匙串中删除保存的令牌',
+      this.priority + 50,
+      () => login ? this.login(provider) : this.logout(provider)
+    )
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'cloud-drive'
+      }
+    }
+  }
+
+  private formatSize(bytes: number): string {
+    const units = ['B', 'KB', 'MB', 'GB', 'TB']
+    let size = bytes
+    let unit = 0
+    while (size >= 1024 && unit < units.length - 1) {
+      size /= 1024
+      unit++
+    }
+    return `${unit === 0 ? size : size.toFixed(1)} ${units[unit]}`
+  }
+
+  private async login(provider: CloudProvider): Promise<void> {
+    const name = PROVIDER_NAMES[provider]
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const { clientId, clientSecret } = this.clientFor(provider)
+      const url = await invoke<string>('start_cloud_login', { provider, clientId, clientSecret })
+      await invoke('plugin:opener|open_url', { url })
+      logger.info(`请在浏览器中完成 ${name} 授权`)
+      await invoke('complete_cloud_login', { provider })
+      logger.success(`已登录 ${name}`)
+    } catch (error) {
+      const appError = handlePluginError(`登录 ${name}`, error)
+      logger.error(`登录 ${name} 失败`, appError)
+    }
+  }
+
+  private async logout(provider: CloudProvider): Promise<void> {
+    const name = PROVIDER_NAMES[provider]
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('disconnect_cloud_drive', { provider })
+      logger.success(`已退出 ${name}`)
+    } catch (error) {
+      const appError = handlePluginError(`退出 ${name}`, error)
+      logger.error(`退出 ${name} 失败`, appError)
+    }
+  }
+
+  private async download(file: CloudFile): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      logger.info(`正在下载 ${file.name}`)
+      const path = await invoke<string>('download_cloud_file', { provider: file.provider, id: file.id, name: file.name })
+      logger.success(`已下载到 ${path}`)
+    } catch (error) {
+      const appError = handlePluginError('下载云盘文件', error)
+      logger.error('下载云盘文件失败', appError)
+    }
+  }
+
+  private async openUrl(url: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_url', { url })
+    } catch (error) {
+      const appError = handlePluginError('打开云盘文件', error)
+      logger.error('打开云盘文件失败', appError)
+    }
+  }
+}
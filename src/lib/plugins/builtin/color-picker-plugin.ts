@@ -0,0 +1,148 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { pluginManager } from '../../search-plugin-manager'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface PickedColor {
+  hex: string
+  rgb: string
+  hsl: string
+  r: number
+  g: number
+  b: number
+}
+
+/**
+ * 屏幕取色插件
+ *
+ * 输入 `pick color` 或 `取色` 后回车，点击屏幕任意位置取色；
+ * 取到的颜色会复制到剪贴板，并在结果中列出 HEX / RGB / HSL 供复制
+ */
+export class ColorPickerPlugin implements SearchPlugin {
+  id = 'color-picker'
+  name = '屏幕取色'
+  description = '点击屏幕任意位置获取颜色并复制'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+  // 不使用前缀，避免 "color scheme" 这类查询只交给本插件处理
+  private readonly triggerPattern = /^(pick\s*color|color\s*picker|取色|屏幕取色)$/i
+
+  settings = {
+    schema: [
+      {
+        key: 'copyFormat',
+        label: '取色后复制的格式',
+        type: 'select' as const,
+        defaultValue: 'hex',
+        options: [
+          { label: 'HEX', value: 'hex' },
+          { label: 'RGB', value: 'rgb' },
+          { label: 'HSL', value: 'hsl' }
+        ]
+      }
+    ],
+    values: {
+      copyFormat: 'hex'
+    }
+  }
+
+  private lastPicked: PickedColor | null = null
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Pipette)
+      logger.info('屏幕取色插件初始化完成')
+    } catch (error) {
+      logger.warn('屏幕取色图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    if (!this.triggerPattern.test(context.query.trim())) {
+      return []
+    }
+
+    const results = [this.createResult('color-picker-pick', '从屏幕取色', '回车后点击屏幕任意位置，Esc 取消', this.priority + 50, () => this.pick())]
+
+    // 上一次取到的颜色，可以复制为其他格式
+    const color = this.lastPicked
+    if (color) {
+      const formats: Array<[string, string]> = [['HEX', color.hex], ['RGB', color.rgb], ['HSL', color.hsl]]
+      formats.forEach(([label, value], index) => {
+        results.push(this.createResult(
+          `color-picker-${label.toLowerCase()}`,
+          value,
+          `复制 ${label}`,
+          this.priority + 45 - index,
+          () => this.copy(value)
+        ))
+      })
+    }
+
+    return results
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'color-picker'
+      }
+    }
+  }
+
+  private async pick(): Promise<void> {
+    const { getCurrentWindow } = await import('@tauri-apps/api/window')
+    const currentWindow = getCurrentWindow()
+
+    try {
+      // 隐藏启动器，避免挡住要取色的位置
+      await currentWindow.hide()
+      const { invoke } = await import('@tauri-apps/api/core')
+      const color = await invoke<PickedColor | null>('pick_color')
+      if (!color) {
+        return
+      }
+
+      this.lastPicked = color
+      const format = this.settings.values.copyFormat as 'hex' | 'rgb' | 'hsl'
+      await this.copy(color[format] ?? color.hex)
+      pluginManager.notifyResultsChanged(this.id)
+    } catch (error) {
+      const appError = handlePluginError('屏幕取色', error)
+      logger.error('屏幕取色失败', appError)
+    } finally {
+      await currentWindow.show()
+      await currentWindow.setFocus()
+    }
+  }
+
+  private async copy(value: string): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('color-picker-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text: value })
+      logger.info(`颜色已复制到剪贴板: ${value}`)
+    } catch (error) {
+      const appError = handlePluginError('复制颜色', error)
+      logger.error('复制颜色失败', appError)
+    }
+  }
+}
@@ -0,0 +1,160 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface Contact {
+  name: string
+  organization: string | null
+  emails: string[]
+  phones: string[]
+  source: string
+}
+
+/**
+ * 联系人插件（默认关闭）
+ *
+ * 输入姓名搜索通讯录或 vCard 文件，提供发邮件、拨打电话、复制号码操作
+ */
+export class ContactsPlugin implements SearchPlugin {
+  id = 'contacts'
+  name = '联系人'
+  description = '搜索通讯录联系人，发送邮件、拨打电话或复制号码'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  // 涉及个人隐私数据，需要用户主动开启
+  enabled = false
+  priority = 60
+
+  settings = {
+    schema: [
+      {
+        key: 'includeSystem',
+        label: '读取系统通讯录',
+        description: '读取 macOS 通讯录（首次使用可能需要授予访问权限）',
+        type: 'boolean' as const,
+        defaultValue: true
+      },
+      {
+        key: 'vcardPaths',
+        label: 'vCard 文件路径',
+        description: 'vCard（.vcf）文件或目录，以逗号分隔，可用于 Windows 人脉或 CardDAV 导出',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      includeSystem: true,
+      vcardPaths: ''
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Contact)
+      logger.info('联系人插件初始化完成')
+    } catch (error) {
+      logger.warn('联系人图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    if (query.length < 2) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const contacts = await invoke<Contact[]>('search_contacts', {
+        query,
+        vcardPaths: this.settings.values.vcardPaths
+          .split(',')
+          .map(path => path.trim())
+          .filter(Boolean),
+        includeSystem: this.settings.values.includeSystem
+      })
+
+      const results: SearchResultItem[] = []
+      contacts.forEach((contact, index) => {
+        const priority = this.priority - index
+        const subtitle = contact.organization ? `${contact.organization} • ` : ''
+
+        contact.emails.forEach(email => results.push(this.createResult(
+          `contact-email-${contact.name}-${email}`,
+          contact.name,
+          `${subtitle}${email} • 回车发送邮件`,
+          priority,
+          () => this.openUrl(`mailto:${email}`)
+        )))
+
+        contact.phones.forEach(phone => {
+          results.push(this.createResult(
+            `contact-call-${contact.name}-${phone}`,
+            contact.name,
+            `${subtitle}${phone} • 回车拨打电话`,
+            priority,
+            () => this.openUrl(`tel:${phone.replace(/[^\d+]/g, '')}`)
+          ))
+          results.push(this.createResult(
+            `contact-copy-${contact.name}-${phone}`,
+            `复制 ${contact.name} 的号码`,
+            phone,
+            priority - 1,
+            () => this.copyToClipboard(phone)
+          ))
+        })
+      })
+
+      return results.slice(0, context.maxResults)
+    } catch (error) {
+      const appError = handlePluginError('搜索联系人', error)
+      logger.error('搜索联系人失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'contact'
+      }
+    }
+  }
+
+  private async openUrl(url: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_url', { url })
+    } catch (error) {
+      const appError = handlePluginError('打开联系人链接', error)
+      logger.error('打开联系人链接失败', appError)
+    }
+  }
+
+  private async copyToClipboard(text: string): Promise<void> {
+    try {
+      const hasPermission = await permissionManager.requestClipboardAccess('contacts-plugin')
+      if (!hasPermission) {
+        logger.warn('剪贴板访问权限被拒绝')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.success(`已复制号码: ${text}`)
+    } catch (error) {
+      const appError = handlePluginError('复制号码', error)
+      logger.error('复制号码失败', appError)
+    }
+  }
+}
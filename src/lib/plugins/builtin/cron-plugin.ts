@@ -0,0 +1,149 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface CronRun {
+  timestamp: number
+  local: string
+}
+
+interface CronExplanation {
+  expression: string
+  description: string
+  next_runs: CronRun[]
+}
+
+/**
+ * cron 表达式插件
+ *
+ * 用法：`cron 0 5 * * 1-5` 显示表达式的中文说明和接下来几次的运行时间（本地时间），
+ * 支持 JAN/MON 等名称和 @daily 等别名，回车复制说明或运行时间
+ */
+export class CronPlugin implements SearchPlugin {
+  id = 'cron'
+  name = 'cron 表达式'
+  description = '解释 cron 表达式并列出接下来的运行时间'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['cron ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'runCount',
+        label: '显示的运行次数',
+        description: '列出接下来多少次运行时间（1-50）',
+        type: 'number' as const,
+        defaultValue: 5
+      }
+    ],
+    values: {
+      runCount: 5
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.CalendarClock)
+      logger.info('cron 插件初始化完成')
+    } catch (error) {
+      logger.warn('cron 图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const expression = context.query.trim()
+    if (!expression) {
+      return []
+    }
+
+    let explanation: CronExplanation
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      explanation = await invoke<CronExplanation>('explain_cron', { expression, count: this.settings.values.runCount })
+    } catch (error) {
+      // 表达式无效时显示原因，便于边输入边修改
+      logger.debug('解析 cron 表达式失败', error)
+      return [this.createResult('cron-invalid', String(error), expression, this.priority + 30, async () => {})]
+    }
+
+    try {
+      const results = [this.createResult(
+        'cron-description',
+        explanation.description,
+        `${explanation.expression} · 回车复制说明`,
+        this.priority + 30,
+        () => this.copy(explanation.description)
+      )]
+
+      if (explanation.next_runs.length === 0) {
+        results.push(this.createResult('cron-never', '近几年内不会运行', '检查日期和月份是否存在，例如 2 月 30 日', this.priority + 29, async () => {}))
+      }
+      explanation.next_runs.forEach((run, index) => {
+        results.push(this.createResult(
+          `cron-run-${run.timestamp}`,
+          run.local,
+          `${index === 0 ? '下次运行' : `第 ${index + 1} 次运行`} · ${this.formatRelative(run.timestamp)}`,
+          this.priority + 29 - index,
+          () => this.copy(run.local)
+        ))
+      })
+      return results
+    } catch (error) {
+      const appError = handlePluginError('解释 cron 表达式', error)
+      logger.error('解释 cron 表达式失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'cron'
+      }
+    }
+  }
+
+  private formatRelative(timestamp: number): string {
+    const minutes = Math.max(0, Math.round((timestamp * 1000 - Date.now()) / 60000))
+    if (minutes < 60) {
+      return `${minutes} 分钟后`
+    }
+    const hours = Math.floor(minutes / 60)
+    if (hours < 48) {
+      return `${hours} 小时 ${minutes % 60} 分钟后`
+    }
+    return `${Math.floor(hours / 24)} 天后`
+  }
+
+  private async copy(text: string): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('cron-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.success(`已复制: ${text}`)
+    } catch (error) {
+      const appError = handlePluginError('复制 cron 信息', error)
+      logger.error('复制 cron 信息失败', appError)
+    }
+  }
+}
@@ -0,0 +1,128 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface TextDiff {
+  left_label: string
+  right_label: string
+  unified: string
+  added: number
+  removed: number
+}
+
+/**
+ * 文本对比插件
+ *
+ * 用法：只输入 `diff` 对比当前剪贴板与上一次复制的文本；`diff <文件1> <文件2>` 对比两个文件
+ * （路径含空格时加引号）。差异以 unified 格式显示在预览中，回车复制
+ */
+export class DiffPlugin implements SearchPlugin {
+  id = 'diff'
+  name = '文本对比'
+  description = '对比剪贴板中前后两次复制的文本或两个文件'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['diff ']
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.GitCompare)
+      logger.info('文本对比插件初始化完成')
+    } catch (error) {
+      logger.warn('文本对比图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 diff
+    if (!context.prefix && !/^(diff)$/i.test(context.query.trim())) {
+      return []
+    }
+    const paths = this.parsePaths(context.prefix ? context.query.trim() : '')
+    if (paths.length !== 0 && paths.length !== 2) {
+      return [this.createResult('diff-usage', '需要两个文件路径', 'diff <文件1> <文件2>，路径含空格时加引号', this.priority, async () => {})]
+    }
+
+    let result: TextDiff
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      result = paths.length === 2
+        ? await invoke<TextDiff>('diff_files', { left: paths[0], right: paths[1] })
+        : await invoke<TextDiff>('diff_clipboard')
+    } catch (error) {
+      // 缺少上一次复制的内容、文件不存在等情况直接显示原因
+      logger.debug('文本对比失败', error)
+      return [this.createResult('diff-error', String(error), '无法对比', this.priority, async () => {})]
+    }
+
+    try {
+      const labels = `${result.left_label} → ${result.right_label}`
+      if (!result.unified) {
+        return [this.createResult('diff-identical', '内容相同', labels, this.priority + 30, async () => {})]
+      }
+
+      const item = this.createResult(
+        'diff-result',
+        `+${result.added} 行 / -${result.removed} 行`,
+        `${labels} · 回车复制差异`,
+        this.priority + 30,
+        () => this.copy(result.unified)
+      )
+      item.preview = async () => result.unified
+      return [item]
+    } catch (error) {
+      const appError = handlePluginError('文本对比', error)
+      logger.error('文本对比失败', appError)
+      return []
+    }
+  }
+
+  // 按空白拆分路径，支持单引号或双引号包裹含空格的路径
+  private parsePaths(query: string): string[] {
+    const paths: string[] = []
+    const pattern = /"([^"]*)"|'([^']*)'|(\S+)/g
+    let match: RegExpExecArray | null
+    while ((match = pattern.exec(query)) !== null) {
+      paths.push(match[1] ?? match[2] ?? match[3])
+    }
+    return paths
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'diff'
+      }
+    }
+  }
+
+  private async copy(text: string): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('diff-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.success('已复制差异')
+    } catch (error) {
+      const appError = handlePluginError('复制差异', error)
+      logger.error('复制差异失败', appError)
+    }
+  }
+}
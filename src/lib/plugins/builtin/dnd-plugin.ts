@@ -0,0 +1,97 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+/**
+ * 勿扰模式插件
+ *
+ * 输入 `dnd` 切换勿扰模式，`dnd on` / `dnd off` 明确开启或关闭，结果中显示当前状态
+ */
+export class DndPlugin implements SearchPlugin {
+  id = 'dnd'
+  name = '勿扰模式'
+  description = '切换系统的勿扰 / 专注模式'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+  // 不使用前缀，避免 "focus" 开头的其他查询只交给本插件处理
+  private readonly triggerPattern = /^(dnd|do not disturb|focus|勿扰|勿扰模式)(?:\s+(on|off))?$/i
+
+  settings = {
+    schema: [
+      {
+        key: 'macShortcut',
+        label: 'macOS 快捷指令名称',
+        description: 'macOS 没有切换专注模式的命令，需要在"快捷指令"中创建一个切换勿扰模式的快捷指令',
+        type: 'string' as const,
+        defaultValue: 'Toggle Do Not Disturb'
+      }
+    ],
+    values: {
+      macShortcut: 'Toggle Do Not Disturb'
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.BellOff)
+      logger.info('勿扰模式插件初始化完成')
+    } catch (error) {
+      logger.warn('勿扰模式图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const match = context.query.trim().match(this.triggerPattern)
+    if (!match) {
+      return []
+    }
+
+    const enabled = match[2] ? match[2].toLowerCase() === 'on' : null
+    let state: boolean | null = null
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      state = await invoke<boolean | null>('get_dnd_state')
+    } catch (error) {
+      logger.warn('读取勿扰模式状态失败', error)
+    }
+
+    const target = enabled ?? (state === null ? null : !state)
+    const title = target === null ? '切换勿扰模式' : target ? '开启勿扰模式' : '关闭勿扰模式'
+
+    return [{
+      id: 'dnd-toggle',
+      title,
+      description: `当前：${state === null ? '状态未知' : state ? '已开启' : '已关闭'}`,
+      icon: this.icon,
+      priority: this.priority + 50,
+      action: () => this.setDnd(enabled),
+      source: this.id,
+      metadata: {
+        type: 'dnd'
+      }
+    }]
+  }
+
+  private async setDnd(enabled: boolean | null): Promise<void> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    try {
+      const state = await invoke<boolean | null>('set_dnd', {
+        enabled,
+        macShortcut: this.settings.values.macShortcut || null
+      })
+      logger.info(`勿扰模式${state ? '已开启' : '已关闭'}`)
+    } catch (error) {
+      const appError = handlePluginError('切换勿扰模式', error)
+      logger.error('切换勿扰模式失败', appError)
+
+      // Windows 无法直接切换专注助手，打开对应的设置页
+      if (navigator.userAgent.includes('Windows')) {
+        await invoke('plugin:opener|open_url', { url: 'ms-settings:quiethours' }).catch(() => {})
+      }
+    }
+  }
+}
@@ -0,0 +1,120 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface DocEntry {
+  docset: string
+  docset_path: string
+  name: string
+  kind: string
+  path: string
+  target: string
+}
+
+/**
+ * 离线文档插件
+ *
+ * 用法：`doc <名称>` 在本机下载的 Dash / Zeal docset 和 DevDocs 文档中查找，预览区显示文档内容；
+ * 第一个词是文档名时只在该文档中查找，例如 `doc rust Vec::retain`。回车打开文档页面
+ */
+export class DocsPlugin implements SearchPlugin {
+  id = 'docs'
+  name = '离线文档'
+  description = '在本机的 Dash / Zeal / DevDocs 文档中查找 API'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 75
+  searchPrefixes = ['doc ', 'docs ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'roots',
+        label: '文档目录',
+        description: 'Zeal 和 Dash 的默认目录总会扫描；DevDocs 文档（含 index.json 和 db.json 的目录）需在此添加，多个目录用 ; 分隔',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      roots: ''
+    },
+    onChange: (key: string) => {
+      if (key === 'roots') {
+        this.syncRoots()
+      }
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.LibraryBig)
+      logger.info('离线文档插件初始化完成')
+    } catch (error) {
+      logger.warn('离线文档图标加载失败，使用默认图标', error)
+    }
+    await this.syncRoots()
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    if (!query) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const entries = await invoke<DocEntry[]>('search_docs', { query, limit: context.maxResults })
+
+      return entries.map((entry, index) => ({
+        id: `docs-${entry.docset_path}-${entry.path}`,
+        title: entry.name,
+        description: [entry.docset, entry.kind, '回车打开文档'].filter(Boolean).join(' · '),
+        icon: this.icon,
+        priority: this.priority + 40 - index,
+        action: () => this.open(entry),
+        preview: () => invoke<string>('render_doc_entry', { docsetPath: entry.docset_path, path: entry.path }),
+        source: this.id,
+        metadata: {
+          type: 'doc',
+          docset: entry.docset
+        }
+      }))
+    } catch (error) {
+      const appError = handlePluginError('查找文档', error)
+      logger.error('查找文档失败', appError)
+      return []
+    }
+  }
+
+  private async syncRoots(): Promise<void> {
+    try {
+      const roots = String(this.settings.values.roots).split(';').map(root => root.trim()).filter(Boolean)
+      const { invoke } = await import('@tauri-apps/api/core')
+      const count = await invoke<number>('set_doc_roots', { roots })
+      logger.info(`已加载 ${count} 个离线文档`)
+    } catch (error) {
+      const appError = handlePluginError('加载离线文档', error)
+      logger.error('加载离线文档失败', appError)
+    }
+  }
+
+  // DevDocs 文档打开 devdocs.io 上的页面，docset 打开本地 HTML 文件
+  private async open(entry: DocEntry): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      if (/^https?:\/\//.test(entry.target)) {
+        await invoke('plugin:opener|open_url', { url: entry.target })
+      } else {
+        await invoke('plugin:opener|open_path', { path: entry.target })
+      }
+    } catch (error) {
+      const appError = handlePluginError('打开文档', error)
+      logger.error('打开文档失败', appError)
+    }
+  }
+}
@@ -0,0 +1,117 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface RemovableDrive {
+  name: string
+  mount_point: string
+  used: number
+  total: number
+  removable: boolean
+}
+
+/**
+ * 弹出磁盘插件
+ *
+ * 用法：`eject` 列出已挂载的可移动磁盘，回车安全弹出；`eject <名称>` 过滤
+ */
+export class DrivesPlugin implements SearchPlugin {
+  id = 'drives'
+  name = '弹出磁盘'
+  description = '安全弹出 U 盘等可移动磁盘'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['eject ', 'usb ']
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.HardDrive)
+      logger.info('弹出磁盘插件初始化完成')
+    } catch (error) {
+      logger.warn('弹出磁盘图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 eject / usb
+    if (!context.prefix && !/^(eject|usb)$/i.test(context.query.trim())) {
+      return []
+    }
+    const query = context.prefix ? context.query.trim().toLowerCase() : ''
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const drives = await invoke<RemovableDrive[]>('list_removable_drives')
+
+      if (drives.length === 0) {
+        return [{
+          id: 'drives-none',
+          title: '没有可弹出的磁盘',
+          description: '未检测到已挂载的可移动磁盘',
+          icon: this.icon,
+          priority: this.priority,
+          action: () => {},
+          source: this.id,
+          metadata: {
+            type: 'drive'
+          }
+        }]
+      }
+
+      return drives
+        .filter(drive => !query || this.label(drive).toLowerCase().includes(query) || drive.mount_point.toLowerCase().includes(query))
+        .slice(0, context.maxResults)
+        .map((drive, index) => ({
+          id: `drive-${drive.mount_point}`,
+          title: `弹出 ${this.label(drive)}`,
+          description: `${drive.mount_point} · 已用 ${this.formatBytes(drive.used)} / ${this.formatBytes(drive.total)}`,
+          icon: this.icon,
+          priority: this.priority + 50 - index,
+          action: () => this.eject(drive),
+          source: this.id,
+          metadata: {
+            type: 'drive',
+            mountPoint: drive.mount_point
+          }
+        }))
+    } catch (error) {
+      const appError = handlePluginError('获取可移动磁盘', error)
+      logger.error('获取可移动磁盘失败', appError)
+      return []
+    }
+  }
+
+  // Linux 上 name 是设备路径，用挂载目录名更直观
+  private label(drive: RemovableDrive): string {
+    if (drive.name && !drive.name.startsWith('/dev/')) {
+      return drive.name
+    }
+    return drive.mount_point.split(/[\\/]/).filter(Boolean).pop() || drive.mount_point
+  }
+
+  private async eject(drive: RemovableDrive): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('eject_drive', { mountPoint: drive.mount_point })
+      logger.success(`已弹出 ${this.label(drive)}，可以安全拔出`)
+    } catch (error) {
+      const appError = handlePluginError('弹出磁盘', error)
+      logger.error('弹出磁盘失败', appError)
+    }
+  }
+
+  private formatBytes(bytes: number): string {
+    const units = ['B', 'KB', 'MB', 'GB', 'TB']
+    let value = bytes
+    let unit = 0
+    while (value >= 1024 && unit < units.length - 1) {
+      value /= 1024
+      unit++
+    }
+    return `${value.toFixed(unit >= 3 ? 1 : 0)} ${units[unit]}`
+  }
+}
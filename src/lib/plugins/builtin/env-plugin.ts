@@ -0,0 +1,104 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface EnvVar {
+  name: string
+  value: string
+  masked: boolean
+}
+
+/**
+ * 环境变量插件
+ *
+ * 用法：`env <名称>` 搜索环境变量并显示值，回车复制；名称像密钥的变量值会打码显示
+ */
+export class EnvPlugin implements SearchPlugin {
+  id = 'env'
+  name = '环境变量'
+  description = '查看和复制环境变量'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 75
+  searchPrefixes = ['env ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'secretPatterns',
+        label: '额外的敏感名称',
+        description: '逗号分隔，名称包含其中任意一项的变量值会打码显示（已内置 TOKEN、SECRET、PASSWORD、KEY 等）',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      secretPatterns: ''
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Variable)
+      logger.info('环境变量插件初始化完成')
+    } catch (error) {
+      logger.warn('环境变量图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const vars = await invoke<EnvVar[]>('search_env_vars', {
+        query: context.query.trim(),
+        secretPatterns: this.settings.values.secretPatterns
+          .split(',')
+          .map((pattern: string) => pattern.trim())
+          .filter(Boolean)
+      })
+
+      return vars.slice(0, context.maxResults).map((envVar, index) => ({
+        id: `env-${envVar.name}`,
+        title: envVar.name,
+        description: envVar.masked ? `${envVar.value}（已隐藏，回车复制原值）` : envVar.value || '（空）',
+        icon: this.icon,
+        priority: this.priority + 40 - index,
+        action: () => this.copy(envVar),
+        source: this.id,
+        metadata: {
+          type: 'env',
+          masked: envVar.masked
+        }
+      }))
+    } catch (error) {
+      const appError = handlePluginError('搜索环境变量', error)
+      logger.error('搜索环境变量失败', appError)
+      return []
+    }
+  }
+
+  private async copy(envVar: EnvVar): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('env-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      // 打码的值只在复制时读取原值
+      const text = envVar.masked ? await invoke<string>('get_env_var', { name: envVar.name }) : envVar.value
+      await invoke('copy_to_clipboard', { text })
+      logger.success(`已复制 ${envVar.name} 的值`)
+    } catch (error) {
+      const appError = handlePluginError('复制环境变量', error)
+      logger.error('复制环境变量失败', appError)
+    }
+  }
+}
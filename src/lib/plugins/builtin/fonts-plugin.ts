@@ -0,0 +1,113 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface FontFamily {
+  family: string
+  styles: string[]
+  monospaced: boolean
+  path: string
+  index: number
+}
+
+/**
+ * 字体插件
+ *
+ * 用法：`font <字族名>` 搜索已安装的字体，预览区显示示例文字的渲染效果，回车复制字体名称；
+ * `font <字族名> | <文字>` 用自定义文字预览。默认示例文字可在设置中修改
+ */
+export class FontsPlugin implements SearchPlugin {
+  id = 'fonts'
+  name = '字体'
+  description = '搜索已安装的字体，预览示例文字并复制字体名称'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 70
+  searchPrefixes = ['font ', 'fonts ', '字体 ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'sampleText',
+        label: '示例文字',
+        description: '预览使用的文字，留空使用默认的中英文示例，可用 \\n 换行',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      sampleText: ''
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Type)
+      logger.info('字体插件初始化完成')
+    } catch (error) {
+      logger.warn('字体图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const [keyword, ...sample] = context.query.split('|')
+    const query = keyword.trim()
+    if (!query) {
+      return []
+    }
+    const text = sample.join('|').trim() || this.settings.values.sampleText.replace(/\\n/g, '\n') || null
+
+    let fonts: FontFamily[]
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      fonts = await invoke<FontFamily[]>('search_fonts', { query, limit: context.maxResults })
+    } catch (error) {
+      const appError = handlePluginError('搜索字体', error)
+      logger.error('搜索字体失败', appError)
+      return []
+    }
+
+    return fonts.map((font, index) => ({
+      id: `font-${font.family}`,
+      title: font.family,
+      description: this.describe(font),
+      icon: this.icon,
+      priority: this.priority + 40 - Math.min(index, 20),
+      action: () => this.copyName(font.family),
+      preview: () => this.renderPreview(font.family, text),
+      source: this.id,
+      metadata: {
+        type: 'font',
+        path: font.path
+      }
+    }))
+  }
+
+  private describe(font: FontFamily): string {
+    const styles = font.styles.length > 4
+      ? `${font.styles.slice(0, 4).join('、')} 等 ${font.styles.length} 种样式`
+      : font.styles.join('、')
+    return [styles, font.monospaced ? '等宽' : '', '回车复制字体名称'].filter(Boolean).join(' · ')
+  }
+
+  // 返回 PNG data URL，预览区显示为图片
+  private async renderPreview(family: string, text: string | null): Promise<string> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    return invoke<string>('render_font_preview', { family, text })
+  }
+
+  private async copyName(family: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text: family })
+      logger.success(`已复制字体名称: ${family}`)
+    } catch (error) {
+      const appError = handlePluginError('复制字体名称', error)
+      logger.error('复制字体名称失败', appError)
+    }
+  }
+}
@@ -0,0 +1,181 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+type RepoAction = 'editor' | 'terminal' | 'url'
+
+interface GitRepo {
+  name: string
+  path: string
+  branch: string | null
+  dirty: boolean | null
+}
+
+const ACTION_LABELS: Record<RepoAction, string> = {
+  editor: '回车在编辑器中打开',
+  terminal: '回车在终端中打开',
+  url: '回车复制远程仓库地址'
+}
+
+/**
+ * Git 仓库插件
+ *
+ * 用法：`git <名称>` 搜索扫描目录中的仓库，回车在编辑器中打开；
+ * `git term <名称>` 在终端中打开，`git url <名称>` 复制远程仓库地址
+ */
+export class GitReposPlugin implements SearchPlugin {
+  id = 'git-repos'
+  name = 'Git 仓库'
+  description = '查找本地 Git 仓库，在编辑器或终端中打开'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 75
+  searchPrefixes = ['git ', 'repo ']
+
+  settings = {
+    schema: [
+      {
+        key: 'roots',
+        label: '扫描目录',
+        description: '查找仓库的目录，多个目录用 ; 分隔',
+        type: 'string' as const,
+        defaultValue: '~/code;~/projects;~/src;~/workspace'
+      },
+      {
+        key: 'maxDepth',
+        label: '扫描深度',
+        description: '在扫描目录下最多查找几层子目录',
+        type: 'number' as const,
+        defaultValue: 4
+      },
+      {
+        key: 'editorCommand',
+        label: '编辑器命令',
+        description: '{path} 会被替换为仓库路径，没有占位符时追加到末尾',
+        type: 'string' as const,
+        defaultValue: 'code'
+      },
+      {
+        key: 'terminalCommand',
+        label: '终端命令',
+        description: '在仓库目录中打开的终端；留空使用系统默认终端',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      roots: '~/code;~/projects;~/src;~/workspace',
+      maxDepth: 4,
+      editorCommand: 'code',
+      terminalCommand: ''
+    },
+    onChange: (key: string) => {
+      if (key === 'roots' || key === 'maxDepth') {
+        this.syncRoots()
+      }
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.FolderGit2)
+      logger.info('Git 仓库插件初始化完成')
+    } catch (error) {
+      logger.warn('Git 仓库图标加载失败，使用默认图标', error)
+    }
+    await this.syncRoots()
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    const [command, ...rest] = query.split(/\s+/)
+    const action: RepoAction = /^(term|terminal)$/i.test(command) ? 'terminal' : /^url$/i.test(command) ? 'url' : 'editor'
+    const name = action === 'editor' ? query : rest.join(' ')
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const repos = await invoke<GitRepo[]>('search_git_repos', { query: name, limit: context.maxResults })
+
+      return repos.map((repo, index) => ({
+        id: `git-repo-${repo.path}`,
+        title: repo.name,
+        description: [this.describeState(repo), repo.path, ACTION_LABELS[action]].filter(Boolean).join(' · '),
+        icon: this.icon,
+        priority: this.priority + 40 - index,
+        action: () => this.runAction(repo, action),
+        source: this.id,
+        metadata: {
+          type: 'git-repo',
+          path: repo.path
+        }
+      }))
+    } catch (error) {
+      const appError = handlePluginError('搜索 Git 仓库', error)
+      logger.error('搜索 Git 仓库失败', appError)
+      return []
+    }
+  }
+
+  private describeState(repo: GitRepo): string {
+    const branch = repo.branch ?? ''
+    if (repo.dirty === null) {
+      return branch
+    }
+    return `${branch}${repo.dirty ? ' · 有未提交的改动' : ' · 无改动'}`
+  }
+
+  private async syncRoots(): Promise<void> {
+    try {
+      const roots = String(this.settings.values.roots).split(';').map(root => root.trim()).filter(Boolean)
+      const { invoke } = await import('@tauri-apps/api/core')
+      const count = await invoke<number>('set_git_repo_roots', {
+        roots,
+        maxDepth: Number(this.settings.values.maxDepth) || null
+      })
+      logger.info(`已找到 ${count} 个 Git 仓库`)
+    } catch (error) {
+      const appError = handlePluginError('扫描 Git 仓库', error)
+      logger.error('扫描 Git 仓库失败', appError)
+    }
+  }
+
+  private async runAction(repo: GitRepo, action: RepoAction): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      if (action === 'editor') {
+        await invoke('open_git_repo_in_editor', {
+          path: repo.path,
+          editorCommand: this.settings.values.editorCommand || null
+        })
+      } else if (action === 'terminal') {
+        await invoke('open_git_repo_in_terminal', {
+          path: repo.path,
+          terminalCommand: this.settings.values.terminalCommand || null
+        })
+      } else {
+        await this.copyRemoteUrl(repo)
+      }
+    } catch (error) {
+      const appError = handlePluginError('打开 Git 仓库', error)
+      logger.error('打开 Git 仓库失败', appError)
+    }
+  }
+
+  private async copyRemoteUrl(repo: GitRepo): Promise<void> {
+    // 请求剪贴板访问权限
+    const hasPermission = await permissionManager.requestClipboardAccess('git-repos-plugin')
+    if (!hasPermission) {
+      logger.warn('用户拒绝了剪贴板访问权限')
+      return
+    }
+
+    const { invoke } = await import('@tauri-apps/api/core')
+    const text = await invoke<string>('get_git_remote_url', { path: repo.path })
+    await invoke('copy_to_clipboard', { text })
+    logger.success(`已复制 ${repo.name} 的远程仓库地址`)
+  }
+}
@@ -0,0 +1,207 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface GitHubItem {
+  kind: 'repo' | 'issue' | 'pull'
+  title: string
+  repo: string
+  number: number | null
+  url: string
+  state: string | null
+  description: string | null
+  clone_url: string | null
+  ssh_url: string | null
+  updated_at: string
+}
+
+const KEYRING_ACCOUNT = 'github'
+
+const KIND_LABELS: Record<GitHubItem['kind'], string> = {
+  repo: '仓库',
+  issue: 'Issue',
+  pull: 'PR'
+}
+
+/**
+ * GitHub 插件
+ *
+ * 用法：`gh login` 输入访问令牌并保存到系统钥匙串，`gh logout` 删除；
+ * `gh <关键词>` 搜索自己的仓库和相关的 Issue、PR，回车在浏览器中打开；`gh clone <关键词>` 回车复制仓库的克隆地址
+ */
+export class GitHubPlugin implements SearchPlugin {
+  id = 'github'
+  name = 'GitHub'
+  description = '搜索自己的 GitHub 仓库、Issue 和 Pull Request'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 70
+  searchPrefixes = ['gh ', 'github ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'cloneProtocol',
+        label: '克隆地址协议',
+        type: 'select' as const,
+        defaultValue: 'https',
+        options: [
+          { label: 'HTTPS', value: 'https' },
+          { label: 'SSH', value: 'ssh' }
+        ]
+      }
+    ],
+    values: {
+      cloneProtocol: 'https'
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Github)
+      logger.info('GitHub 插件初始化完成')
+    } catch (error) {
+      logger.warn('GitHub 图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    const [command, ...rest] = query.split(/\s+/)
+    const argument = rest.join(' ')
+
+    if (/^login$/i.test(command)) {
+      return [this.createLoginResult()]
+    }
+    if (/^logout$/i.test(command)) {
+      return [this.createLogoutResult()]
+    }
+
+    const clone = /^clone$/i.test(command)
+    const keyword = clone ? argument : query
+    if (!keyword) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const items = await invoke<GitHubItem[]>('search_github', { query: keyword })
+
+      return items
+        .filter(item => !clone || item.kind === 'repo')
+        .slice(0, context.maxResults)
+        .map((item, index) => ({
+          id: `github-${item.url}`,
+          title: item.number ? `#${item.number} ${item.title}` : item.title,
+          description: this.describe(item, clone),
+          icon: this.icon,
+          priority: this.priority + 40 - index,
+          action: () => clone ? this.copyCloneUrl(item) : this.openUrl(item.url),
+          source: this.id,
+          metadata: {
+            type: 'github',
+            kind: item.kind
+          }
+        }))
+    } catch (error) {
+      const appError = handlePluginError('搜索 GitHub', error)
+      logger.error('搜索 GitHub 失败', appError)
+      return []
+    }
+  }
+
+  private describe(item: GitHubItem, clone: boolean): string {
+    if (item.kind === 'repo') {
+      return [KIND_LABELS.repo, item.description, clone ? '回车复制克隆地址' : '回车在浏览器中打开'].filter(Boolean).join(' · ')
+    }
+    return [KIND_LABELS[item.kind], item.repo, item.state === 'open' ? '打开' : '已关闭', item.updated_at.slice(0, 10)].join(' · ')
+  }
+
+  // 令牌不能放在搜索词中，否则会被记入搜索统计
+  private createLoginResult(): SearchResultItem {
+    return {
+      id: 'github-login',
+      title: '保存 GitHub 访问令牌',
+      description: '回车输入令牌（需要 repo 权限），保存到系统钥匙串',
+      icon: this.icon,
+      priority: this.priority + 50,
+      action: async () => {
+        const token = prompt('请输入 GitHub 访问令牌')?.trim()
+        if (!token) {
+          return
+        }
+
+        try {
+          const { invoke } = await import('@tauri-apps/api/core')
+          await invoke('save_credential', { account: KEYRING_ACCOUNT, secret: token })
+          logger.success('已保存 GitHub 访问令牌')
+        } catch (error) {
+          const appError = handlePluginError('保存 GitHub 访问令牌', error)
+          logger.error('保存 GitHub 访问令牌失败', appError)
+        }
+      },
+      source: this.id,
+      metadata: {
+        type: 'github-login'
+      }
+    }
+  }
+
+  private createLogoutResult(): SearchResultItem {
+    return {
+      id: 'github-logout',
+      title: '删除 GitHub 访问令牌',
+      description: '从系统钥匙串中删除保存的令牌',
+      icon: this.icon,
+      priority: this.priority + 50,
+      action: async () => {
+        try {
+          const { invoke } = await import('@tauri-apps/api/core')
+          await invoke('delete_credential', { account: KEYRING_ACCOUNT })
+          logger.success('已删除 GitHub 访问令牌')
+        } catch (error) {
+          const appError = handlePluginError('删除 GitHub 访问令牌', error)
+          logger.error('删除 GitHub 访问令牌失败', appError)
+        }
+      },
+      source: this.id,
+      metadata: {
+        type: 'github-logout'
+      }
+    }
+  }
+
+  private async openUrl(url: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_url', { url })
+    } catch (error) {
+      const appError = handlePluginError('打开 GitHub 页面', error)
+      logger.error('打开 GitHub 页面失败', appError)
+    }
+  }
+
+  private async copyCloneUrl(item: GitHubItem): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('github-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const text = this.settings.values.cloneProtocol === 'ssh' ? item.ssh_url : item.clone_url
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.success(`已复制 ${item.repo} 的克隆地址`)
+    } catch (error) {
+      const appError = handlePluginError('复制克隆地址', error)
+      logger.error('复制克隆地址失败', appError)
+    }
+  }
+}
@@ -12,11 +12,129 @@ import { handlePluginError } from '../../error-handler'
 import { AppsSearchPlugin } from './apps-plugin'
 import { CalculatorPlugin, UnitConverterPlugin } from './calculator-plugin'
 import { FileSearchPlugin } from './file-plugin'
+import { PasswordGeneratorPlugin } from './password-plugin'
+import { LoremIpsumPlugin } from './lorem-plugin'
+import { TimestampPlugin } from './timestamp-plugin'
+import { WorldClockPlugin } from './world-clock-plugin'
+import { TimerPlugin } from './timer-plugin'
+import { ReminderPlugin } from './reminder-plugin'
+import { CalendarPlugin } from './calendar-plugin'
+import { ContactsPlugin } from './contacts-plugin'
+import { NotesPlugin } from './notes-plugin'
+import { TodoPlugin } from './todo-plugin'
+import { PowerPlugin } from './power-plugin'
+import { ProcessPlugin } from './process-plugin'
+import { SystemMonitorPlugin } from './system-monitor-plugin'
+import { WifiPlugin } from './wifi-plugin'
+import { BluetoothPlugin } from './bluetooth-plugin'
+import { AudioDevicePlugin } from './audio-device-plugin'
+import { VolumeBrightnessPlugin } from './volume-brightness-plugin'
+import { MediaPlugin } from './media-plugin'
+import { ColorPickerPlugin } from './color-picker-plugin'
+import { OcrPlugin } from './ocr-plugin'
+import { WorkspacePlugin } from './workspace-plugin'
+import { DndPlugin } from './dnd-plugin'
+import { AppearancePlugin } from './appearance-plugin'
+import { TrashPlugin } from './trash-plugin'
+import { DrivesPlugin } from './drives-plugin'
+import { SshPlugin } from './ssh-plugin'
+import { ShellPlugin } from './shell-plugin'
+import { ShellHistoryPlugin } from './shell-history-plugin'
+import { EnvPlugin } from './env-plugin'
+import { ManPlugin } from './man-plugin'
+import { PackagesPlugin } from './packages-plugin'
+import { KubernetesPlugin } from './kubernetes-plugin'
+import { GitReposPlugin } from './git-repos-plugin'
+import { VsCodePlugin } from './vscode-plugin'
+import { JetBrainsPlugin } from './jetbrains-plugin'
+import { GitHubPlugin } from './github-plugin'
+import { IssueTrackerPlugin } from './issue-tracker-plugin'
+import { RegistryPlugin } from './registry-plugin'
+import { StackOverflowPlugin } from './stackoverflow-plugin'
+import { DocsPlugin } from './docs-plugin'
+import { QuicklinksPlugin } from './quicklinks-plugin'
+import { OpenTargetPlugin } from './open-target-plugin'
+import { NetworkToolsPlugin } from './network-tools-plugin'
+import { PortPlugin } from './port-plugin'
+import { WeatherPlugin } from './weather-plugin'
+import { RssPlugin } from './rss-plugin'
+import { MailPlugin } from './mail-plugin'
+import { VaultPlugin } from './vault-plugin'
+import { BatteryPlugin } from './battery-plugin'
+import { TextTransformPlugin } from './text-transform-plugin'
+import { JsonPlugin } from './json-plugin'
+import { RegexPlugin } from './regex-plugin'
+import { CronPlugin } from './cron-plugin'
+import { NumberBasePlugin } from './number-base-plugin'
+import { DiffPlugin } from './diff-plugin'
+import { ClipboardImagePlugin } from './clipboard-image-plugin'
+import { NetworkSharePlugin } from './network-share-plugin'
+import { CloudDrivePlugin } from './cloud-drive-plugin'
+import { FontsPlugin } from './fonts-plugin'
 
 // Re-export the plugins
 export { AppsSearchPlugin } from './apps-plugin'
 export { CalculatorPlugin, UnitConverterPlugin } from './calculator-plugin'
 export { FileSearchPlugin } from './file-plugin'
+export { PasswordGeneratorPlugin } from './password-plugin'
+export { LoremIpsumPlugin } from './lorem-plugin'
+export { TimestampPlugin } from './timestamp-plugin'
+export { WorldClockPlugin } from './world-clock-plugin'
+export { TimerPlugin } from './timer-plugin'
+export { ReminderPlugin } from './reminder-plugin'
+export { CalendarPlugin } from './calendar-plugin'
+export { ContactsPlugin } from './contacts-plugin'
+export { NotesPlugin } from './notes-plugin'
+export { TodoPlugin } from './todo-plugin'
+export { PowerPlugin } from './power-plugin'
+export { ProcessPlugin } from './process-plugin'
+export { SystemMonitorPlugin } from './system-monitor-plugin'
+export { WifiPlugin } from './wifi-plugin'
+export { BluetoothPlugin } from './bluetooth-plugin'
+export { AudioDevicePlugin } from './audio-device-plugin'
+export { VolumeBrightnessPlugin } from './volume-brightness-plugin'
+export { MediaPlugin } from './media-plugin'
+export { ColorPickerPlugin } from './color-picker-plugin'
+export { OcrPlugin } from './ocr-plugin'
+export { WorkspacePlugin } from './workspace-plugin'
+export { DndPlugin } from './dnd-plugin'
+export { AppearancePlugin } from './appearance-plugin'
+export { TrashPlugin } from './trash-plugin'
+export { DrivesPlugin } from './drives-plugin'
+export { SshPlugin } from './ssh-plugin'
+export { ShellPlugin } from './shell-plugin'
+export { ShellHistoryPlugin } from './shell-history-plugin'
+export { EnvPlugin } from './env-plugin'
+export { ManPlugin } from './man-plugin'
+export { PackagesPlugin } from './packages-plugin'
+export { KubernetesPlugin } from './kubernetes-plugin'
+export { GitReposPlugin } from './git-repos-plugin'
+export { VsCodePlugin } from './vscode-plugin'
+export { JetBrainsPlugin } from './jetbrains-plugin'
+export { GitHubPlugin } from './github-plugin'
+export { IssueTrackerPlugin } from './issue-tracker-plugin'
+export { RegistryPlugin } from './registry-plugin'
+export { StackOverflowPlugin } from './stackoverflow-plugin'
+export { DocsPlugin } from './docs-plugin'
+export { QuicklinksPlugin } from './quicklinks-plugin'
+export { OpenTargetPlugin } from './open-target-plugin'
+export { NetworkToolsPlugin } from './network-tools-plugin'
+export { PortPlugin } from './port-plugin'
+export { WeatherPlugin } from './weather-plugin'
+export { RssPlugin } from './rss-plugin'
+export { MailPlugin } from './mail-plugin'
+export { VaultPlugin } from './vault-plugin'
+export { BatteryPlugin } from './battery-plugin'
+export { TextTransformPlugin } from './text-transform-plugin'
+export { JsonPlugin } from './json-plugin'
+export { RegexPlugin } from './regex-plugin'
+export { CronPlugin } from './cron-plugin'
+export { NumberBasePlugin } from './number-base-plugin'
+export { DiffPlugin } from './diff-plugin'
+export { ClipboardImagePlugin } from './clipboard-image-plugin'
+export { NetworkSharePlugin } from './network-share-plugin'
+export { CloudDrivePlugin } from './cloud-drive-plugin'
+export { FontsPlugin } from './fonts-plugin'
 
 // Future built-in plugins can be added here:
 // export { WebSearchPlugin } from './web-plugin'
@@ -35,6 +153,65 @@ export const BUILTIN_PLUGINS = {
   calculator: CalculatorPlugin,
   units: UnitConverterPlugin,
   files: FileSearchPlugin,
+  password: PasswordGeneratorPlugin,
+  lorem: LoremIpsumPlugin,
+  timestamp: TimestampPlugin,
+  'world-clock': WorldClockPlugin,
+  timer: TimerPlugin,
+  reminder: ReminderPlugin,
+  calendar: CalendarPlugin,
+  contacts: ContactsPlugin,
+  notes: NotesPlugin,
+  todo: TodoPlugin,
+  power: PowerPlugin,
+  process: ProcessPlugin,
+  'system-monitor': SystemMonitorPlugin,
+  wifi: WifiPlugin,
+  bluetooth: BluetoothPlugin,
+  'audio-device': AudioDevicePlugin,
+  'volume-brightness': VolumeBrightnessPlugin,
+  media: MediaPlugin,
+  'color-picker': ColorPickerPlugin,
+  ocr: OcrPlugin,
+  workspace: WorkspacePlugin,
+  dnd: DndPlugin,
+  appearance: AppearancePlugin,
+  trash: TrashPlugin,
+  drives: DrivesPlugin,
+  ssh: SshPlugin,
+  shell: ShellPlugin,
+  'shell-history': ShellHistoryPlugin,
+  env: EnvPlugin,
+  man: ManPlugin,
+  packages: PackagesPlugin,
+  kubernetes: KubernetesPlugin,
+  'git-repos': GitReposPlugin,
+  vscode: VsCodePlugin,
+  jetbrains: JetBrainsPlugin,
+  github: GitHubPlugin,
+  'issue-tracker': IssueTrackerPlugin,
+  registry: RegistryPlugin,
+  stackoverflow: StackOverflowPlugin,
+  docs: DocsPlugin,
+  quicklinks: QuicklinksPlugin,
+  'open-target': OpenTargetPlugin,
+  'network-tools': NetworkToolsPlugin,
+  port: PortPlugin,
+  weather: WeatherPlugin,
+  rss: RssPlugin,
+  mail: MailPlugin,
+  vault: VaultPlugin,
+  battery: BatteryPlugin,
+  'text-transform': TextTransformPlugin,
+  json: JsonPlugin,
+  regex: RegexPlugin,
+  cron: CronPlugin,
+  'number-base': NumberBasePlugin,
+  diff: DiffPlugin,
+  'clipboard-image': ClipboardImagePlugin,
+  'network-shares': NetworkSharePlugin,
+  'cloud-drive': CloudDrivePlugin,
+  fonts: FontsPlugin,
   
   // Future plugins can be added here:
   // web: WebSearchPlugin,
@@ -90,6 +267,596 @@ export const BUILTIN_PLUGIN_METADATA = {
     enabled: true,
     icon: 'FilesIcon',
   },
+  password: {
+    id: 'password',
+    name: 'Password Generator',
+    description: 'Generate random passwords and diceware passphrases',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'KeyRoundIcon',
+  },
+  lorem: {
+    id: 'lorem',
+    name: 'Lorem Ipsum',
+    description: 'Generate placeholder words, sentences and paragraphs',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'TypeIcon',
+  },
+  timestamp: {
+    id: 'timestamp',
+    name: 'Timestamp Converter',
+    description: 'Convert between Unix timestamps and human-readable dates',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'ClockIcon',
+  },
+  'world-clock': {
+    id: 'world-clock',
+    name: 'World Clock',
+    description: 'Look up the current time in cities around the world',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'GlobeIcon',
+  },
+  timer: {
+    id: 'timer',
+    name: 'Timers',
+    description: 'Countdown timers and pomodoro sessions with native notifications',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'TimerIcon',
+  },
+  reminder: {
+    id: 'reminder',
+    name: 'Reminders',
+    description: 'Natural-language reminders delivered as system notifications',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'BellIcon',
+  },
+  calendar: {
+    id: 'calendar',
+    name: 'Calendar',
+    description: 'Upcoming calendar events from ICS files with join-meeting links',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'CalendarDaysIcon',
+  },
+  contacts: {
+    id: 'contacts',
+    name: 'Contacts',
+    description: 'Search the address book or vCard exports to email, call or copy numbers',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: false,
+    icon: 'ContactIcon',
+  },
+  notes: {
+    id: 'notes',
+    name: 'Notes',
+    description: 'Search Obsidian vaults and markdown folders',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'NotebookPenIcon',
+  },
+  todo: {
+    id: 'todo',
+    name: 'Todo',
+    description: 'Lightweight todo list with due-date reminders',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'ListTodoIcon',
+  },
+  power: {
+    id: 'power',
+    name: 'Power',
+    description: 'Lock, sleep, restart, shut down, log out and hibernate',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'PowerIcon',
+  },
+  process: {
+    id: 'process',
+    name: 'Processes',
+    description: 'Search running processes and terminate or force-kill them',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'ActivityIcon',
+  },
+  'system-monitor': {
+    id: 'system-monitor',
+    name: 'System Monitor',
+    description: 'Live CPU, memory, disk and per-core load',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'GaugeIcon',
+  },
+  wifi: {
+    id: 'wifi',
+    name: 'Wi-Fi',
+    description: 'Switch Wi-Fi networks and toggle Wi-Fi',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'WifiIcon',
+  },
+  bluetooth: {
+    id: 'bluetooth',
+    name: 'Bluetooth',
+    description: 'Connect or disconnect paired Bluetooth devices',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'BluetoothIcon',
+  },
+  'audio-device': {
+    id: 'audio-device',
+    name: 'Audio Devices',
+    description: 'Switch the default audio output and input device',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'SpeakerIcon',
+  },
+  'volume-brightness': {
+    id: 'volume-brightness',
+    name: 'Volume & Brightness',
+    description: 'Set or nudge system volume, mute and display brightness',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'Volume2Icon',
+  },
+  media: {
+    id: 'media',
+    name: 'Media Control',
+    description: 'Media playback control and now-playing info with Spotify like/skip',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'MusicIcon',
+  },
+  'color-picker': {
+    id: 'color-picker',
+    name: 'Color Picker',
+    description: 'Pick a pixel color anywhere on screen and copy it as HEX/RGB/HSL',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'PipetteIcon',
+  },
+  ocr: {
+    id: 'ocr',
+    name: 'Screen OCR',
+    description: 'Capture a screen region and copy the recognized text',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'ScanTextIcon',
+  },
+  workspace: {
+    id: 'workspace',
+    name: 'Virtual Desktops',
+    description: 'Switch virtual desktops or move the focused window to one',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'LayoutGridIcon',
+  },
+  dnd: {
+    id: 'dnd',
+    name: 'Do Not Disturb',
+    description: 'Toggle the system Do Not Disturb / Focus mode',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'BellOffIcon',
+  },
+  appearance: {
+    id: 'appearance',
+    name: '深色模式',
+    description: '在深色和浅色外观之间切换系统主题',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'SunMoonIcon',
+  },
+  trash: {
+    id: 'trash',
+    name: '回收站',
+    description: '查看回收站占用并清空，搜索已删除的文件并恢复',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'Trash2Icon',
+  },
+  drives: {
+    id: 'drives',
+    name: '弹出磁盘',
+    description: '安全弹出 U 盘等可移动磁盘',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'HardDriveIcon',
+  },
+  ssh: {
+    id: 'ssh',
+    name: 'SSH 主机',
+    description: '从 SSH 配置中选择主机并在终端中连接',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'ServerIcon',
+  },
+  shell: {
+    id: 'shell',
+    name: 'Shell 命令',
+    description: '运行 shell 命令并查看输出',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'SquareTerminalIcon',
+  },
+  'shell-history': {
+    id: 'shell-history',
+    name: 'Shell 历史',
+    description: '搜索 bash、zsh、fish 的历史命令',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'HistoryIcon',
+  },
+  env: {
+    id: 'env',
+    name: '环境变量',
+    description: '查看和复制环境变量',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'VariableIcon',
+  },
+  man: {
+    id: 'man',
+    name: '命令手册',
+    description: '搜索 man 手册和 tldr 页面',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'BookOpenIcon',
+  },
+  packages: {
+    id: 'packages',
+    name: '软件包',
+    description: '通过系统包管理器搜索、安装和升级软件包',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'PackageIcon',
+  },
+  kubernetes: {
+    id: 'kubernetes',
+    name: 'Kubernetes',
+    description: '切换 kubectl 上下文，查看 Pod 和 Deployment 的日志',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: false,
+    icon: 'ContainerIcon',
+  },
+  'git-repos': {
+    id: 'git-repos',
+    name: 'Git 仓库',
+    description: '查找本地 Git 仓库，在编辑器或终端中打开',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'FolderGit2Icon',
+  },
+  vscode: {
+    id: 'vscode',
+    name: 'VS Code 最近打开',
+    description: '搜索 VS Code 最近打开的工作区和文件夹',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'SquareCodeIcon',
+  },
+  jetbrains: {
+    id: 'jetbrains',
+    name: 'JetBrains 最近项目',
+    description: '搜索 JetBrains IDE 最近打开的项目',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'BracesIcon',
+  },
+  github: {
+    id: 'github',
+    name: 'GitHub',
+    description: '搜索自己的 GitHub 仓库、Issue 和 Pull Request',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'GithubIcon',
+  },
+  'issue-tracker': {
+    id: 'issue-tracker',
+    name: '问题跟踪',
+    description: '在 Jira 和 Linear 中按编号或关键词查找 Issue',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'TicketIcon',
+  },
+  registry: {
+    id: 'registry',
+    name: '包仓库',
+    description: '在 crates.io 和 npm 中查找包的最新版本',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'PackageIcon',
+  },
+  stackoverflow: {
+    id: 'stackoverflow',
+    name: 'Stack Overflow',
+    description: '搜索 Stack Overflow 上的问题',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'MessageCircleQuestionIcon',
+  },
+  docs: {
+    id: 'docs',
+    name: '离线文档',
+    description: '在本机的 Dash / Zeal / DevDocs 文档中查找 API',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'LibraryBigIcon',
+  },
+  quicklinks: {
+    id: 'quicklinks',
+    name: '快捷链接',
+    description: '按关键词打开自定义的网址模板',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'LinkIcon',
+  },
+  'open-target': {
+    id: 'open-target',
+    name: '直接打开',
+    description: '识别输入的网址和文件路径并直接打开',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'ExternalLinkIcon',
+  },
+  'network-tools': {
+    id: 'network-tools',
+    name: '网络诊断',
+    description: 'DNS 记录、whois 注册信息和 IP 归属查询',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'NetworkIcon',
+  },
+  port: {
+    id: 'port',
+    name: '端口占用',
+    description: '查找占用本机端口的进程并结束',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'CableIcon',
+  },
+  weather: {
+    id: 'weather',
+    name: '天气',
+    description: '查询当前天气和未来几天的预报',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'CloudSunIcon',
+  },
+  rss: {
+    id: 'rss',
+    name: 'RSS 订阅',
+    description: '订阅 RSS/Atom 并浏览未读条目',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'RssIcon',
+  },
+  mail: {
+    id: 'mail',
+    name: '本地邮件',
+    description: '按发件人和主题搜索本机邮件',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: false,
+    icon: 'MailIcon',
+  },
+  vault: {
+    id: 'vault',
+    name: '密码库',
+    description: '搜索 Bitwarden / 1Password 条目并复制密码或验证码',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: false,
+    icon: 'KeyRoundIcon',
+  },
+  battery: {
+    id: 'battery',
+    name: '电池与运行时间',
+    description: '查看电量、充电状态和系统运行时间',
+    category: 'system',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'BatteryMediumIcon',
+  },
+  'text-transform': {
+    id: 'text-transform',
+    name: '文本转换',
+    description: '转换文本的大小写和命名风格，整理多行文本',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'CaseSensitiveIcon',
+  },
+  json: {
+    id: 'json',
+    name: 'JSON 格式化',
+    description: '格式化、压缩和校验 JSON',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'BracesIcon',
+  },
+  regex: {
+    id: 'regex',
+    name: '正则测试',
+    description: '测试正则表达式的匹配和捕获组',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'RegexIcon',
+  },
+  cron: {
+    id: 'cron',
+    name: 'cron 表达式',
+    description: '解释 cron 表达式并列出接下来的运行时间',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'CalendarClockIcon',
+  },
+  'number-base': {
+    id: 'number-base',
+    name: '进制转换',
+    description: '十进制、十六进制、二进制、八进制互相转换',
+    category: 'development',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'BinaryIcon',
+  },
+  diff: {
+    id: 'diff',
+    name: '文本对比',
+    description: '对比剪贴板中前后两次复制的文本或两个文件',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'GitCompareIcon',
+  },
+  'clipboard-image': {
+    id: 'clipboard-image',
+    name: '保存剪贴板图片',
+    description: '把剪贴板中的图片保存为 PNG / JPEG 文件',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'ImageDownIcon',
+  },
+  'network-shares': {
+    id: 'network-shares',
+    name: '网络共享',
+    description: '搜索 SMB/NFS 网络共享中的文件',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'NetworkIcon',
+  },
+  'cloud-drive': {
+    id: 'cloud-drive',
+    name: '云盘搜索',
+    description: '搜索 Google Drive、Dropbox 和 OneDrive 中的文件',
+    category: 'productivity',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'CloudIcon',
+  },
+  fonts: {
+    id: 'fonts',
+    name: '字体',
+    description: '搜索已安装的字体，预览示例文字并复制字体名称',
+    category: 'utilities',
+    version: '1.0.0',
+    author: 'launch-rs',
+    enabled: true,
+    icon: 'TypeIcon',
+  },
 }
 
 /**
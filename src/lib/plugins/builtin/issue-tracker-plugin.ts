@@ -0,0 +1,174 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+type Tracker = 'jira' | 'linear'
+
+interface TrackerIssue {
+  tracker: Tracker
+  key: string
+  title: string
+  status: string | null
+  url: string
+}
+
+const TRACKER_NAMES: Record<Tracker, string> = {
+  jira: 'Jira',
+  linear: 'Linear'
+}
+
+// 与后端 parse_issue_key 的规则一致
+const ISSUE_KEY_PATTERN = /^[a-z][a-z0-9_]+-\d+$/i
+
+/**
+ * 问题跟踪插件
+ *
+ * 用法：直接粘贴 `PROJ-1234` 打开对应的 Issue；`issue <关键词>` 在 Jira 和 Linear 中搜索，
+ * `jira <关键词>` 或 `linear <关键词>` 只搜索一个服务；`issue login jira` / `issue login linear` 保存令牌
+ */
+export class IssueTrackerPlugin implements SearchPlugin {
+  id = 'issue-tracker'
+  name = '问题跟踪'
+  description = '在 Jira 和 Linear 中按编号或关键词查找 Issue'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 75
+  searchPrefixes = ['issue ', 'jira ', 'linear ']
+
+  settings = {
+    schema: [
+      {
+        key: 'jiraUrl',
+        label: 'Jira 地址',
+        description: '例如 https://acme.atlassian.net；留空不搜索 Jira',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'jiraEmail',
+        label: 'Jira 账号邮箱',
+        description: 'Jira Cloud 使用邮箱和 API 令牌认证；Server / Data Center 使用个人访问令牌时留空',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'linear',
+        label: '搜索 Linear',
+        type: 'boolean' as const,
+        defaultValue: false
+      }
+    ],
+    values: {
+      jiraUrl: '',
+      jiraEmail: '',
+      linear: false
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Ticket)
+      logger.info('问题跟踪插件初始化完成')
+    } catch (error) {
+      logger.warn('问题跟踪图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    const prefix = context.prefix?.trim().toLowerCase()
+    const [command, ...rest] = query.split(/\s+/)
+
+    if (prefix && /^(login|logout)$/i.test(command)) {
+      const tracker = (prefix === 'issue' ? rest[0]?.toLowerCase() : prefix) as Tracker
+      return TRACKER_NAMES[tracker] ? [this.createCredentialResult(tracker, command.toLowerCase() === 'login')] : []
+    }
+
+    // 没有输入前缀时只处理 Issue 编号，避免每次输入都请求网络
+    if (!query || (!prefix && !ISSUE_KEY_PATTERN.test(query))) {
+      return []
+    }
+
+    const useJira = prefix !== 'linear' && Boolean(this.settings.values.jiraUrl)
+    const useLinear = prefix !== 'jira' && Boolean(this.settings.values.linear)
+    if (!useJira && !useLinear) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const issues = await invoke<TrackerIssue[]>('search_issue_tracker', {
+        query,
+        jira: useJira ? { base_url: this.settings.values.jiraUrl, email: this.settings.values.jiraEmail || null } : null,
+        linear: useLinear
+      })
+
+      return issues.slice(0, context.maxResults).map((issue, index) => ({
+        id: `issue-${issue.tracker}-${issue.key}`,
+        title: issue.title === issue.key ? issue.key : `${issue.key} ${issue.title}`,
+        description: [TRACKER_NAMES[issue.tracker], issue.status, '回车在浏览器中打开'].filter(Boolean).join(' · '),
+        icon: this.icon,
+        priority: this.priority + (issue.key.toLowerCase() === query.toLowerCase() ? 60 : 40) - index,
+        action: () => this.openUrl(issue.url),
+        source: this.id,
+        metadata: {
+          type: 'issue',
+          tracker: issue.tracker
+        }
+      }))
+    } catch (error) {
+      const appError = handlePluginError('查找 Issue', error)
+      logger.error('查找 Issue 失败', appError)
+      return []
+    }
+  }
+
+  // 令牌不能放在搜索词中，否则会被记入搜索统计
+  private createCredentialResult(tracker: Tracker, login: boolean): SearchResultItem {
+    const name = TRACKER_NAMES[tracker]
+    return {
+      id: `issue-${login ? 'login' : 'logout'}-${tracker}`,
+      title: login ? `保存 ${name} 令牌` : `删除 ${name} 令牌`,
+      description: login ? '回车输入令牌，保存到系统钥匙串' : '从系统钥匙串中删除保存的令牌',
+      icon: this.icon,
+      priority: this.priority + 50,
+      action: async () => {
+        const secret = login ? prompt(`请输入 ${name} ${tracker === 'jira' ? 'API 令牌' : 'API 密钥'}`)?.trim() : null
+        if (login && !secret) {
+          return
+        }
+
+        try {
+          const { invoke } = await import('@tauri-apps/api/core')
+          if (login) {
+            await invoke('save_credential', { account: tracker, secret })
+            logger.success(`已保存 ${name} 令牌`)
+          } else {
+            await invoke('delete_credential', { account: tracker })
+            logger.success(`已删除 ${name} 令牌`)
+          }
+        } catch (error) {
+          const appError = handlePluginError(`保存 ${name} 令牌`, error)
+          logger.error(`保存 ${name} 令牌失败`, appError)
+        }
+      },
+      source: this.id,
+      metadata: {
+        type: 'issue-credential'
+      }
+    }
+  }
+
+  private async openUrl(url: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_url', { url })
+    } catch (error) {
+      const appError = handlePluginError('打开 Issue', error)
+      logger.error('打开 Issue 失败', appError)
+    }
+  }
+}
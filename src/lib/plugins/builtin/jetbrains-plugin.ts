@@ -0,0 +1,82 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface JetBrainsProject {
+  name: string
+  path: string
+  product: string
+  product_name: string
+  last_opened: number | null
+}
+
+/**
+ * JetBrains 最近项目插件
+ *
+ * 用法：`jb <名称>` 搜索 IntelliJ IDEA、CLion、PyCharm 等 IDE 最近打开的项目，回车用对应的 IDE 打开
+ */
+export class JetBrainsPlugin implements SearchPlugin {
+  id = 'jetbrains'
+  name = 'JetBrains 最近项目'
+  description = '搜索 JetBrains IDE 最近打开的项目'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['jb ', 'idea ']
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Braces)
+      logger.info('JetBrains 最近项目插件初始化完成')
+    } catch (error) {
+      logger.warn('JetBrains 图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const words = context.query.trim().toLowerCase().split(/\s+/).filter(Boolean)
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const projects = await invoke<JetBrainsProject[]>('list_jetbrains_projects')
+
+      return projects
+        .filter(project => {
+          const text = `${project.name} ${project.path} ${project.product_name}`.toLowerCase()
+          return words.every(word => text.includes(word))
+        })
+        .slice(0, context.maxResults)
+        .map((project, index) => ({
+          id: `jetbrains-${project.product}-${project.path}`,
+          title: project.name,
+          description: `${project.product_name} · ${project.path}`,
+          icon: this.icon,
+          // 保持最近打开的顺序，名称以关键词开头的排在前面
+          priority: this.priority + (words.length && project.name.toLowerCase().startsWith(words[0]) ? 50 : 30) - index,
+          action: () => this.open(project),
+          source: this.id,
+          metadata: {
+            type: 'jetbrains-project',
+            product: project.product
+          }
+        }))
+    } catch (error) {
+      const appError = handlePluginError('读取 JetBrains 最近项目', error)
+      logger.error('读取 JetBrains 最近项目失败', appError)
+      return []
+    }
+  }
+
+  private async open(project: JetBrainsProject): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('open_jetbrains_project', { project })
+    } catch (error) {
+      const appError = handlePluginError(`用 ${project.product_name} 打开项目`, error)
+      logger.error('打开 JetBrains 项目失败', appError)
+    }
+  }
+}
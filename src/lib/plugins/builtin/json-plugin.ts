@@ -0,0 +1,142 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface JsonSyntaxError {
+  message: string
+  line: number
+  column: number
+}
+
+interface JsonFormatResult {
+  pretty: string | null
+  minified: string | null
+  summary: string | null
+  error: JsonSyntaxError | null
+}
+
+const MAX_TITLE_LENGTH = 80
+
+/**
+ * JSON 插件
+ *
+ * 用法：`json {"a": 1}` 校验并格式化输入的 JSON，只输入 `json` 处理剪贴板中的内容；
+ * 回车复制格式化或压缩后的结果，也可以保存为下载目录中的 .json 文件。语法错误会给出行号和列号
+ */
+export class JsonPlugin implements SearchPlugin {
+  id = 'json'
+  name = 'JSON 格式化'
+  description = '格式化、压缩和校验 JSON'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['json ']
+  // JSON 中的引号、括号等字符原样交给插件
+  rawInput = true
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Braces)
+      logger.info('JSON 插件初始化完成')
+    } catch (error) {
+      logger.warn('JSON 图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 json，处理剪贴板内容
+    if (!context.prefix && !/^json$/i.test(context.query.trim())) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const fromClipboard = !context.prefix || !context.query.trim()
+      const text = fromClipboard ? await invoke<string | null>('read_clipboard_text') : context.query
+      if (!text?.trim()) {
+        return [this.createResult('json-empty', '剪贴板中没有文本', '复制 JSON 或在 json 后输入内容', this.priority, async () => {})]
+      }
+
+      const result = await invoke<JsonFormatResult>('format_json', { text })
+      const source = fromClipboard ? '剪贴板' : '输入'
+      if (result.error || result.pretty === null || result.minified === null) {
+        const error = result.error
+        return [this.createResult(
+          'json-error',
+          error ? `第 ${error.line} 行第 ${error.column} 列: ${error.message}` : 'JSON 无效',
+          `${source}中的 JSON 无效`,
+          this.priority + 30,
+          async () => {}
+        )]
+      }
+
+      const { pretty, minified } = result
+      const summary = `${result.summary} · ${source}`
+      const results = [
+        this.createResult('json-pretty', this.truncate(minified), `${summary} · 回车复制格式化结果`, this.priority + 30, () => this.copy(pretty, '格式化结果')),
+        this.createResult('json-minified', '复制压缩结果', `${minified.length} 个字符`, this.priority + 29, () => this.copy(minified, '压缩结果')),
+        this.createResult('json-save', '保存为文件', '将格式化结果保存到下载目录', this.priority + 28, () => this.save(pretty))
+      ]
+      results.forEach(item => {
+        item.preview = async () => pretty
+      })
+      return results
+    } catch (error) {
+      const appError = handlePluginError('格式化 JSON', error)
+      logger.error('格式化 JSON 失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'json'
+      }
+    }
+  }
+
+  private truncate(text: string): string {
+    return text.length > MAX_TITLE_LENGTH ? `${text.slice(0, MAX_TITLE_LENGTH)}…` : text
+  }
+
+  private async copy(text: string, label: string): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('json-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.success(`已复制 JSON ${label}`)
+    } catch (error) {
+      const appError = handlePluginError('复制 JSON', error)
+      logger.error('复制 JSON 失败', appError)
+    }
+  }
+
+  private async save(content: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const path = await invoke<string>('save_json_file', { content })
+      logger.success(`已保存到 ${path}`)
+    } catch (error) {
+      const appError = handlePluginError('保存 JSON 文件', error)
+      logger.error('保存 JSON 文件失败', appError)
+    }
+  }
+}
@@ -0,0 +1,176 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface KubeContext {
+  name: string
+  cluster: string
+  namespace: string | null
+  current: boolean
+}
+
+interface KubeResource {
+  kind: 'pod' | 'deployment'
+  name: string
+  namespace: string
+  status: string
+}
+
+/**
+ * Kubernetes 插件（默认关闭）
+ *
+ * 用法：`k8s ctx` 列出上下文并回车切换；`k8s <名称>` 搜索当前上下文的 Pod 和 Deployment，
+ * 回车在终端中查看日志；`k8s exec <名称>` 回车复制 `kubectl exec` 命令
+ */
+export class KubernetesPlugin implements SearchPlugin {
+  id = 'kubernetes'
+  name = 'Kubernetes'
+  description = '切换 kubectl 上下文，查看 Pod 和 Deployment 的日志'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  // 涉及集群凭据，需要用户主动开启
+  enabled = false
+  priority = 70
+  searchPrefixes = ['k8s ', 'kube ', 'kubectl ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'terminalCommand',
+        label: '终端命令',
+        description: '查看日志使用的终端，{cmd} 会被替换为 kubectl 命令；留空使用系统默认终端',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'execShell',
+        label: 'exec 使用的 shell',
+        type: 'string' as const,
+        defaultValue: 'sh'
+      }
+    ],
+    values: {
+      terminalCommand: '',
+      execShell: 'sh'
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Container)
+      logger.info('Kubernetes 插件初始化完成')
+    } catch (error) {
+      logger.warn('Kubernetes 图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    const [command, ...rest] = query.split(/\s+/)
+    const argument = rest.join(' ')
+
+    try {
+      if (/^(ctx|context|contexts)$/i.test(command)) {
+        return await this.searchContexts(argument.toLowerCase(), context.maxResults)
+      }
+      const exec = /^exec$/i.test(command)
+      return await this.searchResources(exec ? argument : query, exec, context.maxResults)
+    } catch (error) {
+      const appError = handlePluginError('查询 Kubernetes', error)
+      logger.error('查询 Kubernetes 失败', appError)
+      return []
+    }
+  }
+
+  private async searchContexts(query: string, maxResults?: number): Promise<SearchResultItem[]> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    const contexts = await invoke<KubeContext[]>('list_kube_contexts')
+
+    return contexts
+      .filter(kubeContext => !query || kubeContext.name.toLowerCase().includes(query) || kubeContext.cluster.toLowerCase().includes(query))
+      .slice(0, maxResults)
+      .map((kubeContext, index) => ({
+        id: `kube-context-${kubeContext.name}`,
+        title: kubeContext.current ? `${kubeContext.name}（当前）` : `切换到 ${kubeContext.name}`,
+        description: `集群 ${kubeContext.cluster}${kubeContext.namespace ? ` · 命名空间 ${kubeContext.namespace}` : ''}`,
+        icon: this.icon,
+        priority: this.priority + 40 - index,
+        action: () => this.switchContext(kubeContext),
+        source: this.id,
+        metadata: {
+          type: 'kube-context'
+        }
+      }))
+  }
+
+  private async searchResources(query: string, exec: boolean, maxResults?: number): Promise<SearchResultItem[]> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    const resources = await invoke<KubeResource[]>('search_kube_resources', { query })
+
+    return resources.slice(0, maxResults).map((resource, index) => ({
+      id: `kube-${resource.kind}-${resource.namespace}-${resource.name}`,
+      title: resource.name,
+      description: `${resource.kind === 'pod' ? 'Pod' : 'Deployment'} · ${resource.namespace} · ${resource.status} · ${exec ? '回车复制 exec 命令' : '回车查看日志'}`,
+      icon: this.icon,
+      priority: this.priority + 40 - index,
+      action: () => exec ? this.copyExecCommand(resource) : this.openLogs(resource),
+      source: this.id,
+      metadata: {
+        type: 'kube-resource',
+        kind: resource.kind
+      }
+    }))
+  }
+
+  private async switchContext(kubeContext: KubeContext): Promise<void> {
+    if (kubeContext.current) {
+      return
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('switch_kube_context', { name: kubeContext.name })
+      logger.success(`已切换到上下文 ${kubeContext.name}`)
+    } catch (error) {
+      const appError = handlePluginError('切换 Kubernetes 上下文', error)
+      logger.error('切换 Kubernetes 上下文失败', appError)
+    }
+  }
+
+  private async openLogs(resource: KubeResource): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('open_kube_logs', {
+        resource,
+        terminalCommand: this.settings.values.terminalCommand || null
+      })
+    } catch (error) {
+      const appError = handlePluginError('查看 Kubernetes 日志', error)
+      logger.error('查看 Kubernetes 日志失败', appError)
+    }
+  }
+
+  private async copyExecCommand(resource: KubeResource): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('kubernetes-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const target = resource.kind === 'pod' ? resource.name : `deployment/${resource.name}`
+      const text = `kubectl exec -it -n ${resource.namespace} ${target} -- ${this.settings.values.execShell || 'sh'}`
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.success('已复制 kubectl exec 命令')
+    } catch (error) {
+      const appError = handlePluginError('复制 kubectl exec 命令', error)
+      logger.error('复制 kubectl exec 命令失败', appError)
+    }
+  }
+}
@@ -0,0 +1,136 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface LoremText {
+  text: string
+  unit: 'words' | 'sentences' | 'paragraphs'
+  count: number
+  word_count: number
+}
+
+const UNIT_LABELS: Record<LoremText['unit'], string> = {
+  words: '个单词',
+  sentences: '个句子',
+  paragraphs: '段'
+}
+
+/**
+ * 占位文本插件
+ *
+ * 用法：`lorem 3p`、`lorem 5 sentences`、`lorem 20w`
+ */
+export class LoremIpsumPlugin implements SearchPlugin {
+  id = 'lorem'
+  name = '占位文本'
+  description = '生成 Lorem ipsum 占位文本'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+  searchPrefixes = ['lorem ', 'lipsum ']
+
+  settings = {
+    schema: [
+      {
+        key: 'startWithLorem',
+        label: '以 Lorem ipsum 开头',
+        description: '生成的文本是否以经典的 "Lorem ipsum dolor sit amet" 开头',
+        type: 'boolean' as const,
+        defaultValue: true
+      }
+    ],
+    values: {
+      startWithLorem: true
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Type)
+      logger.info('占位文本插件初始化完成')
+    } catch (error) {
+      logger.warn('占位文本图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 lorem / lipsum
+    if (!context.prefix && !/^(lorem|lipsum)$/i.test(context.query.trim())) {
+      return []
+    }
+    const spec = this.parseSpec(context.prefix ? context.query : '')
+    if (!spec) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const lorem = await invoke<LoremText>('generate_lorem', {
+        count: spec.count,
+        unit: spec.unit,
+        startWithLorem: this.settings.values.startWithLorem
+      })
+
+      return [{
+        id: `lorem-${spec.count}-${spec.unit}`,
+        title: lorem.text.length > 80 ? `${lorem.text.slice(0, 80)}…` : lorem.text,
+        description: `${lorem.count} ${UNIT_LABELS[lorem.unit]} • ${lorem.word_count} 词 • 回车复制`,
+        icon: this.icon,
+        priority: this.priority + 50,
+        action: () => this.copyText(lorem.text),
+        source: this.id,
+        metadata: {
+          type: 'lorem',
+          text: lorem.text,
+          wordCount: lorem.word_count
+        }
+      }]
+    } catch (error) {
+      const appError = handlePluginError('生成占位文本', error)
+      logger.error('生成占位文本失败', appError)
+      return []
+    }
+  }
+
+  /**
+   * 解析 "3p"、"5 sentences"、"20" 等格式，默认 1 段
+   */
+  private parseSpec(query: string): { count: number; unit: string } | null {
+    const trimmed = query.trim()
+    if (!trimmed) {
+      return { count: 1, unit: 'p' }
+    }
+
+    const match = trimmed.match(/^(\d+)\s*([a-z]*)$/i)
+    if (!match) {
+      return null
+    }
+
+    return {
+      count: parseInt(match[1], 10),
+      unit: match[2] || 'p'
+    }
+  }
+
+  private async copyText(text: string): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('lorem-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.info('占位文本已复制到剪贴板')
+    } catch (error) {
+      const appError = handlePluginError('复制占位文本', error)
+      logger.error('复制占位文本失败', appError)
+    }
+  }
+}
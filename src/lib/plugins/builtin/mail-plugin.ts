@@ -0,0 +1,131 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface MailMessage {
+  subject: string
+  from: string
+  date: number | null
+  path: string
+  offset: number
+  length: number
+  source: string
+}
+
+/**
+ * 本地邮件插件（默认关闭）
+ *
+ * 用法：`mail <发件人或主题>` 搜索本机的 Maildir、mbox（Thunderbird、Evolution）和 Apple Mail 邮件，
+ * 回车在默认邮件客户端中打开。Outlook 的 .pst 无法直接读取，可导出为 mbox 后添加到邮件目录
+ */
+export class MailPlugin implements SearchPlugin {
+  id = 'mail'
+  name = '本地邮件'
+  description = '按发件人和主题搜索本机邮件'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  // 涉及个人隐私数据，需要用户主动开启
+  enabled = false
+  priority = 60
+  searchPrefixes = ['mail ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'includeDefault',
+        label: '扫描默认邮件目录',
+        description: '扫描 ~/Maildir、Thunderbird、Evolution 和 Apple Mail 的默认目录（Apple Mail 需要完全磁盘访问权限）',
+        type: 'boolean' as const,
+        defaultValue: true
+      },
+      {
+        key: 'mailPaths',
+        label: '邮件目录',
+        description: 'Maildir、mbox 文件或包含 .eml 的目录，以逗号分隔',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      includeDefault: true,
+      mailPaths: ''
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Mail)
+      logger.info('本地邮件插件初始化完成')
+    } catch (error) {
+      logger.warn('本地邮件图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    if (query.length < 2) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const messages = await invoke<MailMessage[]>('search_mail', {
+        query,
+        mailPaths: this.settings.values.mailPaths
+          .split(',')
+          .map(path => path.trim())
+          .filter(Boolean),
+        includeDefault: this.settings.values.includeDefault
+      })
+
+      return messages.slice(0, context.maxResults).map((message, index) => {
+        const date = message.date ? new Date(message.date * 1000).toLocaleString() : ''
+        return this.createResult(
+          `mail-${message.path}-${message.offset}`,
+          message.subject,
+          [message.from, date, message.source].filter(Boolean).join(' • '),
+          this.priority + 30 - index,
+          () => this.openMessage(message)
+        )
+      })
+    } catch (error) {
+      const appError = handlePluginError('搜索邮件', error)
+      logger.error('搜索邮件失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'mail'
+      }
+    }
+  }
+
+  // 导出为 .eml 后交给系统默认的邮件客户端打开
+  private async openMessage(message: MailMessage): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const path = await invoke<string>('export_mail_message', {
+        path: message.path,
+        offset: message.offset,
+        length: message.length
+      })
+      await invoke('plugin:opener|open_path', { path })
+    } catch (error) {
+      const appError = handlePluginError('打开邮件', error)
+      logger.error('打开邮件失败', appError)
+    }
+  }
+}
@@ -0,0 +1,139 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface ManPageEntry {
+  name: string
+  section: string | null
+  summary: string
+  source: 'man' | 'tldr'
+}
+
+/**
+ * 命令手册插件
+ *
+ * 用法：`man <命令>` 或 `tldr <命令>` 搜索 man 手册和 tldr 页面，预览区显示页面内容；
+ * man 手册回车在终端中打开，tldr 页面回车复制内容
+ */
+export class ManPlugin implements SearchPlugin {
+  id = 'man'
+  name = '命令手册'
+  description = '搜索 man 手册和 tldr 页面'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 75
+  searchPrefixes = ['man ', 'tldr ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'terminalCommand',
+        label: '终端命令',
+        description: '打开 man 手册使用的终端，{cmd} 会被替换为 man 命令；留空使用系统默认终端',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'downloadTldr',
+        label: '在线获取 tldr 页面',
+        description: '本机没有缓存的 tldr 页面时从 tldr-pages 仓库下载',
+        type: 'boolean' as const,
+        defaultValue: false
+      }
+    ],
+    values: {
+      terminalCommand: '',
+      downloadTldr: false
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.BookOpen)
+      logger.info('命令手册插件初始化完成')
+    } catch (error) {
+      logger.warn('命令手册图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim().toLowerCase()
+    if (!query) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const entries = await invoke<ManPageEntry[]>('search_man_pages', { query })
+
+      // 本机没有该命令的 tldr 页面时提供在线获取
+      if (this.settings.values.downloadTldr && !entries.some(entry => entry.source === 'tldr' && entry.name === query)) {
+        entries.push({ name: query, section: null, summary: 'tldr · 在线获取', source: 'tldr' })
+      }
+
+      return entries.slice(0, context.maxResults).map((entry, index) => ({
+        id: `man-${entry.source}-${entry.name}-${entry.section ?? ''}`,
+        title: entry.source === 'man' ? `${entry.name}(${entry.section ?? '?'})` : `tldr ${entry.name}`,
+        description: entry.source === 'man' ? `${entry.summary} · 回车在终端中打开` : `${entry.summary} · 回车复制`,
+        icon: this.icon,
+        priority: this.priority + (entry.name === query ? 50 : 30) - index,
+        action: () => entry.source === 'man' ? this.openInTerminal(entry) : this.copyTldr(entry),
+        preview: () => this.render(entry),
+        source: this.id,
+        metadata: {
+          type: 'man',
+          source: entry.source
+        }
+      }))
+    } catch (error) {
+      const appError = handlePluginError('搜索命令手册', error)
+      logger.error('搜索命令手册失败', appError)
+      return []
+    }
+  }
+
+  private async render(entry: ManPageEntry): Promise<string> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    return entry.source === 'man'
+      ? invoke<string>('render_man_page', { name: entry.name, section: entry.section })
+      : invoke<string>('render_tldr_page', { name: entry.name })
+  }
+
+  private async openInTerminal(entry: ManPageEntry): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('open_man_page', {
+        name: entry.name,
+        section: entry.section,
+        terminalCommand: this.settings.values.terminalCommand || null
+      })
+    } catch (error) {
+      const appError = handlePluginError('打开 man 手册', error)
+      logger.error('打开 man 手册失败', appError)
+    }
+  }
+
+  private async copyTldr(entry: ManPageEntry): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('man-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const text = await this.render(entry)
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.success(`已复制 ${entry.name} 的 tldr 页面`)
+    } catch (error) {
+      const appError = handlePluginError('复制 tldr 页面', error)
+      logger.error('复制 tldr 页面失败', appError)
+    }
+  }
+}
@@ -0,0 +1,232 @@
+import { h } from 'vue'
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+type MediaAction = 'play_pause' | 'next' | 'previous'
+
+interface NowPlaying {
+  title: string
+  artist: string
+  album: string
+  artwork_url: string | null
+  player: string
+  playing: boolean
+  spotify_track_id: string | null
+}
+
+const ACTION_LABELS: Record<MediaAction, string> = {
+  play_pause: '播放/暂停',
+  next: '下一首',
+  previous: '上一首'
+}
+
+/**
+ * 媒体控制插件
+ *
+ * 输入 `play` / `pause` / `next` / `prev` 控制当前播放的媒体，`media` 列出全部操作。
+ * 输入 `np` / `now playing` 查看当前播放的曲目，可以直接跳过或收藏到 Spotify。
+ * 同样的操作可以在快捷键设置中绑定为全局快捷键
+ */
+export class MediaPlugin implements SearchPlugin {
+  id = 'media'
+  name = '媒体控制'
+  description = '播放/暂停、下一首、上一首，查看正在播放的曲目'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+  // 不使用前缀，避免 "playground" 这类查询只交给本插件处理
+  private readonly keywords = new Map<string, MediaAction[]>([
+    ['media', ['play_pause', 'next', 'previous']],
+    ['play', ['play_pause']],
+    ['pause', ['play_pause']],
+    ['next', ['next']],
+    ['skip', ['next']],
+    ['prev', ['previous']],
+    ['previous', ['previous']],
+    ['播放', ['play_pause']],
+    ['暂停', ['play_pause']],
+    ['下一首', ['next']],
+    ['上一首', ['previous']]
+  ])
+  private readonly nowPlayingKeywords = new Set(['media', 'np', 'now playing', 'music', 'spotify', '正在播放'])
+
+  settings = {
+    schema: [
+      {
+        key: 'spotifyClientId',
+        label: 'Spotify Client ID',
+        description: '在 Spotify 开发者后台创建应用后获得，用于收藏曲目和读取其他设备上的播放',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'spotifyClientSecret',
+        label: 'Spotify Client Secret',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'spotifyRefreshToken',
+        label: 'Spotify Refresh Token',
+        description: '需要 user-read-currently-playing 和 user-library-modify 权限',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      spotifyClientId: '',
+      spotifyClientSecret: '',
+      spotifyRefreshToken: ''
+    }
+  }
+
+  private likeIcon: any = null
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Music)
+      this.likeIcon = await getIcon(ICON_MAP.Heart)
+      logger.info('媒体控制插件初始化完成')
+    } catch (error) {
+      logger.warn('媒体控制图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim().toLowerCase()
+    const results: SearchResultItem[] = []
+
+    if (this.nowPlayingKeywords.has(query)) {
+      results.push(...await this.nowPlayingResults())
+    }
+
+    const actions = this.keywords.get(query) ?? []
+    results.push(...actions.map((action, index) => this.createResult(
+      `media-${action}`,
+      ACTION_LABELS[action],
+      '控制当前播放的媒体',
+      this.icon,
+      this.priority + 40 - index,
+      () => this.control(action)
+    )))
+
+    return results
+  }
+
+  private get spotifyCredentials() {
+    const values = this.settings.values
+    if (!values.spotifyClientId || !values.spotifyClientSecret || !values.spotifyRefreshToken) {
+      return null
+    }
+    return {
+      client_id: values.spotifyClientId,
+      client_secret: values.spotifyClientSecret,
+      refresh_token: values.spotifyRefreshToken
+    }
+  }
+
+  private async nowPlayingResults(): Promise<SearchResultItem[]> {
+    const track = await this.getNowPlaying()
+    if (!track) {
+      return []
+    }
+
+    const results = [
+      this.createResult(
+        'media-now-playing',
+        track.artist ? `${track.title} — ${track.artist}` : track.title,
+        [track.album, track.player, track.playing ? '正在播放' : '已暂停'].filter(Boolean).join(' • '),
+        track.artwork_url ? this.artworkIcon(track.artwork_url) : this.icon,
+        this.priority + 60,
+        () => this.control('play_pause')
+      ),
+      this.createResult('media-skip', '跳过这首', track.title, this.icon, this.priority + 55, () => this.control('next'))
+    ]
+
+    // 收藏需要 Spotify 凭据；本地读不到曲目 ID 时由后端查询 Spotify 上正在播放的曲目
+    const credentials = this.spotifyCredentials
+    if (credentials && (track.spotify_track_id || track.player.toLowerCase().startsWith('spotify'))) {
+      results.push(this.createResult(
+        'media-like',
+        '收藏到 Spotify',
+        track.title,
+        this.likeIcon ?? this.icon,
+        this.priority + 54,
+        () => this.like(track)
+      ))
+    }
+
+    return results
+  }
+
+  private async getNowPlaying(): Promise<NowPlaying | null> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    try {
+      const track = await invoke<NowPlaying | null>('now_playing')
+      if (track) {
+        return track
+      }
+    } catch (error) {
+      logger.warn('读取本机正在播放的曲目失败', error)
+    }
+
+    // 本机没有播放时，尝试读取 Spotify 在其他设备上的播放
+    const credentials = this.spotifyCredentials
+    if (!credentials) {
+      return null
+    }
+    try {
+      return await invoke<NowPlaying | null>('spotify_now_playing', { credentials })
+    } catch (error) {
+      const appError = handlePluginError('读取 Spotify 正在播放', error)
+      logger.error('读取 Spotify 正在播放失败', appError)
+      return null
+    }
+  }
+
+  // 专辑封面作为结果图标
+  private artworkIcon(url: string) {
+    return () => h('img', { src: url, alt: '', class: 'rounded-sm object-cover' })
+  }
+
+  private createResult(id: string, title: string, description: string, icon: any, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'media'
+      }
+    }
+  }
+
+  private async control(action: MediaAction): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('media_control', { action })
+      logger.info(`媒体控制: ${ACTION_LABELS[action]}`)
+    } catch (error) {
+      const appError = handlePluginError('媒体控制', error)
+      logger.error('媒体控制失败', appError)
+    }
+  }
+
+  private async like(track: NowPlaying): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('spotify_save_track', { credentials: this.spotifyCredentials, trackId: track.spotify_track_id })
+      logger.success(`已收藏到 Spotify: ${track.title}`)
+    } catch (error) {
+      const appError = handlePluginError('收藏到 Spotify', error)
+      logger.error('收藏到 Spotify 失败', appError)
+    }
+  }
+}
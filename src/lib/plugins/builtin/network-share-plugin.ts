@@ -0,0 +1,260 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+type ShareStatus = 'pending' | 'indexing' | 'online' | 'offline'
+
+interface NetworkShare {
+  id: number
+  url: string
+  protocol: 'smb' | 'nfs'
+  username: string | null
+  created_at: number
+  indexed_at: number | null
+  last_error: string | null
+  entry_count: number
+  status: ShareStatus
+}
+
+interface ShareEntry {
+  share_id: number
+  share_url: string
+  name: string
+  path: string
+  is_dir: boolean
+  size: number
+  modified: number
+}
+
+const STATUS_LABELS: Record<ShareStatus, string> = {
+  pending: '等待索引',
+  indexing: '正在索引',
+  online: '在线',
+  offline: '离线'
+}
+
+/**
+ * 网络共享插件
+ *
+ * 添加的 SMB/NFS 共享在后台挂载并索引，普通搜索中会一并列出共享里的文件（共享离线时使用上次缓存的结果）。
+ * `share` 列出共享及状态，`share add smb://server/share [用户名]` 添加共享（需要密码时回车后输入，保存到系统钥匙串），
+ * `share rm <名称>` 删除，`share refresh` 立即重新索引，`share <关键词>` 只搜索共享中的文件
+ */
+export class NetworkSharePlugin implements SearchPlugin {
+  id = 'network-shares'
+  name = '网络共享'
+  description = '搜索 SMB/NFS 网络共享中的文件'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 60
+  // 带空格，避免 "shared" 等普通搜索被当作命令
+  searchPrefixes = ['share ', 'share:']
+
+  private folderIcon: any = null
+
+  settings = {
+    schema: [
+      {
+        key: 'maxResults',
+        label: '最大结果数',
+        description: '普通搜索中显示的共享文件数量',
+        type: 'number' as const,
+        defaultValue: 10
+      }
+    ],
+    values: {
+      maxResults: 10
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Network)
+      this.folderIcon = await getIcon(ICON_MAP.Folder)
+      logger.info('网络共享插件初始化完成')
+    } catch (error) {
+      logger.warn('网络共享图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+
+    try {
+      if (!context.prefix) {
+        // 搜索词会去掉末尾空格，单独的 share 不会匹配前缀
+        if (/^share$/i.test(query)) {
+          return await this.listShares('', false)
+        }
+        // 普通搜索中只查缓存，至少两个字符，避免每次按键都列出大量文件
+        return query.length >= 2 ? await this.searchEntries(query, this.settings.values.maxResults, this.priority) : []
+      }
+
+      if (!query) {
+        return await this.listShares('', false)
+      }
+      const [command, ...rest] = query.split(/\s+/)
+      const argument = rest.join(' ')
+      if (/^add$/i.test(command)) {
+        return argument ? [this.createAddResult(rest[0], rest[1])] : []
+      }
+      if (/^(rm|del|delete)$/i.test(command)) {
+        return await this.listShares(argument, true)
+      }
+      if (/^refresh$/i.test(command) && !argument) {
+        return [this.createResult('network-shares-refresh', '立即重新索引所有共享', '回车在后台刷新', this.priority + 50, () => this.refreshAll())]
+      }
+      return await this.searchEntries(query, 50, this.priority + 30)
+    } catch (error) {
+      const appError = handlePluginError('搜索网络共享', error)
+      logger.error('搜索网络共享失败', appError)
+      return []
+    }
+  }
+
+  private async searchEntries(query: string, limit: number, priority: number): Promise<SearchResultItem[]> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    const [entries, shares] = await Promise.all([
+      invoke<ShareEntry[]>('search_network_shares', { query, limit }),
+      invoke<NetworkShare[]>('list_network_shares')
+    ])
+    const offline = new Set(shares.filter(share => share.status === 'offline').map(share => share.id))
+
+    return entries.map((entry, index) => {
+      const location = entry.is_dir ? entry.share_url : `${entry.share_url} · ${this.formatSize(entry.size)}`
+      const result = this.createResult(
+        `network-share-${entry.path}`,
+        entry.name,
+        offline.has(entry.share_id) ? `${location} · 共享离线（缓存结果）` : location,
+        priority - Math.min(index, 20),
+        () => this.openPath(entry.path)
+      )
+      if (entry.is_dir && this.folderIcon) {
+        result.icon = this.folderIcon
+      }
+      result.preview = async () => [entry.path, entry.modified ? new Date(entry.modified * 1000).toLocaleString() : ''].join('\n').trim()
+      return result
+    })
+  }
+
+  private async listShares(filter: string, remove: boolean): Promise<SearchResultItem[]> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    const needle = filter.toLowerCase()
+    const shares = (await invoke<NetworkShare[]>('list_network_shares'))
+      .filter(share => !needle || share.url.toLowerCase().includes(needle))
+
+    if (shares.length === 0) {
+      return [this.createResult('network-shares-empty', '没有网络共享', '使用 share add smb://server/share [用户名] 添加共享', this.priority, async () => {})]
+    }
+
+    return shares.map((share, index) => {
+      const status = share.status === 'offline' && share.last_error
+        ? `离线: ${share.last_error}`
+        : STATUS_LABELS[share.status]
+      const user = share.username ? `${share.username} · ` : ''
+      return this.createResult(
+        `network-share-${share.id}`,
+        remove ? `删除共享: ${share.url}` : share.url,
+        `${user}${status} · ${share.entry_count} 个文件${remove ? '' : ' · 回车重新索引'}`,
+        this.priority + 40 - index,
+        () => remove ? this.deleteShare(share) : this.refresh(share)
+      )
+    })
+  }
+
+  // 密码不能放在搜索词中，否则会被记入搜索统计
+  private createAddResult(url: string, username?: string): SearchResultItem {
+    const hint = username ? `以 ${username} 身份连接，回车输入密码` : '回车添加并在后台索引'
+    return this.createResult(`network-shares-add-${url}`, `添加共享: ${url}`, hint, this.priority + 50, async () => {
+      let password: string | null = null
+      if (username) {
+        password = prompt(`请输入 ${username} 的密码（保存到系统钥匙串）`)
+        if (password === null) {
+          return
+        }
+      }
+
+      try {
+        const { invoke } = await import('@tauri-apps/api/core')
+        const share = await invoke<NetworkShare>('add_network_share', { url, username: username ?? null, password })
+        logger.success(`已添加共享: ${share.url}，正在后台索引`)
+      } catch (error) {
+        const appError = handlePluginError('添加网络共享', error)
+        logger.error('添加网络共享失败', appError)
+      }
+    })
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'network-share'
+      }
+    }
+  }
+
+  private formatSize(bytes: number): string {
+    const units = ['B', 'KB', 'MB', 'GB', 'TB']
+    let size = bytes
+    let unit = 0
+    while (size >= 1024 && unit < units.length - 1) {
+      size /= 1024
+      unit++
+    }
+    return `${unit === 0 ? size : size.toFixed(1)} ${units[unit]}`
+  }
+
+  private async openPath(path: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_path', { path })
+    } catch (error) {
+      const appError = handlePluginError('打开共享文件', error)
+      logger.error('打开共享文件失败', appError)
+    }
+  }
+
+  private async refresh(share: NetworkShare): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('refresh_network_share', { id: share.id })
+      logger.info(`正在重新索引共享: ${share.url}`)
+    } catch (error) {
+      const appError = handlePluginError('刷新网络共享', error)
+      logger.error('刷新网络共享失败', appError)
+    }
+  }
+
+  private async refreshAll(): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const shares = await invoke<NetworkShare[]>('list_network_shares')
+      await Promise.all(shares.map(share => invoke('refresh_network_share', { id: share.id })))
+      logger.info(`正在重新索引 ${shares.length} 个共享`)
+    } catch (error) {
+      const appError = handlePluginError('刷新网络共享', error)
+      logger.error('刷新网络共享失败', appError)
+    }
+  }
+
+  private async deleteShare(share: NetworkShare): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('remove_network_share', { id: share.id })
+      logger.success(`已删除共享: ${share.url}`)
+    } catch (error) {
+      const appError = handlePluginError('删除网络共享', error)
+      logger.error('删除网络共享失败', appError)
+    }
+  }
+}
@@ -0,0 +1,111 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface InfoRow {
+  label: string
+  value: string
+}
+
+type Tool = 'dns' | 'whois' | 'ipinfo'
+
+const TOOL_NAMES: Record<Tool, string> = {
+  dns: 'DNS 查询',
+  whois: 'whois 查询',
+  ipinfo: 'IP 查询'
+}
+
+/**
+ * 网络诊断插件
+ *
+ * 用法：`dns example.com` 查询 A/AAAA/CNAME/MX/TXT 记录，`whois example.com` 查询域名或 IP 的注册信息，
+ * `ipinfo 8.8.8.8` 查询 IP 的位置和 ASN（只输入 `ipinfo` 查询本机公网 IP）；回车复制对应的一行
+ */
+export class NetworkToolsPlugin implements SearchPlugin {
+  id = 'network-tools'
+  name = '网络诊断'
+  description = 'DNS 记录、whois 注册信息和 IP 归属查询'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['dns ', 'whois ', 'ipinfo']
+  prefixOnly = true
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Network)
+      logger.info('网络诊断插件初始化完成')
+    } catch (error) {
+      logger.warn('网络诊断图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const tool = context.prefix?.trim().toLowerCase() as Tool | undefined
+    const query = context.query.trim()
+    if (!tool || !TOOL_NAMES[tool] || (!query && tool !== 'ipinfo')) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const rows = tool === 'dns'
+        ? await invoke<InfoRow[]>('dns_lookup', { domain: query })
+        : tool === 'whois'
+          ? await invoke<InfoRow[]>('whois_lookup', { query })
+          : await invoke<InfoRow[]>('ip_info', { ip: query || null })
+
+      if (rows.length === 0) {
+        return [this.createResult(`${tool}-empty`, '没有找到记录', `${TOOL_NAMES[tool]} · ${query}`, this.priority, async () => {})]
+      }
+      return rows.map((row, index) => this.createResult(
+        `${tool}-${index}-${row.value}`,
+        row.value,
+        `${row.label} · 回车复制`,
+        this.priority + 40 - index,
+        () => this.copy(row)
+      ))
+    } catch (error) {
+      const appError = handlePluginError(TOOL_NAMES[tool], error)
+      logger.error(`${TOOL_NAMES[tool]}失败`, appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'network-tools'
+      }
+    }
+  }
+
+  private async copy(row: InfoRow): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('network-tools-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text: row.value })
+      logger.success(`已复制${row.label}`)
+    } catch (error) {
+      const appError = handlePluginError('复制查询结果', error)
+      logger.error('复制查询结果失败', appError)
+    }
+  }
+}
@@ -0,0 +1,127 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface NoteMatch {
+  title: string
+  path: string
+  vault: string
+  heading: string | null
+  snippet: string | null
+  obsidian_uri: string | null
+  score: number
+}
+
+/**
+ * 笔记插件
+ *
+ * 搜索配置的 Markdown 文件夹（Obsidian 仓库）中笔记的标题、小标题和正文
+ */
+export class NotesPlugin implements SearchPlugin {
+  id = 'notes'
+  name = '笔记'
+  description = '搜索 Obsidian 仓库或 Markdown 文件夹中的笔记'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 65
+  searchPrefixes = ['note ', 'notes ', 'md ']
+
+  settings = {
+    schema: [
+      {
+        key: 'vaults',
+        label: '笔记目录',
+        description: 'Obsidian 仓库或 Markdown 文件夹，以逗号分隔',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'openWith',
+        label: '打开方式',
+        type: 'select' as const,
+        defaultValue: 'obsidian',
+        options: [
+          { label: 'Obsidian（仅限 Obsidian 仓库）', value: 'obsidian' },
+          { label: '默认编辑器', value: 'default' }
+        ]
+      }
+    ],
+    values: {
+      vaults: '',
+      openWith: 'obsidian'
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.NotebookPen)
+      logger.info('笔记插件初始化完成')
+    } catch (error) {
+      logger.warn('笔记图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    const vaults = this.settings.values.vaults
+      .split(',')
+      .map(vault => vault.trim())
+      .filter(Boolean)
+
+    if (query.length < 2 || vaults.length === 0) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const notes = await invoke<NoteMatch[]>('search_notes', { query, vaults })
+
+      return notes.slice(0, context.maxResults).map(note => ({
+        id: `note-${note.path}`,
+        title: note.title,
+        description: this.formatDescription(note),
+        icon: this.icon,
+        priority: this.priority + Math.round(note.score / 10),
+        action: () => this.openNote(note),
+        source: this.id,
+        metadata: {
+          type: 'note',
+          path: note.path,
+          vault: note.vault
+        }
+      }))
+    } catch (error) {
+      const appError = handlePluginError('搜索笔记', error)
+      logger.error('搜索笔记失败', appError)
+      return []
+    }
+  }
+
+  private formatDescription(note: NoteMatch): string {
+    if (note.heading) {
+      return `${note.vault} • # ${note.heading}`
+    }
+    if (note.snippet) {
+      return note.snippet
+    }
+    return `${note.vault} • ${note.path}`
+  }
+
+  private async openNote(note: NoteMatch): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      if (this.settings.values.openWith === 'obsidian' && note.obsidian_uri) {
+        await invoke('plugin:opener|open_url', { url: note.obsidian_uri })
+      } else {
+        await invoke('plugin:opener|open_path', { path: note.path })
+      }
+      logger.info(`打开笔记: ${note.title}`)
+    } catch (error) {
+      const appError = handlePluginError('打开笔记', error)
+      logger.error('打开笔记失败', appError)
+    }
+  }
+}
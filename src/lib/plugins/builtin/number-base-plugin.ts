@@ -0,0 +1,96 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface BaseValue {
+  kind: string
+  name: string
+  value: string
+  display: string
+}
+
+interface BaseConversion {
+  input_base: string
+  values: BaseValue[]
+}
+
+/**
+ * 进制转换插件
+ *
+ * 用法：`0xff`、`0b1010 to dec`、`255 to hex`、`base: -42`，
+ * 显示十进制、十六进制、二进制、八进制结果，负数额外显示补码，回车复制对应进制的值
+ */
+export class NumberBasePlugin implements SearchPlugin {
+  id = 'number-base'
+  name = '进制转换'
+  description = '十进制、十六进制、二进制、八进制互相转换'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 88
+  searchPrefixes = ['base:']
+
+  // 带 0x/0b/0o 前缀的数字，或带 to/in 目标进制的十进制数
+  private readonly prefixedPattern = /^-?0[xbo][0-9a-f_]+(\s+(to|in)\s+(dec|hex|bin|oct))?$/i
+  private readonly targetPattern = /^-?\d[\d_]*\s+(to|in)\s+(dec|hex|bin|oct)$/i
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Binary)
+      logger.info('进制转换插件初始化完成')
+    } catch (error) {
+      logger.warn('进制转换图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const input = context.query.trim()
+    if (!context.prefix && !this.prefixedPattern.test(input) && !this.targetPattern.test(input)) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const conversion = await invoke<BaseConversion>('convert_number_base', { input })
+
+      return conversion.values.map((value, index) => ({
+        id: `number-base-${value.kind}`,
+        title: value.display,
+        description: `${value.name} • ${value.value} • 回车复制`,
+        icon: this.icon,
+        priority: this.priority + 50 - index,
+        action: () => this.copyValue(value),
+        source: this.id,
+        metadata: {
+          type: 'number-base',
+          kind: value.kind
+        }
+      }))
+    } catch (error) {
+      // 无法识别的数字不视为错误
+      logger.debug('进制转换失败', { input, error })
+      return []
+    }
+  }
+
+  private async copyValue(value: BaseValue): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('number-base-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text: value.value })
+      logger.info(`${value.value} 已复制到剪贴板`)
+    } catch (error) {
+      const appError = handlePluginError('复制转换结果', error)
+      logger.error('复制转换结果失败', appError)
+    }
+  }
+}
@@ -0,0 +1,103 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+/**
+ * 屏幕文字识别插件
+ *
+ * 输入 `ocr` 或 `识别文字` 后回车，框选屏幕区域，识别出的文字复制到剪贴板
+ */
+export class OcrPlugin implements SearchPlugin {
+  id = 'ocr'
+  name = '屏幕文字识别'
+  description = '框选屏幕区域识别文字并复制到剪贴板'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+  // 不使用前缀，只匹配完整的触发词
+  private readonly triggerPattern = /^(ocr|grab\s+text|识别文字|文字识别)$/i
+
+  settings = {
+    schema: [
+      {
+        key: 'language',
+        label: '识别语言（tesseract）',
+        description: 'tesseract 的语言代码，例如 eng、chi_sim+eng；macOS 和 Windows 使用系统 OCR，忽略此项',
+        type: 'string' as const,
+        defaultValue: 'eng'
+      }
+    ],
+    values: {
+      language: 'eng'
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.ScanText)
+      logger.info('屏幕文字识别插件初始化完成')
+    } catch (error) {
+      logger.warn('屏幕文字识别图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    if (!this.triggerPattern.test(context.query.trim())) {
+      return []
+    }
+
+    return [{
+      id: 'ocr-capture',
+      title: '框选屏幕区域识别文字',
+      description: '回车后拖动鼠标框选区域，Esc 取消，识别结果复制到剪贴板',
+      icon: this.icon,
+      priority: this.priority + 50,
+      action: () => this.capture(),
+      source: this.id,
+      metadata: {
+        type: 'ocr'
+      }
+    }]
+  }
+
+  private async capture(): Promise<void> {
+    const { getCurrentWindow } = await import('@tauri-apps/api/window')
+    const currentWindow = getCurrentWindow()
+
+    try {
+      // 隐藏启动器，避免挡住要识别的区域
+      await currentWindow.hide()
+      const { invoke } = await import('@tauri-apps/api/core')
+      const text = await invoke<string | null>('ocr_screen_region', {
+        language: this.settings.values.language || null
+      })
+      if (text === null) {
+        return
+      }
+      if (!text) {
+        logger.warn('没有识别到文字')
+        return
+      }
+
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('ocr-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      await invoke('copy_to_clipboard', { text })
+      logger.success(`已复制识别出的文字（${text.length} 个字符）`)
+    } catch (error) {
+      const appError = handlePluginError('屏幕文字识别', error)
+      logger.error('屏幕文字识别失败', appError)
+    } finally {
+      await currentWindow.show()
+      await currentWindow.setFocus()
+    }
+  }
+}
@@ -0,0 +1,90 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface OpenTarget {
+  kind: 'url' | 'file' | 'folder'
+  target: string
+}
+
+const KIND_LABELS: Record<OpenTarget['kind'], string> = {
+  url: '在浏览器中打开',
+  file: '打开文件',
+  folder: '打开文件夹'
+}
+
+/**
+ * 直接打开插件
+ *
+ * 用法：输入或粘贴网址、域名（如 `example.com/docs`）或绝对路径（如 `C:\logs\app.log`、`~/Downloads`），
+ * 第一个结果就是打开它；不带协议的域名自动补全 https://，localhost 和 IP 地址补全 http://
+ */
+export class OpenTargetPlugin implements SearchPlugin {
+  id = 'open-target'
+  name = '直接打开'
+  description = '识别输入的网址和文件路径并直接打开'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 100
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.ExternalLink)
+      logger.info('直接打开插件初始化完成')
+    } catch (error) {
+      logger.warn('直接打开图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    // 太短的输入不会是网址或路径
+    if (query.length < 3) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const target = await invoke<OpenTarget | null>('detect_open_target', { query })
+      if (!target) {
+        return []
+      }
+
+      return [{
+        id: `open-target-${target.target}`,
+        title: target.target,
+        description: `${KIND_LABELS[target.kind]} · 回车打开`,
+        icon: this.icon,
+        // 排在所有结果之前
+        priority: this.priority + 100,
+        action: () => this.open(target),
+        source: this.id,
+        metadata: {
+          type: 'open-target',
+          kind: target.kind
+        }
+      }]
+    } catch (error) {
+      const appError = handlePluginError('识别网址和路径', error)
+      logger.error('识别网址和路径失败', appError)
+      return []
+    }
+  }
+
+  private async open(target: OpenTarget): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      if (target.kind === 'url') {
+        await invoke('plugin:opener|open_url', { url: target.target })
+      } else {
+        await invoke('plugin:opener|open_path', { path: target.target })
+      }
+    } catch (error) {
+      const appError = handlePluginError('打开', error)
+      logger.error(`打开 ${target.target} 失败`, appError)
+    }
+  }
+}
@@ -0,0 +1,128 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+type PackageManager = 'winget' | 'brew' | 'apt' | 'pacman'
+type PackageAction = 'install' | 'upgrade'
+
+interface Package {
+  name: string
+  id: string
+  version: string | null
+  description: string | null
+  installed: boolean
+  upgradable: boolean
+}
+
+interface PackageSearchResult {
+  manager: PackageManager
+  packages: Package[]
+}
+
+/**
+ * 软件包插件
+ *
+ * 用法：`pkg <名称>` 通过系统包管理器（winget、brew、apt、pacman）搜索软件包，
+ * 回车确认后在终端中安装未安装的包，或升级有更新的包
+ */
+export class PackagesPlugin implements SearchPlugin {
+  id = 'packages'
+  name = '软件包'
+  description = '通过系统包管理器搜索、安装和升级软件包'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 70
+  searchPrefixes = ['pkg ', 'brew ', 'apt ', 'winget ', 'pacman ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'terminalCommand',
+        label: '终端命令',
+        description: '执行安装和升级的终端，{cmd} 会被替换为包管理器命令；留空使用系统默认终端',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      terminalCommand: ''
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Package)
+      logger.info('软件包插件初始化完成')
+    } catch (error) {
+      logger.warn('软件包图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    if (query.length < 2) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const { manager, packages } = await invoke<PackageSearchResult>('search_packages', { query })
+
+      return packages.slice(0, context.maxResults).map((pkg, index) => {
+        const action: PackageAction | null = pkg.upgradable ? 'upgrade' : pkg.installed ? null : 'install'
+        return {
+          id: `package-${manager}-${pkg.id}`,
+          title: pkg.name === pkg.id ? pkg.name : `${pkg.name} (${pkg.id})`,
+          description: [this.describeState(pkg), pkg.description, manager].filter(Boolean).join(' · '),
+          icon: this.icon,
+          priority: this.priority + 40 - index,
+          action: () => action ? this.runAction(pkg, action) : logger.info(`${pkg.name} 已是最新版本`),
+          source: this.id,
+          metadata: {
+            type: 'package',
+            manager,
+            installed: pkg.installed
+          }
+        }
+      })
+    } catch (error) {
+      const appError = handlePluginError('搜索软件包', error)
+      logger.error('搜索软件包失败', appError)
+      return []
+    }
+  }
+
+  private describeState(pkg: Package): string {
+    const version = pkg.version ? ` ${pkg.version}` : ''
+    if (pkg.upgradable) {
+      return `已安装${version}，回车升级`
+    }
+    return pkg.installed ? `已安装${version}` : '回车安装'
+  }
+
+  private async runAction(pkg: Package, action: PackageAction): Promise<void> {
+    const label = action === 'install' ? '安装' : '升级'
+    if (!confirm(`确定要${label} ${pkg.name} 吗？`)) {
+      logger.info(`已取消${label} ${pkg.name}`)
+      return
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('run_package_action', {
+        id: pkg.id,
+        action,
+        confirmed: true,
+        terminalCommand: this.settings.values.terminalCommand || null
+      })
+      logger.info(`正在终端中${label} ${pkg.name}`)
+    } catch (error) {
+      const appError = handlePluginError(`${label}软件包`, error)
+      logger.error(`${label}软件包失败`, appError)
+    }
+  }
+}
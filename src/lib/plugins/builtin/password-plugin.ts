@@ -0,0 +1,175 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface GeneratedSecret {
+  value: string
+  kind: 'password' | 'passphrase'
+  entropy_bits: number
+}
+
+/**
+ * 密码生成插件
+ *
+ * 用法：`pw:`、`pw 32`、`pw phrase 8`（只在输入前缀时生成）
+ */
+export class PasswordGeneratorPlugin implements SearchPlugin {
+  id = 'password'
+  name = '密码生成'
+  description = '生成随机密码和口令短语'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 90
+  searchPrefixes = ['pw:', 'pw ', 'password:']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'length',
+        label: '密码长度',
+        description: '默认生成的密码长度',
+        type: 'number' as const,
+        defaultValue: 20
+      },
+      {
+        key: 'includeSymbols',
+        label: '包含符号',
+        description: '密码中是否包含特殊符号',
+        type: 'boolean' as const,
+        defaultValue: true
+      },
+      {
+        key: 'excludeAmbiguous',
+        label: '排除易混淆字符',
+        description: '排除 I、l、1、O、0 等容易混淆的字符',
+        type: 'boolean' as const,
+        defaultValue: false
+      },
+      {
+        key: 'passphraseWords',
+        label: '口令短语单词数',
+        description: '默认生成的口令短语单词数量',
+        type: 'number' as const,
+        defaultValue: 6
+      },
+      {
+        key: 'wordlist',
+        label: '词表',
+        description: '口令短语使用的词表',
+        type: 'select' as const,
+        defaultValue: 'eff-large',
+        options: [
+          { label: 'EFF 大词表', value: 'eff-large' },
+          { label: 'EFF 短词表', value: 'eff-short' }
+        ]
+      },
+      {
+        key: 'customWordlistPath',
+        label: '自定义词表',
+        description: '自定义 diceware 词表文件路径，设置后优先使用',
+        type: 'file' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'clipboardClearSeconds',
+        label: '剪贴板自动清除（秒）',
+        description: '复制后自动清除剪贴板的时间，0 表示不清除',
+        type: 'number' as const,
+        defaultValue: 30
+      }
+    ],
+    values: {
+      length: 20,
+      includeSymbols: true,
+      excludeAmbiguous: false,
+      passphraseWords: 6,
+      wordlist: 'eff-large',
+      customWordlistPath: '',
+      clipboardClearSeconds: 30
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.KeyRound)
+      logger.info('密码生成插件初始化完成')
+    } catch (error) {
+      logger.warn('密码生成图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    if (!context.prefix) {
+      return []
+    }
+    const { keywords } = context
+    const phraseOnly = keywords.some(k => k === 'phrase' || k === 'words')
+    const count = keywords.map(k => parseInt(k, 10)).find(n => !isNaN(n))
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const secrets: GeneratedSecret[] = []
+
+      if (!phraseOnly) {
+        secrets.push(await invoke<GeneratedSecret>('generate_password', {
+          options: {
+            length: count ?? this.settings.values.length,
+            symbols: this.settings.values.includeSymbols,
+            exclude_ambiguous: this.settings.values.excludeAmbiguous
+          }
+        }))
+      }
+
+      secrets.push(await invoke<GeneratedSecret>('generate_passphrase', {
+        options: {
+          words: phraseOnly && count ? count : this.settings.values.passphraseWords,
+          wordlist: this.settings.values.customWordlistPath || this.settings.values.wordlist
+        }
+      }))
+
+      return secrets.map((secret, index) => ({
+        id: `password-${secret.kind}`,
+        title: secret.value,
+        description: `${secret.kind === 'password' ? '随机密码' : '口令短语'} • ${Math.round(secret.entropy_bits)} 位熵 • 回车复制`,
+        icon: this.icon,
+        priority: this.priority + (10 - index),
+        action: () => this.copySecret(secret.value),
+        source: this.id,
+        metadata: {
+          type: secret.kind,
+          entropyBits: secret.entropy_bits
+        }
+      }))
+    } catch (error) {
+      const appError = handlePluginError('生成密码', error)
+      logger.error('生成密码失败', appError)
+      return []
+    }
+  }
+
+  private async copySecret(value: string): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('password-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', {
+        text: value,
+        clearAfterSecs: this.settings.values.clipboardClearSeconds
+      })
+      logger.info('密码已复制到剪贴板')
+    } catch (error) {
+      const appError = handlePluginError('复制密码', error)
+      logger.error('复制密码失败', appError)
+    }
+  }
+}
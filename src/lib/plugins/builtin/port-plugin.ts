@@ -0,0 +1,124 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface PortListener {
+  port: number
+  protocol: 'tcp' | 'udp'
+  address: string
+  pid: number | null
+  process: string | null
+  exe: string | null
+}
+
+/**
+ * 端口占用插件
+ *
+ * 用法：`port 8080` 列出监听该端口的进程，回车结束进程，另有强制结束操作
+ */
+export class PortPlugin implements SearchPlugin {
+  id = 'port'
+  name = '端口占用'
+  description = '查找占用本机端口的进程并结束'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['port ']
+  prefixOnly = true
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Cable)
+      logger.info('端口占用插件初始化完成')
+    } catch (error) {
+      logger.warn('端口占用图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim().replace(/^:/, '')
+    const port = Number(query)
+    if (!/^\d+$/.test(query) || port < 1 || port > 65535) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const listeners = await invoke<PortListener[]>('find_port_listeners', { port })
+
+      if (listeners.length === 0) {
+        return [this.createResult(`port-free-${port}`, `端口 ${port} 未被占用`, '没有进程监听该端口', this.priority, async () => {})]
+      }
+
+      const results: SearchResultItem[] = []
+      listeners.forEach((listener, index) => {
+        const socket = `${listener.protocol.toUpperCase()} ${listener.address}:${listener.port}`
+        const pid = listener.pid
+        const name = listener.process ?? '未知进程'
+
+        if (pid === null) {
+          results.push(this.createResult(
+            `port-${listener.protocol}-${listener.address}`,
+            name,
+            `${socket} • 没有权限查看所属进程`,
+            this.priority - index,
+            async () => logger.warn(`没有权限查看占用端口 ${port} 的进程`)
+          ))
+          return
+        }
+
+        const description = `PID ${pid} • ${socket}${listener.exe ? ` • ${listener.exe}` : ''}`
+        results.push(this.createResult(
+          `port-${listener.protocol}-${pid}-${listener.address}`,
+          `结束 ${name}`,
+          `${description} • 回车结束进程`,
+          this.priority + 50 - index,
+          () => this.killProcess(pid, name, false)
+        ))
+        results.push(this.createResult(
+          `port-force-${listener.protocol}-${pid}-${listener.address}`,
+          `强制结束 ${name}`,
+          `${description} • 进程无响应时使用`,
+          this.priority - index,
+          () => this.killProcess(pid, name, true)
+        ))
+      })
+
+      return results
+    } catch (error) {
+      const appError = handlePluginError('查询端口占用', error)
+      logger.error('查询端口占用失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'port'
+      }
+    }
+  }
+
+  // 受保护的进程由后端拒绝结束
+  private async killProcess(pid: number, name: string, force: boolean): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('kill_process', { pid, force })
+      logger.success(`已${force ? '强制' : ''}结束进程: ${name} (${pid})`)
+    } catch (error) {
+      const appError = handlePluginError('结束进程', error)
+      logger.error('结束进程失败', appError)
+    }
+  }
+}
@@ -0,0 +1,126 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+type PowerAction = 'lock' | 'sleep' | 'restart' | 'shutdown' | 'logout' | 'hibernate'
+
+interface PowerActionInfo {
+  action: PowerAction
+  requires_confirmation: boolean
+}
+
+const POWER_ACTIONS: Record<PowerAction, { title: string; description: string; keywords: string[] }> = {
+  lock: { title: '锁定屏幕', description: '锁定当前会话', keywords: ['lock', 'lock screen', '锁屏', '锁定'] },
+  sleep: { title: '睡眠', description: '让电脑进入睡眠状态', keywords: ['sleep', 'suspend', '睡眠'] },
+  restart: { title: '重新启动', description: '重新启动电脑', keywords: ['restart', 'reboot', '重启'] },
+  shutdown: { title: '关机', description: '关闭电脑', keywords: ['shutdown', 'shut down', 'power off', 'poweroff', '关机'] },
+  logout: { title: '注销', description: '退出当前用户', keywords: ['logout', 'log out', 'sign out', '注销', '登出'] },
+  hibernate: { title: '休眠', description: '保存会话到磁盘并关机', keywords: ['hibernate', '休眠'] }
+}
+
+/**
+ * 电源插件
+ *
+ * 输入 `lock`、`sleep`、`restart`、`shutdown` 等直接执行系统电源操作
+ */
+export class PowerPlugin implements SearchPlugin {
+  id = 'power'
+  name = '电源'
+  description = '锁屏、睡眠、重启、关机、注销和休眠'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 75
+
+  settings = {
+    schema: [
+      {
+        key: 'confirmDestructive',
+        label: '执行前确认',
+        description: '重启、关机、注销和休眠前弹出确认',
+        type: 'boolean' as const,
+        defaultValue: true
+      }
+    ],
+    values: {
+      confirmDestructive: true
+    }
+  }
+
+  private supported: PowerActionInfo[] | null = null
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Power)
+      logger.info('电源插件初始化完成')
+    } catch (error) {
+      logger.warn('电源图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim().toLowerCase()
+    if (query.length < 2) {
+      return []
+    }
+
+    const actions = await this.getSupportedActions()
+    return actions
+      .filter(info => POWER_ACTIONS[info.action].keywords.some(keyword => keyword.startsWith(query)))
+      .map(info => {
+        const meta = POWER_ACTIONS[info.action]
+        const exact = meta.keywords.includes(query)
+        return {
+          id: `power-${info.action}`,
+          title: meta.title,
+          description: info.requires_confirmation ? `${meta.description}（需要确认）` : meta.description,
+          icon: this.icon,
+          priority: this.priority + (exact ? 40 : 10),
+          action: () => this.runAction(info),
+          source: this.id,
+          metadata: {
+            type: 'power',
+            action: info.action
+          }
+        }
+      })
+  }
+
+  private async getSupportedActions(): Promise<PowerActionInfo[]> {
+    if (this.supported) {
+      return this.supported
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      this.supported = await invoke<PowerActionInfo[]>('list_power_actions')
+    } catch (error) {
+      const appError = handlePluginError('获取电源操作', error)
+      logger.error('获取电源操作失败', appError)
+      return []
+    }
+    return this.supported
+  }
+
+  private async runAction(info: PowerActionInfo): Promise<void> {
+    const meta = POWER_ACTIONS[info.action]
+
+    if (info.requires_confirmation && this.settings.values.confirmDestructive) {
+      if (!confirm(`确定要${meta.title}吗？未保存的工作可能会丢失。`)) {
+        logger.info(`已取消电源操作: ${info.action}`)
+        return
+      }
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('run_power_action', { action: info.action, confirmed: true })
+      logger.info(`执行电源操作: ${info.action}`)
+    } catch (error) {
+      const appError = handlePluginError('执行电源操作', error)
+      logger.error('执行电源操作失败', appError)
+    }
+  }
+}
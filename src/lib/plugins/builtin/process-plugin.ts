@@ -0,0 +1,138 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface ProcessInfo {
+  pid: number
+  name: string
+  cpu_usage: number
+  memory: number
+  exe: string | null
+  protected: boolean
+}
+
+/**
+ * 进程插件
+ *
+ * 用法：`kill <进程名或 PID>` 列出匹配的进程，回车结束进程，另有强制结束操作
+ */
+export class ProcessPlugin implements SearchPlugin {
+  id = 'process'
+  name = '进程管理'
+  description = '搜索正在运行的进程并结束或强制结束'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['kill ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'showForceKill',
+        label: '显示强制结束',
+        description: '为每个进程额外显示强制结束（SIGKILL）操作',
+        type: 'boolean' as const,
+        defaultValue: true
+      }
+    ],
+    values: {
+      showForceKill: true
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Activity)
+      logger.info('进程插件初始化完成')
+    } catch (error) {
+      logger.warn('进程图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    if (!query) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const processes = await invoke<ProcessInfo[]>('search_processes', { query })
+
+      const results: SearchResultItem[] = []
+      processes.slice(0, context.maxResults).forEach((process, index) => {
+        const stats = `PID ${process.pid} • CPU ${process.cpu_usage.toFixed(1)}% • 内存 ${this.formatMemory(process.memory)}`
+
+        if (process.protected) {
+          results.push(this.createResult(
+            `process-${process.pid}`,
+            process.name,
+            `${stats} • 受保护的系统进程`,
+            this.priority - index,
+            async () => logger.warn(`不能结束受保护的进程: ${process.name}`)
+          ))
+          return
+        }
+
+        results.push(this.createResult(
+          `process-${process.pid}`,
+          `结束 ${process.name}`,
+          `${stats} • 回车结束进程`,
+          this.priority + 50 - index,
+          () => this.killProcess(process, false)
+        ))
+
+        if (this.settings.values.showForceKill) {
+          results.push(this.createResult(
+            `process-force-${process.pid}`,
+            `强制结束 ${process.name}`,
+            `${stats} • 进程无响应时使用`,
+            this.priority - index,
+            () => this.killProcess(process, true)
+          ))
+        }
+      })
+
+      return results
+    } catch (error) {
+      const appError = handlePluginError('搜索进程', error)
+      logger.error('搜索进程失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'process'
+      }
+    }
+  }
+
+  private formatMemory(bytes: number): string {
+    const mb = bytes / 1024 / 1024
+    return mb >= 1024 ? `${(mb / 1024).toFixed(1)} GB` : `${mb.toFixed(0)} MB`
+  }
+
+  private async killProcess(process: ProcessInfo, force: boolean): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('kill_process', { pid: process.pid, force })
+      logger.success(`已${force ? '强制' : ''}结束进程: ${process.name} (${process.pid})`)
+    } catch (error) {
+      const appError = handlePluginError('结束进程', error)
+      logger.error('结束进程失败', appError)
+    }
+  }
+}
@@ -0,0 +1,176 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface Quicklink {
+  id: number
+  keyword: string
+  name: string
+  url: string
+  created_at: number
+}
+
+interface QuicklinkMatch {
+  link: Quicklink
+  url: string | null
+}
+
+/**
+ * 快捷链接插件
+ *
+ * 用法：`ql add jira https://jira.example.com/browse/{query} Jira` 添加（关键词已存在时更新），
+ * 之后直接输入 `jira ABC-123` 回车打开；`ql` 列出所有快捷链接，`ql rm <关键词>` 删除
+ */
+export class QuicklinksPlugin implements SearchPlugin {
+  id = 'quicklinks'
+  name = '快捷链接'
+  description = '按关键词打开自定义的网址模板'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 90
+  searchPrefixes = ['ql ', 'quicklink ']
+  // 网址模板中包含 {query} 等字符
+  rawInput = true
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Link)
+      logger.info('快捷链接插件初始化完成')
+    } catch (error) {
+      logger.warn('快捷链接图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+
+    try {
+      if (!context.prefix) {
+        return /^(ql|quicklink)$/i.test(query) ? await this.listLinks('', false) : await this.matchLinks(query)
+      }
+
+      const [command, keyword, url, ...name] = query.split(/\s+/)
+      if (/^add$/i.test(command) && keyword && url) {
+        return [this.createAddResult(keyword, url, name.join(' '))]
+      }
+      if (/^(rm|del|delete)$/i.test(command)) {
+        return await this.listLinks(keyword ?? '', true)
+      }
+      return await this.listLinks(query, false)
+    } catch (error) {
+      const appError = handlePluginError('查找快捷链接', error)
+      logger.error('查找快捷链接失败', appError)
+      return []
+    }
+  }
+
+  private async matchLinks(query: string): Promise<SearchResultItem[]> {
+    if (!query) {
+      return []
+    }
+
+    const { invoke } = await import('@tauri-apps/api/core')
+    const matches = await invoke<QuicklinkMatch[]>('match_quicklinks', { query })
+
+    return matches.map((match, index) => {
+      const url = match.url
+      return this.createResult(
+        `quicklink-${match.link.id}`,
+        match.link.name,
+        url ? `${url} · 回车打开` : `${match.link.keyword} <内容> · 在关键词后输入内容`,
+        this.priority + (url ? 60 : 20) - index,
+        url ? () => this.openUrl(url) : async () => logger.warn(`请在 ${match.link.keyword} 后输入内容`)
+      )
+    })
+  }
+
+  private async listLinks(filter: string, remove: boolean): Promise<SearchResultItem[]> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    const links = await invoke<Quicklink[]>('list_quicklinks')
+    const keyword = filter.toLowerCase()
+
+    return links
+      .filter(link => !keyword || link.keyword.toLowerCase().includes(keyword) || link.name.toLowerCase().includes(keyword))
+      .map((link, index) => remove
+        ? this.createResult(
+          `quicklink-delete-${link.id}`,
+          `删除快捷链接：${link.keyword}`,
+          `${link.url} · 回车删除`,
+          this.priority + 40 - index,
+          () => this.deleteLink(link)
+        )
+        : this.createResult(
+          `quicklink-${link.id}`,
+          `${link.keyword} · ${link.name}`,
+          link.url.includes('{query}') ? `${link.url} · 输入 ${link.keyword} <内容> 使用` : `${link.url} · 回车打开`,
+          this.priority + 40 - index,
+          link.url.includes('{query}') ? async () => logger.warn(`请输入 ${link.keyword} <内容>`) : () => this.openUrl(link.url)
+        ))
+  }
+
+  // 关键词已存在时更新该链接
+  private createAddResult(keyword: string, url: string, name: string): SearchResultItem {
+    return this.createResult(
+      `quicklink-add-${keyword}`,
+      `保存快捷链接：${keyword}`,
+      `${url} · 回车保存`,
+      this.priority + 50,
+      async () => {
+        try {
+          const { invoke } = await import('@tauri-apps/api/core')
+          const links = await invoke<Quicklink[]>('list_quicklinks')
+          const existing = links.find(link => link.keyword.toLowerCase() === keyword.toLowerCase())
+          if (existing) {
+            await invoke('update_quicklink', { id: existing.id, keyword, name, url })
+            logger.success(`快捷链接已更新: ${keyword}`)
+          } else {
+            await invoke('add_quicklink', { keyword, name, url })
+            logger.success(`快捷链接已添加: ${keyword}`)
+          }
+        } catch (error) {
+          const appError = handlePluginError('保存快捷链接', error)
+          logger.error('保存快捷链接失败', appError)
+        }
+      }
+    )
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'quicklink'
+      }
+    }
+  }
+
+  private async deleteLink(link: Quicklink): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('delete_quicklink', { id: link.id })
+      logger.info(`快捷链接已删除: ${link.keyword}`)
+    } catch (error) {
+      const appError = handlePluginError('删除快捷链接', error)
+      logger.error('删除快捷链接失败', appError)
+    }
+  }
+
+  private async openUrl(url: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_url', { url })
+    } catch (error) {
+      const appError = handlePluginError('打开快捷链接', error)
+      logger.error('打开快捷链接失败', appError)
+    }
+  }
+}
@@ -0,0 +1,166 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface RegexGroup {
+  index: number
+  name: string | null
+  text: string | null
+}
+
+interface RegexMatch {
+  start: number
+  end: number
+  text: string
+  line: number
+  groups: RegexGroup[]
+}
+
+interface RegexTestResult {
+  total: number
+  matches: RegexMatch[]
+}
+
+const SAMPLE_SEPARATOR = '::'
+const MAX_MATCH_RESULTS = 20
+
+/**
+ * 正则测试插件
+ *
+ * 用法：`re <正则> :: <样本文本>` 测试匹配，省略 `:: <样本>` 时使用剪贴板中的文本；
+ * 写成 `/正则/i` 忽略大小写。列出每个匹配及捕获组，回车复制匹配文本，完整结果显示在预览中
+ */
+export class RegexPlugin implements SearchPlugin {
+  id = 'regex'
+  name = '正则测试'
+  description = '测试正则表达式的匹配和捕获组'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['re ', 'regex ']
+  prefixOnly = true
+  rawInput = true
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Regex)
+      logger.info('正则测试插件初始化完成')
+    } catch (error) {
+      logger.warn('正则测试图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const separator = context.query.indexOf(SAMPLE_SEPARATOR)
+    let pattern = (separator >= 0 ? context.query.slice(0, separator) : context.query).trim()
+    if (!pattern) {
+      return []
+    }
+
+    // /pattern/i 形式
+    let caseInsensitive = false
+    const literal = pattern.match(/^\/(.+)\/(i?)$/)
+    if (literal) {
+      pattern = literal[1]
+      caseInsensitive = literal[2] === 'i'
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const fromClipboard = separator < 0
+      const sample = fromClipboard
+        ? await invoke<string | null>('read_clipboard_text')
+        : context.query.slice(separator + SAMPLE_SEPARATOR.length).trim()
+      if (!sample) {
+        return [this.createResult('regex-no-sample', '没有样本文本', `在 ${SAMPLE_SEPARATOR} 后输入样本，或先复制要测试的文本`, this.priority, async () => {})]
+      }
+
+      let result: RegexTestResult
+      try {
+        result = await invoke<RegexTestResult>('test_regex', { pattern, sample, caseInsensitive })
+      } catch (error) {
+        return [this.createResult('regex-invalid', String(error), pattern, this.priority + 30, async () => {})]
+      }
+
+      const source = fromClipboard ? '剪贴板' : '样本'
+      const preview = async () => this.formatPreview(result)
+      const summary = this.createResult(
+        'regex-summary',
+        result.total > 0 ? `${result.total} 个匹配` : '没有匹配',
+        `/${pattern}/${caseInsensitive ? 'i' : ''} · ${source} · 回车复制正则`,
+        this.priority + 30,
+        () => this.copy(pattern, '正则')
+      )
+      summary.preview = preview
+
+      const matches = result.matches.slice(0, MAX_MATCH_RESULTS).map((match, index) => {
+        const groups = match.groups.map(group => `${group.name ?? `$${group.index}`}=${group.text ?? '∅'}`).join(' ')
+        const item = this.createResult(
+          `regex-match-${index}`,
+          match.text || '（空匹配）',
+          `第 ${match.line} 行 · 位置 ${match.start}-${match.end}${groups ? ` · ${groups}` : ''}`,
+          this.priority + 29 - index,
+          () => this.copy(match.text, '匹配文本')
+        )
+        item.preview = preview
+        return item
+      })
+      return [summary, ...matches]
+    } catch (error) {
+      const appError = handlePluginError('测试正则表达式', error)
+      logger.error('测试正则表达式失败', appError)
+      return []
+    }
+  }
+
+  private formatPreview(result: RegexTestResult): string {
+    if (result.total === 0) {
+      return '没有匹配'
+    }
+    const lines = result.matches.map((match, index) => {
+      const groups = match.groups.map(group => `    ${group.name ? `${group.name} ($${group.index})` : `$${group.index}`}: ${group.text ?? '（未匹配）'}`)
+      return [`#${index + 1} 第 ${match.line} 行 [${match.start}, ${match.end}): ${match.text}`, ...groups].join('\n')
+    })
+    if (result.total > result.matches.length) {
+      lines.push(`…… 另有 ${result.total - result.matches.length} 个匹配未列出`)
+    }
+    return lines.join('\n')
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'regex'
+      }
+    }
+  }
+
+  private async copy(text: string, label: string): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('regex-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.success(`已复制${label}`)
+    } catch (error) {
+      const appError = handlePluginError(`复制${label}`, error)
+      logger.error(`复制${label}失败`, appError)
+    }
+  }
+}
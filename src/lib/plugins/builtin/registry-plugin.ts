@@ -0,0 +1,143 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+type Registry = 'crates' | 'npm'
+
+interface RegistryPackage {
+  registry: Registry
+  name: string
+  version: string
+  description: string | null
+  url: string
+  downloads: number | null
+}
+
+const REGISTRY_NAMES: Record<Registry, string> = {
+  crates: 'crates.io',
+  npm: 'npm'
+}
+
+/**
+ * 包仓库插件
+ *
+ * 用法：`crate <包名>` 查找 crates.io，`npm <包名>` 查找 npm，回车打开包页面；
+ * 在包名前加 `dep`（如 `crate dep serde`）回车复制 Cargo.toml / package.json 的依赖行
+ */
+export class RegistryPlugin implements SearchPlugin {
+  id = 'registry'
+  name = '包仓库'
+  description = '在 crates.io 和 npm 中查找包的最新版本'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 70
+  searchPrefixes = ['crate ', 'crates ', 'npm ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'npmVersionRange',
+        label: 'npm 版本范围',
+        description: '复制 package.json 依赖行时使用的版本前缀',
+        type: 'select' as const,
+        defaultValue: '^',
+        options: [
+          { label: '^（兼容的次版本）', value: '^' },
+          { label: '~（兼容的修订版本）', value: '~' },
+          { label: '精确版本', value: '' }
+        ]
+      }
+    ],
+    values: {
+      npmVersionRange: '^'
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Package)
+      logger.info('包仓库插件初始化完成')
+    } catch (error) {
+      logger.warn('包仓库图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const prefix = context.prefix?.trim().toLowerCase()
+    const [command, ...rest] = context.query.trim().split(/\s+/)
+    const dependency = /^dep$/i.test(command)
+    const name = dependency ? rest.join(' ') : context.query.trim()
+    if (!name) {
+      return []
+    }
+
+    const registry: Registry = prefix === 'npm' ? 'npm' : 'crates'
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const packages = await invoke<RegistryPackage[]>('search_package_registry', { query: name, registry })
+
+      return packages.slice(0, context.maxResults).map((pkg, index) => ({
+        id: `registry-${pkg.registry}-${pkg.name}`,
+        title: `${pkg.name} ${pkg.version}`,
+        description: [
+          REGISTRY_NAMES[pkg.registry],
+          pkg.description,
+          dependency ? `回车复制 ${this.dependencyLine(pkg)}` : '回车打开包页面'
+        ].filter(Boolean).join(' · '),
+        icon: this.icon,
+        priority: this.priority + 40 - index,
+        action: () => dependency ? this.copyDependency(pkg) : this.openUrl(pkg.url),
+        source: this.id,
+        metadata: {
+          type: 'package',
+          registry: pkg.registry
+        }
+      }))
+    } catch (error) {
+      const appError = handlePluginError('查找包', error)
+      logger.error('查找包失败', appError)
+      return []
+    }
+  }
+
+  private dependencyLine(pkg: RegistryPackage): string {
+    if (pkg.registry === 'crates') {
+      return `${pkg.name} = "${pkg.version}"`
+    }
+    return `"${pkg.name}": "${this.settings.values.npmVersionRange}${pkg.version}"`
+  }
+
+  private async openUrl(url: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_url', { url })
+    } catch (error) {
+      const appError = handlePluginError('打开包页面', error)
+      logger.error('打开包页面失败', appError)
+    }
+  }
+
+  private async copyDependency(pkg: RegistryPackage): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('registry-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text: this.dependencyLine(pkg) })
+      logger.success(`已复制 ${pkg.name} 的依赖行`)
+    } catch (error) {
+      const appError = handlePluginError('复制依赖行', error)
+      logger.error('复制依赖行失败', appError)
+    }
+  }
+}
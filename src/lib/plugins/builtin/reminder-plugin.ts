@@ -0,0 +1,175 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface Reminder {
+  id: number
+  message: string
+  due_at: number
+  created_at: number
+  delivered: boolean
+}
+
+interface ParsedReminder {
+  message: string
+  due_at: number
+  due_text: string
+}
+
+/**
+ * 提醒插件
+ *
+ * 用法：`remind me friday 9am standup`、`remind in 10m stretch`，
+ * 仅输入 `remind` 列出提醒（回车删除，已送达的提醒可以稍后提醒）
+ */
+export class ReminderPlugin implements SearchPlugin {
+  id = 'reminder'
+  name = '提醒'
+  description = '用自然语言创建提醒，到期后发送系统通知'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 88
+  searchPrefixes = ['remind ']
+
+  settings = {
+    schema: [
+      {
+        key: 'snoozeMinutes',
+        label: '稍后提醒（分钟）',
+        description: '已送达的提醒再次提醒的间隔',
+        type: 'number' as const,
+        defaultValue: 10
+      }
+    ],
+    values: {
+      snoozeMinutes: 10
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Bell)
+      logger.info('提醒插件初始化完成')
+    } catch (error) {
+      logger.warn('提醒图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 remind
+    if (!context.prefix && !/^(remind)$/i.test(context.query.trim())) {
+      return []
+    }
+    // 前缀为 "remind "，去掉 "remind me ..." 中剩下的 "me"
+    const text = context.prefix ? context.query.trim().replace(/^me\b\s*/i, '') : ''
+
+    if (!text) {
+      return this.listReminders()
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const parsed = await invoke<ParsedReminder>('preview_reminder', { text })
+
+      return [this.createResult(
+        `reminder-add-${text}`,
+        `提醒：${parsed.message}`,
+        `${parsed.due_text} • 回车保存`,
+        () => this.addReminder(text)
+      )]
+    } catch (error) {
+      // 输入未完成时解析失败属于正常情况
+      logger.debug('提醒解析失败', error)
+      return []
+    }
+  }
+
+  private async listReminders(): Promise<SearchResultItem[]> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const reminders = await invoke<Reminder[]>('list_reminders')
+
+      return reminders.map(reminder => reminder.delivered
+        ? this.createResult(
+          `reminder-${reminder.id}`,
+          `${reminder.message} • 已提醒`,
+          `回车 ${this.settings.values.snoozeMinutes} 分钟后再次提醒`,
+          () => this.snoozeReminder(reminder.id)
+        )
+        : this.createResult(
+          `reminder-${reminder.id}`,
+          reminder.message,
+          `${this.formatDue(reminder.due_at)} • 回车删除`,
+          () => this.deleteReminder(reminder.id)
+        ))
+    } catch (error) {
+      const appError = handlePluginError('获取提醒列表', error)
+      logger.error('获取提醒列表失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority: this.priority + 50,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'reminder'
+      }
+    }
+  }
+
+  private formatDue(dueAt: number): string {
+    return new Date(dueAt * 1000).toLocaleString('zh-CN', {
+      month: 'numeric',
+      day: 'numeric',
+      weekday: 'short',
+      hour: '2-digit',
+      minute: '2-digit'
+    })
+  }
+
+  private async addReminder(text: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const reminder = await invoke<Reminder>('add_reminder', { text })
+      logger.success(`提醒已保存: ${reminder.message}`)
+    } catch (error) {
+      const appError = handlePluginError('保存提醒', error)
+      logger.error('保存提醒失败', appError)
+    }
+  }
+
+  private async deleteReminder(id: number): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('delete_reminder', { id })
+      logger.info(`提醒已删除: ${id}`)
+    } catch (error) {
+      const appError = handlePluginError('删除提醒', error)
+      logger.error('删除提醒失败', appError)
+    }
+  }
+
+  private async snoozeReminder(id: number): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke<Reminder>('snooze_reminder', {
+        id,
+        minutes: this.settings.values.snoozeMinutes
+      })
+      logger.info(`提醒已推迟: ${id}`)
+    } catch (error) {
+      const appError = handlePluginError('推迟提醒', error)
+      logger.error('推迟提醒失败', appError)
+    }
+  }
+}
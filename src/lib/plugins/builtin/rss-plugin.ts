@@ -0,0 +1,219 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface Feed {
+  id: number
+  url: string
+  title: string
+  created_at: number
+  fetched_at: number | null
+  last_error: string | null
+  unread_count: number
+}
+
+interface FeedItem {
+  id: number
+  feed_id: number
+  feed_title: string
+  title: string
+  link: string | null
+  summary: string | null
+  published_at: number | null
+}
+
+/**
+ * RSS 订阅插件
+ *
+ * 用法：`rss` 列出未读条目（后面可加关键词过滤），回车在浏览器中打开并标为已读；
+ * `rss read` 回车只标为已读，`rss add <地址>` 添加订阅源，`rss feeds` 列出订阅源，
+ * `rss rm <名称>` 删除订阅源，`rss refresh` 立即抓取。订阅源每 30 分钟在后台自动抓取
+ */
+export class RssPlugin implements SearchPlugin {
+  id = 'rss'
+  name = 'RSS 订阅'
+  description = '订阅 RSS/Atom 并浏览未读条目'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 70
+  searchPrefixes = ['rss ']
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Rss)
+      logger.info('RSS 订阅插件初始化完成')
+    } catch (error) {
+      logger.warn('RSS 订阅图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 rss
+    if (!context.prefix && !/^(rss)$/i.test(context.query.trim())) {
+      return []
+    }
+    const query = context.prefix ? context.query.trim() : ''
+    const [command, ...rest] = query.split(/\s+/)
+    const argument = rest.join(' ')
+
+    try {
+      if (/^add$/i.test(command)) {
+        return argument ? [this.createAddResult(argument)] : []
+      }
+      if (/^feeds$/i.test(command)) {
+        return await this.listFeeds(argument, false)
+      }
+      if (/^(rm|del|delete)$/i.test(command)) {
+        return await this.listFeeds(argument, true)
+      }
+      if (/^refresh$/i.test(command) && !argument) {
+        return [this.createResult('rss-refresh', '立即抓取所有订阅源', '回车刷新', this.priority + 50, () => this.refresh())]
+      }
+      if (/^read$/i.test(command)) {
+        return await this.listItems(argument, true)
+      }
+      return await this.listItems(query, false)
+    } catch (error) {
+      const appError = handlePluginError('读取订阅', error)
+      logger.error('读取订阅失败', appError)
+      return []
+    }
+  }
+
+  private async listItems(filter: string, markOnly: boolean): Promise<SearchResultItem[]> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    const needle = filter.toLowerCase()
+    const items = (await invoke<FeedItem[]>('list_unread_feed_items', { limit: null }))
+      .filter(item => !needle || `${item.title} ${item.feed_title}`.toLowerCase().includes(needle))
+
+    if (items.length === 0) {
+      return [this.createResult('rss-empty', '没有未读条目', filter ? `没有匹配「${filter}」的未读条目` : '使用 rss add <地址> 添加订阅源', this.priority, async () => {})]
+    }
+
+    const results = items.map((item, index) => {
+      const date = item.published_at ? new Date(item.published_at * 1000).toLocaleString() : ''
+      const hint = markOnly || !item.link ? '回车标为已读' : '回车打开并标为已读'
+      const result = this.createResult(
+        `rss-item-${item.id}`,
+        item.title,
+        [item.feed_title, date, hint].filter(Boolean).join(' · '),
+        this.priority + 40 - Math.min(index, 30),
+        () => markOnly ? this.markRead([item.id]) : this.open(item)
+      )
+      result.preview = async () => [item.title, item.link ?? '', '', item.summary ?? ''].join('\n').trim()
+      return result
+    })
+
+    results.push(this.createResult(
+      'rss-mark-all',
+      `全部标为已读（${items.length} 条）`,
+      filter ? `标记所有匹配「${filter}」的未读条目` : '标记所有未读条目',
+      this.priority - 20,
+      () => this.markRead(items.map(item => item.id))
+    ))
+    return results
+  }
+
+  private async listFeeds(filter: string, remove: boolean): Promise<SearchResultItem[]> {
+    const { invoke } = await import('@tauri-apps/api/core')
+    const needle = filter.toLowerCase()
+    const feeds = (await invoke<Feed[]>('list_feeds'))
+      .filter(feed => !needle || `${feed.title} ${feed.url}`.toLowerCase().includes(needle))
+
+    if (feeds.length === 0) {
+      return [this.createResult('rss-no-feeds', '没有订阅源', '使用 rss add <地址> 添加订阅源', this.priority, async () => {})]
+    }
+
+    return feeds.map((feed, index) => {
+      const status = feed.last_error ? `抓取失败: ${feed.last_error}` : `${feed.unread_count} 条未读`
+      return this.createResult(
+        `rss-feed-${feed.id}`,
+        remove ? `删除订阅源: ${feed.title}` : feed.title,
+        `${feed.url} · ${status}`,
+        this.priority + 40 - index,
+        () => remove ? this.deleteFeed(feed) : this.openUrl(feed.url)
+      )
+    })
+  }
+
+  private createAddResult(url: string): SearchResultItem {
+    return this.createResult(`rss-add-${url}`, `添加订阅源: ${url}`, '回车抓取并添加', this.priority + 50, async () => {
+      try {
+        const { invoke } = await import('@tauri-apps/api/core')
+        const feed = await invoke<Feed>('add_feed', { url })
+        logger.success(`已添加订阅源: ${feed.title}（${feed.unread_count} 条未读）`)
+      } catch (error) {
+        const appError = handlePluginError('添加订阅源', error)
+        logger.error('添加订阅源失败', appError)
+      }
+    })
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'rss'
+      }
+    }
+  }
+
+  private async open(item: FeedItem): Promise<void> {
+    if (item.link) {
+      await this.openUrl(item.link)
+    }
+    await this.markRead([item.id])
+  }
+
+  private async openUrl(url: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_url', { url })
+    } catch (error) {
+      const appError = handlePluginError('打开链接', error)
+      logger.error('打开链接失败', appError)
+    }
+  }
+
+  private async markRead(ids: number[]): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('mark_feed_items_read', { ids })
+      logger.info(`已将 ${ids.length} 条条目标为已读`)
+    } catch (error) {
+      const appError = handlePluginError('标记已读', error)
+      logger.error('标记已读失败', appError)
+    }
+  }
+
+  private async deleteFeed(feed: Feed): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('delete_feed', { id: feed.id })
+      logger.success(`已删除订阅源: ${feed.title}`)
+    } catch (error) {
+      const appError = handlePluginError('删除订阅源', error)
+      logger.error('删除订阅源失败', appError)
+    }
+  }
+
+  private async refresh(): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const added = await invoke<number>('refresh_feeds')
+      logger.success(`订阅源已刷新，新增 ${added} 条`)
+    } catch (error) {
+      const appError = handlePluginError('刷新订阅源', error)
+      logger.error('刷新订阅源失败', appError)
+    }
+  }
+}
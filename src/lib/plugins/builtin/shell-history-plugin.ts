@@ -0,0 +1,121 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface HistoryEntry {
+  command: string
+  shell: 'bash' | 'zsh' | 'fish'
+  timestamp: number | null
+}
+
+/**
+ * Shell 历史插件
+ *
+ * 用法：`hist <关键词>` 搜索 bash / zsh / fish 的历史命令，回车复制；
+ * `hist run <关键词>` 回车在终端中重新运行
+ */
+export class ShellHistoryPlugin implements SearchPlugin {
+  id = 'shell-history'
+  name = 'Shell 历史'
+  description = '搜索 bash、zsh、fish 的历史命令'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 75
+  searchPrefixes = ['hist ', 'history ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'terminalCommand',
+        label: '终端命令',
+        description: '重新运行命令时使用的终端，{cmd} 会被替换为要执行的命令；留空使用系统默认终端',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      terminalCommand: ''
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.History)
+      logger.info('Shell 历史插件初始化完成')
+    } catch (error) {
+      logger.warn('Shell 历史图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    let query = context.query.trim()
+    const run = /^run\b/i.test(query)
+    if (run) {
+      query = query.replace(/^run\s*/i, '')
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const entries = await invoke<HistoryEntry[]>('search_shell_history', {
+        query,
+        limit: context.maxResults ?? 20
+      })
+
+      return entries.map((entry, index) => ({
+        id: `shell-history-${index}`,
+        title: entry.command.replace(/\n/g, ' ⏎ '),
+        description: `${entry.shell}${entry.timestamp ? ` · ${new Date(entry.timestamp * 1000).toLocaleString()}` : ''} · ${run ? '回车在终端中运行' : '回车复制'}`,
+        icon: this.icon,
+        priority: this.priority + 40 - index,
+        action: () => run ? this.runInTerminal(entry) : this.copy(entry),
+        source: this.id,
+        metadata: {
+          type: 'shell-history',
+          shell: entry.shell
+        }
+      }))
+    } catch (error) {
+      const appError = handlePluginError('搜索 Shell 历史', error)
+      logger.error('搜索 Shell 历史失败', appError)
+      return []
+    }
+  }
+
+  private async copy(entry: HistoryEntry): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('shell-history-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text: entry.command })
+      logger.success('已复制历史命令')
+    } catch (error) {
+      const appError = handlePluginError('复制历史命令', error)
+      logger.error('复制历史命令失败', appError)
+    }
+  }
+
+  // 用记录该命令的 shell 运行，fish 语法的命令不会被 bash 误解
+  private async runInTerminal(entry: HistoryEntry): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('run_shell_in_terminal', {
+        commandLine: entry.command,
+        shell: entry.shell,
+        terminalCommand: this.settings.values.terminalCommand || null
+      })
+    } catch (error) {
+      const appError = handlePluginError('运行历史命令', error)
+      logger.error('运行历史命令失败', appError)
+    }
+  }
+}
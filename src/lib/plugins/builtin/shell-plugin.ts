@@ -0,0 +1,320 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { pluginManager } from '../../search-plugin-manager'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface ShellOutput {
+  id: string
+  stream: 'stdout' | 'stderr'
+  line: string
+}
+
+interface ShellExit {
+  id: string
+  code: number | null
+}
+
+interface ShellRun {
+  id: string
+  command: string
+  lines: ShellOutput[]
+  running: boolean
+  code: number | null
+}
+
+// 只保留最近的输出，避免长时间运行的命令占用过多内存
+const MAX_OUTPUT_LINES = 500
+// 结果列表中显示的输出行数
+const PREVIEW_LINES = 8
+// 输出很快时合并刷新，避免每行都触发一次界面更新
+const REFRESH_THROTTLE_MS = 200
+// 管道、命令串联、重定向、变量和子命令替换，包含这些时白名单不生效
+const SHELL_OPERATORS = /[;&|`$<>()\n]/
+
+/**
+ * Shell 命令插件
+ *
+ * 用法：`> <命令>` 回车在后台运行并实时显示输出，也可以在终端中运行，支持管道和重定向
+ * （包含这些时总是需要确认）；只输入 `>` 查看最近一次运行的输出并复制
+ */
+export class ShellPlugin implements SearchPlugin {
+  id = 'shell'
+  name = 'Shell 命令'
+  description = '运行 shell 命令并查看输出'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 90
+  searchPrefixes = ['>']
+  prefixOnly = true
+  // 管道、重定向等字符需要原样交给 shell
+  rawInput = true
+
+  settings = {
+    schema: [
+      {
+        key: 'shell',
+        label: 'Shell',
+        description: '运行命令使用的 shell，留空时使用 $SHELL（Windows 为 cmd）',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'terminalCommand',
+        label: '终端命令',
+        description: '"在终端中运行"使用的终端，{cmd} 会被替换为要执行的命令；留空使用系统默认终端',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'confirmMode',
+        label: '运行前确认',
+        type: 'select' as const,
+        defaultValue: 'unlisted',
+        options: [
+          { label: '总是确认', value: 'always' },
+          { label: '白名单以外的命令需要确认', value: 'unlisted' },
+          { label: '从不确认', value: 'never' }
+        ]
+      },
+      {
+        key: 'allowedCommands',
+        label: '免确认命令',
+        description: '逗号分隔，命令以其中任意一项开头时不需要确认（包含管道、重定向等时仍需确认）',
+        type: 'string' as const,
+        defaultValue: 'ls, pwd, echo, date, whoami, uptime, df, git status, git log'
+      }
+    ],
+    values: {
+      shell: '',
+      terminalCommand: '',
+      confirmMode: 'unlisted',
+      allowedCommands: 'ls, pwd, echo, date, whoami, uptime, df, git status, git log'
+    }
+  }
+
+  private lastRun: ShellRun | null = null
+  private unlisten: Array<() => void> = []
+  private refreshTimer: ReturnType<typeof setTimeout> | null = null
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.SquareTerminal)
+      logger.info('Shell 命令插件初始化完成')
+    } catch (error) {
+      logger.warn('Shell 命令图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async destroy(): Promise<void> {
+    this.unlisten.forEach(unlisten => unlisten())
+    this.unlisten = []
+    if (this.refreshTimer) {
+      clearTimeout(this.refreshTimer)
+      this.refreshTimer = null
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const command = context.query.trim()
+    const results: SearchResultItem[] = []
+
+    if (command) {
+      results.push(
+        this.createResult('shell-run', `运行 ${command}`, this.needsConfirmation(command) ? '在后台运行并显示输出（需要确认）' : '在后台运行并显示输出', this.priority + 50, () => this.run(command)),
+        this.createResult('shell-terminal', '在终端中运行', command, this.priority + 45, () => this.runInTerminal(command))
+      )
+    }
+
+    // 最近一次运行的输出
+    const run = this.lastRun
+    if (run && (!command || run.command === command)) {
+      results.push(...this.outputResults(run))
+    } else if (!command) {
+      results.push(this.createResult('shell-hint', '输入要运行的命令', '例如 > git status', this.priority, async () => {}))
+    }
+
+    return results
+  }
+
+  private outputResults(run: ShellRun): SearchResultItem[] {
+    const status = run.running ? '运行中' : run.code === 0 ? '已完成' : `退出码 ${run.code ?? '（被终止）'}`
+    const results = [
+      this.createResult(
+        'shell-status',
+        run.running ? `中止 ${run.command}` : `复制输出：${run.command}`,
+        `${status} · ${run.lines.length} 行输出`,
+        this.priority + 40,
+        () => run.running ? this.kill(run) : this.copyOutput(run)
+      )
+    ]
+
+    run.lines.slice(-PREVIEW_LINES).forEach((output, index) => {
+      results.push(this.createResult(
+        `shell-line-${index}`,
+        output.line || ' ',
+        output.stream === 'stderr' ? '标准错误 · 回车复制全部输出' : '回车复制全部输出',
+        this.priority + 30 - index,
+        () => this.copyOutput(run)
+      ))
+    })
+
+    return results
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'shell'
+      }
+    }
+  }
+
+  private needsConfirmation(command: string): boolean {
+    switch (this.settings.values.confirmMode) {
+      case 'never':
+        return false
+      case 'always':
+        return true
+      default:
+        if (SHELL_OPERATORS.test(command)) {
+          return true
+        }
+        return !this.settings.values.allowedCommands
+          .split(',')
+          .map(entry => entry.trim())
+          .filter(Boolean)
+          .some(entry => command === entry || command.startsWith(`${entry} `))
+    }
+  }
+
+  private async subscribe(): Promise<void> {
+    if (this.unlisten.length > 0) {
+      return
+    }
+
+    const { listen } = await import('@tauri-apps/api/event')
+    this.unlisten.push(
+      await listen<ShellOutput>('shell-output', event => {
+        const run = this.lastRun
+        if (!run || run.id !== event.payload.id) {
+          return
+        }
+        run.lines.push(event.payload)
+        if (run.lines.length > MAX_OUTPUT_LINES) {
+          run.lines.splice(0, run.lines.length - MAX_OUTPUT_LINES)
+        }
+        this.scheduleRefresh()
+      }),
+      await listen<ShellExit>('shell-exit', event => {
+        const run = this.lastRun
+        if (!run || run.id !== event.payload.id) {
+          return
+        }
+        run.running = false
+        run.code = event.payload.code
+        logger.info(`命令已结束（退出码 ${event.payload.code ?? '无'}）: ${run.command}`)
+        if (this.refreshTimer) {
+          clearTimeout(this.refreshTimer)
+          this.refreshTimer = null
+        }
+        pluginManager.notifyResultsChanged(this.id)
+      })
+    )
+  }
+
+  private scheduleRefresh(): void {
+    if (this.refreshTimer) {
+      return
+    }
+    this.refreshTimer = setTimeout(() => {
+      this.refreshTimer = null
+      pluginManager.notifyResultsChanged(this.id)
+    }, REFRESH_THROTTLE_MS)
+  }
+
+  private async run(command: string): Promise<void> {
+    if (this.needsConfirmation(command) && !confirm(`确定要运行以下命令吗？\n\n${command}`)) {
+      logger.info(`已取消运行命令: ${command}`)
+      return
+    }
+
+    try {
+      await this.subscribe()
+      const run: ShellRun = { id: `shell-${Date.now()}`, command, lines: [], running: true, code: null }
+      this.lastRun = run
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('run_shell_command', {
+        id: run.id,
+        commandLine: command,
+        shell: this.settings.values.shell || null
+      })
+      logger.info(`正在运行命令: ${command}，输入 > 查看输出`)
+    } catch (error) {
+      if (this.lastRun) {
+        this.lastRun.running = false
+      }
+      const appError = handlePluginError('运行命令', error)
+      logger.error('运行命令失败', appError)
+    }
+  }
+
+  private async runInTerminal(command: string): Promise<void> {
+    if (this.needsConfirmation(command) && !confirm(`确定要在终端中运行以下命令吗？\n\n${command}`)) {
+      logger.info(`已取消运行命令: ${command}`)
+      return
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('run_shell_in_terminal', {
+        commandLine: command,
+        shell: this.settings.values.shell || null,
+        terminalCommand: this.settings.values.terminalCommand || null
+      })
+    } catch (error) {
+      const appError = handlePluginError('在终端中运行命令', error)
+      logger.error('在终端中运行命令失败', appError)
+    }
+  }
+
+  private async kill(run: ShellRun): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('kill_shell_command', { id: run.id })
+      logger.info(`已中止命令: ${run.command}`)
+    } catch (error) {
+      const appError = handlePluginError('中止命令', error)
+      logger.error('中止命令失败', appError)
+    }
+  }
+
+  private async copyOutput(run: ShellRun): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('shell-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text: run.lines.map(output => output.line).join('\n') })
+      logger.success(`已复制 ${run.lines.length} 行输出`)
+    } catch (error) {
+      const appError = handlePluginError('复制命令输出', error)
+      logger.error('复制命令输出失败', appError)
+    }
+  }
+}
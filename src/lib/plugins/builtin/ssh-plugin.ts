@@ -0,0 +1,131 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface SshHost {
+  alias: string
+  hostname: string | null
+  user: string | null
+  port: number | null
+  source: 'config' | 'known_hosts'
+}
+
+/**
+ * SSH 主机插件
+ *
+ * 用法：`ssh <主机>` 列出 ~/.ssh/config 和 known_hosts 中的主机，回车在终端中连接
+ */
+export class SshPlugin implements SearchPlugin {
+  id = 'ssh'
+  name = 'SSH 主机'
+  description = '从 SSH 配置中选择主机并在终端中连接'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['ssh ']
+
+  settings = {
+    schema: [
+      {
+        key: 'terminalCommand',
+        label: '终端命令',
+        description: '打开终端的命令，{cmd} 会被替换为 ssh 命令，例如 kitty -e {cmd}；留空使用系统默认终端',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'hostCommands',
+        label: '按主机指定终端命令',
+        description: '格式为 主机=终端命令，多条用 ; 分隔，主机支持 * 通配符，例如 prod-*=wezterm start -- {cmd}',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'includeKnownHosts',
+        label: '包含 known_hosts 中的主机',
+        type: 'boolean' as const,
+        defaultValue: true
+      }
+    ],
+    values: {
+      terminalCommand: '',
+      hostCommands: '',
+      includeKnownHosts: true
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Server)
+      logger.info('SSH 主机插件初始化完成')
+    } catch (error) {
+      logger.warn('SSH 主机图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim().toLowerCase()
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const hosts = await invoke<SshHost[]>('list_ssh_hosts')
+
+      return hosts
+        .filter(host => this.settings.values.includeKnownHosts || host.source === 'config')
+        .filter(host => !query || host.alias.toLowerCase().includes(query) || host.hostname?.toLowerCase().includes(query))
+        .slice(0, context.maxResults)
+        .map((host, index) => ({
+          id: `ssh-${host.source}-${host.alias}-${host.port ?? ''}`,
+          title: `ssh ${host.alias}`,
+          description: this.describe(host),
+          icon: this.icon,
+          priority: this.priority + (host.alias.toLowerCase() === query ? 60 : 40) - index,
+          action: () => this.connect(host),
+          source: this.id,
+          metadata: {
+            type: 'ssh',
+            host: host.alias
+          }
+        }))
+    } catch (error) {
+      const appError = handlePluginError('读取 SSH 主机', error)
+      logger.error('读取 SSH 主机失败', appError)
+      return []
+    }
+  }
+
+  private describe(host: SshHost): string {
+    const target = `${host.user ? `${host.user}@` : ''}${host.hostname ?? host.alias}${host.port ? `:${host.port}` : ''}`
+    return host.source === 'config' ? target : `${target}（known_hosts）`
+  }
+
+  // 按主机匹配的终端命令优先，其次是默认终端命令
+  private terminalCommandFor(alias: string): string | null {
+    for (const rule of this.settings.values.hostCommands.split(';')) {
+      const index = rule.indexOf('=')
+      if (index <= 0) {
+        continue
+      }
+      const pattern = rule.slice(0, index).trim()
+      const regex = new RegExp(`^${pattern.replace(/[.+?^${}()|[\]\\]/g, '\\$&').replace(/\*/g, '.*')}$`, 'i')
+      if (regex.test(alias)) {
+        return rule.slice(index + 1).trim()
+      }
+    }
+    return this.settings.values.terminalCommand.trim() || null
+  }
+
+  private async connect(host: SshHost): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('open_ssh', { host, terminalCommand: this.terminalCommandFor(host.alias) })
+      logger.info(`已打开 SSH 连接: ${host.alias}`)
+    } catch (error) {
+      const appError = handlePluginError('打开 SSH 连接', error)
+      logger.error('打开 SSH 连接失败', appError)
+    }
+  }
+}
@@ -0,0 +1,104 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface StackOverflowQuestion {
+  id: number
+  title: string
+  url: string
+  score: number
+  answer_count: number
+  is_answered: boolean
+  accepted: boolean
+  tags: string[]
+}
+
+/**
+ * Stack Overflow 插件
+ *
+ * 用法：`so <关键词>` 搜索 Stack Overflow 上的问题，回车在浏览器中打开；
+ * 结果缓存 30 分钟，未注册应用每天只有 300 次请求配额
+ */
+export class StackOverflowPlugin implements SearchPlugin {
+  id = 'stackoverflow'
+  name = 'Stack Overflow'
+  description = '搜索 Stack Overflow 上的问题'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 65
+  searchPrefixes = ['so ', 'stackoverflow ']
+  prefixOnly = true
+
+  settings = {
+    schema: [
+      {
+        key: 'answeredOnly',
+        label: '只显示有答案的问题',
+        type: 'boolean' as const,
+        defaultValue: false
+      }
+    ],
+    values: {
+      answeredOnly: false
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.MessageCircleQuestion)
+      logger.info('Stack Overflow 插件初始化完成')
+    } catch (error) {
+      logger.warn('Stack Overflow 图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    if (query.length < 2) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const questions = await invoke<StackOverflowQuestion[]>('search_stackoverflow', { query })
+
+      return questions
+        .filter(question => !this.settings.values.answeredOnly || question.is_answered)
+        .slice(0, context.maxResults)
+        .map((question, index) => ({
+          id: `stackoverflow-${question.id}`,
+          title: question.title,
+          description: [
+            `${question.score} 票`,
+            question.accepted ? '已采纳答案' : question.answer_count > 0 ? `${question.answer_count} 个回答` : '暂无回答',
+            question.tags.slice(0, 3).join(', ')
+          ].filter(Boolean).join(' · '),
+          icon: this.icon,
+          priority: this.priority + 40 - index,
+          action: () => this.openUrl(question.url),
+          source: this.id,
+          metadata: {
+            type: 'stackoverflow',
+            answered: question.is_answered
+          }
+        }))
+    } catch (error) {
+      const appError = handlePluginError('搜索 Stack Overflow', error)
+      logger.error('搜索 Stack Overflow 失败', appError)
+      return []
+    }
+  }
+
+  private async openUrl(url: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('plugin:opener|open_url', { url })
+    } catch (error) {
+      const appError = handlePluginError('打开 Stack Overflow 问题', error)
+      logger.error('打开 Stack Overflow 问题失败', appError)
+    }
+  }
+}
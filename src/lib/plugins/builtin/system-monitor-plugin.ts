@@ -0,0 +1,190 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { pluginManager } from '../../search-plugin-manager'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface DiskUsage {
+  name: string
+  mount_point: string
+  used: number
+  total: number
+  removable: boolean
+}
+
+interface SystemSnapshot {
+  cpu_usage: number
+  per_core: number[]
+  memory_used: number
+  memory_total: number
+  swap_used: number
+  swap_total: number
+  disks: DiskUsage[]
+  uptime_secs: number
+}
+
+/**
+ * 系统监控插件
+ *
+ * 输入 `sys` 查看 CPU、内存、磁盘和每个核心的负载，结果显示期间实时刷新
+ */
+export class SystemMonitorPlugin implements SearchPlugin {
+  id = 'system-monitor'
+  name = '系统监控'
+  description = '实时查看 CPU、内存、磁盘和每个核心的负载'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+  // 不使用前缀，避免 "system settings" 这类查询只交给本插件处理
+  private readonly triggerPattern = /^(sys|sysinfo|系统状态)(\s+(cpu|mem|memory|disk|core|cores))?$/i
+
+  settings = {
+    schema: [
+      {
+        key: 'refreshInterval',
+        label: '刷新间隔（毫秒）',
+        type: 'number' as const,
+        defaultValue: 1000
+      },
+      {
+        key: 'showPerCore',
+        label: '显示每个核心的负载',
+        type: 'boolean' as const,
+        defaultValue: true
+      }
+    ],
+    values: {
+      refreshInterval: 1000,
+      showPerCore: true
+    }
+  }
+
+  private latest: SystemSnapshot | null = null
+  private unlisten: (() => void) | null = null
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Gauge)
+      logger.info('系统监控插件初始化完成')
+    } catch (error) {
+      logger.warn('系统监控图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async destroy(): Promise<void> {
+    this.unlisten?.()
+    this.unlisten = null
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const match = context.query.trim().match(this.triggerPattern)
+    if (!match) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await this.subscribe()
+      // 每次搜索都续期，结果不再显示后后端自动停止推送
+      await invoke('start_system_monitor', { intervalMs: this.settings.values.refreshInterval })
+
+      if (!this.latest) {
+        this.latest = await invoke<SystemSnapshot>('get_system_snapshot')
+      }
+      const filter = match[3]?.toLowerCase().slice(0, 3)
+      return this.toResults(this.latest)
+        .filter(result => !filter || result.id.startsWith(`sys-${filter}`))
+    } catch (error) {
+      const appError = handlePluginError('获取系统状态', error)
+      logger.error('获取系统状态失败', appError)
+      return []
+    }
+  }
+
+  private async subscribe(): Promise<void> {
+    if (this.unlisten) {
+      return
+    }
+
+    const { listen } = await import('@tauri-apps/api/event')
+    this.unlisten = await listen<SystemSnapshot>('system-snapshot', event => {
+      this.latest = event.payload
+      pluginManager.notifyResultsChanged(this.id)
+    })
+  }
+
+  private toResults(snapshot: SystemSnapshot): SearchResultItem[] {
+    const results = [
+      this.createResult(
+        'sys-cpu',
+        `CPU ${snapshot.cpu_usage.toFixed(1)}%`,
+        `${snapshot.per_core.length} 个核心 • 运行时间 ${this.formatUptime(snapshot.uptime_secs)}`,
+        0
+      ),
+      this.createResult(
+        'sys-memory',
+        `内存 ${this.percent(snapshot.memory_used, snapshot.memory_total)}`,
+        `${this.formatBytes(snapshot.memory_used)} / ${this.formatBytes(snapshot.memory_total)}` +
+          (snapshot.swap_total > 0 ? ` • 交换 ${this.formatBytes(snapshot.swap_used)} / ${this.formatBytes(snapshot.swap_total)}` : ''),
+        1
+      )
+    ]
+
+    snapshot.disks.forEach((disk, index) => results.push(this.createResult(
+      `sys-disk-${disk.mount_point}`,
+      `磁盘 ${disk.mount_point} ${this.percent(disk.used, disk.total)}`,
+      `已用 ${this.formatBytes(disk.used)} / ${this.formatBytes(disk.total)}${disk.removable ? ' • 可移动' : ''}`,
+      2 + index
+    )))
+
+    if (this.settings.values.showPerCore && snapshot.per_core.length > 0) {
+      results.push(this.createResult(
+        'sys-cores',
+        '各核心负载',
+        snapshot.per_core.map(usage => `${Math.round(usage)}%`).join(' '),
+        2 + snapshot.disks.length
+      ))
+    }
+
+    return results
+  }
+
+  private createResult(id: string, title: string, description: string, order: number): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority: this.priority + 50 - order,
+      action: async () => {},
+      source: this.id,
+      metadata: {
+        type: 'system-monitor'
+      }
+    }
+  }
+
+  private percent(used: number, total: number): string {
+    return total > 0 ? `${((used / total) * 100).toFixed(1)}%` : '-'
+  }
+
+  private formatBytes(bytes: number): string {
+    const units = ['B', 'KB', 'MB', 'GB', 'TB']
+    let value = bytes
+    let unit = 0
+    while (value >= 1024 && unit < units.length - 1) {
+      value /= 1024
+      unit++
+    }
+    return `${value.toFixed(unit >= 3 ? 1 : 0)} ${units[unit]}`
+  }
+
+  private formatUptime(seconds: number): string {
+    const days = Math.floor(seconds / 86400)
+    const hours = Math.floor((seconds % 86400) / 3600)
+    const minutes = Math.floor((seconds % 3600) / 60)
+    return days > 0 ? `${days} 天 ${hours} 小时` : `${hours} 小时 ${minutes} 分钟`
+  }
+}
@@ -0,0 +1,104 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface TextTransform {
+  kind: string
+  name: string
+  value: string
+}
+
+const MAX_TITLE_LENGTH = 80
+
+/**
+ * 文本转换插件
+ *
+ * 用法：`tx hello world` 转换输入的文本，只输入 `tx:` 转换剪贴板中的文本；
+ * 提供大小写、camelCase / snake_case / kebab-case、slug、去除空白、删除重复行等转换，回车复制结果
+ */
+export class TextTransformPlugin implements SearchPlugin {
+  id = 'text-transform'
+  name = '文本转换'
+  description = '转换文本的大小写和命名风格，整理多行文本'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['tx:', 'tx ', 'case:']
+  prefixOnly = true
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.CaseSensitive)
+      logger.info('文本转换插件初始化完成')
+    } catch (error) {
+      logger.warn('文本转换图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const fromClipboard = !context.query.trim()
+      const text = fromClipboard ? await invoke<string | null>('read_clipboard_text') : context.query
+      if (!text) {
+        return []
+      }
+
+      const transforms = await invoke<TextTransform[]>('transform_text', { text })
+      return transforms.map((transform, index) => {
+        const lines = transform.value.split('\n')
+        const firstLine = lines[0].length > MAX_TITLE_LENGTH ? `${lines[0].slice(0, MAX_TITLE_LENGTH)}…` : lines[0]
+        const result = this.createResult(
+          `text-transform-${transform.kind}`,
+          lines.length > 1 ? `${firstLine} …（共 ${lines.length} 行）` : firstLine,
+          `${transform.name}${fromClipboard ? ' • 剪贴板' : ''} • 回车复制`,
+          this.priority + 30 - index,
+          () => this.copy(transform)
+        )
+        result.preview = async () => transform.value
+        return result
+      })
+    } catch (error) {
+      const appError = handlePluginError('转换文本', error)
+      logger.error('转换文本失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'text-transform'
+      }
+    }
+  }
+
+  private async copy(transform: TextTransform): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('text-transform-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text: transform.value })
+      logger.success(`已复制${transform.name}结果`)
+    } catch (error) {
+      const appError = handlePluginError('复制转换结果', error)
+      logger.error('复制转换结果失败', appError)
+    }
+  }
+}
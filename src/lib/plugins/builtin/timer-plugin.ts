@@ -0,0 +1,199 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface TimerInfo {
+  id: number
+  label: string
+  kind: 'timer' | 'pomodoro'
+  phase: string
+  phase_index: number
+  phase_count: number
+  duration_secs: number
+  remaining_secs: number
+  ends_at: number
+  play_sound: boolean
+}
+
+/**
+ * 计时器插件
+ *
+ * 用法：`timer 10m tea` 开始计时，`timer pomodoro` 开始番茄钟，
+ * 仅输入 `timer` 列出正在运行的计时器（回车取消）
+ */
+export class TimerPlugin implements SearchPlugin {
+  id = 'timer'
+  name = '计时器'
+  description = '倒计时与番茄钟，结束时发送系统通知'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 88
+  searchPrefixes = ['timer ']
+
+  settings = {
+    schema: [
+      {
+        key: 'playSound',
+        label: '播放提示音',
+        description: '计时结束时播放系统提示音',
+        type: 'boolean' as const,
+        defaultValue: true
+      },
+      {
+        key: 'workMinutes',
+        label: '番茄钟专注时长（分钟）',
+        type: 'number' as const,
+        defaultValue: 25
+      },
+      {
+        key: 'breakMinutes',
+        label: '番茄钟休息时长（分钟）',
+        type: 'number' as const,
+        defaultValue: 5
+      },
+      {
+        key: 'rounds',
+        label: '番茄钟轮数',
+        type: 'number' as const,
+        defaultValue: 4
+      }
+    ],
+    values: {
+      playSound: true,
+      workMinutes: 25,
+      breakMinutes: 5,
+      rounds: 4
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Timer)
+      logger.info('计时器插件初始化完成')
+    } catch (error) {
+      logger.warn('计时器图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 timer
+    if (!context.prefix && !/^(timer)$/i.test(context.query.trim())) {
+      return []
+    }
+    const spec = context.prefix ? context.query.trim() : ''
+
+    if (!spec) {
+      return this.listTimers()
+    }
+
+    if (/^pomo(doro)?$/i.test(spec)) {
+      const { workMinutes, breakMinutes, rounds } = this.settings.values
+      return [this.createResult(
+        'timer-start-pomodoro',
+        '开始番茄钟',
+        `专注 ${workMinutes} 分钟 / 休息 ${breakMinutes} 分钟 × ${rounds} 轮`,
+        () => this.startPomodoro()
+      )]
+    }
+
+    const match = spec.match(/^((?:\d+[a-z]*\s*)+)(.*)$/i)
+    if (!match) {
+      return []
+    }
+
+    const label = match[2].trim()
+    return [this.createResult(
+      `timer-start-${spec}`,
+      `开始 ${match[1].trim()} 计时${label ? `：${label}` : ''}`,
+      '计时在后台运行，结束时发送系统通知',
+      () => this.startTimer(spec)
+    )]
+  }
+
+  private async listTimers(): Promise<SearchResultItem[]> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const timers = await invoke<TimerInfo[]>('list_timers')
+
+      return timers.map(timer => this.createResult(
+        `timer-${timer.id}`,
+        `${timer.label} • 剩余 ${this.formatRemaining(timer.remaining_secs)}`,
+        timer.kind === 'pomodoro'
+          ? `${timer.phase}（${timer.phase_index + 1}/${timer.phase_count}）• 回车取消`
+          : '回车取消计时',
+        () => this.cancelTimer(timer.id)
+      ))
+    } catch (error) {
+      const appError = handlePluginError('获取计时器列表', error)
+      logger.error('获取计时器列表失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority: this.priority + 50,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'timer'
+      }
+    }
+  }
+
+  private formatRemaining(seconds: number): string {
+    const h = Math.floor(seconds / 3600)
+    const m = Math.floor((seconds % 3600) / 60)
+    const s = seconds % 60
+    const pad = (n: number) => n.toString().padStart(2, '0')
+    return h > 0 ? `${h}:${pad(m)}:${pad(s)}` : `${pad(m)}:${pad(s)}`
+  }
+
+  private async startTimer(spec: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const timer = await invoke<TimerInfo>('start_timer', {
+        spec,
+        playSound: this.settings.values.playSound
+      })
+      logger.success(`计时器已开始: ${timer.label}`)
+    } catch (error) {
+      const appError = handlePluginError('开始计时', error)
+      logger.error('开始计时失败', appError)
+    }
+  }
+
+  private async startPomodoro(): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke<TimerInfo>('start_pomodoro', {
+        workMinutes: this.settings.values.workMinutes,
+        breakMinutes: this.settings.values.breakMinutes,
+        rounds: this.settings.values.rounds,
+        playSound: this.settings.values.playSound
+      })
+      logger.success('番茄钟已开始')
+    } catch (error) {
+      const appError = handlePluginError('开始番茄钟', error)
+      logger.error('开始番茄钟失败', appError)
+    }
+  }
+
+  private async cancelTimer(id: number): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('cancel_timer', { id })
+      logger.info(`计时器已取消: ${id}`)
+    } catch (error) {
+      const appError = handlePluginError('取消计时', error)
+      logger.error('取消计时失败', appError)
+    }
+  }
+}
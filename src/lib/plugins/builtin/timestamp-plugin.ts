@@ -0,0 +1,113 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface TimestampInfo {
+  input_kind: 'timestamp' | 'date'
+  unix_seconds: number
+  unix_millis: number
+  local: string
+  utc: string
+  iso8601: string
+  relative: string
+}
+
+/**
+ * 时间戳转换插件
+ *
+ * 用法：`1717171717`、`2024-06-01 14:00 to unix`、`ts: now`
+ */
+export class TimestampPlugin implements SearchPlugin {
+  id = 'timestamp'
+  name = '时间戳转换'
+  description = 'Unix 时间戳与日期时间互相转换'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 88
+  searchPrefixes = ['ts:', 'unix:']
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Clock)
+      logger.info('时间戳转换插件初始化完成')
+    } catch (error) {
+      logger.warn('时间戳转换图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const { query } = context
+    if (!this.looksLikeTime(query)) {
+      return []
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const info = await invoke<TimestampInfo>('convert_timestamp', { input: query })
+
+      const rows = [
+        { key: 'local', label: '本地时间', value: info.local },
+        { key: 'utc', label: 'UTC', value: info.utc },
+        { key: 'iso', label: 'ISO 8601', value: info.iso8601 },
+        { key: 'seconds', label: 'Unix 秒', value: String(info.unix_seconds) },
+        { key: 'millis', label: 'Unix 毫秒', value: String(info.unix_millis) }
+      ]
+
+      // 输入为日期时优先显示时间戳，反之优先显示日期
+      if (info.input_kind === 'date') {
+        rows.unshift(...rows.splice(3, 2))
+      }
+
+      return rows.map((row, index) => ({
+        id: `timestamp-${row.key}`,
+        title: row.value,
+        description: `${row.label} • ${info.relative} • 回车复制`,
+        icon: this.icon,
+        priority: this.priority + 50 - index,
+        action: () => this.copyValue(row.value),
+        source: this.id,
+        metadata: {
+          type: 'timestamp',
+          field: row.key,
+          unixSeconds: info.unix_seconds
+        }
+      }))
+    } catch (error) {
+      // 无法识别的格式不视为错误
+      logger.debug('时间戳转换失败', { query, error })
+      return []
+    }
+  }
+
+  /**
+   * 判断查询是否像时间戳或日期
+   */
+  private looksLikeTime(query: string): boolean {
+    const trimmed = query.trim().toLowerCase()
+    return trimmed === 'now' ||
+      /^-?\d{9,19}$/.test(trimmed) ||
+      /^\d{4}[-/]\d{1,2}[-/]\d{1,2}([ t]\d{1,2}:\d{2}.*)?$/.test(trimmed)
+  }
+
+  private async copyValue(value: string): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('timestamp-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text: value })
+      logger.info(`${value} 已复制到剪贴板`)
+    } catch (error) {
+      const appError = handlePluginError('复制时间', error)
+      logger.error('复制时间失败', appError)
+    }
+  }
+}
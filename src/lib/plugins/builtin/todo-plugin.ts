@@ -0,0 +1,195 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface Todo {
+  id: number
+  title: string
+  done: boolean
+  created_at: number
+  completed_at: number | null
+  due_at: number | null
+  reminder_id: number | null
+}
+
+interface ParsedTodo {
+  title: string
+  due_at: number | null
+  due_text: string | null
+}
+
+/**
+ * 待办插件
+ *
+ * 用法：`todo buy milk` 添加待办，`todo pay rent friday 9am` 添加带提醒的待办，
+ * 仅输入 `todo` 列出未完成的待办（回车完成，另有删除操作）
+ */
+export class TodoPlugin implements SearchPlugin {
+  id = 'todo'
+  name = '待办'
+  description = '轻量待办清单，带截止时间的待办会按时提醒'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 88
+  // 带空格，避免 "todoist" 等普通搜索被当作待办
+  searchPrefixes = ['todo ']
+
+  settings = {
+    schema: [
+      {
+        key: 'showCompleted',
+        label: '显示已完成',
+        description: '在列表中显示最近一天内完成的待办',
+        type: 'boolean' as const,
+        defaultValue: false
+      }
+    ],
+    values: {
+      showCompleted: false
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.ListTodo)
+      logger.info('待办插件初始化完成')
+    } catch (error) {
+      logger.warn('待办图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const text = context.query.trim()
+
+    if (!context.prefix) {
+      // 搜索词会去掉末尾空格，单独的 todo 不会匹配前缀
+      return /^todo$/i.test(text) ? this.listTodos() : []
+    }
+    if (!text) {
+      return this.listTodos()
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const parsed = await invoke<ParsedTodo>('preview_todo', { text })
+
+      return [this.createResult(
+        `todo-add-${text}`,
+        `添加待办：${parsed.title}`,
+        parsed.due_text ? `截止 ${parsed.due_text}，到时提醒 • 回车添加` : '回车添加',
+        this.priority + 50,
+        () => this.addTodo(text)
+      )]
+    } catch (error) {
+      const appError = handlePluginError('解析待办', error)
+      logger.error('解析待办失败', appError)
+      return []
+    }
+  }
+
+  private async listTodos(): Promise<SearchResultItem[]> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const todos = await invoke<Todo[]>('list_todos', {
+        includeDone: this.settings.values.showCompleted
+      })
+
+      const results: SearchResultItem[] = []
+      todos.forEach((todo, index) => {
+        const priority = this.priority + 50 - index
+        const due = todo.due_at ? `截止 ${this.formatDue(todo.due_at)} • ` : ''
+
+        results.push(todo.done
+          ? this.createResult(
+            `todo-${todo.id}`,
+            `✓ ${todo.title}`,
+            '已完成 • 回车重新打开',
+            priority,
+            () => this.setDone(todo.id, false)
+          )
+          : this.createResult(
+            `todo-${todo.id}`,
+            todo.title,
+            `${due}回车完成`,
+            priority,
+            () => this.setDone(todo.id, true)
+          ))
+
+        results.push(this.createResult(
+          `todo-delete-${todo.id}`,
+          `删除待办：${todo.title}`,
+          '回车删除',
+          // 删除操作排在所有待办之后
+          this.priority - index,
+          () => this.deleteTodo(todo.id)
+        ))
+      })
+
+      return results
+    } catch (error) {
+      const appError = handlePluginError('获取待办列表', error)
+      logger.error('获取待办列表失败', appError)
+      return []
+    }
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'todo'
+      }
+    }
+  }
+
+  private formatDue(dueAt: number): string {
+    return new Date(dueAt * 1000).toLocaleString('zh-CN', {
+      month: 'numeric',
+      day: 'numeric',
+      hour: '2-digit',
+      minute: '2-digit'
+    })
+  }
+
+  private async addTodo(text: string): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const todo = await invoke<Todo>('add_todo', { text })
+      logger.success(`待办已添加: ${todo.title}`)
+    } catch (error) {
+      const appError = handlePluginError('添加待办', error)
+      logger.error('添加待办失败', appError)
+    }
+  }
+
+  private async setDone(id: number, done: boolean): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const todo = await invoke<Todo>('complete_todo', { id, done })
+      logger.info(`${done ? '待办已完成' : '待办已重新打开'}: ${todo.title}`)
+    } catch (error) {
+      const appError = handlePluginError('更新待办', error)
+      logger.error('更新待办失败', appError)
+    }
+  }
+
+  private async deleteTodo(id: number): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('delete_todo', { id })
+      logger.info(`待办已删除: ${id}`)
+    } catch (error) {
+      const appError = handlePluginError('删除待办', error)
+      logger.error('删除待办失败', appError)
+    }
+  }
+}
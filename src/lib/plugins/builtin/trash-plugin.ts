@@ -0,0 +1,200 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface TrashSummary {
+  count: number
+  size: number | null
+}
+
+interface TrashItem {
+  id: string
+  name: string
+  original_path: string | null
+  deleted_at: number | null
+  size: number | null
+  is_dir: boolean
+  restorable: boolean
+}
+
+/**
+ * 回收站插件
+ *
+ * 输入 `empty trash` 或 `清空回收站`，结果中显示回收站的项目数和占用空间，确认后清空。
+ * `trash <关键词>` 按名称搜索回收站，副标题显示原位置，回车恢复到原位置；
+ * `trash rm <关键词>` 回车永久删除（需要确认）。macOS 无法读取原位置，只能永久删除
+ */
+export class TrashPlugin implements SearchPlugin {
+  id = 'trash'
+  name = '回收站'
+  description = '查看回收站占用并清空，搜索已删除的文件并恢复'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  // 带空格，单独的 trash 仍然显示清空回收站
+  searchPrefixes = ['trash ', '回收站 ']
+
+  private folderIcon: any = null
+  // 不使用前缀时只匹配完整的触发词
+  private readonly triggerPattern = /^(empty\s+)?(trash|recycle\s+bin|bin)$|^(清空)?(回收站|废纸篓)$/i
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Trash2)
+      this.folderIcon = await getIcon(ICON_MAP.Folder)
+      logger.info('回收站插件初始化完成')
+    } catch (error) {
+      logger.warn('回收站图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    if (context.prefix) {
+      return this.searchItems(context)
+    }
+    if (!this.triggerPattern.test(context.query.trim())) {
+      return []
+    }
+
+    let summary: TrashSummary | null = null
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      summary = await invoke<TrashSummary>('get_trash_summary')
+    } catch (error) {
+      logger.warn('读取回收站信息失败', error)
+    }
+
+    const empty = summary?.count === 0
+    return [{
+      id: 'trash-empty',
+      title: empty ? '回收站是空的' : '清空回收站',
+      description: summary ? this.describe(summary) : '无法读取回收站内容（需要确认）',
+      icon: this.icon,
+      priority: this.priority + 50,
+      action: () => empty ? Promise.resolve() : this.emptyTrash(summary),
+      source: this.id,
+      metadata: {
+        type: 'trash',
+        count: summary?.count
+      }
+    }]
+  }
+
+  private async searchItems(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+    const match = query.match(/^(rm|del|delete)(?:\s+(.*))?$/i)
+    const remove = match !== null
+    const keyword = match ? match[2] ?? '' : query
+
+    let items: TrashItem[]
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      items = await invoke<TrashItem[]>('search_trash', { query: keyword })
+    } catch (error) {
+      const appError = handlePluginError('搜索回收站', error)
+      logger.error('搜索回收站失败', appError)
+      return []
+    }
+
+    if (items.length === 0) {
+      return [{
+        id: 'trash-no-match',
+        title: keyword ? `回收站中没有 "${keyword}"` : '回收站是空的',
+        description: '按名称搜索已删除的项目',
+        icon: this.icon,
+        priority: this.priority,
+        action: async () => {},
+        source: this.id,
+        metadata: { type: 'trash' }
+      }]
+    }
+
+    return items.slice(0, context.maxResults).map((item, index) => ({
+      id: `trash-item-${item.id}`,
+      title: remove ? `永久删除: ${item.name}` : item.name,
+      description: this.describeItem(item, remove),
+      icon: item.is_dir && this.folderIcon ? this.folderIcon : this.icon,
+      priority: this.priority + 40 - Math.min(index, 20),
+      action: () => remove || !item.restorable ? this.deleteItem(item) : this.restoreItem(item),
+      source: this.id,
+      metadata: {
+        type: 'trash-item',
+        originalPath: item.original_path
+      }
+    }))
+  }
+
+  private describeItem(item: TrashItem, remove: boolean): string {
+    const location = item.original_path ? `原位置: ${item.original_path}` : '原位置未知'
+    const deleted = item.deleted_at ? `删除于 ${new Date(item.deleted_at * 1000).toLocaleString()}` : ''
+    const size = item.size === null ? '' : this.formatBytes(item.size)
+    const hint = remove || !item.restorable ? '回车永久删除（需要确认）' : '回车恢复'
+    return [location, deleted, size, hint].filter(Boolean).join(' · ')
+  }
+
+  private async restoreItem(item: TrashItem): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const path = await invoke<string>('restore_trash_item', { id: item.id })
+      logger.success(`已恢复到 ${path}`)
+    } catch (error) {
+      const appError = handlePluginError('恢复回收站项目', error)
+      logger.error('恢复回收站项目失败', appError)
+    }
+  }
+
+  private async deleteItem(item: TrashItem): Promise<void> {
+    if (!confirm(`确定要永久删除 "${item.name}" 吗？此操作无法撤销。`)) {
+      logger.info('已取消永久删除')
+      return
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('delete_trash_item', { id: item.id, confirmed: true })
+      logger.success(`已永久删除 ${item.name}`)
+    } catch (error) {
+      const appError = handlePluginError('永久删除回收站项目', error)
+      logger.error('永久删除回收站项目失败', appError)
+    }
+  }
+
+  private describe(summary: TrashSummary): string {
+    if (summary.count === 0) {
+      return '没有需要清空的项目'
+    }
+    const size = summary.size === null ? '' : `，共 ${this.formatBytes(summary.size)}`
+    return `${summary.count} 个项目${size}（需要确认）`
+  }
+
+  private async emptyTrash(summary: TrashSummary | null): Promise<void> {
+    const detail = summary ? `回收站中的 ${summary.count} 个项目` : '回收站'
+    if (!confirm(`确定要永久删除${detail}吗？此操作无法撤销。`)) {
+      logger.info('已取消清空回收站')
+      return
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('empty_trash', { confirmed: true })
+      logger.success('回收站已清空')
+    } catch (error) {
+      const appError = handlePluginError('清空回收站', error)
+      logger.error('清空回收站失败', appError)
+    }
+  }
+
+  private formatBytes(bytes: number): string {
+    const units = ['B', 'KB', 'MB', 'GB', 'TB']
+    let value = bytes
+    let unit = 0
+    while (value >= 1024 && unit < units.length - 1) {
+      value /= 1024
+      unit++
+    }
+    return `${value.toFixed(unit >= 3 ? 1 : 0)} ${units[unit]}`
+  }
+}
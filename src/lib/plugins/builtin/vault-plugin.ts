@@ -0,0 +1,228 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+type VaultProvider = 'bitwarden' | 'onepassword'
+type VaultField = 'username' | 'password' | 'totp'
+
+interface VaultItem {
+  id: string
+  name: string
+  username: string | null
+  url: string | null
+  vault: string | null
+  has_totp: boolean
+}
+
+interface VaultStatus {
+  installed: boolean
+  unlocked: boolean
+  message: string | null
+}
+
+const PROVIDER_NAMES: Record<VaultProvider, string> = {
+  bitwarden: 'Bitwarden',
+  onepassword: '1Password'
+}
+
+const FIELD_NAMES: Record<VaultField, string> = {
+  username: '用户名',
+  password: '密码',
+  totp: '验证码'
+}
+
+/**
+ * 密码库插件（默认关闭）
+ *
+ * 用法：`vault github` 在默认密码库中搜索，`bw github` / `op github` 指定 Bitwarden 或 1Password，
+ * 回车复制密码（按设置自动清除剪贴板），第一个结果另有复制验证码和用户名操作；`vault lock` 锁定。
+ * 需要安装 bw 或 op 命令行工具，主密码只用于解锁，会话只保存在内存中
+ */
+export class VaultPlugin implements SearchPlugin {
+  id = 'vault'
+  name = '密码库'
+  description = '搜索 Bitwarden / 1Password 条目并复制密码或验证码'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  // 需要安装命令行工具并解锁密码库，由用户主动开启
+  enabled = false
+  priority = 85
+  searchPrefixes = ['vault ', 'bw ', 'op ']
+
+  settings = {
+    schema: [
+      {
+        key: 'provider',
+        label: '默认密码库',
+        description: '使用 vault 前缀时搜索的密码库',
+        type: 'select' as const,
+        defaultValue: 'bitwarden',
+        options: [
+          { label: 'Bitwarden（bw）', value: 'bitwarden' },
+          { label: '1Password（op）', value: 'onepassword' }
+        ]
+      },
+      {
+        key: 'clipboardClearSeconds',
+        label: '剪贴板自动清除（秒）',
+        description: '复制密码或验证码后自动清除剪贴板的时间，0 表示不清除',
+        type: 'number' as const,
+        defaultValue: 30
+      }
+    ],
+    values: {
+      provider: 'bitwarden',
+      clipboardClearSeconds: 30
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.KeyRound)
+      logger.info('密码库插件初始化完成')
+    } catch (error) {
+      logger.warn('密码库图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 vault
+    if (!context.prefix && !/^(vault)$/i.test(context.query.trim())) {
+      return []
+    }
+    const prefix = context.prefix?.trim().toLowerCase()
+    const provider: VaultProvider = prefix === 'bw' ? 'bitwarden' : prefix === 'op' ? 'onepassword' : this.settings.values.provider as VaultProvider
+    const name = PROVIDER_NAMES[provider]
+    const query = context.prefix ? context.query.trim() : ''
+
+    if (/^lock$/i.test(query)) {
+      return [this.createResult(`vault-lock-${provider}`, `锁定 ${name}`, '丢弃内存中的会话，下次使用时需要重新解锁', this.priority + 50, () => this.lock(provider))]
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      let items: VaultItem[]
+      try {
+        items = await invoke<VaultItem[]>('search_vault', { provider, query })
+      } catch (error) {
+        return await this.statusResults(provider, error)
+      }
+
+      if (items.length === 0) {
+        return [this.createResult(`vault-empty-${provider}`, '没有匹配的条目', `${name} · ${query}`, this.priority, async () => {})]
+      }
+
+      const results: SearchResultItem[] = []
+      items.forEach((item, index) => {
+        const detail = [item.username, item.vault ?? item.url].filter(Boolean).join(' · ')
+        results.push(this.createResult(
+          `vault-${provider}-${item.id}-password`,
+          item.name,
+          `${detail ? `${detail} · ` : ''}回车复制密码`,
+          this.priority + 40 - index,
+          () => this.copyField(provider, item, 'password')
+        ))
+        // 只为最匹配的条目提供其他字段，避免结果过多
+        if (index === 0 && item.has_totp) {
+          results.push(this.createResult(`vault-${provider}-${item.id}-totp`, `复制 ${item.name} 的验证码`, name, this.priority + 39, () => this.copyField(provider, item, 'totp')))
+        }
+        if (index === 0 && item.username) {
+          results.push(this.createResult(`vault-${provider}-${item.id}-username`, `复制 ${item.name} 的用户名`, item.username, this.priority + 38, () => this.copyField(provider, item, 'username')))
+        }
+      })
+      return results
+    } catch (error) {
+      const appError = handlePluginError(`搜索 ${name}`, error)
+      logger.error(`搜索 ${name} 失败`, appError)
+      return []
+    }
+  }
+
+  // 搜索失败时根据状态提示安装、登录或解锁
+  private async statusResults(provider: VaultProvider, searchError: unknown): Promise<SearchResultItem[]> {
+    const name = PROVIDER_NAMES[provider]
+    const { invoke } = await import('@tauri-apps/api/core')
+    const status = await invoke<VaultStatus>('vault_status', { provider })
+
+    if (!status.installed || status.message) {
+      return [this.createResult(`vault-status-${provider}`, `${name} 不可用`, status.message ?? '', this.priority, async () => {})]
+    }
+    if (!status.unlocked) {
+      return [this.createResult(`vault-unlock-${provider}`, `解锁 ${name}`, '回车输入主密码', this.priority + 50, () => this.unlock(provider))]
+    }
+
+    const appError = handlePluginError(`搜索 ${name}`, searchError)
+    logger.error(`搜索 ${name} 失败`, appError)
+    return []
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'vault'
+      }
+    }
+  }
+
+  private async unlock(provider: VaultProvider): Promise<void> {
+    const name = PROVIDER_NAMES[provider]
+    const password = prompt(`请输入 ${name} 主密码`)
+    if (!password) {
+      return
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('unlock_vault', { provider, password })
+      logger.success(`${name} 已解锁`)
+    } catch (error) {
+      const appError = handlePluginError(`解锁 ${name}`, error)
+      logger.error(`解锁 ${name} 失败`, appError)
+    }
+  }
+
+  private async lock(provider: VaultProvider): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('lock_vault', { provider })
+      logger.info(`${PROVIDER_NAMES[provider]} 已锁定`)
+    } catch (error) {
+      const appError = handlePluginError('锁定密码库', error)
+      logger.error('锁定密码库失败', appError)
+    }
+  }
+
+  // 密码和验证码由后端直接写入剪贴板，不经过前端
+  private async copyField(provider: VaultProvider, item: VaultItem, field: VaultField): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('vault-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_vault_field', {
+        provider,
+        id: item.id,
+        field,
+        clearAfterSecs: this.settings.values.clipboardClearSeconds
+      })
+      logger.info(`已复制 ${item.name} 的${FIELD_NAMES[field]}`)
+    } catch (error) {
+      const appError = handlePluginError(`复制${FIELD_NAMES[field]}`, error)
+      logger.error(`复制${FIELD_NAMES[field]}失败`, appError)
+    }
+  }
+}
@@ -0,0 +1,180 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface VolumeState {
+  volume: number
+  muted: boolean
+}
+
+/**
+ * 音量和亮度插件
+ *
+ * 用法：`vol 30` 设置音量，`vol up` / `vol down` 调节，`vol mute` 切换静音；
+ * `brightness 60`、`brightness up` / `brightness down` 调节屏幕亮度
+ */
+export class VolumeBrightnessPlugin implements SearchPlugin {
+  id = 'volume-brightness'
+  name = '音量和亮度'
+  description = '调节系统音量、静音和屏幕亮度'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+  // 不使用前缀，避免 "volume mixer" 这类查询只交给本插件处理
+  private readonly volumePattern = /^(vol|volume|音量)(?:\s+(up|down|mute|unmute|\d{1,3}))?$/i
+  private readonly brightnessPattern = /^(bright|brightness|亮度)(?:\s+(up|down|\d{1,3}))?$/i
+
+  settings = {
+    schema: [
+      {
+        key: 'step',
+        label: '每次调节的幅度（%）',
+        type: 'number' as const,
+        defaultValue: 10
+      }
+    ],
+    values: {
+      step: 10
+    }
+  }
+
+  private brightnessIcon: any = null
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Volume2)
+      this.brightnessIcon = await getIcon(ICON_MAP.Sun)
+      logger.info('音量和亮度插件初始化完成')
+    } catch (error) {
+      logger.warn('音量和亮度图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const query = context.query.trim()
+
+    const volumeMatch = query.match(this.volumePattern)
+    if (volumeMatch) {
+      return this.volumeResults(volumeMatch[2]?.toLowerCase())
+    }
+
+    const brightnessMatch = query.match(this.brightnessPattern)
+    if (brightnessMatch) {
+      return this.brightnessResults(brightnessMatch[2]?.toLowerCase())
+    }
+
+    return []
+  }
+
+  private get step(): number {
+    return Number(this.settings.values.step) || 10
+  }
+
+  private async volumeResults(argument: string | undefined): Promise<SearchResultItem[]> {
+    if (argument === 'mute' || argument === 'unmute') {
+      const muted = argument === 'mute'
+      return [this.createResult('volume-mute', muted ? '静音' : '取消静音', '回车执行', this.icon, () => this.setMuted(muted))]
+    }
+    if (argument === 'up' || argument === 'down') {
+      const delta = argument === 'up' ? this.step : -this.step
+      return [this.createResult(`volume-${argument}`, `音量${delta > 0 ? '增加' : '降低'} ${this.step}%`, '回车执行', this.icon, () => this.setVolume(delta, true))]
+    }
+    if (argument) {
+      const value = Math.min(Number(argument), 100)
+      return [this.createResult('volume-set', `将音量设为 ${value}%`, '回车执行', this.icon, () => this.setVolume(value, false))]
+    }
+
+    // 只输入 "vol" 时显示当前音量和常用操作
+    let current = '当前音量未知'
+    let muted = false
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const state = await invoke<VolumeState>('get_volume')
+      current = `当前音量 ${state.volume}%${state.muted ? '（已静音）' : ''}`
+      muted = state.muted
+    } catch (error) {
+      logger.warn('读取当前音量失败', error)
+    }
+
+    return [
+      this.createResult('volume-up', `音量增加 ${this.step}%`, current, this.icon, () => this.setVolume(this.step, true)),
+      this.createResult('volume-down', `音量降低 ${this.step}%`, current, this.icon, () => this.setVolume(-this.step, true)),
+      this.createResult('volume-mute', muted ? '取消静音' : '静音', current, this.icon, () => this.setMuted(!muted))
+    ]
+  }
+
+  private async brightnessResults(argument: string | undefined): Promise<SearchResultItem[]> {
+    if (argument === 'up' || argument === 'down') {
+      const delta = argument === 'up' ? this.step : -this.step
+      return [this.createResult(`brightness-${argument}`, `亮度${delta > 0 ? '提高' : '降低'} ${this.step}%`, '回车执行', this.brightnessIcon, () => this.setBrightness(delta, true))]
+    }
+    if (argument) {
+      const value = Math.min(Number(argument), 100)
+      return [this.createResult('brightness-set', `将亮度设为 ${value}%`, '回车执行', this.brightnessIcon, () => this.setBrightness(value, false))]
+    }
+
+    let current = '当前亮度未知'
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      current = `当前亮度 ${await invoke<number>('get_brightness')}%`
+    } catch (error) {
+      logger.warn('读取当前亮度失败', error)
+    }
+
+    return [
+      this.createResult('brightness-up', `亮度提高 ${this.step}%`, current, this.brightnessIcon, () => this.setBrightness(this.step, true)),
+      this.createResult('brightness-down', `亮度降低 ${this.step}%`, current, this.brightnessIcon, () => this.setBrightness(-this.step, true))
+    ]
+  }
+
+  private createResult(id: string, title: string, description: string, icon: any, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon,
+      priority: this.priority + 50,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'volume-brightness'
+      }
+    }
+  }
+
+  private async setVolume(value: number, relative: boolean): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const state = await invoke<VolumeState>('set_volume', { value, relative })
+      logger.info(`音量已设为 ${state.volume}%`)
+    } catch (error) {
+      const appError = handlePluginError('调节音量', error)
+      logger.error('调节音量失败', appError)
+    }
+  }
+
+  private async setMuted(muted: boolean): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const state = await invoke<VolumeState>('set_muted', { muted })
+      logger.info(state.muted ? '已静音' : '已取消静音')
+    } catch (error) {
+      const appError = handlePluginError('切换静音', error)
+      logger.error('切换静音失败', appError)
+    }
+  }
+
+  private async setBrightness(value: number, relative: boolean): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const brightness = await invoke<number>('set_brightness', { value, relative })
+      logger.info(`亮度已设为 ${brightness}%`)
+    } catch (error) {
+      const appError = handlePluginError('调节亮度', error)
+      logger.error('调节亮度失败', appError)
+    }
+  }
+}
@@ -0,0 +1,115 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface VsCodeRecent {
+  label: string
+  uri: string
+  kind: 'folder' | 'workspace' | 'file'
+  path: string | null
+  remote: string | null
+  program: string
+}
+
+const KIND_LABELS: Record<VsCodeRecent['kind'], string> = {
+  folder: '文件夹',
+  workspace: '工作区',
+  file: '文件'
+}
+
+/**
+ * VS Code 最近打开插件
+ *
+ * 用法：`vsc <名称>` 搜索 VS Code 最近打开的工作区和文件夹，回车用 VS Code 打开
+ */
+export class VsCodePlugin implements SearchPlugin {
+  id = 'vscode'
+  name = 'VS Code 最近打开'
+  description = '搜索 VS Code 最近打开的工作区和文件夹'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['vsc ', 'code ']
+
+  settings = {
+    schema: [
+      {
+        key: 'codeCommand',
+        label: 'VS Code 命令',
+        description: '打开工作区使用的命令；留空按记录来源使用 code、code-insiders 或 codium',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'includeFiles',
+        label: '包含最近打开的文件',
+        type: 'boolean' as const,
+        defaultValue: false
+      }
+    ],
+    values: {
+      codeCommand: '',
+      includeFiles: false
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.SquareCode)
+      logger.info('VS Code 最近打开插件初始化完成')
+    } catch (error) {
+      logger.warn('VS Code 图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    const words = context.query.trim().toLowerCase().split(/\s+/).filter(Boolean)
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const recents = await invoke<VsCodeRecent[]>('list_vscode_recents')
+
+      return recents
+        .filter(recent => this.settings.values.includeFiles || recent.kind !== 'file')
+        .filter(recent => {
+          const text = `${recent.label} ${recent.path ?? recent.uri}`.toLowerCase()
+          return words.every(word => text.includes(word))
+        })
+        .slice(0, context.maxResults)
+        .map((recent, index) => ({
+          id: `vscode-${recent.program}-${recent.uri}`,
+          title: recent.label,
+          description: [KIND_LABELS[recent.kind], recent.remote, recent.path ?? recent.uri].filter(Boolean).join(' · '),
+          icon: this.icon,
+          // 保持最近使用的顺序，名称以关键词开头的排在前面
+          priority: this.priority + (words.length && recent.label.toLowerCase().startsWith(words[0]) ? 50 : 30) - index,
+          action: () => this.open(recent),
+          source: this.id,
+          metadata: {
+            type: 'vscode-recent',
+            kind: recent.kind
+          }
+        }))
+    } catch (error) {
+      const appError = handlePluginError('读取 VS Code 最近打开记录', error)
+      logger.error('读取 VS Code 最近打开记录失败', appError)
+      return []
+    }
+  }
+
+  private async open(recent: VsCodeRecent): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('open_vscode_recent', {
+        recent,
+        codeCommand: this.settings.values.codeCommand || null
+      })
+    } catch (error) {
+      const appError = handlePluginError('打开 VS Code', error)
+      logger.error('打开 VS Code 失败', appError)
+    }
+  }
+}
@@ -0,0 +1,219 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface WeatherLocation {
+  name: string
+  region: string | null
+  country: string | null
+  latitude: number
+  longitude: number
+}
+
+interface CurrentWeather {
+  time: string
+  temperature: number
+  apparent_temperature: number
+  humidity: number
+  wind_speed: number
+  weather_code: number
+  description: string
+}
+
+interface DailyForecast {
+  date: string
+  weather_code: number
+  description: string
+  temperature_max: number
+  temperature_min: number
+  precipitation_probability: number | null
+}
+
+interface Weather {
+  location: WeatherLocation
+  current: CurrentWeather
+  daily: DailyForecast[]
+  temperature_unit: string
+  wind_speed_unit: string
+}
+
+const DAY_NAMES = ['今天', '明天', '后天']
+
+/**
+ * 天气插件
+ *
+ * 用法：`weather 北京` 或 `天气 上海` 查询当前天气和未来三天的预报，只输入 `weather` 查询默认位置；
+ * 位置也可以写成 `纬度,经度`，回车复制天气摘要。默认使用 Open-Meteo（无需密钥），可在设置中换成兼容的接口
+ */
+export class WeatherPlugin implements SearchPlugin {
+  id = 'weather'
+  name = '天气'
+  description = '查询当前天气和未来几天的预报'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 75
+  searchPrefixes = ['weather ', '天气']
+
+  settings = {
+    schema: [
+      {
+        key: 'defaultLocation',
+        label: '默认位置',
+        description: '只输入 weather 时查询的城市名或 纬度,经度',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'units',
+        label: '温度单位',
+        description: '华氏度时风速使用 mph',
+        type: 'select' as const,
+        defaultValue: 'celsius',
+        options: [
+          { label: '摄氏度 (°C)', value: 'celsius' },
+          { label: '华氏度 (°F)', value: 'fahrenheit' }
+        ]
+      },
+      {
+        key: 'forecastUrl',
+        label: '天气接口',
+        description: '兼容 Open-Meteo 的预报接口地址，留空使用 https://api.open-meteo.com/v1/forecast',
+        type: 'string' as const,
+        defaultValue: ''
+      },
+      {
+        key: 'geocodingUrl',
+        label: '城市查询接口',
+        description: '兼容 Open-Meteo 的地理编码接口地址，留空使用 https://geocoding-api.open-meteo.com/v1/search',
+        type: 'string' as const,
+        defaultValue: ''
+      }
+    ],
+    values: {
+      defaultLocation: '',
+      units: 'celsius',
+      forecastUrl: '',
+      geocodingUrl: ''
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.CloudSun)
+      logger.info('天气插件初始化完成')
+    } catch (error) {
+      logger.warn('天气图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 weather
+    if (!context.prefix && !/^(weather)$/i.test(context.query.trim())) {
+      return []
+    }
+    const location = (context.prefix ? context.query.trim() : '') || this.settings.values.defaultLocation.trim()
+    if (!location) {
+      return [this.createResult('weather-no-location', '请输入城市名', '例如 weather 北京，或在插件设置中填写默认位置', this.priority, async () => {})]
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const weather = await invoke<Weather>('get_weather', {
+        location,
+        config: {
+          forecast_url: this.settings.values.forecastUrl || null,
+          geocoding_url: this.settings.values.geocodingUrl || null,
+          fahrenheit: this.settings.values.units === 'fahrenheit'
+        }
+      })
+
+      const { current, temperature_unit: unit } = weather
+      const place = this.formatLocation(weather.location)
+      const summary = `${place}：${current.description} ${this.formatTemperature(current.temperature, unit)}`
+      const results = [this.createResult(
+        `weather-current-${place}`,
+        summary,
+        `体感 ${this.formatTemperature(current.apparent_temperature, unit)} · 湿度 ${Math.round(current.humidity)}% · 风速 ${current.wind_speed} ${weather.wind_speed_unit} · 回车复制`,
+        this.priority + 40,
+        () => this.copy(this.formatSummary(weather))
+      )]
+
+      weather.daily.forEach((day, index) => {
+        const label = DAY_NAMES[index] ?? day.date
+        const range = `${this.formatTemperature(day.temperature_min, unit)} ~ ${this.formatTemperature(day.temperature_max, unit)}`
+        const rain = day.precipitation_probability === null ? '' : ` · 降水概率 ${Math.round(day.precipitation_probability)}%`
+        results.push(this.createResult(
+          `weather-day-${place}-${day.date}`,
+          `${label}：${day.description} ${range}`,
+          `${day.date}${rain}`,
+          this.priority + 30 - index,
+          () => this.copy(`${place} ${label}：${day.description} ${range}${rain}`)
+        ))
+      })
+
+      return results
+    } catch (error) {
+      const appError = handlePluginError('查询天气', error)
+      logger.error('查询天气失败', appError)
+      return []
+    }
+  }
+
+  private formatLocation(location: WeatherLocation): string {
+    return [location.name, location.region, location.country]
+      .filter((part, index, parts): part is string => !!part && parts.indexOf(part) === index)
+      .join(', ')
+  }
+
+  private formatTemperature(value: number, unit: string): string {
+    return `${Math.round(value)}${unit}`
+  }
+
+  private formatSummary(weather: Weather): string {
+    const { current, temperature_unit: unit } = weather
+    const lines = [
+      `${this.formatLocation(weather.location)}：${current.description} ${this.formatTemperature(current.temperature, unit)}（体感 ${this.formatTemperature(current.apparent_temperature, unit)}）`,
+      ...weather.daily.map((day, index) =>
+        `${DAY_NAMES[index] ?? day.date}：${day.description} ${this.formatTemperature(day.temperature_min, unit)} ~ ${this.formatTemperature(day.temperature_max, unit)}`
+      )
+    ]
+    return lines.join('\n')
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'weather'
+      }
+    }
+  }
+
+  private async copy(text: string): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('weather-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text })
+      logger.success('已复制天气信息')
+    } catch (error) {
+      const appError = handlePluginError('复制天气信息', error)
+      logger.error('复制天气信息失败', appError)
+    }
+  }
+}
@@ -0,0 +1,154 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface WifiNetwork {
+  ssid: string
+  signal: number | null
+  secure: boolean
+  known: boolean
+  connected: boolean
+}
+
+/**
+ * Wi-Fi 插件
+ *
+ * 用法：`wifi` 列出网络并回车连接，`wifi <名称>` 过滤，`wifi on` / `wifi off` 开关 Wi-Fi
+ */
+export class WifiPlugin implements SearchPlugin {
+  id = 'wifi'
+  name = 'Wi-Fi'
+  description = '切换 Wi-Fi 网络，打开或关闭 Wi-Fi'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['wifi ', 'wi-fi ']
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Wifi)
+      logger.info('Wi-Fi 插件初始化完成')
+    } catch (error) {
+      logger.warn('Wi-Fi 图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 扫描网络较慢，不带前缀时只响应单独的 wifi
+    if (!context.prefix && !/^wi-?fi$/i.test(context.query.trim())) {
+      return []
+    }
+    const query = context.prefix ? context.query.trim().toLowerCase() : ''
+
+    if (query === 'on' || query === 'off' || query === 'toggle') {
+      const enabled = query === 'toggle' ? undefined : query === 'on'
+      return [this.createResult(
+        `wifi-power-${query}`,
+        query === 'toggle' ? '切换 Wi-Fi 开关' : query === 'on' ? '打开 Wi-Fi' : '关闭 Wi-Fi',
+        '回车执行',
+        this.priority + 50,
+        () => this.setEnabled(enabled)
+      )]
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const networks = await invoke<WifiNetwork[]>('list_wifi_networks')
+
+      const results = networks
+        .filter(network => !query || network.ssid.toLowerCase().includes(query))
+        .slice(0, context.maxResults)
+        .map((network, index) => this.createResult(
+          `wifi-${network.ssid}`,
+          network.connected ? `${network.ssid}（已连接）` : network.ssid,
+          this.formatDescription(network),
+          this.priority + 40 - index,
+          () => this.connect(network)
+        ))
+
+      if (!query) {
+        results.push(this.createResult(
+          'wifi-power-toggle',
+          '切换 Wi-Fi 开关',
+          '打开或关闭 Wi-Fi',
+          this.priority,
+          () => this.setEnabled(undefined)
+        ))
+      }
+
+      return results
+    } catch (error) {
+      const appError = handlePluginError('获取 Wi-Fi 网络', error)
+      logger.error('获取 Wi-Fi 网络失败', appError)
+      return []
+    }
+  }
+
+  private formatDescription(network: WifiNetwork): string {
+    const parts = []
+    if (network.signal !== null) {
+      parts.push(`信号 ${network.signal}%`)
+    }
+    parts.push(network.secure ? '加密' : '开放')
+    if (network.known) {
+      parts.push('已保存')
+    }
+    if (!network.connected) {
+      parts.push('回车连接')
+    }
+    return parts.join(' • ')
+  }
+
+  private createResult(id: string, title: string, description: string, priority: number, action: () => Promise<void>): SearchResultItem {
+    return {
+      id,
+      title,
+      description,
+      icon: this.icon,
+      priority,
+      action,
+      source: this.id,
+      metadata: {
+        type: 'wifi'
+      }
+    }
+  }
+
+  private async connect(network: WifiNetwork): Promise<void> {
+    if (network.connected) {
+      return
+    }
+
+    // 未保存的加密网络需要输入密码
+    let password: string | null = null
+    if (network.secure && !network.known) {
+      password = prompt(`请输入 ${network.ssid} 的密码`)
+      if (password === null) {
+        return
+      }
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('connect_wifi', { ssid: network.ssid, password })
+      logger.success(`已连接 Wi-Fi: ${network.ssid}`)
+    } catch (error) {
+      const appError = handlePluginError('连接 Wi-Fi', error)
+      logger.error('连接 Wi-Fi 失败', appError)
+    }
+  }
+
+  private async setEnabled(enabled: boolean | undefined): Promise<void> {
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const state = await invoke<boolean>('set_wifi_enabled', { enabled: enabled ?? null })
+      logger.info(`Wi-Fi 已${state ? '打开' : '关闭'}`)
+    } catch (error) {
+      const appError = handlePluginError('切换 Wi-Fi', error)
+      logger.error('切换 Wi-Fi 失败', appError)
+    }
+  }
+}
@@ -0,0 +1,106 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+
+interface Workspace {
+  id: number
+  name: string
+  current: boolean
+}
+
+/**
+ * 虚拟桌面插件
+ *
+ * 用法：`desktop` 列出虚拟桌面并回车切换，`desktop <名称或序号>` 过滤；
+ * `desktop move <名称或序号>` 把当前窗口移到该桌面
+ */
+export class WorkspacePlugin implements SearchPlugin {
+  id = 'workspace'
+  name = '虚拟桌面'
+  description = '切换虚拟桌面，或把当前窗口移到其他桌面'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 80
+  searchPrefixes = ['desktop ', 'workspace ', 'ws ']
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.LayoutGrid)
+      logger.info('虚拟桌面插件初始化完成')
+    } catch (error) {
+      logger.warn('虚拟桌面图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 desktop / workspace / ws
+    if (!context.prefix && !/^(desktop|workspace|ws)$/i.test(context.query.trim())) {
+      return []
+    }
+    let query = context.prefix ? context.query.trim().toLowerCase() : ''
+    const move = /^move\b/.test(query)
+    if (move) {
+      query = query.replace(/^move\s*/, '')
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const workspaces = await invoke<Workspace[]>('list_workspaces')
+
+      return workspaces
+        .filter(workspace => !query || workspace.name.toLowerCase().includes(query) || String(workspace.id) === query)
+        .slice(0, context.maxResults)
+        .map((workspace, index) => ({
+          id: `workspace-${move ? 'move' : 'switch'}-${workspace.id}`,
+          title: move ? `把当前窗口移到 ${workspace.name}` : `切换到 ${workspace.name}`,
+          description: workspace.current ? '当前桌面' : '回车执行',
+          icon: this.icon,
+          priority: this.priority + 50 - index,
+          action: () => move ? this.moveWindow(workspace) : this.switchTo(workspace),
+          source: this.id,
+          metadata: {
+            type: 'workspace',
+            workspaceId: workspace.id
+          }
+        }))
+    } catch (error) {
+      const appError = handlePluginError('获取虚拟桌面', error)
+      logger.error('获取虚拟桌面失败', appError)
+      return []
+    }
+  }
+
+  private async switchTo(workspace: Workspace): Promise<void> {
+    if (workspace.current) {
+      return
+    }
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('switch_workspace', { id: workspace.id })
+      logger.info(`已切换到 ${workspace.name}`)
+    } catch (error) {
+      const appError = handlePluginError('切换虚拟桌面', error)
+      logger.error('切换虚拟桌面失败', appError)
+    }
+  }
+
+  private async moveWindow(workspace: Workspace): Promise<void> {
+    try {
+      // 先隐藏启动器，让焦点回到之前的窗口
+      const { getCurrentWindow } = await import('@tauri-apps/api/window')
+      await getCurrentWindow().hide()
+      await new Promise(resolve => setTimeout(resolve, 200))
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('move_window_to_workspace', { id: workspace.id })
+      logger.info(`已把当前窗口移到 ${workspace.name}`)
+    } catch (error) {
+      const appError = handlePluginError('移动窗口', error)
+      logger.error('移动窗口失败', appError)
+    }
+  }
+}
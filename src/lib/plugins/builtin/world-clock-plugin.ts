@@ -0,0 +1,117 @@
+import { useIcon, ICON_MAP } from '@/lib/utils/icon-manager'
+import type { SearchContext, SearchPlugin, SearchResultItem } from '../../search-plugins'
+import { logger } from '../../logger'
+import { handlePluginError } from '../../error-handler'
+import { permissionManager } from '../../security/permission-manager'
+
+interface WorldTime {
+  city: string
+  timezone: string
+  time: string
+  date: string
+  weekday: string
+  utc_offset: string
+  diff_from_local: string
+  diff_minutes: number
+}
+
+/**
+ * 世界时钟插件
+ *
+ * 用法：`time in tokyo`、`tz: new york`；仅输入 `clock` 显示收藏城市
+ */
+export class WorldClockPlugin implements SearchPlugin {
+  id = 'world-clock'
+  name = '世界时钟'
+  description = '查询世界各地城市的当前时间'
+  icon: any = null // 将在初始化时动态加载
+  version = '1.0.0'
+  enabled = true
+  priority = 85
+  searchPrefixes = ['time in ', 'tz:', 'clock ']
+
+  settings = {
+    schema: [
+      {
+        key: 'favoriteCities',
+        label: '收藏城市',
+        description: '不输入城市时显示的城市，以逗号分隔',
+        type: 'string' as const,
+        defaultValue: 'London, New York, Tokyo'
+      }
+    ],
+    values: {
+      favoriteCities: 'London, New York, Tokyo'
+    }
+  }
+
+  async initialize(): Promise<void> {
+    const { getIcon } = useIcon()
+    try {
+      this.icon = await getIcon(ICON_MAP.Globe)
+      logger.info('世界时钟插件初始化完成')
+    } catch (error) {
+      logger.warn('世界时钟图标加载失败，使用默认图标', error)
+    }
+  }
+
+  async search(context: SearchContext): Promise<SearchResultItem[]> {
+    // 不带前缀时只响应单独的 clock，不影响搜索名为 Clock 的应用
+    if (!context.prefix && !/^clock$/i.test(context.query.trim())) {
+      return []
+    }
+    const query = context.prefix ? context.query.trim() : ''
+    const favorites = this.settings.values.favoriteCities
+      .split(',')
+      .map((city: string) => city.trim())
+      .filter(Boolean)
+
+    try {
+      const { invoke } = await import('@tauri-apps/api/core')
+      const times = await invoke<WorldTime[]>('lookup_world_time', {
+        query,
+        favorites
+      })
+
+      return times.map((time, index) => ({
+        id: `world-clock-${time.timezone}`,
+        title: `${time.city} ${time.time}`,
+        description: `${time.weekday} ${time.date} • UTC${time.utc_offset} • ${this.formatDiff(time)}`,
+        icon: this.icon,
+        priority: this.priority + 20 - index,
+        action: () => this.copyTime(time),
+        source: this.id,
+        metadata: {
+          type: 'world-time',
+          timezone: time.timezone,
+          diffMinutes: time.diff_minutes
+        }
+      }))
+    } catch (error) {
+      logger.debug('世界时钟查询无结果', { query: context.query, error })
+      return []
+    }
+  }
+
+  private formatDiff(time: WorldTime): string {
+    return time.diff_minutes === 0 ? '与本地时间相同' : `比本地 ${time.diff_from_local}`
+  }
+
+  private async copyTime(time: WorldTime): Promise<void> {
+    try {
+      // 请求剪贴板访问权限
+      const hasPermission = await permissionManager.requestClipboardAccess('world-clock-plugin')
+      if (!hasPermission) {
+        logger.warn('用户拒绝了剪贴板访问权限')
+        return
+      }
+
+      const { invoke } = await import('@tauri-apps/api/core')
+      await invoke('copy_to_clipboard', { text: `${time.date} ${time.time} (${time.timezone})` })
+      logger.info(`${time.city} 时间已复制到剪贴板`)
+    } catch (error) {
+      const appError = handlePluginError('复制时间', error)
+      logger.error('复制时间失败', appError)
+    }
+  }
+}
@@ -11,8 +11,13 @@ import { logger } from './logger'
 import { handlePluginError } from './error-handler'
 import { InputValidator } from './security/input-validator'
 import { searchCache, withSearchCache } from './cache/search-cache'
+import { getSelectionBoosts, recordSearch, recordSelection as saveSelection, resetLearning, type ProviderTiming } from './usage'
 import { getActivePinia } from 'pinia'
 
+// 排序学习：每次（按时间衰减的）选中增加的优先级及上限，避免盖过前缀命令等高优先级结果
+const SELECTION_BOOST = 15
+const MAX_SELECTION_BOOST = 60
+
 /**
  * 搜索插件管理器实现
  */
@@ -230,10 +235,14 @@ export class SearchPluginManager implements PluginManager {
 
   /**
    * 执行搜索（带缓存支持）
-   */
-  async search(query: string, maxResults = 50): Promise<SearchResultItem[]> {
-    // 输入验证和清理
-    const validationResult = InputValidator.validateSearchQuery(query)
+   * 预热、插件测试等非用户发起的搜索传 recordUsage = false，不计入使用统计
+   */
+  async search(query: string, maxResults = 50, recordUsage = true): Promise<SearchResultItem[]> {
+    // 输入验证和清理；原样接收输入的插件（Shell、正则等）在输入其前缀时只做基础检查
+    const rawInput = this.matchesRawInputPrefix(query)
+    const validationResult = rawInput
+      ? InputValidator.validateToolInput(query)
+      : InputValidator.validateSearchQuery(query)
     if (!validationResult.isValid) {
       logger.warn('搜索查询验证失败', { 
         query, 
@@ -279,39 +288,26 @@ export class SearchPluginManager implements PluginManager {
       // 检查是否有插件前缀
       const hasPrefix = this.checkSearchPrefix(sanitizedQuery)
 
+      // 实际参与本次搜索的插件耗时，写入使用记录
+      const providerTimings: ProviderTiming[] = []
+
+      // 与插件搜索并行读取该查询之前的选择
+      const boostsPromise = getSelectionBoosts(sanitizedQuery)
+
       // 并行执行所有启用插件的搜索（每个插件都有独立的缓存）
       const searchPromises = enabledPlugins.map(async (plugin) => {
         const pluginStartTime = Date.now()
         let pluginResults: SearchResultItem[] = []
         let hasError = false
+        let searched = false
 
         try {
-          // 如果有前缀，只搜索支持该前缀的插件
-          if (hasPrefix && plugin.searchPrefixes) {
-            const matchedPrefix = plugin.searchPrefixes.find(prefix =>
-              sanitizedQuery.toLowerCase().startsWith(prefix.toLowerCase())
-            )
-            if (!matchedPrefix) {
-              return []
-            }
-            // 移除前缀后搜索
-            const queryWithoutPrefix = sanitizedQuery.slice(matchedPrefix.length).trim()
-            const prefixContext = this.createSearchContext(queryWithoutPrefix, maxResults)
-            
-            // 使用带缓存的搜索
-            const cachedSearch = withSearchCache(
-              plugin.id,
-              (context: SearchContext) => plugin.search(context)
-            )
-            pluginResults = await cachedSearch({ ...prefixContext, query: queryWithoutPrefix, maxResults })
-          } else {
-            // 正常搜索（带缓存）
-            const cachedSearch = withSearchCache(
-              plugin.id,
-              (context: SearchContext) => plugin.search(context)
-            )
-            pluginResults = await cachedSearch({ ...context, query: sanitizedQuery, maxResults })
+          const results = await this.runPluginSearch(plugin, sanitizedQuery, context, hasPrefix, rawInput, maxResults)
+          if (!results) {
+            return []
           }
+          searched = true
+          pluginResults = results
         } catch (error) {
           const appError = handlePluginError(`插件 ${plugin.name} 搜索`, error)
           logger.error(`插件 ${plugin.name} 搜索失败`, appError)
@@ -332,6 +328,10 @@ export class SearchPluginManager implements PluginManager {
           
           // 记录到智能缓存系统
           searchCache.recordSearchMetrics(plugin.id, sanitizedQuery, pluginResults, pluginSearchTime)
+
+          if (searched) {
+            providerTimings.push({ provider: plugin.id, latency_ms: pluginSearchTime, result_count: pluginResults.length })
+          }
         }
 
         return pluginResults
@@ -345,7 +345,7 @@ export class SearchPluginManager implements PluginManager {
       }
 
       // 排序和去重
-      const sortedResults = this.sortAndDeduplicateResults(allResults)
+      const sortedResults = this.sortAndDeduplicateResults(this.applySelectionBoosts(allResults, await boostsPromise))
 
       // 限制结果数量
       const finalResults = sortedResults.slice(0, maxResults)
@@ -359,6 +359,9 @@ export class SearchPluginManager implements PluginManager {
       searchCache.recordSearchMetrics('global-search', sanitizedQuery, finalResults, searchTime)
 
       logger.info(`搜索完成: "${sanitizedQuery}" -> ${finalResults.length} 个结果 (${searchTime}ms)`)
+      if (recordUsage) {
+        recordSearch(searchTime, finalResults.length, providerTimings)
+      }
 
       this.emit('search:results', finalResults)
       this.emit('search:end', sanitizedQuery, finalResults.length)
@@ -385,6 +388,92 @@ export class SearchPluginManager implements PluginManager {
     )
   }
 
+  /**
+   * 按前缀规则执行单个插件的搜索（带缓存），插件不参与本次搜索时返回 null
+   */
+  private async runPluginSearch(
+    plugin: SearchPlugin,
+    sanitizedQuery: string,
+    context: SearchContext,
+    hasPrefix: boolean,
+    rawInput: boolean,
+    maxResults: number
+  ): Promise<SearchResultItem[] | null> {
+    const cachedSearch = withSearchCache(
+      plugin.id,
+      (searchContext: SearchContext) => plugin.search(searchContext)
+    )
+
+    // 如果有前缀，只搜索支持该前缀的插件
+    if (hasPrefix && plugin.searchPrefixes) {
+      const matchedPrefix = plugin.searchPrefixes.find(prefix =>
+        sanitizedQuery.toLowerCase().startsWith(prefix.toLowerCase())
+      )
+      if (!matchedPrefix) {
+        return null
+      }
+      // 移除前缀后搜索
+      const queryWithoutPrefix = sanitizedQuery.slice(matchedPrefix.length).trim()
+      const prefixContext = this.createSearchContext(queryWithoutPrefix, maxResults)
+      return await cachedSearch({ ...prefixContext, query: queryWithoutPrefix, maxResults, prefix: matchedPrefix })
+    }
+    // 未经危险字符检查的输入只交给匹配前缀的插件
+    if (plugin.prefixOnly || rawInput) {
+      return null
+    }
+    return await cachedSearch({ ...context, query: sanitizedQuery, maxResults })
+  }
+
+  /**
+   * 只重新执行单个插件的搜索，替换当前结果中该插件的部分；用于实时刷新，不计入使用记录
+   */
+  async refreshPluginResults(
+    pluginId: string,
+    query: string,
+    current: SearchResultItem[],
+    maxResults = 50
+  ): Promise<SearchResultItem[]> {
+    const plugin = this.getEnabledPlugins().find(p => p.id === pluginId)
+    if (!plugin) {
+      return current
+    }
+
+    const rawInput = this.matchesRawInputPrefix(query)
+    const validationResult = rawInput
+      ? InputValidator.validateToolInput(query)
+      : InputValidator.validateSearchQuery(query)
+    const sanitizedQuery = validationResult.sanitized
+    if (!validationResult.isValid || !sanitizedQuery.trim()) {
+      return current
+    }
+
+    let results: SearchResultItem[]
+    try {
+      const context = this.createSearchContext(sanitizedQuery, maxResults)
+      const hasPrefix = this.checkSearchPrefix(sanitizedQuery)
+      results = await this.runPluginSearch(plugin, sanitizedQuery, context, hasPrefix, rawInput, maxResults) ?? []
+    } catch (error) {
+      const appError = handlePluginError(`刷新插件 ${plugin.name} 结果`, error)
+      logger.error(`刷新插件 ${plugin.name} 结果失败`, appError)
+      return current
+    }
+
+    const boosted = this.applySelectionBoosts(results, await getSelectionBoosts(sanitizedQuery))
+    const others = current.filter(result => result.source !== pluginId)
+    return this.sortAndDeduplicateResults([...others, ...boosted]).slice(0, maxResults)
+  }
+
+  /**
+   * 检查查询是否以原样接收输入的插件的前缀开头
+   */
+  private matchesRawInputPrefix(query: string): boolean {
+    const trimmed = query.trim().toLowerCase()
+    return this.getEnabledPlugins().some(plugin =>
+      plugin.rawInput &&
+      plugin.searchPrefixes?.some(prefix => trimmed.startsWith(prefix.toLowerCase()))
+    )
+  }
+
   /**
    * 创建搜索上下文
    */
@@ -400,6 +489,23 @@ export class SearchPluginManager implements PluginManager {
     }
   }
 
+  /**
+   * 提升之前在相同查询下选中过的结果；返回新对象，不修改插件缓存中的结果
+   */
+  private applySelectionBoosts(results: SearchResultItem[], boosts: Map<string, number>): SearchResultItem[] {
+    if (boosts.size === 0) {
+      return results
+    }
+    return results.map(result => {
+      const score = boosts.get(result.id)
+      if (!score) {
+        return result
+      }
+      const boost = Math.round(Math.min(score * SELECTION_BOOST, MAX_SELECTION_BOOST))
+      return { ...result, priority: result.priority + boost }
+    })
+  }
+
   /**
    * 排序和去重结果
    */
@@ -777,6 +883,16 @@ export class SearchPluginManager implements PluginManager {
     logger.info(`插件 ${pluginId} 的缓存已清除`)
   }
 
+  /**
+   * 通知插件结果已变化（例如实时数据），界面只刷新该插件的结果
+   */
+  notifyResultsChanged(pluginId: string): void {
+    searchCache.invalidatePlugin(pluginId)
+    // 综合搜索缓存中也包含该插件的旧结果
+    searchCache.invalidatePlugin('global-search')
+    this.emit('plugin:results-changed', pluginId)
+  }
+
   /**
    * 预热插件缓存
    */
@@ -854,10 +970,20 @@ export class SearchPluginManager implements PluginManager {
   /**
    * 清除学习数据
    */
-  clearLearningData(): void {
+  async clearLearningData(): Promise<void> {
     searchCache.clearLearningData()
+    await resetLearning()
+    searchCache.invalidatePlugin('global-search')
     logger.info('智能缓存学习数据已清除')
   }
+
+  /**
+   * 记录用户选中的结果，并清除综合搜索缓存，使下次搜索按新的选择排序
+   */
+  recordSelection(query: string, result: SearchResultItem): void {
+    saveSelection(query, result)
+    searchCache.invalidatePlugin('global-search')
+  }
 }
 
 // 全局插件管理器实例
@@ -18,6 +18,8 @@ export interface SearchResultItem {
   action: () => void | Promise<void>
   /** 结果来源插件 */
   source: string
+  /** 预览内容（纯文本），选中时按需加载并显示在结果旁的预览区 */
+  preview?: () => Promise<string>
   /** 扩展数据 */
   metadata?: Record<string, any>
 }
@@ -34,6 +36,8 @@ export interface SearchContext {
   keywords: string[]
   /** 最大结果数量 */
   maxResults?: number
+  /** 匹配到的搜索前缀，没有输入前缀时为空 */
+  prefix?: string
 }
 
 /**
@@ -56,6 +60,10 @@ export interface SearchPlugin {
   priority: number
   /** 支持的搜索前缀（例如 'calc:', 'file:'） */
   searchPrefixes?: string[]
+  /** 只在输入前缀时参与搜索（例如需要访问网络或启动外部程序的插件） */
+  prefixOnly?: boolean
+  /** 前缀后的内容原样交给插件（例如 Shell 命令、正则表达式、JSON），不做危险字符检查，由插件自行处理 */
+  rawInput?: boolean
   /** 初始化插件 */
   initialize?: () => Promise<void> | void
   /** 销毁插件 */
@@ -107,6 +115,8 @@ export interface PluginManagerEvents {
   'plugin:disabled': (pluginId: string) => void
   /** 插件配置时触发 */
   'plugin:configured': (pluginId: string, config: Record<string, any>) => void
+  /** 插件结果变化时触发（例如实时刷新的数据） */
+  'plugin:results-changed': (pluginId: string) => void
   /** 搜索开始时触发 */
   'search:start': (query: string) => void
   /** 搜索结果时触发 */
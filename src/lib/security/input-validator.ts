@@ -87,6 +87,41 @@ export function validateAndSanitizeSearchQuery(query: string): ValidationResult
   return result
 }
 
+/**
+ * 验证前缀工具插件（Shell、正则、JSON 等）的输入
+ * 这些插件需要 ( ) [ ] { } | $ 等字符，只检查长度并移除控制字符，保留原有空格
+ */
+export function validateAndSanitizeToolInput(query: string): ValidationResult {
+  const result: ValidationResult = {
+    isValid: true,
+    sanitized: query.trim(),
+    errors: [],
+    warnings: []
+  }
+
+  if (result.sanitized.length === 0) {
+    result.isValid = false
+    result.errors.push('输入不能为空')
+    return result
+  }
+
+  if (result.sanitized.length > 10000) {
+    result.warnings.push('输入过长，已截断')
+    result.sanitized = result.sanitized.substring(0, 10000)
+  }
+
+  result.sanitized = result.sanitized
+    .replace(/[\x00-\x1F\x7F]/g, '') // 移除控制字符
+    .replace(/[\u2028\u2029]/g, '') // 移除行分隔符和段落分隔符
+
+  if (result.sanitized.trim().length === 0) {
+    result.isValid = false
+    result.errors.push('清理后的输入为空')
+  }
+
+  return result
+}
+
 /**
  * 验证文件路径
  */
@@ -345,6 +380,13 @@ export class InputValidator {
     return validateAndSanitizeSearchQuery(query)
   }
 
+  /**
+   * 验证前缀工具插件的输入
+   */
+  static validateToolInput(query: string): ValidationResult {
+    return validateAndSanitizeToolInput(query)
+  }
+
   /**
    * 验证文件路径
    */
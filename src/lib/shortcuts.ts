@@ -6,6 +6,7 @@ import { handlePluginError } from './error-handler'
 
 // 快捷键类型定义
 export type ShortcutKey = 'toggleSearch' | 'navigateUp' | 'navigateDown' | 'launch' | 'adminLaunch' | 'clearSearch'
+    | 'mediaPlayPause' | 'mediaNext' | 'mediaPrevious'
 
 export interface ShortcutConfig {
     label: string
@@ -177,4 +178,20 @@ export const defaultShortcuts: Shortcuts = {
         label: '清空搜索',
         keys: ['Esc'],
     },
+    // 媒体控制默认不绑定，由后端根据快捷键 ID 执行对应操作
+    mediaPlayPause: {
+        label: '播放/暂停',
+        keys: [],
+        global: true,
+    },
+    mediaNext: {
+        label: '下一首',
+        keys: [],
+        global: true,
+    },
+    mediaPrevious: {
+        label: '上一首',
+        keys: [],
+        global: true,
+    },
 } 
\ No newline at end of file
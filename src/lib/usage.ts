@@ -0,0 +1,82 @@
+// lib/usage.ts
+// 使用记录：搜索耗时和选中的结果写入本地数据库，供设置页面展示个人使用统计、发现较慢的插件；
+// 选中记录还用于排序学习，同一查询反复选中的结果排在前面
+
+import type { SearchResultItem } from './search-plugins'
+import { logger } from './logger'
+
+export type UsageRange = 'day' | 'week' | 'month' | 'all'
+
+export interface ProviderTiming {
+  provider: string
+  latency_ms: number
+  result_count: number
+}
+
+export interface UsageCount {
+  name: string
+  count: number
+}
+
+export interface ProviderUsage {
+  provider: string
+  searches: number
+  selections: number
+  average_latency_ms: number | null
+}
+
+export interface SelectionBoost {
+  result_id: string
+  score: number
+}
+
+export interface UsageStats {
+  range: UsageRange
+  total_searches: number
+  total_selections: number
+  average_latency_ms: number | null
+  top_apps: UsageCount[]
+  top_queries: UsageCount[]
+  providers: ProviderUsage[]
+}
+
+// 记录失败不影响搜索，只输出调试日志
+export function recordSearch(latencyMs: number, resultCount: number, providers: ProviderTiming[]) {
+  void import('@tauri-apps/api/core')
+    .then(({ invoke }) => invoke('record_search', { latencyMs, resultCount, providers }))
+    .catch(error => logger.debug('记录搜索耗时失败', error))
+}
+
+export function recordSelection(query: string, result: SearchResultItem) {
+  void import('@tauri-apps/api/core')
+    .then(({ invoke }) => invoke('record_selection', {
+      query,
+      resultId: result.id,
+      title: result.title,
+      provider: result.source
+    }))
+    .catch(error => logger.debug('记录选中结果失败', error))
+}
+
+export async function getUsageStats(range: UsageRange): Promise<UsageStats> {
+  const { invoke } = await import('@tauri-apps/api/core')
+  return invoke<UsageStats>('get_usage_stats', { range })
+}
+
+// 当前查询下之前选中过的结果及其得分（按时间衰减的选中次数），失败时不调整排序
+export async function getSelectionBoosts(query: string): Promise<Map<string, number>> {
+  try {
+    const { invoke } = await import('@tauri-apps/api/core')
+    const boosts = await invoke<SelectionBoost[]>('get_selection_boosts', { query })
+    return new Map(Array.isArray(boosts) ? boosts.map(boost => [boost.result_id, boost.score]) : [])
+  } catch (error) {
+    logger.debug('读取排序学习数据失败', error)
+    return new Map()
+  }
+}
+
+// 清除全部选中记录，常用应用和常用查询统计也随之清空
+export async function resetLearning(): Promise<void> {
+  const { invoke } = await import('@tauri-apps/api/core')
+  await invoke('reset_learning')
+}
@@ -427,5 +427,63 @@ export const ICON_MAP = {
   Flag: 'Flag',
   FlagIcon: 'Flag',
   Circle: 'Circle',
-  CircleIcon: 'Circle'
+  CircleIcon: 'Circle',
+  
+  // 工具相关
+  KeyRound: 'KeyRound',
+  Type: 'Type',
+  Clock: 'Clock',
+  Globe: 'Globe',
+  Timer: 'Timer',
+  Bell: 'Bell',
+  CalendarDays: 'CalendarDays',
+  Contact: 'Contact',
+  NotebookPen: 'NotebookPen',
+  ListTodo: 'ListTodo',
+  Power: 'Power',
+  Activity: 'Activity',
+  Gauge: 'Gauge',
+  Wifi: 'Wifi',
+  Bluetooth: 'Bluetooth',
+  Speaker: 'Speaker',
+  Volume2: 'Volume2',
+  Sun: 'Sun',
+  Music: 'Music',
+  Heart: 'Heart',
+  Pipette: 'Pipette',
+  ScanText: 'ScanText',
+  LayoutGrid: 'LayoutGrid',
+  BellOff: 'BellOff',
+  SunMoon: 'SunMoon',
+  Trash2: 'Trash2',
+  HardDrive: 'HardDrive',
+  Server: 'Server',
+  SquareTerminal: 'SquareTerminal',
+  History: 'History',
+  Variable: 'Variable',
+  BookOpen: 'BookOpen',
+  Package: 'Package',
+  Container: 'Container',
+  FolderGit2: 'FolderGit2',
+  SquareCode: 'SquareCode',
+  Braces: 'Braces',
+  Github: 'Github',
+  Ticket: 'Ticket',
+  MessageCircleQuestion: 'MessageCircleQuestion',
+  LibraryBig: 'LibraryBig',
+  Link: 'Link',
+  ExternalLink: 'ExternalLink',
+  Network: 'Network',
+  Cable: 'Cable',
+  CloudSun: 'CloudSun',
+  Rss: 'Rss',
+  Mail: 'Mail',
+  BatteryMedium: 'BatteryMedium',
+  CaseSensitive: 'CaseSensitive',
+  Regex: 'Regex',
+  CalendarClock: 'CalendarClock',
+  Binary: 'Binary',
+  GitCompare: 'GitCompare',
+  ImageDown: 'ImageDown',
+  Cloud: 'Cloud'
 }
\ No newline at end of file
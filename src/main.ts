@@ -4,6 +4,7 @@ import { createApp } from "vue";
 import App from "./App.vue";
 import "./index.css";
 import { initializeGlobalShortcuts } from './lib/shortcuts';
+import { initializeAppearanceSync } from './lib/appearance';
 import i18n, { initI18nLanguage } from './locales';
 import router from "./router";
 import { logger } from './lib/logger';
@@ -64,4 +65,9 @@ initializeGlobalShortcuts().catch(error => {
   logger.warn('初始化全局快捷键失败:', appError);
 });
 
+// 主题跟随系统外观（在pinia加载之后）
+initializeAppearanceSync().catch(error => {
+  logger.warn('初始化外观同步失败:', error);
+});
+
 app.mount("#app");
@@ -17,6 +17,11 @@ interface UserState {
     };
 }
 
+// 合并默认快捷键，旧版本持久化的配置里缺少新增的快捷键
+function withDefaultShortcuts(saved?: Partial<Shortcuts>): Shortcuts {
+    return { ...defaultShortcuts, ...saved } as Shortcuts;
+}
+
 // 定义用户状态管理
 export const useUserStore = defineStore('user', {
     // 状态 - 使用统一状态管理器作为底层
@@ -30,7 +35,7 @@ export const useUserStore = defineStore('user', {
                 preferences: {
                     theme: unifiedStore.user.preferences.theme,
                     language: unifiedStore.user.preferences.language,
-                    shortcuts: unifiedStore.user.preferences.shortcuts || defaultShortcuts,
+                    shortcuts: withDefaultShortcuts(unifiedStore.user.preferences.shortcuts),
                 },
             };
         } catch (error) {
@@ -42,7 +47,7 @@ export const useUserStore = defineStore('user', {
                 preferences: {
                     theme: 'system',
                     language: 'zh-CN',
-                    shortcuts: defaultShortcuts,
+                    shortcuts: withDefaultShortcuts(),
                 },
             };
         }
@@ -120,8 +125,10 @@ export const useUserStore = defineStore('user', {
         setShortcut(shortcutKey: keyof Shortcuts, keys: string[]) {
             try {
                 const unifiedStore = useUnifiedStateStore();
-                unifiedStore.updateNestedState(`user.preferences.shortcuts.${shortcutKey}.keys`, keys);
-                this.preferences.shortcuts[shortcutKey].keys = keys;
+                // 整项写入，持久化的配置里可能还没有这个快捷键
+                const shortcut = { ...this.preferences.shortcuts[shortcutKey], keys };
+                unifiedStore.updateNestedState(`user.preferences.shortcuts.${shortcutKey}`, shortcut);
+                this.preferences.shortcuts[shortcutKey] = shortcut;
             } catch (error) {
                 // 统一状态管理器不可用时的回退
                 if (this.preferences.shortcuts[shortcutKey]) {